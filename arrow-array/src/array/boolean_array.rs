@@ -128,6 +128,24 @@ impl BooleanArray {
         BooleanBuffer::new(Buffer::from(value), 0, value.len() * 8).into()
     }
 
+    /// Create a new [`BooleanArray`] of `len` values from the LSB packed bits in `bits`,
+    /// copying only the `ceil(len / 8)` bytes needed
+    ///
+    /// See [`BooleanBuffer::to_packed_vec`] for the inverse
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` contains fewer than `len` bits
+    pub fn from_bit_slice(bits: &[u8], len: usize) -> Self {
+        let byte_len = bit_util::ceil(len, 8);
+        assert!(
+            byte_len <= bits.len(),
+            "bit slice not large enough (len: {len}, bytes: {})",
+            bits.len()
+        );
+        Self::new_from_packed(&bits[..byte_len], 0, len)
+    }
+
     /// Returns the length of this array.
     pub fn len(&self) -> usize {
         self.values.len()
@@ -641,6 +659,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_boolean_array_from_bit_slice() {
+        let mut rng = rng();
+        let values: Vec<bool> = (0..200).map(|_| rng.random_bool(0.5)).collect();
+        let bits = BooleanBuffer::from_bools(&values).to_packed_vec();
+
+        for _ in 0..50 {
+            let len = rng.random_range(0..=values.len());
+            let arr = BooleanArray::from_bit_slice(&bits, len);
+            assert_eq!(arr.len(), len);
+            assert_eq!(arr.null_count(), 0);
+            assert_eq!(arr.values().iter().collect::<Vec<_>>(), &values[..len]);
+
+            // Round trip through a sliced array, which must be re-packed
+            let offset = rng.random_range(0..=len);
+            let sliced = arr.slice(offset, len - offset);
+            let packed = sliced.values().to_packed_vec();
+            let expected = BooleanArray::from(values[offset..len].to_vec());
+            assert_eq!(
+                BooleanArray::from_bit_slice(&packed, len - offset),
+                expected
+            );
+
+            let arr = BooleanArray::new_from_packed(bits.clone(), offset, len - offset);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bit slice not large enough (len: 17, bytes: 2)")]
+    fn test_boolean_array_from_bit_slice_too_short() {
+        BooleanArray::from_bit_slice(&[0, 1], 17);
+    }
+
     #[test]
     fn test_boolean_array_from_slice_u8() {
         let v: Vec<u8> = vec![1, 2, 3];
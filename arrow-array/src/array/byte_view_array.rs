@@ -16,12 +16,12 @@
 // under the License.
 
 use crate::array::print_long_array;
-use crate::builder::{ArrayBuilder, GenericByteViewBuilder};
+use crate::builder::{ArrayBuilder, GenericByteBuilder, GenericByteViewBuilder};
 use crate::iterator::ArrayIter;
 use crate::types::bytes::ByteArrayNativeType;
 use crate::types::{BinaryViewType, ByteViewType, StringViewType};
 use crate::{Array, ArrayAccessor, ArrayRef, GenericByteArray, OffsetSizeTrait, Scalar};
-use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, ScalarBuffer};
+use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder, ByteView, MAX_INLINE_VIEW_LEN};
 use arrow_schema::{ArrowError, DataType};
 use core::str;
@@ -30,10 +30,15 @@ use std::any::Any;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::sync::Arc;
 
 use super::ByteArrayType;
 
+/// Data buffers of at least this size that are entirely referenced by the views
+/// of an array are not copied by [`GenericByteViewArray::gc`]
+const GC_PRESERVE_BUFFER_LEN: usize = 1024 * 1024;
+
 /// [Variable-size Binary View Layout]: An array of variable length bytes views.
 ///
 /// This array type is used to store variable length byte data (e.g. Strings, Binary)
@@ -475,9 +480,12 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
     /// This method will compact the data buffers by recreating the view array and only include the data
     /// that is pointed to by the views.
     ///
-    /// Note that it will copy the array regardless of whether the original array is compact.
+    /// Data buffers of at least 1MB whose bytes are all referenced by the views are
+    /// reused rather than copied. All other referenced data is copied into a single
+    /// new buffer, regardless of whether the original array is compact.
     /// Use with caution as this can be an expensive operation, only use it when you are sure that the view
     /// array is significantly smaller than when it is originally created, e.g., after filtering or slicing.
+    /// See [`Self::gc_if_sparse`] to only compact arrays with little referenced data.
     ///
     /// Note: this function does not attempt to canonicalize / deduplicate values. For this
     /// feature see  [`GenericByteViewBuilder::with_deduplicate_strings`].
@@ -512,23 +520,145 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
             };
         }
 
-        // 3) Allocate exactly capacity for all non-inline data
-        let mut data_buf = Vec::with_capacity(total_large);
+        // 3) Find large buffers that are fully referenced, which are reused as is
+        let mut data_blocks = vec![];
+        let mut preserved = vec![None; self.buffers.len()];
+        if self
+            .buffers
+            .iter()
+            .any(|b| b.len() >= GC_PRESERVE_BUFFER_LEN)
+        {
+            let referenced = self.referenced_buffer_bytes(|b| b.len() >= GC_PRESERVE_BUFFER_LEN);
+            for (idx, buffer) in self.buffers.iter().enumerate() {
+                if buffer.len() >= GC_PRESERVE_BUFFER_LEN && referenced[idx] == buffer.len() {
+                    preserved[idx] = Some(data_blocks.len() as u32);
+                    data_blocks.push(buffer.clone());
+                }
+            }
+        }
+        let copied_index = data_blocks.len() as u32;
+
+        // 4) Allocate exactly capacity for all non-inline data to copy
+        let total_copied = match data_blocks.is_empty() {
+            true => total_large,
+            false => self
+                .views()
+                .iter()
+                .map(|v| ByteView::from(*v))
+                .filter(|v| v.length > MAX_INLINE_VIEW_LEN)
+                .filter(|v| preserved[v.buffer_index as usize].is_none())
+                .map(|v| v.length as usize)
+                .sum(),
+        };
+        let mut data_buf = Vec::with_capacity(total_copied);
 
-        // 4) Iterate over views and process each inline/non-inline view
+        // 5) Iterate over views and process each inline/non-inline view
         let views_buf: Vec<u128> = (0..len)
-            .map(|i| unsafe { self.copy_view_to_buffer(i, &mut data_buf) })
+            .map(|i| {
+                let raw_view = self.views()[i];
+                if raw_view as u32 > MAX_INLINE_VIEW_LEN {
+                    let mut view = ByteView::from(raw_view);
+                    if let Some(idx) = preserved[view.buffer_index as usize] {
+                        view.buffer_index = idx;
+                        return view.into();
+                    }
+                }
+                unsafe { self.copy_view_to_buffer(i, copied_index, &mut data_buf) }
+            })
             .collect();
 
-        // 5) Wrap up buffers
-        let data_block = Buffer::from_vec(data_buf);
+        // 6) Wrap up buffers
+        if !data_buf.is_empty() {
+            data_blocks.push(Buffer::from_vec(data_buf));
+        }
         let views_scalar = ScalarBuffer::from(views_buf);
-        let data_blocks = vec![data_block];
 
         // SAFETY: views_scalar, data_blocks, and nulls are correctly aligned and sized
         unsafe { GenericByteViewArray::new_unchecked(views_scalar, data_blocks, nulls) }
     }
 
+    /// Returns a compacted version of this array, as [`Self::gc`], if its
+    /// [`Self::data_buffer_utilization`] is less than `threshold`, otherwise
+    /// returns a clone of this array without copying any data
+    ///
+    /// For example, a `threshold` of `0.5` compacts the array if less than half
+    /// of the bytes of its data buffers are referenced by its views.
+    ///
+    /// ```
+    /// # use arrow_array::StringViewArray;
+    /// let array = StringViewArray::from(vec!["a value longer than 12 bytes"; 4]);
+    /// let sliced = array.slice(0, 1);
+    /// assert_eq!(sliced.data_buffer_utilization(), 0.25);
+    /// // Compacted, as only a quarter of the data is referenced
+    /// let gced = sliced.gc_if_sparse(0.5);
+    /// assert_eq!(gced.data_buffer_utilization(), 1.0);
+    /// assert_ne!(gced.data_buffers()[0].as_ptr(), array.data_buffers()[0].as_ptr());
+    /// // Not compacted
+    /// let gced = array.gc_if_sparse(0.5);
+    /// assert_eq!(gced.data_buffers()[0].as_ptr(), array.data_buffers()[0].as_ptr());
+    /// ```
+    pub fn gc_if_sparse(&self, threshold: f64) -> Self {
+        match self.data_buffer_utilization() < threshold {
+            true => self.gc(),
+            false => self.clone(),
+        }
+    }
+
+    /// Returns the fraction of the bytes of the data buffers that are referenced
+    /// by the views of this array, between `0.0` and `1.0`
+    ///
+    /// Bytes referenced by several views, such as repeated values or values
+    /// that overlap, are only counted once. Returns `1.0` if the data buffers
+    /// are empty.
+    ///
+    /// This can be used to decide if an array, for example produced by slicing
+    /// or filtering a larger array, is worth compacting with [`Self::gc`].
+    /// See [`Self::gc_if_sparse`].
+    pub fn data_buffer_utilization(&self) -> f64 {
+        let total: usize = self.buffers.iter().map(|b| b.len()).sum();
+        if total == 0 {
+            return 1.0;
+        }
+        let referenced: usize = self.referenced_buffer_bytes(|_| true).into_iter().sum();
+        referenced as f64 / total as f64
+    }
+
+    /// Returns the number of bytes of each data buffer referenced by the views,
+    /// counting bytes referenced by several views once, for the buffers for
+    /// which `include` returns true, and 0 for the others
+    fn referenced_buffer_bytes(&self, include: impl Fn(&Buffer) -> bool) -> Vec<usize> {
+        let mut ranges: Vec<Vec<Range<usize>>> = self.buffers.iter().map(|_| Vec::new()).collect();
+        let included: Vec<bool> = self.buffers.iter().map(include).collect();
+        for view in self.views.iter() {
+            let len = *view as u32;
+            if len > MAX_INLINE_VIEW_LEN {
+                let view = ByteView::from(*view);
+                let idx = view.buffer_index as usize;
+                if included[idx] {
+                    let start = view.offset as usize;
+                    ranges[idx].push(start..start + len as usize);
+                }
+            }
+        }
+
+        ranges
+            .into_iter()
+            .map(|mut ranges| {
+                ranges.sort_unstable_by_key(|r| r.start);
+                let mut referenced = 0;
+                let mut end = 0;
+                for range in ranges {
+                    let start = range.start.max(end);
+                    if range.end > start {
+                        referenced += range.end - start;
+                        end = range.end;
+                    }
+                }
+                referenced
+            })
+            .collect()
+    }
+
     /// Copy the i‑th view into `data_buf` if it refers to an out‑of‑line buffer.
     ///
     /// # Safety
@@ -538,10 +668,15 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
     ///   inside one of `self.buffers`.
     /// - `data_buf` must be ready to have additional bytes appended.
     /// - After this call, the returned view will have its
-    ///   `buffer_index` reset to `0` and its `offset` updated so that it points
+    ///   `buffer_index` set to `buffer_index` and its `offset` updated so that it points
     ///   into the bytes just appended at the end of `data_buf`.
     #[inline(always)]
-    unsafe fn copy_view_to_buffer(&self, i: usize, data_buf: &mut Vec<u8>) -> u128 {
+    unsafe fn copy_view_to_buffer(
+        &self,
+        i: usize,
+        buffer_index: u32,
+        data_buf: &mut Vec<u8>,
+    ) -> u128 {
         // SAFETY: `i < self.len()` ensures this is in‑bounds.
         let raw_view = unsafe { *self.views().get_unchecked(i) };
         let mut bv = ByteView::from(raw_view);
@@ -557,11 +692,11 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
             let end = start + bv.length as usize;
             let slice = unsafe { buffer.get_unchecked(start..end) };
 
-            // Copy out‑of‑line data into our single “0” buffer.
+            // Copy out‑of‑line data into our single new buffer.
             let new_offset = data_buf.len() as u32;
             data_buf.extend_from_slice(slice);
 
-            bv.buffer_index = 0;
+            bv.buffer_index = buffer_index;
             bv.offset = new_offset;
             bv.into()
         }
@@ -593,6 +728,100 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
             .sum()
     }
 
+    /// Converts this array to a [`GenericByteArray`] with the same values
+    ///
+    /// If the non-inlined values are stored contiguously and in order in a
+    /// single data buffer, as is the case for arrays converted from a
+    /// [`GenericByteArray`], the values are not copied and the returned array
+    /// references a slice of that buffer. Otherwise the values are copied.
+    ///
+    /// Returns an error if the values do not fit in the offsets of `T`
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow_array::{StringArray, StringViewArray};
+    /// # use arrow_array::types::Utf8Type;
+    /// let array = StringViewArray::from(vec![Some("hello"), None, Some("large payload over 12 bytes")]);
+    /// let array = array.to_byte_array::<Utf8Type>().unwrap();
+    /// assert_eq!(array, StringArray::from(vec![Some("hello"), None, Some("large payload over 12 bytes")]));
+    /// ```
+    pub fn to_byte_array<B>(&self) -> Result<GenericByteArray<B>, ArrowError>
+    where
+        B: ByteArrayType<Native = T::Native>,
+    {
+        let overflow = |len: usize| {
+            ArrowError::InvalidArgumentError(format!(
+                "{len} bytes of values exceed the maximum offset of {}{}Array",
+                B::Offset::PREFIX,
+                B::PREFIX
+            ))
+        };
+
+        if let Some((buffer, range)) = self.contiguous_values() {
+            let mut offsets = Vec::with_capacity(self.len() + 1);
+            offsets.push(B::Offset::usize_as(0));
+            let mut end = 0;
+            for (i, view) in self.views.iter().enumerate() {
+                if self.is_valid(i) {
+                    end += *view as u32 as usize;
+                }
+                offsets.push(B::Offset::from_usize(end).ok_or_else(|| overflow(end))?);
+            }
+            let values = self.buffers[buffer].slice_with_length(range.start, range.len());
+            // Safety: the offsets are monotonically increasing and delimit the
+            // values of this array, which are valid for `T` and so for `B`
+            return Ok(unsafe {
+                GenericByteArray::new_unchecked(
+                    OffsetBuffer::new_unchecked(offsets.into()),
+                    values,
+                    self.nulls.clone(),
+                )
+            });
+        }
+
+        let data_len = self
+            .views
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.is_valid(*i))
+            .map(|(_, view)| *view as u32 as usize)
+            .sum::<usize>();
+        if B::Offset::from_usize(data_len).is_none() {
+            return Err(overflow(data_len));
+        }
+        let mut builder = GenericByteBuilder::<B>::with_capacity(self.len(), data_len);
+        builder.extend(self.iter());
+        Ok(builder.finish())
+    }
+
+    /// Returns the index of the data buffer and the range within it if the
+    /// non-inlined values of this array are stored contiguously and in order
+    /// in a single data buffer, and there are no non-empty inlined values
+    fn contiguous_values(&self) -> Option<(usize, Range<usize>)> {
+        let mut contiguous: Option<(u32, Range<usize>)> = None;
+        for (i, view) in self.views.iter().enumerate() {
+            let len = *view as u32;
+            if len == 0 || self.is_null(i) {
+                continue;
+            }
+            if len <= MAX_INLINE_VIEW_LEN {
+                return None;
+            }
+            let view = ByteView::from(*view);
+            let offset = view.offset as usize;
+            match &mut contiguous {
+                Some((buffer, range)) => {
+                    if view.buffer_index != *buffer || offset != range.end {
+                        return None;
+                    }
+                    range.end += len as usize;
+                }
+                None => contiguous = Some((view.buffer_index, offset..offset + len as usize)),
+            }
+        }
+        contiguous.map(|(buffer, range)| (buffer as usize, range))
+    }
+
     /// Compare two [`GenericByteViewArray`] at index `left_idx` and `right_idx`
     ///
     /// Comparing two ByteView types are non-trivial.
@@ -1078,9 +1307,10 @@ impl From<Vec<Option<String>>> for StringViewArray {
 #[cfg(test)]
 mod tests {
     use crate::builder::{BinaryViewBuilder, StringViewBuilder};
-    use crate::types::BinaryViewType;
+    use crate::types::{BinaryType, BinaryViewType, LargeUtf8Type, Utf8Type};
     use crate::{
-        Array, BinaryViewArray, GenericBinaryArray, GenericByteViewArray, StringViewArray,
+        Array, BinaryArray, BinaryViewArray, GenericBinaryArray, GenericByteViewArray,
+        LargeStringArray, StringArray, StringViewArray,
     };
     use arrow_buffer::{Buffer, ScalarBuffer};
     use arrow_data::{ByteView, MAX_INLINE_VIEW_LEN};
@@ -1404,6 +1634,172 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_data_buffer_utilization() {
+        let values = [
+            "value 0 longer than 12 bytes",
+            "value 1 longer than 12 bytes",
+        ];
+        let mut builder = StringViewBuilder::new();
+        for v in values.iter().cycle().take(8) {
+            builder.append_value(v);
+        }
+        builder.append_value("short");
+        builder.append_null();
+        let array = builder.finish();
+        assert_eq!(array.data_buffer_utilization(), 1.0);
+
+        // Sliced arrays only reference part of the buffer
+        let sliced = array.slice(0, 4);
+        assert_eq!(sliced.data_buffer_utilization(), 0.5);
+        assert_eq!(array.slice(8, 2).data_buffer_utilization(), 0.0);
+
+        // Views referencing the same bytes are counted once
+        let (_, buffers, _) = array.clone().into_parts();
+        let view = array.views()[0];
+        let repeated = StringViewArray::new(vec![view; 10].into(), buffers.clone(), None);
+        assert_eq!(repeated.data_buffer_utilization(), 0.125);
+
+        // Overlapping views are counted once
+        let overlapping = crate::builder::make_view(&buffers[0][10..30], 0, 10);
+        let overlapping =
+            StringViewArray::new(vec![view, overlapping].into(), buffers.clone(), None);
+        assert_eq!(overlapping.data_buffer_utilization(), 30.0 / 224.0);
+
+        assert_eq!(
+            StringViewArray::from(vec!["short"]).data_buffer_utilization(),
+            1.0
+        );
+        assert_eq!(StringViewArray::new_null(3).data_buffer_utilization(), 1.0);
+    }
+
+    #[test]
+    fn test_gc_if_sparse() {
+        let array = StringViewArray::from_iter_values(
+            (0..100).map(|i| format!("value {i:03} longer than 12 bytes")),
+        );
+        assert_eq!(array.data_buffers().len(), 1);
+
+        // Arrays sharing the buffer of their sibling
+        let first = array.slice(0, 10);
+        let second = array.slice(10, 90);
+        assert_eq!(first.data_buffer_utilization(), 0.1);
+        assert_eq!(second.data_buffer_utilization(), 0.9);
+
+        let gced = first.gc_if_sparse(0.5);
+        assert_eq!(gced.data_buffer_utilization(), 1.0);
+        assert_eq!(gced.data_buffers()[0].len(), 300);
+        assert_eq!(gced, first);
+
+        let gced = second.gc_if_sparse(0.5);
+        assert_eq!(
+            gced.data_buffers()[0].as_ptr(),
+            array.data_buffers()[0].as_ptr()
+        );
+        assert_eq!(gced, second);
+
+        // The sibling is unaffected
+        assert_eq!(array.data_buffer_utilization(), 1.0);
+        assert_eq!(array.slice(0, 10), first);
+    }
+
+    #[test]
+    fn test_gc_preserves_large_buffers() {
+        // A single buffer larger than GC_PRESERVE_BUFFER_LEN
+        let strings = StringArray::from_iter_values(
+            (0..40_000).map(|i| format!("value {i:05} longer than 12 bytes")),
+        );
+        let large = StringViewArray::from(&strings);
+        assert_eq!(large.data_buffers().len(), 1);
+        assert!(large.data_buffers()[0].len() >= super::GC_PRESERVE_BUFFER_LEN);
+
+        let mut builder = StringViewBuilder::new().with_fixed_block_size(BLOCK_SIZE);
+        builder.append_value("small buffer value longer than 12 bytes");
+        builder.append_value("short");
+        let small = builder.finish();
+
+        // Combine the large buffer with a small one
+        let mut builder = StringViewBuilder::new();
+        builder.append_array(&small);
+        builder.append_array(&large);
+        builder.append_array(&small);
+        let combined = builder.finish();
+        assert_eq!(combined.data_buffers().len(), 3);
+
+        let gced = combined.gc();
+        gced.to_data().validate_full().unwrap();
+        assert_eq!(gced, combined);
+        assert_eq!(gced.data_buffers().len(), 2);
+        // The large buffer is reused, the small buffers are copied into a new buffer
+        assert_eq!(
+            gced.data_buffers()[0].as_ptr(),
+            large.data_buffers()[0].as_ptr()
+        );
+        assert_eq!(gced.data_buffers()[1].len(), 2 * 39);
+
+        // A slice of the large buffer is copied
+        let sliced = large.slice(10, 5);
+        let gced = sliced.gc();
+        assert_eq!(gced, sliced);
+        assert_eq!(gced.data_buffers().len(), 1);
+        assert_eq!(gced.data_buffers()[0].len(), 5 * 32);
+    }
+
+    #[test]
+    fn test_to_byte_array() {
+        let data = [
+            Some("first value longer than 12 bytes"),
+            None,
+            Some(""),
+            Some("second value longer than 12 bytes"),
+            None,
+            Some("third value longer than 12 bytes"),
+        ];
+        let string_array = StringArray::from_iter(data);
+
+        // A view of a byte array references its buffer, which is reused
+        let view_array = StringViewArray::from(&string_array);
+        let array = view_array.to_byte_array::<Utf8Type>().unwrap();
+        assert_eq!(array, string_array);
+        assert_eq!(array.values().as_ptr(), string_array.values().as_ptr());
+
+        let array = view_array.to_byte_array::<LargeUtf8Type>().unwrap();
+        assert_eq!(array, LargeStringArray::from_iter(data));
+        assert_eq!(array.values().as_ptr(), string_array.values().as_ptr());
+
+        // Slices reference a slice of the buffer
+        let array = view_array.slice(3, 3).to_byte_array::<Utf8Type>().unwrap();
+        assert_eq!(array, string_array.slice(3, 3));
+        assert_eq!(array.value_offsets(), &[0, 33, 33, 65]);
+        assert_eq!(array.values().as_ptr(), unsafe {
+            string_array.values().as_ptr().add(32)
+        });
+
+        // Values in multiple buffers are copied
+        let mut builder = StringViewBuilder::new().with_fixed_block_size(BLOCK_SIZE);
+        builder.extend(data);
+        let view_array = builder.finish();
+        assert!(view_array.data_buffers().len() > 1);
+        assert_eq!(
+            view_array.to_byte_array::<Utf8Type>().unwrap(),
+            string_array
+        );
+
+        // Inlined values are copied
+        let data = [Some("short"), None, Some("longer than 12 bytes")];
+        let view_array = StringViewArray::from_iter(data);
+        let array = view_array.to_byte_array::<Utf8Type>().unwrap();
+        assert_eq!(array, StringArray::from_iter(data));
+
+        let view_array = BinaryViewArray::from_iter(data.map(|v| v.map(str::as_bytes)));
+        let array = view_array.to_byte_array::<BinaryType>().unwrap();
+        assert_eq!(array, BinaryArray::from_iter(data));
+
+        let view_array = StringViewArray::new_null(3);
+        let array = view_array.to_byte_array::<Utf8Type>().unwrap();
+        assert_eq!(array, StringArray::new_null(3));
+    }
+
     #[test]
     fn test_eq() {
         let test_data = [
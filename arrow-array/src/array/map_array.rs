@@ -132,6 +132,30 @@ impl MapArray {
         Self::try_new(field, offsets, entries, nulls, ordered).unwrap()
     }
 
+    /// Create a new [`MapArray`] from the provided parts without validation
+    ///
+    /// # Safety
+    ///
+    /// Safe if [`Self::new`] would not panic with the given arguments
+    pub unsafe fn new_unchecked(
+        field: FieldRef,
+        offsets: OffsetBuffer<i32>,
+        entries: StructArray,
+        nulls: Option<NullBuffer>,
+        ordered: bool,
+    ) -> Self {
+        if cfg!(feature = "force_validate") {
+            return Self::new(field, offsets, entries, nulls, ordered);
+        }
+
+        Self {
+            data_type: DataType::Map(field, ordered),
+            nulls,
+            entries,
+            value_offsets: offsets,
+        }
+    }
+
     /// Deconstruct this array into its constituent parts
     pub fn into_parts(
         self,
@@ -820,4 +844,33 @@ mod tests {
             "Invalid argument error: MapArray entries must contain two children, got 3"
         );
     }
+
+    #[test]
+    fn test_into_parts_round_trip() {
+        let map_array = create_from_buffers();
+        let entries_ptr = map_array.entries().column(0).to_data().buffers()[0].as_ptr();
+
+        let (field, offsets, entries, nulls, ordered) = map_array.clone().into_parts();
+        assert_eq!(offsets.as_ref(), &[0, 3, 6, 8]);
+        assert!(nulls.is_none());
+        assert!(!ordered);
+
+        let rebuilt = MapArray::try_new(
+            field.clone(),
+            offsets.clone(),
+            entries.clone(),
+            nulls.clone(),
+            ordered,
+        )
+        .unwrap();
+        assert_eq!(rebuilt, map_array);
+        assert_eq!(
+            rebuilt.entries().column(0).to_data().buffers()[0].as_ptr(),
+            entries_ptr
+        );
+
+        // Safety: the parts were taken from a valid MapArray
+        let rebuilt = unsafe { MapArray::new_unchecked(field, offsets, entries, nulls, ordered) };
+        assert_eq!(rebuilt, map_array);
+    }
 }
@@ -22,6 +22,7 @@ mod binary_array;
 use crate::types::*;
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::ArrayData;
+use arrow_data::transform::MutableArrayData;
 use arrow_schema::{DataType, IntervalUnit, TimeUnit};
 use std::any::Any;
 use std::sync::Arc;
@@ -875,6 +876,60 @@ pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
     make_array(ArrayData::new_null(data_type, length))
 }
 
+/// Returns a copy of `array` whose buffers only hold the memory required for its values
+///
+/// Unlike [`Array::shrink_to_fit`], which only shrinks exclusively owned buffers and
+/// preserves any offsets, this copies the values of sliced or shared arrays into new
+/// buffers, recursing into child arrays. The values of dictionaries are compacted,
+/// but unused values are not removed, and view arrays are garbage collected as by
+/// [`GenericByteViewArray::gc`].
+///
+/// ```
+/// use arrow_array::{Array, Int32Array, compact_array};
+///
+/// let array = Int32Array::from_iter_values(0..1000);
+/// let sliced = array.slice(10, 10);
+/// assert_eq!(sliced.get_array_memory_size(), array.get_array_memory_size());
+///
+/// let compacted = compact_array(&sliced);
+/// assert_eq!(compacted.as_ref(), &sliced as &dyn Array);
+/// assert!(compacted.get_array_memory_size() < 200);
+/// ```
+pub fn compact_array(array: &dyn Array) -> ArrayRef {
+    make_array(compact_data(&array.to_data()))
+}
+
+/// Copies `data` into compact buffers, see [`compact_array`]
+fn compact_data(data: &ArrayData) -> ArrayData {
+    match data.data_type() {
+        DataType::Utf8View => StringViewArray::from(data.clone()).gc().into_data(),
+        DataType::BinaryView => BinaryViewArray::from(data.clone()).gc().into_data(),
+        _ => {
+            let mut mutable = MutableArrayData::new(vec![data], false, data.len());
+            mutable.extend(0, 0, data.len());
+            compact_children(mutable.freeze())
+        }
+    }
+}
+
+/// Compacts the children of `data`, whose own buffers have been copied by
+/// [`MutableArrayData`], which does not copy dictionary values or view buffers
+fn compact_children(data: ArrayData) -> ArrayData {
+    let children: Vec<_> = match data.data_type() {
+        DataType::Utf8View | DataType::BinaryView => return compact_data(&data),
+        DataType::Dictionary(_, _) => data.child_data().iter().map(compact_data).collect(),
+        _ if data.child_data().is_empty() => return data,
+        _ => data
+            .child_data()
+            .iter()
+            .map(|c| compact_children(c.clone()))
+            .collect(),
+    };
+    let builder = data.into_builder().child_data(children);
+    // Safety: the children are equal to those of the valid `data`
+    unsafe { builder.build_unchecked() }
+}
+
 /// Helper function that gets offset from an [`ArrayData`]
 ///
 /// # Safety
@@ -1279,4 +1334,88 @@ mod tests {
         let expected: Int32Array = vec![1, 2, 3].into_iter().map(Some).collect();
         assert_eq!(array, expected);
     }
+
+    #[test]
+    fn test_compact_builder_array() {
+        let mut builder = crate::builder::Int32Builder::with_capacity(4096);
+        builder.extend((0..10).map(Some));
+        builder.append_null();
+        let array = builder.finish();
+        assert!(array.get_array_memory_size() > 4096 * 4);
+
+        let compacted = compact_array(&array);
+        assert_eq!(compacted.as_ref(), &array as &dyn Array);
+        assert!(compacted.get_array_memory_size() < 512);
+    }
+
+    #[test]
+    fn test_compact_sliced_arrays() {
+        let strings = StringArray::from_iter_values((0..1000).map(|i| format!("value{i}")));
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, false)),
+            OffsetBuffer::from_lengths(std::iter::repeat_n(2, 500)),
+            Arc::new(strings.clone()),
+            None,
+        );
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("s", DataType::Utf8, false)),
+                Arc::new(strings.slice(0, 500)) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("l", list.data_type().clone(), false)),
+                Arc::new(list.clone()) as ArrayRef,
+            ),
+        ]);
+
+        for array in [
+            Arc::new(strings) as ArrayRef,
+            Arc::new(list),
+            Arc::new(struct_array),
+        ] {
+            let sliced = array.slice(100, 5);
+            let compacted = compact_array(&sliced);
+            assert_eq!(&compacted, &sliced);
+            assert_eq!(compacted.offset(), 0);
+            assert!(
+                compacted.get_array_memory_size() * 10 < sliced.get_array_memory_size(),
+                "{}: {} {}",
+                array.data_type(),
+                compacted.get_array_memory_size(),
+                sliced.get_array_memory_size()
+            );
+        }
+    }
+
+    #[test]
+    fn test_compact_dictionary_and_view_arrays() {
+        let mut builder =
+            crate::builder::StringDictionaryBuilder::<Int32Type>::with_capacity(4096, 4096, 65536);
+        builder.extend(["a", "b", "a"].map(Some));
+        let dictionary = builder.finish();
+        let compacted = compact_array(&dictionary);
+        assert_eq!(compacted.as_ref(), &dictionary as &dyn Array);
+        assert!(compacted.get_array_memory_size() < 1024);
+
+        let views = StringViewArray::from_iter_values(
+            (0..1000).map(|i| format!("a string longer than 12 bytes {i}")),
+        );
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8View, false)),
+            OffsetBuffer::from_lengths(std::iter::repeat_n(2, 500)),
+            Arc::new(views.clone()),
+            None,
+        );
+        for array in [Arc::new(views) as ArrayRef, Arc::new(list)] {
+            let sliced = array.slice(10, 2);
+            let compacted = compact_array(&sliced);
+            assert_eq!(&compacted, &sliced);
+            assert!(
+                compacted.get_array_memory_size() < 1024,
+                "{}: {}",
+                array.data_type(),
+                compacted.get_array_memory_size()
+            );
+        }
+    }
 }
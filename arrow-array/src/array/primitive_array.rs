@@ -24,8 +24,8 @@ use crate::temporal_conversions::{
 use crate::timezone::Tz;
 use crate::trusted_len::trusted_len_unzip;
 use crate::types::*;
-use crate::{Array, ArrayAccessor, ArrayRef, Scalar};
-use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, ScalarBuffer, i256};
+use crate::{Array, ArrayAccessor, ArrayRef, ArrowNativeTypeOp, Scalar};
+use arrow_buffer::{ArrowNativeType, Buffer, IntervalMonthDayNano, NullBuffer, ScalarBuffer, i256};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType};
@@ -1538,6 +1538,11 @@ def_numeric_from_vec!(TimestampMillisecondType);
 def_numeric_from_vec!(TimestampMicrosecondType);
 def_numeric_from_vec!(TimestampNanosecondType);
 
+/// The [`Tz`] used for timestamps without a timezone
+fn utc_tz() -> Tz {
+    "+00:00".parse().unwrap()
+}
+
 impl<T: ArrowTimestampType> PrimitiveArray<T> {
     /// Returns the timezone of this array if any
     pub fn timezone(&self) -> Option<&str> {
@@ -1564,6 +1569,135 @@ impl<T: ArrowTimestampType> PrimitiveArray<T> {
             ..self
         }
     }
+
+    /// Returns the timezone of this array parsed as a [`Tz`], or `None` if
+    /// this array has no timezone
+    ///
+    /// Returns an error if the timezone is not valid, see [`Tz`]
+    pub fn parsed_timezone(&self) -> Result<Option<Tz>, ArrowError> {
+        self.timezone().map(str::parse).transpose()
+    }
+
+    /// Returns value `i` as a chrono `DateTime` in the timezone of this array
+    ///
+    /// Values of an array without a timezone are returned in UTC. Returns
+    /// `Ok(None)` if the value can not be represented as a `DateTime`, and an
+    /// error if the timezone of this array is not valid.
+    ///
+    /// The timezone is parsed on each call. When converting many values, parse
+    /// it once with [`Self::parsed_timezone`] and pass it to
+    /// [`Self::value_as_datetime_with_tz`], or use [`Self::try_iter_as_datetime`]
+    ///
+    /// ```
+    /// # use arrow_array::TimestampSecondArray;
+    /// let array = TimestampSecondArray::from(vec![0, 3600]).with_timezone("+02:00");
+    /// let value = array.try_value_as_datetime_with_tz(1).unwrap().unwrap();
+    /// assert_eq!(value.to_rfc3339(), "1970-01-01T03:00:00+02:00");
+    ///
+    /// // Parse the timezone once for many values
+    /// let tz = array.parsed_timezone().unwrap().unwrap();
+    /// for i in [1, 0, 1] {
+    ///     let value = array.value_as_datetime_with_tz(i, tz).unwrap();
+    ///     assert_eq!(value, array.try_value_as_datetime_with_tz(i).unwrap().unwrap());
+    /// }
+    /// ```
+    ///
+    /// See notes on [`PrimitiveArray::value`] regarding nulls and panics
+    pub fn try_value_as_datetime_with_tz(
+        &self,
+        i: usize,
+    ) -> Result<Option<DateTime<Tz>>, ArrowError> {
+        let tz = self.parsed_timezone()?.unwrap_or_else(utc_tz);
+        Ok(as_datetime_with_timezone::<T>(self.value(i), tz))
+    }
+
+    /// Returns an iterator over the values of this array as chrono `DateTime`
+    /// in the timezone of this array, yielding `None` for null values
+    ///
+    /// Values of an array without a timezone are returned in UTC, and values
+    /// that can not be represented as a `DateTime` are yielded as `None`.
+    ///
+    /// Returns an error if the timezone of this array is not valid.
+    ///
+    /// ```
+    /// # use arrow_array::TimestampSecondArray;
+    /// let array = TimestampSecondArray::from(vec![Some(0), None]).with_timezone("+02:00");
+    /// let values: Vec<_> = array
+    ///     .try_iter_as_datetime()
+    ///     .unwrap()
+    ///     .map(|v| v.map(|v| v.to_rfc3339()))
+    ///     .collect();
+    /// assert_eq!(values, [Some("1970-01-01T02:00:00+02:00".to_string()), None]);
+    ///
+    /// let array = array.with_timezone("Mars/Olympus_Mons");
+    /// assert!(array.try_iter_as_datetime().is_err());
+    /// ```
+    pub fn try_iter_as_datetime(
+        &self,
+    ) -> Result<impl Iterator<Item = Option<DateTime<Tz>>> + '_, ArrowError> {
+        let tz = self.parsed_timezone()?.unwrap_or_else(utc_tz);
+        Ok(self
+            .iter()
+            .map(move |v| v.and_then(|v| as_datetime_with_timezone::<T>(v, tz))))
+    }
+
+    /// Returns an iterator over the values of this array as chrono `DateTime`
+    /// in the timezone of this array, yielding `None` for null values
+    ///
+    /// See [`Self::try_iter_as_datetime`] for a fallible version
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timezone of this array is not valid
+    pub fn iter_as_datetime(&self) -> impl Iterator<Item = Option<DateTime<Tz>>> + '_ {
+        self.try_iter_as_datetime()
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl PrimitiveArray<IntervalMonthDayNanoType> {
+    /// Adds `rhs` to each value of this array, with each component checked
+    /// for overflow
+    ///
+    /// Returns an error if the months, days or nanoseconds of any value
+    /// overflow. Null values are ignored.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, IntervalMonthDayNanoArray};
+    /// # use arrow_buffer::IntervalMonthDayNano;
+    /// let array = IntervalMonthDayNanoArray::from(vec![
+    ///     Some(IntervalMonthDayNano::from_parts(1, -2, 3)),
+    ///     None,
+    /// ]);
+    /// let rhs = IntervalMonthDayNano::from_parts(-2, 2, 1);
+    /// let sum = array.add_checked(rhs).unwrap();
+    /// assert_eq!(sum.value(0).to_parts(), (-1, 0, 4));
+    /// assert!(sum.is_null(1));
+    ///
+    /// let rhs = IntervalMonthDayNano::from_parts(0, i32::MIN, 0);
+    /// assert!(array.add_checked(rhs).is_err());
+    /// ```
+    pub fn add_checked(&self, rhs: IntervalMonthDayNano) -> Result<Self, ArrowError> {
+        self.try_unary(|v| v.add_checked(rhs))
+    }
+
+    /// Subtracts `rhs` from each value of this array, with each component
+    /// checked for overflow
+    ///
+    /// Returns an error if the months, days or nanoseconds of any value
+    /// overflow. Null values are ignored.
+    pub fn sub_checked(&self, rhs: IntervalMonthDayNano) -> Result<Self, ArrowError> {
+        self.try_unary(|v| v.sub_checked(rhs))
+    }
+
+    /// Negates each value of this array, with each component checked for
+    /// overflow
+    ///
+    /// Returns an error if any component of a value is the minimum value of
+    /// its type. Null values are ignored.
+    pub fn neg_checked(&self) -> Result<Self, ArrowError> {
+        self.try_unary(|v| v.neg_checked())
+    }
 }
 
 /// Constructs a `PrimitiveArray` from an array data reference.
@@ -1585,6 +1719,100 @@ impl<T: ArrowPrimitiveType> From<ArrayData> for PrimitiveArray<T> {
     }
 }
 
+/// How values that exceed the precision of a decimal array are handled, see
+/// [`PrimitiveArray::with_precision_and_scale_checked`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DecimalOverflow {
+    /// Return an error
+    #[default]
+    Error,
+    /// Replace the value with null
+    Null,
+}
+
+/// Parses a decimal string into a value of `T` with the given precision and scale,
+/// rounding excess fractional digits half away from zero
+fn parse_decimal_string<T: DecimalType>(
+    s: &str,
+    precision: u8,
+    scale: i8,
+) -> Result<T::Native, ArrowError> {
+    let parse_err =
+        || ArrowError::ParseError(format!("can't parse the string value {s} to decimal"));
+    let overflow_err = || {
+        ArrowError::InvalidArgumentError(format!(
+            "{s} is out of range for {}({precision}, {scale})",
+            T::PREFIX
+        ))
+    };
+
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(idx) => (&unsigned[..idx], Some(&unsigned[idx + 1..])),
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(parse_err());
+    }
+    let exponent = match exponent {
+        Some(e) => e.parse::<i64>().map_err(|_| parse_err())?,
+        None => 0,
+    };
+
+    // The string represents `digits * 10^(exponent - fraction.len())`, which is
+    // stored as `digits * 10^shift` for the given scale
+    let digits: Vec<u8> = integer
+        .bytes()
+        .chain(fraction.bytes())
+        .skip_while(|b| *b == b'0')
+        .map(|b| b - b'0')
+        .collect();
+    let shift = exponent
+        .saturating_sub(fraction.len() as i64)
+        .saturating_add(scale as i64);
+    // The number of digits before the decimal point of the stored value
+    let keep = digits.len() as i64 + shift.min(0);
+
+    let ten = T::Native::usize_as(10);
+    let mut value = T::Native::usize_as(0);
+    for d in digits.iter().take(keep.max(0) as usize) {
+        value = value
+            .mul_checked(ten)
+            .and_then(|v| v.add_checked(T::Native::usize_as(*d as usize)))
+            .map_err(|_| overflow_err())?;
+    }
+    if shift > 0 && !digits.is_empty() {
+        let shift = u32::try_from(shift).map_err(|_| overflow_err())?;
+        value = ten
+            .pow_checked(shift)
+            .and_then(|m| value.mul_checked(m))
+            .map_err(|_| overflow_err())?;
+    }
+    if shift < 0 && keep >= 0 && digits.get(keep as usize).is_some_and(|d| *d >= 5) {
+        value = value
+            .add_checked(T::Native::usize_as(1))
+            .map_err(|_| overflow_err())?;
+    }
+    if !T::is_valid_decimal_precision(value, precision) {
+        return Err(overflow_err());
+    }
+    Ok(if negative {
+        value.neg_wrapping()
+    } else {
+        value
+    })
+}
+
 impl<T: DecimalType + ArrowPrimitiveType> PrimitiveArray<T> {
     /// Returns a Decimal array with the same data as self, with the
     /// specified precision and scale.
@@ -1623,6 +1851,103 @@ impl<T: DecimalType + ArrowPrimitiveType> PrimitiveArray<T> {
         self.unary_opt::<_, T>(|v| T::is_valid_decimal_precision(v, precision).then_some(v))
     }
 
+    /// Returns a Decimal array with the same data as self, with the specified
+    /// precision and scale, handling values that exceed `precision` as
+    /// specified by `overflow`
+    ///
+    /// Unlike [`Self::with_precision_and_scale`], this validates the values of the array
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Decimal128Array, DecimalOverflow};
+    /// let array = Decimal128Array::from(vec![Some(12345), None, Some(123)]);
+    ///
+    /// let err = array.clone().with_precision_and_scale_checked(4, 2, DecimalOverflow::Error);
+    /// assert!(err.is_err());
+    ///
+    /// let array = array.with_precision_and_scale_checked(4, 2, DecimalOverflow::Null).unwrap();
+    /// assert_eq!(array.null_count(), 2);
+    /// assert_eq!(array.value_as_string(2), "1.23");
+    /// ```
+    pub fn with_precision_and_scale_checked(
+        self,
+        precision: u8,
+        scale: i8,
+        overflow: DecimalOverflow,
+    ) -> Result<Self, ArrowError> {
+        let array = self.with_precision_and_scale(precision, scale)?;
+        match overflow {
+            DecimalOverflow::Error => {
+                array.validate_decimal_precision(precision)?;
+                Ok(array)
+            }
+            DecimalOverflow::Null => array
+                .null_if_overflow_precision(precision)
+                .with_precision_and_scale(precision, scale),
+        }
+    }
+
+    /// Creates a Decimal array with the specified precision and scale from an
+    /// iterator of values, returning an error if any value exceeds `precision`
+    ///
+    /// ```
+    /// # use arrow_array::Decimal256Array;
+    /// # use arrow_buffer::i256;
+    /// let values = [i256::from_i128(12345), i256::from_i128(-99999)];
+    /// let array = Decimal256Array::from_iter_values_with_precision(values, 5, 2).unwrap();
+    /// assert_eq!(array.value_as_string(1), "-999.99");
+    ///
+    /// let values = [i256::from_i128(100000)];
+    /// assert!(Decimal256Array::from_iter_values_with_precision(values, 5, 2).is_err());
+    /// ```
+    pub fn from_iter_values_with_precision<I: IntoIterator<Item = T::Native>>(
+        iter: I,
+        precision: u8,
+        scale: i8,
+    ) -> Result<Self, ArrowError> {
+        Self::from_iter_values(iter).with_precision_and_scale_checked(
+            precision,
+            scale,
+            DecimalOverflow::Error,
+        )
+    }
+
+    /// Creates a Decimal array with the specified precision and scale by parsing
+    /// decimal strings, such as `"-123.45"` or `"1.2345e2"`
+    ///
+    /// Fractional digits in excess of `scale` are rounded, with ties rounded away
+    /// from zero. Returns an error if a string is not a valid decimal, or if its
+    /// rounded value exceeds `precision`.
+    ///
+    /// ```
+    /// # use arrow_array::Decimal128Array;
+    /// let array = Decimal128Array::try_from_decimal_strings(
+    ///     ["1.5", "-0.125", "2.5e-1", "1E2"],
+    ///     5,
+    ///     2,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(array.values(), &[150, -13, 25, 10000]);
+    ///
+    /// assert!(Decimal128Array::try_from_decimal_strings(["1000"], 5, 2).is_err());
+    /// assert!(Decimal128Array::try_from_decimal_strings(["1.2.3"], 5, 2).is_err());
+    /// ```
+    pub fn try_from_decimal_strings<I, S>(
+        values: I,
+        precision: u8,
+        scale: i8,
+    ) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        validate_decimal_precision_and_scale::<T>(precision, scale)?;
+        let values = values
+            .into_iter()
+            .map(|s| parse_decimal_string::<T>(s.as_ref(), precision, scale))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(values.into(), None).with_precision_and_scale(precision, scale)
+    }
+
     /// Returns [`Self::value`] formatted as a string
     pub fn value_as_string(&self, row: usize) -> String {
         T::format_decimal(self.value(row), self.precision(), self.scale())
@@ -1896,6 +2221,59 @@ mod tests {
         assert_eq!(v2, arr.values()[2]);
     }
 
+    #[test]
+    fn test_interval_month_day_nano_checked_arithmetic() {
+        let interval = IntervalMonthDayNano::from_parts;
+        let arr = IntervalMonthDayNanoArray::from(vec![
+            Some(interval(1, -2, 3_000_000_000)),
+            None,
+            Some(interval(-14, 30, -500)),
+            Some(interval(0, 0, 0)),
+        ]);
+
+        let rhs = interval(-2, 3, -4_000_000_000);
+        let sum = arr.add_checked(rhs).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(interval(-1, 1, -1_000_000_000)),
+            None,
+            Some(interval(-16, 33, -4_000_000_500)),
+            Some(interval(-2, 3, -4_000_000_000)),
+        ]);
+        assert_eq!(sum, expected);
+        assert_eq!(sum.sub_checked(rhs).unwrap(), arr);
+
+        let neg = arr.neg_checked().unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(interval(-1, 2, -3_000_000_000)),
+            None,
+            Some(interval(14, -30, 500)),
+            Some(interval(0, 0, 0)),
+        ]);
+        assert_eq!(neg, expected);
+        assert_eq!(neg.neg_checked().unwrap(), arr);
+
+        // Overflow of each component is an error, even if the others do not overflow
+        for rhs in [
+            interval(i32::MAX, 0, 0),
+            interval(0, i32::MIN, 0),
+            interval(0, 0, i64::MAX),
+        ] {
+            let err = arr.add_checked(rhs).unwrap_err();
+            assert!(matches!(err, ArrowError::ArithmeticOverflow(_)), "{err}");
+        }
+        let err = arr.sub_checked(interval(0, 0, i64::MIN)).unwrap_err();
+        assert!(matches!(err, ArrowError::ArithmeticOverflow(_)), "{err}");
+
+        let arr = IntervalMonthDayNanoArray::from(vec![Some(interval(1, i32::MIN, -1)), None]);
+        let err = arr.neg_checked().unwrap_err();
+        assert!(matches!(err, ArrowError::ArithmeticOverflow(_)), "{err}");
+
+        // Null values are not checked
+        let arr = IntervalMonthDayNanoArray::from(vec![None, Some(interval(1, 1, 1))]);
+        let arr = arr.slice(0, 1);
+        assert_eq!(arr.neg_checked().unwrap().null_count(), 1);
+    }
+
     #[test]
     fn test_duration_array_from_vec() {
         let arr = DurationSecondArray::from(vec![Some(1), None, Some(-5)]);
@@ -2181,6 +2559,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_as_datetime_with_fixed_offset_tz() {
+        fn check<T: ArrowTimestampType>(array: PrimitiveArray<T>) {
+            let expected = [Some("2018-12-31T08:00:00+08:00".to_string()), None];
+            let array = array.with_timezone("+08:00");
+            let values: Vec<_> = array
+                .iter_as_datetime()
+                .map(|v| v.map(|v| v.to_rfc3339()))
+                .collect();
+            assert_eq!(values, expected);
+            let value = array.try_value_as_datetime_with_tz(0).unwrap().unwrap();
+            assert_eq!(value.to_rfc3339(), "2018-12-31T08:00:00+08:00");
+
+            // Without a timezone values are returned in UTC
+            let array = array.with_timezone_opt(None::<String>);
+            let value = array.try_value_as_datetime_with_tz(0).unwrap().unwrap();
+            assert_eq!(value.to_rfc3339(), "2018-12-31T00:00:00+00:00");
+            let array = array.with_timezone_utc();
+            let value = array.iter_as_datetime().next().unwrap().unwrap();
+            assert_eq!(value.to_rfc3339(), "2018-12-31T00:00:00+00:00");
+        }
+
+        check(TimestampSecondArray::from(vec![Some(1546214400), None]));
+        check(TimestampMillisecondArray::from(vec![
+            Some(1546214400000),
+            None,
+        ]));
+        check(TimestampMicrosecondArray::from(vec![
+            Some(1546214400000000),
+            None,
+        ]));
+        check(TimestampNanosecondArray::from(vec![
+            Some(1546214400000000000),
+            None,
+        ]));
+    }
+
+    #[test]
+    fn test_timestamp_as_datetime_with_invalid_tz() {
+        let array = TimestampMillisecondArray::from(vec![1546214400000]).with_timezone("xxx");
+        assert!(array.parsed_timezone().is_err());
+        let err = array.try_value_as_datetime_with_tz(0).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid timezone \"xxx\""),
+            "{err}"
+        );
+        assert!(array.try_iter_as_datetime().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid timezone \"xxx\"")]
+    fn test_timestamp_iter_as_datetime_invalid_tz() {
+        let array = TimestampMillisecondArray::from(vec![1546214400000]).with_timezone("xxx");
+        let _ = array.iter_as_datetime();
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_timestamp_as_datetime_with_named_tz() {
+        // Either side of the DST transitions in America/Denver
+        let array = TimestampMillisecondArray::from(vec![
+            Some(1647161999000),
+            Some(1647162000000),
+            None,
+            Some(1667721599000),
+            Some(1667721600000),
+        ])
+        .with_timezone("America/Denver");
+        let values: Vec<_> = array
+            .try_iter_as_datetime()
+            .unwrap()
+            .map(|v| v.map(|v| v.to_rfc3339()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                Some("2022-03-13T01:59:59-07:00".to_string()),
+                Some("2022-03-13T03:00:00-06:00".to_string()),
+                None,
+                Some("2022-11-06T01:59:59-06:00".to_string()),
+                Some("2022-11-06T01:00:00-07:00".to_string()),
+            ]
+        );
+        let value = array.try_value_as_datetime_with_tz(1).unwrap().unwrap();
+        assert_eq!(value.to_rfc3339(), "2022-03-13T03:00:00-06:00");
+    }
+
     #[test]
     fn test_date32_fmt_debug() {
         let arr: PrimitiveArray<Date32Type> = vec![12356, 13548, -365].into();
@@ -2598,6 +3063,174 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_decimal_array_with_precision_and_scale_checked() {
+        let array = Decimal128Array::from(vec![Some(99999), Some(-100000), None, Some(-99999)]);
+
+        let err = array
+            .clone()
+            .with_precision_and_scale_checked(5, 2, DecimalOverflow::Error)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: -1000.00 is too small to store in a Decimal128 of precision 5. Min is -999.99"
+        );
+
+        let result = array
+            .with_precision_and_scale_checked(5, 2, DecimalOverflow::Null)
+            .unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(5, 2));
+        let expected = Decimal128Array::from(vec![Some(99999), None, None, Some(-99999)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_decimal_from_iter_values_with_precision() {
+        let max = Decimal128Type::MAX_FOR_EACH_PRECISION[38];
+        let array =
+            Decimal128Array::from_iter_values_with_precision([max, -max, 0], 38, 0).unwrap();
+        assert_eq!(array.values(), &[max, -max, 0]);
+        assert_eq!(array.data_type(), &DataType::Decimal128(38, 0));
+        Decimal128Array::from_iter_values_with_precision([max + 1], 38, 0).unwrap_err();
+
+        let max = Decimal256Type::MAX_FOR_EACH_PRECISION[76];
+        let array =
+            Decimal256Array::from_iter_values_with_precision([max, max.neg_wrapping()], 76, 10)
+                .unwrap();
+        assert_eq!(array.values(), &[max, max.neg_wrapping()]);
+        assert_eq!(array.data_type(), &DataType::Decimal256(76, 10));
+
+        let min = max.neg_wrapping().sub_wrapping(i256::ONE);
+        let err = Decimal256Array::from_iter_values_with_precision([min], 76, 0).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("too small to store in a Decimal256 of precision 76"),
+            "{err}"
+        );
+
+        let err = Decimal256Array::from_iter_values_with_precision([i256::ONE], 77, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: precision 77 is greater than max 76"
+        );
+    }
+
+    #[test]
+    fn test_decimal_try_from_decimal_strings() {
+        let array = Decimal128Array::try_from_decimal_strings(
+            [
+                "0",
+                "-0",
+                "+1",
+                "1.",
+                ".5",
+                "-.5",
+                "123.45",
+                "-123.45",
+                "0.001",
+                "00012.3400",
+            ],
+            10,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            array.values(),
+            &[0, 0, 1000, 1000, 500, -500, 123450, -123450, 1, 12340]
+        );
+        assert_eq!(array.data_type(), &DataType::Decimal128(10, 3));
+
+        for s in [
+            "", "-", ".", "1.2.3", "1a", " 1", "--1", "1e", "e1", "1e1.5", "1e+-1",
+        ] {
+            let err = Decimal128Array::try_from_decimal_strings([s], 10, 3).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Parser error: can't parse the string value {s} to decimal")
+            );
+        }
+    }
+
+    #[test]
+    fn test_decimal_try_from_decimal_strings_rounding() {
+        let array = Decimal128Array::try_from_decimal_strings(
+            [
+                "1.234", "1.235", "-1.235", "0.005", "-0.004", "0.0005", "9.995",
+            ],
+            5,
+            2,
+        )
+        .unwrap();
+        assert_eq!(array.values(), &[123, 124, -124, 1, 0, 0, 1000]);
+
+        // Rounding may exceed the precision
+        let err = Decimal128Array::try_from_decimal_strings(["999.995"], 5, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 999.995 is out of range for Decimal128(5, 2)"
+        );
+
+        let array =
+            Decimal128Array::try_from_decimal_strings(["1250", "-1249", "50"], 5, -2).unwrap();
+        assert_eq!(array.values(), &[13, -12, 1]);
+    }
+
+    #[test]
+    fn test_decimal_try_from_decimal_strings_scientific() {
+        let array = Decimal256Array::try_from_decimal_strings(
+            [
+                "1e2", "1.5E3", "-2.5e-1", "12345e-4", "0.0001e4", "1e+2", "0e1000", "1e-1000",
+            ],
+            10,
+            2,
+        )
+        .unwrap();
+        let expected = [10000, 150000, -25, 123, 100, 10000, 0, 0].map(i256::from_i128);
+        assert_eq!(array.values(), &expected);
+
+        for s in ["1e9", "1e1000", "1e99999999999"] {
+            let err = Decimal256Array::try_from_decimal_strings([s], 10, 2).unwrap_err();
+            assert!(err.to_string().contains("is out of range"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_decimal_try_from_decimal_strings_precision_boundaries() {
+        let max = "9".repeat(38);
+        let array =
+            Decimal128Array::try_from_decimal_strings([max.as_str(), &format!("-{max}")], 38, 0)
+                .unwrap();
+        let expected = Decimal128Type::MAX_FOR_EACH_PRECISION[38];
+        assert_eq!(array.values(), &[expected, -expected]);
+        for s in [
+            format!("1{max}"),
+            format!("-1{max}"),
+            format!("{max}.5"),
+            "1e38".into(),
+        ] {
+            Decimal128Array::try_from_decimal_strings([s], 38, 0).unwrap_err();
+        }
+
+        let max = format!("{}.{}", "9".repeat(66), "9".repeat(10));
+        let array =
+            Decimal256Array::try_from_decimal_strings([max.as_str(), &format!("-{max}")], 76, 10)
+                .unwrap();
+        let expected = Decimal256Type::MAX_FOR_EACH_PRECISION[76];
+        assert_eq!(array.values(), &[expected, expected.neg_wrapping()]);
+        assert_eq!(array.value_as_string(0), max);
+        for s in [format!("1{max}"), format!("-{max}5"), "1e66".into()] {
+            Decimal256Array::try_from_decimal_strings([s], 76, 10).unwrap_err();
+        }
+
+        let array = Decimal256Array::try_from_decimal_strings(["-9.999999e75"], 76, 0).unwrap();
+        assert_eq!(
+            array.value_as_string(0),
+            format!("-9999999{}", "0".repeat(69))
+        );
+    }
+
     #[test]
     fn test_decimal256_iter() {
         let mut builder = Decimal256Builder::with_capacity(30);
@@ -151,6 +151,20 @@ impl UnionArray {
         type_ids: ScalarBuffer<i8>,
         offsets: Option<ScalarBuffer<i32>>,
         children: Vec<ArrayRef>,
+    ) -> Self {
+        if cfg!(feature = "force_validate") {
+            return Self::try_new(fields, type_ids, offsets, children).unwrap();
+        }
+        Self::from_parts_unchecked(fields, type_ids, offsets, children)
+    }
+
+    /// Creates a new `UnionArray` from its parts without validating them, see
+    /// [`Self::new_unchecked`]
+    fn from_parts_unchecked(
+        fields: UnionFields,
+        type_ids: ScalarBuffer<i8>,
+        offsets: Option<ScalarBuffer<i32>>,
+        children: Vec<ArrayRef>,
     ) -> Self {
         let mode = if offsets.is_some() {
             UnionMode::Dense
@@ -158,22 +172,37 @@ impl UnionArray {
             UnionMode::Sparse
         };
 
-        let len = type_ids.len();
-        let builder = ArrayData::builder(DataType::Union(fields, mode))
-            .add_buffer(type_ids.into_inner())
-            .child_data(children.into_iter().map(Array::into_data).collect())
-            .len(len);
+        // Index the children by type id, without round-tripping through ArrayData
+        let max_id = fields.iter().map(|(i, _)| i).max().unwrap_or_default() as usize;
+        let mut boxed_fields = vec![None; max_id + 1];
+        for (child, (field_id, _)) in children.into_iter().zip(fields.iter()) {
+            boxed_fields[field_id as usize] = Some(child);
+        }
 
-        let data = match offsets {
-            Some(offsets) => unsafe { builder.add_buffer(offsets.into_inner()).build_unchecked() },
-            None => unsafe { builder.build_unchecked() },
-        };
-        Self::from(data)
+        Self {
+            data_type: DataType::Union(fields, mode),
+            type_ids,
+            offsets,
+            fields: boxed_fields,
+        }
     }
 
     /// Attempts to create a new `UnionArray`, validating the inputs provided.
     ///
     /// The order of child arrays child array order must match the fields order
+    ///
+    /// This is the inverse of [`Self::into_parts`], and does not copy the child arrays
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `fields.len() != children.len()`
+    /// * the data type of a child array does not match its field
+    /// * `offsets.len() != type_ids.len()` for dense unions
+    /// * the length of a child array does not match `type_ids.len()` for sparse unions
+    /// * a type id does not match one of the type ids of `fields`
+    /// * an offset is negative or out of bounds of the corresponding child array
     pub fn try_new(
         fields: UnionFields,
         type_ids: ScalarBuffer<i8>,
@@ -187,6 +216,18 @@ impl UnionArray {
             ));
         }
 
+        // The child arrays must have the data types of their fields.
+        for (child, (_, field)) in children.iter().zip(fields.iter()) {
+            if child.data_type() != field.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Union child array for field {} has data type {}, expected {}",
+                    field.name(),
+                    child.data_type(),
+                    field.data_type()
+                )));
+            }
+        }
+
         if let Some(offsets) = &offsets {
             // There must be an offset value for every type id value.
             if offsets.len() != type_ids.len() {
@@ -235,9 +276,8 @@ impl UnionArray {
             }
         }
 
-        // Safety:
-        // - Arguments validated above.
-        let union_array = unsafe { Self::new_unchecked(fields, type_ids, offsets, children) };
+        // Arguments validated above.
+        let union_array = Self::from_parts_unchecked(fields, type_ids, offsets, children);
         Ok(union_array)
     }
 
@@ -1895,6 +1935,104 @@ mod tests {
             err.to_string(),
             "Invalid argument error: Union fields length must match child arrays length"
         );
+
+        let children = vec![
+            Arc::new(StringArray::from_iter_values(["a", "b"])) as _,
+            Arc::new(Int32Array::from(vec![1])) as _,
+        ];
+        let type_ids = ScalarBuffer::from(vec![3_i8, 3, 2]);
+        let offsets = Some(vec![0, 1, 0].into());
+        let err = UnionArray::try_new(fields.clone(), type_ids, offsets, children).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Union child array for field b has data type Int32, expected Utf8"
+        );
+
+        let children = vec![
+            Arc::new(StringArray::from_iter_values(["a", "b"])) as _,
+            Arc::new(StringArray::from_iter_values(["c"])) as _,
+        ];
+        let type_ids = ScalarBuffer::from(vec![3_i8, -1, 2]);
+        let offsets = Some(vec![0, 1, 0].into());
+        let err = UnionArray::try_new(fields.clone(), type_ids.clone(), offsets, children.clone())
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Type Ids values must match one of the field type ids"
+        );
+
+        let type_ids = ScalarBuffer::from(vec![3_i8, 3, 2]);
+        let offsets = Some(vec![0, -1, 0].into());
+        let err = UnionArray::try_new(fields, type_ids, offsets, children).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Offsets must be non-negative and within the length of the Array"
+        );
+    }
+
+    #[test]
+    fn test_into_parts_round_trip() {
+        let fields = UnionFields::new(
+            [3, 1],
+            [
+                Field::new("a", DataType::Utf8, true),
+                Field::new("b", DataType::Int32, true),
+            ],
+        );
+        let children: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(["a", "b"])),
+            Arc::new(Int32Array::from(vec![Some(1), None])),
+        ];
+        let type_ids = ScalarBuffer::from(vec![3_i8, 1, 1, 3]);
+        let offsets = ScalarBuffer::from(vec![0, 1, 0, 1]);
+
+        for offsets in [Some(offsets), None] {
+            let children = match offsets {
+                Some(_) => children.clone(),
+                None => vec![
+                    Arc::new(StringArray::from_iter_values(["a", "b", "c", "d"])) as _,
+                    Arc::new(Int32Array::from(vec![None, Some(1), Some(2), None])) as _,
+                ],
+            };
+            let array =
+                UnionArray::try_new(fields.clone(), type_ids.clone(), offsets, children.clone())
+                    .unwrap();
+
+            // The children are returned in the order of the fields, sharing their data
+            let (parts_fields, parts_type_ids, parts_offsets, parts_children) =
+                array.clone().into_parts();
+            assert_eq!(parts_fields, fields);
+            assert_eq!(parts_type_ids, type_ids);
+            assert_eq!(parts_children.len(), 2);
+            for (child, expected) in parts_children.iter().zip(&children) {
+                assert!(Arc::ptr_eq(child, expected));
+            }
+
+            let rebuilt = UnionArray::try_new(
+                parts_fields.clone(),
+                parts_type_ids.clone(),
+                parts_offsets.clone(),
+                parts_children.clone(),
+            )
+            .unwrap();
+            assert_eq!(rebuilt.to_data(), array.to_data());
+            assert!(Arc::ptr_eq(rebuilt.child(3), &children[0]));
+
+            // Safety: the parts were taken from a valid UnionArray
+            let rebuilt = unsafe {
+                UnionArray::new_unchecked(
+                    parts_fields,
+                    parts_type_ids,
+                    parts_offsets,
+                    parts_children,
+                )
+            };
+            assert_eq!(rebuilt.to_data(), array.to_data());
+            assert!(Arc::ptr_eq(rebuilt.child(1), &children[1]));
+        }
     }
 
     #[test]
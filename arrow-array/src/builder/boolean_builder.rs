@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::{ArrayBuilder, BooleanBufferBuilder};
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder, BooleanBufferBuilder};
 use crate::{Array, ArrayRef, BooleanArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::NullBufferBuilder;
@@ -146,6 +146,29 @@ impl BooleanBuilder {
         }
     }
 
+    /// Appends values from a trusted length iterator.
+    ///
+    /// # Safety
+    /// This requires the iterator be a trusted length. This could instead require
+    /// the iterator implement `TrustedLen` once that is stabilized.
+    #[inline]
+    pub unsafe fn append_trusted_len_iter(&mut self, iter: impl IntoIterator<Item = bool>) {
+        let iter = iter.into_iter();
+        let len = iter
+            .size_hint()
+            .1
+            .expect("append_trusted_len_iter requires an upper bound");
+
+        let offset = self.values_builder.len();
+        self.values_builder.advance(len);
+        for (idx, v) in iter.take(len).enumerate() {
+            if v {
+                self.values_builder.set_bit(offset + idx, true);
+            }
+        }
+        self.null_buffer_builder.append_n_non_nulls(len);
+    }
+
     /// Appends array values and null to this builder as is
     /// (this means that underlying null values are copied as is).
     #[inline]
@@ -199,6 +222,21 @@ impl BooleanBuilder {
     }
 }
 
+impl AppendArray for BooleanBuilder {
+    type Array = BooleanArray;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        Self::append_array(self, array);
+        Ok(())
+    }
+}
+
+impl AppendNulls for BooleanBuilder {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl ArrayBuilder for BooleanBuilder {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &dyn Any {
@@ -408,4 +446,22 @@ mod tests {
         assert_eq!(actual, array);
         assert_eq!(actual.values(), array.values())
     }
+
+    #[test]
+    fn test_append_slice_trusted_len_iter_nulls() {
+        let mut builder = BooleanBuilder::new();
+        builder.append_value(true);
+        builder.append_null();
+        builder.append_slice(&[false, true]);
+        // Cross a byte boundary with the trusted length iterator
+        unsafe { builder.append_trusted_len_iter((0..10).map(|v| v % 3 == 0)) };
+        builder.append_nulls(2);
+        unsafe { builder.append_trusted_len_iter(std::iter::empty()) };
+
+        let actual = builder.finish();
+        let mut expected = vec![Some(true), None, Some(false), Some(true)];
+        expected.extend((0..10).map(|v| Some(v % 3 == 0)));
+        expected.extend([None, None]);
+        assert_eq!(actual, BooleanArray::from(expected));
+    }
 }
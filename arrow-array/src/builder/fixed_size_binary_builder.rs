@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendNulls, ArrayBuilder};
 use crate::{ArrayRef, FixedSizeBinaryArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::NullBufferBuilder;
@@ -135,6 +135,12 @@ impl FixedSizeBinaryBuilder {
     }
 }
 
+impl AppendNulls for FixedSizeBinaryBuilder {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl ArrayBuilder for FixedSizeBinaryBuilder {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &dyn Any {
@@ -15,10 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
-use crate::{ArrayRef, FixedSizeListArray};
-use arrow_buffer::NullBufferBuilder;
-use arrow_schema::{Field, FieldRef};
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder};
+use crate::{Array, ArrayRef, FixedSizeListArray};
+use arrow_buffer::{NullBuffer, NullBufferBuilder};
+use arrow_schema::{ArrowError, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -107,6 +107,20 @@ impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
     }
 }
 
+impl<T: AppendArray> AppendArray for FixedSizeListBuilder<T> {
+    type Array = FixedSizeListArray;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        Self::append_array(self, array)
+    }
+}
+
+impl<T: AppendNulls> AppendNulls for FixedSizeListBuilder<T> {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl<T: ArrayBuilder> ArrayBuilder for FixedSizeListBuilder<T>
 where
     T: 'static,
@@ -165,50 +179,152 @@ where
         self.null_buffer_builder.append(is_valid);
     }
 
+    /// Appends a null slot, padding the values builder with `value_length` nulls
+    ///
+    /// Unlike [`Self::append`], this does not require the values of the slot to be
+    /// appended first. The padding nulls are masked by the null slot, and so are
+    /// permitted even if the field of the values is not nullable.
+    ///
+    /// See [`AppendNulls`] for an example use.
+    #[inline]
+    pub fn append_null(&mut self)
+    where
+        T: AppendNulls,
+    {
+        self.append_nulls(1)
+    }
+
+    /// Appends `n` null slots, padding the values builder with `n * value_length` nulls
+    ///
+    /// See [`Self::append_null`]
+    #[inline]
+    pub fn append_nulls(&mut self, n: usize)
+    where
+        T: AppendNulls,
+    {
+        self.values_builder.append_nulls(n * self.list_len as usize);
+        self.null_buffer_builder.append_n_nulls(n);
+    }
+
+    /// Appends the lists and nulls of `array` to this builder
+    ///
+    /// Only the values referenced by `array` are appended, so `array` may be a slice
+    /// of a larger array.
+    ///
+    /// Returns an error, without modifying the builder, if the value length of
+    /// `array` differs from that of this builder or if its values are not of the
+    /// array type appended by `T`.
+    ///
+    /// ```
+    /// # use arrow_array::builder::{FixedSizeListBuilder, Int32Builder};
+    /// # use arrow_array::{Array, FixedSizeListArray, Int32Array};
+    /// # use arrow_array::types::Int32Type;
+    /// let array = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+    ///     [Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3), None])],
+    ///     2,
+    /// );
+    ///
+    /// let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+    /// builder.append_array(&array.slice(1, 2)).unwrap();
+    /// builder.append_array(&array).unwrap();
+    ///
+    /// let result = builder.finish();
+    /// assert_eq!(result.len(), 5);
+    /// assert_eq!(result.null_count(), 2);
+    /// assert_eq!(result.value(1).as_ref(), &Int32Array::from(vec![Some(3), None]));
+    /// ```
+    pub fn append_array(&mut self, array: &FixedSizeListArray) -> Result<(), ArrowError>
+    where
+        T: AppendArray,
+    {
+        if array.value_length() != self.list_len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot append fixed size list array with value length {} to a builder with value length {}",
+                array.value_length(),
+                self.list_len
+            )));
+        }
+
+        // The values of a sliced FixedSizeListArray are sliced accordingly
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<T::Array>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot append fixed size list array with values of type {} to this fixed size list builder",
+                values.data_type()
+            ))
+        })?;
+        self.values_builder.append_array(values)?;
+
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+        Ok(())
+    }
+
     /// Builds the [`FixedSizeListBuilder`] and reset this builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_finish`] would return an error
     pub fn finish(&mut self) -> FixedSizeListArray {
+        self.try_finish().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds the [`FixedSizeListBuilder`] without resetting the builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_finish_cloned`] would return an error
+    pub fn finish_cloned(&self) -> FixedSizeListArray {
+        self.try_finish_cloned().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds the [`FixedSizeListBuilder`] and reset this builder.
+    ///
+    /// Returns an error if the values builder does not contain exactly
+    /// `value_length` values per slot, or if the values do not match the field
+    /// set by [`Self::with_field`]
+    pub fn try_finish(&mut self) -> Result<FixedSizeListArray, ArrowError> {
         let len = self.len();
         let values = self.values_builder.finish();
         let nulls = self.null_buffer_builder.finish();
-
-        assert_eq!(
-            values.len(),
-            len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values.len(),
-            self.list_len,
-            len,
-        );
-
-        let field = self
-            .field
-            .clone()
-            .unwrap_or_else(|| Arc::new(Field::new_list_field(values.data_type().clone(), true)));
-
-        FixedSizeListArray::new(field, self.list_len, values, nulls)
+        self.build(len, values, nulls)
     }
 
     /// Builds the [`FixedSizeListBuilder`] without resetting the builder.
-    pub fn finish_cloned(&self) -> FixedSizeListArray {
+    ///
+    /// Returns an error if the values builder does not contain exactly
+    /// `value_length` values per slot, or if the values do not match the field
+    /// set by [`Self::with_field`]
+    pub fn try_finish_cloned(&self) -> Result<FixedSizeListArray, ArrowError> {
         let len = self.len();
         let values = self.values_builder.finish_cloned();
         let nulls = self.null_buffer_builder.finish_cloned();
+        self.build(len, values, nulls)
+    }
 
-        assert_eq!(
-            values.len(),
-            len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values.len(),
-            self.list_len,
-            len,
-        );
+    fn build(
+        &self,
+        len: usize,
+        values: ArrayRef,
+        nulls: Option<NullBuffer>,
+    ) -> Result<FixedSizeListArray, ArrowError> {
+        if values.len() != len * self.list_len as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
+                values.len(),
+                self.list_len,
+                len,
+            )));
+        }
 
         let field = self
             .field
             .clone()
             .unwrap_or_else(|| Arc::new(Field::new_list_field(values.data_type().clone(), true)));
 
-        FixedSizeListArray::new(field, self.list_len, values, nulls)
+        FixedSizeListArray::try_new(field, self.list_len, values, nulls)
     }
 
     /// Returns the current null buffer as a slice
@@ -225,6 +341,7 @@ mod tests {
     use crate::Array;
     use crate::Int32Array;
     use crate::builder::Int32Builder;
+    use crate::cast::AsArray;
 
     fn make_list_builder(
         include_null_element: bool,
@@ -491,4 +608,155 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_try_finish() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 3);
+        builder.values().append_slice(&[1, 2, 3, 4]);
+        builder.append(true);
+
+        let err = builder.try_finish_cloned().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Length of the child array (4) must be the multiple of the value length (3) and the array length (1)."
+        );
+        assert!(builder.try_finish().is_err());
+
+        // The builder is reset by try_finish
+        builder.append_null();
+        let array = builder.try_finish().unwrap();
+        assert_eq!(array.len(), 1);
+        assert!(array.is_null(0));
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_append_null() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2).with_field(Field::new(
+            "item",
+            DataType::Int32,
+            false,
+        ));
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        builder.append_null();
+        builder.append_nulls(2);
+        builder.values().append_slice(&[3, 4]);
+        builder.append(true);
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.null_count(), 3);
+        assert_eq!(array.values().len(), 10);
+        assert_eq!(array.values().null_count(), 6);
+        assert_eq!(array.value(4).as_ref(), &Int32Array::from(vec![3, 4]));
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_append_array() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+
+        let mut other = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        other.values().append_slice(&[3, 4]);
+        other.append(true);
+        other.append_null();
+        other.values().append_value(5);
+        other.values().append_null();
+        other.append(true);
+        let other = other.finish();
+
+        builder.append_array(&other.slice(1, 2)).unwrap();
+        builder.append_array(&other).unwrap();
+
+        // Arrays without nulls are appended as valid slots
+        builder.append_array(&other.slice(0, 1)).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 7);
+        let nulls: Vec<_> = (0..array.len()).map(|i| array.is_valid(i)).collect();
+        assert_eq!(nulls, [true, false, true, true, false, true, true]);
+        assert_eq!(
+            array.values().as_ref(),
+            &Int32Array::from(vec![
+                Some(1),
+                Some(2),
+                None,
+                None,
+                Some(5),
+                None,
+                Some(3),
+                Some(4),
+                None,
+                None,
+                Some(5),
+                None,
+                Some(3),
+                Some(4),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_append_array_mismatch() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 3);
+
+        let mut other = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        other.values().append_slice(&[1, 2]);
+        other.append(true);
+        let err = builder.append_array(&other.finish()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append fixed size list array with value length 2 to a builder with value length 3"
+        );
+
+        let mut other = FixedSizeListBuilder::new(crate::builder::Int64Builder::new(), 3);
+        other.values().append_slice(&[1, 2, 3]);
+        other.append(true);
+        let err = builder.append_array(&other.finish()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append fixed size list array with values of type Int64 to this fixed size list builder"
+        );
+
+        assert!(builder.is_empty());
+        assert!(builder.values().is_empty());
+    }
+
+    #[test]
+    fn test_nested_fixed_size_list_array_builder() {
+        let inner = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        let mut builder = FixedSizeListBuilder::new(inner, 2);
+
+        // [[[1, 2], null], null]
+        let inner = builder.values();
+        inner.values().append_slice(&[1, 2]);
+        inner.append(true);
+        inner.append_null();
+        builder.append(true);
+        builder.append_null();
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 2);
+        assert!(array.is_null(1));
+        let inner = array.values().as_fixed_size_list();
+        assert_eq!(inner.len(), 4);
+        let nulls: Vec<_> = (0..inner.len()).map(|i| inner.is_valid(i)).collect();
+        assert_eq!(nulls, [true, false, false, false]);
+        assert_eq!(inner.values().len(), 8);
+        assert_eq!(inner.values().null_count(), 6);
+
+        // Append the nested array, including a slice of it, to a new builder
+        let inner = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        let mut builder = FixedSizeListBuilder::new(inner, 2);
+        builder.append_array(&array).unwrap();
+        builder.append_array(&array.slice(0, 1)).unwrap();
+        builder.append_null();
+
+        let appended = builder.finish();
+        assert_eq!(appended.len(), 4);
+        assert_eq!(appended.slice(0, 2), array);
+        assert_eq!(appended.slice(2, 1), array.slice(0, 1));
+        assert!(appended.is_null(3));
+    }
 }
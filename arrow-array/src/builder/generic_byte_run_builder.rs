@@ -23,9 +23,11 @@ use crate::{
     types::{BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, RunEndIndexType, Utf8Type},
 };
 
+use super::primitive_run_builder::checked_run_end_index;
 use super::{ArrayBuilder, GenericByteBuilder, PrimitiveBuilder};
 
 use arrow_buffer::ArrowNativeType;
+use arrow_schema::ArrowError;
 
 /// Builder for [`RunArray`] of [`GenericByteArray`](crate::array::GenericByteArray)
 ///
@@ -161,36 +163,110 @@ where
     V: ByteArrayType,
 {
     /// Appends optional value to the logical array encoded by the RunArray.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_option(&mut self, input_value: Option<impl AsRef<V::Native>>) {
-        match input_value {
-            Some(value) => self.append_value(value),
-            None => self.append_null(),
-        }
+        self.append_option_n(input_value, 1)
     }
 
     /// Appends value to the logical array encoded by the RunArray.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_value(&mut self, input_value: impl AsRef<V::Native>) {
-        let value: &[u8] = input_value.as_ref().as_ref();
-        if !self.has_current_value {
-            self.append_run_end();
-            self.current_value.extend_from_slice(value);
-            self.has_current_value = true;
-        } else if self.current_value.as_slice() != value {
-            self.append_run_end();
-            self.current_value.clear();
-            self.current_value.extend_from_slice(value);
-        }
-        self.current_run_end_index += 1;
+        self.append_option_n(Some(input_value), 1)
     }
 
     /// Appends null to the logical array encoded by the RunArray.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_null(&mut self) {
-        if self.has_current_value {
-            self.append_run_end();
-            self.current_value.clear();
-            self.has_current_value = false;
+        self.append_nulls(1)
+    }
+
+    /// Appends `n` copies of `value` to the logical array encoded by the RunArray.
+    ///
+    /// The copies are merged into the current run if it has the same value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`,
+    /// see [`Self::try_append_option_n`]
+    pub fn append_value_n(&mut self, input_value: impl AsRef<V::Native>, n: usize) {
+        self.append_option_n(Some(input_value), n)
+    }
+
+    /// Appends `n` nulls to the logical array encoded by the RunArray.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`,
+    /// see [`Self::try_append_option_n`]
+    pub fn append_nulls(&mut self, n: usize) {
+        self.append_option_n(None::<&V::Native>, n)
+    }
+
+    fn append_option_n(&mut self, input_value: Option<impl AsRef<V::Native>>, n: usize) {
+        self.try_append_option_n(input_value, n)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Appends `n` copies of an optional value to the logical array encoded by the
+    /// RunArray, merging them into the current run if it has the same value.
+    ///
+    /// Returns an error, without modifying the builder, if the length of the logical
+    /// array would exceed the maximum value of the run end type `R`.
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringRunBuilder;
+    /// # use arrow_array::types::Int16Type;
+    /// let mut builder = StringRunBuilder::<Int16Type>::new();
+    /// builder.try_append_option_n(Some("a"), 32000).unwrap();
+    /// builder.try_append_option_n(None::<&str>, 767).unwrap();
+    ///
+    /// // The length of the array cannot exceed i16::MAX
+    /// assert!(builder.try_append_option_n(Some("a"), 1).is_err());
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.run_ends().values(), &[32000, 32767]);
+    /// ```
+    pub fn try_append_option_n(
+        &mut self,
+        input_value: Option<impl AsRef<V::Native>>,
+        n: usize,
+    ) -> Result<(), ArrowError> {
+        if n == 0 {
+            return Ok(());
+        }
+        let run_end_index = checked_run_end_index::<R>(self.current_run_end_index, n)?;
+        match input_value {
+            Some(input_value) => {
+                let value: &[u8] = input_value.as_ref().as_ref();
+                if !self.has_current_value {
+                    self.append_run_end();
+                    self.current_value.extend_from_slice(value);
+                    self.has_current_value = true;
+                } else if self.current_value.as_slice() != value {
+                    self.append_run_end();
+                    self.current_value.clear();
+                    self.current_value.extend_from_slice(value);
+                }
+            }
+            None => {
+                if self.has_current_value {
+                    self.append_run_end();
+                    self.current_value.clear();
+                    self.has_current_value = false;
+                }
+            }
         }
-        self.current_run_end_index += 1;
+        self.current_run_end_index = run_end_index;
+        Ok(())
     }
 
     /// Creates the RunArray and resets the builder.
@@ -377,6 +453,7 @@ mod tests {
 
     use crate::GenericByteArray;
     use crate::Int16RunArray;
+    use crate::StringArray;
     use crate::array::Array;
     use crate::cast::AsArray;
     use crate::types::{Int16Type, Int32Type};
@@ -514,4 +591,89 @@ mod tests {
         assert_eq!(str_array.value(2), "b");
         assert_eq!(str_array.value(3), "cupcakes");
     }
+
+    #[test]
+    fn test_empty() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        builder.append_value_n("a", 0);
+        builder.append_nulls(0);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 0);
+        assert!(array.run_ends().values().is_empty());
+        assert!(array.values().is_empty());
+    }
+
+    #[test]
+    fn test_append_value_n() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        builder.append_value("a");
+        builder.append_value_n("a", 3);
+        builder.append_nulls(2);
+        builder.append_null();
+        builder.append_value_n("", 2);
+        builder.append_value_n("b", 1);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.logical_null_count(), 3);
+        assert_eq!(array.run_ends().values(), &[4, 7, 9, 10]);
+
+        let str_array = array.values().as_string::<i32>();
+        assert_eq!(
+            str_array,
+            &StringArray::from(vec![Some("a"), None, Some(""), Some("b")])
+        );
+    }
+
+    #[test]
+    fn test_single_run() {
+        let mut builder = StringRunBuilder::<Int32Type>::new();
+        builder.append_value_n("abc", 600);
+        builder.append_value_n("abc", 400);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 1000);
+        assert_eq!(array.run_ends().values(), &[1000]);
+        assert_eq!(
+            array.values().as_string::<i32>(),
+            &StringArray::from(vec!["abc"])
+        );
+    }
+
+    #[test]
+    fn test_alternating_values() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        for i in 0..10 {
+            builder.append_option((i % 2 == 0).then_some("a"));
+        }
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.run_ends().values(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(array.values().null_count(), 5);
+    }
+
+    #[test]
+    fn test_run_end_overflow() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        builder.append_value_n("a", i16::MAX as usize - 1);
+
+        let err = builder.try_append_option_n(Some("b"), 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append 2 values to a run array of length 32766, as its length would exceed the maximum run end of Int16"
+        );
+
+        // The failed append leaves the builder unchanged
+        assert_eq!(builder.len(), i16::MAX as usize - 1);
+        builder.try_append_option_n(Some("b"), 1).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.run_ends().values(), &[32766, 32767]);
+        assert_eq!(
+            array.values().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b"])
+        );
+    }
 }
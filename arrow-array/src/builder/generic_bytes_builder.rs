@@ -15,13 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder, CompareValues};
 use crate::types::{ByteArrayType, GenericBinaryType, GenericStringType};
 use crate::{Array, ArrayRef, GenericByteArray, OffsetSizeTrait};
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer, NullBufferBuilder, ScalarBuffer};
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::ArrowError;
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// Builder for [`GenericByteArray`]
@@ -140,6 +141,55 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
             .extend(std::iter::repeat_n(next_offset, n));
     }
 
+    /// Appends a slice of non-null values into the builder
+    ///
+    /// The offsets and value bytes are reserved up front.
+    ///
+    /// See [`Self::append_value`] for more panic information.
+    #[inline]
+    pub fn append_slice(&mut self, values: &[impl AsRef<T::Native>]) {
+        let bytes = values
+            .iter()
+            .map(|v| {
+                let v: &[u8] = v.as_ref().as_ref();
+                v.len()
+            })
+            .sum::<usize>();
+        self.value_builder.reserve(bytes);
+        self.offsets_builder.reserve(values.len());
+        self.null_buffer_builder.append_n_non_nulls(values.len());
+        for v in values {
+            self.value_builder.extend_from_slice(v.as_ref().as_ref());
+            self.offsets_builder.push(self.next_offset());
+        }
+    }
+
+    /// Appends non-null values from a trusted length iterator.
+    ///
+    /// The offsets are reserved up front, see [`Self::append_value`] for more panic information.
+    ///
+    /// # Safety
+    /// This requires the iterator be a trusted length. This could instead require
+    /// the iterator implement `TrustedLen` once that is stabilized.
+    #[inline]
+    pub unsafe fn append_trusted_len_iter<V: AsRef<T::Native>>(
+        &mut self,
+        iter: impl IntoIterator<Item = V>,
+    ) {
+        let iter = iter.into_iter();
+        let len = iter
+            .size_hint()
+            .1
+            .expect("append_trusted_len_iter requires an upper bound");
+
+        self.null_buffer_builder.append_n_non_nulls(len);
+        self.offsets_builder.reserve(len);
+        for v in iter {
+            self.value_builder.extend_from_slice(v.as_ref().as_ref());
+            self.offsets_builder.push(self.next_offset());
+        }
+    }
+
     /// Appends array values and null to this builder as is
     /// (this means that underlying null values are copied as is).
     #[inline]
@@ -249,6 +299,29 @@ impl<T: ByteArrayType> Default for GenericByteBuilder<T> {
     }
 }
 
+impl<T: ByteArrayType> AppendArray for GenericByteBuilder<T> {
+    type Array = GenericByteArray<T>;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        Self::append_array(self, array)
+    }
+}
+
+impl<T: ByteArrayType> CompareValues for GenericByteBuilder<T> {
+    fn compare_values(&self, left: usize, right: usize) -> Ordering {
+        let offsets = self.offsets_slice();
+        let values = self.values_slice();
+        let value = |i: usize| &values[offsets[i].as_usize()..offsets[i + 1].as_usize()];
+        value(left).cmp(value(right))
+    }
+}
+
+impl<T: ByteArrayType> AppendNulls for GenericByteBuilder<T> {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl<T: ByteArrayType> ArrayBuilder for GenericByteBuilder<T> {
     /// Returns the number of binary slots in the builder
     fn len(&self) -> usize {
@@ -851,4 +924,40 @@ mod tests {
 
         assert!(matches!(result, Err(ArrowError::OffsetOverflowError(_))));
     }
+
+    fn _test_generic_string_builder_append_slice<O: OffsetSizeTrait>() {
+        let mut builder = GenericStringBuilder::<O>::new();
+        builder.append_value("hello");
+        builder.append_null();
+        builder.append_slice(&["a", "", "bc"]);
+        let owned = ["def".to_string(), "ghij".to_string()];
+        unsafe { builder.append_trusted_len_iter(owned.iter()) };
+        builder.append_nulls(2);
+        builder.append_slice(&[] as &[&str]);
+        unsafe { builder.append_trusted_len_iter(std::iter::empty::<&str>()) };
+
+        let actual = builder.finish();
+        let expected = GenericStringArray::<O>::from(vec![
+            Some("hello"),
+            None,
+            Some("a"),
+            Some(""),
+            Some("bc"),
+            Some("def"),
+            Some("ghij"),
+            None,
+            None,
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_string_builder_append_slice() {
+        _test_generic_string_builder_append_slice::<i32>()
+    }
+
+    #[test]
+    fn test_large_string_builder_append_slice() {
+        _test_generic_string_builder_append_slice::<i64>()
+    }
 }
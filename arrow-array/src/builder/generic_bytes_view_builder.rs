@@ -16,6 +16,7 @@
 // under the License.
 
 use std::any::Any;
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -25,7 +26,7 @@ use arrow_schema::ArrowError;
 use hashbrown::HashTable;
 use hashbrown::hash_table::Entry;
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder, CompareValues};
 use crate::types::bytes::ByteArrayNativeType;
 use crate::types::{BinaryViewType, ByteViewType, StringViewType};
 use crate::{Array, ArrayRef, GenericByteViewArray};
@@ -138,6 +139,10 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
     ///
     /// This will potentially decrease the memory usage if the array have repeated strings
     /// It will also increase the time to build the array as it needs to hash the strings
+    ///
+    /// Repeated values longer than 12 bytes reuse the view of their first
+    /// occurrence, which continues to reference the block the value was
+    /// originally written to, even once that block is no longer in progress
     pub fn with_deduplicate_strings(self) -> Self {
         Self {
             string_tracker: Some((
@@ -411,6 +416,48 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
         self.views_buffer.push(0);
     }
 
+    /// Appends `n` `null`s into the builder.
+    #[inline]
+    pub fn append_nulls(&mut self, n: usize) {
+        self.null_buffer_builder.append_n_nulls(n);
+        self.views_buffer.extend(std::iter::repeat_n(0, n));
+    }
+
+    /// Appends a slice of non-null values into the builder
+    ///
+    /// The views are reserved up front, see [`Self::append_value`] for panic information.
+    #[inline]
+    pub fn append_slice(&mut self, values: &[impl AsRef<T::Native>]) {
+        self.views_buffer.reserve(values.len());
+        for v in values {
+            self.append_value(v);
+        }
+    }
+
+    /// Appends non-null values from a trusted length iterator.
+    ///
+    /// The views are reserved up front, see [`Self::append_value`] for panic information.
+    ///
+    /// # Safety
+    /// This requires the iterator be a trusted length. This could instead require
+    /// the iterator implement `TrustedLen` once that is stabilized.
+    #[inline]
+    pub unsafe fn append_trusted_len_iter<V: AsRef<T::Native>>(
+        &mut self,
+        iter: impl IntoIterator<Item = V>,
+    ) {
+        let iter = iter.into_iter();
+        let len = iter
+            .size_hint()
+            .1
+            .expect("append_trusted_len_iter requires an upper bound");
+
+        self.views_buffer.reserve(len);
+        for v in iter {
+            self.append_value(v);
+        }
+    }
+
     /// Builds the [`GenericByteViewArray`] and reset this builder
     pub fn finish(&mut self) -> GenericByteViewArray<T> {
         self.flush_in_progress();
@@ -475,6 +522,27 @@ impl<T: ByteViewType + ?Sized> std::fmt::Debug for GenericByteViewBuilder<T> {
     }
 }
 
+impl<T: ByteViewType + ?Sized> AppendArray for GenericByteViewBuilder<T> {
+    type Array = GenericByteViewArray<T>;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        Self::append_array(self, array);
+        Ok(())
+    }
+}
+
+impl<T: ByteViewType + ?Sized> CompareValues for GenericByteViewBuilder<T> {
+    fn compare_values(&self, left: usize, right: usize) -> Ordering {
+        self.get_value(left).cmp(self.get_value(right))
+    }
+}
+
+impl<T: ByteViewType + ?Sized> AppendNulls for GenericByteViewBuilder<T> {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl<T: ByteViewType + ?Sized> ArrayBuilder for GenericByteViewBuilder<T> {
     fn len(&self) -> usize {
         self.null_buffer_builder.len()
@@ -658,6 +726,45 @@ mod tests {
         let _array = builder.finish();
     }
 
+    #[test]
+    fn test_string_view_deduplicate_memory() {
+        let values: Vec<String> = (0..10)
+            .map(|i| format!("low cardinality string value {i}"))
+            .collect();
+        let distinct_len: usize = values.iter().map(|v| v.len()).sum();
+
+        let build = |num_rows: usize| {
+            let mut builder = StringViewBuilder::with_capacity(num_rows)
+                .with_deduplicate_strings()
+                .with_fixed_block_size(64);
+            for i in 0..num_rows {
+                builder.append_value(&values[i % values.len()]);
+            }
+            let array = builder.finish();
+            array.to_data().validate_full().unwrap();
+            array
+        };
+
+        let small = build(100);
+        let large = build(1_000_000);
+        assert_eq!(large.value(999_999), values[9]);
+
+        // The data buffers only contain each distinct value once, regardless
+        // of the number of rows
+        let data_size = |array: &crate::StringViewArray| {
+            array.data_buffers().iter().map(|b| b.len()).sum::<usize>()
+        };
+        assert_eq!(data_size(&small), distinct_len);
+        assert_eq!(data_size(&large), distinct_len);
+
+        // Beyond the views themselves, memory usage stays flat
+        let views_size = |array: &crate::StringViewArray| array.views().inner().capacity();
+        assert_eq!(
+            large.get_buffer_memory_size() - views_size(&large),
+            small.get_buffer_memory_size() - views_size(&small)
+        );
+    }
+
     #[test]
     fn test_string_view() {
         let b1 = Buffer::from(b"world\xFFbananas\xF0\x9F\x98\x81");
@@ -783,4 +890,30 @@ mod tests {
             MAX_BLOCK_SIZE as usize
         );
     }
+
+    #[test]
+    fn test_string_view_append_slice_trusted_len_iter_nulls() {
+        let long = "a value longer than twelve bytes";
+        let mut builder = StringViewBuilder::new();
+        builder.append_value("short");
+        builder.append_null();
+        builder.append_slice(&["x", long]);
+        unsafe { builder.append_trusted_len_iter([long, "y"]) };
+        builder.append_nulls(3);
+
+        let actual = builder.finish();
+        let expected = crate::StringViewArray::from(vec![
+            Some("short"),
+            None,
+            Some("x"),
+            Some(long),
+            Some(long),
+            Some("y"),
+            None,
+            None,
+            None,
+        ]);
+        assert_eq!(actual, expected);
+        assert_eq!(actual.null_count(), 4);
+    }
 }
@@ -15,11 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder};
 use crate::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{Buffer, OffsetBuffer};
-use arrow_schema::{Field, FieldRef};
+use arrow_schema::{ArrowError, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -132,6 +132,24 @@ impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize
     }
 }
 
+impl<OffsetSize: OffsetSizeTrait, T: AppendArray> AppendArray
+    for GenericListBuilder<OffsetSize, T>
+{
+    type Array = GenericListArray<OffsetSize>;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        Self::append_array(self, array)
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> AppendNulls
+    for GenericListBuilder<OffsetSize, T>
+{
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> ArrayBuilder
     for GenericListBuilder<OffsetSize, T>
 where
@@ -263,22 +281,149 @@ where
 
     /// Append a null to this [`GenericListBuilder`]
     ///
-    /// See [`Self::append_value`] for an example use.
+    /// A null list has no child values, and so no values should have been
+    /// appended to [`Self::values`] since the previous list was delimited. To
+    /// append a list containing a single null value, instead append a null to
+    /// [`Self::values`] and call [`Self::append`] with `true`. Call
+    /// [`Self::append`] with `false` to delimit a null list with masked child values.
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, ListBuilder};
+    /// # use arrow_array::Array;
+    /// let mut builder = ListBuilder::new(Int32Builder::new());
+    ///
+    /// builder.append_null(); // null list
+    /// builder.append_empty(); // empty list
+    /// builder.values().append_null();
+    /// builder.append(true); // list containing a null element
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.value_offsets(), &[0, 0, 0, 1]);
+    /// assert!(array.is_null(0));
+    /// assert!(array.is_valid(1) && array.value(1).is_empty());
+    /// assert!(array.is_valid(2) && array.value(2).is_null(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if values have been appended to [`Self::values`]
+    /// since the previous list was delimited
     #[inline]
     pub fn append_null(&mut self) {
+        self.debug_assert_no_pending_values("append_null");
         self.offsets_builder.push(self.next_offset());
         self.null_buffer_builder.append_null();
     }
 
+    /// Append an empty, non-null list to this [`GenericListBuilder`]
+    ///
+    /// See [`Self::append_null`] for an example use.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if values have been appended to [`Self::values`]
+    /// since the previous list was delimited
+    #[inline]
+    pub fn append_empty(&mut self) {
+        self.debug_assert_no_pending_values("append_empty");
+        self.offsets_builder.push(self.next_offset());
+        self.null_buffer_builder.append_non_null();
+    }
+
+    /// Checks that no child values have been appended since the last offset
+    #[inline]
+    fn debug_assert_no_pending_values(&self, method: &str) {
+        debug_assert_eq!(
+            self.offsets_builder.last().copied(),
+            Some(self.next_offset()),
+            "values appended to GenericListBuilder::values before {method}, call append(true) to complete the list"
+        );
+    }
+
     /// Appends `n` `null`s into the builder.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if values have been appended to [`Self::values`]
+    /// since the previous list was delimited, see [`Self::append_null`]
     #[inline]
     pub fn append_nulls(&mut self, n: usize) {
+        self.debug_assert_no_pending_values("append_nulls");
         let next_offset = self.next_offset();
         self.offsets_builder
             .extend(std::iter::repeat_n(next_offset, n));
         self.null_buffer_builder.append_n_nulls(n);
     }
 
+    /// Appends the lists and nulls of `array` to this builder
+    ///
+    /// Only the child values referenced by `array` are appended to [`Self::values`],
+    /// and the offsets are shifted accordingly, so `array` may be sliced.
+    ///
+    /// Returns an error if the child values of `array` are not of the array type
+    /// appended by `T`, or if appending them would overflow `OffsetSize`. The
+    /// builder is left unchanged in the latter case.
+    ///
+    /// See [`AppendArray`] for an example use.
+    pub fn append_array(&mut self, array: &GenericListArray<OffsetSize>) -> Result<(), ArrowError>
+    where
+        T: AppendArray,
+    {
+        if array.is_empty() {
+            return Ok(());
+        }
+
+        let offsets = array.offsets();
+        let start = offsets[0];
+        let end = offsets[array.len()];
+        let values = array
+            .values()
+            .slice(start.as_usize(), (end - start).as_usize());
+        let values = values.as_any().downcast_ref::<T::Array>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot append list array with values of type {} to this list builder",
+                values.data_type()
+            ))
+        })?;
+
+        let next_offset = self.next_offset();
+        let shift = next_offset - start;
+        let last = next_offset.as_usize() + (end - start).as_usize();
+        if OffsetSize::from_usize(last).is_none() {
+            return Err(ArrowError::OffsetOverflowError(last));
+        }
+
+        self.values_builder.append_array(values)?;
+        self.offsets_builder
+            .extend(offsets[1..].iter().map(|&offset| offset + shift));
+
+        if let Some(null_buffer) = array.nulls() {
+            self.null_buffer_builder.append_buffer(null_buffer);
+        } else {
+            self.null_buffer_builder.append_n_non_nulls(array.len());
+        }
+        Ok(())
+    }
+
+    /// Appends `len` lists of `array` starting at `offset` to this builder
+    ///
+    /// Equivalent to [`Self::append_array`] with `array.slice(offset, len)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + len` exceeds the length of `array`
+    pub fn append_array_slice(
+        &mut self,
+        array: &GenericListArray<OffsetSize>,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), ArrowError>
+    where
+        T: AppendArray,
+    {
+        self.append_array(&array.slice(offset, len))
+    }
+
     /// Appends an optional value into this [`GenericListBuilder`]
     ///
     /// If `Some` calls [`Self::append_value`] otherwise calls [`Self::append_null`]
@@ -818,4 +963,198 @@ mod tests {
         builder.append_value([Some(1)]);
         builder.finish();
     }
+
+    fn _test_generic_list_builder_append_array<O: OffsetSizeTrait>() {
+        let mut input = GenericListBuilder::<O, _>::new(Int32Builder::new());
+        input.append_value([Some(1), Some(2)]);
+        input.append_null();
+        input.append_value([Some(3), None]);
+        input.append_value([]);
+        input.append_value([Some(4)]);
+        let input = input.finish();
+
+        let mut builder = GenericListBuilder::<O, _>::new(Int32Builder::new());
+        builder.append_value([Some(0)]);
+        builder.append_array(&input).unwrap();
+        builder.append_array(&input.slice(2, 3)).unwrap();
+        builder.append_array(&input.slice(0, 0)).unwrap();
+        let array = builder.finish();
+
+        let mut expected = GenericListBuilder::<O, _>::new(Int32Builder::new());
+        expected.append_value([Some(0)]);
+        for list in input.iter().chain(input.slice(2, 3).iter()) {
+            expected.append_option(
+                list.map(|l| l.as_primitive::<Int32Type>().iter().collect::<Vec<_>>()),
+            );
+        }
+        assert_eq!(array, expected.finish());
+        assert_eq!(
+            array.value_offsets(),
+            [0, 1, 3, 3, 5, 5, 6, 8, 8, 9].map(O::usize_as)
+        );
+        assert_eq!(array.null_count(), 1);
+    }
+
+    #[test]
+    fn test_list_builder_append_array() {
+        _test_generic_list_builder_append_array::<i32>()
+    }
+
+    #[test]
+    fn test_large_list_builder_append_array() {
+        _test_generic_list_builder_append_array::<i64>()
+    }
+
+    #[test]
+    fn test_list_builder_append_array_nested() {
+        let mut input = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+        input.values().append_value([Some(1)]);
+        input.values().append_null();
+        input.append(true);
+        input.values().append_value([Some(2), Some(3)]);
+        input.append(true);
+        input.append_null();
+        let input = input.finish();
+
+        let mut builder = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+        builder.append_array(&input.slice(1, 2)).unwrap();
+        builder.append_array(&input).unwrap();
+        let array = builder.finish();
+
+        assert_eq!(array.value_offsets(), &[0, 1, 1, 3, 4, 4]);
+        assert_eq!(array.nulls().unwrap().null_count(), 2);
+        let values = array.values().as_list::<i32>();
+        assert_eq!(values.value_offsets(), &[0, 2, 3, 3, 5]);
+        assert_eq!(
+            values.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![2, 3, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_list_builder_append_array_type_mismatch() {
+        let mut input = ListBuilder::new(Int32Builder::new());
+        input.append_value([Some(1)]);
+        let input = input.finish();
+
+        let mut builder = ListBuilder::new(crate::builder::StringBuilder::new());
+        let err = builder.append_array(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append list array with values of type Int32 to this list builder"
+        );
+        assert!(builder.is_empty());
+        assert!(builder.values().is_empty());
+    }
+
+    #[test]
+    fn test_list_builder_null_empty_and_null_element() {
+        use crate::builder::{StringBuilder, StructBuilder};
+        use arrow_schema::Fields;
+
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let mut builder = ListBuilder::new(StructBuilder::from_fields(fields, 0));
+
+        // [{a: 1, b: "x"}]
+        let values = builder.values();
+        values
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1);
+        values
+            .field_builder::<StringBuilder>(1)
+            .unwrap()
+            .append_value("x");
+        values.append(true);
+        builder.append(true);
+        // null
+        builder.append_null();
+        // []
+        builder.append_empty();
+        // [null]
+        let values = builder.values();
+        values
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_null();
+        values
+            .field_builder::<StringBuilder>(1)
+            .unwrap()
+            .append_null();
+        values.append_null();
+        builder.append(true);
+        builder.append_nulls(2);
+        builder.append_empty();
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 7);
+        assert_eq!(array.value_offsets(), &[0, 1, 1, 1, 2, 2, 2, 2]);
+        let valid: Vec<_> = (0..array.len()).map(|i| array.is_valid(i)).collect();
+        assert_eq!(valid, [true, false, true, true, false, false, true]);
+        let values = array.values().as_struct();
+        assert_eq!(values.len(), 2);
+        assert!(values.is_valid(0));
+        assert!(values.is_null(1));
+
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), None]);
+        builder.append_empty();
+        builder.append_null();
+        builder.append_value([None::<&str>]);
+        let array = builder.finish();
+        assert_eq!(array.value_offsets(), &[0, 2, 2, 2, 3]);
+        assert_eq!(array.null_count(), 1);
+        assert!(array.is_null(2));
+        assert_eq!(
+            array.values().as_string::<i32>().iter().collect::<Vec<_>>(),
+            [Some("a"), None, None]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "values appended to GenericListBuilder::values before append_null")]
+    fn test_list_builder_append_null_with_pending_values() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.values().append_value(1);
+        builder.append_null();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "values appended to GenericListBuilder::values before append_empty")]
+    fn test_list_builder_append_empty_with_pending_values() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.append_empty();
+        builder.values().append_null();
+        builder.append_empty();
+    }
+
+    #[test]
+    fn test_list_builder_append_array_slice() {
+        let mut input = ListBuilder::new(Int32Builder::new());
+        input.append_value([Some(1), Some(2)]);
+        input.append_null();
+        input.append_value([Some(3), None]);
+        input.append_empty();
+        let input = input.finish();
+
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.append_array_slice(&input, 1, 2).unwrap();
+        builder.append_array_slice(&input, 3, 1).unwrap();
+        builder.append_array_slice(&input, 0, 0).unwrap();
+        builder.append_array_slice(&input, 0, 1).unwrap();
+        let array = builder.finish();
+
+        assert_eq!(array.value_offsets(), &[0, 0, 2, 2, 4]);
+        let valid: Vec<_> = (0..array.len()).map(|i| array.is_valid(i)).collect();
+        assert_eq!(valid, [false, true, true, true]);
+        assert_eq!(
+            array.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(3), None, Some(1), Some(2)])
+        );
+    }
 }
@@ -15,13 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{ArrayBuilder, CompareValues};
 use crate::{Array, ArrayRef, MapArray, StructArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::{NullBuffer, NullBufferBuilder};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// Builder for [`MapArray`]
@@ -63,6 +64,9 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     value_builder: V,
     key_field: Option<FieldRef>,
     value_field: Option<FieldRef>,
+    keys_sorted: bool,
+    unique_keys: bool,
+    compare_keys: Option<fn(&K, usize, usize) -> Ordering>,
 }
 
 /// The [`Field`] names for a [`MapArray`]
@@ -110,6 +114,9 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             value_builder,
             key_field: None,
             value_field: None,
+            keys_sorted: false,
+            unique_keys: false,
+            compare_keys: None,
         }
     }
 
@@ -139,6 +146,60 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         }
     }
 
+    /// Validate that the keys of each map are strictly increasing, and mark the
+    /// [`DataType::Map`] of the built array as having sorted keys
+    ///
+    /// By default, keys are not validated and the map is not marked as sorted
+    ///
+    /// When enabled, [`Self::append`] returns an error if the keys of a valid map are
+    /// not strictly increasing. Keys are compared with [`CompareValues::compare_values`].
+    /// See [`Self::append`] for how to recover from such an error.
+    ///
+    /// ```
+    /// # use arrow_array::Array;
+    /// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    /// # use arrow_schema::DataType;
+    /// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+    ///     .with_keys_sorted(true);
+    ///
+    /// builder.keys().append_value("a");
+    /// builder.values().append_value(1);
+    /// builder.keys().append_value("b");
+    /// builder.values().append_value(2);
+    /// builder.append(true).unwrap();
+    ///
+    /// let array = builder.finish();
+    /// assert!(matches!(array.data_type(), DataType::Map(_, true)));
+    /// ```
+    pub fn with_keys_sorted(self, keys_sorted: bool) -> Self
+    where
+        K: CompareValues,
+    {
+        Self {
+            keys_sorted,
+            compare_keys: Some(K::compare_values),
+            ..self
+        }
+    }
+
+    /// Validate that the keys of each map are unique
+    ///
+    /// By default, keys are not validated
+    ///
+    /// When enabled, [`Self::append`] returns an error if a valid map contains duplicate
+    /// keys. Unlike [`Self::with_keys_sorted`] the keys may be in any order. See
+    /// [`Self::append`] for how to recover from such an error.
+    pub fn with_unique_keys(self, unique_keys: bool) -> Self
+    where
+        K: CompareValues,
+    {
+        Self {
+            unique_keys,
+            compare_keys: Some(K::compare_values),
+            ..self
+        }
+    }
+
     /// Returns the key array builder of the map
     pub fn keys(&mut self) -> &mut K {
         &mut self.key_builder
@@ -156,7 +217,14 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
 
     /// Finish the current map array slot
     ///
-    /// Returns an error if the key and values builders are in an inconsistent state.
+    /// Returns an error if the key and values builders are in an inconsistent state,
+    /// or if `is_valid` and the keys fail the validation enabled by
+    /// [`Self::with_keys_sorted`] or [`Self::with_unique_keys`].
+    ///
+    /// On error no map is appended, but the rejected keys and values remain in the
+    /// key and value builders, and would become part of the next map appended. As
+    /// null maps are not validated, calling `append(false)` records them as a null
+    /// map, otherwise the builder should be discarded.
     #[inline]
     pub fn append(&mut self, is_valid: bool) -> Result<(), ArrowError> {
         if self.key_builder.len() != self.value_builder.len() {
@@ -166,11 +234,52 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
                 self.value_builder.len()
             )));
         }
+        if is_valid {
+            self.validate_keys()?;
+        }
         self.offsets_builder.push(self.key_builder.len() as i32);
         self.null_buffer_builder.append(is_valid);
         Ok(())
     }
 
+    /// Validates the keys appended since the last call to [`Self::append`]
+    fn validate_keys(&self) -> Result<(), ArrowError> {
+        let Some(compare) = self.compare_keys else {
+            return Ok(());
+        };
+        let start = *self.offsets_builder.last().unwrap() as usize;
+        let end = self.key_builder.len();
+        let keys = &self.key_builder;
+
+        if self.keys_sorted {
+            // Strictly increasing keys are also unique
+            if let Some(i) = (start + 1..end).find(|&i| compare(keys, i - 1, i) != Ordering::Less) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Keys of map {} are not sorted: key {} is not less than key {}",
+                    self.len(),
+                    i - start - 1,
+                    i - start
+                )));
+            }
+        } else if self.unique_keys {
+            let mut indices: Vec<usize> = (start..end).collect();
+            indices.sort_unstable_by(|a, b| compare(keys, *a, *b));
+            if let Some(w) = indices
+                .windows(2)
+                .find(|w| compare(keys, w[0], w[1]) == Ordering::Equal)
+            {
+                let (first, second) = (w[0].min(w[1]), w[0].max(w[1]));
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Keys of map {} are not unique: key {} is equal to key {}",
+                    self.len(),
+                    first - start,
+                    second - start
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Builds the [`MapArray`]
     pub fn finish(&mut self) -> MapArray {
         let len = self.len();
@@ -237,7 +346,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             struct_array.data_type().clone(),
             false, // always non-nullable
         ));
-        let array_data = ArrayData::builder(DataType::Map(map_field, false)) // TODO: support sorted keys
+        let array_data = ArrayData::builder(DataType::Map(map_field, self.keys_sorted))
             .len(len)
             .add_buffer(offset_buffer)
             .add_child_data(struct_array.into_data())
@@ -284,7 +393,9 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builder::{Int32Builder, StringBuilder, make_builder};
+    use crate::builder::{
+        Int32Builder, ListBuilder, StringBuilder, StringViewBuilder, make_builder,
+    };
     use crate::{Int32Array, StringArray};
     use std::collections::HashMap;
 
@@ -461,4 +572,142 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_keys_sorted() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new()).with_keys_sorted(true);
+
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+
+        builder.keys().append_value("b");
+        builder.values().append_value(3);
+        builder.keys().append_value("a");
+        builder.values().append_value(4);
+        let err = builder.append(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Keys of map 1 are not sorted: key 0 is not less than key 1"
+        );
+
+        // The rejected keys remain in the builder, and null maps are not validated
+        builder.append(false).unwrap();
+
+        builder.keys().append_value("c");
+        builder.values().append_value(5);
+        builder.keys().append_value("c");
+        builder.values().append_value(6);
+        let err = builder.append(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Keys of map 2 are not sorted: key 0 is not less than key 1"
+        );
+
+        builder.append(false).unwrap();
+        let array = builder.finish();
+        assert!(matches!(array.data_type(), DataType::Map(_, true)));
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 2);
+        assert_eq!(array.value_offsets(), &[0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_keys_unsorted_by_default() {
+        let mut builder = MapBuilder::new(None, Int32Builder::new(), Int32Builder::new());
+        builder.keys().append_slice(&[2, 1, 2]);
+        builder.values().append_slice(&[1, 2, 3]);
+        builder.append(true).unwrap();
+
+        let array = builder.finish();
+        assert!(matches!(array.data_type(), DataType::Map(_, false)));
+    }
+
+    #[test]
+    fn test_unique_keys() {
+        let mut builder =
+            MapBuilder::new(None, Int32Builder::new(), Int32Builder::new()).with_unique_keys(true);
+
+        // Unique keys need not be sorted
+        builder.keys().append_slice(&[3, 1, 2]);
+        builder.values().append_slice(&[1, 2, 3]);
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+
+        builder.keys().append_slice(&[4, 1, 5, 1]);
+        builder.values().append_slice(&[4, 5, 6, 7]);
+        let err = builder.append(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Keys of map 2 are not unique: key 1 is equal to key 3"
+        );
+        builder.append(false).unwrap();
+
+        let array = builder.finish();
+        assert!(matches!(array.data_type(), DataType::Map(_, false)));
+        assert_eq!(array.value_offsets(), &[0, 3, 3, 7]);
+    }
+
+    #[test]
+    fn test_keys_sorted_string_view() {
+        let mut builder = MapBuilder::new(None, StringViewBuilder::new(), Int32Builder::new())
+            .with_keys_sorted(true);
+
+        builder.keys().append_value("a long string value");
+        builder.values().append_value(1);
+        builder.keys().append_value("a longer string value");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+
+        builder.keys().append_value("z");
+        builder.values().append_value(3);
+        builder.keys().append_value("a long string value");
+        builder.values().append_value(4);
+        let err = builder.append(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Keys of map 1 are not sorted: key 0 is not less than key 1"
+        );
+    }
+
+    #[test]
+    fn test_nested_sorted_map() {
+        let map_builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+            .with_keys_sorted(true)
+            .with_unique_keys(true);
+        let mut builder = ListBuilder::new(map_builder);
+
+        // [[{"a": 1, "b": 2}, {}], null]
+        let maps = builder.values();
+        maps.keys().append_value("a");
+        maps.values().append_value(1);
+        maps.keys().append_value("b");
+        maps.values().append_value(2);
+        maps.append(true).unwrap();
+        maps.append(true).unwrap();
+        builder.append(true);
+        builder.append(false);
+
+        let maps = builder.values();
+        maps.keys().append_value("b");
+        maps.values().append_value(3);
+        maps.keys().append_value("a");
+        maps.values().append_value(4);
+        let err = maps.append(true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Keys of map 2 are not sorted: key 0 is not less than key 1"
+        );
+
+        let list = builder.finish();
+        let DataType::List(field) = list.data_type() else {
+            unreachable!()
+        };
+        assert!(matches!(field.data_type(), DataType::Map(_, true)));
+        assert_eq!(list.value_offsets(), &[0, 2, 2]);
+        assert_eq!(list.values().len(), 2);
+    }
 }
@@ -273,10 +273,10 @@ mod union_builder;
 
 pub use union_builder::*;
 
-use crate::ArrayRef;
-use crate::types::{Int8Type, Int16Type, Int32Type, Int64Type};
-use arrow_schema::{DataType, IntervalUnit, TimeUnit};
+use crate::{Array, ArrayRef, downcast_integer, downcast_primitive};
+use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit, UnionMode};
 use std::any::Any;
+use std::cmp::Ordering;
 
 /// Trait for dealing with different array builders at runtime
 ///
@@ -389,6 +389,95 @@ impl ArrayBuilder for Box<dyn ArrayBuilder> {
     }
 }
 
+/// An [`ArrayBuilder`] that can append the contents of an existing array in bulk
+///
+/// This is implemented by builders that provide an `append_array` method, and allows
+/// [`GenericListBuilder::append_array`] to append the child values of a list array to
+/// its values builder, including for nested lists.
+///
+/// ```
+/// # use arrow_array::builder::{AppendArray, Int32Builder, ListBuilder};
+/// # use arrow_array::{Int32Array, ListArray};
+/// # use arrow_array::types::Int32Type;
+/// let array = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![Some(3)]),
+/// ]);
+///
+/// let mut builder = ListBuilder::new(Int32Builder::new());
+/// builder.append_array(&array.slice(1, 2)).unwrap();
+/// builder.append_array(&array).unwrap();
+///
+/// let result = builder.finish();
+/// assert_eq!(result.value_offsets(), &[0, 0, 1, 3, 3, 4]);
+/// assert_eq!(result.values().as_ref(), &Int32Array::from(vec![3, 1, 2, 3]));
+/// ```
+pub trait AppendArray: ArrayBuilder {
+    /// The type of array that can be appended to this builder
+    type Array: Array;
+
+    /// Appends the values and nulls of `array` to this builder
+    ///
+    /// Returns an error if `array` cannot be appended, for example if its data type
+    /// does not match that of the builder or if appending it would overflow the offsets
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError>;
+}
+
+/// An [`ArrayBuilder`] that can append null slots
+///
+/// This is implemented by builders that provide an `append_nulls` method, and allows
+/// [`FixedSizeListBuilder::append_null`] to pad its values builder with nulls,
+/// including for nested fixed size lists.
+///
+/// ```
+/// # use arrow_array::builder::{FixedSizeListBuilder, Int32Builder};
+/// # use arrow_array::{Array, Int32Array};
+/// let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+/// builder.values().append_slice(&[1, 2]);
+/// builder.append(true);
+/// builder.append_null();
+///
+/// let array = builder.finish();
+/// assert!(array.is_null(1));
+/// assert_eq!(
+///     array.values().as_ref(),
+///     &Int32Array::from(vec![Some(1), Some(2), None, None])
+/// );
+/// ```
+pub trait AppendNulls: ArrayBuilder {
+    /// Appends `n` nulls to this builder
+    fn append_nulls(&mut self, n: usize);
+}
+
+/// An [`ArrayBuilder`] that can compare the values it has appended
+///
+/// This is implemented by the builders of primitive, string and binary values, and
+/// allows [`MapBuilder::with_keys_sorted`] and [`MapBuilder::with_unique_keys`] to
+/// validate the keys of each map as it is appended.
+///
+/// ```
+/// # use std::cmp::Ordering;
+/// # use arrow_array::builder::{CompareValues, StringBuilder};
+/// let mut builder = StringBuilder::new();
+/// builder.append_value("b");
+/// builder.append_value("a");
+/// builder.append_value("b");
+///
+/// assert_eq!(builder.compare_values(0, 1), Ordering::Greater);
+/// assert_eq!(builder.compare_values(0, 2), Ordering::Equal);
+/// ```
+pub trait CompareValues: ArrayBuilder {
+    /// Compares the values at indexes `left` and `right` of this builder
+    ///
+    /// Null slots are compared using their underlying, unspecified, values
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds
+    fn compare_values(&self, left: usize, right: usize) -> Ordering;
+}
+
 /// Builder for [`ListArray`](crate::array::ListArray)
 pub type ListBuilder<T> = GenericListBuilder<i32, T>;
 
@@ -429,9 +518,49 @@ pub type LargeStringBuilder = GenericStringBuilder<i64>;
 ///
 /// See comments on [StructBuilder] for retrieving collection builders built by
 /// make_builder.
+///
+/// # Panics
+///
+/// Panics if `datatype` is not supported, see [`try_make_builder`]
 pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilder> {
+    try_make_builder(datatype, capacity).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Returns a builder with capacity for `capacity` elements of datatype
+/// `DataType`, or an error if `datatype` is not supported
+///
+/// The returned builder is of the type that would be used to build an array of
+/// `datatype` directly, for example a [`StringDictionaryBuilder<Int32Type>`]
+/// for `Dictionary(Int32, Utf8)`, with nested types using a `Box<dyn ArrayBuilder>`
+/// for each child. Dictionaries are supported with any integer key type, and
+/// string, binary, fixed size binary or primitive values.
+///
+/// ```
+/// # use arrow_array::builder::{try_make_builder, StringDictionaryBuilder};
+/// # use arrow_array::types::UInt16Type;
+/// # use arrow_schema::{DataType, Field};
+/// # use std::sync::Arc;
+/// let data_type = DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8));
+/// let mut builder = try_make_builder(&data_type, 10).unwrap();
+/// let dictionary = builder
+///     .as_any_mut()
+///     .downcast_mut::<StringDictionaryBuilder<UInt16Type>>()
+///     .unwrap();
+/// dictionary.append_value("a");
+/// assert_eq!(builder.finish().data_type(), &data_type);
+///
+/// let unsupported = DataType::RunEndEncoded(
+///     Arc::new(Field::new("run_ends", DataType::Int32, false)),
+///     Arc::new(Field::new("values", DataType::Utf8, true)),
+/// );
+/// assert!(try_make_builder(&unsupported, 10).is_err());
+/// ```
+pub fn try_make_builder(
+    datatype: &DataType,
+    capacity: usize,
+) -> Result<Box<dyn ArrayBuilder>, ArrowError> {
     use crate::builder::*;
-    match datatype {
+    Ok(match datatype {
         DataType::Null => Box::new(NullBuilder::new()),
         DataType::Boolean => Box::new(BooleanBuilder::with_capacity(capacity)),
         DataType::Int8 => Box::new(Int8Builder::with_capacity(capacity)),
@@ -518,11 +647,11 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
             Box::new(DurationNanosecondBuilder::with_capacity(capacity))
         }
         DataType::List(field) => {
-            let builder = make_builder(field.data_type(), capacity);
+            let builder = try_make_builder(field.data_type(), capacity)?;
             Box::new(ListBuilder::with_capacity(builder, capacity).with_field(field.clone()))
         }
         DataType::LargeList(field) => {
-            let builder = make_builder(field.data_type(), capacity);
+            let builder = try_make_builder(field.data_type(), capacity)?;
             Box::new(LargeListBuilder::with_capacity(builder, capacity).with_field(field.clone()))
         }
         DataType::FixedSizeList(field, size) => {
@@ -531,18 +660,18 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                 let size: usize = size.try_into().unwrap();
                 capacity * size
             };
-            let builder = make_builder(field.data_type(), values_builder_capacity);
+            let builder = try_make_builder(field.data_type(), values_builder_capacity)?;
             Box::new(
                 FixedSizeListBuilder::with_capacity(builder, size, capacity)
                     .with_field(field.clone()),
             )
         }
         DataType::ListView(field) => {
-            let builder = make_builder(field.data_type(), capacity);
+            let builder = try_make_builder(field.data_type(), capacity)?;
             Box::new(ListViewBuilder::with_capacity(builder, capacity).with_field(field.clone()))
         }
         DataType::LargeListView(field) => {
-            let builder = make_builder(field.data_type(), capacity);
+            let builder = try_make_builder(field.data_type(), capacity)?;
             Box::new(
                 LargeListViewBuilder::with_capacity(builder, capacity).with_field(field.clone()),
             )
@@ -554,8 +683,8 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                     value: fields[1].name().clone(),
                     entry: field.name().clone(),
                 };
-                let key_builder = make_builder(fields[0].data_type(), capacity);
-                let value_builder = make_builder(fields[1].data_type(), capacity);
+                let key_builder = try_make_builder(fields[0].data_type(), capacity)?;
+                let value_builder = try_make_builder(fields[1].data_type(), capacity)?;
                 Box::new(
                     MapBuilder::with_capacity(
                         Some(map_field_names),
@@ -567,47 +696,104 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                     .with_values_field(fields[1].clone()),
                 )
             }
-            t => panic!("The field of Map data type {t} should have a child Struct field"),
-        },
-        DataType::Struct(fields) => Box::new(StructBuilder::from_fields(fields.clone(), capacity)),
-        t @ DataType::Dictionary(key_type, value_type) => {
-            macro_rules! dict_builder {
-                ($key_type:ty) => {
-                    match &**value_type {
-                        DataType::Utf8 => {
-                            let dict_builder: StringDictionaryBuilder<$key_type> =
-                                StringDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                            Box::new(dict_builder)
-                        }
-                        DataType::LargeUtf8 => {
-                            let dict_builder: LargeStringDictionaryBuilder<$key_type> =
-                                LargeStringDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                            Box::new(dict_builder)
-                        }
-                        DataType::Binary => {
-                            let dict_builder: BinaryDictionaryBuilder<$key_type> =
-                                BinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                            Box::new(dict_builder)
-                        }
-                        DataType::LargeBinary => {
-                            let dict_builder: LargeBinaryDictionaryBuilder<$key_type> =
-                                LargeBinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                            Box::new(dict_builder)
-                        }
-                        t => panic!("Dictionary value type {t} is not currently supported"),
-                    }
-                };
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The field of Map data type {t} should have a child Struct field"
+                )));
             }
-            match &**key_type {
-                DataType::Int8 => dict_builder!(Int8Type),
-                DataType::Int16 => dict_builder!(Int16Type),
-                DataType::Int32 => dict_builder!(Int32Type),
-                DataType::Int64 => dict_builder!(Int64Type),
-                _ => {
-                    panic!("Data type {t} with key type {key_type} is not currently supported")
+        },
+        DataType::Struct(fields) => {
+            Box::new(StructBuilder::try_from_fields(fields.clone(), capacity)?)
+        }
+        DataType::Union(fields, mode) => {
+            let child_builders = fields
+                .iter()
+                .map(|(_, field)| try_make_builder(field.data_type(), capacity))
+                .collect::<Result<Vec<_>, _>>()?;
+            match mode {
+                UnionMode::Dense => {
+                    Box::new(DenseUnionBuilder::new(fields.clone(), child_builders))
+                }
+                UnionMode::Sparse => {
+                    Box::new(SparseUnionBuilder::new(fields.clone(), child_builders))
                 }
             }
         }
-        t => panic!("Data type {t} is not currently supported"),
+        DataType::Dictionary(key_type, value_type) => {
+            make_dictionary_builder(key_type, value_type, capacity)?
+        }
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Data type {t} is not currently supported"
+            )));
+        }
+    })
+}
+
+/// Returns a builder for a dictionary with `key_type` keys and `value_type` values
+fn make_dictionary_builder(
+    key_type: &DataType,
+    value_type: &DataType,
+    capacity: usize,
+) -> Result<Box<dyn ArrayBuilder>, ArrowError> {
+    use crate::builder::*;
+
+    macro_rules! primitive_dict_builder {
+        ($value_type:ty, $key_type:ty) => {{
+            let keys = PrimitiveBuilder::<$key_type>::with_capacity(capacity);
+            let values = PrimitiveBuilder::<$value_type>::with_capacity(256)
+                .with_data_type(value_type.clone());
+            let dict_builder = PrimitiveDictionaryBuilder::new_from_empty_builders(keys, values);
+            Box::new(dict_builder) as Box<dyn ArrayBuilder>
+        }};
+    }
+
+    macro_rules! dict_builder {
+        ($key_type:ty) => {
+            match value_type {
+                DataType::Utf8 => {
+                    let dict_builder: StringDictionaryBuilder<$key_type> =
+                        StringDictionaryBuilder::with_capacity(capacity, 256, 1024);
+                    Box::new(dict_builder) as Box<dyn ArrayBuilder>
+                }
+                DataType::LargeUtf8 => {
+                    let dict_builder: LargeStringDictionaryBuilder<$key_type> =
+                        LargeStringDictionaryBuilder::with_capacity(capacity, 256, 1024);
+                    Box::new(dict_builder)
+                }
+                DataType::Binary => {
+                    let dict_builder: BinaryDictionaryBuilder<$key_type> =
+                        BinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
+                    Box::new(dict_builder)
+                }
+                DataType::LargeBinary => {
+                    let dict_builder: LargeBinaryDictionaryBuilder<$key_type> =
+                        LargeBinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
+                    Box::new(dict_builder)
+                }
+                DataType::FixedSizeBinary(width) => {
+                    let dict_builder: FixedSizeBinaryDictionaryBuilder<$key_type> =
+                        FixedSizeBinaryDictionaryBuilder::with_capacity(capacity, 256, *width);
+                    Box::new(dict_builder)
+                }
+                v => downcast_primitive! {
+                    v => (primitive_dict_builder, $key_type),
+                    t => {
+                        return Err(ArrowError::NotYetImplemented(format!(
+                            "Dictionary value type {t} is not currently supported"
+                        )));
+                    }
+                },
+            }
+        };
     }
+
+    Ok(downcast_integer! {
+        key_type => (dict_builder),
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Dictionary key type {t} is not an integer type"
+            )));
+        }
+    })
 }
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendNulls, ArrayBuilder};
 use crate::{ArrayRef, NullArray};
 use arrow_data::ArrayData;
 use arrow_schema::DataType;
@@ -102,6 +102,12 @@ impl NullBuilder {
     }
 }
 
+impl AppendNulls for NullBuilder {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl ArrayBuilder for NullBuilder {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &dyn Any {
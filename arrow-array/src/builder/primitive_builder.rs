@@ -15,13 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::ArrayBuilder;
+use crate::builder::{AppendArray, AppendNulls, ArrayBuilder, CompareValues};
 use crate::types::*;
-use crate::{Array, ArrayRef, PrimitiveArray};
+use crate::{Array, ArrayRef, ArrowNativeTypeOp, PrimitiveArray};
 use arrow_buffer::{Buffer, MutableBuffer, NullBufferBuilder, ScalarBuffer};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// A signed 8-bit integer array builder.
@@ -103,6 +104,12 @@ pub struct PrimitiveBuilder<T: ArrowPrimitiveType> {
     data_type: DataType,
 }
 
+impl<T: ArrowPrimitiveType> AppendNulls for PrimitiveBuilder<T> {
+    fn append_nulls(&mut self, n: usize) {
+        Self::append_nulls(self, n)
+    }
+}
+
 impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &dyn Any {
@@ -141,6 +148,29 @@ impl<T: ArrowPrimitiveType> Default for PrimitiveBuilder<T> {
     }
 }
 
+impl<T: ArrowPrimitiveType> AppendArray for PrimitiveBuilder<T> {
+    type Array = PrimitiveArray<T>;
+
+    fn append_array(&mut self, array: &Self::Array) -> Result<(), ArrowError> {
+        if &self.data_type != array.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot append array of type {} to builder of type {}",
+                array.data_type(),
+                self.data_type
+            )));
+        }
+        Self::append_array(self, array);
+        Ok(())
+    }
+}
+
+impl<T: ArrowPrimitiveType> CompareValues for PrimitiveBuilder<T> {
+    fn compare_values(&self, left: usize, right: usize) -> Ordering {
+        let values = self.values_slice();
+        values[left].compare(values[right])
+    }
+}
+
 impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
     /// Creates a new primitive array builder
     pub fn new() -> Self {
@@ -296,6 +326,7 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
             .expect("append_trusted_len_iter requires an upper bound");
 
         self.null_buffer_builder.append_n_non_nulls(len);
+        self.values_builder.reserve(len);
         self.values_builder.extend(iter);
     }
 
@@ -702,4 +733,44 @@ mod tests {
         let mut builder = Decimal128Builder::new().with_data_type(DataType::Decimal128(2, 3));
         builder.append_array(&array)
     }
+
+    #[test]
+    fn test_append_array_trait_data_type_mismatch() {
+        use crate::builder::AppendArray;
+
+        let array =
+            crate::Decimal128Array::from(vec![1]).with_data_type(DataType::Decimal128(1, 2));
+        let mut builder = Decimal128Builder::new().with_data_type(DataType::Decimal128(2, 3));
+        let err = AppendArray::append_array(&mut builder, &array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append array of type Decimal128(1, 2) to builder of type Decimal128(2, 3)"
+        );
+        assert!(builder.is_empty());
+    }
+
+    #[test]
+    fn test_append_slice_trusted_len_iter_nulls() {
+        let mut builder = Int32Builder::new();
+        builder.append_value(1);
+        builder.append_null();
+        builder.append_slice(&[2, 3]);
+        unsafe { builder.append_trusted_len_iter((4..6).map(|v| v * 2)) };
+        builder.append_nulls(2);
+        unsafe { builder.append_trusted_len_iter(std::iter::empty()) };
+        builder.append_slice(&[]);
+
+        let actual = builder.finish();
+        let expected = Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(2),
+            Some(3),
+            Some(8),
+            Some(10),
+            None,
+            None,
+        ]);
+        assert_eq!(actual, expected);
+    }
 }
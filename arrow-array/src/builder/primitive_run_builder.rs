@@ -22,6 +22,7 @@ use crate::{ArrayRef, ArrowPrimitiveType, RunArray, types::RunEndIndexType};
 use super::{ArrayBuilder, PrimitiveBuilder};
 
 use arrow_buffer::ArrowNativeType;
+use arrow_schema::ArrowError;
 
 /// Builder for [`RunArray`] of [`PrimitiveArray`](crate::array::PrimitiveArray)
 ///
@@ -153,30 +154,97 @@ where
     V: ArrowPrimitiveType,
 {
     /// Appends optional value to the logical array encoded by the RunArray.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_option(&mut self, value: Option<V::Native>) {
-        if self.current_run_end_index == 0 {
-            self.current_run_end_index = 1;
-            self.current_value = value;
-            return;
-        }
-        if self.current_value != value {
-            self.append_run_end();
-            self.current_value = value;
-        }
-
-        self.current_run_end_index += 1;
+        self.append_option_n(value, 1)
     }
 
     /// Appends value to the logical array encoded by the run-ends array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_value(&mut self, value: V::Native) {
         self.append_option(Some(value))
     }
 
     /// Appends null to the logical array encoded by the run-ends array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`
     pub fn append_null(&mut self) {
         self.append_option(None)
     }
 
+    /// Appends `n` copies of `value` to the logical array encoded by the run-ends array.
+    ///
+    /// The copies are merged into the current run if it has the same value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`,
+    /// see [`Self::try_append_option_n`]
+    pub fn append_value_n(&mut self, value: V::Native, n: usize) {
+        self.append_option_n(Some(value), n)
+    }
+
+    /// Appends `n` nulls to the logical array encoded by the run-ends array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the logical array would exceed the maximum value of `R`,
+    /// see [`Self::try_append_option_n`]
+    pub fn append_nulls(&mut self, n: usize) {
+        self.append_option_n(None, n)
+    }
+
+    fn append_option_n(&mut self, value: Option<V::Native>, n: usize) {
+        self.try_append_option_n(value, n)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Appends `n` copies of an optional value to the logical array encoded by the
+    /// run-ends array, merging them into the current run if it has the same value.
+    ///
+    /// Returns an error, without modifying the builder, if the length of the logical
+    /// array would exceed the maximum value of the run end type `R`.
+    ///
+    /// ```
+    /// # use arrow_array::builder::PrimitiveRunBuilder;
+    /// # use arrow_array::types::{Int16Type, Int32Type};
+    /// let mut builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
+    /// builder.try_append_option_n(Some(1), 32000).unwrap();
+    /// builder.try_append_option_n(None, 767).unwrap();
+    ///
+    /// // The length of the array cannot exceed i16::MAX
+    /// assert!(builder.try_append_option_n(Some(1), 1).is_err());
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.run_ends().values(), &[32000, 32767]);
+    /// ```
+    pub fn try_append_option_n(
+        &mut self,
+        value: Option<V::Native>,
+        n: usize,
+    ) -> Result<(), ArrowError> {
+        if n == 0 {
+            return Ok(());
+        }
+        let run_end_index = checked_run_end_index::<R>(self.current_run_end_index, n)?;
+        if self.current_run_end_index == 0 {
+            self.current_value = value;
+        } else if self.current_value != value {
+            self.append_run_end();
+            self.current_value = value;
+        }
+        self.current_run_end_index = run_end_index;
+        Ok(())
+    }
+
     /// Creates the RunArray and resets the builder.
     /// Panics if RunArray cannot be built.
     pub fn finish(&mut self) -> RunArray<R> {
@@ -186,6 +254,7 @@ where
         // reset the run index to zero.
         self.current_value = None;
         self.current_run_end_index = 0;
+        self.prev_run_end_index = 0;
 
         // build the run encoded array by adding run_ends and values array as its children.
         let run_ends_array = self.run_ends_builder.finish();
@@ -245,6 +314,22 @@ where
     }
 }
 
+/// Returns the run end index after appending `n` values to a run array of length
+/// `len`, or an error if it cannot be represented by `R`
+pub(super) fn checked_run_end_index<R: RunEndIndexType>(
+    len: usize,
+    n: usize,
+) -> Result<usize, ArrowError> {
+    len.checked_add(n)
+        .filter(|end| R::Native::from_usize(*end).is_some())
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot append {n} values to a run array of length {len}, as its length would exceed the maximum run end of {}",
+                R::DATA_TYPE
+            ))
+        })
+}
+
 impl<R, V> Extend<Option<V::Native>> for PrimitiveRunBuilder<R, V>
 where
     R: RunEndIndexType,
@@ -259,9 +344,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::builder::PrimitiveRunBuilder;
+    use crate::builder::{ArrayBuilder, PrimitiveRunBuilder};
     use crate::cast::AsArray;
-    use crate::types::{Int16Type, UInt32Type};
+    use crate::types::{Int16Type, Int32Type, UInt32Type};
     use crate::{Array, UInt32Array};
 
     #[test]
@@ -310,4 +395,113 @@ mod tests {
             &[1, 2, 5, 4, 6, 2]
         );
     }
+
+    #[test]
+    fn test_empty() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        builder.append_value_n(1, 0);
+        builder.append_nulls(0);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 0);
+        assert!(array.run_ends().values().is_empty());
+        assert!(array.values().is_empty());
+    }
+
+    #[test]
+    fn test_append_value_n() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        builder.append_value(1);
+        builder.append_value_n(1, 3);
+        builder.append_nulls(2);
+        builder.append_null();
+        builder.append_value_n(2, 2);
+        builder.append_value_n(3, 1);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.logical_null_count(), 3);
+        assert_eq!(array.run_ends().values(), &[4, 7, 9, 10]);
+        assert_eq!(
+            array.values().as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![Some(1), None, Some(2), Some(3)])
+        );
+    }
+
+    #[test]
+    fn test_single_run() {
+        let mut builder = PrimitiveRunBuilder::<Int32Type, UInt32Type>::new();
+        builder.append_value_n(7, 600);
+        builder.append_value_n(7, 400);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 1000);
+        assert_eq!(array.run_ends().values(), &[1000]);
+        assert_eq!(array.values().as_primitive::<UInt32Type>().values(), &[7]);
+    }
+
+    #[test]
+    fn test_alternating_values() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        for i in 0..10 {
+            builder.append_value(i % 2);
+        }
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.run_ends().values(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(
+            array.values().as_primitive::<UInt32Type>().values(),
+            &[0, 1, 0, 1, 0, 1, 0, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_run_end_overflow() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        builder.append_value_n(1, i16::MAX as usize - 1);
+
+        let err = builder.try_append_option_n(Some(2), 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append 2 values to a run array of length 32766, as its length would exceed the maximum run end of Int16"
+        );
+        builder.try_append_option_n(None, usize::MAX).unwrap_err();
+
+        // The failed appends leave the builder unchanged
+        assert_eq!(builder.len(), i16::MAX as usize - 1);
+        builder.try_append_option_n(Some(2), 1).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.run_ends().values(), &[32766, 32767]);
+        assert_eq!(
+            array.values().as_primitive::<UInt32Type>().values(),
+            &[1, 2]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "as its length would exceed the maximum run end of Int16")]
+    fn test_run_end_overflow_panics() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        builder.append_nulls(i16::MAX as usize);
+        builder.append_null();
+    }
+
+    #[test]
+    fn test_reuse_after_finish() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+        builder.append_value_n(1, 2);
+        builder.append_value(2);
+        builder.finish();
+
+        builder.append_value_n(3, 3);
+        builder.append_value(4);
+        let array = builder.finish();
+        assert_eq!(array.run_ends().values(), &[3, 4]);
+        assert_eq!(
+            array.values().as_primitive::<UInt32Type>().values(),
+            &[3, 4]
+        );
+    }
 }
@@ -170,13 +170,24 @@ impl StructBuilder {
     }
 
     /// Creates a new `StructBuilder` from [`Fields`] and `capacity`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data type of any field is not supported by [`make_builder`],
+    /// see [`Self::try_from_fields`]
     pub fn from_fields(fields: impl Into<Fields>, capacity: usize) -> Self {
+        Self::try_from_fields(fields, capacity).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a new `StructBuilder` from [`Fields`] and `capacity`, returning
+    /// an error if the data type of any field is not supported by [`try_make_builder`]
+    pub fn try_from_fields(fields: impl Into<Fields>, capacity: usize) -> Result<Self, ArrowError> {
         let fields = fields.into();
-        let mut builders = Vec::with_capacity(fields.len());
-        for field in &fields {
-            builders.push(make_builder(field.data_type(), capacity));
-        }
-        Self::new(fields, builders)
+        let builders = fields
+            .iter()
+            .map(|field| try_make_builder(field.data_type(), capacity))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(fields, builders))
     }
 
     /// Returns a mutable reference to the child field builder at index `i`.
@@ -296,8 +307,10 @@ mod tests {
     use super::*;
     use arrow_buffer::Buffer;
     use arrow_data::ArrayData;
-    use arrow_schema::Field;
+    use arrow_schema::{Field, UnionFields, UnionMode};
 
+    use crate::cast::AsArray;
+    use crate::types::*;
     use crate::{array::Array, types::ArrowDictionaryKeyType};
 
     #[test]
@@ -614,15 +627,260 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Data type Dictionary(UInt64, Utf8) with key type UInt64 is not currently supported"
-    )]
+    fn test_struct_array_builder_from_dictionary_type_unsigned_key() {
+        test_struct_array_builder_from_dictionary_type_inner::<UInt8Type>(DataType::UInt8);
+        test_struct_array_builder_from_dictionary_type_inner::<UInt16Type>(DataType::UInt16);
+        test_struct_array_builder_from_dictionary_type_inner::<UInt32Type>(DataType::UInt32);
+        test_struct_array_builder_from_dictionary_type_inner::<UInt64Type>(DataType::UInt64);
+    }
+
+    #[test]
+    fn test_struct_array_builder_from_dictionary_non_string_values() {
+        let dict =
+            |key: DataType, value: DataType| DataType::Dictionary(Box::new(key), Box::new(value));
+        let fields = Fields::from(vec![
+            Field::new("i32", dict(DataType::Int32, DataType::Int32), true),
+            Field::new(
+                "dec",
+                dict(DataType::UInt8, DataType::Decimal128(10, 2)),
+                true,
+            ),
+            Field::new(
+                "fsb",
+                dict(DataType::Int16, DataType::FixedSizeBinary(3)),
+                true,
+            ),
+            Field::new("bin", dict(DataType::UInt32, DataType::LargeBinary), true),
+        ]);
+        let mut builder = StructBuilder::from_fields(fields.clone(), 2);
+
+        builder
+            .field_builder::<PrimitiveDictionaryBuilder<Int32Type, Int32Type>>(0)
+            .unwrap()
+            .append_value(42);
+        builder
+            .field_builder::<PrimitiveDictionaryBuilder<UInt8Type, Decimal128Type>>(1)
+            .unwrap()
+            .append_value(12345);
+        builder
+            .field_builder::<FixedSizeBinaryDictionaryBuilder<Int16Type>>(2)
+            .unwrap()
+            .append(b"abc")
+            .unwrap();
+        builder
+            .field_builder::<LargeBinaryDictionaryBuilder<UInt32Type>>(3)
+            .unwrap()
+            .append_null();
+        builder.append(true);
+
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &DataType::Struct(fields));
+        let decimals = array.column(1).as_dictionary::<UInt8Type>();
+        assert_eq!(
+            decimals
+                .values()
+                .as_primitive::<Decimal128Type>()
+                .value_as_string(0),
+            "123.45"
+        );
+    }
+
+    #[test]
+    fn test_struct_array_builder_from_view_map_union_fields() {
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("keys", DataType::Utf8, false),
+                Field::new("values", DataType::Int32, true),
+            ])),
+            false,
+        );
+        let union_fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8View, true),
+            ],
+        );
+        let fields = Fields::from(vec![
+            Field::new("sv", DataType::Utf8View, true),
+            Field::new("bv", DataType::BinaryView, true),
+            Field::new("map", DataType::Map(Arc::new(entries), false), true),
+            Field::new(
+                "union",
+                DataType::Union(union_fields, UnionMode::Dense),
+                false,
+            ),
+        ]);
+        let mut builder = StructBuilder::from_fields(fields.clone(), 2);
+
+        builder
+            .field_builder::<StringViewBuilder>(0)
+            .unwrap()
+            .append_value("a string longer than twelve bytes");
+        builder
+            .field_builder::<BinaryViewBuilder>(1)
+            .unwrap()
+            .append_null();
+        let map = builder
+            .field_builder::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>(2)
+            .unwrap();
+        map.keys()
+            .as_any_mut()
+            .downcast_mut::<StringBuilder>()
+            .unwrap()
+            .append_value("k");
+        map.values()
+            .as_any_mut()
+            .downcast_mut::<Int32Builder>()
+            .unwrap()
+            .append_value(1);
+        map.append(true).unwrap();
+        let union = builder.field_builder::<DenseUnionBuilder>(3).unwrap();
+        union.append_type_id(1).unwrap();
+        union
+            .child_builder::<StringViewBuilder>(1)
+            .unwrap()
+            .append_value("u");
+        builder.append(true);
+
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &DataType::Struct(fields));
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.column(2).as_map().value(0).len(), 1);
+        assert_eq!(array.column(3).as_union().type_ids(), &[1]);
+    }
+
+    #[test]
+    fn test_struct_array_builder_nested_schema_round_trip() {
+        let point = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, false),
+        ]);
+        let fields = Fields::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new(
+                "name",
+                DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new(
+                "path",
+                DataType::FixedSizeList(
+                    Arc::new(Field::new_list_field(DataType::Struct(point.clone()), true)),
+                    2,
+                ),
+                true,
+            ),
+            Field::new(
+                "tags",
+                DataType::List(Arc::new(Field::new_list_field(DataType::Utf8View, true))),
+                true,
+            ),
+        ]);
+        let mut builder = StructBuilder::try_from_fields(fields.clone(), 2).unwrap();
+
+        for row in 0..2_i64 {
+            builder
+                .field_builder::<Int64Builder>(0)
+                .unwrap()
+                .append_value(row);
+            builder
+                .field_builder::<StringDictionaryBuilder<UInt16Type>>(1)
+                .unwrap()
+                .append_value("same");
+
+            let path = builder
+                .field_builder::<FixedSizeListBuilder<Box<dyn ArrayBuilder>>>(2)
+                .unwrap();
+            let points = path
+                .values()
+                .as_any_mut()
+                .downcast_mut::<StructBuilder>()
+                .unwrap();
+            for v in [0.0, 1.0] {
+                points
+                    .field_builder::<Float64Builder>(0)
+                    .unwrap()
+                    .append_value(v);
+                points
+                    .field_builder::<Float64Builder>(1)
+                    .unwrap()
+                    .append_value(row as f64);
+                points.append(true);
+            }
+            path.append(true);
+
+            let tags = builder
+                .field_builder::<ListBuilder<Box<dyn ArrayBuilder>>>(3)
+                .unwrap();
+            tags.values()
+                .as_any_mut()
+                .downcast_mut::<StringViewBuilder>()
+                .unwrap()
+                .append_value(format!("tag{row}"));
+            tags.append(true);
+            builder.append(true);
+        }
+
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &DataType::Struct(fields));
+        assert_eq!(array.len(), 2);
+        let names = array.column(1).as_dictionary::<UInt16Type>();
+        assert_eq!(names.keys().values(), &[0, 0]);
+        let path = array.column(2).as_fixed_size_list();
+        let ys = path.value(1);
+        let ys = ys.as_struct().column(1).as_primitive::<Float64Type>();
+        assert_eq!(ys.values(), &[1.0, 1.0]);
+        let tags = array.column(3).as_list::<i32>();
+        assert_eq!(tags.value(1).as_string_view().value(0), "tag1");
+    }
+
+    #[test]
+    fn test_struct_array_builder_try_from_fields_unsupported() {
+        let run_end_encoded = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        let cases = [
+            (
+                run_end_encoded,
+                "Not yet implemented: Data type RunEndEncoded(\"run_ends\": Int32, \"values\": nullable Utf8) is not currently supported",
+            ),
+            (
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8View)),
+                "Not yet implemented: Dictionary value type Utf8View is not currently supported",
+            ),
+            (
+                DataType::Dictionary(Box::new(DataType::Utf8), Box::new(DataType::Utf8)),
+                "Invalid argument error: Dictionary key type Utf8 is not an integer type",
+            ),
+            (
+                DataType::List(Arc::new(Field::new_list_field(
+                    DataType::Time32(TimeUnit::Nanosecond),
+                    true,
+                ))),
+                "Not yet implemented: Data type Time32(ns) is not currently supported",
+            ),
+        ];
+        for (data_type, expected) in cases {
+            let fields = vec![
+                Field::new("f1", DataType::UInt64, false),
+                Field::new("f2", data_type, true),
+            ];
+            let err = StructBuilder::try_from_fields(fields, 5).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Dictionary value type Utf8View is not currently supported")]
     fn test_struct_array_builder_from_schema_unsupported_type() {
         let fields = vec![
             Field::new("f1", DataType::UInt64, false),
             Field::new(
                 "f2",
-                DataType::Dictionary(Box::new(DataType::UInt64), Box::new(DataType::Utf8)),
+                DataType::Dictionary(Box::new(DataType::UInt64), Box::new(DataType::Utf8View)),
                 false,
             ),
         ];
@@ -630,18 +888,6 @@ mod tests {
         let _ = StructBuilder::from_fields(fields, 5);
     }
 
-    #[test]
-    #[should_panic(expected = "Dictionary value type Int32 is not currently supported")]
-    fn test_struct_array_builder_from_dict_with_unsupported_value_type() {
-        let fields = vec![Field::new(
-            "f1",
-            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int32)),
-            false,
-        )];
-
-        let _ = StructBuilder::from_fields(fields, 5);
-    }
-
     #[test]
     fn test_struct_array_builder_field_builder_type_mismatch() {
         let int_builder = Int32Builder::with_capacity(10);
@@ -16,12 +16,13 @@
 // under the License.
 
 use crate::builder::buffer_builder::{Int8BufferBuilder, Int32BufferBuilder};
-use crate::builder::{ArrayBuilder, BufferBuilder};
-use crate::{ArrayRef, ArrowPrimitiveType, UnionArray, make_array};
+use crate::builder::{ArrayBuilder, BufferBuilder, make_builder};
+use crate::{Array, ArrayRef, ArrowPrimitiveType, UnionArray, make_array};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, DataType, Field};
+use arrow_data::transform::MutableArrayData;
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, Field, UnionFields};
 use std::any::Any;
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -399,12 +400,474 @@ impl ArrayBuilder for UnionBuilder {
     }
 }
 
+/// The child builders of a [`DenseUnionBuilder`] or [`SparseUnionBuilder`]
+struct UnionChildBuilders {
+    fields: UnionFields,
+    child_builders: Vec<Box<dyn ArrayBuilder>>,
+    /// The type id of each slot of the union
+    type_ids: Vec<i8>,
+}
+
+impl std::fmt::Debug for UnionChildBuilders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnionChildBuilders")
+            .field("fields", &self.fields)
+            .field("len", &self.type_ids.len())
+            .finish()
+    }
+}
+
+impl UnionChildBuilders {
+    fn new(fields: UnionFields, child_builders: Vec<Box<dyn ArrayBuilder>>) -> Self {
+        assert_eq!(
+            fields.len(),
+            child_builders.len(),
+            "Number of union fields must match number of child builders"
+        );
+        Self {
+            fields,
+            child_builders,
+            type_ids: Vec::new(),
+        }
+    }
+
+    fn from_fields(fields: UnionFields, capacity: usize) -> Self {
+        let child_builders = fields
+            .iter()
+            .map(|(_, field)| make_builder(field.data_type(), capacity))
+            .collect();
+        let mut builders = Self::new(fields, child_builders);
+        builders.type_ids.reserve(capacity);
+        builders
+    }
+
+    /// Returns the index of the child with `type_id`
+    fn child_index(&self, type_id: i8) -> Result<usize, ArrowError> {
+        self.fields
+            .iter()
+            .position(|(id, _)| id == type_id)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Type id {type_id} is not a field of this union"
+                ))
+            })
+    }
+
+    fn child_builder<T: ArrayBuilder>(&mut self, type_id: i8) -> Option<&mut T> {
+        let idx = self.child_index(type_id).ok()?;
+        self.child_builders[idx].as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Checks that every child contains exactly one value per slot that selects it
+    fn validate(&self, type_ids: &[i8], children: &[ArrayRef]) -> Result<(), ArrowError> {
+        for ((type_id, _), child) in self.fields.iter().zip(children) {
+            let expected = type_ids.iter().filter(|id| **id == type_id).count();
+            if child.len() != expected {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Child with type id {type_id} has {} values, expected {expected}",
+                    child.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for a dense [`UnionArray`] with arbitrary child builders
+///
+/// Unlike [`UnionBuilder`], which only supports primitive children, the children
+/// may be any [`ArrayBuilder`], including nested and variable-width builders.
+/// Each slot is appended by calling [`Self::append_type_id`] and then appending
+/// exactly one value to the corresponding [`Self::child_builder`].
+///
+/// ```
+/// # use arrow_array::Array;
+/// # use arrow_array::builder::{DenseUnionBuilder, Int32Builder, StringBuilder};
+/// # use arrow_schema::{DataType, Field, UnionFields};
+/// let fields = UnionFields::new(
+///     [0, 1],
+///     [
+///         Field::new("a", DataType::Int32, true),
+///         Field::new("b", DataType::Utf8, true),
+///     ],
+/// );
+/// let mut builder = DenseUnionBuilder::from_fields(fields, 3);
+///
+/// builder.append_type_id(0).unwrap();
+/// builder.child_builder::<Int32Builder>(0).unwrap().append_value(1);
+/// builder.append_type_id(1).unwrap();
+/// builder.child_builder::<StringBuilder>(1).unwrap().append_value("foo");
+/// builder.append_type_id(0).unwrap();
+/// builder.child_builder::<Int32Builder>(0).unwrap().append_null();
+///
+/// let union = builder.try_finish().unwrap();
+/// assert_eq!(union.type_ids(), &[0, 1, 0]);
+/// assert_eq!(union.offsets().unwrap(), &[0, 0, 1]);
+/// assert!(union.value(2).is_null(0));
+/// ```
+#[derive(Debug)]
+pub struct DenseUnionBuilder {
+    children: UnionChildBuilders,
+    offsets: Vec<i32>,
+}
+
+impl DenseUnionBuilder {
+    /// Creates a new [`DenseUnionBuilder`] with a child builder for each of `fields`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fields` and `child_builders` have different lengths
+    pub fn new(fields: UnionFields, child_builders: Vec<Box<dyn ArrayBuilder>>) -> Self {
+        Self {
+            children: UnionChildBuilders::new(fields, child_builders),
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`DenseUnionBuilder`] from [`UnionFields`] and `capacity`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data type of a field is not supported by [`make_builder`]
+    pub fn from_fields(fields: UnionFields, capacity: usize) -> Self {
+        Self {
+            children: UnionChildBuilders::from_fields(fields, capacity),
+            offsets: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the fields of the union this builder is building
+    pub fn fields(&self) -> &UnionFields {
+        &self.children.fields
+    }
+
+    /// Returns a mutable reference to the builder of the child with `type_id`
+    ///
+    /// Returns `None` if `type_id` is not a field of this union, or if `T`
+    /// doesn't match the type of the child builder
+    pub fn child_builder<T: ArrayBuilder>(&mut self, type_id: i8) -> Option<&mut T> {
+        self.children.child_builder(type_id)
+    }
+
+    /// Appends a slot of the child with `type_id`
+    ///
+    /// The value of the slot must then be appended to [`Self::child_builder`], a null
+    /// value is encoded by appending a null to the child.
+    ///
+    /// Returns an error if `type_id` is not a field of this union
+    pub fn append_type_id(&mut self, type_id: i8) -> Result<(), ArrowError> {
+        let idx = self.children.child_index(type_id)?;
+        let offset = self.children.child_builders[idx].len();
+        let offset = i32::try_from(offset).map_err(|_| ArrowError::OffsetOverflowError(offset))?;
+        self.children.type_ids.push(type_id);
+        self.offsets.push(offset);
+        Ok(())
+    }
+
+    /// Builds the [`UnionArray`] and resets this builder
+    ///
+    /// Returns an error if a child does not contain exactly one value per slot that selects it
+    pub fn try_finish(&mut self) -> Result<UnionArray, ArrowError> {
+        let children: Vec<_> = self
+            .children
+            .child_builders
+            .iter_mut()
+            .map(|b| b.finish())
+            .collect();
+        let type_ids = std::mem::take(&mut self.children.type_ids);
+        let offsets = std::mem::take(&mut self.offsets);
+        self.children.validate(&type_ids, &children)?;
+        UnionArray::try_new(
+            self.children.fields.clone(),
+            type_ids.into(),
+            Some(offsets.into()),
+            children,
+        )
+    }
+
+    /// Builds the [`UnionArray`] without resetting the builder
+    ///
+    /// Returns an error if a child does not contain exactly one value per slot that selects it
+    pub fn try_finish_cloned(&self) -> Result<UnionArray, ArrowError> {
+        let children: Vec<_> = self
+            .children
+            .child_builders
+            .iter()
+            .map(|b| b.finish_cloned())
+            .collect();
+        self.children.validate(&self.children.type_ids, &children)?;
+        UnionArray::try_new(
+            self.children.fields.clone(),
+            self.children.type_ids.clone().into(),
+            Some(self.offsets.clone().into()),
+            children,
+        )
+    }
+
+    /// Builds the [`UnionArray`] and resets this builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if a child does not contain exactly one value per slot that selects it
+    pub fn finish(&mut self) -> UnionArray {
+        self.try_finish().unwrap()
+    }
+
+    /// Builds the [`UnionArray`] without resetting the builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if a child does not contain exactly one value per slot that selects it
+    pub fn finish_cloned(&self) -> UnionArray {
+        self.try_finish_cloned().unwrap()
+    }
+}
+
+impl ArrayBuilder for DenseUnionBuilder {
+    fn len(&self) -> usize {
+        self.children.type_ids.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.finish_cloned())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Builder for a sparse [`UnionArray`] with arbitrary child builders
+///
+/// This has the same API as [`DenseUnionBuilder`]: each slot is appended by calling
+/// [`Self::append_type_id`] and then appending exactly one value to the corresponding
+/// [`Self::child_builder`]. The children are padded with nulls for the slots that
+/// select other children when the array is built, so they stay aligned.
+///
+/// The children of non-nullable fields are padded with nulls as well, as the
+/// slots that select other children are not values of that child. A child that
+/// is itself a union, which has no nulls, is padded with slots selecting the
+/// first child of that union, which are in turn padded with nulls.
+///
+/// ```
+/// # use arrow_array::Array;
+/// # use arrow_array::builder::{Int32Builder, SparseUnionBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_schema::{DataType, Field, UnionFields};
+/// let fields = UnionFields::new(
+///     [0, 1],
+///     [
+///         Field::new("a", DataType::Int32, true),
+///         Field::new("b", DataType::Utf8, true),
+///     ],
+/// );
+/// let mut builder = SparseUnionBuilder::from_fields(fields, 3);
+///
+/// builder.append_type_id(0).unwrap();
+/// builder.child_builder::<Int32Builder>(0).unwrap().append_value(1);
+/// builder.append_type_id(1).unwrap();
+/// builder.child_builder::<StringBuilder>(1).unwrap().append_value("foo");
+/// builder.append_type_id(0).unwrap();
+/// builder.child_builder::<Int32Builder>(0).unwrap().append_value(2);
+///
+/// let union = builder.try_finish().unwrap();
+/// assert_eq!(union.type_ids(), &[0, 1, 0]);
+/// let a = union.child(0).as_primitive::<Int32Type>();
+/// assert_eq!(a.len(), 3);
+/// assert!(a.is_null(1));
+/// assert_eq!(a.value(2), 2);
+/// ```
+#[derive(Debug)]
+pub struct SparseUnionBuilder {
+    children: UnionChildBuilders,
+}
+
+impl SparseUnionBuilder {
+    /// Creates a new [`SparseUnionBuilder`] with a child builder for each of `fields`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fields` and `child_builders` have different lengths
+    pub fn new(fields: UnionFields, child_builders: Vec<Box<dyn ArrayBuilder>>) -> Self {
+        Self {
+            children: UnionChildBuilders::new(fields, child_builders),
+        }
+    }
+
+    /// Creates a new [`SparseUnionBuilder`] from [`UnionFields`] and `capacity`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data type of a field is not supported by [`make_builder`]
+    pub fn from_fields(fields: UnionFields, capacity: usize) -> Self {
+        Self {
+            children: UnionChildBuilders::from_fields(fields, capacity),
+        }
+    }
+
+    /// Returns the fields of the union this builder is building
+    pub fn fields(&self) -> &UnionFields {
+        &self.children.fields
+    }
+
+    /// Returns a mutable reference to the builder of the child with `type_id`
+    ///
+    /// Returns `None` if `type_id` is not a field of this union, or if `T`
+    /// doesn't match the type of the child builder
+    pub fn child_builder<T: ArrayBuilder>(&mut self, type_id: i8) -> Option<&mut T> {
+        self.children.child_builder(type_id)
+    }
+
+    /// Appends a slot of the child with `type_id`
+    ///
+    /// The value of the slot must then be appended to [`Self::child_builder`], a null
+    /// value is encoded by appending a null to the child.
+    ///
+    /// Returns an error if `type_id` is not a field of this union
+    pub fn append_type_id(&mut self, type_id: i8) -> Result<(), ArrowError> {
+        self.children.child_index(type_id)?;
+        self.children.type_ids.push(type_id);
+        Ok(())
+    }
+
+    /// Pads each child with nulls for the slots that select other children
+    fn build(&self, children: Vec<ArrayRef>, type_ids: Vec<i8>) -> Result<UnionArray, ArrowError> {
+        self.children.validate(&type_ids, &children)?;
+        let children = self
+            .children
+            .fields
+            .iter()
+            .zip(children)
+            .map(|((type_id, _), child)| {
+                let data = child.to_data();
+                // A union has no null buffer, so a nested union child is padded
+                // with the slots of a null union, which select its first child
+                let padding = match data.data_type() {
+                    DataType::Union(fields, _) if fields.is_empty() && type_ids.len() > data.len() => {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "Cannot pad the child with type id {type_id} with nulls, as it is a union without fields"
+                        )));
+                    }
+                    DataType::Union(_, _) => Some(ArrayData::new_null(data.data_type(), type_ids.len())),
+                    _ => None,
+                };
+                let sources = std::iter::once(&data).chain(padding.as_ref()).collect();
+                let mut mutable = MutableArrayData::new(sources, true, type_ids.len());
+                let mut next = 0;
+                for run in type_ids.chunk_by(|a, b| (*a == type_id) == (*b == type_id)) {
+                    if run[0] == type_id {
+                        mutable.extend(0, next, next + run.len());
+                        next += run.len();
+                    } else if padding.is_some() {
+                        mutable.extend(1, 0, run.len());
+                    } else {
+                        mutable.extend_nulls(run.len());
+                    }
+                }
+                Ok(make_array(mutable.freeze()))
+            })
+            .collect::<Result<_, _>>()?;
+        UnionArray::try_new(
+            self.children.fields.clone(),
+            type_ids.into(),
+            None,
+            children,
+        )
+    }
+
+    /// Builds the [`UnionArray`] and resets this builder
+    ///
+    /// Returns an error if a child does not contain exactly one value per slot that selects it
+    pub fn try_finish(&mut self) -> Result<UnionArray, ArrowError> {
+        let children = self
+            .children
+            .child_builders
+            .iter_mut()
+            .map(|b| b.finish())
+            .collect();
+        let type_ids = std::mem::take(&mut self.children.type_ids);
+        self.build(children, type_ids)
+    }
+
+    /// Builds the [`UnionArray`] without resetting the builder
+    ///
+    /// Returns an error if a child does not contain exactly one value per slot that selects it
+    pub fn try_finish_cloned(&self) -> Result<UnionArray, ArrowError> {
+        let children = self
+            .children
+            .child_builders
+            .iter()
+            .map(|b| b.finish_cloned())
+            .collect();
+        self.build(children, self.children.type_ids.clone())
+    }
+
+    /// Builds the [`UnionArray`] and resets this builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if a child does not contain exactly one value per slot that selects it
+    pub fn finish(&mut self) -> UnionArray {
+        self.try_finish().unwrap()
+    }
+
+    /// Builds the [`UnionArray`] without resetting the builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if a child does not contain exactly one value per slot that selects it
+    pub fn finish_cloned(&self) -> UnionArray {
+        self.try_finish_cloned().unwrap()
+    }
+}
+
+impl ArrayBuilder for SparseUnionBuilder {
+    fn len(&self) -> usize {
+        self.children.type_ids.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.finish_cloned())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::array::Array;
+    use crate::builder::{Int32Builder, StringBuilder, StructBuilder};
     use crate::cast::AsArray;
     use crate::types::{Float64Type, Int32Type};
+    use arrow_schema::UnionMode;
 
     #[test]
     fn test_union_builder_array_builder_trait() {
@@ -484,4 +947,217 @@ mod tests {
         assert!(float_array.is_null(0)); // Null in sparse layout
         assert_eq!(float_array.value(1), 20.0);
     }
+
+    fn nested_union_fields() -> UnionFields {
+        let struct_fields = vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Utf8, true),
+        ];
+        UnionFields::new(
+            [0, 1, 5],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+                Field::new_struct("c", struct_fields, true),
+            ],
+        )
+    }
+
+    /// Appends `[1, "foo", {x: 10, y: "s"}, null (a), null (c), "bar"]`
+    macro_rules! append_nested_rows {
+        ($builder:expr) => {{
+            let builder = &mut $builder;
+            builder.append_type_id(0).unwrap();
+            builder
+                .child_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(1);
+            builder.append_type_id(1).unwrap();
+            builder
+                .child_builder::<StringBuilder>(1)
+                .unwrap()
+                .append_value("foo");
+            builder.append_type_id(5).unwrap();
+            let c = builder.child_builder::<StructBuilder>(5).unwrap();
+            c.field_builder::<Int32Builder>(0).unwrap().append_value(10);
+            c.field_builder::<StringBuilder>(1)
+                .unwrap()
+                .append_value("s");
+            c.append(true);
+            builder.append_type_id(0).unwrap();
+            builder
+                .child_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_null();
+            builder.append_type_id(5).unwrap();
+            let c = builder.child_builder::<StructBuilder>(5).unwrap();
+            c.field_builder::<Int32Builder>(0).unwrap().append_null();
+            c.field_builder::<StringBuilder>(1).unwrap().append_null();
+            c.append_null();
+            builder.append_type_id(1).unwrap();
+            builder
+                .child_builder::<StringBuilder>(1)
+                .unwrap()
+                .append_value("bar");
+        }};
+    }
+
+    fn check_nested_union_values(union: &UnionArray) {
+        assert_eq!(union.len(), 6);
+        assert_eq!(union.type_ids(), &[0, 1, 5, 0, 5, 1]);
+        assert_eq!(union.value(0).as_primitive::<Int32Type>().value(0), 1);
+        assert_eq!(union.value(1).as_string::<i32>().value(0), "foo");
+        let c = union.value(2);
+        let c = c.as_struct();
+        assert_eq!(c.column(0).as_primitive::<Int32Type>().value(0), 10);
+        assert_eq!(c.column(1).as_string::<i32>().value(0), "s");
+        assert!(union.value(3).is_null(0));
+        assert!(union.value(4).is_null(0));
+        assert_eq!(union.value(5).as_string::<i32>().value(0), "bar");
+    }
+
+    #[test]
+    fn test_dense_union_builder_nested() {
+        let mut builder = DenseUnionBuilder::from_fields(nested_union_fields(), 4);
+        append_nested_rows!(builder);
+        assert_eq!(ArrayBuilder::len(&builder), 6);
+
+        let cloned = builder.finish_cloned();
+        let union = builder.finish();
+        assert_eq!(union.to_data(), cloned.to_data());
+        assert_eq!(ArrayBuilder::len(&builder), 0);
+
+        check_nested_union_values(&union);
+        assert_eq!(union.offsets().unwrap(), &[0, 0, 0, 1, 1, 1]);
+        assert_eq!(union.child(0).len(), 2);
+        assert_eq!(union.child(1).len(), 2);
+        assert_eq!(union.child(5).len(), 2);
+
+        // The builder can be reused after finish
+        append_nested_rows!(builder);
+        assert_eq!(builder.finish().to_data(), union.to_data());
+    }
+
+    #[test]
+    fn test_sparse_union_builder_nested() {
+        let mut builder = SparseUnionBuilder::from_fields(nested_union_fields(), 4);
+        append_nested_rows!(builder);
+
+        let cloned = builder.finish_cloned();
+        let union = builder.finish();
+        assert_eq!(union.to_data(), cloned.to_data());
+
+        check_nested_union_values(&union);
+        assert!(union.offsets().is_none());
+        for type_id in [0, 1, 5] {
+            let child = union.child(type_id);
+            assert_eq!(child.len(), 6);
+            for (idx, id) in union.type_ids().iter().enumerate() {
+                if *id != type_id {
+                    assert!(child.is_null(idx));
+                }
+            }
+        }
+
+        let mut dense = DenseUnionBuilder::from_fields(nested_union_fields(), 4);
+        append_nested_rows!(dense);
+        let dense = dense.finish();
+        for idx in 0..union.len() {
+            assert_eq!(&union.value(idx), &dense.value(idx));
+        }
+    }
+
+    #[test]
+    fn test_sparse_union_builder_union_child() {
+        let inner = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("s", DataType::Utf8, true),
+                Field::new("i", DataType::Int32, false),
+            ],
+        );
+        let fields = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("a", DataType::Int32, false),
+                Field::new("u", DataType::Union(inner, UnionMode::Sparse), false),
+            ],
+        );
+        let mut builder = SparseUnionBuilder::from_fields(fields, 3);
+        builder.append_type_id(0).unwrap();
+        builder
+            .child_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1);
+        builder.append_type_id(1).unwrap();
+        let child = builder.child_builder::<SparseUnionBuilder>(1).unwrap();
+        child.append_type_id(1).unwrap();
+        child
+            .child_builder::<Int32Builder>(1)
+            .unwrap()
+            .append_value(5);
+        builder.append_type_id(0).unwrap();
+        builder
+            .child_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(2);
+
+        let union = builder.try_finish().unwrap();
+        assert_eq!(union.type_ids(), &[0, 1, 0]);
+        // The non-nullable child is padded with nulls
+        let a = union.child(0).as_primitive::<Int32Type>();
+        assert!(a.is_null(1));
+
+        // The union child is padded with slots selecting its first child
+        let u = union.child(1).as_union();
+        assert_eq!(u.type_ids(), &[0, 1, 0]);
+        assert!(u.child(0).is_null(0));
+        assert!(u.child(0).is_null(2));
+        assert_eq!(u.child(1).as_primitive::<Int32Type>().value(1), 5);
+        let value = union.value(1);
+        assert_eq!(
+            value
+                .as_union()
+                .value(0)
+                .as_primitive::<Int32Type>()
+                .value(0),
+            5
+        );
+    }
+
+    #[test]
+    fn test_union_builder_type_id_errors() {
+        let mut dense = DenseUnionBuilder::from_fields(nested_union_fields(), 0);
+        let err = dense.append_type_id(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Type id 2 is not a field of this union"
+        );
+        assert!(dense.child_builder::<Int32Builder>(1).is_none());
+        assert!(dense.child_builder::<Int32Builder>(2).is_none());
+
+        // A slot without a child value
+        dense.append_type_id(1).unwrap();
+        let err = dense.try_finish_cloned().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Child with type id 1 has 0 values, expected 1"
+        );
+
+        let mut sparse = SparseUnionBuilder::from_fields(nested_union_fields(), 0);
+        sparse.append_type_id(0).unwrap();
+        sparse
+            .child_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1);
+        sparse
+            .child_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(2);
+        let err = sparse.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Child with type id 0 has 2 values, expected 1"
+        );
+    }
 }
@@ -19,7 +19,7 @@
 //! [schema](arrow_schema::Schema).
 
 use crate::cast::AsArray;
-use crate::{Array, ArrayRef, StructArray, new_empty_array};
+use crate::{Array, ArrayRef, StructArray, compact_array, new_empty_array};
 use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
@@ -457,6 +457,54 @@ impl RecordBatch {
         }
     }
 
+    /// Projects the schema onto the columns with the specified names
+    ///
+    /// The columns of the returned [`RecordBatch`] are in the order of `names`, a name
+    /// may be repeated to include a column more than once. If the schema contains
+    /// multiple fields with the same name, the first is used.
+    ///
+    /// Returns an error listing every name that is not present in the schema.
+    ///
+    /// ```
+    /// # use arrow_array::record_batch;
+    /// let batch = record_batch!(
+    ///     ("a", Int32, [1, 2, 3]),
+    ///     ("b", Utf8, ["x", "y", "z"]),
+    ///     ("c", Float64, [1.0, 2.0, 3.0])
+    /// ).unwrap();
+    ///
+    /// let projected = batch.project_by_name(&["c", "a"]).unwrap();
+    /// assert_eq!(projected, batch.project(&[2, 0]).unwrap());
+    ///
+    /// let err = batch.project_by_name(&["a", "d", "e"]).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"Schema error: Unable to get fields named ["d", "e"]. Valid fields: ["a", "b", "c"]"#
+    /// );
+    /// ```
+    pub fn project_by_name(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        let fields = self.schema.fields();
+        let mut missing = vec![];
+        let indices: Vec<_> = names
+            .iter()
+            .filter_map(|name| {
+                let idx = fields.find(name).map(|(idx, _)| idx);
+                if idx.is_none() && !missing.contains(name) {
+                    missing.push(*name);
+                }
+                idx
+            })
+            .collect();
+
+        if !missing.is_empty() {
+            let valid_fields: Vec<_> = fields.iter().map(|f| f.name()).collect();
+            return Err(ArrowError::SchemaError(format!(
+                "Unable to get fields named {missing:?}. Valid fields: {valid_fields:?}"
+            )));
+        }
+        self.project(&indices)
+    }
+
     /// Normalize a semi-structured [`RecordBatch`] into a flat table.
     ///
     /// Nested [`Field`]s will generate names separated by `separator`, up to a depth of `max_level`
@@ -773,6 +821,40 @@ impl RecordBatch {
         RecordBatch::try_new(schema, columns)
     }
 
+    /// Shrinks the capacity of the buffers of each column that are exclusively owned
+    /// by this batch, see [`Array::shrink_to_fit`]
+    ///
+    /// Columns and buffers that are shared, for example with the batch this one was
+    /// sliced from, are left unchanged. See [`Self::compact`] to copy these instead.
+    pub fn shrink_to_fit(&mut self) {
+        self.columns.iter_mut().for_each(|c| c.shrink_to_fit());
+    }
+
+    /// Returns a copy of this batch whose columns only hold the memory required
+    /// for their values, see [`compact_array`]
+    ///
+    /// Unlike [`Self::shrink_to_fit`], this also copies columns that are sliced or
+    /// shared with other arrays.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("a", Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef),
+    /// ]).unwrap();
+    /// let sliced = batch.slice(10, 10);
+    /// let compacted = sliced.compact();
+    /// assert_eq!(compacted, sliced);
+    /// assert!(compacted.get_array_memory_size() < sliced.get_array_memory_size());
+    /// ```
+    pub fn compact(&self) -> RecordBatch {
+        Self {
+            schema: self.schema.clone(),
+            columns: self.columns.iter().map(|c| compact_array(c)).collect(),
+            row_count: self.row_count,
+        }
+    }
+
     /// Returns the total number of bytes of memory occupied physically by this batch.
     ///
     /// Note that this does not always correspond to the exact memory usage of a
@@ -1555,6 +1637,40 @@ mod tests {
         assert_eq!(expected, record_batch.project(&[0, 2]).unwrap());
     }
 
+    #[test]
+    fn project_by_name() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
+
+        let record_batch =
+            RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b.clone()), ("c", c.clone())])
+                .expect("valid conversion");
+
+        // Reordering
+        let expected = RecordBatch::try_from_iter(vec![("c", c.clone()), ("a", a.clone())])
+            .expect("valid conversion");
+        assert_eq!(expected, record_batch.project_by_name(&["c", "a"]).unwrap());
+
+        // Duplicate names
+        let expected = RecordBatch::try_from_iter(vec![("b", b.clone()), ("b", b.clone())])
+            .expect("valid conversion");
+        assert_eq!(expected, record_batch.project_by_name(&["b", "b"]).unwrap());
+
+        let projected = record_batch.project_by_name(&[]).unwrap();
+        assert_eq!(projected.num_columns(), 0);
+        assert_eq!(projected.num_rows(), 3);
+
+        // All missing names are reported
+        let err = record_batch
+            .project_by_name(&["x", "a", "y", "x"])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Schema error: Unable to get fields named ["x", "y"]. Valid fields: ["a", "b", "c"]"#
+        );
+    }
+
     #[test]
     fn project_empty() {
         let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
@@ -1706,4 +1822,38 @@ mod tests {
             "bar"
         );
     }
+
+    #[test]
+    fn test_shrink_to_fit_and_compact() {
+        let mut builder = crate::builder::Int32Builder::with_capacity(4096);
+        builder.extend((0..10).map(Some));
+        let column = Arc::new(builder.finish()) as ArrayRef;
+        let mut batch = RecordBatch::try_from_iter([("a", column)]).unwrap();
+        let size = batch.get_array_memory_size();
+        assert!(size > 4096 * 4);
+
+        // The column is shared with `shared`, and so cannot be shrunk
+        let shared = batch.clone();
+        batch.shrink_to_fit();
+        assert_eq!(batch.get_array_memory_size(), size);
+        drop(shared);
+
+        batch.shrink_to_fit();
+        assert!(batch.get_array_memory_size() < 512);
+
+        // Shrinking preserves the offset of the slice, compacting copies it
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut sliced = batch.slice(990, 10);
+        drop(batch);
+        sliced.shrink_to_fit();
+        assert!(sliced.get_array_memory_size() > 990 * 4);
+
+        let compacted = sliced.compact();
+        assert_eq!(compacted, sliced);
+        assert!(compacted.get_array_memory_size() < 512);
+    }
 }
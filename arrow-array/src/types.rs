@@ -36,7 +36,7 @@ use arrow_schema::{
     DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
     DataType, IntervalUnit, TimeUnit,
 };
-use chrono::{Duration, NaiveDate, NaiveDateTime};
+use chrono::{Duration, Months, NaiveDate, NaiveDateTime};
 use half::f16;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -896,6 +896,52 @@ impl IntervalMonthDayNanoType {
     pub fn to_parts(i: IntervalMonthDayNano) -> (i32, i32, i64) {
         (i.months, i.days, i.nanoseconds)
     }
+
+    /// Converts a [`Months`] into an IntervalMonthDayNanoType::Native
+    ///
+    /// Returns `None` if the number of months exceeds `i32::MAX`
+    pub fn from_months(months: Months) -> Option<IntervalMonthDayNano> {
+        let months = i32::try_from(months.as_u32()).ok()?;
+        Some(IntervalMonthDayNano::new(months, 0, 0))
+    }
+
+    /// Converts a [`Duration`] into an IntervalMonthDayNanoType::Native
+    ///
+    /// The duration is stored as nanoseconds, as a day is not always 24 hours
+    /// long. Returns `None` if the number of nanoseconds exceeds an `i64`.
+    pub fn from_duration(duration: Duration) -> Option<IntervalMonthDayNano> {
+        Some(IntervalMonthDayNano::new(0, 0, duration.num_nanoseconds()?))
+    }
+
+    /// Converts an IntervalMonthDayNanoType into a [`Months`]
+    ///
+    /// Returns `None` if the interval has days or nanoseconds, or a negative
+    /// number of months, as these cannot be represented by [`Months`]
+    pub fn to_months(i: IntervalMonthDayNano) -> Option<Months> {
+        match i {
+            IntervalMonthDayNano {
+                months,
+                days: 0,
+                nanoseconds: 0,
+            } => Some(Months::new(u32::try_from(months).ok()?)),
+            _ => None,
+        }
+    }
+
+    /// Converts an IntervalMonthDayNanoType into a [`Duration`]
+    ///
+    /// Returns `None` if the interval has months or days, as their length
+    /// depends on the date they are added to
+    pub fn to_duration(i: IntervalMonthDayNano) -> Option<Duration> {
+        match i {
+            IntervalMonthDayNano {
+                months: 0,
+                days: 0,
+                nanoseconds,
+            } => Some(Duration::nanoseconds(nanoseconds)),
+            _ => None,
+        }
+    }
 }
 
 impl Date32Type {
@@ -1753,6 +1799,38 @@ mod tests {
         assert_eq!(IntervalMonthDayNanoType::to_parts(value), (1, 2, 3));
     }
 
+    #[test]
+    fn month_day_nano_chrono_conversions() {
+        let value = IntervalMonthDayNanoType::from_months(Months::new(14)).unwrap();
+        assert_eq!(value, IntervalMonthDayNano::new(14, 0, 0));
+        assert_eq!(
+            IntervalMonthDayNanoType::to_months(value),
+            Some(Months::new(14))
+        );
+        assert_eq!(IntervalMonthDayNanoType::to_duration(value), None);
+        assert_eq!(
+            IntervalMonthDayNanoType::from_months(Months::new(u32::MAX)),
+            None
+        );
+
+        let duration = Duration::seconds(-90) + Duration::nanoseconds(5);
+        let value = IntervalMonthDayNanoType::from_duration(duration).unwrap();
+        assert_eq!(value, IntervalMonthDayNano::new(0, 0, -89_999_999_995));
+        assert_eq!(IntervalMonthDayNanoType::to_duration(value), Some(duration));
+        assert_eq!(IntervalMonthDayNanoType::to_months(value), None);
+        assert_eq!(
+            IntervalMonthDayNanoType::from_duration(Duration::days(300 * 365)),
+            None
+        );
+
+        // mixed intervals cannot be converted losslessly
+        let value = IntervalMonthDayNano::new(-1, 2, 0);
+        assert_eq!(IntervalMonthDayNanoType::to_months(value), None);
+        assert_eq!(IntervalMonthDayNanoType::to_duration(value), None);
+        let value = IntervalMonthDayNano::new(-1, 0, 0);
+        assert_eq!(IntervalMonthDayNanoType::to_months(value), None);
+    }
+
     #[test]
     fn month_day_nano_should_roundtrip_neg() {
         let value = IntervalMonthDayNanoType::make_value(-1, -2, -3);
@@ -96,6 +96,23 @@ impl BooleanBuffer {
         Self::new(buffer.into(), 0, len)
     }
 
+    /// Create a new [`BooleanBuffer`] from a slice of `bool`, packing 64
+    /// values at a time into each word
+    pub fn from_bools(values: &[bool]) -> Self {
+        let len = values.len();
+        let mut buffer = MutableBuffer::new(bit_util::ceil(len, 64) * 8);
+        let chunks = values.chunks_exact(64);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            buffer.push(pack_bools(chunk));
+        }
+        if !remainder.is_empty() {
+            buffer.push(pack_bools(remainder));
+        }
+        buffer.truncate(bit_util::ceil(len, 8));
+        Self::new(buffer.into(), 0, len)
+    }
+
     /// Returns the number of set bits in this buffer
     pub fn count_set_bits(&self) -> usize {
         self.buffer.count_set_bits_offset(self.offset, self.len)
@@ -178,6 +195,22 @@ impl BooleanBuffer {
         self.buffer.bit_slice(self.offset, self.len)
     }
 
+    /// Returns the packed values of this [`BooleanBuffer`] as a `Vec<u8>` of
+    /// `ceil(len / 8)` bytes, starting from bit 0 of the first byte
+    ///
+    /// Unlike [`Self::values`], this accounts for [`Self::offset`], re-packing
+    /// the values if it is not a multiple of 8. Any bits past [`Self::len`]
+    /// in the final byte are zero.
+    pub fn to_packed_vec(&self) -> Vec<u8> {
+        let byte_len = bit_util::ceil(self.len, 8);
+        let mut packed = self.sliced()[..byte_len].to_vec();
+        let remainder = self.len % 8;
+        if let (Some(last), true) = (packed.last_mut(), remainder != 0) {
+            *last &= (1 << remainder) - 1;
+        }
+        packed
+    }
+
     /// Returns true if this [`BooleanBuffer`] is equal to `other`, using pointer comparisons
     /// to determine buffer equality. This is cheaper than `PartialEq::eq` but may
     /// return false when the arrays are logically equal
@@ -279,11 +312,18 @@ impl<'a> IntoIterator for &'a BooleanBuffer {
     }
 }
 
+/// Packs up to 64 `values` into the low bits of a `u64`
+#[inline]
+fn pack_bools(values: &[bool]) -> u64 {
+    values
+        .iter()
+        .enumerate()
+        .fold(0, |packed, (i, v)| packed | ((*v as u64) << i))
+}
+
 impl From<&[bool]> for BooleanBuffer {
     fn from(value: &[bool]) -> Self {
-        let mut builder = BooleanBufferBuilder::new(value.len());
-        builder.append_slice(value);
-        builder.finish()
+        Self::from_bools(value)
     }
 }
 
@@ -306,6 +346,7 @@ impl FromIterator<bool> for BooleanBuffer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
 
     #[test]
     fn test_boolean_new() {
@@ -428,6 +469,51 @@ mod tests {
         assert_eq!(!boolean_buf, expected);
     }
 
+    #[test]
+    fn test_from_bools() {
+        let mut rng = rand::rng();
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 128, 200, 1000] {
+            let values: Vec<bool> = (0..len).map(|_| rng.random_bool(0.5)).collect();
+            let buffer = BooleanBuffer::from_bools(&values);
+
+            let mut builder = BooleanBufferBuilder::new(len);
+            values.iter().for_each(|v| builder.append(*v));
+            let expected = builder.finish();
+
+            assert_eq!(buffer, expected);
+            assert_eq!(buffer.values(), expected.values());
+            assert_eq!(buffer.iter().collect::<Vec<_>>(), values);
+        }
+    }
+
+    #[test]
+    fn test_to_packed_vec() {
+        let mut rng = rand::rng();
+        let values: Vec<bool> = (0..304).map(|_| rng.random_bool(0.5)).collect();
+        // Bits past the end of the slices below are set to check they are masked
+        let mut bytes = BooleanBuffer::from_bools(&values).values().to_vec();
+        bytes.push(0xFF);
+        let buffer = BooleanBuffer::new(Buffer::from(bytes), 0, values.len() + 8);
+
+        for _ in 0..100 {
+            let offset = rng.random_range(0..values.len());
+            let len = rng.random_range(0..=values.len() - offset);
+            let sliced = buffer.slice(offset, len);
+            let packed = sliced.to_packed_vec();
+
+            let mut expected = vec![0_u8; bit_util::ceil(len, 8)];
+            for (i, v) in values[offset..offset + len].iter().enumerate() {
+                if *v {
+                    bit_util::set_bit(&mut expected, i);
+                }
+            }
+            assert_eq!(packed, expected, "offset {offset} len {len}");
+        }
+
+        let sliced = buffer.slice(values.len(), 5);
+        assert_eq!(sliced.to_packed_vec(), vec![0b11111]);
+    }
+
     #[test]
     fn test_boolean_from_slice_bool() {
         let v = [true, false, false];
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::arith::derive_arith;
+use std::fmt::{Display, Formatter};
 use std::ops::Neg;
 
 /// Value of an IntervalMonthDayNano array
@@ -102,6 +103,19 @@ impl IntervalMonthDayNano {
         }
     }
 
+    /// Create a new [`IntervalMonthDayNano`] from its components, the inverse
+    /// of [`Self::to_parts`]
+    #[inline]
+    pub const fn from_parts(months: i32, days: i32, nanoseconds: i64) -> Self {
+        Self::new(months, days, nanoseconds)
+    }
+
+    /// Returns the components of this interval as `(months, days, nanoseconds)`
+    #[inline]
+    pub const fn to_parts(self) -> (i32, i32, i64) {
+        (self.months, self.days, self.nanoseconds)
+    }
+
     /// Computes the absolute value
     #[inline]
     pub fn wrapping_abs(self) -> Self {
@@ -274,6 +288,16 @@ impl Neg for IntervalMonthDayNano {
     }
 }
 
+impl Display for IntervalMonthDayNano {
+    /// Formats the interval as an ISO 8601 duration, such as `P1M2DT3.000000004S`
+    ///
+    /// Zero components are omitted, and negative components are prefixed with `-`,
+    /// for example `P-1M2DT-0.5S`. The zero interval is formatted as `PT0S`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_iso8601(f, self.months, self.days, self.nanoseconds, 9)
+    }
+}
+
 derive_arith!(
     IntervalMonthDayNano,
     Add,
@@ -532,6 +556,49 @@ impl Neg for IntervalDayTime {
     }
 }
 
+impl Display for IntervalDayTime {
+    /// Formats the interval as an ISO 8601 duration, such as `P2DT3.004S`
+    ///
+    /// Zero components are omitted, and negative components are prefixed with `-`,
+    /// for example `P2DT-0.5S`. The zero interval is formatted as `PT0S`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_iso8601(f, 0, self.days, self.milliseconds as i64, 3)
+    }
+}
+
+/// Writes an ISO 8601 duration of `months`, `days` and `subsecs`, a number
+/// of seconds with `scale` fractional digits
+fn write_iso8601(
+    f: &mut Formatter<'_>,
+    months: i32,
+    days: i32,
+    subsecs: i64,
+    scale: u32,
+) -> std::fmt::Result {
+    if months == 0 && days == 0 && subsecs == 0 {
+        return f.write_str("PT0S");
+    }
+    f.write_str("P")?;
+    if months != 0 {
+        write!(f, "{months}M")?;
+    }
+    if days != 0 {
+        write!(f, "{days}D")?;
+    }
+    if subsecs != 0 {
+        let unit = 10_i64.pow(scale);
+        let sign = if subsecs < 0 { "-" } else { "" };
+        write!(f, "T{sign}{}", (subsecs / unit).unsigned_abs())?;
+        let fraction = (subsecs % unit).unsigned_abs();
+        if fraction != 0 {
+            let fraction = format!("{fraction:0width$}", width = scale as usize);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        f.write_str("S")?;
+    }
+    Ok(())
+}
+
 derive_arith!(
     IntervalDayTime,
     Add,
@@ -577,3 +644,68 @@ derive_arith!(
     wrapping_rem,
     checked_rem
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_day_nano_display() {
+        let cases = [
+            (IntervalMonthDayNano::ZERO, "PT0S"),
+            (
+                IntervalMonthDayNano::new(1, 2, 3_000_000_004),
+                "P1M2DT3.000000004S",
+            ),
+            (IntervalMonthDayNano::new(14, 0, 0), "P14M"),
+            (IntervalMonthDayNano::new(0, 3, 0), "P3D"),
+            (IntervalMonthDayNano::new(0, 0, 5_000_000_000), "PT5S"),
+            (IntervalMonthDayNano::new(0, 0, 1_500_000_000), "PT1.5S"),
+            (IntervalMonthDayNano::new(0, 0, 1), "PT0.000000001S"),
+            (
+                IntervalMonthDayNano::new(-1, 2, -500_000_000),
+                "P-1M2DT-0.5S",
+            ),
+            (
+                IntervalMonthDayNano::new(1, -2, -3_250_000_000),
+                "P1M-2DT-3.25S",
+            ),
+            (
+                IntervalMonthDayNano::MIN,
+                "P-2147483648M-2147483648DT-9223372036.854775808S",
+            ),
+            (
+                IntervalMonthDayNano::MAX,
+                "P2147483647M2147483647DT9223372036.854775807S",
+            ),
+        ];
+        for (interval, expected) in cases {
+            assert_eq!(interval.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_month_day_nano_parts() {
+        let interval = IntervalMonthDayNano::from_parts(-1, 2, -3);
+        assert_eq!(interval, IntervalMonthDayNano::new(-1, 2, -3));
+        assert_eq!(interval.to_parts(), (-1, 2, -3));
+        assert_eq!(
+            IntervalMonthDayNano::MIN.to_parts(),
+            (i32::MIN, i32::MIN, i64::MIN)
+        );
+    }
+
+    #[test]
+    fn test_day_time_display() {
+        let cases = [
+            (IntervalDayTime::ZERO, "PT0S"),
+            (IntervalDayTime::new(2, 3_004), "P2DT3.004S"),
+            (IntervalDayTime::new(2, -500), "P2DT-0.5S"),
+            (IntervalDayTime::new(-1, 60_000), "P-1DT60S"),
+            (IntervalDayTime::MIN, "P-2147483648DT-2147483.648S"),
+        ];
+        for (interval, expected) in cases {
+            assert_eq!(interval.to_string(), expected);
+        }
+    }
+}
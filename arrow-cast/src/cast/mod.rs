@@ -51,7 +51,6 @@ use crate::cast::run_array::*;
 use crate::cast::string::*;
 
 use arrow_buffer::IntervalMonthDayNano;
-use arrow_data::ByteView;
 use chrono::{NaiveTime, Offset, TimeZone, Utc};
 use std::cmp::Ordering;
 use std::sync::Arc;
@@ -1299,8 +1298,14 @@ pub fn cast_with_options(
             Float64 => parse_string_view::<Float64Type>(array, cast_options),
             Date32 => parse_string_view::<Date32Type>(array, cast_options),
             Date64 => parse_string_view::<Date64Type>(array, cast_options),
-            Binary => cast_view_to_byte::<StringViewType, GenericBinaryType<i32>>(array),
-            LargeBinary => cast_view_to_byte::<StringViewType, GenericBinaryType<i64>>(array),
+            Binary => {
+                let array = array.as_string_view().clone().to_binary_view();
+                cast_view_to_byte::<BinaryViewType, GenericBinaryType<i32>>(&array)
+            }
+            LargeBinary => {
+                let array = array.as_string_view().clone().to_binary_view();
+                cast_view_to_byte::<BinaryViewType, GenericBinaryType<i64>>(&array)
+            }
             BinaryView => Ok(Arc::new(array.as_string_view().clone().to_binary_view())),
             Utf8 => cast_view_to_byte::<StringViewType, GenericStringType<i32>>(array),
             LargeUtf8 => cast_view_to_byte::<StringViewType, GenericStringType<i64>>(array),
@@ -2646,30 +2651,17 @@ where
     Ok(Arc::new(GenericByteArray::<TO>::from(array_data)))
 }
 
-/// Helper function to cast from one `ByteViewType` array to `ByteArrayType` array.
+/// Helper function to cast from one `ByteViewType` array to `ByteArrayType` array,
+/// reusing the data buffer of the view array if possible, see
+/// [`GenericByteViewArray::to_byte_array`]
 fn cast_view_to_byte<FROM, TO>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
 where
     FROM: ByteViewType,
-    TO: ByteArrayType,
-    FROM::Native: AsRef<TO::Native>,
+    TO: ByteArrayType<Native = FROM::Native>,
 {
-    let data = array.to_data();
-    let view_array = GenericByteViewArray::<FROM>::from(data);
-
-    let len = view_array.len();
-    let bytes = view_array
-        .views()
-        .iter()
-        .map(|v| ByteView::from(*v).length as usize)
-        .sum::<usize>();
-
-    let mut byte_array_builder = GenericByteBuilder::<TO>::with_capacity(len, bytes);
-
-    for val in view_array.iter() {
-        byte_array_builder.append_option(val);
-    }
-
-    Ok(Arc::new(byte_array_builder.finish()))
+    Ok(Arc::new(
+        array.as_byte_view::<FROM>().to_byte_array::<TO>()?,
+    ))
 }
 
 #[cfg(test)]
@@ -6696,6 +6688,56 @@ mod tests {
         assert_eq!(binary_view_casted_array.as_ref(), &expected_string_array);
     }
 
+    #[test]
+    fn test_string_view_round_trip_zero_copy() {
+        _test_string_view_round_trip_zero_copy::<i32>();
+        _test_string_view_round_trip_zero_copy::<i64>();
+    }
+
+    fn _test_string_view_round_trip_zero_copy<O>()
+    where
+        O: OffsetSizeTrait,
+    {
+        let data = [
+            Some("large payload over 12 bytes"),
+            None,
+            Some("another large payload over 12 bytes"),
+            Some(""),
+            None,
+        ];
+        let string_array = GenericStringArray::<O>::from_iter(data);
+
+        let view_array = cast(&string_array, &DataType::Utf8View).unwrap();
+        let view_array = view_array.as_string_view();
+        assert_eq!(view_array, &StringViewArray::from_iter(data));
+        assert_eq!(view_array.data_buffers().len(), 1);
+        assert_eq!(
+            view_array.data_buffers()[0].as_ptr(),
+            string_array.values().as_ptr()
+        );
+
+        let array = cast(view_array, string_array.data_type()).unwrap();
+        let array = array.as_string::<O>();
+        assert_eq!(array, &string_array);
+        assert_eq!(array.values().as_ptr(), string_array.values().as_ptr());
+
+        let binary = cast(view_array, &GenericBinaryArray::<O>::DATA_TYPE).unwrap();
+        let binary = binary.as_binary::<O>();
+        assert_eq!(binary, &GenericBinaryArray::<O>::from_iter(data));
+        assert_eq!(binary.values().as_ptr(), string_array.values().as_ptr());
+
+        // Values that are not contiguous in a single buffer are copied
+        let view_array =
+            StringViewArray::from_iter([Some("short"), None, Some("large payload over 12 bytes")]);
+        let array = cast(&view_array, string_array.data_type()).unwrap();
+        let expected = GenericStringArray::<O>::from_iter([
+            Some("short"),
+            None,
+            Some("large payload over 12 bytes"),
+        ]);
+        assert_eq!(array.as_string::<O>(), &expected);
+    }
+
     #[test]
     fn test_view_to_binary() {
         _test_view_to_binary::<i32>();
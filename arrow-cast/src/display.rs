@@ -48,6 +48,16 @@ pub enum DurationFormat {
     Pretty,
 }
 
+/// Format for displaying intervals
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalFormat {
+    /// ISO 8601 - `P1M2DT3.000000004S`
+    ISO8601,
+    /// A human readable representation - `1 mons 2 days 3.000000004 secs`
+    Pretty,
+}
+
 /// Options for formatting arrays
 ///
 /// By default nulls are formatted as `""` and temporal types formatted
@@ -72,6 +82,8 @@ pub struct FormatOptions<'a> {
     time_format: TimeFormat<'a>,
     /// Duration format
     duration_format: DurationFormat,
+    /// Interval format
+    interval_format: IntervalFormat,
     /// Show types in visual representation batches
     types_info: bool,
 }
@@ -94,6 +106,7 @@ impl<'a> FormatOptions<'a> {
             timestamp_tz_format: None,
             time_format: None,
             duration_format: DurationFormat::ISO8601,
+            interval_format: IntervalFormat::Pretty,
             types_info: false,
         }
     }
@@ -162,6 +175,16 @@ impl<'a> FormatOptions<'a> {
         }
     }
 
+    /// Overrides the format used for interval columns
+    ///
+    /// Defaults to [`IntervalFormat::Pretty`]
+    pub const fn with_interval_format(self, interval_format: IntervalFormat) -> Self {
+        Self {
+            interval_format,
+            ..self
+        }
+    }
+
     /// Overrides if types should be shown
     ///
     /// Defaults to [`false`]
@@ -696,8 +719,19 @@ duration_option_display!(try_duration_ms_to_duration, DurationMillisecondType, 3
 duration_display!(duration_us_to_duration, DurationMicrosecondType, 6);
 duration_display!(duration_ns_to_duration, DurationNanosecondType, 9);
 
-impl DisplayIndex for &PrimitiveArray<IntervalYearMonthType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalYearMonthType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        if *fmt == IntervalFormat::ISO8601 {
+            let interval = IntervalMonthDayNano::new(self.value(idx), 0, 0);
+            write!(f, "{interval}")?;
+            return Ok(());
+        }
         let interval = self.value(idx) as f64;
         let years = (interval / 12_f64).floor();
         let month = interval - (years * 12_f64);
@@ -707,9 +741,19 @@ impl DisplayIndex for &PrimitiveArray<IntervalYearMonthType> {
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalDayTimeType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        if *fmt == IntervalFormat::ISO8601 {
+            write!(f, "{value}")?;
+            return Ok(());
+        }
         let mut prefix = "";
 
         if value.days != 0 {
@@ -730,9 +774,19 @@ impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalMonthDayNanoType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalMonthDayNanoType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        if *fmt == IntervalFormat::ISO8601 {
+            write!(f, "{value}")?;
+            return Ok(());
+        }
         let mut prefix = "";
 
         if value.months != 0 {
@@ -1141,6 +1195,47 @@ mod tests {
         (0..array.len()).map(|x| fmt.value(x).to_string()).collect()
     }
 
+    #[test]
+    fn test_array_value_to_string_interval() {
+        let iso_fmt = FormatOptions::new().with_interval_format(IntervalFormat::ISO8601);
+        let pretty_fmt = FormatOptions::new();
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 2, 3_000_000_004)),
+            Some(IntervalMonthDayNano::new(-1, 2, -500_000_000)),
+            Some(IntervalMonthDayNano::ZERO),
+            None,
+        ]);
+        let iso = format_array(&array, &iso_fmt);
+        assert_eq!(iso, vec!["P1M2DT3.000000004S", "P-1M2DT-0.5S", "PT0S", ""]);
+        let pretty = format_array(&array, &pretty_fmt);
+        assert_eq!(
+            pretty,
+            vec![
+                "1 mons 2 days 3.000000004 secs",
+                "-1 mons 2 days -0.500000000 secs",
+                "",
+                ""
+            ]
+        );
+
+        let array = IntervalDayTimeArray::from(vec![
+            IntervalDayTime::new(2, 3_004),
+            IntervalDayTime::new(-1, 500),
+        ]);
+        let iso = format_array(&array, &iso_fmt);
+        assert_eq!(iso, vec!["P2DT3.004S", "P-1DT0.5S"]);
+
+        let array = IntervalYearMonthArray::from(vec![14, -3, 0]);
+        let iso = format_array(&array, &iso_fmt);
+        assert_eq!(iso, vec!["P14M", "P-3M", "PT0S"]);
+        let pretty = format_array(&array, &pretty_fmt);
+        assert_eq!(
+            pretty,
+            vec!["1 years 2 mons", "-1 years 9 mons", "0 years 0 mons"]
+        );
+    }
+
     #[test]
     fn test_array_value_to_string_duration() {
         let iso_fmt = FormatOptions::new();
@@ -1820,7 +1820,10 @@ mod tests {
     use super::*;
 
     use crate::{root_as_footer, root_as_message, size_prefixed_root_as_message};
-    use arrow_array::builder::{PrimitiveRunBuilder, UnionBuilder};
+    use arrow_array::builder::{
+        DenseUnionBuilder, Int32Builder, PrimitiveRunBuilder, SparseUnionBuilder, StringBuilder,
+        StructBuilder, UnionBuilder,
+    };
     use arrow_array::types::*;
     use arrow_buffer::{NullBuffer, OffsetBuffer};
     use arrow_data::ArrayDataBuilder;
@@ -2319,6 +2322,72 @@ mod tests {
         check_union_with_builder(UnionBuilder::new_sparse());
     }
 
+    fn nested_union_fields() -> UnionFields {
+        let struct_fields = vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Utf8, true),
+        ];
+        UnionFields::new(
+            [0, 1, 2],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+                Field::new_struct("c", struct_fields, true),
+            ],
+        )
+    }
+
+    macro_rules! append_nested_union_rows {
+        ($builder:expr) => {{
+            let builder = &mut $builder;
+            for i in 0..10 {
+                let type_id = (i % 3) as i8;
+                builder.append_type_id(type_id).unwrap();
+                match type_id {
+                    0 => {
+                        let a = builder.child_builder::<Int32Builder>(0).unwrap();
+                        a.append_option((i % 2 == 0).then_some(i));
+                    }
+                    1 => {
+                        let b = builder.child_builder::<StringBuilder>(1).unwrap();
+                        b.append_value(format!("value {i}"));
+                    }
+                    _ => {
+                        let c = builder.child_builder::<StructBuilder>(2).unwrap();
+                        c.field_builder::<Int32Builder>(0).unwrap().append_value(i);
+                        c.field_builder::<StringBuilder>(1).unwrap().append_null();
+                        c.append(i != 5);
+                    }
+                }
+            }
+        }};
+    }
+
+    fn check_nested_union_roundtrip(union: UnionArray) {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "union",
+            union.data_type().clone(),
+            false,
+        )]));
+        let rb = RecordBatch::try_new(schema, vec![Arc::new(union)]).unwrap();
+        let rb2 = roundtrip_ipc(&rb);
+        assert_eq!(rb, rb2);
+    }
+
+    #[test]
+    fn test_roundtrip_dense_union_builder_nested() {
+        let mut builder = DenseUnionBuilder::from_fields(nested_union_fields(), 10);
+        append_nested_union_rows!(builder);
+        check_nested_union_roundtrip(builder.finish());
+    }
+
+    #[test]
+    fn test_roundtrip_sparse_union_builder_nested() {
+        let mut builder = SparseUnionBuilder::from_fields(nested_union_fields(), 10);
+        append_nested_union_rows!(builder);
+        check_nested_union_roundtrip(builder.finish());
+    }
+
     #[test]
     fn test_roundtrip_struct_empty_fields() {
         let nulls = NullBuffer::from(&[true, true, false]);
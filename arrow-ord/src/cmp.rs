@@ -22,6 +22,31 @@
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 //!
+//! # Floating point semantics
+//!
+//! Unlike the IEEE 754 comparison operators, where any comparison involving `NaN`
+//! is `false`, these kernels order floating point values according to the
+//! totalOrder predicate (see [`f64::total_cmp`]). This gives a consistent
+//! ordering suitable for range filters and min/max statistics:
+//!
+//! * `NaN` is greater than every other value, including positive infinity,
+//!   and is equal to itself
+//! * `-0.0` is less than `+0.0`
+//!
+//! ```
+//! # use arrow_array::{BooleanArray, Float64Array, Scalar};
+//! # use arrow_ord::cmp::{eq, gt};
+//! let a = Float64Array::from(vec![f64::NAN, f64::INFINITY, 0.0, -0.0]);
+//!
+//! let r = gt(&a, &Scalar::new(Float64Array::from(vec![f64::INFINITY]))).unwrap();
+//! assert_eq!(r, BooleanArray::from(vec![true, false, false, false]));
+//!
+//! let r = eq(&a, &Scalar::new(Float64Array::from(vec![-0.0]))).unwrap();
+//! assert_eq!(r, BooleanArray::from(vec![false, false, false, true]));
+//! ```
+//!
+//! To treat `-0.0` and `+0.0` as equal, normalize zeros before calling these kernels.
+//!
 
 use arrow_array::cast::AsArray;
 use arrow_array::types::{ByteArrayType, ByteViewType};
@@ -659,7 +684,9 @@ pub fn compare_byte_view<T: ByteViewType>(
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{DictionaryArray, Int32Array, Scalar, StringArray};
+    use arrow_array::{
+        DictionaryArray, Float32Array, Float64Array, Int32Array, Scalar, StringArray,
+    };
 
     use super::*;
 
@@ -694,6 +721,52 @@ mod tests {
         assert_eq!(r.null_count(), 3);
     }
 
+    #[test]
+    fn test_float_total_order_scalar() {
+        let a = Float64Array::from(vec![f64::NAN, -0.0, 0.0, 1.0, f64::NEG_INFINITY]);
+
+        let nan = Float64Array::from(vec![f64::NAN]);
+        let nan = Scalar::new(&nan);
+        let zero = Float64Array::from(vec![0.0]);
+        let zero = Scalar::new(&zero);
+        let neg_zero = Float64Array::from(vec![-0.0]);
+        let neg_zero = Scalar::new(&neg_zero);
+
+        // NaN equals itself and sorts above all other values
+        let r = eq(&a, &nan).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![true, false, false, false, false])
+        );
+        let r = lt(&a, &nan).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, true, true, true]));
+        let r = gt_eq(&a, &nan).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![true, false, false, false, false])
+        );
+
+        // -0.0 sorts below +0.0
+        let r = gt(&a, &zero).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, false, true, false]));
+        let r = lt(&a, &zero).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false, false, true]));
+        let r = eq(&a, &neg_zero).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![false, true, false, false, false])
+        );
+        let r = lt_eq(&a, &neg_zero).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false, false, true]));
+
+        let a = Float32Array::from(vec![f32::NAN, -0.0, 0.0]);
+        let b = Float32Array::from(vec![f32::INFINITY, 0.0, -0.0]);
+        let r = gt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true]));
+        let r = neq(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, true]));
+    }
+
     #[test]
     fn is_distinct_from_non_nulls() {
         let left_int_array = Int32Array::from(vec![0, 1, 2, 3, 4]);
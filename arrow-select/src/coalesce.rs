@@ -611,6 +611,18 @@ mod tests {
             .run();
     }
 
+    #[test]
+    fn test_coalesce_one_by_one_multi_column() {
+        // single row inputs with many column types, such as the output of a very
+        // selective filter, are combined into batches of the target size
+        let batches = (0..8192 * 2 + 5).map(|i| multi_column_batch(i..i + 1));
+        Test::new()
+            .with_batches(batches)
+            .with_batch_size(8192)
+            .with_expected_output_sizes(vec![8192, 8192, 5])
+            .run();
+    }
+
     #[test]
     fn test_coalesce_empty() {
         let schema = Arc::new(Schema::new(vec![Field::new("c0", DataType::UInt32, false)]));
@@ -166,6 +166,53 @@ pub fn filter(values: &dyn Array, predicate: &BooleanArray) -> Result<ArrayRef,
     filter_array(values, &predicate)
 }
 
+/// Returns the indices of the elements of `predicate` that are `true`
+///
+/// Null elements of `predicate` are treated as `false`. This is useful to apply
+/// the same selection to several arrays with [`take`], or to convert a
+/// predicate into a list of row positions.
+///
+/// Returns an error if `predicate` is too long for its indices to fit in a `u32`.
+///
+/// [`take`]: crate::take::take
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::{BooleanArray, UInt32Array};
+/// # use arrow_select::filter::filter_indices;
+/// let predicate = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+/// let indices = filter_indices(&predicate).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![0, 3]));
+/// ```
+pub fn filter_indices(predicate: &BooleanArray) -> Result<UInt32Array, ArrowError> {
+    if predicate.len() > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compute filter indices of a predicate of length {} which exceeds {}",
+            predicate.len(),
+            u32::MAX
+        )));
+    }
+
+    let values = match predicate.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => predicate.values() & nulls.inner(),
+        _ => predicate.values().clone(),
+    };
+    let count = values.count_set_bits();
+
+    let mut indices = Vec::with_capacity(count);
+    match IterationStrategy::default_strategy(values.len(), count) {
+        IterationStrategy::None => {}
+        IterationStrategy::All => indices.extend(0..values.len() as u32),
+        IterationStrategy::SlicesIterator => {
+            for (start, end) in values.set_slices() {
+                indices.extend(start as u32..end as u32);
+            }
+        }
+        _ => indices.extend(values.set_indices().map(|idx| idx as u32)),
+    }
+    Ok(UInt32Array::from(indices))
+}
+
 fn multiple_arrays(data_type: &DataType) -> bool {
     match data_type {
         DataType::Struct(fields) => {
@@ -1046,6 +1093,54 @@ mod tests {
         assert_eq!(9, d.value(1));
     }
 
+    #[test]
+    fn test_filter_indices() {
+        let predicate = BooleanArray::from(vec![true, false, false, true, true]);
+        let indices = filter_indices(&predicate).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 3, 4]));
+        assert_eq!(indices.null_count(), 0);
+
+        // nulls are treated as false
+        let predicate = BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None]);
+        let indices = filter_indices(&predicate).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 3]));
+
+        // indices are relative to the start of a sliced predicate
+        let predicate = predicate.slice(1, 3);
+        let indices = filter_indices(&predicate).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2]));
+
+        let predicate = BooleanArray::from(vec![false; 10]);
+        assert!(filter_indices(&predicate).unwrap().is_empty());
+        let predicate = BooleanArray::from(vec![true; 10]);
+        let indices = filter_indices(&predicate).unwrap();
+        assert_eq!(indices, UInt32Array::from_iter_values(0..10));
+        let predicate = BooleanArray::from(Vec::<bool>::new());
+        assert!(filter_indices(&predicate).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_filter_indices_fuzz() {
+        let mut rng = rng();
+        for density in [0.01, 0.5, 0.95, 0.999] {
+            let predicate: BooleanArray = (0..rng.random_range(1..2000))
+                .map(|_| rng.random_bool(0.9).then(|| rng.random_bool(density)))
+                .collect();
+
+            let expected: UInt32Array = predicate
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, v)| v.unwrap_or(false).then_some(idx as u32))
+                .collect();
+            assert_eq!(filter_indices(&predicate).unwrap(), expected);
+
+            // consistent with filter
+            let values = UInt32Array::from_iter_values(0..predicate.len() as u32);
+            let filtered = filter(&values, &predicate).unwrap();
+            assert_eq!(filtered.as_primitive::<UInt32Type>(), &expected);
+        }
+    }
+
     #[test]
     fn test_filter_array_low_density() {
         // this test exercises the all 0's branch of the filter algorithm
@@ -1197,6 +1292,37 @@ mod tests {
         _test_filter_byte_view::<BinaryViewType>()
     }
 
+    #[test]
+    fn test_filter_string_view_gc_if_sparse() {
+        let array = StringViewArray::from_iter_values(
+            (0..100).map(|i| format!("value {i:03} longer than 12 bytes")),
+        );
+        assert_eq!(array.data_buffer_utilization(), 1.0);
+
+        // The filtered array references the data buffer of the input
+        let predicate = BooleanArray::from_iter((0..100).map(|i| Some(i % 10 == 0)));
+        let filtered = filter(&array, &predicate).unwrap();
+        let filtered = filtered.as_string_view();
+        assert_eq!(filtered.len(), 10);
+        assert_eq!(filtered.data_buffer_utilization(), 0.1);
+
+        let gced = filtered.gc_if_sparse(0.5);
+        assert_eq!(&gced, filtered);
+        assert_eq!(gced.data_buffer_utilization(), 1.0);
+        assert_eq!(gced.data_buffers()[0].len(), 10 * 30);
+
+        // A dense filter result is not compacted
+        let predicate = BooleanArray::from_iter((0..100).map(|i| Some(i != 50)));
+        let filtered = filter(&array, &predicate).unwrap();
+        let filtered = filtered.as_string_view();
+        assert_eq!(filtered.data_buffer_utilization(), 0.99);
+        let gced = filtered.gc_if_sparse(0.5);
+        assert_eq!(
+            gced.data_buffers()[0].as_ptr(),
+            array.data_buffers()[0].as_ptr()
+        );
+    }
+
     #[test]
     fn test_filter_fixed_binary() {
         let v1 = [1_u8, 2];
@@ -42,19 +42,6 @@ use arrow_schema::{ArrowError, DataType};
 /// assert_eq!(nulled.as_primitive(), &Int32Array::from(vec![None, None, Some(1), Some(9)]));
 /// ```
 pub fn nullif(left: &dyn Array, right: &BooleanArray) -> Result<ArrayRef, ArrowError> {
-    let left_data = left.to_data();
-
-    if left_data.len() != right.len() {
-        return Err(ArrowError::ComputeError(
-            "Cannot perform comparison operation on arrays of different length".to_string(),
-        ));
-    }
-    let len = left_data.len();
-
-    if len == 0 || left_data.data_type() == &DataType::Null {
-        return Ok(make_array(left_data));
-    }
-
     // left=0 (null)   right=null       output bitmap=null
     // left=0          right=1          output bitmap=null
     // left=1 (set)    right=null       output bitmap=set   (passthrough)
@@ -69,6 +56,109 @@ pub fn nullif(left: &dyn Array, right: &BooleanArray) -> Result<ArrayRef, ArrowE
         Some(nulls) => right.values() & nulls.inner(),
         None => right.values().clone(),
     };
+    null_where_set(left, &right)
+}
+
+/// Returns a new array with the same values and the validity bit to false where
+/// the corresponding element of `mask` is false.
+///
+/// This is the inverse of [`nullif`]: values are kept where `mask` is true, and
+/// nulled out where it is false. Unlike [`filter`](crate::filter::filter), the
+/// length of the array is preserved. Null elements of `mask` keep the value.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, BooleanArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::nullif::nullif_where;
+/// // input is [null, 8, 1, 9]
+/// let a = Int32Array::from(vec![None, Some(8), Some(1), Some(9)]);
+/// // use nullif_where to set index 2 to null
+/// let mask = BooleanArray::from(vec![Some(true), Some(true), Some(false), None]);
+/// let masked = nullif_where(&a, &mask).unwrap();
+/// // The resulting array is [null, 8, null, 9]
+/// assert_eq!(masked.as_primitive(), &Int32Array::from(vec![None, Some(8), None, Some(9)]));
+/// ```
+pub fn nullif_where(array: &dyn Array, mask: &BooleanArray) -> Result<ArrayRef, ArrowError> {
+    // Compute !mask_values & mask_bitmap
+    let mask = match mask.nulls() {
+        Some(nulls) => &!mask.values() & nulls.inner(),
+        None => !mask.values(),
+    };
+    null_where_set(array, &mask)
+}
+
+/// Returns a new array with the same values as `values` and the validity given
+/// by `validity`, where elements of `validity` that are false or null are null
+///
+/// This combines values and a validity mask computed separately, for example
+/// before writing them, without copying the values.
+///
+/// Returns an error if the lengths of `values` and `validity` differ, or if an
+/// element of `values` is null where `validity` is true, as the value for that
+/// element is unknown. Use [`nullif_where`] to instead keep such elements null.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, BooleanArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_select::nullif::apply_validity;
+/// let values = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+/// let validity = BooleanArray::from(vec![Some(true), Some(false), Some(false), None]);
+/// let array = apply_validity(&values, &validity).unwrap();
+/// assert_eq!(array.as_primitive(), &Int32Array::from(vec![Some(1), None, None, None]));
+///
+/// // The value of element 2 is null, and so it cannot be valid
+/// let validity = BooleanArray::from(vec![true, true, true, true]);
+/// let err = apply_validity(&values, &validity).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid argument error: Cannot apply validity to element 2, which is null"
+/// );
+/// ```
+pub fn apply_validity(values: &dyn Array, validity: &BooleanArray) -> Result<ArrayRef, ArrowError> {
+    if values.len() != validity.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot apply validity of length {} to array of length {}",
+            validity.len(),
+            values.len()
+        )));
+    }
+
+    // Compute validity_values & validity_bitmap
+    let valid = match validity.nulls() {
+        Some(nulls) => validity.values() & nulls.inner(),
+        None => validity.values().clone(),
+    };
+
+    // Any element that is valid must have a value
+    if let Some(nulls) = values.logical_nulls() {
+        let conflicts = &valid & &!nulls.inner();
+        if let Some(idx) = conflicts.set_indices().next() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot apply validity to element {idx}, which is null"
+            )));
+        }
+    }
+    null_where_set(values, &!&valid)
+}
+
+/// Returns a new array with the same values as `left` and the validity bit to
+/// false where `right` is set
+fn null_where_set(left: &dyn Array, right: &BooleanBuffer) -> Result<ArrayRef, ArrowError> {
+    let left_data = left.to_data();
+
+    if left_data.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length".to_string(),
+        ));
+    }
+    let len = left_data.len();
+
+    if len == 0 || left_data.data_type() == &DataType::Null {
+        return Ok(make_array(left_data));
+    }
 
     // Compute left null bitmap & !right
 
@@ -117,7 +207,7 @@ mod tests {
     use arrow_array::builder::{BooleanBuilder, Int32Builder, StructBuilder};
     use arrow_array::cast::AsArray;
     use arrow_array::types::Int32Type;
-    use arrow_array::{Int32Array, NullArray, StringArray, StructArray};
+    use arrow_array::{Int32Array, NullArray, StringArray, StringViewArray, StructArray};
     use arrow_data::ArrayData;
     use arrow_schema::{Field, Fields};
     use rand::{Rng, rng};
@@ -478,6 +568,156 @@ mod tests {
         assert_eq!(res.as_ref(), &a);
     }
 
+    #[test]
+    fn test_nullif_where_int_array() {
+        let a = Int32Array::from(vec![Some(15), None, Some(8), Some(1), Some(9), None]);
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            None,
+        ]);
+        let res = nullif_where(&a, &mask).unwrap();
+        let expected = Int32Array::from(vec![Some(15), None, None, Some(1), None, None]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+
+        // mask without nulls
+        let mask = BooleanArray::from(vec![true, false, true, false, true, true]);
+        let res = nullif_where(&a, &mask).unwrap();
+        let expected = Int32Array::from(vec![Some(15), None, Some(8), None, Some(9), None]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+
+        // sliced inputs
+        let res = nullif_where(&a.slice(1, 4), &mask.slice(2, 4)).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(1), Some(9)]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+
+        let err = nullif_where(&a, &mask.slice(0, 3)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform comparison operation on arrays of different length"
+        );
+    }
+
+    #[test]
+    fn test_nullif_where_string_view() {
+        let s = StringViewArray::from(vec![
+            Some("hello"),
+            None,
+            Some("a string longer than twelve bytes"),
+            Some("world"),
+        ]);
+        let mask = BooleanArray::from(vec![Some(false), Some(true), Some(true), None]);
+        let res = nullif_where(&s, &mask).unwrap();
+        res.to_data().validate_full().unwrap();
+
+        let expected = StringViewArray::from(vec![
+            None,
+            None,
+            Some("a string longer than twelve bytes"),
+            Some("world"),
+        ]);
+        assert_eq!(res.as_string_view(), &expected);
+    }
+
+    #[test]
+    fn test_nullif_where_no_nulls() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let mask = BooleanArray::from(vec![true, true, true]);
+        let res = nullif_where(&a, &mask).unwrap();
+        assert_eq!(res.as_ref(), &a);
+        assert_eq!(res.null_count(), 0);
+
+        let mask = BooleanArray::from(vec![false, false, false]);
+        let res = nullif_where(&a, &mask).unwrap();
+        assert_eq!(res.null_count(), 3);
+    }
+
+    #[test]
+    fn test_apply_validity_int_array() {
+        let a = Int32Array::from(vec![1, 2, 3, 4, 5]);
+        let validity = BooleanArray::from(vec![Some(true), Some(false), None, Some(true), None]);
+        let res = apply_validity(&a, &validity).unwrap();
+        res.to_data().validate_full().unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(4), None]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+        // The values are not copied
+        assert_eq!(
+            res.as_primitive::<Int32Type>().values().as_ptr(),
+            a.values().as_ptr()
+        );
+
+        // Nulls of the values are merged with the validity, if consistent
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let validity = BooleanArray::from(vec![true, false, false, false]);
+        let res = apply_validity(&a, &validity).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, None]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+
+        // sliced inputs
+        let validity = BooleanArray::from(vec![false, true, false, true, false]);
+        let res = apply_validity(&a.slice(2, 2), &validity.slice(1, 2)).unwrap();
+        let expected = Int32Array::from(vec![Some(3), None]);
+        assert_eq!(res.as_primitive::<Int32Type>(), &expected);
+
+        let err = apply_validity(&a.slice(1, 3), &validity.slice(1, 3)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot apply validity to element 0, which is null"
+        );
+
+        let err = apply_validity(&a, &validity).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot apply validity of length 5 to array of length 4"
+        );
+    }
+
+    #[test]
+    fn test_apply_validity_string_view() {
+        let s = StringViewArray::from(vec![
+            Some("hello"),
+            None,
+            Some("a string longer than twelve bytes"),
+            Some("world"),
+        ]);
+        let validity = BooleanArray::from(vec![Some(false), Some(false), Some(true), None]);
+        let res = apply_validity(&s, &validity).unwrap();
+        res.to_data().validate_full().unwrap();
+
+        let expected = StringViewArray::from(vec![
+            None,
+            None,
+            Some("a string longer than twelve bytes"),
+            None,
+        ]);
+        assert_eq!(res.as_string_view(), &expected);
+
+        let validity = BooleanArray::from(vec![true, true, true, true]);
+        let err = apply_validity(&s, &validity).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot apply validity to element 1, which is null"
+        );
+    }
+
+    #[test]
+    fn test_apply_validity_null_array() {
+        let a = NullArray::new(3);
+        let validity = BooleanArray::from(vec![false, false, false]);
+        let res = apply_validity(&a, &validity).unwrap();
+        assert_eq!(res.as_ref(), &a);
+
+        let validity = BooleanArray::from(vec![false, true, false]);
+        let err = apply_validity(&a, &validity).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot apply validity to element 1, which is null"
+        );
+    }
+
     fn test_nullif(values: &Int32Array, filter: &BooleanArray) {
         let expected: Int32Array = values
             .iter()
@@ -959,6 +959,14 @@ to_indices_reinterpret!(Int64Type, UInt64Type);
 ///
 /// This function will call [`take`] on each array of the [`RecordBatch`] and assemble a new [`RecordBatch`].
 ///
+/// Null indices produce rows of nulls, and the schema of the returned batch is that of
+/// `record_batch`.
+///
+/// # Errors
+/// This function errors whenever:
+/// * An index is out of bounds of the rows of `record_batch`
+/// * `indices` contains nulls and `record_batch` has a non-nullable column
+///
 /// # Example
 /// ```
 /// # use std::sync::Arc;
@@ -998,12 +1006,14 @@ pub fn take_record_batch(
     record_batch: &RecordBatch,
     indices: &dyn Array,
 ) -> Result<RecordBatch, ArrowError> {
-    let columns = record_batch
-        .columns()
-        .iter()
-        .map(|c| take(c, indices, None))
-        .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(record_batch.schema(), columns)
+    // Check the bounds once, rather than for each column
+    downcast_integer_array!(
+        indices => check_bounds(record_batch.num_rows(), indices)?,
+        d => return Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+    );
+    let columns = take_arrays(record_batch.columns(), indices, None)?;
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(record_batch.schema(), columns, &options)
 }
 
 #[cfg(test)]
@@ -1012,7 +1022,7 @@ mod tests {
     use arrow_array::builder::*;
     use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
     use arrow_data::ArrayData;
-    use arrow_schema::{Field, Fields, TimeUnit, UnionFields};
+    use arrow_schema::{Field, Fields, Schema, TimeUnit, UnionFields};
     use num_traits::ToPrimitive;
 
     fn test_take_decimal_arrays(
@@ -2590,4 +2600,101 @@ mod tests {
             Err(ArrowError::OffsetOverflowError(_))
         ));
     }
+
+    #[test]
+    fn test_take_record_batch() {
+        let schema = Arc::new(
+            Schema::new(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ])
+            .with_metadata([("k".to_string(), "v".to_string())].into()),
+        );
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(0), None, Some(2), Some(3)])),
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])),
+            ],
+        )
+        .unwrap();
+
+        // Out of order and repeated indices
+        let indices = UInt32Array::from(vec![3, 0, 2, 1, 3]);
+        let taken = take_record_batch(&batch, &indices).unwrap();
+        let expected = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(3),
+                    Some(0),
+                    Some(2),
+                    None,
+                    Some(3),
+                ])),
+                Arc::new(StringArray::from(vec!["d", "a", "c", "b", "d"])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(taken, expected);
+
+        // Null indices produce null rows
+        let indices = Int64Array::from(vec![Some(2), None, Some(0)]);
+        let taken = take_record_batch(&batch, &indices).unwrap();
+        let expected = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(2), None, Some(0)])),
+                Arc::new(StringArray::from(vec![Some("c"), None, Some("a")])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(taken, expected);
+
+        let indices = UInt32Array::from(vec![0, 4]);
+        let err = take_record_batch(&batch, &indices).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 4 from 4 entries"
+        );
+
+        let indices = Float32Array::from(vec![0.0]);
+        let err = take_record_batch(&batch, &indices).unwrap_err();
+        assert!(
+            err.to_string().contains("Take only supported for integers"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_take_record_batch_non_nullable() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![1, 2]))]).unwrap();
+
+        let taken = take_record_batch(&batch, &UInt32Array::from(vec![1, 0])).unwrap();
+        assert_eq!(taken.schema(), batch.schema());
+        assert_eq!(
+            taken.column(0).as_primitive::<Int32Type>().values(),
+            &[2, 1]
+        );
+
+        let indices = UInt32Array::from(vec![Some(1), None]);
+        let err = take_record_batch(&batch, &indices).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column 'a' is declared as non-nullable but contains null values"
+        );
+    }
+
+    #[test]
+    fn test_take_record_batch_no_columns() {
+        let options = RecordBatchOptions::new().with_row_count(Some(3));
+        let batch =
+            RecordBatch::try_new_with_options(Arc::new(Schema::empty()), vec![], &options).unwrap();
+        let taken = take_record_batch(&batch, &UInt32Array::from(vec![2, 2, 0, 1])).unwrap();
+        assert_eq!(taken.num_rows(), 4);
+        assert_eq!(taken.num_columns(), 0);
+        assert!(take_record_batch(&batch, &UInt32Array::from(vec![3])).is_err());
+    }
 }
@@ -19,7 +19,7 @@
 
 use arrow_array::*;
 use arrow_array::{cast::AsArray, types::*};
-use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, NullBuffer, OffsetBuffer};
 use arrow_schema::{ArrowError, DataType};
 use std::sync::Arc;
 
@@ -167,6 +167,81 @@ pub fn bit_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+fn not_empty_offsets<O: ArrowNativeType>(offsets: &OffsetBuffer<O>) -> BooleanBuffer {
+    BooleanBuffer::collect_bool(offsets.len() - 1, |i| offsets[i + 1] != offsets[i])
+}
+
+fn not_empty_views(views: &[u128]) -> BooleanBuffer {
+    // The length of a view is stored in its lower 32 bits
+    BooleanBuffer::collect_bool(views.len(), |i| views[i] as u32 != 0)
+}
+
+/// Returns a [`BooleanArray`] that is `true` where the value in the array is not empty.
+///
+/// This is equivalent to `length(array) > 0`, but determines the result directly from
+/// the offsets or views of the array, without computing the lengths or accessing
+/// the values.
+///
+/// * this only accepts StringArray/LargeStringArray/StringViewArray,
+///   BinaryArray/LargeBinaryArray/BinaryViewArray and FixedSizeBinaryArray,
+///   or DictionaryArray with above Arrays as values
+/// * if `nulls_as_false` is `false`, not_empty of null is null, otherwise it is `false`
+///   and the returned array has no nulls. The latter is convenient when the result is
+///   used as a filter predicate.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, StringViewArray};
+/// # use arrow_string::length::not_empty;
+/// let array = StringViewArray::from(vec![Some("foo"), Some(""), None]);
+///
+/// let result = not_empty(&array, false).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), Some(false), None]));
+///
+/// let result = not_empty(&array, true).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![true, false, false]));
+/// ```
+pub fn not_empty(array: &dyn Array, nulls_as_false: bool) -> Result<BooleanArray, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = not_empty(d.values().as_ref(), false)?;
+        let result = arrow_select::take::take(&values, d.keys(), None)?;
+        let result = result.as_boolean();
+        return Ok(match nulls_as_false {
+            true => BooleanArray::new(not_empty_valid(result), None),
+            false => result.clone(),
+        });
+    }
+
+    let values = match array.data_type() {
+        DataType::Utf8 => not_empty_offsets(array.as_string::<i32>().offsets()),
+        DataType::LargeUtf8 => not_empty_offsets(array.as_string::<i64>().offsets()),
+        DataType::Utf8View => not_empty_views(array.as_string_view().views()),
+        DataType::Binary => not_empty_offsets(array.as_binary::<i32>().offsets()),
+        DataType::LargeBinary => not_empty_offsets(array.as_binary::<i64>().offsets()),
+        DataType::BinaryView => not_empty_views(array.as_binary_view().views()),
+        DataType::FixedSizeBinary(0) => BooleanBuffer::new_unset(array.len()),
+        DataType::FixedSizeBinary(_) => BooleanBuffer::new_set(array.len()),
+        other => {
+            return Err(ArrowError::ComputeError(format!(
+                "not_empty not supported for {other:?}"
+            )));
+        }
+    };
+
+    let result = BooleanArray::new(values, array.nulls().cloned());
+    Ok(match nulls_as_false {
+        true => BooleanArray::new(not_empty_valid(&result), None),
+        false => result,
+    })
+}
+
+/// Returns the values of `array` with any null slots set to `false`
+fn not_empty_valid(array: &BooleanArray) -> BooleanBuffer {
+    match array.nulls() {
+        Some(nulls) => array.values() & nulls.inner(),
+        None => array.values().clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -733,4 +808,85 @@ mod tests {
         let result = bit_length(&array).unwrap();
         assert_eq!(result.as_ref(), &Int32Array::from(vec![32; 4]));
     }
+
+    fn not_empty_cases() -> (Vec<Option<&'static str>>, Vec<Option<bool>>) {
+        let values = vec![
+            Some("one"),
+            Some(""),
+            None,
+            Some("this is a longer string to test string array with"),
+            Some(""),
+        ];
+        let expected = values.iter().map(|v| v.map(|v| !v.is_empty())).collect();
+        (values, expected)
+    }
+
+    #[test]
+    fn not_empty_string() {
+        let (values, expected) = not_empty_cases();
+        let expected = BooleanArray::from(expected);
+        let expected_false = BooleanArray::from(vec![true, false, false, true, false]);
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(values.clone())),
+            Arc::new(LargeStringArray::from(values.clone())),
+            Arc::new(StringViewArray::from(values.clone())),
+            Arc::new(BinaryArray::from_iter(
+                values.iter().map(|v| v.map(str::as_bytes)),
+            )),
+            Arc::new(LargeBinaryArray::from_iter(
+                values.iter().map(|v| v.map(str::as_bytes)),
+            )),
+            Arc::new(BinaryViewArray::from_iter(
+                values.iter().map(|v| v.map(str::as_bytes)),
+            )),
+            Arc::new(
+                values
+                    .iter()
+                    .copied()
+                    .collect::<DictionaryArray<Int8Type>>(),
+            ),
+        ];
+
+        for array in arrays {
+            assert_eq!(not_empty(&array, false).unwrap(), expected, "{array:?}");
+            assert_eq!(
+                not_empty(&array, true).unwrap(),
+                expected_false,
+                "{array:?}"
+            );
+
+            let sliced = array.slice(1, 3);
+            assert_eq!(not_empty(&sliced, false).unwrap(), expected.slice(1, 3));
+            assert_eq!(
+                not_empty(&sliced, true).unwrap(),
+                expected_false.slice(1, 3)
+            );
+        }
+    }
+
+    #[test]
+    fn not_empty_fixed_size_binary() {
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = FixedSizeBinaryArray::new(4, [0; 12].into(), Some(nulls.clone()));
+        let result = not_empty(&array, false).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), None, Some(true)])
+        );
+
+        let array = FixedSizeBinaryArray::new_null(0, 3);
+        let result = not_empty(&array, true).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false; 3]));
+    }
+
+    #[test]
+    fn not_empty_wrong_type() {
+        let array: UInt64Array = vec![1u64].into();
+        let err = not_empty(&array, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: not_empty not supported for UInt64"
+        );
+    }
 }
@@ -64,7 +64,13 @@ fn add_benchmark(c: &mut Criterion) {
             .bench_function("min nonnull", |b| b.iter(|| min_string(&nonnull_strings)))
             .bench_function("max nonnull", |b| b.iter(|| max_string(&nonnull_strings)))
             .bench_function("min nullable", |b| b.iter(|| min_string(&nullable_strings)))
-            .bench_function("max nullable", |b| b.iter(|| max_string(&nullable_strings)));
+            .bench_function("max nullable", |b| b.iter(|| max_string(&nullable_strings)))
+            .bench_function("approx_distinct_count nonnull", |b| {
+                b.iter(|| approx_distinct_count(&nonnull_strings))
+            })
+            .bench_function("approx_distinct_count nullable", |b| {
+                b.iter(|| approx_distinct_count(&nullable_strings))
+            });
     }
 
     {
@@ -83,6 +89,12 @@ fn add_benchmark(c: &mut Criterion) {
             })
             .bench_function("max nullable", |b| {
                 b.iter(|| max_string_view(&nullable_strings))
+            })
+            .bench_function("approx_distinct_count nonnull", |b| {
+                b.iter(|| approx_distinct_count(&nonnull_strings))
+            })
+            .bench_function("approx_distinct_count nullable", |b| {
+                b.iter(|| approx_distinct_count(&nullable_strings))
             });
     }
 
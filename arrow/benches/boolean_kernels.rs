@@ -19,12 +19,13 @@
 extern crate criterion;
 use criterion::Criterion;
 
-use arrow::util::bench_util::create_boolean_array;
+use arrow::util::bench_util::{create_boolean_array, create_primitive_array};
 
 extern crate arrow;
 
 use arrow::array::*;
 use arrow::compute::kernels::boolean as boolean_kernels;
+use arrow::datatypes::Int32Type;
 use std::hint;
 
 fn bench_and(lhs: &BooleanArray, rhs: &BooleanArray) {
@@ -39,6 +40,19 @@ fn bench_not(array: &BooleanArray) {
     hint::black_box(boolean_kernels::not(array).unwrap());
 }
 
+fn bench_is_null(array: &dyn Array) {
+    hint::black_box(boolean_kernels::is_null(array).unwrap());
+}
+
+fn bench_is_not_null(array: &dyn Array) {
+    hint::black_box(boolean_kernels::is_not_null(array).unwrap());
+}
+
+fn bench_is_not_null_loop(array: &dyn Array) {
+    let result: BooleanArray = (0..array.len()).map(|i| Some(array.is_valid(i))).collect();
+    hint::black_box(result);
+}
+
 fn add_benchmark(c: &mut Criterion) {
     let size = 2usize.pow(15);
     let array1 = create_boolean_array(size, 0.0, 0.5);
@@ -65,6 +79,21 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_or(array1_slice, array2_slice))
     });
     c.bench_function("not_sliced", |b| b.iter(|| bench_not(array1_slice)));
+
+    let nullable = create_primitive_array::<Int32Type>(size, 0.5);
+    let non_nullable = create_primitive_array::<Int32Type>(size, 0.0);
+    let nullable_slice = nullable.slice(1, size - 1);
+    c.bench_function("is_null", |b| b.iter(|| bench_is_null(&nullable)));
+    c.bench_function("is_not_null", |b| b.iter(|| bench_is_not_null(&nullable)));
+    c.bench_function("is_not_null_sliced", |b| {
+        b.iter(|| bench_is_not_null(&nullable_slice))
+    });
+    c.bench_function("is_not_null_no_nulls", |b| {
+        b.iter(|| bench_is_not_null(&non_nullable))
+    });
+    c.bench_function("is_not_null_loop", |b| {
+        b.iter(|| bench_is_not_null_loop(&nullable))
+    });
 }
 
 criterion_group!(benches, add_benchmark);
@@ -108,6 +108,171 @@ fn bench_string(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_string_view_deduplicate(c: &mut Criterion) {
+    // 10 distinct values, all longer than the 12 byte inline threshold
+    let values: Vec<String> = (0..10)
+        .map(|i| format!("low cardinality string value {i}"))
+        .collect();
+    let mut group = c.benchmark_group("bench_string_view_deduplicate");
+    group.throughput(Throughput::Bytes(
+        ((BATCH_SIZE * NUM_BATCHES * values[0].len()) as u32).into(),
+    ));
+    for deduplicate in [false, true] {
+        group.bench_function(format!("deduplicate: {deduplicate}"), |b| {
+            b.iter(|| {
+                let mut builder = StringViewBuilder::new();
+                if deduplicate {
+                    builder = builder.with_deduplicate_strings();
+                }
+                for i in 0..NUM_BATCHES * BATCH_SIZE {
+                    builder.append_value(&values[i % values.len()]);
+                }
+                hint::black_box(builder.finish());
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_append_array(c: &mut Criterion) {
+    let mut rng = seedable_rng();
+    let primitive: Int64Array = (0..BATCH_SIZE)
+        .map(|_| rng.random_bool(0.9).then(|| rng.random::<i64>()))
+        .collect();
+    let string: StringArray = (0..BATCH_SIZE)
+        .map(|i| rng.random_bool(0.9).then(|| format!("value {i}")))
+        .collect();
+    let list = ListArray::from_iter_primitive::<types::Int64Type, _, _>((0..BATCH_SIZE).map(|i| {
+        rng.random_bool(0.9)
+            .then(|| (0..i % 8).map(|j| Some(j as i64)).collect::<Vec<_>>())
+    }));
+
+    let mut group = c.benchmark_group("bench_append_array");
+    group.bench_function("primitive: append_array", |b| {
+        b.iter(|| {
+            let mut builder = Int64Builder::with_capacity(BATCH_SIZE * NUM_BATCHES);
+            for _ in 0..NUM_BATCHES {
+                builder.append_array(&primitive);
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("primitive: append_option", |b| {
+        b.iter(|| {
+            let mut builder = Int64Builder::with_capacity(BATCH_SIZE * NUM_BATCHES);
+            for _ in 0..NUM_BATCHES {
+                primitive.iter().for_each(|v| builder.append_option(v));
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("string: append_array", |b| {
+        b.iter(|| {
+            let mut builder = StringBuilder::new();
+            for _ in 0..NUM_BATCHES {
+                builder.append_array(&string).unwrap();
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("string: append_option", |b| {
+        b.iter(|| {
+            let mut builder = StringBuilder::new();
+            for _ in 0..NUM_BATCHES {
+                string.iter().for_each(|v| builder.append_option(v));
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("list: append_array", |b| {
+        b.iter(|| {
+            let mut builder = ListBuilder::new(Int64Builder::new());
+            for _ in 0..NUM_BATCHES {
+                builder.append_array(&list).unwrap();
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("list: append_option", |b| {
+        b.iter(|| {
+            let mut builder = ListBuilder::new(Int64Builder::new());
+            for _ in 0..NUM_BATCHES {
+                list.iter().for_each(|v| {
+                    builder.append_option(v.map(|v| {
+                        v.as_primitive::<types::Int64Type>()
+                            .iter()
+                            .collect::<Vec<_>>()
+                    }))
+                });
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.finish();
+}
+
+fn bench_append_trusted_len(c: &mut Criterion) {
+    let values: Vec<i64> = (0..BATCH_SIZE as i64).collect();
+    let strings: Vec<String> = (0..BATCH_SIZE).map(|i| format!("value {i}")).collect();
+
+    let mut group = c.benchmark_group("bench_append_trusted_len");
+    group.bench_function("primitive: append_value", |b| {
+        b.iter(|| {
+            let mut builder = Int64Builder::new();
+            for _ in 0..NUM_BATCHES {
+                values.iter().for_each(|v| builder.append_value(*v));
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("primitive: append_trusted_len_iter", |b| {
+        b.iter(|| {
+            let mut builder = Int64Builder::new();
+            for _ in 0..NUM_BATCHES {
+                unsafe { builder.append_trusted_len_iter(values.iter().copied()) };
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("primitive: append_slice", |b| {
+        b.iter(|| {
+            let mut builder = Int64Builder::new();
+            for _ in 0..NUM_BATCHES {
+                builder.append_slice(&values);
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("string: append_value", |b| {
+        b.iter(|| {
+            let mut builder = StringBuilder::new();
+            for _ in 0..NUM_BATCHES {
+                strings.iter().for_each(|v| builder.append_value(v));
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("string: append_trusted_len_iter", |b| {
+        b.iter(|| {
+            let mut builder = StringBuilder::new();
+            for _ in 0..NUM_BATCHES {
+                unsafe { builder.append_trusted_len_iter(strings.iter()) };
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.bench_function("string: append_slice", |b| {
+        b.iter(|| {
+            let mut builder = StringBuilder::new();
+            for _ in 0..NUM_BATCHES {
+                builder.append_slice(&strings);
+            }
+            hint::black_box(builder.finish());
+        })
+    });
+    group.finish();
+}
+
 fn bench_decimal32(c: &mut Criterion) {
     c.bench_function("bench_decimal32_builder", |b| {
         b.iter(|| {
@@ -187,6 +352,9 @@ criterion_group!(
     bench_primitive_nulls,
     bench_bool,
     bench_string,
+    bench_string_view_deduplicate,
+    bench_append_array,
+    bench_append_trusted_len,
     bench_decimal32,
     bench_decimal64,
     bench_decimal128,
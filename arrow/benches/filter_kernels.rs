@@ -18,7 +18,7 @@ extern crate arrow;
 
 use std::sync::Arc;
 
-use arrow::compute::{FilterBuilder, FilterPredicate, filter_record_batch};
+use arrow::compute::{FilterBuilder, FilterPredicate, filter_indices, filter_record_batch};
 use arrow::util::bench_util::*;
 
 use arrow::array::*;
@@ -37,6 +37,15 @@ fn bench_built_filter(filter: &FilterPredicate, array: &dyn Array) {
     hint::black_box(filter.filter(array).unwrap());
 }
 
+/// Builds the indices of the `true` elements of `filter` without [`filter_indices`]
+fn manual_filter_indices(filter: &BooleanArray) -> UInt32Array {
+    filter
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, v)| v.unwrap_or(false).then_some(idx as u32))
+        .collect()
+}
+
 fn add_benchmark(c: &mut Criterion) {
     let size = 65536;
     let filter_array = create_boolean_array(size, 0.0, 0.5);
@@ -61,6 +70,19 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| FilterBuilder::new(&sparse_filter_array).optimize().build())
     });
 
+    for (name, filter_array) in [
+        ("kept 1/2", &filter_array),
+        ("high selectivity (kept 1023/1024)", &dense_filter_array),
+        ("low selectivity (kept 1/1024)", &sparse_filter_array),
+    ] {
+        c.bench_function(&format!("filter_indices {name}"), |b| {
+            b.iter(|| hint::black_box(filter_indices(filter_array).unwrap()))
+        });
+        c.bench_function(&format!("manual filter indices {name}"), |b| {
+            b.iter(|| hint::black_box(manual_filter_indices(filter_array)))
+        });
+    }
+
     c.bench_function("filter u8 (kept 1/2)", |b| {
         b.iter(|| bench_filter(&data_array, &filter_array))
     });
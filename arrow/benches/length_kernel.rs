@@ -22,7 +22,7 @@ use criterion::Criterion;
 extern crate arrow;
 
 use arrow::array::*;
-use arrow::compute::kernels::length::length;
+use arrow::compute::kernels::length::{length, not_empty};
 use std::hint;
 
 fn bench_length(array: &StringArray) {
@@ -42,6 +42,24 @@ fn add_benchmark(c: &mut Criterion) {
     let array = StringArray::from(values);
 
     c.bench_function("length", |b| b.iter(|| bench_length(&array)));
+
+    let view_array = StringViewArray::from_iter(array.iter());
+    c.bench_function("not_empty utf8", |b| {
+        b.iter(|| hint::black_box(not_empty(&array, true).unwrap()))
+    });
+    c.bench_function("not_empty utf8view", |b| {
+        b.iter(|| hint::black_box(not_empty(&view_array, true).unwrap()))
+    });
+    // The equivalent hand written loop over the values
+    c.bench_function("not_empty utf8view loop", |b| {
+        b.iter(|| {
+            let result: BooleanArray = view_array
+                .iter()
+                .map(|v| Some(v.is_some_and(|v| !v.is_empty())))
+                .collect();
+            hint::black_box(result)
+        })
+    });
 }
 
 criterion_group!(benches, add_benchmark);
@@ -1400,7 +1400,7 @@ fn test_struct_equal_slice() {
 fn test_list_excess_children_equal() {
     let mut a = ListBuilder::new(FixedSizeBinaryBuilder::new(5));
     a.values().append_value(b"11111").unwrap(); // Masked value
-    a.append_null();
+    a.append(false);
     a.values().append_value(b"22222").unwrap();
     a.values().append_null();
     a.append(true);
@@ -24,11 +24,11 @@ use half::f16;
 use num_bigint::BigInt;
 use num_traits::FromPrimitive;
 use parquet::arrow::array_reader::{
-    ListArrayReader, make_byte_array_reader, make_byte_view_array_reader,
-    make_fixed_len_byte_array_reader,
+    ListArrayReader, make_boolean_array_reader, make_byte_array_reader,
+    make_byte_view_array_reader, make_fixed_len_byte_array_reader,
 };
 use parquet::basic::Type;
-use parquet::data_type::{ByteArray, FixedLenByteArrayType};
+use parquet::data_type::{BoolType, ByteArray, FixedLenByteArrayType};
 use parquet::util::{DataPageBuilder, DataPageBuilderImpl, InMemoryPageIterator};
 use parquet::{
     arrow::array_reader::ArrayReader,
@@ -88,6 +88,8 @@ fn build_test_schema() -> SchemaDescPtr {
             OPTIONAL INT32 optional_int16_leaf (INTEGER(16, true));
             REQUIRED INT64 mandatory_uint64_leaf (INTEGER(64, false));
             OPTIONAL INT64 optional_uint64_leaf (INTEGER(64, false));
+            REQUIRED BOOLEAN mandatory_bool_leaf;
+            OPTIONAL BOOLEAN optional_bool_leaf;
         }
         ";
     parse_message_type(message_type)
@@ -320,6 +322,54 @@ where
     InMemoryPageIterator::new(pages)
 }
 
+/// Builds pages of booleans where each value is repeated `run_length` times,
+/// or is random if `run_length` is 1
+fn build_encoded_bool_page_iterator(
+    column_desc: ColumnDescPtr,
+    null_density: f32,
+    encoding: Encoding,
+    run_length: usize,
+) -> impl PageIterator + Clone {
+    let max_def_level = column_desc.max_def_level();
+    let max_rep_level = column_desc.max_rep_level();
+    let rep_levels = vec![0; VALUES_PER_PAGE];
+    let mut rng = seedable_rng();
+    let mut pages: Vec<Vec<parquet::column::page::Page>> = Vec::new();
+    for _i in 0..NUM_ROW_GROUPS {
+        let mut column_chunk_pages = Vec::new();
+        for _j in 0..PAGES_PER_GROUP {
+            // generate page
+            let mut values = Vec::with_capacity(VALUES_PER_PAGE);
+            let mut def_levels = Vec::with_capacity(VALUES_PER_PAGE);
+            let mut value = false;
+            for k in 0..VALUES_PER_PAGE {
+                value = match run_length {
+                    1 => rng.random(),
+                    _ => value ^ (k % run_length == 0),
+                };
+                let def_level = if rng.random::<f32>() < null_density {
+                    max_def_level - 1
+                } else {
+                    max_def_level
+                };
+                if def_level == max_def_level {
+                    values.push(value);
+                }
+                def_levels.push(def_level);
+            }
+            let mut page_builder =
+                DataPageBuilderImpl::new(column_desc.clone(), values.len() as u32, true);
+            page_builder.add_rep_levels(max_rep_level, &rep_levels);
+            page_builder.add_def_levels(max_def_level, &def_levels);
+            page_builder.add_values::<BoolType>(encoding, &values);
+            column_chunk_pages.push(page_builder.consume());
+        }
+        pages.push(column_chunk_pages);
+    }
+
+    InMemoryPageIterator::new(pages)
+}
+
 fn build_dictionary_encoded_primitive_page_iterator<T>(
     column_desc: ColumnDescPtr,
     null_density: f32,
@@ -620,6 +670,13 @@ fn create_primitive_array_reader(
     }
 }
 
+fn create_boolean_array_reader(
+    page_iterator: impl PageIterator + 'static,
+    column_desc: ColumnDescPtr,
+) -> Box<dyn ArrayReader> {
+    make_boolean_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
+}
+
 fn create_f16_by_bytes_reader(
     page_iterator: impl PageIterator + 'static,
     column_desc: ColumnDescPtr,
@@ -640,7 +697,7 @@ fn create_decimal_by_bytes_reader(
     let physical_type = column_desc.physical_type();
     match physical_type {
         Type::BYTE_ARRAY => {
-            make_byte_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
+            make_byte_array_reader(Box::new(page_iterator), column_desc, None, None).unwrap()
         }
         Type::FIXED_LEN_BYTE_ARRAY => {
             make_fixed_len_byte_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
@@ -660,21 +717,21 @@ fn create_byte_array_reader(
     page_iterator: impl PageIterator + 'static,
     column_desc: ColumnDescPtr,
 ) -> Box<dyn ArrayReader> {
-    make_byte_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
+    make_byte_array_reader(Box::new(page_iterator), column_desc, None, None).unwrap()
 }
 
 fn create_byte_view_array_reader(
     page_iterator: impl PageIterator + 'static,
     column_desc: ColumnDescPtr,
 ) -> Box<dyn ArrayReader> {
-    make_byte_view_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
+    make_byte_view_array_reader(Box::new(page_iterator), column_desc, None, None).unwrap()
 }
 
 fn create_string_view_byte_array_reader(
     page_iterator: impl PageIterator + 'static,
     column_desc: ColumnDescPtr,
 ) -> Box<dyn ArrayReader> {
-    make_byte_view_array_reader(Box::new(page_iterator), column_desc, None).unwrap()
+    make_byte_view_array_reader(Box::new(page_iterator), column_desc, None, None).unwrap()
 }
 
 fn create_string_byte_array_dictionary_reader(
@@ -1204,6 +1261,44 @@ fn float16_benches(c: &mut Criterion) {
     group.finish();
 }
 
+fn boolean_benches(c: &mut Criterion) {
+    let schema = build_test_schema();
+    let mandatory_bool_leaf_desc = schema.column(39);
+    let optional_bool_leaf_desc = schema.column(40);
+    let mut count: usize = 0;
+
+    let mut group = c.benchmark_group("arrow_array_reader/BOOLEAN/BooleanArray");
+    for encoding in [Encoding::PLAIN, Encoding::RLE] {
+        let encoding_name = encoding.to_string().to_lowercase();
+        for (run_name, run_length) in [("random", 1), ("runs of 1000", 1000)] {
+            let cases = [
+                ("mandatory, no NULLs", &mandatory_bool_leaf_desc, 0.0),
+                ("optional, half NULLs", &optional_bool_leaf_desc, 0.5),
+            ];
+            for (name, column_desc, null_density) in cases {
+                let data = build_encoded_bool_page_iterator(
+                    column_desc.clone(),
+                    null_density,
+                    encoding,
+                    run_length,
+                );
+                group.bench_function(
+                    format!("{encoding_name} encoded, {run_name}, {name}"),
+                    |b| {
+                        b.iter(|| {
+                            let array_reader =
+                                create_boolean_array_reader(data.clone(), column_desc.clone());
+                            count = bench_array_reader(array_reader);
+                        });
+                        assert_eq!(count, EXPECTED_VALUE_COUNT);
+                    },
+                );
+            }
+        }
+    }
+    group.finish();
+}
+
 fn decimal_benches(c: &mut Criterion) {
     let schema = build_test_schema();
     // parquet int32, logical type decimal(8,2)
@@ -1881,5 +1976,11 @@ fn add_benches(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, add_benches, decimal_benches, float16_benches,);
+criterion_group!(
+    benches,
+    add_benches,
+    decimal_benches,
+    float16_benches,
+    boolean_benches,
+);
 criterion_main!(benches);
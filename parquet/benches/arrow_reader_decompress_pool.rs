@@ -0,0 +1,131 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks reading a multi-column ZSTD compressed file with and without
+//! [`ArrowReaderOptions::with_decompress_pool`]
+
+use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray};
+use bytes::Bytes;
+use criterion::*;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::{ArrowReaderOptions, Executor, ParquetRecordBatchReaderBuilder};
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use rand::Rng;
+use std::hint;
+use std::sync::mpsc::{Sender, channel};
+use std::sync::{Arc, Mutex};
+
+const NUM_COLUMNS: usize = 16;
+const NUM_ROWS: usize = 1024 * 1024;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+/// A fixed size thread pool
+#[derive(Debug)]
+struct ThreadPool {
+    sender: Mutex<Sender<Task>>,
+}
+
+impl ThreadPool {
+    fn new(num_threads: usize) -> Self {
+        let (sender, receiver) = channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..num_threads {
+            let receiver = Arc::clone(&receiver);
+            std::thread::spawn(move || {
+                loop {
+                    let task = receiver.lock().unwrap().recv();
+                    match task {
+                        Ok(task) => task(),
+                        Err(_) => break,
+                    }
+                }
+            });
+        }
+        Self {
+            sender: Mutex::new(sender),
+        }
+    }
+}
+
+impl Executor for ThreadPool {
+    fn spawn(&self, task: Task) {
+        self.sender.lock().unwrap().send(task).unwrap();
+    }
+}
+
+/// Writes a file with `NUM_COLUMNS` columns of integers, floats and strings
+fn write_file() -> Bytes {
+    let mut rng = rand::rng();
+    let columns = (0..NUM_COLUMNS).map(|i| {
+        let array: ArrayRef = match i % 3 {
+            0 => Arc::new(Int64Array::from_iter_values(
+                (0..NUM_ROWS).map(|_| rng.random_range(0..1_000_000)),
+            )),
+            1 => Arc::new(Float64Array::from_iter_values(
+                (0..NUM_ROWS).map(|_| rng.random::<f64>()),
+            )),
+            _ => Arc::new(StringArray::from_iter_values(
+                (0..NUM_ROWS).map(|_| format!("value_{}", rng.random_range(0..100_000))),
+            )),
+        };
+        (format!("col_{i}"), array)
+    });
+    let batch = RecordBatch::try_from_iter(columns.collect::<Vec<_>>()).unwrap();
+
+    let props = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::try_new(3).unwrap()))
+        .set_dictionary_enabled(false)
+        .set_max_row_group_size(NUM_ROWS / 4)
+        .build();
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    Bytes::from(buf)
+}
+
+fn read(data: &Bytes, options: ArrowReaderOptions) {
+    let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+        .unwrap()
+        .with_batch_size(8192)
+        .build()
+        .unwrap();
+    for batch in reader {
+        hint::black_box(batch.unwrap());
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let data = write_file();
+    let pool: Arc<dyn Executor> = Arc::new(ThreadPool::new(8));
+
+    let mut group = c.benchmark_group("arrow_reader_decompress_pool");
+    group.sample_size(10);
+    group.bench_function("single_threaded", |b| {
+        b.iter(|| read(&data, ArrowReaderOptions::new()))
+    });
+    group.bench_function("decompress_pool", |b| {
+        let options = ArrowReaderOptions::new().with_decompress_pool(Arc::clone(&pool));
+        b.iter(|| read(&data, options.clone()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -52,13 +52,15 @@
 //!   - unsel_clustered: for Unselective Clustered – in each 10K-row block, rows with an offset >= 1000 are "unsel_clustered".
 //!
 
-use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, TimestampMillisecondArray};
+use arrow::array::{
+    ArrayRef, AsArray, BooleanArray, Float64Array, Int64Array, TimestampMillisecondArray,
+};
 use arrow::compute::and;
 use arrow::compute::kernels::cmp::{eq, gt, lt, neq};
 use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
 use arrow::record_batch::RecordBatch;
-use arrow_array::StringViewArray;
 use arrow_array::builder::{ArrayBuilder, StringViewBuilder};
+use arrow_array::{BinaryViewArray, StringViewArray};
 use arrow_cast::pretty::pretty_format_batches;
 use bytes::Bytes;
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
@@ -150,6 +152,12 @@ fn create_utf8_view_array(size: usize) -> ArrayRef {
     Arc::new(builder.finish()) as ArrayRef
 }
 
+/// Creates a binaryView array with the same values as [`create_utf8_view_array`].
+fn create_binary_view_array(size: usize) -> ArrayRef {
+    let array = create_utf8_view_array(size).as_string_view().clone();
+    Arc::new(array.to_binary_view()) as ArrayRef
+}
+
 /// Creates a ts (timestamp) array of a given size. Each value is computed as i % 10_000,
 /// which simulates repeating blocks (each block of 10,000) to model clustered patterns.
 fn create_ts_array(size: usize) -> ArrayRef {
@@ -157,7 +165,7 @@ fn create_ts_array(size: usize) -> ArrayRef {
     Arc::new(TimestampMillisecondArray::from(values)) as ArrayRef
 }
 
-/// Creates a RecordBatch with 100K rows and 4 columns: int64, float64, utf8View, and ts.
+/// Creates a RecordBatch with 100K rows and 5 columns: int64, float64, utf8View, ts, and binaryView.
 fn create_record_batch(size: usize) -> RecordBatch {
     let fields = vec![
         Field::new("int64", DataType::Int64, false),
@@ -168,6 +176,7 @@ fn create_record_batch(size: usize) -> RecordBatch {
             DataType::Timestamp(TimeUnit::Millisecond, None),
             false,
         ),
+        Field::new("binaryView", DataType::BinaryView, true),
     ];
     let schema = Arc::new(Schema::new(fields));
 
@@ -175,8 +184,15 @@ fn create_record_batch(size: usize) -> RecordBatch {
     let float64_array = create_float64_array(size);
     let utf8_array = create_utf8_view_array(size);
     let ts_array = create_ts_array(size);
-
-    let arrays: Vec<ArrayRef> = vec![int64_array, float64_array, utf8_array, ts_array];
+    let binary_array = create_binary_view_array(size);
+
+    let arrays: Vec<ArrayRef> = vec![
+        int64_array,
+        float64_array,
+        utf8_array,
+        ts_array,
+        binary_array,
+    ];
     RecordBatch::try_new(schema, arrays).unwrap()
 }
 
@@ -327,6 +343,9 @@ enum FilterType {
     /// [ClickBench]: https://github.com/ClickHouse/ClickBench
     /// [Q21-Q27]: https://github.com/apache/datafusion/blob/b7177234e65cbbb2dcc04c252f6acd80bb026362/benchmarks/queries/clickbench/queries.sql#L22-L28
     Utf8ViewNonEmpty,
+    /// `binaryView <> ''`, the same as [`Self::Utf8ViewNonEmpty`] on binary
+    /// values
+    BinaryViewNonEmpty,
 }
 
 impl std::fmt::Display for FilterType {
@@ -340,6 +359,7 @@ impl std::fmt::Display for FilterType {
             FilterType::UnselectiveClustered => "ts < 9000",
             FilterType::Composite => "float64 > 99.0 AND ts >= 9000",
             FilterType::Utf8ViewNonEmpty => "utf8View <> ''",
+            FilterType::BinaryViewNonEmpty => "binaryView <> ''",
         };
         write!(f, "{s}")
     }
@@ -394,6 +414,12 @@ impl FilterType {
                 let scalar = StringViewArray::new_scalar("");
                 neq(array, &scalar)
             }
+            // BinaryViewNonEmpty: selects rows where the binaryView column is not empty.
+            FilterType::BinaryViewNonEmpty => {
+                let array = batch.column(batch.schema().index_of("binaryView")?);
+                let scalar = BinaryViewArray::new_scalar(b"");
+                neq(array, &scalar)
+            }
         }
     }
 
@@ -408,6 +434,7 @@ impl FilterType {
             FilterType::UnselectiveClustered => &[3],
             FilterType::Composite => &[1, 3], // Use float64 column and ts column as representative for composite
             FilterType::Utf8ViewNonEmpty => &[2],
+            FilterType::BinaryViewNonEmpty => &[4],
         }
     }
 }
@@ -426,6 +453,7 @@ fn benchmark_filters_and_projections(c: &mut Criterion) {
         FilterType::UnselectiveUnclustered,
         FilterType::UnselectiveClustered,
         FilterType::Utf8ViewNonEmpty,
+        FilterType::BinaryViewNonEmpty,
         FilterType::Composite,
     ];
     let projection_cases = vec![
@@ -442,8 +470,8 @@ fn benchmark_filters_and_projections(c: &mut Criterion) {
 
     for filter_type in filter_types {
         for proj_case in &projection_cases {
-            // All indices corresponding to the 10 columns.
-            let all_indices = vec![0, 1, 2, 3];
+            // All indices corresponding to the 5 columns.
+            let all_indices = vec![0, 1, 2, 3, 4];
             let filter_col = filter_type.filter_projection().to_vec();
             // For the projection, either select all columns or exclude the filter column(s).
             let output_projection: Vec<usize> = match proj_case {
@@ -29,6 +29,8 @@ use std::hint::black_box;
 use std::io::Empty;
 use std::sync::Arc;
 
+use arrow::array::{ArrayRef, StringViewArray, UInt32Array};
+use arrow::compute::take;
 use arrow::datatypes::*;
 use arrow::util::bench_util::{create_f16_array, create_f32_array, create_f64_array};
 use arrow::{record_batch::RecordBatch, util::data_gen::*};
@@ -120,6 +122,15 @@ fn create_string_and_binary_view_bench_batch(
     )?)
 }
 
+/// Creates a batch with a single `Utf8View` column of 100 byte strings, taken
+/// from a pool of `distinct` values
+fn create_long_string_view_bench_batch(size: usize, distinct: usize) -> Result<RecordBatch> {
+    let values = StringViewArray::from_iter_values((0..distinct).map(|i| format!("{i:0>100}")));
+    let indices = UInt32Array::from_iter_values((0..size).map(|i| (i * 7 % distinct) as u32));
+    let array = take(&values, &indices, None)?;
+    Ok(RecordBatch::try_from_iter([("_1", array as ArrayRef)])?)
+}
+
 fn create_string_dictionary_bench_batch(
     size: usize,
     null_density: f32,
@@ -466,5 +477,53 @@ fn bench_all_writers(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_all_writers);
+/// Compares the dictionary options for `Utf8View` columns of long strings that
+/// are mostly duplicates, which benefit from dictionary encoding, and that are
+/// unique, which do not
+fn bench_view_dictionary_options(c: &mut Criterion) {
+    const BATCH_SIZE: usize = 4096;
+
+    let batches = [
+        (
+            "long_string_view_duplicate",
+            create_long_string_view_bench_batch(BATCH_SIZE, 16).unwrap(),
+        ),
+        (
+            "long_string_view_unique",
+            create_long_string_view_bench_batch(BATCH_SIZE, BATCH_SIZE).unwrap(),
+        ),
+    ];
+
+    let props = [
+        ("default", WriterProperties::default()),
+        (
+            "dictionary_max_value_length",
+            WriterProperties::builder()
+                .set_dictionary_max_value_length(64)
+                .build(),
+        ),
+        (
+            "dictionary_deduplicate_views",
+            WriterProperties::builder()
+                .set_dictionary_deduplicate_views(true)
+                .build(),
+        ),
+    ];
+
+    for (batch_name, batch) in &batches {
+        let mut group = c.benchmark_group(*batch_name);
+        group.throughput(Throughput::Bytes(
+            batch.column(0).get_array_memory_size() as u64
+        ));
+
+        for (prop_name, prop) in &props {
+            group.bench_function(*prop_name, |b| {
+                write_batch_with_option(b, batch, Some(prop.clone())).unwrap()
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_all_writers, bench_view_dictionary_options);
 criterion_main!(benches);
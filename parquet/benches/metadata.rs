@@ -169,6 +169,47 @@ fn criterion_benchmark(c: &mut Criterion) {
             ParquetMetaDataReader::decode_metadata(&buf).unwrap();
         })
     });
+
+    let file = wide_file(&buf);
+    c.bench_function("parse parquet metadata (wide)", |b| {
+        b.iter(|| {
+            ParquetMetaDataReader::new()
+                .parse_and_finish(&file)
+                .unwrap();
+        })
+    });
+
+    c.bench_function("parse parquet metadata (wide, lazy)", |b| {
+        b.iter(|| {
+            ParquetMetaDataReader::new()
+                .with_lazy_column_metadata(true)
+                .parse_and_finish(&file)
+                .unwrap();
+        })
+    });
+
+    c.bench_function("parse parquet metadata (wide, lazy, 10 columns)", |b| {
+        b.iter(|| {
+            let metadata = ParquetMetaDataReader::new()
+                .with_lazy_column_metadata(true)
+                .parse_and_finish(&file)
+                .unwrap();
+            for row_group in metadata.row_groups() {
+                for i in 0..10 {
+                    black_box(row_group.column(i * 1000));
+                }
+            }
+        })
+    });
+}
+
+/// Returns a parquet file with the encoded metadata `meta` and no data
+fn wide_file(meta: &[u8]) -> Bytes {
+    let mut file = b"PAR1".to_vec();
+    file.extend_from_slice(meta);
+    file.extend_from_slice(&(meta.len() as u32).to_le_bytes());
+    file.extend_from_slice(b"PAR1");
+    file.into()
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Writes a parquet file one column at a time, without first assembling a
+//! `RecordBatch` containing all the columns of each row group.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array, Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::util::pretty::print_batches;
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::errors::Result;
+
+/// Simulates a source that produces the data for a single column of a row group
+fn produce_column(column: usize, row_group: i64, num_rows: i64) -> ArrayRef {
+    let start = row_group * num_rows;
+    match column {
+        0 => Arc::new(Int64Array::from_iter_values(start..start + num_rows)),
+        1 => Arc::new(StringArray::from_iter_values(
+            (start..start + num_rows).map(|x| format!("name-{x}")),
+        )),
+        _ => Arc::new(Float64Array::from_iter_values(
+            (start..start + num_rows).map(|x| x as f64 * 0.5),
+        )),
+    }
+}
+
+fn main() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("score", DataType::Float64, false),
+    ]));
+
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, schema.clone(), None)?;
+
+    for row_group in 0..2 {
+        // Start a new row group. Only the data for the column currently being
+        // written needs to be held in memory, the rest is already encoded.
+        let mut row_group_writer = writer.start_columnwise_row_group()?;
+
+        // The columns may arrive in any order, and each column may be written
+        // in several chunks
+        for column in [2, 0, 1] {
+            let array = produce_column(column, row_group, 4);
+            row_group_writer.write_column(column, &array.slice(0, 2))?;
+            row_group_writer.write_column(column, &array.slice(2, 2))?;
+        }
+
+        // Check every column has the same number of rows, and append the
+        // column chunks to the file in schema order
+        row_group_writer.finalize()?;
+    }
+
+    // A row group whose columns have different lengths is rejected, and
+    // nothing is written to the file
+    let mut row_group_writer = writer.start_columnwise_row_group()?;
+    row_group_writer.write_column(0, &produce_column(0, 2, 4))?;
+    row_group_writer.write_column(1, &produce_column(1, 2, 3))?;
+    row_group_writer.write_column(2, &produce_column(2, 2, 4))?;
+    let err = row_group_writer.finalize().unwrap_err();
+    println!("Rejected row group: {err}");
+
+    let metadata = writer.close()?;
+    assert_eq!(metadata.num_row_groups(), 2);
+
+    // Read the file back
+    let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))?.build()?;
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    print_batches(&batches).unwrap();
+    Ok(())
+}
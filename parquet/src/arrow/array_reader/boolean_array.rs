@@ -0,0 +1,453 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::{ArrayReader, read_records, skip_records};
+use crate::arrow::buffer::bit_util::iter_set_bits_rev;
+use crate::arrow::record_reader::GenericRecordReader;
+use crate::arrow::record_reader::buffer::ValuesBuffer;
+use crate::arrow::schema::parquet_to_arrow_field;
+use crate::basic::{Encoding, Type};
+use crate::column::page::PageIterator;
+use crate::column::reader::decoder::{ColumnValueDecoder, ColumnValueDecoderImpl};
+use crate::data_type::BoolType;
+use crate::encodings::rle::RleDecoder;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnDescPtr;
+use crate::util::bit_util;
+use arrow_array::{ArrayRef, BooleanArray};
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder, NullBuffer};
+use arrow_schema::DataType as ArrowType;
+use bytes::Bytes;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Returns an [`ArrayReader`] that decodes the provided boolean column
+///
+/// PLAIN and RLE encoded pages are decoded directly into the packed bits of the
+/// resulting [`BooleanArray`], whose values can then be accessed without copying
+/// with [`BooleanArray::values`] or [`BooleanArray::into_parts`]
+pub fn make_boolean_array_reader(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    arrow_type: Option<ArrowType>,
+) -> Result<Box<dyn ArrayReader>> {
+    if column_desc.physical_type() != Type::BOOLEAN {
+        return Err(general_err!(
+            "invalid physical type for boolean array reader - {}",
+            column_desc.physical_type()
+        ));
+    }
+
+    // Check if Arrow type is specified, else create it from Parquet type
+    let data_type = match arrow_type {
+        Some(t) => t,
+        None => parquet_to_arrow_field(column_desc.as_ref())?
+            .data_type()
+            .clone(),
+    };
+
+    Ok(Box::new(BooleanArrayReader {
+        data_type,
+        pages,
+        def_levels_buffer: None,
+        rep_levels_buffer: None,
+        record_reader: GenericRecordReader::new(column_desc),
+    }))
+}
+
+/// An [`ArrayReader`] for boolean columns
+struct BooleanArrayReader {
+    data_type: ArrowType,
+    pages: Box<dyn PageIterator>,
+    def_levels_buffer: Option<Vec<i16>>,
+    rep_levels_buffer: Option<Vec<i16>>,
+    record_reader: GenericRecordReader<BooleanValuesBuffer, BooleanValueDecoder>,
+}
+
+impl ArrayReader for BooleanArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        read_records(&mut self.record_reader, self.pages.as_mut(), batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let values = self.record_reader.consume_record_data().builder.finish();
+        let nulls = self
+            .record_reader
+            .consume_bitmap_buffer()
+            .map(|b| NullBuffer::new(BooleanBuffer::new(b, 0, values.len())));
+
+        let array: ArrayRef = Arc::new(BooleanArray::new(values, nulls));
+        let array = match &self.data_type {
+            ArrowType::Boolean => array,
+            target_type => arrow_cast::cast(&array, target_type)?,
+        };
+
+        self.def_levels_buffer = self.record_reader.consume_def_levels();
+        self.rep_levels_buffer = self.record_reader.consume_rep_levels();
+        self.record_reader.reset();
+        Ok(array)
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        skip_records(&mut self.record_reader, self.pages.as_mut(), num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.def_levels_buffer.as_deref()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.rep_levels_buffer.as_deref()
+    }
+}
+
+/// A [`ValuesBuffer`] of packed boolean values
+struct BooleanValuesBuffer {
+    builder: BooleanBufferBuilder,
+}
+
+impl Default for BooleanValuesBuffer {
+    fn default() -> Self {
+        Self {
+            builder: BooleanBufferBuilder::new(0),
+        }
+    }
+}
+
+impl ValuesBuffer for BooleanValuesBuffer {
+    fn pad_nulls(
+        &mut self,
+        read_offset: usize,
+        values_read: usize,
+        levels_read: usize,
+        valid_mask: &[u8],
+    ) {
+        assert_eq!(self.builder.len(), read_offset + values_read);
+        self.builder.resize(read_offset + levels_read);
+
+        let values_range = read_offset..read_offset + values_read;
+        for (value_pos, level_pos) in values_range.rev().zip(iter_set_bits_rev(valid_mask)) {
+            debug_assert!(level_pos >= value_pos);
+            if level_pos <= value_pos {
+                break;
+            }
+            let value = self.builder.get_bit(value_pos);
+            self.builder.set_bit(level_pos, value);
+        }
+    }
+}
+
+/// A [`ColumnValueDecoder`] that decodes boolean values into a [`BooleanValuesBuffer`]
+struct BooleanValueDecoder {
+    decoder: Option<Decoder>,
+    /// Decodes encodings other than PLAIN and RLE, such as dictionary encoded pages
+    fallback: ColumnValueDecoderImpl<BoolType>,
+    /// Scratch space for values decoded by `fallback`
+    scratch: Vec<bool>,
+}
+
+enum Decoder {
+    /// Bit-packed values, read starting from the bit `offset` of `buf`
+    Plain {
+        buf: Bytes,
+        offset: usize,
+        values_left: usize,
+    },
+    /// RLE/Bit-Packing hybrid encoded values
+    Rle {
+        decoder: RleDecoder,
+        values_left: usize,
+    },
+    Fallback,
+}
+
+impl ColumnValueDecoder for BooleanValueDecoder {
+    type Buffer = BooleanValuesBuffer;
+
+    fn new(col: &ColumnDescPtr) -> Self {
+        Self {
+            decoder: None,
+            fallback: ColumnValueDecoderImpl::new(col),
+            scratch: Vec::new(),
+        }
+    }
+
+    fn set_dict(
+        &mut self,
+        buf: Bytes,
+        num_values: u32,
+        encoding: Encoding,
+        is_sorted: bool,
+    ) -> Result<()> {
+        self.fallback.set_dict(buf, num_values, encoding, is_sorted)
+    }
+
+    fn set_data(
+        &mut self,
+        encoding: Encoding,
+        data: Bytes,
+        num_levels: usize,
+        num_values: Option<usize>,
+    ) -> Result<()> {
+        let values_left = num_values.unwrap_or(num_levels);
+        self.decoder = Some(match encoding {
+            Encoding::PLAIN => Decoder::Plain {
+                buf: data,
+                offset: 0,
+                values_left,
+            },
+            Encoding::RLE => {
+                // The RLE encoded values are prefixed with their length in bytes
+                const I32_SIZE: usize = std::mem::size_of::<i32>();
+                if data.len() < I32_SIZE {
+                    return Err(eof_err!("Not enough bytes to decode"));
+                }
+                let data_size = bit_util::read_num_bytes::<i32>(I32_SIZE, data.as_ref()) as usize;
+                if data.len() - I32_SIZE < data_size {
+                    return Err(eof_err!("Not enough bytes to decode"));
+                }
+
+                let mut decoder = RleDecoder::new(1);
+                decoder.set_data(data.slice(I32_SIZE..I32_SIZE + data_size));
+                Decoder::Rle {
+                    decoder,
+                    values_left,
+                }
+            }
+            _ => {
+                self.fallback
+                    .set_data(encoding, data, num_levels, num_values)?;
+                Decoder::Fallback
+            }
+        });
+        Ok(())
+    }
+
+    fn read(&mut self, out: &mut Self::Buffer, num_values: usize) -> Result<usize> {
+        match self.decoder.as_mut().unwrap() {
+            Decoder::Plain {
+                buf,
+                offset,
+                values_left,
+            } => {
+                let to_read = num_values.min(*values_left).min(buf.len() * 8 - *offset);
+                out.builder
+                    .append_packed_range(*offset..*offset + to_read, buf.as_ref());
+                *offset += to_read;
+                *values_left -= to_read;
+                Ok(to_read)
+            }
+            Decoder::Rle {
+                decoder,
+                values_left,
+            } => {
+                let to_read = num_values.min(*values_left);
+                let read = decoder.get_batch_bits(&mut out.builder, to_read)?;
+                *values_left -= read;
+                Ok(read)
+            }
+            Decoder::Fallback => {
+                self.scratch.clear();
+                let read = self.fallback.read(&mut self.scratch, num_values)?;
+                out.builder.append_slice(&self.scratch);
+                Ok(read)
+            }
+        }
+    }
+
+    fn skip_values(&mut self, num_values: usize) -> Result<usize> {
+        match self.decoder.as_mut().unwrap() {
+            Decoder::Plain {
+                buf,
+                offset,
+                values_left,
+            } => {
+                let to_skip = num_values.min(*values_left).min(buf.len() * 8 - *offset);
+                *offset += to_skip;
+                *values_left -= to_skip;
+                Ok(to_skip)
+            }
+            Decoder::Rle {
+                decoder,
+                values_left,
+            } => {
+                let skipped = decoder.skip(num_values.min(*values_left))?;
+                *values_left -= skipped;
+                Ok(skipped)
+            }
+            Decoder::Fallback => self.fallback.skip_values(num_values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::EmptyPageIterator;
+    use crate::schema::parser::parse_message_type;
+    use crate::schema::types::SchemaDescriptor;
+    use crate::util::{DataPageBuilder, DataPageBuilderImpl, InMemoryPageIterator};
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+
+    fn column_desc(repetition: &str) -> ColumnDescPtr {
+        let message_type = format!("message test_schema {{ {repetition} BOOLEAN leaf; }}");
+        let schema = parse_message_type(&message_type)
+            .map(|t| Arc::new(SchemaDescriptor::new(Arc::new(t))))
+            .unwrap();
+        schema.column(0)
+    }
+
+    /// Encodes `values` into pages of at most `page_size` levels
+    fn make_pages(
+        desc: &ColumnDescPtr,
+        encoding: Encoding,
+        values: &[Option<bool>],
+        page_size: usize,
+        use_v2: bool,
+    ) -> Box<dyn PageIterator> {
+        let max_def_level = desc.max_def_level();
+        let pages = values
+            .chunks(page_size)
+            .map(|chunk| {
+                let def_levels: Vec<i16> = chunk
+                    .iter()
+                    .map(|v| if v.is_some() { max_def_level } else { 0 })
+                    .collect();
+                let non_null: Vec<bool> = chunk.iter().flatten().copied().collect();
+
+                let mut builder =
+                    DataPageBuilderImpl::new(desc.clone(), non_null.len() as u32, use_v2);
+                builder.add_def_levels(max_def_level, &def_levels);
+                builder.add_values::<BoolType>(encoding, &non_null);
+                builder.consume()
+            })
+            .collect();
+        Box::new(InMemoryPageIterator::new(vec![pages]))
+    }
+
+    fn read_all(mut reader: Box<dyn ArrayReader>, batch_size: usize) -> BooleanArray {
+        let mut values = vec![];
+        loop {
+            let array = reader.next_batch(batch_size).unwrap();
+            if array.is_empty() {
+                break;
+            }
+            values.extend(array.as_boolean().iter());
+        }
+        BooleanArray::from(values)
+    }
+
+    #[test]
+    fn test_boolean_array_reader_empty_pages() {
+        let mut reader = make_boolean_array_reader(
+            Box::<EmptyPageIterator>::default(),
+            column_desc("REQUIRED"),
+            None,
+        )
+        .unwrap();
+        let array = reader.next_batch(50).unwrap();
+        assert!(array.is_empty());
+        assert_eq!(array.data_type(), &ArrowType::Boolean);
+    }
+
+    #[test]
+    fn test_boolean_array_reader() {
+        let values: Vec<_> = (0..1000)
+            .map(|i| match i % 7 {
+                0 => None,
+                x => Some(x % 3 == 0),
+            })
+            .collect();
+        let expected = BooleanArray::from(values.clone());
+
+        for repetition in ["OPTIONAL", "REQUIRED"] {
+            let desc = column_desc(repetition);
+            let values: Vec<_> = match repetition {
+                "REQUIRED" => values.iter().map(|v| Some(v.unwrap_or(true))).collect(),
+                _ => values.clone(),
+            };
+            let expected = match repetition {
+                "REQUIRED" => BooleanArray::from(values.clone()),
+                _ => expected.clone(),
+            };
+
+            for encoding in [Encoding::PLAIN, Encoding::RLE] {
+                for use_v2 in [false, true] {
+                    let pages = make_pages(&desc, encoding, &values, 300, use_v2);
+                    let reader = make_boolean_array_reader(pages, desc.clone(), None).unwrap();
+                    assert_eq!(read_all(reader, 77), expected, "{encoding} {use_v2}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_boolean_array_reader_long_rle_runs() {
+        // Runs far longer than a single batch, interleaved with short runs
+        // that are bit-packed, and nulls which split the runs of values
+        let mut values = vec![];
+        values.extend(std::iter::repeat_n(Some(true), 100_000));
+        values.extend((0..21).map(|i| Some(i % 2 == 0)));
+        values.extend(std::iter::repeat_n(Some(false), 70_003));
+        values.extend(std::iter::repeat_n(None, 5_000));
+        values.extend(std::iter::repeat_n(Some(true), 33_333));
+        values.extend((0..1000).map(|i| (i % 3 != 0).then_some(i % 5 == 0)));
+        let expected = BooleanArray::from(values.clone());
+
+        let desc = column_desc("OPTIONAL");
+        let pages = make_pages(&desc, Encoding::RLE, &values, 150_000, false);
+        let reader = make_boolean_array_reader(pages, desc.clone(), None).unwrap();
+        assert_eq!(read_all(reader, 8192), expected);
+
+        // Skip over and read across the boundaries of the runs
+        let pages = make_pages(&desc, Encoding::RLE, &values, 150_000, true);
+        let mut reader = make_boolean_array_reader(pages, desc, None).unwrap();
+        let mut offset = 0;
+        for (skip, read) in [(99_990, 20), (70_000, 10), (4_990, 33_350), (500, 10_000)] {
+            assert_eq!(reader.skip_records(skip).unwrap(), skip);
+            offset += skip;
+
+            let len = read.min(values.len() - offset);
+            let array = reader.next_batch(read).unwrap();
+            assert_eq!(array.as_boolean(), &expected.slice(offset, len));
+            offset += len;
+        }
+    }
+
+    #[test]
+    fn test_boolean_array_reader_values_buffer() {
+        let values = [Some(true), None, Some(false), Some(true), None, Some(true)];
+        let desc = column_desc("OPTIONAL");
+        let pages = make_pages(&desc, Encoding::RLE, &values, 6, false);
+        let mut reader = make_boolean_array_reader(pages, desc, None).unwrap();
+
+        let array = reader.next_batch(6).unwrap();
+        let (values, nulls) = array.as_boolean().clone().into_parts();
+        assert_eq!(values.len(), 6);
+        assert_eq!(values.count_set_bits(), 3);
+        assert_eq!(nulls.unwrap().null_count(), 2);
+    }
+}
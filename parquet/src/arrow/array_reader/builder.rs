@@ -20,6 +20,8 @@ use std::sync::{Arc, Mutex};
 use arrow_schema::{DataType, Fields, SchemaBuilder};
 
 use crate::arrow::ProjectionMask;
+use crate::arrow::array_reader::boolean_array::make_boolean_array_reader;
+use crate::arrow::array_reader::byte_array::make_byte_array_reader;
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::cached_array_reader::CacheRole;
 use crate::arrow::array_reader::cached_array_reader::CachedArrayReader;
@@ -27,14 +29,15 @@ use crate::arrow::array_reader::empty_array::make_empty_array_reader;
 use crate::arrow::array_reader::fixed_len_byte_array::make_fixed_len_byte_array_reader;
 use crate::arrow::array_reader::row_group_cache::RowGroupCache;
 use crate::arrow::array_reader::{
-    ArrayReader, FixedSizeListArrayReader, ListArrayReader, MapArrayReader, NullArrayReader,
-    PrimitiveArrayReader, RowGroups, StructArrayReader, make_byte_array_dictionary_reader,
-    make_byte_array_reader,
+    ArrayReader, DecimalArrayReader, FixedSizeListArrayReader, ListArrayReader, MapArrayReader,
+    NullArrayReader, PrimitiveArrayReader, RowGroups, RunEndEncodedArrayReader, StructArrayReader,
+    make_byte_array_dictionary_reader,
 };
 use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+use crate::arrow::record_reader::buffer::PreallocationHint;
 use crate::arrow::schema::{ParquetField, ParquetFieldType};
 use crate::basic::Type as PhysicalType;
-use crate::data_type::{BoolType, DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
+use crate::data_type::{DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::{ColumnDescriptor, ColumnPath, Type};
 
@@ -91,6 +94,10 @@ pub struct ArrayReaderBuilder<'a> {
     cache_options: Option<&'a CacheOptions<'a>>,
     /// metrics
     metrics: &'a ArrowReaderMetrics,
+    /// Preallocate buffers using the column chunk metadata
+    preallocate_from_stats: bool,
+    /// Verify decoded decimals fit the precision of their column
+    decimal_validation: bool,
 }
 
 impl<'a> ArrayReaderBuilder<'a> {
@@ -99,6 +106,8 @@ impl<'a> ArrayReaderBuilder<'a> {
             row_groups,
             cache_options: None,
             metrics,
+            preallocate_from_stats: false,
+            decimal_validation: false,
         }
     }
 
@@ -108,6 +117,20 @@ impl<'a> ArrayReaderBuilder<'a> {
         self
     }
 
+    /// Preallocate the buffers of variable length columns using the metadata
+    /// of the column chunks, see [`RowGroups::row_group_metadata`]
+    pub fn with_preallocate_from_stats(mut self, preallocate_from_stats: bool) -> Self {
+        self.preallocate_from_stats = preallocate_from_stats;
+        self
+    }
+
+    /// Verify the values of decimal columns fit their precision, returning an
+    /// error for out of range values, see [`DecimalArrayReader`]
+    pub fn with_decimal_validation(mut self, decimal_validation: bool) -> Self {
+        self.decimal_validation = decimal_validation;
+        self
+    }
+
     /// Create [`ArrayReader`] from parquet schema, projection mask, and parquet file reader.
     pub fn build_array_reader(
         &self,
@@ -127,11 +150,57 @@ impl<'a> ArrayReaderBuilder<'a> {
         self.row_groups.num_rows()
     }
 
+    /// Returns the [`PreallocationHint`] for the byte array column `col_idx`,
+    /// if enabled and the row group metadata is known
+    fn preallocation_hint(&self, col_idx: usize) -> Option<PreallocationHint> {
+        if !self.preallocate_from_stats {
+            return None;
+        }
+        let hint = self
+            .row_group_metadata_hints(col_idx)
+            .fold(PreallocationHint::default(), |acc, hint| acc + hint);
+        (hint != PreallocationHint::default()).then_some(hint)
+    }
+
+    fn row_group_metadata_hints(
+        &self,
+        col_idx: usize,
+    ) -> impl Iterator<Item = PreallocationHint> + '_ {
+        self.row_groups.row_group_metadata().map(move |rg| {
+            let column = rg.column(col_idx);
+            // Prefer the exact size of the values from the size statistics
+            let num_bytes = column
+                .unencoded_byte_array_data_bytes()
+                .unwrap_or(column.uncompressed_size());
+            PreallocationHint::new(
+                rg.num_rows().max(0) as u64,
+                column.num_values().max(0) as u64,
+                num_bytes.max(0) as u64,
+            )
+        })
+    }
+
     fn build_reader(
         &self,
         field: &ParquetField,
         mask: &ProjectionMask,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
+        // Run-end encoded columns are stored as their values, read the values and
+        // recompute the runs
+        if let DataType::RunEndEncoded(_, values) = &field.arrow_type {
+            let values_field = ParquetField {
+                arrow_type: values.data_type().clone(),
+                ..field.clone()
+            };
+            let reader = self.build_reader(&values_field, mask)?;
+            return Ok(reader.map(|reader| {
+                Box::new(RunEndEncodedArrayReader::new(
+                    reader,
+                    field.arrow_type.clone(),
+                )) as _
+            }));
+        }
+
         match field.field_type {
             ParquetFieldType::Primitive { col_idx, .. } => {
                 let Some(reader) = self.build_primitive_reader(field, mask)? else {
@@ -326,24 +395,38 @@ impl<'a> ArrayReaderBuilder<'a> {
         // of bugs when the arrow mapping converts more than one level in the parquet
         // schema into a single arrow field.
         //
-        // None of the readers actually use this field, but it is required for this type,
-        // so just stick a placeholder in
+        // The path is only used to report decode errors, so take it from the column
+        // chunk metadata where available, and otherwise stick a placeholder in
+        let column_path = self
+            .row_groups
+            .row_group_metadata()
+            .next()
+            .map(|rg| rg.column(col_idx).column_path().clone())
+            .unwrap_or_else(|| ColumnPath::new(vec![]));
         let column_desc = Arc::new(ColumnDescriptor::new(
             primitive_type,
             field.def_level,
             field.rep_level,
-            ColumnPath::new(vec![]),
+            column_path,
         ));
 
         let page_iterator = self.row_groups.column_chunks(col_idx)?;
         let arrow_type = Some(field.arrow_type.clone());
 
-        let reader = match physical_type {
-            PhysicalType::BOOLEAN => Box::new(PrimitiveArrayReader::<BoolType>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?) as _,
+        // Decimal readers trust the declared precision unless validation is requested
+        let validate_decimals = match &field.arrow_type {
+            DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => self.decimal_validation,
+            _ => false,
+        };
+        let decimal_column_path = validate_decimals.then(|| column_desc.path().clone());
+
+        let reader: Box<dyn ArrayReader> = match physical_type {
+            PhysicalType::BOOLEAN => {
+                make_boolean_array_reader(page_iterator, column_desc, arrow_type)?
+            }
             PhysicalType::INT32 => {
                 if let Some(DataType::Null) = arrow_type {
                     Box::new(NullArrayReader::<Int32Type>::new(
@@ -382,10 +465,18 @@ impl<'a> ArrayReaderBuilder<'a> {
                 Some(DataType::Dictionary(_, _)) => {
                     make_byte_array_dictionary_reader(page_iterator, column_desc, arrow_type)?
                 }
-                Some(DataType::Utf8View | DataType::BinaryView) => {
-                    make_byte_view_array_reader(page_iterator, column_desc, arrow_type)?
-                }
-                _ => make_byte_array_reader(page_iterator, column_desc, arrow_type)?,
+                Some(DataType::Utf8View | DataType::BinaryView) => make_byte_view_array_reader(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                    self.preallocation_hint(col_idx),
+                )?,
+                _ => make_byte_array_reader(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                    self.preallocation_hint(col_idx),
+                )?,
             },
             PhysicalType::FIXED_LEN_BYTE_ARRAY => match arrow_type {
                 Some(DataType::Dictionary(_, _)) => {
@@ -394,7 +485,10 @@ impl<'a> ArrayReaderBuilder<'a> {
                 _ => make_fixed_len_byte_array_reader(page_iterator, column_desc, arrow_type)?,
             },
         };
-        Ok(Some(reader))
+        match decimal_column_path {
+            Some(path) => Ok(Some(Box::new(DecimalArrayReader::new(reader, path)))),
+            None => Ok(Some(reader)),
+        }
     }
 
     fn build_struct_reader(
@@ -455,6 +549,7 @@ mod tests {
             file_metadata.schema_descr(),
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            Default::default(),
         )
         .unwrap();
 
@@ -19,6 +19,7 @@ use crate::arrow::array_reader::{ArrayReader, read_records, skip_records};
 use crate::arrow::buffer::view_buffer::ViewBuffer;
 use crate::arrow::decoder::{DeltaByteArrayDecoder, DictIndexDecoder};
 use crate::arrow::record_reader::GenericRecordReader;
+use crate::arrow::record_reader::buffer::PreallocationHint;
 use crate::arrow::schema::parquet_to_arrow_field;
 use crate::basic::{ConvertedType, Encoding};
 use crate::column::page::PageIterator;
@@ -35,11 +36,13 @@ use arrow_schema::DataType as ArrowType;
 use bytes::Bytes;
 use std::any::Any;
 
-/// Returns an [`ArrayReader`] that decodes the provided byte array column to view types.
+/// Returns an [`ArrayReader`] that decodes the provided byte array column to view types,
+/// preallocating its buffers according to `hint`, if any
 pub fn make_byte_view_array_reader(
     pages: Box<dyn PageIterator>,
     column_desc: ColumnDescPtr,
     arrow_type: Option<ArrowType>,
+    hint: Option<PreallocationHint>,
 ) -> Result<Box<dyn ArrayReader>> {
     // Check if Arrow type is specified, else create it from Parquet type
     let data_type = match arrow_type {
@@ -52,7 +55,7 @@ pub fn make_byte_view_array_reader(
 
     match data_type {
         ArrowType::BinaryView | ArrowType::Utf8View => {
-            let reader = GenericRecordReader::new(column_desc);
+            let reader = GenericRecordReader::new(column_desc).with_preallocation_hint(hint);
             Ok(Box::new(ByteViewArrayReader::new(pages, data_type, reader)))
         }
 
@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DecimalArrayReader`] wrapper around [`ArrayReader`]
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::{DecodeError, ParquetError, Result};
+use crate::schema::types::ColumnPath;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal32Type, Decimal64Type, Decimal128Type, Decimal256Type};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::DataType as ArrowType;
+use std::any::Any;
+
+/// A wrapper around an [`ArrayReader`] of decimals that verifies the decoded
+/// values fit the precision of the column
+///
+/// The readers of decimal columns trust the precision declared by the file,
+/// so a corrupt or mis-annotated file may otherwise produce decimal arrays
+/// containing values that exceed their precision.
+///
+/// See [`ArrowReaderOptions::with_decimal_validation`]
+///
+/// [`ArrowReaderOptions::with_decimal_validation`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_decimal_validation
+pub struct DecimalArrayReader {
+    /// The underlying array reader
+    inner: Box<dyn ArrayReader>,
+    /// The path of the column, used to report invalid values
+    column_path: ColumnPath,
+}
+
+impl DecimalArrayReader {
+    /// Creates a new [`DecimalArrayReader`] validating the arrays read by `inner`
+    pub fn new(inner: Box<dyn ArrayReader>, column_path: ColumnPath) -> Self {
+        Self { inner, column_path }
+    }
+
+    fn validate(&self, array: &dyn Array) -> Result<()> {
+        let result = match array.data_type() {
+            ArrowType::Decimal32(p, _) => array
+                .as_primitive::<Decimal32Type>()
+                .validate_decimal_precision(*p),
+            ArrowType::Decimal64(p, _) => array
+                .as_primitive::<Decimal64Type>()
+                .validate_decimal_precision(*p),
+            ArrowType::Decimal128(p, _) => array
+                .as_primitive::<Decimal128Type>()
+                .validate_decimal_precision(*p),
+            ArrowType::Decimal256(p, _) => array
+                .as_primitive::<Decimal256Type>()
+                .validate_decimal_precision(*p),
+            _ => Ok(()),
+        };
+        result.map_err(|e| {
+            ParquetError::Decode(Box::new(DecodeError::new(
+                self.column_path.clone(),
+                e.into(),
+            )))
+        })
+    }
+}
+
+impl ArrayReader for DecimalArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        self.inner.get_data_type()
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.inner.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.inner.consume_batch()?;
+        self.validate(array.as_ref())?;
+        Ok(array)
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.inner.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.inner.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.inner.get_rep_levels()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::InMemoryArrayReader;
+    use arrow_array::Decimal128Array;
+    use std::sync::Arc;
+
+    fn reader(array: Decimal128Array) -> DecimalArrayReader {
+        let inner =
+            InMemoryArrayReader::new(array.data_type().clone(), Arc::new(array), None, None);
+        DecimalArrayReader::new(Box::new(inner), ColumnPath::from("d"))
+    }
+
+    #[test]
+    fn test_valid_decimals() {
+        let array = Decimal128Array::from(vec![Some(99999), None, Some(-99999)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let mut reader = reader(array.clone());
+        assert_eq!(reader.read_records(3).unwrap(), 3);
+        let read = reader.consume_batch().unwrap();
+        assert_eq!(read.as_primitive::<Decimal128Type>(), &array);
+    }
+
+    #[test]
+    fn test_invalid_decimals() {
+        let array = Decimal128Array::from(vec![Some(1), Some(100000)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let mut reader = reader(array);
+        assert_eq!(reader.read_records(2).unwrap(), 2);
+        let err = reader.consume_batch().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("1000.00 is too large to store in a Decimal128 of precision 5"),
+            "{err}"
+        );
+        assert!(err.to_string().contains("\"d\""), "{err}");
+    }
+}
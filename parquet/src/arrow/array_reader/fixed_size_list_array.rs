@@ -154,6 +154,14 @@ impl ArrayReader for FixedSizeListArrayReader {
                     } else {
                         // Null list entry
 
+                        if *d + 1 == self.def_level && self.fixed_size != 0 {
+                            // An empty list can only be valid for a list of size 0
+                            return Err(general_err!(
+                                "Encountered misaligned row with length 0 (expected length {})",
+                                self.fixed_size
+                            ));
+                        }
+
                         if let Some(start) = start_idx.take() {
                             // Flush pending child items
                             child_data_builder.extend(0, start, child_idx);
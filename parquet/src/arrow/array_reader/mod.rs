@@ -27,13 +27,16 @@ use crate::arrow::record_reader::GenericRecordReader;
 use crate::arrow::record_reader::buffer::ValuesBuffer;
 use crate::column::page::PageIterator;
 use crate::column::reader::decoder::ColumnValueDecoder;
+use crate::file::metadata::RowGroupMetaData;
 use crate::file::reader::{FilePageIterator, FileReader};
 
+mod boolean_array;
 mod builder;
 mod byte_array;
 mod byte_array_dictionary;
 mod byte_view_array;
 mod cached_array_reader;
+mod decimal_array;
 mod empty_array;
 mod fixed_len_byte_array;
 mod fixed_size_list_array;
@@ -42,17 +45,22 @@ mod map_array;
 mod null_array;
 mod primitive_array;
 mod row_group_cache;
+mod run_end_encoded_array;
 mod struct_array;
 
 #[cfg(test)]
 mod test_util;
 
 // Note that this crate is public under the `experimental` feature flag.
+#[allow(unused_imports)] // Only used for benchmarks
+pub use boolean_array::make_boolean_array_reader;
 pub use builder::{ArrayReaderBuilder, CacheOptions, CacheOptionsBuilder};
+#[allow(unused_imports)] // Only used with the experimental feature flag
 pub use byte_array::make_byte_array_reader;
 pub use byte_array_dictionary::make_byte_array_dictionary_reader;
 #[allow(unused_imports)] // Only used for benchmarks
 pub use byte_view_array::make_byte_view_array_reader;
+pub use decimal_array::DecimalArrayReader;
 #[allow(unused_imports)] // Only used for benchmarks
 pub use fixed_len_byte_array::make_fixed_len_byte_array_reader;
 pub use fixed_size_list_array::FixedSizeListArrayReader;
@@ -61,6 +69,7 @@ pub use map_array::MapArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
 pub use row_group_cache::RowGroupCache;
+pub use run_end_encoded_array::RunEndEncodedArrayReader;
 pub use struct_array::StructArrayReader;
 
 /// Reads Parquet data into Arrow Arrays.
@@ -139,6 +148,14 @@ pub trait RowGroups {
     /// Returns a [`PageIterator`] for all pages in the specified column chunk
     /// across all row groups in this collection.
     fn column_chunks(&self, i: usize) -> Result<Box<dyn PageIterator>>;
+
+    /// Returns the [`RowGroupMetaData`] of the row groups in this collection
+    ///
+    /// This is only used for estimates, such as when preallocating buffers,
+    /// and returns nothing by default.
+    fn row_group_metadata(&self) -> Box<dyn Iterator<Item = &RowGroupMetaData> + '_> {
+        Box::new(std::iter::empty())
+    }
 }
 
 impl RowGroups for Arc<dyn FileReader> {
@@ -146,6 +163,10 @@ impl RowGroups for Arc<dyn FileReader> {
         self.metadata().file_metadata().num_rows() as usize
     }
 
+    fn row_group_metadata(&self) -> Box<dyn Iterator<Item = &RowGroupMetaData> + '_> {
+        Box::new(self.metadata().row_groups().iter())
+    }
+
     fn column_chunks(&self, column_index: usize) -> Result<Box<dyn PageIterator>> {
         let iterator = FilePageIterator::new(column_index, Arc::clone(self))?;
         Ok(Box::new(iterator))
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use arrow_array::{ArrayRef, make_array};
+use arrow_cast::cast;
+use arrow_schema::DataType as ArrowType;
+use std::any::Any;
+
+/// An [`ArrayReader`] that run-end encodes the values read by a child [`ArrayReader`]
+///
+/// Parquet has no run-end encoded type, run-end encoded arrays are written as their
+/// values and the runs are recomputed from the decoded values
+pub struct RunEndEncodedArrayReader {
+    data_type: ArrowType,
+    values_reader: Box<dyn ArrayReader>,
+}
+
+impl RunEndEncodedArrayReader {
+    /// Construct a new [`RunEndEncodedArrayReader`] returning arrays of `data_type`,
+    /// which must be a [`ArrowType::RunEndEncoded`] of the child reader's type
+    pub fn new(values_reader: Box<dyn ArrayReader>, data_type: ArrowType) -> Self {
+        Self {
+            data_type,
+            values_reader,
+        }
+    }
+}
+
+impl ArrayReader for RunEndEncodedArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.values_reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let values = self.values_reader.consume_batch()?;
+        let encoded = cast(&values, &self.data_type)?;
+        if encoded.data_type() == &self.data_type {
+            return Ok(encoded);
+        }
+        // The cast names the values field "values" and marks it nullable, restore
+        // the names and nullability of the requested type
+        let data = encoded
+            .to_data()
+            .into_builder()
+            .data_type(self.data_type.clone())
+            .build()?;
+        Ok(make_array(data))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.values_reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.values_reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.values_reader.get_rep_levels()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::InMemoryArrayReader;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{Array, Int64Array};
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_run_end_encoded_array_reader() {
+        let values = Int64Array::from(vec![Some(1), Some(1), None, None, Some(2), Some(1)]);
+        let data_type = ArrowType::RunEndEncoded(
+            Arc::new(Field::new("ends", ArrowType::Int32, false)),
+            Arc::new(Field::new("vals", ArrowType::Int64, true)),
+        );
+        let child = InMemoryArrayReader::new(
+            ArrowType::Int64,
+            Arc::new(values),
+            Some(vec![1, 1, 0, 0, 1, 1]),
+            None,
+        );
+        let mut reader = RunEndEncodedArrayReader::new(Box::new(child), data_type.clone());
+        assert_eq!(reader.get_data_type(), &data_type);
+
+        let array = reader.next_batch(4).unwrap();
+        assert_eq!(array.data_type(), &data_type);
+        let run_array = array.as_run::<Int32Type>();
+        assert_eq!(run_array.run_ends().values(), &[2, 4]);
+        assert_eq!(run_array.values().len(), 2);
+        assert_eq!(reader.get_def_levels(), Some([1, 1, 0, 0].as_slice()));
+
+        let array = reader.next_batch(4).unwrap();
+        let run_array = array.as_run::<Int32Type>();
+        assert_eq!(run_array.run_ends().values(), &[1, 2]);
+        let values = run_array.values().as_primitive::<Int64Type>();
+        assert_eq!(values.values(), &[2, 1]);
+    }
+}
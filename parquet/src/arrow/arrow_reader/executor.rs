@@ -0,0 +1,244 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Executor`] for decompressing column chunks in parallel
+
+use crate::column::page::{Page, PageMetadata, PageReader};
+use crate::errors::{ParquetError, Result};
+use std::fmt::Debug;
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+use std::sync::{Arc, Mutex};
+
+/// A thread pool used to decompress the pages of column chunks in parallel
+///
+/// See [`ArrowReaderOptions::with_decompress_pool`] for how this is used.
+///
+/// # Example
+///
+/// An executor that spawns a new thread for each task:
+///
+/// ```
+/// # use parquet::arrow::arrow_reader::Executor;
+/// #[derive(Debug)]
+/// struct ThreadExecutor;
+///
+/// impl Executor for ThreadExecutor {
+///     fn spawn(&self, task: Box<dyn FnOnce() + Send>) {
+///         std::thread::spawn(task);
+///     }
+/// }
+/// ```
+///
+/// [`ArrowReaderOptions::with_decompress_pool`]: super::ArrowReaderOptions::with_decompress_pool
+pub trait Executor: Send + Sync + Debug {
+    /// Run `task` to completion, typically on another thread
+    ///
+    /// The task does not block on the reader, and so it is safe to run it
+    /// inline, though this will forgo any parallelism. If the task is dropped
+    /// without being run, reading the corresponding column chunk will fail.
+    fn spawn(&self, task: Box<dyn FnOnce() + Send>);
+}
+
+/// A decompressed page along with the state of the source [`PageReader`]
+/// before the page was read
+struct BufferedPage {
+    metadata: PageMetadata,
+    at_record_boundary: bool,
+    page: Page,
+}
+
+/// The maximum number of decompressed pages of a column chunk buffered ahead of
+/// the reader
+const READ_AHEAD_PAGES: usize = 4;
+
+type PageResult = Result<Option<BufferedPage>>;
+
+/// A [`PageReader`] that reads and decompresses the pages of another
+/// [`PageReader`] on an [`Executor`], yielding them in order
+///
+/// At most [`READ_AHEAD_PAGES`] pages are read ahead of the reader, once these
+/// are buffered the task returns, and is spawned again when the reader
+/// receives a page. This avoids occupying a thread of the [`Executor`] while
+/// waiting for the reader. Skipped pages are still decompressed, as the pages
+/// are read ahead of the reader.
+pub(crate) struct ExecutorPageReader {
+    executor: Arc<dyn Executor>,
+    receiver: Receiver<PageResult>,
+    state: Arc<Mutex<ReadAheadState>>,
+    /// The next page, if it has been received
+    next: Option<BufferedPage>,
+    /// Whether all pages have been received
+    finished: bool,
+    /// The index of the row group of the column chunk
+    row_group_idx: Option<usize>,
+}
+
+/// State shared between an [`ExecutorPageReader`] and its [`ReadAheadTask`]
+#[derive(Default)]
+struct ReadAheadState {
+    /// The number of pages sent but not yet received
+    buffered: usize,
+    /// The task, if it returned because [`READ_AHEAD_PAGES`] pages are buffered
+    paused: Option<ReadAheadTask>,
+}
+
+/// Reads pages from the source [`PageReader`] and sends them to the
+/// [`ExecutorPageReader`]
+struct ReadAheadTask {
+    reader: Box<dyn PageReader>,
+    sender: SyncSender<PageResult>,
+    state: Arc<Mutex<ReadAheadState>>,
+}
+
+impl ReadAheadTask {
+    fn spawn(self, executor: &Arc<dyn Executor>) {
+        executor.spawn(Box::new(move || self.run()));
+    }
+
+    /// Read pages until all have been read, [`READ_AHEAD_PAGES`] pages are
+    /// buffered, or the [`ExecutorPageReader`] is dropped
+    fn run(mut self) {
+        loop {
+            let page = next_buffered_page(self.reader.as_mut());
+            let done = !matches!(page, Ok(Some(_)));
+
+            // Sending while holding the lock ensures a paused task is only
+            // resumed once its last page has been sent
+            let state = Arc::clone(&self.state);
+            let mut state = state.lock().unwrap();
+            // Stop if the reader has been dropped
+            if self.sender.send(page).is_err() {
+                return;
+            }
+            state.buffered += 1;
+            if done {
+                return;
+            }
+            if state.buffered >= READ_AHEAD_PAGES {
+                state.paused = Some(self);
+                return;
+            }
+        }
+    }
+}
+
+impl ExecutorPageReader {
+    /// Spawn a task on `executor` that reads the pages from `reader`
+    pub(crate) fn new(executor: &Arc<dyn Executor>, reader: Box<dyn PageReader>) -> Self {
+        let (sender, receiver) = sync_channel(READ_AHEAD_PAGES);
+        let state = Arc::new(Mutex::new(ReadAheadState::default()));
+        let row_group_idx = reader.row_group_index();
+        let task = ReadAheadTask {
+            reader,
+            sender,
+            state: Arc::clone(&state),
+        };
+        task.spawn(executor);
+        Self {
+            executor: Arc::clone(executor),
+            receiver,
+            state,
+            next: None,
+            finished: false,
+            row_group_idx,
+        }
+    }
+
+    /// Receive the next page if necessary, returning a reference to it
+    fn peek(&mut self) -> Result<Option<&BufferedPage>> {
+        if self.next.is_none() && !self.finished {
+            let page = self.receiver.recv().map_err(|_| {
+                general_err!("Column chunk decompression task ended without completing")
+            });
+            if page.is_ok() {
+                self.resume();
+            }
+            match page.and_then(|page| page) {
+                Ok(Some(page)) => self.next = Some(page),
+                Ok(None) => self.finished = true,
+                Err(e) => {
+                    self.finished = true;
+                    return Err(e);
+                }
+            }
+        }
+        Ok(self.next.as_ref())
+    }
+
+    /// Record that a page was received, spawning the task again if it is paused
+    fn resume(&self) {
+        let paused = {
+            let mut state = self.state.lock().unwrap();
+            state.buffered -= 1;
+            state.paused.take()
+        };
+        if let Some(task) = paused {
+            task.spawn(&self.executor);
+        }
+    }
+}
+
+/// Read the next page from `reader`, recording its metadata
+fn next_buffered_page(reader: &mut dyn PageReader) -> Result<Option<BufferedPage>> {
+    let at_record_boundary = reader.at_record_boundary()?;
+    let Some(metadata) = reader.peek_next_page()? else {
+        return Ok(None);
+    };
+    let Some(page) = reader.get_next_page()? else {
+        return Err(general_err!(
+            "Page reader returned no page after peeking one"
+        ));
+    };
+    Ok(Some(BufferedPage {
+        metadata,
+        at_record_boundary,
+        page,
+    }))
+}
+
+impl PageReader for ExecutorPageReader {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        self.peek()?;
+        Ok(self.next.take().map(|page| page.page))
+    }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        Ok(self.peek()?.map(|page| page.metadata.clone()))
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        self.peek()?;
+        self.next = None;
+        Ok(())
+    }
+
+    fn at_record_boundary(&mut self) -> Result<bool> {
+        Ok(self.peek()?.is_none_or(|page| page.at_record_boundary))
+    }
+
+    fn row_group_index(&self) -> Option<usize> {
+        self.row_group_idx
+    }
+}
+
+impl Iterator for ExecutorPageReader {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_page().transpose()
+    }
+}
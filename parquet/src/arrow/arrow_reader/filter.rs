@@ -16,9 +16,23 @@
 // under the License.
 
 use crate::arrow::ProjectionMask;
-use arrow_array::{BooleanArray, RecordBatch};
-use arrow_schema::ArrowError;
+use crate::arrow::array_reader::ArrayReader;
+use crate::arrow::arrow_reader::ReadPlanBuilder;
+use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::SchemaDescriptor;
+use arrow_array::cast::AsArray;
+use arrow_array::{
+    Array, ArrayRef, BooleanArray, Datum, FixedSizeListArray, LargeListArray, ListArray,
+    RecordBatch, RecordBatchOptions, Scalar, StructArray,
+};
+use arrow_buffer::BooleanBuffer;
+use arrow_cast::{CastOptions, cast, cast_with_options};
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, DataType, FieldRef, Fields, Schema};
+use arrow_select::filter::prep_null_mask_filter;
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 /// A predicate operating on [`RecordBatch`]
 ///
@@ -119,6 +133,47 @@ where
     }
 }
 
+impl<F> ArrowPredicateFn<BorrowedFn<F>>
+where
+    F: FnMut(&RecordBatch) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    /// Create a new [`ArrowPredicateFn`] that invokes `f` with a reference to
+    /// the columns specified in `projection`
+    ///
+    /// This is equivalent to [`Self::new`], but accepts functions that only read
+    /// the batch, such as existing helpers taking a `&RecordBatch`, without
+    /// wrapping them in a closure that takes ownership of the batch.
+    ///
+    /// This is purely a convenience: the reader does not retain the batch, and
+    /// decodes the columns needed for the output projection in the same way for
+    /// both variants. The async reader can instead avoid decoding the columns of
+    /// a predicate twice using its predicate cache, see
+    /// [`ArrowReaderBuilder::with_max_predicate_cache_size`].
+    ///
+    /// [`ArrowReaderBuilder::with_max_predicate_cache_size`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_max_predicate_cache_size
+    ///
+    /// ```
+    /// # use arrow_array::{BooleanArray, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_schema::ArrowError;
+    /// # use parquet::arrow::ProjectionMask;
+    /// # use parquet::arrow::arrow_reader::ArrowPredicateFn;
+    /// fn is_positive(batch: &RecordBatch) -> Result<BooleanArray, ArrowError> {
+    ///     let column = batch.column(0).as_primitive::<Int64Type>();
+    ///     Ok(BooleanArray::from_unary(column, |v| v > 0))
+    /// }
+    ///
+    /// let predicate = ArrowPredicateFn::new_borrowed(ProjectionMask::all(), is_positive);
+    /// ```
+    pub fn new_borrowed(projection: ProjectionMask, f: F) -> Self {
+        Self {
+            f: BorrowedFn(f),
+            projection,
+        }
+    }
+}
+
 impl<F> ArrowPredicate for ArrowPredicateFn<F>
 where
     F: FnMut(RecordBatch) -> Result<BooleanArray, ArrowError> + Send + 'static,
@@ -132,6 +187,269 @@ where
     }
 }
 
+impl<F> ArrowPredicate for ArrowPredicateFn<BorrowedFn<F>>
+where
+    F: FnMut(&RecordBatch) -> Result<BooleanArray, ArrowError> + Send + 'static,
+{
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        (self.f.0)(&batch)
+    }
+}
+
+mod private {
+    /// A function evaluating a predicate on a borrowed [`RecordBatch`], see
+    /// [`ArrowPredicateFn::new_borrowed`]
+    ///
+    /// [`RecordBatch`]: arrow_array::RecordBatch
+    /// [`ArrowPredicateFn::new_borrowed`]: super::ArrowPredicateFn::new_borrowed
+    #[derive(Debug)]
+    pub struct BorrowedFn<F>(pub(super) F);
+}
+use private::BorrowedFn;
+
+/// A comparison operator for [`columns_cmp`] and [`SimplePredicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    /// `left == right`
+    Eq,
+    /// `left != right`
+    NotEq,
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+}
+
+/// Compares the columns named `left` and `right` of `batch` element-wise
+///
+/// The columns are looked up once per call, and then compared in a single
+/// pass using the [`arrow_ord::cmp`] kernels, which are specialized for each
+/// data type. The result is `null` where either input is `null`.
+///
+/// This is intended for evaluating predicates comparing two columns with an
+/// [`ArrowPredicateFn`], whose projection must contain both columns:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use parquet::arrow::{ArrowSchemaConverter, ProjectionMask};
+/// # use parquet::arrow::arrow_reader::{columns_cmp, ArrowPredicateFn, CmpOp, RowFilter};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int64Array::from(vec![1])) as ArrayRef),
+/// #     ("b", Arc::new(Int64Array::from(vec![1])) as ArrayRef),
+/// # ]).unwrap();
+/// # let schema_descr = ArrowSchemaConverter::new().convert(&batch.schema()).unwrap();
+/// // a < b
+/// let projection = ProjectionMask::leaves(&schema_descr, [0, 1]);
+/// let predicate = ArrowPredicateFn::new(projection, |batch| {
+///     columns_cmp(&batch, "a", "b", CmpOp::Lt)
+/// });
+/// let filter = RowFilter::new(vec![Box::new(predicate)]);
+/// ```
+///
+/// # Errors
+///
+/// * If either column is not present in `batch`
+/// * If the columns have different data types. Dictionary encoded columns
+///   are compared by their value type.
+pub fn columns_cmp(
+    batch: &RecordBatch,
+    left: &str,
+    right: &str,
+    op: CmpOp,
+) -> Result<BooleanArray, ArrowError> {
+    let schema = batch.schema_ref();
+    let l = batch.column(schema.index_of(left)?);
+    let r = batch.column(schema.index_of(right)?);
+
+    let value_type = |data_type: &DataType| match data_type {
+        DataType::Dictionary(_, value_type) => value_type.as_ref().clone(),
+        data_type => data_type.clone(),
+    };
+    if value_type(l.data_type()) != value_type(r.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare column \"{left}\" of type {} with column \"{right}\" of type {}",
+            l.data_type(),
+            r.data_type()
+        )));
+    }
+
+    compare(l, r, op)
+}
+
+/// Compares `l` and `r` with the [`arrow_ord::cmp`] kernel for `op`
+fn compare(l: &dyn Datum, r: &dyn Datum, op: CmpOp) -> Result<BooleanArray, ArrowError> {
+    match op {
+        CmpOp::Eq => cmp::eq(l, r),
+        CmpOp::NotEq => cmp::neq(l, r),
+        CmpOp::Lt => cmp::lt(l, r),
+        CmpOp::LtEq => cmp::lt_eq(l, r),
+        CmpOp::Gt => cmp::gt(l, r),
+        CmpOp::GtEq => cmp::gt_eq(l, r),
+    }
+}
+
+/// A predicate comparing a column to a literal value, `column OP literal`
+///
+/// This is the most common form of predicate, and [`SimplePredicate`] avoids
+/// the boilerplate of implementing it with an [`ArrowPredicateFn`]: the
+/// [`ProjectionMask`] is derived from the name of the column, and the column
+/// is compared with the [`arrow_ord::cmp`] kernels, which are specialized for
+/// each data type, including dictionaries and view types.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray};
+/// # use parquet::arrow::ArrowSchemaConverter;
+/// # use parquet::arrow::arrow_reader::{CmpOp, RowFilter, SimplePredicate};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("id", Arc::new(Int64Array::from(vec![1])) as ArrayRef),
+/// #     ("price", Arc::new(Float64Array::from(vec![1.0])) as ArrayRef),
+/// #     ("name", Arc::new(StringArray::from(vec!["foo"])) as ArrayRef),
+/// # ]).unwrap();
+/// # let schema_descr = ArrowSchemaConverter::new().convert(&batch.schema()).unwrap();
+/// // price > 99.0 AND name <> ''
+/// let filter = RowFilter::new(vec![
+///     SimplePredicate::new("price", CmpOp::Gt, Float64Array::new_scalar(99.0))
+///         .into_predicate(&schema_descr)
+///         .unwrap(),
+///     SimplePredicate::new("name", CmpOp::NotEq, StringArray::new_scalar(""))
+///         .into_predicate(&schema_descr)
+///         .unwrap(),
+/// ]);
+/// ```
+///
+/// If the type of the literal differs from that of the column, the literal is
+/// cast to the type of the column once, when the predicate is first evaluated.
+/// For example, an `Int64` literal can be compared to a timestamp column, or a
+/// `Utf8` literal to a `Utf8View` column. The cast fails if a string literal can
+/// not be parsed as the type of the column.
+///
+/// A numeric literal that can not be represented exactly by the type of the
+/// column, such as `1.5` for an integer column, or an `Int64` literal outside
+/// the range of an `Int32` column, is not truncated. Instead, the column is
+/// cast to the type of the literal before each comparison.
+///
+/// As for any [`ArrowPredicate`], rows for which the column is `null` are not
+/// selected.
+#[derive(Debug, Clone)]
+pub struct SimplePredicate {
+    column: String,
+    op: CmpOp,
+    literal: ArrayRef,
+}
+
+impl SimplePredicate {
+    /// Create a new [`SimplePredicate`] comparing the column named `column` to
+    /// `literal` with `op`, where the column is the left operand
+    pub fn new<T: Array + 'static>(
+        column: impl Into<String>,
+        op: CmpOp,
+        literal: Scalar<T>,
+    ) -> Self {
+        Self {
+            column: column.into(),
+            op,
+            literal: Arc::new(literal.into_inner()),
+        }
+    }
+
+    /// Convert this predicate into an [`ArrowPredicate`] for use in a [`RowFilter`]
+    /// or [`PredicateExpr`]
+    ///
+    /// `schema_descr` must be the [`SchemaDescriptor`] of the file being read
+    ///
+    /// Returns an error if `schema_descr` does not contain a top level primitive
+    /// column with the name of the column of this predicate
+    pub fn into_predicate(
+        self,
+        schema_descr: &SchemaDescriptor,
+    ) -> Result<Box<dyn ArrowPredicate>> {
+        let leaf = schema_descr
+            .columns()
+            .iter()
+            .position(|c| c.path().parts() == std::slice::from_ref(&self.column))
+            .ok_or_else(|| {
+                general_err!(
+                    "Column \"{}\" is not a top level primitive column of the parquet schema",
+                    self.column
+                )
+            })?;
+        Ok(Box::new(SimplePredicateEvaluator {
+            projection: ProjectionMask::leaves(schema_descr, [leaf]),
+            op: self.op,
+            literal: self.literal,
+            cast_column: false,
+        }))
+    }
+}
+
+/// The [`ArrowPredicate`] returned by [`SimplePredicate::into_predicate`]
+struct SimplePredicateEvaluator {
+    projection: ProjectionMask,
+    op: CmpOp,
+    /// The literal, cast to the type of the column once it is known, unless
+    /// this would lose information
+    literal: ArrayRef,
+    /// If the column is cast to the type of the literal instead
+    cast_column: bool,
+}
+
+impl SimplePredicateEvaluator {
+    /// Returns `self.literal` cast to `data_type`, or `None` if the numeric
+    /// literal can not be represented exactly by `data_type`
+    fn cast_literal(&self, data_type: &DataType) -> Result<Option<ArrayRef>, ArrowError> {
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        if !self.literal.data_type().is_numeric() {
+            return cast_with_options(&self.literal, data_type, &options).map(Some);
+        }
+        let Ok(literal) = cast_with_options(&self.literal, data_type, &options) else {
+            return Ok(None);
+        };
+        // The cast is lossless if casting back returns the original literal
+        let exact = cast_with_options(&literal, self.literal.data_type(), &options)
+            .is_ok_and(|roundtrip| roundtrip.as_ref() == self.literal.as_ref());
+        Ok(exact.then_some(literal))
+    }
+}
+
+impl ArrowPredicate for SimplePredicateEvaluator {
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        let column = batch.column(0);
+        let value_type = match column.data_type() {
+            DataType::Dictionary(_, value_type) => value_type.as_ref(),
+            data_type => data_type,
+        };
+        if !self.cast_column && self.literal.data_type() != value_type {
+            match self.cast_literal(value_type)? {
+                Some(literal) => self.literal = literal,
+                None => self.cast_column = true,
+            }
+        }
+        let literal = Scalar::new(Arc::clone(&self.literal));
+        if self.cast_column {
+            let column = cast(column, self.literal.data_type())?;
+            return compare(&column, &literal, self.op);
+        }
+        compare(column, &literal, self.op)
+    }
+}
+
 /// Filter applied *during* the parquet read process
 ///
 /// [`RowFilter`] applies predicates in order, after decoding only the columns
@@ -169,23 +487,270 @@ where
 /// to filter the data after the RecordBatch has been fully decoded, if the eliminated rows are
 /// not contiguous.
 ///
+/// By default, an error returned while evaluating a predicate fails the whole
+/// read. See [`RowFilter::with_error_policy`] to change this.
+///
 /// [`RowSelection`]: crate::arrow::arrow_reader::RowSelection
 pub struct RowFilter {
     /// A list of [`ArrowPredicate`]
     pub(crate) predicates: Vec<Box<dyn ArrowPredicate>>,
+    /// How to handle errors while evaluating `predicates`
+    pub(crate) error_policy: ErrorPolicy,
+    /// Called with the [`PredicateStats`] of each evaluation of a predicate
+    stats_callback: Option<PredicateStatsCallback>,
+    /// Called with the error of each row group skipped by [`ErrorPolicy::SkipRowGroup`]
+    error_callback: Option<RowGroupErrorCallback>,
 }
 
 impl Debug for RowFilter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "RowFilter {{ {} predicates: }}", self.predicates.len())
+        write!(
+            f,
+            "RowFilter {{ {} predicates, error_policy: {:?}, stats_callback: {}, error_callback: {} }}",
+            self.predicates.len(),
+            self.error_policy,
+            self.stats_callback.is_some(),
+            self.error_callback.is_some()
+        )
     }
 }
 
+/// The number of rows evaluated and selected by a predicate of a [`RowFilter`]
+///
+/// See [`RowFilter::with_stats_callback`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PredicateStats {
+    /// The index of the predicate within the [`RowFilter`]
+    pub predicate_index: usize,
+    /// The number of rows passed to the predicate, that is the rows that
+    /// survived the previous predicates
+    pub rows_evaluated: usize,
+    /// The number of rows for which the predicate returned `true`
+    pub rows_selected: usize,
+}
+
+/// A callback receiving [`PredicateStats`], see [`RowFilter::with_stats_callback`]
+pub type PredicateStatsCallback = Arc<dyn Fn(PredicateStats) + Send + Sync>;
+
+/// A callback receiving the index of a row group skipped by
+/// [`ErrorPolicy::SkipRowGroup`] and the error that caused it, see
+/// [`RowFilter::with_error_callback`]
+pub type RowGroupErrorCallback = Arc<dyn Fn(usize, ArrowError) + Send + Sync>;
+
+/// What to do when evaluating a [`RowFilter`] fails
+///
+/// See [`RowFilter::with_error_policy`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Return the error, failing the read (default)
+    #[default]
+    Fail,
+    /// Skip the row group being filtered and continue with the next one
+    ///
+    /// No rows are returned from a skipped row group. The number of row groups
+    /// skipped is recorded in [`ArrowReaderMetrics::row_groups_skipped`], and
+    /// the errors can be observed with [`RowFilter::with_error_callback`].
+    ///
+    /// **Warning**: this silently drops data from the result of the scan.
+    ///
+    /// [`ArrowReaderMetrics::row_groups_skipped`]: crate::arrow::arrow_reader::metrics::ArrowReaderMetrics::row_groups_skipped
+    SkipRowGroup,
+}
+
 impl RowFilter {
     /// Create a new [`RowFilter`] from an array of [`ArrowPredicate`]
     pub fn new(predicates: Vec<Box<dyn ArrowPredicate>>) -> Self {
-        Self { predicates }
+        Self {
+            predicates,
+            error_policy: ErrorPolicy::default(),
+            stats_callback: None,
+            error_callback: None,
+        }
+    }
+
+    /// Set the [`ErrorPolicy`] applied when evaluating the predicates fails,
+    /// defaults to [`ErrorPolicy::Fail`]
+    ///
+    /// This covers errors returned by [`ArrowPredicate::evaluate`] as well as
+    /// errors decoding the columns a predicate needs. Errors fetching data or
+    /// decoding the final projection always fail the read.
+    ///
+    /// With [`ErrorPolicy::SkipRowGroup`], a row group whose predicates fail is
+    /// excluded from the output in its entirety, which means data is **silently
+    /// dropped**. Only opt into this when an incomplete result is acceptable, and
+    /// use [`ArrowReaderMetrics`] or [`Self::with_error_callback`] to detect when
+    /// it happens.
+    ///
+    /// [`ArrowReaderMetrics`]: crate::arrow::arrow_reader::metrics::ArrowReaderMetrics
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
     }
+
+    /// Returns the [`ErrorPolicy`] of this filter
+    pub fn error_policy(&self) -> ErrorPolicy {
+        self.error_policy
+    }
+
+    /// Set a callback that is called with the [`PredicateStats`] of each
+    /// evaluation of a predicate, to observe how selective each predicate is
+    ///
+    /// A predicate may be evaluated several times while reading a file, for
+    /// example once per row group, and the callback is called each time. Sum
+    /// the stats of each `predicate_index` to get the totals for the read.
+    ///
+    /// The callback is not called for a predicate that is not evaluated, for
+    /// example because the previous predicates ruled out all rows, or whose
+    /// evaluation fails.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use arrow_array::{ArrayRef, BooleanArray, Int32Array, RecordBatch};
+    /// # use parquet::arrow::{ArrowSchemaConverter, ProjectionMask};
+    /// # use parquet::arrow::arrow_reader::{ArrowPredicateFn, PredicateStats, RowFilter};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let schema_descr = ArrowSchemaConverter::new().convert(&batch.schema()).unwrap();
+    /// # let predicate = ArrowPredicateFn::new(ProjectionMask::all(), |batch: RecordBatch| {
+    /// #     Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+    /// # });
+    /// let stats = Arc::new(Mutex::new(vec![PredicateStats::default()]));
+    /// let captured = Arc::clone(&stats);
+    /// let filter = RowFilter::new(vec![Box::new(predicate)]).with_stats_callback(move |s| {
+    ///     let mut stats = captured.lock().unwrap();
+    ///     stats[s.predicate_index].rows_evaluated += s.rows_evaluated;
+    ///     stats[s.predicate_index].rows_selected += s.rows_selected;
+    /// });
+    /// ```
+    pub fn with_stats_callback(
+        mut self,
+        callback: impl Fn(PredicateStats) + Send + Sync + 'static,
+    ) -> Self {
+        self.stats_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Set a callback that is called with the index of each row group skipped by
+    /// [`ErrorPolicy::SkipRowGroup`], and the error evaluating its predicates
+    ///
+    /// The callback is not called with [`ErrorPolicy::Fail`], as the error is
+    /// returned by the reader instead.
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use arrow_array::{BooleanArray, RecordBatch};
+    /// # use parquet::arrow::ProjectionMask;
+    /// # use parquet::arrow::arrow_reader::{ArrowPredicateFn, ErrorPolicy, RowFilter};
+    /// # let predicate = ArrowPredicateFn::new(ProjectionMask::all(), |batch: RecordBatch| {
+    /// #     Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+    /// # });
+    /// let errors = Arc::new(Mutex::new(vec![]));
+    /// let captured = Arc::clone(&errors);
+    /// let filter = RowFilter::new(vec![Box::new(predicate)])
+    ///     .with_error_policy(ErrorPolicy::SkipRowGroup)
+    ///     .with_error_callback(move |row_group_idx, error| {
+    ///         captured.lock().unwrap().push((row_group_idx, error));
+    ///     });
+    /// ```
+    pub fn with_error_callback(
+        mut self,
+        callback: impl Fn(usize, ArrowError) + Send + Sync + 'static,
+    ) -> Self {
+        self.error_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Handles `error` evaluating the predicates of row group `row_group_idx`
+    /// according to the [`ErrorPolicy`]
+    ///
+    /// Returns the error if the read should fail, otherwise records that the
+    /// row group is skipped
+    pub(crate) fn handle_error(
+        &self,
+        row_group_idx: usize,
+        error: ParquetError,
+        metrics: &ArrowReaderMetrics,
+    ) -> Result<()> {
+        match self.error_policy {
+            ErrorPolicy::Fail => Err(error),
+            ErrorPolicy::SkipRowGroup => {
+                metrics.increment_row_groups_skipped();
+                if let Some(callback) = &self.error_callback {
+                    // Errors returned by predicates are wrapped, unwrap these
+                    let error = match error {
+                        ParquetError::External(e) => match e.downcast::<ArrowError>() {
+                            Ok(e) => *e,
+                            Err(e) => ParquetError::External(e).into(),
+                        },
+                        e => e.into(),
+                    };
+                    callback(row_group_idx, error);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Evaluates the predicate at `index` with `array_reader`, see
+    /// [`ReadPlanBuilder::with_predicate`], reporting its [`PredicateStats`]
+    /// to the stats callback, if any
+    pub(crate) fn evaluate_predicate(
+        &mut self,
+        index: usize,
+        plan_builder: ReadPlanBuilder,
+        array_reader: Box<dyn ArrayReader>,
+    ) -> Result<ReadPlanBuilder> {
+        let predicate = self.predicates[index].as_mut();
+        let Some(callback) = &self.stats_callback else {
+            return plan_builder.with_predicate(array_reader, predicate);
+        };
+        let mut stats = PredicateStats {
+            predicate_index: index,
+            ..Default::default()
+        };
+        let plan_builder =
+            plan_builder.with_predicate_stats(array_reader, predicate, &mut stats)?;
+        callback(stats);
+        Ok(plan_builder)
+    }
+
+    /// Returns the [`ProjectionMask`] of all columns required to evaluate this
+    /// filter, that is the union of [`ArrowPredicate::projection`] for each predicate
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, BooleanArray, Int32Array, RecordBatch};
+    /// # use parquet::arrow::{ArrowSchemaConverter, ProjectionMask};
+    /// # use parquet::arrow::arrow_reader::{ArrowPredicateFn, RowFilter};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+    /// #     ("b", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+    /// #     ("c", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let schema_descr = ArrowSchemaConverter::new().convert(&batch.schema()).unwrap();
+    /// let predicate = |mask| {
+    ///     Box::new(ArrowPredicateFn::new(mask, |batch: RecordBatch| {
+    ///         Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+    ///     })) as _
+    /// };
+    /// let filter = RowFilter::new(vec![
+    ///     predicate(ProjectionMask::leaves(&schema_descr, [0])),
+    ///     predicate(ProjectionMask::leaves(&schema_descr, [2])),
+    /// ]);
+    ///
+    /// // Columns "a" and "c" are needed to evaluate the filter
+    /// let projection = filter.required_projection(&schema_descr);
+    /// assert_eq!(projection, ProjectionMask::leaves(&schema_descr, [0, 2]));
+    /// ```
+    pub fn required_projection(&self, schema_descr: &SchemaDescriptor) -> ProjectionMask {
+        let mut projection = ProjectionMask::none(schema_descr.num_columns());
+        for predicate in &self.predicates {
+            projection.union(predicate.projection());
+        }
+        projection
+    }
+
     /// Returns the inner predicates
     pub fn predicates(&self) -> &Vec<Box<dyn ArrowPredicate>> {
         &self.predicates
@@ -195,3 +760,333 @@ impl RowFilter {
         self.predicates
     }
 }
+
+/// A boolean combination of [`ArrowPredicate`]s
+///
+/// [`RowFilter`] only supports a conjunction of predicates, each evaluated on
+/// the rows that survived the previous ones. [`PredicateExpr`] allows
+/// composing predicates with `AND`, `OR` and `NOT` into a single
+/// [`ArrowPredicate`] that can then be added to a [`RowFilter`]:
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::compute::kernels::cmp::{eq, gt};
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use parquet::arrow::{ArrowSchemaConverter, ProjectionMask};
+/// # use parquet::arrow::arrow_reader::{ArrowPredicateFn, PredicateExpr, RowFilter};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+/// #     ("b", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+/// # ]).unwrap();
+/// # let schema_descr = ArrowSchemaConverter::new().convert(&batch.schema()).unwrap();
+/// // a > 10
+/// let a_gt_10 = ArrowPredicateFn::new(ProjectionMask::leaves(&schema_descr, [0]), |batch| {
+///     gt(batch.column(0), &Int32Array::new_scalar(10))
+/// });
+/// // b = 5
+/// let b_eq_5 = ArrowPredicateFn::new(ProjectionMask::leaves(&schema_descr, [1]), |batch| {
+///     eq(batch.column(0), &Int32Array::new_scalar(5))
+/// });
+///
+/// // (a > 10) OR NOT (b = 5)
+/// let expr = PredicateExpr::new(Box::new(a_gt_10)).or(!PredicateExpr::new(Box::new(b_eq_5)));
+/// let filter = RowFilter::new(vec![expr.into_predicate(&schema_descr)]);
+/// ```
+///
+/// Each predicate is passed a [`RecordBatch`] containing only the columns of
+/// its own [`ArrowPredicate::projection`], and the results are then combined.
+/// A `null` result is treated as `false` before combining, consistent with
+/// [`ArrowPredicate::evaluate`], so `NOT` selects the rows for which the inner
+/// predicate returned `null`.
+///
+/// Note that, unlike separate predicates in a [`RowFilter`], the combined
+/// predicate is evaluated in one step on the union of the columns of all its
+/// predicates. In particular, both sides of an `OR` must be decoded, even for
+/// rows already selected by one of them.
+pub enum PredicateExpr {
+    /// A single [`ArrowPredicate`]
+    Predicate(Box<dyn ArrowPredicate>),
+    /// Rows for which both expressions are `true`
+    And(Box<PredicateExpr>, Box<PredicateExpr>),
+    /// Rows for which either expression is `true`
+    Or(Box<PredicateExpr>, Box<PredicateExpr>),
+    /// Rows for which the expression is not `true`
+    Not(Box<PredicateExpr>),
+}
+
+impl Debug for PredicateExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Predicate(_) => write!(f, "Predicate"),
+            Self::And(l, r) => write!(f, "({l:?} AND {r:?})"),
+            Self::Or(l, r) => write!(f, "({l:?} OR {r:?})"),
+            Self::Not(e) => write!(f, "NOT {e:?}"),
+        }
+    }
+}
+
+impl PredicateExpr {
+    /// Create a new [`PredicateExpr`] from a single [`ArrowPredicate`]
+    pub fn new(predicate: Box<dyn ArrowPredicate>) -> Self {
+        Self::Predicate(predicate)
+    }
+
+    /// Returns an expression selecting the rows for which both `self` and
+    /// `other` are `true`
+    pub fn and(self, other: PredicateExpr) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// Returns an expression selecting the rows for which either `self` or
+    /// `other` is `true`
+    pub fn or(self, other: PredicateExpr) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Returns the [`ProjectionMask`] of all columns required to evaluate this
+    /// expression, that is the union of the projections of its predicates
+    pub fn projection(&self, schema_descr: &SchemaDescriptor) -> ProjectionMask {
+        match self {
+            Self::Predicate(p) => {
+                let mut projection = ProjectionMask::none(schema_descr.num_columns());
+                projection.union(p.projection());
+                projection
+            }
+            Self::And(l, r) | Self::Or(l, r) => {
+                let mut projection = l.projection(schema_descr);
+                projection.union(&r.projection(schema_descr));
+                projection
+            }
+            Self::Not(e) => e.projection(schema_descr),
+        }
+    }
+
+    /// Convert this expression into an [`ArrowPredicate`] for use in a [`RowFilter`]
+    ///
+    /// `schema_descr` must be the [`SchemaDescriptor`] of the file being read
+    pub fn into_predicate(self, schema_descr: &SchemaDescriptor) -> Box<dyn ArrowPredicate> {
+        let projection = self.projection(schema_descr);
+        let leaf_roots = (0..schema_descr.num_columns())
+            .map(|leaf| schema_descr.get_column_root_idx(leaf))
+            .collect();
+        Box::new(PredicateExprEvaluator {
+            expr: self,
+            projection,
+            leaf_roots,
+        })
+    }
+}
+
+impl std::ops::Not for PredicateExpr {
+    type Output = Self;
+
+    /// Returns an expression selecting the rows for which `self` is not `true`
+    fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// The [`ArrowPredicate`] returned by [`PredicateExpr::into_predicate`]
+struct PredicateExprEvaluator {
+    expr: PredicateExpr,
+    /// The union of the projections of all predicates in `expr`
+    projection: ProjectionMask,
+    /// The root column index of each leaf column
+    leaf_roots: Vec<usize>,
+}
+
+impl ArrowPredicate for PredicateExprEvaluator {
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        let values = evaluate_expr(&mut self.expr, &batch, &self.projection, &self.leaf_roots)?;
+        Ok(BooleanArray::new(values, None))
+    }
+}
+
+/// Evaluates `expr` on `batch`, which contains the columns of `projection`,
+/// returning the selected rows with `null` mapped to `false`
+fn evaluate_expr(
+    expr: &mut PredicateExpr,
+    batch: &RecordBatch,
+    projection: &ProjectionMask,
+    leaf_roots: &[usize],
+) -> Result<BooleanBuffer, ArrowError> {
+    match expr {
+        PredicateExpr::Predicate(predicate) => {
+            let input = if predicate.projection() == projection {
+                batch.clone()
+            } else {
+                project_batch(batch, projection, predicate.projection(), leaf_roots)?
+            };
+            let result = predicate.evaluate(input)?;
+            if result.len() != batch.num_rows() {
+                return Err(ArrowError::ComputeError(format!(
+                    "Predicate returned {} rows, expected {}",
+                    result.len(),
+                    batch.num_rows()
+                )));
+            }
+            Ok(match result.null_count() {
+                0 => result.values().clone(),
+                _ => prep_null_mask_filter(&result).values().clone(),
+            })
+        }
+        PredicateExpr::And(l, r) => {
+            let l = evaluate_expr(l, batch, projection, leaf_roots)?;
+            let r = evaluate_expr(r, batch, projection, leaf_roots)?;
+            Ok(&l & &r)
+        }
+        PredicateExpr::Or(l, r) => {
+            let l = evaluate_expr(l, batch, projection, leaf_roots)?;
+            let r = evaluate_expr(r, batch, projection, leaf_roots)?;
+            Ok(&l | &r)
+        }
+        PredicateExpr::Not(e) => Ok(!&evaluate_expr(e, batch, projection, leaf_roots)?),
+    }
+}
+
+/// Projects `batch`, decoded with the `from` projection, to the leaves of `to`
+fn project_batch(
+    batch: &RecordBatch,
+    from: &ProjectionMask,
+    to: &ProjectionMask,
+    leaf_roots: &[usize],
+) -> Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let mut leaves = (0..leaf_roots.len())
+        .filter(|leaf| from.leaf_included(*leaf))
+        .peekable();
+
+    let mut fields = Vec::new();
+    let mut columns = Vec::new();
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let root = leaves.peek().map(|leaf| leaf_roots[*leaf]);
+        let mut root_leaves =
+            std::iter::from_fn(|| leaves.next_if(|leaf| Some(leaf_roots[*leaf]) == root));
+        if let Some((field, column)) = project_array(field, column, &mut root_leaves, to)? {
+            fields.push(field);
+            columns.push(column);
+        }
+        // Skip any leaves not consumed
+        root_leaves.for_each(drop);
+    }
+
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+}
+
+/// Projects `array`, whose leaves are produced by `leaves`, to the leaves of
+/// `projection`, returning `None` if none are included
+fn project_array(
+    field: &FieldRef,
+    array: &ArrayRef,
+    leaves: &mut dyn Iterator<Item = usize>,
+    projection: &ProjectionMask,
+) -> Result<Option<(FieldRef, ArrayRef)>, ArrowError> {
+    let with_values = |values: (FieldRef, ArrayRef)| {
+        let data_type = match field.data_type() {
+            DataType::List(_) => DataType::List(values.0.clone()),
+            DataType::LargeList(_) => DataType::LargeList(values.0.clone()),
+            DataType::FixedSizeList(_, size) => DataType::FixedSizeList(values.0.clone(), *size),
+            _ => unreachable!(),
+        };
+        Arc::new(field.as_ref().clone().with_data_type(data_type))
+    };
+
+    Ok(match field.data_type() {
+        DataType::Struct(child_fields) => {
+            let struct_array = array.as_struct();
+            let mut projected_fields = Vec::new();
+            let mut projected_columns = Vec::new();
+            for (child_field, child) in child_fields.iter().zip(struct_array.columns()) {
+                if let Some((f, c)) = project_array(child_field, child, leaves, projection)? {
+                    projected_fields.push(f);
+                    projected_columns.push(c);
+                }
+            }
+            match projected_fields.is_empty() {
+                true => None,
+                false => {
+                    let fields = Fields::from(projected_fields);
+                    let data_type = DataType::Struct(fields.clone());
+                    let array = StructArray::try_new_with_length(
+                        fields,
+                        projected_columns,
+                        struct_array.nulls().cloned(),
+                        struct_array.len(),
+                    )?;
+                    Some((
+                        Arc::new(field.as_ref().clone().with_data_type(data_type)),
+                        Arc::new(array),
+                    ))
+                }
+            }
+        }
+        DataType::List(values_field) => {
+            let list = array.as_list::<i32>();
+            project_array(values_field, list.values(), leaves, projection)?
+                .map(|values| {
+                    let array = ListArray::try_new(
+                        values.0.clone(),
+                        list.offsets().clone(),
+                        values.1.clone(),
+                        list.nulls().cloned(),
+                    )?;
+                    Ok::<_, ArrowError>((with_values(values) as FieldRef, Arc::new(array) as _))
+                })
+                .transpose()?
+        }
+        DataType::LargeList(values_field) => {
+            let list = array.as_list::<i64>();
+            project_array(values_field, list.values(), leaves, projection)?
+                .map(|values| {
+                    let array = LargeListArray::try_new(
+                        values.0.clone(),
+                        list.offsets().clone(),
+                        values.1.clone(),
+                        list.nulls().cloned(),
+                    )?;
+                    Ok::<_, ArrowError>((with_values(values) as FieldRef, Arc::new(array) as _))
+                })
+                .transpose()?
+        }
+        DataType::FixedSizeList(values_field, size) => {
+            let list = array.as_fixed_size_list();
+            project_array(values_field, list.values(), leaves, projection)?
+                .map(|values| {
+                    let array = FixedSizeListArray::try_new(
+                        values.0.clone(),
+                        *size,
+                        values.1.clone(),
+                        list.nulls().cloned(),
+                    )?;
+                    Ok::<_, ArrowError>((with_values(values) as FieldRef, Arc::new(array) as _))
+                })
+                .transpose()?
+        }
+        data_type => {
+            // Any other type, including maps, is projected as a whole
+            let mut included = false;
+            for leaf in leaves.take(num_leaves(data_type)) {
+                included |= projection.leaf_included(leaf);
+            }
+            included.then(|| (field.clone(), array.clone()))
+        }
+    })
+}
+
+/// Returns the number of parquet leaf columns of `data_type`
+fn num_leaves(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Struct(fields) => fields.iter().map(|f| num_leaves(f.data_type())).sum(),
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => num_leaves(f.data_type()),
+        _ => 1,
+    }
+}
@@ -90,6 +90,45 @@ impl ArrowReaderMetrics {
         }
     }
 
+    /// Number of row groups skipped because evaluating a [`RowFilter`] failed
+    ///
+    /// Only row groups skipped due to [`ErrorPolicy::SkipRowGroup`] are counted.
+    ///
+    /// Returns None if metrics are disabled.
+    ///
+    /// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+    /// [`ErrorPolicy::SkipRowGroup`]: crate::arrow::arrow_reader::ErrorPolicy::SkipRowGroup
+    pub fn row_groups_skipped(&self) -> Option<usize> {
+        match self {
+            Self::Disabled => None,
+            Self::Enabled(inner) => Some(
+                inner
+                    .row_groups_skipped
+                    .load(std::sync::atomic::Ordering::Relaxed),
+            ),
+        }
+    }
+
+    /// Lazy Page Index: number of page index bytes decoded while reading
+    ///
+    /// When [`ArrowReaderOptions::with_lazy_page_index`] is enabled, the page
+    /// index is only read for the row groups that are actually scanned. This
+    /// is the total size of the page index bytes fetched and decoded for them.
+    ///
+    /// Returns None if metrics are disabled.
+    ///
+    /// [`ArrowReaderOptions::with_lazy_page_index`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_lazy_page_index
+    pub fn page_index_bytes_read(&self) -> Option<usize> {
+        match self {
+            Self::Disabled => None,
+            Self::Enabled(inner) => Some(
+                inner
+                    .page_index_bytes_read
+                    .load(std::sync::atomic::Ordering::Relaxed),
+            ),
+        }
+    }
+
     /// Increments the count of records read from the inner reader
     pub(crate) fn increment_inner_reads(&self, count: usize) {
         let Self::Enabled(inner) = self else {
@@ -110,6 +149,28 @@ impl ArrowReaderMetrics {
             .records_read_from_cache
             .fetch_add(count, std::sync::atomic::Ordering::Relaxed);
     }
+
+    /// Increments the count of row groups skipped due to a filter error
+    pub(crate) fn increment_row_groups_skipped(&self) {
+        let Self::Enabled(inner) = self else {
+            return;
+        };
+
+        inner
+            .row_groups_skipped
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Increments the number of page index bytes read lazily
+    pub(crate) fn increment_page_index_bytes_read(&self, count: usize) {
+        let Self::Enabled(inner) = self else {
+            return;
+        };
+
+        inner
+            .page_index_bytes_read
+            .fetch_add(count, std::sync::atomic::Ordering::Relaxed);
+    }
 }
 
 /// Holds the actual metrics for the Arrow reader.
@@ -122,6 +183,14 @@ pub struct ArrowReaderMetricsInner {
     records_read_from_inner: AtomicUsize,
     /// Total number of records read from previously cached pages
     records_read_from_cache: AtomicUsize,
+
+    // Metrics for RowFilter
+    /// Total number of row groups skipped due to errors evaluating a filter
+    row_groups_skipped: AtomicUsize,
+
+    // Metrics for lazy page index loading
+    /// Total number of page index bytes read for the scanned row groups
+    page_index_bytes_read: AtomicUsize,
 }
 
 impl ArrowReaderMetricsInner {
@@ -130,6 +199,8 @@ impl ArrowReaderMetricsInner {
         Self {
             records_read_from_inner: AtomicUsize::new(0),
             records_read_from_cache: AtomicUsize::new(0),
+            row_groups_skipped: AtomicUsize::new(0),
+            page_index_bytes_read: AtomicUsize::new(0),
         }
     }
 }
@@ -20,17 +20,31 @@
 use arrow_array::Array;
 use arrow_array::cast::AsArray;
 use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
-pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+use arrow_schema::{
+    ArrowError, DataType as ArrowType, FieldRef, Fields, Schema, SchemaRef, TimeUnit,
+};
+use bytes::Bytes;
+pub use executor::Executor;
+pub use filter::{
+    ArrowPredicate, ArrowPredicateFn, CmpOp, ErrorPolicy, PredicateExpr, PredicateStats,
+    PredicateStatsCallback, RowFilter, RowGroupErrorCallback, SimplePredicate, columns_cmp,
+};
+pub use projected::ProjectedReader;
 pub use selection::{RowSelection, RowSelector};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 pub use crate::arrow::array_reader::RowGroups;
 use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder};
-use crate::arrow::schema::{ParquetField, parquet_to_arrow_schema_and_fields};
-use crate::arrow::{FieldLevels, ProjectionMask, parquet_to_arrow_field_levels};
-use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
+use crate::arrow::schema::{
+    ParquetField, ParquetFieldType, embedded_arrow_schema,
+    parquet_to_arrow_field_levels_with_policy, parquet_to_arrow_schema_and_fields,
+    restore_extension_metadata,
+};
+use crate::arrow::{FieldLevels, ProjectionMask};
+use crate::basic::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, Type as PhysicalType,
+};
 use crate::bloom_filter::{
     SBBF_HEADER_SIZE_ESTIMATE, Sbbf, chunk_read_bloom_filter_header_and_offset,
 };
@@ -38,15 +52,27 @@ use crate::column::page::{PageIterator, PageReader};
 #[cfg(feature = "encryption")]
 use crate::encryption::decrypt::FileDecryptionProperties;
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader};
+use crate::file::FOOTER_SIZE;
+#[cfg(feature = "encryption")]
+use crate::file::column_crypto_metadata::ColumnCryptoMetaData;
+use crate::file::metadata::thrift::parquet_schema_from_bytes;
+use crate::file::metadata::{
+    FooterTail, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, ParquetOffsetIndex,
+    parse_row_group_offset_index, row_group_offset_index_range,
+};
 use crate::file::reader::{ChunkReader, SerializedPageReader};
+use crate::schema::types::ColumnPath;
 use crate::schema::types::SchemaDescriptor;
+use std::collections::{HashMap, VecDeque};
 
 use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+pub(crate) use executor::ExecutorPageReader;
 pub use read_plan::{ReadPlan, ReadPlanBuilder};
 
+mod executor;
 mod filter;
 pub mod metrics;
+mod projected;
 mod read_plan;
 mod selection;
 pub mod statistics;
@@ -124,6 +150,22 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) metrics: ArrowReaderMetrics,
 
     pub(crate) max_predicate_cache_size: usize,
+
+    pub(crate) batch_transform: Option<BatchTransform>,
+
+    pub(crate) verify_page_checksums: bool,
+
+    pub(crate) preallocate_from_stats: bool,
+
+    pub(crate) decimal_validation: bool,
+
+    pub(crate) decompress_pool: Option<Arc<dyn Executor>>,
+
+    pub(crate) lazy_page_index_policy: PageIndexPolicy,
+
+    pub(crate) fetch_coalesce_gap: Option<usize>,
+
+    pub(crate) row_group_aligned_batches: bool,
 }
 
 impl<T: Debug> Debug for ArrowReaderBuilder<T> {
@@ -141,6 +183,17 @@ impl<T: Debug> Debug for ArrowReaderBuilder<T> {
             .field("limit", &self.limit)
             .field("offset", &self.offset)
             .field("metrics", &self.metrics)
+            .field(
+                "batch_transform",
+                &self.batch_transform.as_ref().map(|_| "..."),
+            )
+            .field("verify_page_checksums", &self.verify_page_checksums)
+            .field("preallocate_from_stats", &self.preallocate_from_stats)
+            .field("decimal_validation", &self.decimal_validation)
+            .field("decompress_pool", &self.decompress_pool)
+            .field("lazy_page_index_policy", &self.lazy_page_index_policy)
+            .field("fetch_coalesce_gap", &self.fetch_coalesce_gap)
+            .field("row_group_aligned_batches", &self.row_group_aligned_batches)
             .finish()
     }
 }
@@ -161,6 +214,45 @@ impl<T> ArrowReaderBuilder<T> {
             offset: None,
             metrics: ArrowReaderMetrics::Disabled,
             max_predicate_cache_size: 100 * 1024 * 1024, // 100MB default cache size
+            batch_transform: None,
+            verify_page_checksums: true,
+            preallocate_from_stats: false,
+            decimal_validation: false,
+            decompress_pool: None,
+            lazy_page_index_policy: PageIndexPolicy::Skip,
+            fetch_coalesce_gap: None,
+            row_group_aligned_batches: false,
+        }
+    }
+
+    /// Apply the [`ArrowReaderOptions`] that control how column data is read,
+    /// rather than how the metadata and schema are determined
+    ///
+    /// These are [`ArrowReaderOptions::with_verify_page_checksums`],
+    /// [`ArrowReaderOptions::with_preallocate_from_stats`],
+    /// [`ArrowReaderOptions::with_decimal_validation`],
+    /// [`ArrowReaderOptions::with_decompress_pool`],
+    /// [`ArrowReaderOptions::with_lazy_page_index`] and
+    /// [`ArrowReaderOptions::with_fetch_coalesce_gap`].
+    ///
+    /// The options are not stored in [`ArrowReaderMetadata`], and so are applied
+    /// when the builder is created from [`ArrowReaderOptions`], but not when it is
+    /// created from an existing [`ArrowReaderMetadata`], such as with
+    /// [`ParquetRecordBatchReaderBuilder::new_with_metadata`]. In that case use
+    /// this method to apply them.
+    pub fn with_reader_options(self, options: &ArrowReaderOptions) -> Self {
+        let lazy_page_index_policy = match options.lazy_page_index {
+            true if self.metadata.offset_index().is_none() => options.page_index_policy,
+            _ => PageIndexPolicy::Skip,
+        };
+        Self {
+            verify_page_checksums: !options.skip_page_checksums,
+            preallocate_from_stats: options.preallocate_from_stats,
+            decimal_validation: options.decimal_validation,
+            decompress_pool: options.decompress_pool.clone(),
+            lazy_page_index_policy,
+            fetch_coalesce_gap: options.fetch_coalesce_gap,
+            ..self
         }
     }
 
@@ -179,6 +271,43 @@ impl<T> ArrowReaderBuilder<T> {
         &self.schema
     }
 
+    /// Returns an estimate of the memory, in bytes, needed to hold the decoded
+    /// data of this read, computed from the metadata without reading any data
+    ///
+    /// This is the sum of the uncompressed sizes of the column chunks in the
+    /// [`Self::with_projection`] projection, across the row groups selected
+    /// by [`Self::with_row_groups`], or all row groups if not set.
+    ///
+    /// # Accuracy
+    ///
+    /// This is an estimate of the total size of the decoded data, and not the
+    /// peak memory used by the reader, which decodes at most
+    /// [`Self::with_batch_size`] rows at a time. As the uncompressed sizes are
+    /// of the encoded pages, the estimate:
+    ///
+    /// * Is close to the decoded size for `PLAIN` encoded data
+    /// * May be much smaller than the decoded size for dictionary or
+    ///   run-length encoded data, whose values are expanded when decoded
+    /// * Includes page headers and levels, and does not account for any
+    ///   [`RowSelection`], [`RowFilter`], offset or limit, nor for columns only
+    ///   read by a [`RowFilter`]
+    pub fn estimated_decoded_size(&self) -> usize {
+        let row_groups = self.metadata.row_groups();
+        let size = |idx: &usize| -> usize {
+            let row_group = &row_groups[*idx];
+            // invalid lazily decoded metadata is reported when building the reader
+            (0..row_group.num_columns())
+                .filter(|leaf| self.projection.leaf_included(*leaf))
+                .filter_map(|leaf| row_group.try_column(leaf).ok())
+                .map(|column| column.uncompressed_size().max(0) as usize)
+                .sum()
+        };
+        match &self.row_groups {
+            Some(selected) => selected.iter().map(size).sum(),
+            None => (0..row_groups.len()).map(|idx| size(&idx)).sum(),
+        }
+    }
+
     /// Set the size of [`RecordBatch`] to produce. Defaults to 1024
     /// If the batch_size more than the file row count, use the file row count.
     pub fn with_batch_size(self, batch_size: usize) -> Self {
@@ -187,6 +316,26 @@ impl<T> ArrowReaderBuilder<T> {
         Self { batch_size, ..self }
     }
 
+    /// Produce one [`RecordBatch`] per row group, instead of batches of
+    /// [`Self::with_batch_size`] rows that may span row groups. Defaults to `false`
+    ///
+    /// When enabled, the batch size is ignored, and each batch contains the rows of a
+    /// single row group remaining after any [`RowSelection`], [`RowFilter`], offset and
+    /// limit have been applied. Row groups with no remaining rows produce no batch. This
+    /// allows correlating each batch with the corresponding [`RowGroupMetaData`], for
+    /// example to use its statistics.
+    ///
+    /// Note: the entire row group is decoded into memory at once, which for large
+    /// row groups may use significantly more memory than reading smaller batches.
+    ///
+    /// [`RowGroupMetaData`]: crate::file::metadata::RowGroupMetaData
+    pub fn with_row_group_aligned_batches(self, row_group_aligned_batches: bool) -> Self {
+        Self {
+            row_group_aligned_batches,
+            ..self
+        }
+    }
+
     /// Only read data from the provided row group indexes
     ///
     /// This is also called row group filtering
@@ -271,6 +420,77 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Only read the selected rows of the provided row groups
+    ///
+    /// `selections` maps the index of each row group to read to a [`RowSelection`]
+    /// of the rows to read within that row group, relative to its first row. Row
+    /// groups are read in ascending order of index, and rows beyond the end of a
+    /// selection are skipped.
+    ///
+    /// This is equivalent to calling [`Self::with_row_groups`] and
+    /// [`Self::with_row_selection`], without needing to account for the rows of
+    /// skipped row groups when constructing the [`RowSelection`]. It replaces any
+    /// row groups or row selection previously provided.
+    ///
+    /// Returns an error if a row group index is out of bounds, or if a selection
+    /// covers more rows than its row group contains.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use std::fs::File;
+    /// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, RowSelection, RowSelector};
+    /// # let file = File::open("data.parquet").unwrap();
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+    /// let selections = HashMap::from([
+    ///     // scan all rows in row group 0
+    ///     (0, RowSelection::from(vec![RowSelector::select(1000)])),
+    ///     // scan rows 50-100 in row group 2
+    ///     (2, RowSelection::from(vec![RowSelector::skip(50), RowSelector::select(50)])),
+    /// ]);
+    /// let reader = builder.with_row_group_selections(selections).unwrap().build().unwrap();
+    /// ```
+    pub fn with_row_group_selections(
+        self,
+        selections: HashMap<usize, RowSelection>,
+    ) -> Result<Self> {
+        let mut selections: Vec<_> = selections.into_iter().collect();
+        selections.sort_unstable_by_key(|(idx, _)| *idx);
+
+        let num_row_groups = self.metadata.num_row_groups();
+        let mut row_groups = Vec::with_capacity(selections.len());
+        let mut selectors = vec![];
+        for (idx, selection) in selections {
+            if idx >= num_row_groups {
+                return Err(general_err!(
+                    "Row group {} out of bounds, file has {} row groups",
+                    idx,
+                    num_row_groups
+                ));
+            }
+            let num_rows = self.metadata.row_group(idx).num_rows() as usize;
+            let selection_rows = selection.row_count() + selection.skipped_row_count();
+            if selection_rows > num_rows {
+                return Err(general_err!(
+                    "Selection for row group {} covers {} rows, but the row group has {} rows",
+                    idx,
+                    selection_rows,
+                    num_rows
+                ));
+            }
+            row_groups.push(idx);
+            selectors.extend(selection.iter().copied());
+            selectors.push(RowSelector::skip(num_rows - selection_rows));
+        }
+
+        Ok(Self {
+            row_groups: Some(row_groups),
+            selection: Some(selectors.into()),
+            ..self
+        })
+    }
+
     /// Provide a [`RowFilter`] to skip decoding rows
     ///
     /// Row filters are applied after row group selection and row selection
@@ -370,6 +590,152 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Decodes the metadata of the column chunks that will be read, returning
+    /// an error if it is invalid, see [`ArrowReaderOptions::with_lazy_column_metadata`]
+    ///
+    /// The metadata of other column chunks is not decoded.
+    pub(crate) fn check_column_metadata(&self) -> Result<()> {
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let filter_projections = self
+            .filter
+            .iter()
+            .flat_map(|filter| filter.predicates.iter().map(|p| p.projection()));
+
+        for leaf_idx in 0..self.parquet_schema().num_columns() {
+            let read = self.projection.leaf_included(leaf_idx)
+                || filter_projections
+                    .clone()
+                    .any(|projection| projection.leaf_included(leaf_idx));
+            if !read {
+                continue;
+            }
+            for rg in row_groups
+                .iter()
+                .filter_map(|i| self.metadata.row_groups().get(*i))
+            {
+                rg.try_column(leaf_idx)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a column that will be read is encrypted with a key
+    /// that was not provided, see [`ArrowReaderOptions::with_column_keys`]
+    #[cfg(feature = "encryption")]
+    pub(crate) fn check_column_keys(&self) -> Result<()> {
+        let Some(decryptor) = self.metadata.file_decryptor() else {
+            return Ok(());
+        };
+        let schema = self.parquet_schema();
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let filter_projections = self
+            .filter
+            .iter()
+            .flat_map(|filter| filter.predicates.iter().map(|p| p.projection()));
+
+        for leaf_idx in 0..schema.num_columns() {
+            let read = self.projection.leaf_included(leaf_idx)
+                || filter_projections
+                    .clone()
+                    .any(|projection| projection.leaf_included(leaf_idx));
+            if !read {
+                continue;
+            }
+            for rg in row_groups
+                .iter()
+                .filter_map(|i| self.metadata.row_groups().get(*i))
+            {
+                let column = rg.column(leaf_idx);
+                if let Some(ColumnCryptoMetaData::ENCRYPTION_WITH_COLUMN_KEY(crypto)) =
+                    column.crypto_metadata()
+                {
+                    if decryptor.is_column_skipped(&crypto.path_in_schema.join(".")) {
+                        return Err(general_err!(
+                            "No decryption key was provided for encrypted column '{}'",
+                            schema.column(leaf_idx).path().string()
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Provide a [`BatchTransform`] applied to every [`RecordBatch`] produced
+    /// by the reader
+    ///
+    /// The transform is invoked after any [`RowFilter`] and projection have
+    /// been applied, immediately before the batch is returned to the caller,
+    /// and can be used for lightweight normalization of values, such as
+    /// trimming strings or clamping numbers.
+    ///
+    /// The transform must not change the number of rows or the schema of the
+    /// batch, so that the batches returned match the schema reported by the
+    /// reader. An error is returned if it does. To change the types of columns,
+    /// supply a schema with [`ArrowReaderOptions::with_schema`] instead.
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{Int32Array, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # let mut file: Vec<u8> = Vec::with_capacity(1024);
+    /// # let schema = Arc::new(Schema::new(vec![Field::new("i32", DataType::Int32, false)]));
+    /// # let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), None).unwrap();
+    /// # let batch = RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![1, 2, 3]))]).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     // negate every value before it is returned
+    ///     .with_batch_transform(Arc::new(|batch: RecordBatch| {
+    ///         let values = batch.column(0).as_primitive::<Int32Type>();
+    ///         let negated = values.unary::<_, Int32Type>(|v| -v);
+    ///         RecordBatch::try_new(batch.schema(), vec![Arc::new(negated)])
+    ///     }))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_ref(), &Int32Array::from(vec![-1, -2, -3]));
+    /// ```
+    pub fn with_batch_transform(self, batch_transform: BatchTransform) -> Self {
+        Self {
+            batch_transform: Some(batch_transform),
+            ..self
+        }
+    }
+}
+
+/// A function applied to each [`RecordBatch`] produced by a reader
+///
+/// See [`ArrowReaderBuilder::with_batch_transform`]
+pub type BatchTransform = Arc<dyn Fn(RecordBatch) -> Result<RecordBatch, ArrowError> + Send + Sync>;
+
+/// How to read columns annotated with a logical type that is not known to
+/// this crate, such as one added by a newer version of the parquet format
+///
+/// See [`ArrowReaderOptions::with_unknown_logical_type_policy`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownLogicalTypePolicy {
+    /// Return an error when determining the Arrow schema (default)
+    #[default]
+    Error,
+    /// Ignore the logical type, and read the column as the default Arrow type
+    /// for its physical type, e.g. [`ArrowType::Binary`] for `BYTE_ARRAY`
+    PhysicalType,
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -387,9 +753,54 @@ pub struct ArrowReaderOptions {
     supplied_schema: Option<SchemaRef>,
     /// Policy for reading offset and column indexes.
     pub(crate) page_index_policy: PageIndexPolicy,
+    /// Read the page index for each row group as it is read, see [`Self::with_lazy_page_index`]
+    pub(crate) lazy_page_index: bool,
+    /// Decode column chunk metadata on first access, see [`Self::with_lazy_column_metadata`]
+    lazy_column_metadata: bool,
     /// If encryption is enabled, the file decryption properties can be provided
     #[cfg(feature = "encryption")]
     pub(crate) file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
+    /// Decryption keys for individual columns, see [`Self::with_column_keys`]
+    #[cfg(feature = "encryption")]
+    column_keys: Option<ColumnKeys>,
+    /// Should the reader skip verifying page checksums, see [`Self::with_verify_page_checksums`]
+    skip_page_checksums: bool,
+    /// Read string columns as [`ArrowType::Utf8View`], see [`Self::with_string_view`]
+    string_view: bool,
+    /// Read binary columns as [`ArrowType::BinaryView`], see [`Self::with_binary_view`]
+    binary_view: bool,
+    /// Restore extension types from the key-value metadata, see [`Self::with_extension_metadata`]
+    extension_metadata: bool,
+    /// Columns to read as dictionary arrays, see [`Self::with_dictionary_output`]
+    dictionary_columns: Vec<ColumnPath>,
+    /// Lists to read as fixed size lists, see [`Self::with_fixed_size_list_hint`]
+    fixed_size_lists: Vec<(ColumnPath, i32)>,
+    /// The unit to read `INT96` timestamps as, see [`Self::with_int96_timeunit`]
+    int96_timeunit: Option<TimeUnit>,
+    /// Restore run-end encoded columns, see [`Self::with_run_end_encoded_output`]
+    run_end_encoded_output: bool,
+    /// Size read buffers from the column chunk metadata, see [`Self::with_preallocate_from_stats`]
+    preallocate_from_stats: bool,
+    /// Verify decoded decimals fit their precision, see [`Self::with_decimal_validation`]
+    decimal_validation: bool,
+    /// Decompress column chunks in parallel, see [`Self::with_decompress_pool`]
+    decompress_pool: Option<Arc<dyn Executor>>,
+    /// Merge nearby byte ranges in the async reader, see [`Self::with_fetch_coalesce_gap`]
+    fetch_coalesce_gap: Option<usize>,
+    /// How to read unknown logical types, see [`Self::with_unknown_logical_type_policy`]
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
+}
+
+/// Column decryption keys, wrapped so that [`Debug`] does not print the keys
+#[cfg(feature = "encryption")]
+#[derive(Clone, Default)]
+struct ColumnKeys(HashMap<ColumnPath, Vec<u8>>);
+
+#[cfg(feature = "encryption")]
+impl Debug for ColumnKeys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
 }
 
 impl ArrowReaderOptions {
@@ -475,6 +886,270 @@ impl ArrowReaderOptions {
         }
     }
 
+    /// Read `INT96` timestamp columns as [`ArrowType::Timestamp`] with the given `unit`
+    ///
+    /// Legacy writers, such as Spark and Impala, store timestamps as `INT96`, which is
+    /// read as nanoseconds by default, unless the embedded arrow schema specifies
+    /// otherwise. Nanosecond timestamps can only represent dates between the years
+    /// 1677 and 2262, and values outside this range wrap around. Coarser units can
+    /// represent a far larger range of dates, any sub-unit precision is truncated.
+    ///
+    /// Any timezone of the column is preserved. Columns nested within lists, structs
+    /// and maps are also converted.
+    ///
+    /// This option has no effect if a schema is provided via
+    /// [`Self::with_schema`], as the supplied schema takes precedence.
+    pub fn with_int96_timeunit(self, unit: TimeUnit) -> Self {
+        Self {
+            int96_timeunit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Read columns written from [`ArrowType::RunEndEncoded`] arrays as run-end
+    /// encoded arrays again (defaults to `false`)
+    ///
+    /// Parquet has no run-end encoded type, such columns are stored as their values,
+    /// which are efficiently compressed by the dictionary and RLE encodings, and are
+    /// read as their values type by default. With this option the run-end encoded
+    /// types recorded in the embedded arrow schema are restored, with the runs
+    /// recomputed from the decoded values. Run-end encoded columns nested within
+    /// lists, structs and maps are also restored.
+    ///
+    /// Run-end encoded columns can also be requested explicitly via
+    /// [`Self::with_schema`], in which case this option has no effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, RunArray, StringArray};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// let run_ends = Int32Array::from(vec![100, 200]);
+    /// let values = StringArray::from(vec!["foo", "bar"]);
+    /// let array = RunArray::try_new(&run_ends, &values).unwrap();
+    /// let batch = RecordBatch::try_from_iter([("s", Arc::new(array) as ArrayRef)]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new().with_run_end_encoded_output(true);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap();
+    /// assert_eq!(builder.schema(), &batch.schema());
+    ///
+    /// let read = builder.build().unwrap().next().unwrap().unwrap();
+    /// assert_eq!(read.column(0).as_run::<Int32Type>().run_ends().values(), &[100, 200]);
+    /// ```
+    pub fn with_run_end_encoded_output(self, run_end_encoded_output: bool) -> Self {
+        Self {
+            run_end_encoded_output,
+            ..self
+        }
+    }
+
+    /// Read `BYTE_ARRAY` columns with a string logical type as
+    /// [`ArrowType::Utf8View`] (defaults to `false`)
+    ///
+    /// Without this option such columns are read as [`ArrowType::Utf8`],
+    /// unless the embedded arrow schema specifies otherwise. Columns nested
+    /// within lists, structs and maps are also converted, but dictionary
+    /// encoded columns are left unchanged.
+    ///
+    /// This option has no effect if a schema is provided via
+    /// [`Self::with_schema`], as the supplied schema takes precedence.
+    pub fn with_string_view(self, string_view: bool) -> Self {
+        Self {
+            string_view,
+            ..self
+        }
+    }
+
+    /// Read `BYTE_ARRAY` columns without a string logical type as
+    /// [`ArrowType::BinaryView`] (defaults to `false`)
+    ///
+    /// This is the binary counterpart of [`Self::with_string_view`]. Reading
+    /// into a [`BinaryViewArray`] avoids copying values into a single
+    /// contiguous buffer, and values of up to 12 bytes, as well as the first 4
+    /// bytes of longer values, are stored inline in the views. Predicates such
+    /// as [`starts_with`] can make use of this inline prefix.
+    ///
+    /// This option has no effect if a schema is provided via
+    /// [`Self::with_schema`], as the supplied schema takes precedence.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, BinaryArray, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_schema::DataType;
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("b", Arc::new(BinaryArray::from_vec(vec![b"foo", b"bar"])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_skip_arrow_metadata(true)
+    ///     .with_binary_view(true);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap();
+    /// assert_eq!(builder.schema().field(0).data_type(), &DataType::BinaryView);
+    ///
+    /// let batch = builder.build().unwrap().next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_binary_view().value(1), b"bar");
+    /// ```
+    ///
+    /// [`BinaryViewArray`]: arrow_array::BinaryViewArray
+    /// [`starts_with`]: https://docs.rs/arrow/latest/arrow/compute/kernels/comparison/fn.starts_with.html
+    pub fn with_binary_view(self, binary_view: bool) -> Self {
+        Self {
+            binary_view,
+            ..self
+        }
+    }
+
+    /// Restore the extension types of fields from the file's key-value
+    /// metadata (defaults to `false`)
+    ///
+    /// [`ArrowWriter`] stores the extension type of each field that is not
+    /// represented by a Parquet logical type, such as a custom extension type,
+    /// in the key-value metadata of the file. If this is enabled, the extension
+    /// name and metadata are added back to the corresponding fields of the
+    /// Arrow schema, even if the embedded Arrow schema is skipped or absent.
+    ///
+    /// Fields that already have an extension type, for example from the
+    /// embedded Arrow schema, are unchanged. This has no effect if a schema is
+    /// supplied with [`Self::with_schema`].
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    pub fn with_extension_metadata(self, extension_metadata: bool) -> Self {
+        Self {
+            extension_metadata,
+            ..self
+        }
+    }
+
+    /// Read the provided `BYTE_ARRAY` and `FIXED_LEN_BYTE_ARRAY` columns as
+    /// [`ArrowType::Dictionary`] with `Int32` keys (defaults to none)
+    ///
+    /// Columns are identified by their [`ColumnPath`] in the parquet schema, for example
+    /// `ColumnPath::new(vec!["a".into(), "b".into()])` for the field `b` of a struct `a`.
+    /// For dictionary encoded column chunks, the parquet dictionary is used as the
+    /// values of the returned arrays, instead of being expanded for each row. This can
+    /// considerably reduce the memory used for columns with few distinct values.
+    ///
+    /// View types are read as dictionaries of the corresponding non-view type, for
+    /// example a [`ArrowType::Utf8View`] column is read as a dictionary of
+    /// [`ArrowType::Utf8`]. Columns of other types are unaffected.
+    ///
+    /// If a column chunk is not entirely dictionary encoded, for example because the
+    /// writer fell back to plain encoding once the dictionary grew too large, or if a
+    /// batch spans multiple row groups, the values are materialized and a new dictionary
+    /// is computed for the batch. The result is then still a dictionary array, but does
+    /// not share the parquet dictionary.
+    ///
+    /// This option has no effect if a schema is provided via
+    /// [`Self::with_schema`], as the supplied schema takes precedence.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_schema::DataType;
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::schema::types::ColumnPath;
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("s", Arc::new(StringArray::from(vec!["foo", "bar", "foo"])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// let options = ArrowReaderOptions::new().with_dictionary_output([ColumnPath::from("s")]);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     builder.schema().field(0).data_type(),
+    ///     &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    /// );
+    ///
+    /// let batch = builder.build().unwrap().next().unwrap().unwrap();
+    /// let dictionary = batch.column(0).as_dictionary::<Int32Type>();
+    /// assert_eq!(dictionary.values().len(), 2);
+    /// ```
+    pub fn with_dictionary_output(self, columns: impl IntoIterator<Item = ColumnPath>) -> Self {
+        Self {
+            dictionary_columns: columns.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Read the list column at `column_path` as a [`ArrowType::FixedSizeList`] of
+    /// `size` elements, instead of a variable length list
+    ///
+    /// This is useful for files that store fixed length vectors, such as embeddings,
+    /// as parquet `LIST`s. `column_path` contains the names of the list field and of
+    /// its parents, for example `ColumnPath::from("a")` for a top level list `a`, or
+    /// `ColumnPath::new(vec!["a".into(), "b".into()])` for the list `b` of a struct
+    /// `a`. This may be called multiple times to read several lists as fixed size lists.
+    ///
+    /// Reading a batch returns an error if any non-null list does not contain exactly
+    /// `size` elements. Creating the reader returns an error if `column_path` does not
+    /// identify a list column.
+    ///
+    /// This option has no effect if a schema is provided via
+    /// [`Self::with_schema`], as the supplied schema takes precedence.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, ListArray, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_schema::{DataType, Field};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::schema::types::ColumnPath;
+    /// # let list = ListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>([
+    /// #     Some(vec![Some(1), Some(2)]),
+    /// #     Some(vec![Some(3), Some(4)]),
+    /// # ]);
+    /// # let batch = RecordBatch::try_from_iter([("v", Arc::new(list) as ArrayRef)]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// let options = ArrowReaderOptions::new().with_fixed_size_list_hint(ColumnPath::from("v"), 2);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap();
+    /// assert!(matches!(
+    ///     builder.schema().field(0).data_type(),
+    ///     DataType::FixedSizeList(_, 2)
+    /// ));
+    ///
+    /// let batch = builder.build().unwrap().next().unwrap().unwrap();
+    /// let list = batch.column(0).as_fixed_size_list();
+    /// assert_eq!(list.values().as_primitive::<Int32Type>().values(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn with_fixed_size_list_hint(mut self, column_path: ColumnPath, size: i32) -> Self {
+        self.fixed_size_lists.push((column_path, size));
+        self
+    }
+
     /// Enable reading [`PageIndex`], if present (defaults to `false`)
     ///
     /// The `PageIndex` can be used to push down predicates to the parquet scan,
@@ -504,6 +1179,59 @@ impl ArrowReaderOptions {
         }
     }
 
+    /// Defer reading the [`PageIndex`] until a row group is read (defaults to `false`)
+    ///
+    /// By default, enabling the page index via [`Self::with_page_index`] reads
+    /// and decodes the column and offset indexes of every row group when the
+    /// metadata is loaded. For files with many row groups, of which only a few
+    /// are read, this can dominate the cost of opening the file.
+    ///
+    /// If this is enabled, the page index is not read with the metadata, and
+    /// [`ParquetMetaData::column_index`] and [`ParquetMetaData::offset_index`]
+    /// are not populated. Instead, the offset index of each row group is read
+    /// and decoded only when that row group is scanned, and used to avoid
+    /// reading unnecessary pages as normal. The column index is not read.
+    ///
+    /// The page index is still governed by [`Self::with_page_index_policy`],
+    /// so this has no effect unless the page index is enabled. If the metadata
+    /// provided to [`ArrowReaderMetadata::try_new`] already contains the offset
+    /// index, it is used as is. The [`ParquetPushDecoder`] does not fetch the
+    /// page index itself, and so returns an error when built with this option.
+    ///
+    /// The number of page index bytes read lazily is reported by
+    /// [`ArrowReaderMetrics::page_index_bytes_read`].
+    ///
+    /// [`PageIndex`]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+    /// [`ParquetMetaData::column_index`]: crate::file::metadata::ParquetMetaData::column_index
+    /// [`ParquetMetaData::offset_index`]: crate::file::metadata::ParquetMetaData::offset_index
+    /// [`ParquetPushDecoder`]: crate::arrow::push_decoder::ParquetPushDecoder
+    pub fn with_lazy_page_index(self, lazy_page_index: bool) -> Self {
+        Self {
+            lazy_page_index,
+            ..self
+        }
+    }
+
+    /// Decode the [`ColumnChunkMetaData`] of each column when it is first
+    /// accessed (defaults to `false`)
+    ///
+    /// For files with many columns, of which only a few are read, decoding the
+    /// metadata of every column chunk can dominate the cost of opening the file.
+    /// If this is enabled, the metadata is loaded with
+    /// [`ParquetMetaDataReader::with_lazy_column_metadata`], and the reader only
+    /// decodes the metadata of the columns in the projection and [`RowFilter`].
+    ///
+    /// This has no effect on metadata provided to [`ArrowReaderMetadata::try_new`],
+    /// which is used as is, whether or not it was decoded lazily.
+    ///
+    /// [`ColumnChunkMetaData`]: crate::file::metadata::ColumnChunkMetaData
+    pub fn with_lazy_column_metadata(self, lazy_column_metadata: bool) -> Self {
+        Self {
+            lazy_column_metadata,
+            ..self
+        }
+    }
+
     /// Provide the file decryption properties to use when reading encrypted parquet files.
     ///
     /// If encryption is enabled and the file is encrypted, the `file_decryption_properties` must be provided.
@@ -518,21 +1246,273 @@ impl ArrowReaderOptions {
         }
     }
 
-    /// Retrieve the currently set page index behavior.
+    /// Provide decryption keys for individual columns of an encrypted parquet file.
     ///
-    /// This can be set via [`with_page_index`][Self::with_page_index].
-    pub fn page_index(&self) -> bool {
-        self.page_index_policy != PageIndexPolicy::Skip
+    /// This permits reading the columns for which keys are available from a
+    /// file that uses column-level (non-uniform) encryption, without requiring
+    /// keys for every encrypted column. Encrypted columns without a key are
+    /// ignored unless they are projected or used by a [`RowFilter`], in which
+    /// case building the reader returns an error naming the column.
+    ///
+    /// The keys are combined with any properties provided via
+    /// [`Self::with_file_decryption_properties`], which must also be set to
+    /// supply the footer key.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::Arc;
+    /// # use parquet::arrow::arrow_reader::ArrowReaderOptions;
+    /// # use parquet::encryption::decrypt::FileDecryptionProperties;
+    /// # use parquet::schema::types::ColumnPath;
+    /// let footer_key = b"0123456789012345".to_vec();
+    /// let decryption_properties = FileDecryptionProperties::builder(footer_key).build()?;
+    ///
+    /// // Only the key for column "x" is known
+    /// let column_keys = HashMap::from([(ColumnPath::from("x"), b"1234567890123450".to_vec())]);
+    ///
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_file_decryption_properties(decryption_properties)
+    ///     .with_column_keys(column_keys);
+    /// # Ok::<(), parquet::errors::ParquetError>(())
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub fn with_column_keys(self, column_keys: HashMap<ColumnPath, Vec<u8>>) -> Self {
+        Self {
+            column_keys: Some(ColumnKeys(column_keys)),
+            ..self
+        }
     }
 
-    /// Retrieve the currently set file decryption properties.
+    /// Verify the CRC32 checksum of each page that has one (defaults to `true`)
     ///
-    /// This can be set via
-    /// [`file_decryption_properties`][Self::with_file_decryption_properties].
-    #[cfg(feature = "encryption")]
+    /// If a checksum does not match the page data, reading fails with a
+    /// [`ParquetError`] identifying the column and page. This applies to data
+    /// pages (v1 and v2) and dictionary pages. Pages written without a checksum
+    /// are not verified, see [`WriterPropertiesBuilder::set_page_checksums_enabled`]
+    /// to write them.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// # ]).unwrap();
+    /// let props = WriterProperties::builder()
+    ///     .set_page_checksums_enabled(true)
+    ///     .build();
+    /// let mut buf = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new().with_verify_page_checksums(true);
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// # assert_eq!(reader.map(|b| b.unwrap().num_rows()).sum::<usize>(), 3);
+    /// ```
+    ///
+    /// [`WriterPropertiesBuilder::set_page_checksums_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_page_checksums_enabled
+    #[cfg(feature = "crc")]
+    pub fn with_verify_page_checksums(self, verify: bool) -> Self {
+        Self {
+            skip_page_checksums: !verify,
+            ..self
+        }
+    }
+
+    /// Preallocate the output buffers of `BYTE_ARRAY` columns using the row
+    /// counts and sizes recorded in the column chunk metadata (defaults to `false`)
+    ///
+    /// Without this option the value and view buffers of [`ArrowType::Utf8`],
+    /// [`ArrowType::Binary`], [`ArrowType::Utf8View`] and [`ArrowType::BinaryView`]
+    /// columns grow as values are decoded, which may reallocate and copy the
+    /// buffers several times per batch. With this option the expected size of
+    /// each batch is estimated from the selected row groups, using the
+    /// unencoded byte array data size where available, and reserved up front.
+    ///
+    /// The estimate is only a hint: each reservation is capped at 16 MiB so
+    /// that inaccurate or malicious metadata cannot cause unbounded allocation,
+    /// and buffers still grow as needed.
+    pub fn with_preallocate_from_stats(self, preallocate_from_stats: bool) -> Self {
+        Self {
+            preallocate_from_stats,
+            ..self
+        }
+    }
+
+    /// Verify that the decoded values of decimal columns fit the precision of
+    /// the column, returning an error for any value that does not (defaults to `false`)
+    ///
+    /// The reader otherwise trusts the precision and scale declared by the file,
+    /// so a corrupt or mis-annotated file may produce decimal arrays containing
+    /// values that exceed their precision, which can cause incorrect results or
+    /// panics in later processing. Validation requires checking every decoded
+    /// value, and so is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use parquet::arrow::arrow_reader::ArrowReaderOptions;
+    /// // Error when reading decimal values that exceed the declared precision
+    /// let options = ArrowReaderOptions::new().with_decimal_validation(true);
+    /// ```
+    pub fn with_decimal_validation(self, decimal_validation: bool) -> Self {
+        Self {
+            decimal_validation,
+            ..self
+        }
+    }
+
+    /// Set how to read columns annotated with a logical type that is not known
+    /// to this crate (defaults to [`UnknownLogicalTypePolicy::Error`])
+    ///
+    /// Parquet writers may annotate columns with logical types added by newer
+    /// versions of the format. By default such columns cause an error when
+    /// determining the Arrow schema, as their values may not be correctly
+    /// interpreted without understanding the annotation. With
+    /// [`UnknownLogicalTypePolicy::PhysicalType`] the annotation is instead
+    /// ignored, and the column is read using its physical type, e.g. a
+    /// `BYTE_ARRAY` column is read as [`ArrowType::Binary`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, UnknownLogicalTypePolicy};
+    /// // Read columns with unknown logical types as their physical type
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_unknown_logical_type_policy(UnknownLogicalTypePolicy::PhysicalType);
+    /// ```
+    pub fn with_unknown_logical_type_policy(self, policy: UnknownLogicalTypePolicy) -> Self {
+        Self {
+            unknown_logical_type_policy: policy,
+            ..self
+        }
+    }
+
+    /// Decompress the column chunks of each row group in parallel on `pool`
+    /// (defaults to decompressing on the reading thread)
+    ///
+    /// When set, a task is spawned on `pool` for each column chunk read, which
+    /// reads and decompresses all the pages of that column chunk, allowing the
+    /// columns of a row group to be decompressed concurrently. Decoding the
+    /// decompressed pages into arrays still happens in order on the reading
+    /// thread, and so the output is identical to that without a pool.
+    ///
+    /// Note that with this option:
+    ///
+    /// * A few decompressed pages of each column chunk are buffered in memory
+    ///   ahead of the reader, including for the next row group with the
+    ///   synchronous [`ParquetRecordBatchReader`] which starts decompressing
+    ///   it early
+    /// * Pages are decompressed even if they are later skipped by a
+    ///   [`RowSelection`]. Pages that are not fetched, e.g. when using the page
+    ///   index with the async reader, are not read at all, and so column chunks
+    ///   that are only partially fetched are decompressed on the reading thread
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, Executor};
+    /// #[derive(Debug)]
+    /// struct ThreadExecutor;
+    ///
+    /// impl Executor for ThreadExecutor {
+    ///     fn spawn(&self, task: Box<dyn FnOnce() + Send>) {
+    ///         std::thread::spawn(task);
+    ///     }
+    /// }
+    ///
+    /// let options = ArrowReaderOptions::new().with_decompress_pool(Arc::new(ThreadExecutor));
+    /// ```
+    pub fn with_decompress_pool(self, pool: Arc<dyn Executor>) -> Self {
+        Self {
+            decompress_pool: Some(pool),
+            ..self
+        }
+    }
+
+    /// Merge the byte ranges fetched by the async reader when they are separated by
+    /// at most `gap` bytes (defaults to no merging)
+    ///
+    /// The async reader fetches the column chunks, or the pages, of each row group with
+    /// a single call to `AsyncFileReader::get_byte_ranges`, whose default implementation
+    /// issues one request per range. With this option, ranges closer than `gap` bytes
+    /// are merged into a single larger range before being fetched, trading the transfer
+    /// of the bytes in between for fewer round trips. This is beneficial for object
+    /// stores, where the latency of a request typically dominates its transfer time.
+    ///
+    /// A `gap` of `0` only merges adjacent ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use parquet::arrow::arrow_reader::ArrowReaderOptions;
+    /// // Merge column chunks that are less than 1 MiB apart
+    /// let options = ArrowReaderOptions::new().with_fetch_coalesce_gap(1024 * 1024);
+    /// ```
+    pub fn with_fetch_coalesce_gap(self, gap: usize) -> Self {
+        Self {
+            fetch_coalesce_gap: Some(gap),
+            ..self
+        }
+    }
+
+    /// Retrieve the currently set page index behavior.
+    ///
+    /// This can be set via [`with_page_index`][Self::with_page_index].
+    pub fn page_index(&self) -> bool {
+        self.page_index_policy != PageIndexPolicy::Skip
+    }
+
+    /// Retrieve whether column chunk metadata is decoded on first access.
+    ///
+    /// This can be set via [`with_lazy_column_metadata`][Self::with_lazy_column_metadata].
+    pub fn lazy_column_metadata(&self) -> bool {
+        self.lazy_column_metadata
+    }
+
+    /// Returns the [`PageIndexPolicy`] to use when loading the metadata, which
+    /// skips the page index if it is read lazily
+    pub(crate) fn metadata_page_index_policy(&self) -> PageIndexPolicy {
+        match self.lazy_page_index {
+            true => PageIndexPolicy::Skip,
+            false => self.page_index_policy,
+        }
+    }
+
+    /// Retrieve the currently set file decryption properties.
+    ///
+    /// This can be set via
+    /// [`file_decryption_properties`][Self::with_file_decryption_properties].
+    #[cfg(feature = "encryption")]
     pub fn file_decryption_properties(&self) -> Option<&Arc<FileDecryptionProperties>> {
         self.file_decryption_properties.as_ref()
     }
+
+    /// Returns the [`FileDecryptionProperties`] to use when reading the file
+    /// metadata, including any keys provided via [`Self::with_column_keys`]
+    #[cfg(feature = "encryption")]
+    pub(crate) fn metadata_decryption_properties(
+        &self,
+    ) -> Result<Option<Arc<FileDecryptionProperties>>> {
+        let Some(ColumnKeys(column_keys)) = &self.column_keys else {
+            return Ok(self.file_decryption_properties.clone());
+        };
+        let properties = self.file_decryption_properties.as_ref().ok_or_else(|| {
+            general_err!("File decryption properties must be provided with column keys")
+        })?;
+        Ok(Some(Arc::new(
+            properties.with_selective_column_keys(column_keys)?,
+        )))
+    }
 }
 
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
@@ -571,12 +1551,12 @@ impl ArrowReaderMetadata {
     /// `Self::metadata` is missing the page index, this function will attempt
     /// to load the page index by making an object store request.
     pub fn load<T: ChunkReader>(reader: &T, options: ArrowReaderOptions) -> Result<Self> {
-        let metadata =
-            ParquetMetaDataReader::new().with_page_index_policy(options.page_index_policy);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(options.metadata_page_index_policy())
+            .with_lazy_column_metadata(options.lazy_column_metadata);
         #[cfg(feature = "encryption")]
-        let metadata = metadata.with_decryption_properties(
-            options.file_decryption_properties.as_ref().map(Arc::clone),
-        );
+        let metadata =
+            metadata.with_decryption_properties(options.metadata_decryption_properties()?);
         let metadata = metadata.parse_and_finish(reader)?;
         Self::try_new(Arc::new(metadata), options)
     }
@@ -588,8 +1568,13 @@ impl ArrowReaderMetadata {
     /// This function does not attempt to load the PageIndex if not present in the metadata.
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
+        let unknown_logical_type_policy = options.unknown_logical_type_policy;
         match options.supplied_schema {
-            Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
+            Some(supplied_schema) => Self::with_supplied_schema(
+                metadata,
+                supplied_schema.clone(),
+                unknown_logical_type_policy,
+            ),
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
                     true => None,
@@ -600,8 +1585,67 @@ impl ArrowReaderMetadata {
                     metadata.file_metadata().schema_descr(),
                     ProjectionMask::all(),
                     kv_metadata,
+                    unknown_logical_type_policy,
                 )?;
 
+                if options.extension_metadata
+                    || options.string_view
+                    || options.binary_view
+                    || !options.dictionary_columns.is_empty()
+                    || !options.fixed_size_lists.is_empty()
+                    || options.int96_timeunit.is_some()
+                    || options.run_end_encoded_output
+                {
+                    let mut updated_schema = match options.extension_metadata {
+                        true => restore_extension_metadata(
+                            &schema,
+                            metadata.file_metadata().key_value_metadata(),
+                        ),
+                        false => schema.clone(),
+                    };
+                    if let (true, Some(hint)) = (
+                        options.run_end_encoded_output,
+                        embedded_arrow_schema(kv_metadata),
+                    ) {
+                        updated_schema = with_run_end_encoded_types(&updated_schema, &hint);
+                    }
+                    if options.string_view || options.binary_view {
+                        updated_schema = with_view_types(
+                            &updated_schema,
+                            options.string_view,
+                            options.binary_view,
+                        );
+                    }
+                    if let (false, Some(fields)) = (options.dictionary_columns.is_empty(), &fields)
+                    {
+                        updated_schema = with_dictionary_types(
+                            &updated_schema,
+                            fields,
+                            metadata.file_metadata().schema_descr(),
+                            &options.dictionary_columns,
+                        );
+                    }
+                    if !options.fixed_size_lists.is_empty() {
+                        updated_schema =
+                            with_fixed_size_list_types(&updated_schema, &options.fixed_size_lists)?;
+                    }
+                    if let (Some(unit), Some(fields)) = (options.int96_timeunit, &fields) {
+                        updated_schema = with_int96_types(
+                            &updated_schema,
+                            fields,
+                            metadata.file_metadata().schema_descr(),
+                            unit,
+                        );
+                    }
+                    if updated_schema != schema {
+                        return Self::with_supplied_schema(
+                            metadata,
+                            Arc::new(updated_schema),
+                            unknown_logical_type_policy,
+                        );
+                    }
+                }
+
                 Ok(Self {
                     metadata,
                     schema: Arc::new(schema),
@@ -614,12 +1658,14 @@ impl ArrowReaderMetadata {
     fn with_supplied_schema(
         metadata: Arc<ParquetMetaData>,
         supplied_schema: SchemaRef,
+        unknown_logical_type_policy: UnknownLogicalTypePolicy,
     ) -> Result<Self> {
         let parquet_schema = metadata.file_metadata().schema_descr();
-        let field_levels = parquet_to_arrow_field_levels(
+        let field_levels = parquet_to_arrow_field_levels_with_policy(
             parquet_schema,
             ProjectionMask::all(),
             Some(supplied_schema.fields()),
+            unknown_logical_type_policy,
         )?;
         let fields = field_levels.fields;
         let inferred_len = fields.len();
@@ -695,6 +1741,309 @@ impl ArrowReaderMetadata {
     }
 }
 
+/// Reads the arrow [`SchemaRef`] of a parquet file from its footer
+///
+/// Returns the schema of the [`RecordBatch`]es that a reader for this file,
+/// created with the default [`ArrowReaderOptions`], would emit without a
+/// projection. This honors any arrow schema embedded in the parquet metadata.
+///
+/// Only the footer is read and parsed, and no decoding state is created. See
+/// [`ArrowReaderMetadata::load`] to take [`ArrowReaderOptions`] into account.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::arrow_schema_from_parquet;
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+/// # ]).unwrap();
+/// # let mut buf = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let file = Bytes::from(buf);
+/// let schema = arrow_schema_from_parquet(&file).unwrap();
+/// assert_eq!(schema, batch.schema());
+/// ```
+pub fn arrow_schema_from_parquet<T: ChunkReader>(reader: &T) -> Result<SchemaRef> {
+    let metadata = ParquetMetaDataReader::new()
+        .with_page_index_policy(PageIndexPolicy::Skip)
+        .parse_and_finish(reader)?;
+    let metadata = ArrowReaderMetadata::try_new(Arc::new(metadata), ArrowReaderOptions::default())?;
+    Ok(metadata.schema)
+}
+
+/// Returns `schema` with any `Utf8` and `Binary` types, including those nested
+/// within lists, structs and maps, replaced by their view equivalents
+fn with_view_types(schema: &Schema, string_view: bool, binary_view: bool) -> Schema {
+    fn convert_field(field: &FieldRef, string_view: bool, binary_view: bool) -> FieldRef {
+        let data_type = convert_type(field.data_type(), string_view, binary_view);
+        Arc::new(field.as_ref().clone().with_data_type(data_type))
+    }
+
+    fn convert_type(data_type: &ArrowType, string_view: bool, binary_view: bool) -> ArrowType {
+        match data_type {
+            ArrowType::Utf8 if string_view => ArrowType::Utf8View,
+            ArrowType::Binary if binary_view => ArrowType::BinaryView,
+            ArrowType::List(f) => ArrowType::List(convert_field(f, string_view, binary_view)),
+            ArrowType::LargeList(f) => {
+                ArrowType::LargeList(convert_field(f, string_view, binary_view))
+            }
+            ArrowType::FixedSizeList(f, size) => {
+                ArrowType::FixedSizeList(convert_field(f, string_view, binary_view), *size)
+            }
+            ArrowType::Struct(fields) => ArrowType::Struct(
+                fields
+                    .iter()
+                    .map(|f| convert_field(f, string_view, binary_view))
+                    .collect(),
+            ),
+            ArrowType::Map(f, sorted) => {
+                ArrowType::Map(convert_field(f, string_view, binary_view), *sorted)
+            }
+            _ => data_type.clone(),
+        }
+    }
+
+    let fields: Fields = schema
+        .fields()
+        .iter()
+        .map(|f| convert_field(f, string_view, binary_view))
+        .collect();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
+/// Returns `schema` with the byte array leaf columns whose path is in `columns`
+/// replaced by dictionary types with `Int32` keys
+fn with_dictionary_types(
+    schema: &Schema,
+    fields: &ParquetField,
+    schema_descr: &SchemaDescriptor,
+    columns: &[ColumnPath],
+) -> Schema {
+    with_leaf_types(schema, fields, &|col_idx, data_type| {
+        if !columns.contains(schema_descr.column(col_idx).path()) {
+            return None;
+        }
+        let value_type = match data_type {
+            ArrowType::Utf8
+            | ArrowType::LargeUtf8
+            | ArrowType::Binary
+            | ArrowType::LargeBinary
+            | ArrowType::FixedSizeBinary(_) => data_type.clone(),
+            ArrowType::Utf8View => ArrowType::Utf8,
+            ArrowType::BinaryView => ArrowType::Binary,
+            _ => return None,
+        };
+        Some(ArrowType::Dictionary(
+            Box::new(ArrowType::Int32),
+            Box::new(value_type),
+        ))
+    })
+}
+
+/// Returns `schema` with the list fields identified by the paths of `lists`
+/// converted to [`ArrowType::FixedSizeList`]s of the corresponding size
+///
+/// Returns an error if a path does not identify a list field
+fn with_fixed_size_list_types(schema: &Schema, lists: &[(ColumnPath, i32)]) -> Result<Schema> {
+    fn convert_field(
+        field: &FieldRef,
+        path: &mut Vec<String>,
+        lists: &[(ColumnPath, i32)],
+        matched: &mut [bool],
+    ) -> FieldRef {
+        path.push(field.name().clone());
+        let size = match field.data_type() {
+            ArrowType::List(_) | ArrowType::LargeList(_) => {
+                let hint = lists.iter().position(|(p, _)| p.parts() == path.as_slice());
+                hint.map(|idx| {
+                    matched[idx] = true;
+                    lists[idx].1
+                })
+            }
+            _ => None,
+        };
+        let mut convert = |f: &FieldRef| convert_field(f, path, lists, matched);
+        let data_type = match (field.data_type(), size) {
+            (ArrowType::List(f) | ArrowType::LargeList(f), Some(size)) => {
+                ArrowType::FixedSizeList(convert(f), size)
+            }
+            (ArrowType::List(f), None) => ArrowType::List(convert(f)),
+            (ArrowType::LargeList(f), None) => ArrowType::LargeList(convert(f)),
+            (ArrowType::FixedSizeList(f, size), _) => ArrowType::FixedSizeList(convert(f), *size),
+            (ArrowType::Struct(fields), _) => {
+                ArrowType::Struct(fields.iter().map(convert).collect())
+            }
+            (ArrowType::Map(f, sorted), _) => ArrowType::Map(convert(f), *sorted),
+            (data_type, _) => data_type.clone(),
+        };
+        path.pop();
+        Arc::new(field.as_ref().clone().with_data_type(data_type))
+    }
+
+    if let Some((path, size)) = lists.iter().find(|(_, size)| *size < 0) {
+        return Err(arrow_err!(format!(
+            "Invalid fixed size list size {size} for column {path}"
+        )));
+    }
+    let mut matched = vec![false; lists.len()];
+    let fields: Fields = schema
+        .fields()
+        .iter()
+        .map(|f| convert_field(f, &mut Vec::new(), lists, &mut matched))
+        .collect();
+    if let Some(((path, _), _)) = lists.iter().zip(&matched).find(|(_, m)| !**m) {
+        return Err(arrow_err!(format!(
+            "Cannot read column {path} as a fixed size list, as it is not a list column"
+        )));
+    }
+    Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Returns `schema` with the timestamp types of the `INT96` leaf columns
+/// converted to `unit`, preserving any timezone
+fn with_int96_types(
+    schema: &Schema,
+    fields: &ParquetField,
+    schema_descr: &SchemaDescriptor,
+    unit: TimeUnit,
+) -> Schema {
+    with_leaf_types(schema, fields, &|col_idx, data_type| match (
+        schema_descr.column(col_idx).physical_type(),
+        data_type,
+    ) {
+        (PhysicalType::INT96, ArrowType::Timestamp(_, tz)) => {
+            Some(ArrowType::Timestamp(unit, tz.clone()))
+        }
+        _ => None,
+    })
+}
+
+/// Returns `schema` with the fields that are run-end encoded in the embedded arrow
+/// schema `hint` converted back to [`ArrowType::RunEndEncoded`]
+fn with_run_end_encoded_types(schema: &Schema, hint: &Schema) -> Schema {
+    fn convert_fields(fields: &Fields, hints: &Fields) -> Fields {
+        if fields.len() != hints.len() {
+            return fields.clone();
+        }
+        fields
+            .iter()
+            .zip(hints.iter())
+            .map(|(field, hint)| convert_field(field, hint))
+            .collect()
+    }
+
+    fn convert_field(field: &FieldRef, hint: &FieldRef) -> FieldRef {
+        let data_type = match (field.data_type(), hint.data_type()) {
+            (data_type, ArrowType::RunEndEncoded(_, values)) if data_type == values.data_type() => {
+                hint.data_type().clone()
+            }
+            (ArrowType::List(f), ArrowType::List(h)) => ArrowType::List(convert_field(f, h)),
+            (ArrowType::LargeList(f), ArrowType::LargeList(h)) => {
+                ArrowType::LargeList(convert_field(f, h))
+            }
+            (ArrowType::FixedSizeList(f, size), ArrowType::FixedSizeList(h, _)) => {
+                ArrowType::FixedSizeList(convert_field(f, h), *size)
+            }
+            (ArrowType::Struct(fields), ArrowType::Struct(hints)) => {
+                ArrowType::Struct(convert_fields(fields, hints))
+            }
+            (ArrowType::Map(f, sorted), ArrowType::Map(h, _)) => {
+                ArrowType::Map(convert_field(f, h), *sorted)
+            }
+            _ => return Arc::clone(field),
+        };
+        Arc::new(field.as_ref().clone().with_data_type(data_type))
+    }
+
+    Schema::new_with_metadata(
+        convert_fields(schema.fields(), hint.fields()),
+        schema.metadata().clone(),
+    )
+}
+
+/// Returns `schema` with the type of each leaf field replaced by the result of
+/// `f`, if any, called with the index of the leaf's parquet column and its type
+///
+/// `fields` is the [`ParquetField`] computed for `schema`, and is used to determine
+/// the parquet column of each leaf field
+fn with_leaf_types(
+    schema: &Schema,
+    fields: &ParquetField,
+    f: &dyn Fn(usize, &ArrowType) -> Option<ArrowType>,
+) -> Schema {
+    fn convert_field(
+        field: &FieldRef,
+        parquet_field: &ParquetField,
+        f: &dyn Fn(usize, &ArrowType) -> Option<ArrowType>,
+    ) -> FieldRef {
+        let data_type = convert_type(field.data_type(), parquet_field, f);
+        Arc::new(field.as_ref().clone().with_data_type(data_type))
+    }
+
+    fn convert_type(
+        data_type: &ArrowType,
+        parquet_field: &ParquetField,
+        f: &dyn Fn(usize, &ArrowType) -> Option<ArrowType>,
+    ) -> ArrowType {
+        let convert = |field: &FieldRef, c: &ParquetField| convert_field(field, c, f);
+
+        match (&parquet_field.field_type, data_type) {
+            (ParquetFieldType::Primitive { col_idx, .. }, _) => {
+                f(*col_idx, data_type).unwrap_or_else(|| data_type.clone())
+            }
+            (ParquetFieldType::Group { children }, ArrowType::List(field)) => {
+                ArrowType::List(convert(field, &children[0]))
+            }
+            (ParquetFieldType::Group { children }, ArrowType::LargeList(field)) => {
+                ArrowType::LargeList(convert(field, &children[0]))
+            }
+            (ParquetFieldType::Group { children }, ArrowType::FixedSizeList(field, size)) => {
+                ArrowType::FixedSizeList(convert(field, &children[0]), *size)
+            }
+            (ParquetFieldType::Group { children }, ArrowType::Struct(fields)) => ArrowType::Struct(
+                fields
+                    .iter()
+                    .zip(children)
+                    .map(|(field, c)| convert(field, c))
+                    .collect(),
+            ),
+            (ParquetFieldType::Group { children }, ArrowType::Map(field, sorted)) => {
+                match field.data_type() {
+                    ArrowType::Struct(entries) => {
+                        let entries = entries
+                            .iter()
+                            .zip(children)
+                            .map(|(field, c)| convert(field, c))
+                            .collect();
+                        let field = field
+                            .as_ref()
+                            .clone()
+                            .with_data_type(ArrowType::Struct(entries));
+                        ArrowType::Map(Arc::new(field), *sorted)
+                    }
+                    _ => data_type.clone(),
+                }
+            }
+            _ => data_type.clone(),
+        }
+    }
+
+    let fields: Fields = match &fields.field_type {
+        ParquetFieldType::Group { children } => schema
+            .fields()
+            .iter()
+            .zip(children)
+            .map(|(field, c)| convert_field(field, c, f))
+            .collect(),
+        ParquetFieldType::Primitive { .. } => schema.fields().clone(),
+    };
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
 #[doc(hidden)]
 // A newtype used within `ReaderOptionsBuilder` to distinguish sync readers from async
 pub struct SyncReader<T: ChunkReader>(T);
@@ -747,8 +2096,8 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
 
     /// Create a new [`ParquetRecordBatchReaderBuilder`] with [`ArrowReaderOptions`]
     pub fn try_new_with_options(reader: T, options: ArrowReaderOptions) -> Result<Self> {
-        let metadata = ArrowReaderMetadata::load(&reader, options)?;
-        Ok(Self::new_with_metadata(reader, metadata))
+        let metadata = ArrowReaderMetadata::load(&reader, options.clone())?;
+        Ok(Self::new_with_metadata(reader, metadata).with_reader_options(&options))
     }
 
     /// Create a [`ParquetRecordBatchReaderBuilder`] from the provided [`ArrowReaderMetadata`]
@@ -761,7 +2110,10 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     /// 2. Using a cached copy of the metadata rather than re-reading it from the
     ///    file each time a reader is constructed.
     ///
-    /// See the docs on [`ArrowReaderMetadata`] for more details
+    /// See the docs on [`ArrowReaderMetadata`] for more details, and
+    /// [`Self::try_new_with_metadata`] to check the metadata is consistent with `input`.
+    /// Options that control how column data is read are not part of the metadata,
+    /// see [`Self::with_reader_options`]
     ///
     /// # Example
     /// ```
@@ -792,6 +2144,67 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         Self::new_builder(SyncReader(input), metadata)
     }
 
+    /// Create a [`ParquetRecordBatchReaderBuilder`] from the provided [`ArrowReaderMetadata`],
+    /// checking that it is consistent with `input`
+    ///
+    /// This is the same as [`Self::new_with_metadata`], but returns an error if
+    /// `metadata` was loaded from a different file than `input`, that is if:
+    ///
+    /// * any column chunk described by `metadata` lies beyond the end of `input`
+    /// * the parquet schema in the footer of `input` differs from that of `metadata`
+    ///
+    /// To check the schema, the footer of `input` is read, but only its schema is
+    /// decoded, which is much cheaper than decoding the full metadata for files with
+    /// many row groups. The schema of files with an encrypted footer is not checked.
+    ///
+    /// These checks can not detect every mismatch, for example a different file with
+    /// the same schema and at least the same length.
+    pub fn try_new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Result<Self> {
+        let len = input.len();
+        for (rg_idx, rg) in metadata.metadata.row_groups().iter().enumerate() {
+            for col_idx in 0..rg.num_columns() {
+                let (start, length) = rg.try_column(col_idx)?.byte_range();
+                let end = start.saturating_add(length);
+                if end > len {
+                    return Err(general_err!(
+                        "Column chunk {} of row group {} at range {}..{} is outside of the input of length {}, \
+                         was the metadata loaded from a different file?",
+                        col_idx,
+                        rg_idx,
+                        start,
+                        end,
+                        len
+                    ));
+                }
+            }
+        }
+
+        let footer_start = len.checked_sub(FOOTER_SIZE as u64).ok_or_else(|| {
+            general_err!("Input of length {len} is too small to contain a parquet footer")
+        })?;
+        let footer = input.get_bytes(footer_start, FOOTER_SIZE)?;
+        let footer_tail = FooterTail::try_from(footer.as_ref())?;
+        if !footer_tail.is_encrypted_footer() {
+            let metadata_len = footer_tail.metadata_length();
+            let metadata_start = footer_start
+                .checked_sub(metadata_len as u64)
+                .ok_or_else(|| {
+                    general_err!(
+                        "Footer metadata of length {metadata_len} does not fit in input of length {len}"
+                    )
+                })?;
+            let buf = input.get_bytes(metadata_start, metadata_len)?;
+            let schema = parquet_schema_from_bytes(&buf)?;
+            if schema.as_ref() != metadata.metadata.file_metadata().schema() {
+                return Err(general_err!(
+                    "The parquet schema of the metadata does not match the schema in the footer \
+                     of the input, was the metadata loaded from a different file?"
+                ));
+            }
+        }
+        Ok(Self::new_with_metadata(input, metadata))
+    }
+
     /// Read bloom filter for a column in a row group
     ///
     /// Returns `None` if the column does not have a bloom filter
@@ -856,6 +2269,10 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
     pub fn build(self) -> Result<ParquetRecordBatchReader> {
+        self.check_column_metadata()?;
+        #[cfg(feature = "encryption")]
+        self.check_column_keys()?;
+
         let Self {
             input,
             metadata,
@@ -871,42 +2288,75 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             metrics,
             // Not used for the sync reader, see https://github.com/apache/arrow-rs/issues/8000
             max_predicate_cache_size: _,
+            batch_transform,
+            verify_page_checksums,
+            preallocate_from_stats,
+            decimal_validation,
+            decompress_pool,
+            lazy_page_index_policy,
+            // Only used by the async reader, which fetches the column data itself
+            fetch_coalesce_gap: _,
+            row_group_aligned_batches,
         } = self;
 
         // Try to avoid allocate large buffer
-        let batch_size = self
+        let mut batch_size = self
             .batch_size
             .min(metadata.file_metadata().num_rows() as usize);
 
         let row_groups = row_groups.unwrap_or_else(|| (0..metadata.num_row_groups()).collect());
 
+        let row_group_rows = row_group_aligned_batches.then(|| {
+            let rows: VecDeque<_> = row_groups
+                .iter()
+                .map(|idx| metadata.row_group(*idx).num_rows() as usize)
+                .filter(|rows| *rows > 0)
+                .collect();
+            // Each batch contains at most one row group
+            batch_size = rows.iter().copied().max().unwrap_or(batch_size);
+            rows
+        });
+
+        let lazy_offset_index = match lazy_page_index_policy {
+            PageIndexPolicy::Skip => None,
+            policy => Some(Arc::new(read_lazy_offset_index(
+                &input.0,
+                &metadata,
+                &row_groups,
+                policy,
+                &metrics,
+            )?)),
+        };
+
         let reader = ReaderRowGroups {
             reader: Arc::new(input.0),
             metadata,
+            lazy_offset_index,
             row_groups,
+            verify_page_checksums,
+            decompress_pool,
         };
 
         let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(selection);
 
         // Update selection based on any filters
         if let Some(filter) = filter.as_mut() {
-            for predicate in filter.predicates.iter_mut() {
-                // break early if we have ruled out all rows
-                if !plan_builder.selects_any() {
-                    break;
+            plan_builder = match filter.error_policy {
+                ErrorPolicy::Fail => {
+                    reader.evaluate_filter(plan_builder, filter, fields.as_deref(), &metrics)?
                 }
-
-                let mut cache_projection = predicate.projection().clone();
-                cache_projection.intersect(&projection);
-
-                let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
-                    .build_array_reader(fields.as_deref(), predicate.projection())?;
-
-                plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
-            }
+                ErrorPolicy::SkipRowGroup => reader.evaluate_filter_per_row_group(
+                    plan_builder,
+                    filter,
+                    fields.as_deref(),
+                    &metrics,
+                )?,
+            };
         }
 
         let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
+            .with_preallocate_from_stats(preallocate_from_stats)
+            .with_decimal_validation(decimal_validation)
             .build_array_reader(fields.as_deref(), &projection)?;
 
         let read_plan = plan_builder
@@ -916,16 +2366,127 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .build_limited()
             .build();
 
-        Ok(ParquetRecordBatchReader::new(array_reader, read_plan))
+        Ok(ParquetRecordBatchReader::new(array_reader, read_plan)
+            .with_batch_transform(batch_transform)
+            .with_row_group_rows(row_group_rows))
+    }
+}
+
+/// Reads the offset index of the row groups in `row_groups`, returning it indexed
+/// by row group, with an empty offset index for any row group not in `row_groups`
+///
+/// See [`ArrowReaderOptions::with_lazy_page_index`]
+fn read_lazy_offset_index<T: ChunkReader>(
+    reader: &T,
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    policy: PageIndexPolicy,
+    metrics: &ArrowReaderMetrics,
+) -> Result<ParquetOffsetIndex> {
+    let mut offset_index = vec![vec![]; metadata.num_row_groups()];
+    for &row_group_idx in row_groups {
+        let range = row_group_offset_index_range(metadata, row_group_idx)?.unwrap_or_default();
+        let bytes = match usize::try_from(range.end - range.start)? {
+            0 => Bytes::new(),
+            len => reader.get_bytes(range.start, len)?,
+        };
+        metrics.increment_page_index_bytes_read(bytes.len());
+
+        let index =
+            parse_row_group_offset_index(metadata, policy, row_group_idx, &bytes, range.start)?;
+        if let Some(index) = index {
+            offset_index[row_group_idx] = index;
+        }
     }
+    Ok(offset_index)
 }
 
 struct ReaderRowGroups<T: ChunkReader> {
     reader: Arc<T>,
 
     metadata: Arc<ParquetMetaData>,
+    /// The offset index read for each row group, if the page index is read lazily
+    lazy_offset_index: Option<Arc<ParquetOffsetIndex>>,
     /// Optional list of row group indices to scan
     row_groups: Vec<usize>,
+    /// Whether to verify page checksums
+    verify_page_checksums: bool,
+    /// The executor on which to decompress column chunks, if any
+    decompress_pool: Option<Arc<dyn Executor>>,
+}
+
+impl<T: ChunkReader + 'static> ReaderRowGroups<T> {
+    /// Evaluates the predicates in `filter` across all row groups at once
+    fn evaluate_filter(
+        &self,
+        mut plan_builder: ReadPlanBuilder,
+        filter: &mut RowFilter,
+        fields: Option<&ParquetField>,
+        metrics: &ArrowReaderMetrics,
+    ) -> Result<ReadPlanBuilder> {
+        for index in 0..filter.predicates.len() {
+            // break early if we have ruled out all rows
+            if !plan_builder.selects_any() {
+                break;
+            }
+
+            let array_reader = ArrayReaderBuilder::new(self, metrics)
+                .build_array_reader(fields, filter.predicates[index].projection())?;
+
+            plan_builder = filter.evaluate_predicate(index, plan_builder, array_reader)?;
+        }
+        Ok(plan_builder)
+    }
+
+    /// Evaluates the predicates in `filter` one row group at a time, skipping
+    /// any row group for which evaluation fails
+    ///
+    /// See [`ErrorPolicy::SkipRowGroup`]
+    fn evaluate_filter_per_row_group(
+        &self,
+        plan_builder: ReadPlanBuilder,
+        filter: &mut RowFilter,
+        fields: Option<&ParquetField>,
+        metrics: &ArrowReaderMetrics,
+    ) -> Result<ReadPlanBuilder> {
+        let batch_size = plan_builder.batch_size();
+        let mut remaining = plan_builder.selection().cloned();
+        let mut selectors = vec![];
+
+        for &row_group_idx in &self.row_groups {
+            let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
+            let row_group = Self {
+                reader: Arc::clone(&self.reader),
+                metadata: Arc::clone(&self.metadata),
+                lazy_offset_index: self.lazy_offset_index.clone(),
+                row_groups: vec![row_group_idx],
+                verify_page_checksums: self.verify_page_checksums,
+                decompress_pool: self.decompress_pool.clone(),
+            };
+
+            let selection = remaining.as_mut().map(|s| s.split_off(row_count));
+            let row_group_plan = ReadPlanBuilder::new(batch_size).with_selection(selection);
+
+            let selection = match row_group.evaluate_filter(row_group_plan, filter, fields, metrics)
+            {
+                Ok(plan) => plan
+                    .selection()
+                    .cloned()
+                    .unwrap_or_else(|| vec![RowSelector::select(row_count)].into()),
+                Err(e) => {
+                    filter.handle_error(row_group_idx, e, metrics)?;
+                    vec![RowSelector::skip(row_count)].into()
+                }
+            };
+
+            // Pad selections that end early so the next row group starts at the right offset
+            let covered: usize = selection.iter().map(|s| s.row_count).sum();
+            selectors.extend(selection.iter().copied());
+            selectors.push(RowSelector::skip(row_count - covered));
+        }
+
+        Ok(plan_builder.with_selection(Some(selectors.into())))
+    }
 }
 
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
@@ -937,13 +2498,25 @@ impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
             .sum()
     }
 
+    fn row_group_metadata(
+        &self,
+    ) -> Box<dyn Iterator<Item = &crate::file::metadata::RowGroupMetaData> + '_> {
+        Box::new(self.row_groups.iter().map(|x| self.metadata.row_group(*x)))
+    }
+
     fn column_chunks(&self, i: usize) -> Result<Box<dyn PageIterator>> {
-        Ok(Box::new(ReaderPageIterator {
+        let mut iter = ReaderPageIterator {
             column_idx: i,
             reader: self.reader.clone(),
             metadata: self.metadata.clone(),
+            lazy_offset_index: self.lazy_offset_index.clone(),
             row_groups: self.row_groups.clone().into_iter(),
-        }))
+            verify_page_checksums: self.verify_page_checksums,
+            decompress_pool: self.decompress_pool.clone(),
+            prefetched: None,
+        };
+        iter.prefetch();
+        Ok(Box::new(iter))
     }
 }
 
@@ -952,14 +2525,36 @@ struct ReaderPageIterator<T: ChunkReader> {
     column_idx: usize,
     row_groups: std::vec::IntoIter<usize>,
     metadata: Arc<ParquetMetaData>,
+    lazy_offset_index: Option<Arc<ParquetOffsetIndex>>,
+    verify_page_checksums: bool,
+    decompress_pool: Option<Arc<dyn Executor>>,
+    /// The page reader for the next row group, if decompressing on `decompress_pool`
+    prefetched: Option<Result<Box<dyn PageReader>>>,
 }
 
 impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
+    /// Start decompressing the next row group on `decompress_pool`, if any
+    fn prefetch(&mut self) {
+        let Some(pool) = self.decompress_pool.clone() else {
+            return;
+        };
+        let Some(rg_idx) = self.row_groups.next() else {
+            return;
+        };
+        let page_reader = self.next_page_reader(rg_idx).map(|page_reader| {
+            Box::new(ExecutorPageReader::new(&pool, Box::new(page_reader))) as _
+        });
+        self.prefetched = Some(page_reader);
+    }
+
     /// Return the next SerializedPageReader
     fn next_page_reader(&mut self, rg_idx: usize) -> Result<SerializedPageReader<T>> {
         let rg = self.metadata.row_group(rg_idx);
         let column_chunk_metadata = rg.column(self.column_idx);
-        let offset_index = self.metadata.offset_index();
+        let offset_index = match &self.lazy_offset_index {
+            Some(index) => Some(index.as_slice()),
+            None => self.metadata.offset_index().map(|index| index.as_slice()),
+        };
         // `offset_index` may not exist and `i[rg_idx]` will be empty.
         // To avoid `i[rg_idx][self.column_idx`] panic, we need to filter out empty `i[rg_idx]`.
         let page_locations = offset_index
@@ -969,6 +2564,8 @@ impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
         let reader = self.reader.clone();
 
         SerializedPageReader::new(reader, column_chunk_metadata, total_rows, page_locations)?
+            .with_verify_checksums(self.verify_page_checksums)
+            .with_row_group_index(rg_idx)
             .add_crypto_context(
                 rg_idx,
                 self.column_idx,
@@ -982,6 +2579,11 @@ impl<T: ChunkReader + 'static> Iterator for ReaderPageIterator<T> {
     type Item = Result<Box<dyn PageReader>>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.decompress_pool.is_some() {
+            let page_reader = self.prefetched.take()?;
+            self.prefetch();
+            return Some(page_reader);
+        }
         let rg_idx = self.row_groups.next()?;
         let page_reader = self
             .next_page_reader(rg_idx)
@@ -1002,6 +2604,14 @@ pub struct ParquetRecordBatchReader {
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
     read_plan: ReadPlan,
+    batch_transform: Option<BatchTransform>,
+    /// The number of rows still to be read from each row group, if batches
+    /// should not span row groups
+    row_group_rows: Option<VecDeque<usize>>,
+    /// The number of rows read or skipped from the row groups
+    rows_consumed: usize,
+    /// The index of the first row of the last batch, see [`Self::enumerate_rows`]
+    batch_start_row: usize,
 }
 
 impl Debug for ParquetRecordBatchReader {
@@ -1010,6 +2620,13 @@ impl Debug for ParquetRecordBatchReader {
             .field("array_reader", &"...")
             .field("schema", &self.schema)
             .field("read_plan", &self.read_plan)
+            .field(
+                "batch_transform",
+                &self.batch_transform.as_ref().map(|_| "..."),
+            )
+            .field("row_group_rows", &self.row_group_rows)
+            .field("rows_consumed", &self.rows_consumed)
+            .field("batch_start_row", &self.batch_start_row)
             .finish()
     }
 }
@@ -1018,12 +2635,42 @@ impl Iterator for ParquetRecordBatchReader {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next_inner()
-            .map_err(|arrow_err| arrow_err.into())
-            .transpose()
+        let batch = match self.next_inner() {
+            Ok(Some(batch)) => batch,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e.into())),
+        };
+        Some(match &self.batch_transform {
+            Some(transform) => apply_batch_transform(transform, batch, &self.schema),
+            None => Ok(batch),
+        })
     }
 }
 
+/// Applies `transform` to `batch`, verifying the row count and the `schema`
+/// are unchanged
+fn apply_batch_transform(
+    transform: &BatchTransform,
+    batch: RecordBatch,
+    schema: &SchemaRef,
+) -> Result<RecordBatch, ArrowError> {
+    let num_rows = batch.num_rows();
+    let transformed = transform(batch)?;
+    if transformed.num_rows() != num_rows {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "batch transform must preserve the number of rows, expected {num_rows} got {}",
+            transformed.num_rows()
+        )));
+    }
+    if transformed.schema_ref() != schema {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "batch transform must preserve the schema, expected {schema} got {}",
+            transformed.schema_ref()
+        )));
+    }
+    Ok(transformed)
+}
+
 impl ParquetRecordBatchReader {
     /// Returns the next `RecordBatch` from the reader, or `None` if the reader
     /// has reached the end of the file.
@@ -1036,7 +2683,29 @@ impl ParquetRecordBatchReader {
         match self.read_plan.selection_mut() {
             Some(selection) => {
                 while read_records < batch_size && !selection.is_empty() {
-                    let front = selection.pop_front().unwrap();
+                    if let Some(row_group_rows) = self.row_group_rows.as_mut() {
+                        // End the batch at the end of each row group
+                        if row_group_rows.front() == Some(&0) {
+                            row_group_rows.pop_front();
+                            if read_records > 0 {
+                                break;
+                            }
+                        }
+                    }
+
+                    let mut front = selection.pop_front().unwrap();
+                    if let Some(rows) = self.row_group_rows.as_mut().and_then(|r| r.front_mut()) {
+                        // Split selectors that span the end of the current row group
+                        if front.row_count > *rows {
+                            selection.push_front(RowSelector {
+                                row_count: front.row_count - *rows,
+                                skip: front.skip,
+                            });
+                            front.row_count = *rows;
+                        }
+                        *rows -= front.row_count;
+                    }
+
                     if front.skip {
                         let skipped = self.array_reader.skip_records(front.row_count)?;
 
@@ -1047,6 +2716,7 @@ impl ParquetRecordBatchReader {
                                 skipped
                             ));
                         }
+                        self.rows_consumed += skipped;
                         continue;
                     }
 
@@ -1067,14 +2737,25 @@ impl ParquetRecordBatchReader {
                         }
                         _ => front.row_count,
                     };
+                    if read_records == 0 {
+                        self.batch_start_row = self.rows_consumed;
+                    }
                     match self.array_reader.read_records(to_read)? {
                         0 => break,
-                        rec => read_records += rec,
+                        rec => {
+                            read_records += rec;
+                            self.rows_consumed += rec;
+                        }
                     };
                 }
             }
             None => {
-                self.array_reader.read_records(batch_size)?;
+                let to_read = match self.row_group_rows.as_mut() {
+                    Some(row_group_rows) => row_group_rows.pop_front().unwrap_or_default(),
+                    None => batch_size,
+                };
+                self.batch_start_row = self.rows_consumed;
+                self.rows_consumed += self.array_reader.read_records(to_read)?;
             }
         };
 
@@ -1134,6 +2815,10 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(Schema::new(levels.fields.clone())),
             read_plan,
+            batch_transform: None,
+            row_group_rows: None,
+            rows_consumed: 0,
+            batch_start_row: 0,
         })
     }
 
@@ -1150,15 +2835,104 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(schema),
             read_plan,
+            batch_transform: None,
+            row_group_rows: None,
+            rows_consumed: 0,
+            batch_start_row: 0,
         }
     }
 
+    /// Set the [`BatchTransform`] applied to each batch returned by this reader
+    pub(crate) fn with_batch_transform(mut self, batch_transform: Option<BatchTransform>) -> Self {
+        self.batch_transform = batch_transform;
+        self
+    }
+
+    /// Set the number of rows in each row group to be read, batches will then not
+    /// span more than one row group
+    pub(crate) fn with_row_group_rows(mut self, row_group_rows: Option<VecDeque<usize>>) -> Self {
+        self.row_group_rows = row_group_rows;
+        self
+    }
+
+    /// Returns an iterator over the batches of this reader, along with the index
+    /// of the first row of each batch
+    ///
+    /// The index of a row is its position among the rows of the row groups read,
+    /// before any [`RowSelection`], [`RowFilter`], offset or limit is applied. When
+    /// reading all row groups of a file, as is the default, this is the index of
+    /// the first row of the batch in the file. Otherwise, it is the index in the
+    /// concatenation of the row groups read, in the order they are read.
+    ///
+    /// Note that the rows of a batch are only contiguous in the file if no rows
+    /// are filtered, and that batches may span row groups.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{
+    /// #     ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+    /// # };
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let data = Bytes::from(buf);
+    /// let selection = RowSelection::from(vec![RowSelector::skip(3), RowSelector::select(7)]);
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+    ///     .unwrap()
+    ///     .with_row_selection(selection)
+    ///     .with_batch_size(4)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let start_rows: Vec<_> = reader
+    ///     .enumerate_rows()
+    ///     .map(|r| r.map(|(_, start_row)| start_row))
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(start_rows, [3, 7]);
+    /// ```
+    ///
+    /// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+    pub fn enumerate_rows(self) -> EnumerateRows {
+        EnumerateRows { reader: self }
+    }
+
     #[inline(always)]
     pub(crate) fn batch_size(&self) -> usize {
         self.read_plan.batch_size()
     }
 }
 
+/// An iterator over the batches of a [`ParquetRecordBatchReader`] and the index of
+/// the first row of each batch, see [`ParquetRecordBatchReader::enumerate_rows`]
+#[derive(Debug)]
+pub struct EnumerateRows {
+    reader: ParquetRecordBatchReader,
+}
+
+impl EnumerateRows {
+    /// Consumes this iterator, returning the underlying reader
+    pub fn into_inner(self) -> ParquetRecordBatchReader {
+        self.reader
+    }
+}
+
+impl Iterator for EnumerateRows {
+    type Item = Result<(RecordBatch, usize), ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.reader.next()?;
+        Some(batch.map(|batch| (batch, self.reader.batch_start_row)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp::min;
@@ -1167,7 +2941,8 @@ mod tests {
     use std::fs::File;
     use std::io::Seek;
     use std::path::PathBuf;
-    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
 
     use arrow_array::builder::*;
     use arrow_array::cast::AsArray;
@@ -1177,36 +2952,47 @@ mod tests {
         Time64MicrosecondType,
     };
     use arrow_array::*;
-    use arrow_buffer::{ArrowNativeType, Buffer, IntervalDayTime, NullBuffer, i256};
+    use arrow_buffer::{ArrowNativeType, Buffer, IntervalDayTime, NullBuffer, OffsetBuffer, i256};
     use arrow_data::{ArrayData, ArrayDataBuilder};
+    use arrow_ord::cmp;
     use arrow_schema::{
         ArrowError, DataType as ArrowDataType, Field, Fields, Schema, SchemaRef, TimeUnit,
     };
     use arrow_select::concat::concat_batches;
+    use arrow_select::filter::filter_record_batch;
     use bytes::Bytes;
     use half::f16;
     use num_traits::PrimInt;
     use rand::{Rng, RngCore, rng};
     use tempfile::tempfile;
 
+    use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReader,
-        ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+        ArrowPredicate, ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderMetadata,
+        ArrowReaderOptions, BatchTransform, CmpOp, ErrorPolicy, Executor, ParquetRecordBatchReader,
+        ParquetRecordBatchReaderBuilder, PredicateExpr, PredicateStats, RowFilter, RowSelection,
+        RowSelector, SimplePredicate, UnknownLogicalTypePolicy, arrow_schema_from_parquet,
+        columns_cmp,
     };
+    use crate::arrow::arrow_writer::ArrowWriterOptions;
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
     use crate::arrow::{ArrowWriter, ProjectionMask};
-    use crate::basic::{ConvertedType, Encoding, Repetition, Type as PhysicalType};
+    use crate::basic::{ConvertedType, Encoding, LogicalType, Repetition, Type as PhysicalType};
     use crate::column::reader::decoder::REPETITION_LEVELS_BATCH_SIZE;
     use crate::data_type::{
         BoolType, ByteArray, ByteArrayType, DataType, FixedLenByteArray, FixedLenByteArrayType,
         FloatType, Int32Type, Int64Type, Int96, Int96Type,
     };
     use crate::errors::Result;
-    use crate::file::metadata::ParquetMetaData;
+    use crate::file::metadata::{
+        FileMetaData, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader,
+        row_group_offset_index_range,
+    };
     use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+    use crate::file::reader::{ChunkReader, Length};
     use crate::file::writer::SerializedFileWriter;
     use crate::schema::parser::parse_message_type;
-    use crate::schema::types::{Type, TypePtr};
+    use crate::schema::types::{ColumnPath, SchemaDescriptor, Type, TypePtr};
     use crate::util::test_common::rand_gen::RandGen;
 
     #[test]
@@ -1774,6 +3560,210 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_int96_timeunit() {
+        // 1000-01-01T00:00:00.000001, outside the range of nanosecond timestamps
+        let ancient = -30_610_224_000_000_000 + 1;
+        // 2020-09-13T12:26:40.123456
+        let recent = 1_600_000_000_123_456;
+        let values = vec![Some(recent), None, Some(ancient)];
+        let timestamps = TimestampMicrosecondArray::from(values.clone()).with_timezone("+01:00");
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(timestamps.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([2, 0, 1]),
+            Arc::new(timestamps.clone()),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("ts", Arc::new(timestamps) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_int96_timestamps(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |options: ArrowReaderOptions| {
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                .unwrap()
+                .build()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+        };
+        let list_values = |batch: &RecordBatch| batch.column(1).as_list::<i32>().values().clone();
+
+        // Without the embedded arrow schema, as written by other implementations,
+        // INT96 is read as nanoseconds, and the ancient timestamp wraps around
+        let batch = read(ArrowReaderOptions::new().with_skip_arrow_metadata(true));
+        let ts = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::TimestampNanosecondType>();
+        assert_eq!(
+            ts.data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        assert_eq!(ts.value(0), recent * 1000);
+        assert!(ts.value(2) > 0);
+
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(recent / 1000),
+            None,
+            Some(ancient.div_euclid(1000)),
+        ]);
+        let options = ArrowReaderOptions::new()
+            .with_skip_arrow_metadata(true)
+            .with_int96_timeunit(TimeUnit::Millisecond);
+        let batch = read(options);
+        assert_eq!(batch.column(0).as_ref(), &expected);
+        assert_eq!(list_values(&batch).as_ref(), &expected);
+
+        // The timezone of the embedded arrow schema is preserved
+        let expected = TimestampMicrosecondArray::from(values).with_timezone("+01:00");
+        let batch = read(ArrowReaderOptions::new().with_int96_timeunit(TimeUnit::Microsecond));
+        assert_eq!(batch.column(0).as_ref(), &expected);
+        assert_eq!(list_values(&batch).as_ref(), &expected);
+
+        let batch = read(ArrowReaderOptions::new().with_int96_timeunit(TimeUnit::Second));
+        let expected = ArrowDataType::Timestamp(TimeUnit::Second, Some("+01:00".into()));
+        assert_eq!(batch.column(0).data_type(), &expected);
+        assert_eq!(list_values(&batch).data_type(), &expected);
+        let ts = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::TimestampSecondType>();
+        assert_eq!(ts.value(0), 1_600_000_000);
+        assert_eq!(ts.value(2), -30_610_224_000);
+    }
+
+    #[test]
+    fn test_required_column_no_null_buffer() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int64", ArrowDataType::Int64, false),
+            Field::new("utf8", ArrowDataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from_iter_values(0..1000)),
+                Arc::new(StringArray::from_iter_values(
+                    (0..1000).map(|i| format!("value{i}")),
+                )),
+            ],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        for column in builder.parquet_schema().columns() {
+            assert_eq!(
+                column.self_type().get_basic_info().repetition(),
+                Repetition::REQUIRED
+            );
+            assert_eq!(column.max_def_level(), 0);
+        }
+
+        let selection = RowSelection::from(vec![
+            RowSelector::select(100),
+            RowSelector::skip(200),
+            RowSelector::select(300),
+        ]);
+        let reader = builder
+            .with_batch_size(128)
+            .with_row_selection(selection)
+            .build()
+            .unwrap();
+
+        let mut total_rows = 0;
+        for batch in reader {
+            let batch = batch.unwrap();
+            let ints = batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int64Type>();
+            assert_eq!(ints.null_count(), 0);
+            assert!(ints.nulls().is_none());
+            let strings = batch.column(1).as_string::<i32>();
+            assert_eq!(strings.null_count(), 0);
+            assert!(strings.nulls().is_none());
+            total_rows += batch.num_rows();
+        }
+        assert_eq!(total_rows, 400);
+    }
+
+    #[test]
+    fn test_page_index_columns() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|i| format!("value{i}")),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        for columns in [vec![], vec![0], vec![1], vec![0, 1]] {
+            let metadata = ParquetMetaDataReader::new()
+                .with_page_index_policy(PageIndexPolicy::Required)
+                .with_page_index_columns(columns.iter().copied())
+                .parse_and_finish(&data)
+                .unwrap();
+            match metadata.offset_index() {
+                // Without any columns, no page index is read
+                None => assert!(columns.is_empty()),
+                Some(offset_index) => {
+                    for (idx, index) in offset_index[0].iter().enumerate() {
+                        let expected_pages = if columns.contains(&idx) { 10 } else { 0 };
+                        assert_eq!(index.page_locations().len(), expected_pages);
+                    }
+                }
+            }
+
+            let options = ArrowReaderOptions::new().with_page_index(true);
+            let metadata = ArrowReaderMetadata::try_new(Arc::new(metadata), options).unwrap();
+            let selection = RowSelection::from(vec![
+                RowSelector::skip(15),
+                RowSelector::select(10),
+                RowSelector::skip(50),
+                RowSelector::select(5),
+            ]);
+            let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(data.clone(), metadata)
+                .with_row_selection(selection)
+                .build()
+                .unwrap();
+            let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+
+            let expected = [batch.slice(15, 10), batch.slice(75, 5)];
+            let expected = concat_batches(&batch.schema(), &expected).unwrap();
+            let actual = concat_batches(&batch.schema(), &batches).unwrap();
+            assert_eq!(actual, expected, "{columns:?}");
+        }
+    }
+
     #[test]
     fn test_int96_from_spark_file_with_provided_schema() {
         // int96_from_spark.parquet was written based on Spark's microsecond timestamps which trade
@@ -3081,6 +5071,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_nested_struct_round_trip() {
+        // s: {a: Int32, b: {c: Utf8, d: Int64}}, x: Int32
+        let b = StructArray::from(vec![
+            (
+                Arc::new(Field::new("c", ArrowDataType::Utf8, true)),
+                Arc::new(StringArray::from(vec![Some("foo"), None, Some("baz")])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("d", ArrowDataType::Int64, false)),
+                Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+        ]);
+        let s = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("a", ArrowDataType::Int32, true),
+                Field::new("b", b.data_type().clone(), true),
+            ]),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None])),
+                Arc::new(b),
+            ],
+            Some(NullBuffer::from(vec![true, false, true])),
+        )
+        .unwrap();
+        let written = RecordBatch::try_from_iter([
+            ("s", Arc::new(s) as ArrayRef),
+            ("x", Arc::new(Int32Array::from(vec![4, 5, 6])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, written.schema(), None).unwrap();
+        writer.write(&written).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        // The top-level struct is read as a single StructArray column, whether
+        // or not the embedded arrow schema is used
+        for skip_arrow_metadata in [false, true] {
+            let options = ArrowReaderOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let builder =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap();
+            assert_eq!(builder.parquet_schema().num_columns(), 4);
+            assert_eq!(builder.schema(), &written.schema());
+
+            let read = builder.build().unwrap().next().unwrap().unwrap();
+            assert_eq!(read.num_columns(), 2);
+            assert_eq!(read, written);
+        }
+
+        // Projecting a nested leaf retains the struct hierarchy above it
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let mask = ProjectionMask::columns(builder.parquet_schema(), ["s.b.d"]);
+        let read = builder
+            .with_projection(mask)
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read.num_columns(), 1);
+        let s = read.column(0).as_struct();
+        assert_eq!(s.nulls(), written.column(0).nulls());
+        let b = s.column_by_name("b").unwrap().as_struct();
+        assert_eq!(s.num_columns(), 1);
+        assert_eq!(b.num_columns(), 1);
+        // The value of `d` is unspecified where `s` is null
+        let d = b
+            .column_by_name("d")
+            .unwrap()
+            .as_primitive::<types::Int64Type>();
+        assert_eq!((d.value(0), d.value(2)), (1, 3));
+    }
+
     #[test]
     // same as test_read_structs but constructs projection mask via column names
     fn test_read_structs_by_name() {
@@ -3135,790 +5201,3199 @@ mod tests {
     }
 
     #[test]
-    fn test_read_maps() {
-        let testdata = arrow::util::test_util::parquet_test_data();
-        let path = format!("{testdata}/nested_maps.snappy.parquet");
-        let file = File::open(path).unwrap();
-        let record_batch_reader = ParquetRecordBatchReader::try_new(file, 60).unwrap();
-
-        for batch in record_batch_reader {
-            batch.unwrap();
-        }
-    }
-
-    #[test]
-    fn test_nested_nullability() {
-        let message_type = "message nested {
-          OPTIONAL Group group {
-            REQUIRED INT32 leaf;
-          }
-        }";
+    fn test_batch_transform() {
+        let a = Int32Array::from_iter_values(0..10);
+        let b = StringArray::from_iter_values((0..10).map(|i| format!("  {i}  ")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
 
-        let file = tempfile::tempfile().unwrap();
-        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
 
-        {
-            // Write using low-level parquet API (#1167)
-            let mut writer =
-                SerializedFileWriter::new(file.try_clone().unwrap(), schema, Default::default())
-                    .unwrap();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+        // keep only the even values of `a`
+        let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+            ProjectionMask::leaves(&schema_descr, [0]),
+            |batch: RecordBatch| {
+                let a = batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>();
+                Ok(BooleanArray::from_unary(a, |v| v % 2 == 0))
+            },
+        ))]);
 
-            {
-                let mut row_group_writer = writer.next_row_group().unwrap();
-                let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let captured = Arc::clone(&calls);
+        let transform: BatchTransform = Arc::new(move |batch: RecordBatch| {
+            captured.fetch_add(1, Ordering::SeqCst);
+            // transform is applied after projection
+            assert_eq!(batch.num_columns(), 1);
+            let b = batch.column(0).as_string::<i32>();
+            let trimmed = StringArray::from_iter(b.iter().map(|v| v.map(str::trim)));
+            RecordBatch::try_new(batch.schema(), vec![Arc::new(trimmed)])
+        });
 
-                column_writer
-                    .typed::<Int32Type>()
-                    .write_batch(&[34, 76], Some(&[0, 1, 0, 1]), None)
-                    .unwrap();
+        let reader = builder
+            .with_batch_size(2)
+            .with_projection(ProjectionMask::leaves(&schema_descr, [1]))
+            .with_row_filter(filter)
+            .with_batch_transform(transform)
+            .build()
+            .unwrap();
 
-                column_writer.close().unwrap();
-                row_group_writer.close().unwrap();
-            }
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        // 5 rows pass the filter, the last batch is partial
+        let lengths: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(lengths, vec![2, 2, 1]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
 
-            writer.close().unwrap();
-        }
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| {
+                b.column(0)
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|v| v.unwrap().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(values, vec!["0", "2", "4", "6", "8"]);
 
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
-        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        // transforms that change the number of rows are rejected
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_batch_size(4)
+            .with_batch_transform(Arc::new(|batch: RecordBatch| Ok(batch.slice(0, 1))))
+            .build()
+            .unwrap();
 
-        let reader = builder.with_projection(mask).build().unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: batch transform must preserve the number of rows, expected 4 got 1"
+        );
 
-        let expected_schema = Schema::new(vec![Field::new(
-            "group",
-            ArrowDataType::Struct(vec![Field::new("leaf", ArrowDataType::Int32, false)].into()),
-            true,
-        )]);
+        // as are transforms that change the schema
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_projection(ProjectionMask::leaves(&schema_descr, [0]))
+            .with_batch_transform(Arc::new(|batch: RecordBatch| {
+                let a = arrow_cast::cast(batch.column(0), &ArrowDataType::Int64)?;
+                RecordBatch::try_from_iter([("a", a)])
+            }))
+            .build()
+            .unwrap();
+        assert_eq!(reader.schema().field(0).data_type(), &ArrowDataType::Int32);
 
-        let batch = reader.into_iter().next().unwrap().unwrap();
-        assert_eq!(batch.schema().as_ref(), &expected_schema);
-        assert_eq!(batch.num_rows(), 4);
-        assert_eq!(batch.column(0).null_count(), 2);
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: batch transform must preserve the schema, expected Field { \"a\": Int32 } got Field { \"a\": Int64 }"
+        );
     }
 
     #[test]
-    fn test_invalid_utf8() {
-        // a parquet file with 1 column with invalid utf8
-        let data = vec![
-            80, 65, 82, 49, 21, 6, 21, 22, 21, 22, 92, 21, 2, 21, 0, 21, 2, 21, 0, 21, 4, 21, 0,
-            18, 28, 54, 0, 40, 5, 104, 101, 255, 108, 111, 24, 5, 104, 101, 255, 108, 111, 0, 0, 0,
-            3, 1, 5, 0, 0, 0, 104, 101, 255, 108, 111, 38, 110, 28, 21, 12, 25, 37, 6, 0, 25, 24,
-            2, 99, 49, 21, 0, 22, 2, 22, 102, 22, 102, 38, 8, 60, 54, 0, 40, 5, 104, 101, 255, 108,
-            111, 24, 5, 104, 101, 255, 108, 111, 0, 0, 0, 21, 4, 25, 44, 72, 4, 114, 111, 111, 116,
-            21, 2, 0, 21, 12, 37, 2, 24, 2, 99, 49, 37, 0, 76, 28, 0, 0, 0, 22, 2, 25, 28, 25, 28,
-            38, 110, 28, 21, 12, 25, 37, 6, 0, 25, 24, 2, 99, 49, 21, 0, 22, 2, 22, 102, 22, 102,
-            38, 8, 60, 54, 0, 40, 5, 104, 101, 255, 108, 111, 24, 5, 104, 101, 255, 108, 111, 0, 0,
-            0, 22, 102, 22, 2, 0, 40, 44, 65, 114, 114, 111, 119, 50, 32, 45, 32, 78, 97, 116, 105,
-            118, 101, 32, 82, 117, 115, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97,
-            116, 105, 111, 110, 32, 111, 102, 32, 65, 114, 114, 111, 119, 0, 130, 0, 0, 0, 80, 65,
-            82, 49,
-        ];
+    fn test_row_filter_stats_callback() {
+        let a = Int32Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
 
-        let file = Bytes::from(data);
-        let mut record_batch_reader = ParquetRecordBatchReader::try_new(file, 10).unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(50)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let predicate = |f: fn(i32) -> bool| -> Box<dyn ArrowPredicate> {
+            Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                move |batch: RecordBatch| {
+                    let a = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int32Type>();
+                    Ok(BooleanArray::from_unary(a, f))
+                },
+            ))
+        };
 
-        let error = record_batch_reader.next().unwrap().unwrap_err();
+        let stats = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&stats);
+        let filter = RowFilter::new(vec![
+            predicate(|v| v % 2 == 0),
+            predicate(|v| v < 30),
+            predicate(|v| v > 100),
+            // not evaluated as no rows survive the previous predicates
+            predicate(|_| true),
+        ])
+        .with_stats_callback(move |s| captured.lock().unwrap().push(s));
 
-        assert!(
-            error.to_string().contains("invalid utf-8 sequence"),
-            "{}",
-            error
-        );
-    }
+        let reader = builder.with_row_filter(filter).build().unwrap();
+        assert_eq!(reader.count(), 0);
 
-    #[test]
-    fn test_invalid_utf8_string_array() {
-        test_invalid_utf8_string_array_inner::<i32>();
+        let stats = stats.lock().unwrap();
+        let expected = [(0, 100, 50), (1, 50, 15), (2, 15, 0)].map(|(i, evaluated, selected)| {
+            PredicateStats {
+                predicate_index: i,
+                rows_evaluated: evaluated,
+                rows_selected: selected,
+            }
+        });
+        assert_eq!(stats.as_slice(), &expected);
     }
 
     #[test]
-    fn test_invalid_utf8_large_string_array() {
-        test_invalid_utf8_string_array_inner::<i64>();
-    }
+    fn test_row_group_selections() {
+        let a = Int32Array::from_iter_values(0..400);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
 
-    fn test_invalid_utf8_string_array_inner<O: OffsetSizeTrait>() {
-        let cases = [
-            invalid_utf8_first_char::<O>(),
-            invalid_utf8_first_char_long_strings::<O>(),
-            invalid_utf8_later_char::<O>(),
-            invalid_utf8_later_char_long_strings::<O>(),
-            invalid_utf8_later_char_really_long_strings::<O>(),
-            invalid_utf8_later_char_really_long_strings2::<O>(),
-        ];
-        for array in &cases {
-            for encoding in STRING_ENCODINGS {
-                // data is not valid utf8 we can not construct a correct StringArray
-                // safely, so purposely create an invalid StringArray
-                let array = unsafe {
-                    GenericStringArray::<O>::new_unchecked(
-                        array.offsets().clone(),
-                        array.values().clone(),
-                        array.nulls().cloned(),
-                    )
-                };
-                let data_type = array.data_type().clone();
-                let data = write_to_parquet_with_encoding(Arc::new(array), *encoding);
-                let err = read_from_parquet(data).unwrap_err();
-                let expected_err =
-                    "Parquet argument error: Parquet error: encountered non UTF-8 data";
-                assert!(
-                    err.to_string().contains(expected_err),
-                    "data type: {data_type}, expected: {expected_err}, got: {err}"
-                );
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |selections: HashMap<usize, RowSelection>| -> Result<Vec<i32>> {
+            let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())?
+                .with_row_group_selections(selections)?
+                .build()?;
+            let mut values = vec![];
+            for batch in reader {
+                let batch = batch?;
+                let a = batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>();
+                values.extend(a.values().iter().copied());
             }
-        }
-    }
+            Ok(values)
+        };
 
-    #[test]
-    fn test_invalid_utf8_string_view_array() {
-        let cases = [
-            invalid_utf8_first_char::<i32>(),
-            invalid_utf8_first_char_long_strings::<i32>(),
-            invalid_utf8_later_char::<i32>(),
-            invalid_utf8_later_char_long_strings::<i32>(),
-            invalid_utf8_later_char_really_long_strings::<i32>(),
-            invalid_utf8_later_char_really_long_strings2::<i32>(),
-        ];
+        let selections = HashMap::from([
+            // row group 3 partially selected, with a selection covering all rows
+            (
+                3,
+                RowSelection::from(vec![RowSelector::select(10), RowSelector::skip(90)]),
+            ),
+            // row group 0 fully selected
+            (0, RowSelection::from(vec![RowSelector::select(100)])),
+            // row group 2 partially selected, with a selection covering fewer rows
+            (
+                2,
+                RowSelection::from(vec![RowSelector::skip(50), RowSelector::select(20)]),
+            ),
+        ]);
+        let expected: Vec<i32> = (0..100).chain(250..270).chain(300..310).collect();
+        assert_eq!(read(selections.clone()).unwrap(), expected);
 
-        for encoding in STRING_ENCODINGS {
-            for array in &cases {
-                let array = arrow_cast::cast(&array, &ArrowDataType::BinaryView).unwrap();
-                let array = array.as_binary_view();
+        // Equivalent to the global row selection
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_row_groups(vec![0, 2, 3])
+            .with_row_selection(RowSelection::from(vec![
+                RowSelector::select(100),
+                RowSelector::skip(50),
+                RowSelector::select(20),
+                RowSelector::skip(30),
+                RowSelector::select(10),
+            ]))
+            .build()
+            .unwrap();
+        let total: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+        assert_eq!(total, expected.len());
 
-                // data is not valid utf8 we can not construct a correct StringArray
-                // safely, so purposely create an invalid StringViewArray
-                let array = unsafe {
-                    StringViewArray::new_unchecked(
-                        array.views().clone(),
-                        array.data_buffers().to_vec(),
-                        array.nulls().cloned(),
-                    )
-                };
+        // Row groups with an empty selection are skipped
+        let selections = HashMap::from([
+            (1, RowSelection::from(vec![])),
+            (2, RowSelection::from(vec![RowSelector::select(1)])),
+        ]);
+        assert_eq!(read(selections).unwrap(), vec![200]);
+        assert!(read(HashMap::new()).unwrap().is_empty());
 
-                let data_type = array.data_type().clone();
-                let data = write_to_parquet_with_encoding(Arc::new(array), *encoding);
-                let err = read_from_parquet(data).unwrap_err();
-                let expected_err =
-                    "Parquet argument error: Parquet error: encountered non UTF-8 data";
-                assert!(
-                    err.to_string().contains(expected_err),
-                    "data type: {data_type}, expected: {expected_err}, got: {err}"
-                );
-            }
-        }
+        let err = read(HashMap::from([(4, RowSelection::from(vec![]))])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row group 4 out of bounds, file has 4 row groups"
+        );
+        let selections = HashMap::from([(
+            1,
+            RowSelection::from(vec![RowSelector::skip(50), RowSelector::select(51)]),
+        )]);
+        let err = read(selections).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Selection for row group 1 covers 101 rows, but the row group has 100 rows"
+        );
     }
 
-    /// Encodings suitable for string data
-    const STRING_ENCODINGS: &[Option<Encoding>] = &[
-        None,
-        Some(Encoding::PLAIN),
-        Some(Encoding::DELTA_LENGTH_BYTE_ARRAY),
-        Some(Encoding::DELTA_BYTE_ARRAY),
-    ];
+    #[test]
+    fn test_enumerate_rows() {
+        let a = Int32Array::from_iter_values(0..35);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
 
-    /// Invalid Utf-8 sequence in the first character
-    /// <https://stackoverflow.com/questions/1301402/example-invalid-utf8-string>
-    const INVALID_UTF8_FIRST_CHAR: &[u8] = &[0xa0, 0xa1, 0x20, 0x20];
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
 
-    /// Invalid Utf=8 sequence in NOT the first character
-    /// <https://stackoverflow.com/questions/1301402/example-invalid-utf8-string>
-    const INVALID_UTF8_LATER_CHAR: &[u8] = &[0x20, 0x20, 0x20, 0xa0, 0xa1, 0x20, 0x20];
+        let read = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            builder
+                .with_batch_size(4)
+                .build()
+                .unwrap()
+                .enumerate_rows()
+                .map(|r| {
+                    let (batch, start_row) = r.unwrap();
+                    let a = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int32Type>();
+                    (start_row, a.values().to_vec())
+                })
+                .collect::<Vec<_>>()
+        };
 
-    /// returns a BinaryArray with invalid UTF8 data in the first character
-    fn invalid_utf8_first_char<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let invalid = INVALID_UTF8_FIRST_CHAR;
-        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(invalid)])
-    }
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let batches = read(builder);
+        assert_eq!(batches.len(), 9);
+        for (start_row, values) in batches {
+            assert_eq!(values[0] as usize, start_row);
+        }
 
-    /// Returns a BinaryArray with invalid UTF8 data in the first character of a
-    /// string larger than 12 bytes which is handled specially when reading
-    /// `ByteViewArray`s
-    fn invalid_utf8_first_char_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let mut invalid = vec![];
-        invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
-        invalid.extend_from_slice(INVALID_UTF8_FIRST_CHAR);
-        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
-    }
+        // keeps multiples of 3, after skipping the first 2 rows
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+            ProjectionMask::leaves(&schema_descr, [0]),
+            |batch: RecordBatch| {
+                let a = batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>();
+                Ok(BooleanArray::from_unary(a, |v| v % 3 == 0))
+            },
+        ))]);
+        let builder = builder.with_row_filter(filter).with_offset(1);
+        assert_eq!(
+            read(builder),
+            vec![
+                (3, vec![3, 6, 9, 12]),
+                (15, vec![15, 18, 21, 24]),
+                (27, vec![27, 30, 33]),
+            ]
+        );
 
-    /// returns a BinaryArray with invalid UTF8 data in a character other than
-    /// the first (this is checked in a special codepath)
-    fn invalid_utf8_later_char<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let invalid: &[u8] = INVALID_UTF8_LATER_CHAR;
-        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(invalid)])
+        // row indices are relative to the row groups read
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_groups(vec![3, 1])
+            .with_row_selection(RowSelection::from(vec![
+                RowSelector::skip(3),
+                RowSelector::select(4),
+                RowSelector::skip(1),
+                RowSelector::select(2),
+            ]));
+        assert_eq!(
+            read(builder),
+            vec![(3, vec![33, 34, 10, 11]), (8, vec![13, 14])]
+        );
     }
 
-    /// returns a BinaryArray with invalid UTF8 data in a character other than
-    /// the first in a string larger than 12 bytes which is handled specially
-    /// when reading `ByteViewArray`s (this is checked in a special codepath)
-    fn invalid_utf8_later_char_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let mut invalid = vec![];
-        invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
-        invalid.extend_from_slice(INVALID_UTF8_LATER_CHAR);
-        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
-    }
+    #[test]
+    fn test_row_filter_borrowed_predicate() {
+        let a = Int32Array::from_iter_values(0..30);
+        let b = StringArray::from_iter_values((0..30).map(|i| format!("v{i}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
 
-    /// returns a BinaryArray with invalid UTF8 data in a character other than
-    /// the first in a string larger than 128 bytes which is handled specially
-    /// when reading `ByteViewArray`s (this is checked in a special codepath)
-    fn invalid_utf8_later_char_really_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let mut invalid = vec![];
-        for _ in 0..10 {
-            // each instance is 38 bytes
-            invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
-        }
-        invalid.extend_from_slice(INVALID_UTF8_LATER_CHAR);
-        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
-    }
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
 
-    /// returns a BinaryArray with small invalid UTF8 data followed by a large
-    /// invalid UTF8 data in a character other than the first in a string larger
-    fn invalid_utf8_later_char_really_long_strings2<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
-        let valid: &[u8] = b"   ";
-        let mut valid_long = vec![];
-        for _ in 0..10 {
-            // each instance is 38 bytes
-            valid_long.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
+        fn is_multiple_of_4(batch: &RecordBatch) -> Result<BooleanArray, ArrowError> {
+            let a = batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>();
+            Ok(BooleanArray::from_unary(a, |v| v % 4 == 0))
         }
-        let invalid = INVALID_UTF8_LATER_CHAR;
-        GenericBinaryArray::<O>::from_iter(vec![
-            None,
-            Some(valid),
-            Some(invalid),
-            None,
-            Some(&valid_long),
-            Some(valid),
-        ])
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let filter = RowFilter::new(vec![
+            Box::new(ArrowPredicateFn::new_borrowed(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                is_multiple_of_4,
+            )),
+            Box::new(ArrowPredicateFn::new_borrowed(
+                ProjectionMask::leaves(&schema_descr, [1]),
+                |batch: &RecordBatch| {
+                    let b = batch.column(0).as_string::<i32>();
+                    Ok(BooleanArray::from_iter(
+                        b.iter().map(|v| v.map(|v| v != "v8")),
+                    ))
+                },
+            )),
+        ]);
+        let reader = builder.with_row_filter(filter).build().unwrap();
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+        let a = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::Int32Type>();
+        assert_eq!(a.values(), &[0, 4, 12, 16, 20, 24, 28]);
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(b.value(1), "v4");
     }
 
-    /// writes the array into a single column parquet file with the specified
-    /// encoding.
-    ///
-    /// If no encoding is specified, use default (dictionary) encoding
-    fn write_to_parquet_with_encoding(array: ArrayRef, encoding: Option<Encoding>) -> Vec<u8> {
-        let batch = RecordBatch::try_from_iter(vec![("c", array)]).unwrap();
-        let mut data = vec![];
-        let schema = batch.schema();
-        let props = encoding.map(|encoding| {
-            WriterProperties::builder()
-                // must disable dictionary encoding to actually use encoding
-                .set_dictionary_enabled(false)
-                .set_encoding(encoding)
-                .build()
-        });
+    #[test]
+    fn test_row_filter_error_policy() {
+        let a = Int32Array::from_iter_values(0..30);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
 
-        {
-            let mut writer = ArrowWriter::try_new(&mut data, schema, props).unwrap();
-            writer.write(&batch).unwrap();
-            writer.flush().unwrap();
-            writer.close().unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // keeps even values, but fails for the second row group
+        let make_filter = |schema_descr: &SchemaDescriptor| {
+            RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(schema_descr, [0]),
+                |batch: RecordBatch| {
+                    let a = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int32Type>();
+                    if a.values().iter().any(|v| (10..20).contains(v)) {
+                        return Err(ArrowError::ComputeError("corrupt".to_string()));
+                    }
+                    Ok(BooleanArray::from_unary(a, |v| v % 2 == 0))
+                },
+            ))])
         };
-        data
-    }
 
-    /// read the parquet file into a record batch
-    fn read_from_parquet(data: Vec<u8>) -> Result<Vec<RecordBatch>, ArrowError> {
-        let reader = ArrowReaderBuilder::try_new(bytes::Bytes::from(data))
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let err = builder
+            .with_row_filter(make_filter(&schema_descr))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "External: Compute error: corrupt");
+
+        let metrics = ArrowReaderMetrics::enabled();
+        let errors = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&errors);
+        let filter = make_filter(&schema_descr)
+            .with_error_policy(ErrorPolicy::SkipRowGroup)
+            .with_error_callback(move |row_group_idx, error| {
+                captured
+                    .lock()
+                    .unwrap()
+                    .push((row_group_idx, error.to_string()));
+            });
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data)
             .unwrap()
+            .with_row_selection(RowSelection::from(vec![
+                RowSelector::skip(5),
+                RowSelector::select(25),
+            ]))
+            .with_row_filter(filter)
+            .with_metrics(metrics.clone())
             .build()
             .unwrap();
 
-        reader.collect()
-    }
-
-    #[test]
-    fn test_dictionary_preservation() {
-        let fields = vec![Arc::new(
-            Type::primitive_type_builder("leaf", PhysicalType::BYTE_ARRAY)
-                .with_repetition(Repetition::OPTIONAL)
-                .with_converted_type(ConvertedType::UTF8)
-                .build()
-                .unwrap(),
-        )];
-
-        let schema = Arc::new(
-            Type::group_type_builder("test_schema")
-                .with_fields(fields)
-                .build()
-                .unwrap(),
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| {
+                b.column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        assert_eq!(values, vec![6, 8, 20, 22, 24, 26, 28]);
+        assert_eq!(metrics.row_groups_skipped(), Some(1));
+        assert_eq!(
+            errors.lock().unwrap().as_slice(),
+            &[(1, "Compute error: corrupt".to_string())]
         );
+    }
 
-        let dict_type = ArrowDataType::Dictionary(
-            Box::new(ArrowDataType::Int32),
-            Box::new(ArrowDataType::Utf8),
-        );
+    #[test]
+    fn test_arrow_schema_from_parquet() {
+        let item = Arc::new(Field::new_list_field(ArrowDataType::Utf8, true));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int32", ArrowDataType::Int32, false),
+            Field::new_struct(
+                "struct",
+                vec![
+                    Field::new("a", ArrowDataType::Int64, true),
+                    Field::new_list("b", item.clone(), true),
+                ],
+                true,
+            ),
+            Field::new_large_list("large_list", item, true),
+            Field::new_map(
+                "map",
+                "entries",
+                Field::new("keys", ArrowDataType::Utf8, false),
+                Field::new("values", ArrowDataType::Int64, true),
+                false,
+                true,
+            ),
+            Field::new(
+                "timestamp",
+                ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                true,
+            ),
+            Field::new("decimal", ArrowDataType::Decimal128(20, 3), true),
+            Field::new("date", ArrowDataType::Date32, true),
+            Field::new("float16", ArrowDataType::Float16, true),
+            Field::new("uint8", ArrowDataType::UInt8, true),
+            Field::new("fixed", ArrowDataType::FixedSizeBinary(16), true),
+            Field::new(
+                "dictionary",
+                ArrowDataType::Dictionary(
+                    Box::new(ArrowDataType::Int32),
+                    Box::new(ArrowDataType::Utf8),
+                ),
+                true,
+            ),
+            Field::new("large_utf8", ArrowDataType::LargeUtf8, true),
+        ]));
 
-        let arrow_field = Field::new("leaf", dict_type, true);
+        for skip_arrow_metadata in [false, true] {
+            let mut buf = Vec::new();
+            let options = ArrowWriterOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, schema.clone(), options).unwrap();
+            writer
+                .write(&RecordBatch::new_empty(schema.clone()))
+                .unwrap();
+            writer.close().unwrap();
+            let data = Bytes::from(buf);
 
-        let mut file = tempfile::tempfile().unwrap();
+            let from_footer = arrow_schema_from_parquet(&data).unwrap();
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+            assert_eq!(&from_footer, builder.schema());
+            let reader = builder.build().unwrap();
+            assert_eq!(from_footer, reader.schema());
 
-        let values = vec![
-            vec![
-                ByteArray::from("hello"),
-                ByteArray::from("a"),
-                ByteArray::from("b"),
-                ByteArray::from("d"),
-            ],
-            vec![
-                ByteArray::from("c"),
-                ByteArray::from("a"),
-                ByteArray::from("b"),
-            ],
-        ];
+            if skip_arrow_metadata {
+                // types are inferred from the parquet logical types
+                assert_ne!(from_footer, schema);
+                assert_eq!(
+                    from_footer
+                        .field_with_name("timestamp")
+                        .unwrap()
+                        .data_type(),
+                    &ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+                );
+                assert_eq!(
+                    from_footer
+                        .field_with_name("large_utf8")
+                        .unwrap()
+                        .data_type(),
+                    &ArrowDataType::Utf8
+                );
+            } else {
+                assert_eq!(from_footer.fields(), schema.fields());
+            }
+        }
+    }
 
-        let def_levels = vec![
-            vec![1, 0, 0, 1, 0, 0, 1, 1],
-            vec![0, 0, 1, 1, 0, 0, 1, 0, 0],
-        ];
+    #[test]
+    fn test_row_filter_predicate_expr() {
+        let a = Int32Array::from_iter_values(0..20);
+        let b = Int32Array::from_iter_values((0..20).map(|v| v * 10));
+        let c = Int32Array::from_iter_values((0..20).map(|v| v % 3));
+        let s = StructArray::from(vec![
+            (
+                Arc::new(Field::new("c", ArrowDataType::Int32, false)),
+                Arc::new(c) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("d", ArrowDataType::Int32, false)),
+                Arc::new(Int32Array::from_iter_values(0..20)) as ArrayRef,
+            ),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("s", Arc::new(s) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
 
-        let opts = TestOptions {
-            encoding: Encoding::RLE_DICTIONARY,
-            ..Default::default()
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Predicate on a single leaf that asserts it only sees its own column
+        fn predicate(
+            schema_descr: &SchemaDescriptor,
+            leaf: usize,
+            f: fn(i32) -> bool,
+        ) -> PredicateExpr {
+            let expected = schema_descr.column(leaf).path().string();
+            PredicateExpr::new(Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(schema_descr, [leaf]),
+                move |batch: RecordBatch| {
+                    assert_eq!(batch.num_columns(), 1);
+                    let mut column = batch.column(0).clone();
+                    let mut path = batch.schema().field(0).name().clone();
+                    while let Some(s) = column.as_struct_opt() {
+                        assert_eq!(s.num_columns(), 1);
+                        let ArrowDataType::Struct(fields) = column.data_type() else {
+                            unreachable!()
+                        };
+                        path = format!("{path}.{}", fields[0].name());
+                        column = s.column(0).clone();
+                    }
+                    assert_eq!(path, expected);
+                    let values = column.as_primitive::<arrow_array::types::Int32Type>();
+                    Ok(BooleanArray::from_unary(values, f))
+                },
+            )))
+        }
+
+        let read = |expr: fn(&SchemaDescriptor) -> PredicateExpr| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+            let filter = RowFilter::new(vec![expr(&schema_descr).into_predicate(&schema_descr)]);
+            let reader = builder
+                .with_projection(ProjectionMask::leaves(&schema_descr, [0]))
+                .with_row_filter(filter)
+                .build()
+                .unwrap();
+            reader
+                .flat_map(|b| {
+                    b.unwrap()
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int32Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>()
         };
 
-        generate_single_column_file_with_data::<ByteArrayType>(
-            &values,
-            Some(&def_levels),
-            file.try_clone().unwrap(), // Cannot use &mut File (#1163)
-            schema,
-            Some(arrow_field),
-            &opts,
-        )
+        // (a < 3) OR (b > 160)
+        let values = read(|schema_descr| {
+            predicate(schema_descr, 0, |v| v < 3).or(predicate(schema_descr, 3, |v| v > 160))
+        });
+        assert_eq!(values, vec![0, 1, 2, 17, 18, 19]);
+
+        // (s.c = 0) AND NOT (a < 10)
+        let values = read(|schema_descr| {
+            predicate(schema_descr, 1, |v| v == 0).and(!predicate(schema_descr, 0, |v| v < 10))
+        });
+        assert_eq!(values, vec![12, 15, 18]);
+
+        // ((s.d < 2) OR (s.c = 2)) AND (b < 100)
+        let values = read(|schema_descr| {
+            predicate(schema_descr, 2, |v| v < 2)
+                .or(predicate(schema_descr, 1, |v| v == 2))
+                .and(predicate(schema_descr, 3, |v| v < 100))
+        });
+        assert_eq!(values, vec![0, 1, 2, 5, 8]);
+    }
+
+    #[test]
+    fn test_row_filter_columns_cmp() {
+        let a = Int64Array::from_iter((0..20).map(|v| (v % 7 != 0).then_some(v)));
+        let b = Int64Array::from_iter_values((0..20).map(|v| 20 - v));
+        let c = DictionaryArray::<arrow_array::types::Int32Type>::from_iter(
+            (0..20).map(|v| ["x", "y"][v % 2]),
+        );
+        let d = StringArray::from_iter_values((0..20).map(|v| ["x", "z"][v % 2]));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+            ("d", Arc::new(d) as ArrayRef),
+        ])
         .unwrap();
 
-        file.rewind().unwrap();
+        assert_eq!(
+            columns_cmp(&batch.slice(0, 4), "a", "b", CmpOp::Lt).unwrap(),
+            BooleanArray::from(vec![None, Some(true), Some(true), Some(true)])
+        );
+        assert_eq!(
+            columns_cmp(&batch.slice(0, 4), "c", "d", CmpOp::Eq).unwrap(),
+            BooleanArray::from(vec![true, false, true, false])
+        );
+        let err = columns_cmp(&batch, "a", "d", CmpOp::Eq).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare column \"a\" of type Int64 with column \"d\" of type Utf8"
+        );
+        let err = columns_cmp(&batch, "a", "e", CmpOp::Eq).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unable to get field named \"e\". Valid fields: [\"a\", \"b\", \"c\", \"d\"]"
+        );
 
-        let record_reader = ParquetRecordBatchReader::try_new(file, 3).unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |left: &'static str, right: &'static str, op: CmpOp| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+            let columns = [left, right].map(|name| builder.schema().index_of(name).unwrap());
+            let predicate = ArrowPredicateFn::new(
+                ProjectionMask::roots(&schema_descr, columns),
+                move |batch: RecordBatch| columns_cmp(&batch, left, right, op),
+            );
+            let reader = builder
+                .with_projection(ProjectionMask::roots(&schema_descr, [0]))
+                .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+                .build()
+                .unwrap();
+            reader
+                .flat_map(|b| {
+                    b.unwrap()
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int64Type>()
+                        .iter()
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
 
-        let batches = record_reader
-            .collect::<Result<Vec<RecordBatch>, _>>()
-            .unwrap();
+        // Rows with a null are not selected
+        let values = read("a", "b", CmpOp::GtEq);
+        let expected: Vec<_> = (10..20).filter(|v| v % 7 != 0).map(Some).collect();
+        assert_eq!(values, expected);
+        assert_eq!(read("b", "a", CmpOp::Gt).len(), 8);
+        assert_eq!(read("a", "b", CmpOp::NotEq).len(), 16);
+        assert_eq!(read("c", "d", CmpOp::Eq).len(), 10);
+        assert_eq!(read("c", "d", CmpOp::LtEq).len(), 20);
+    }
 
-        assert_eq!(batches.len(), 6);
-        assert!(batches.iter().all(|x| x.num_columns() == 1));
+    #[test]
+    fn test_row_filter_simple_predicate() {
+        // Columns modelled on the row filter benchmark
+        let int64 = Int64Array::from_iter_values(
+            (0..2000).map(|i| if i == 1234 { 9999 } else { (i * 37) % 100 }),
+        );
+        let float64 =
+            Float64Array::from_iter_values((0..2000).map(|i| ((i * 7919) % 1000) as f64 / 10.0));
+        let strings = (0..2000).map(|i| match (i % 5, i % 11) {
+            (_, 0) => None,
+            (0, _) => Some(String::new()),
+            _ => Some(format!("s{i}")),
+        });
+        let utf8_view = StringViewArray::from_iter(strings.clone());
+        let ts = TimestampMillisecondArray::from_iter_values((0..2000).map(|i| i * 5));
+        let binary_view = BinaryViewArray::from_iter(strings.map(|s| s.map(String::into_bytes)));
+        let batch = RecordBatch::try_from_iter([
+            ("int64", Arc::new(int64) as ArrayRef),
+            ("float64", Arc::new(float64) as ArrayRef),
+            ("utf8View", Arc::new(utf8_view) as ArrayRef),
+            ("ts", Arc::new(ts) as ArrayRef),
+            ("binaryView", Arc::new(binary_view) as ArrayRef),
+        ])
+        .unwrap();
 
-        let row_counts = batches
-            .iter()
-            .map(|x| (x.num_rows(), x.column(0).null_count()))
-            .collect::<Vec<_>>();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(500)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |predicates: Vec<SimplePredicate>| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+            let predicates = predicates
+                .into_iter()
+                .map(|p| p.into_predicate(&schema_descr).unwrap())
+                .collect();
+            let batches = builder
+                .with_row_filter(RowFilter::new(predicates))
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            concat_batches(&batch.schema(), &batches).unwrap()
+        };
+        let expected = |column: &str,
+                        op: fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
+                        literal: &dyn Datum| {
+            op(batch.column_by_name(column).unwrap(), literal).unwrap()
+        };
 
+        let cases = [
+            // int64 == 9999
+            (
+                vec![SimplePredicate::new(
+                    "int64",
+                    CmpOp::Eq,
+                    Int64Array::new_scalar(9999),
+                )],
+                expected("int64", cmp::eq, &Int64Array::new_scalar(9999)),
+            ),
+            // float64 > 99.0
+            (
+                vec![SimplePredicate::new(
+                    "float64",
+                    CmpOp::Gt,
+                    Float64Array::new_scalar(99.0),
+                )],
+                expected("float64", cmp::gt, &Float64Array::new_scalar(99.0)),
+            ),
+            // ts >= 9000, the literal is cast to a timestamp
+            (
+                vec![SimplePredicate::new(
+                    "ts",
+                    CmpOp::GtEq,
+                    Int64Array::new_scalar(9000),
+                )],
+                expected(
+                    "ts",
+                    cmp::gt_eq,
+                    &TimestampMillisecondArray::new_scalar(9000),
+                ),
+            ),
+            // int64 > 90
+            (
+                vec![SimplePredicate::new(
+                    "int64",
+                    CmpOp::Gt,
+                    Int64Array::new_scalar(90),
+                )],
+                expected("int64", cmp::gt, &Int64Array::new_scalar(90)),
+            ),
+            // float64 <= 99.0
+            (
+                vec![SimplePredicate::new(
+                    "float64",
+                    CmpOp::LtEq,
+                    Float64Array::new_scalar(99.0),
+                )],
+                expected("float64", cmp::lt_eq, &Float64Array::new_scalar(99.0)),
+            ),
+            // ts < 9000
+            (
+                vec![SimplePredicate::new(
+                    "ts",
+                    CmpOp::Lt,
+                    TimestampMillisecondArray::new_scalar(9000),
+                )],
+                expected("ts", cmp::lt, &TimestampMillisecondArray::new_scalar(9000)),
+            ),
+            // float64 > 99.0 AND ts >= 9000
+            (
+                vec![
+                    SimplePredicate::new("float64", CmpOp::Gt, Float64Array::new_scalar(99.0)),
+                    SimplePredicate::new("ts", CmpOp::GtEq, Int64Array::new_scalar(9000)),
+                ],
+                arrow::compute::and(
+                    &expected("float64", cmp::gt, &Float64Array::new_scalar(99.0)),
+                    &expected(
+                        "ts",
+                        cmp::gt_eq,
+                        &TimestampMillisecondArray::new_scalar(9000),
+                    ),
+                )
+                .unwrap(),
+            ),
+            // utf8View <> '', the literal is cast to a string view
+            (
+                vec![SimplePredicate::new(
+                    "utf8View",
+                    CmpOp::NotEq,
+                    StringArray::new_scalar(""),
+                )],
+                expected("utf8View", cmp::neq, &StringViewArray::new_scalar("")),
+            ),
+            // binaryView <> ''
+            (
+                vec![SimplePredicate::new(
+                    "binaryView",
+                    CmpOp::NotEq,
+                    BinaryViewArray::new_scalar(b""),
+                )],
+                expected("binaryView", cmp::neq, &BinaryViewArray::new_scalar(b"")),
+            ),
+        ];
+        for (predicates, mask) in cases {
+            let expected = filter_record_batch(&batch, &mask).unwrap();
+            assert!(expected.num_rows() > 0);
+            assert_eq!(read(predicates), expected);
+        }
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let Err(err) = SimplePredicate::new("int", CmpOp::Eq, Int64Array::new_scalar(1))
+            .into_predicate(&schema_descr)
+        else {
+            panic!("expected error for missing column")
+        };
         assert_eq!(
-            row_counts,
-            vec![(3, 2), (3, 2), (3, 1), (3, 1), (3, 2), (2, 2)]
+            err.to_string(),
+            "Parquet error: Column \"int\" is not a top level primitive column of the parquet schema"
         );
 
-        let get_dict = |batch: &RecordBatch| batch.column(0).to_data().child_data()[0].clone();
-
-        // First and second batch in same row group -> same dictionary
-        assert_eq!(get_dict(&batches[0]), get_dict(&batches[1]));
-        // Third batch spans row group -> computed dictionary
-        assert_ne!(get_dict(&batches[1]), get_dict(&batches[2]));
-        assert_ne!(get_dict(&batches[2]), get_dict(&batches[3]));
-        // Fourth, fifth and sixth from same row group -> same dictionary
-        assert_eq!(get_dict(&batches[3]), get_dict(&batches[4]));
-        assert_eq!(get_dict(&batches[4]), get_dict(&batches[5]));
+        // The literal must be castable to the type of the column
+        let predicate = SimplePredicate::new("int64", CmpOp::Eq, StringArray::new_scalar("a"))
+            .into_predicate(&schema_descr)
+            .unwrap();
+        let Err(err) = builder
+            .with_row_filter(RowFilter::new(vec![predicate]))
+            .build()
+        else {
+            panic!("expected error casting the literal")
+        };
+        assert!(
+            err.to_string()
+                .contains("Cannot cast string 'a' to value of Int64 type"),
+            "{err}"
+        );
     }
 
     #[test]
-    fn test_read_null_list() {
-        let testdata = arrow::util::test_util::parquet_test_data();
-        let path = format!("{testdata}/null_list.parquet");
-        let file = File::open(path).unwrap();
-        let mut record_batch_reader = ParquetRecordBatchReader::try_new(file, 60).unwrap();
+    fn test_row_filter_simple_predicate_inexact_literal() {
+        let batch = RecordBatch::try_from_iter([(
+            "int32",
+            Arc::new(Int32Array::from(vec![
+                Some(-2),
+                Some(1),
+                None,
+                Some(2),
+                Some(1),
+            ])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |predicate: SimplePredicate| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+            let predicate = predicate.into_predicate(&schema_descr).unwrap();
+            let batches = builder
+                .with_row_filter(RowFilter::new(vec![predicate]))
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let batch = concat_batches(&batch.schema(), &batches).unwrap();
+            batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .values()
+                .to_vec()
+        };
 
-        let batch = record_batch_reader.next().unwrap().unwrap();
-        assert_eq!(batch.num_rows(), 1);
-        assert_eq!(batch.num_columns(), 1);
-        assert_eq!(batch.column(0).len(), 1);
+        // A float literal with a fraction is not truncated to an integer
+        let predicate = SimplePredicate::new("int32", CmpOp::Eq, Float64Array::new_scalar(1.5));
+        assert_eq!(read(predicate), Vec::<i32>::new());
+        let predicate = SimplePredicate::new("int32", CmpOp::Lt, Float64Array::new_scalar(1.5));
+        assert_eq!(read(predicate), vec![-2, 1, 1]);
+        let predicate = SimplePredicate::new("int32", CmpOp::Gt, Float64Array::new_scalar(-1.5));
+        assert_eq!(read(predicate), vec![1, 2, 1]);
 
-        let list = batch
-            .column(0)
-            .as_any()
-            .downcast_ref::<ListArray>()
-            .unwrap();
-        assert_eq!(list.len(), 1);
-        assert!(list.is_valid(0));
+        // A float literal without a fraction is compared as an integer
+        let predicate = SimplePredicate::new("int32", CmpOp::Eq, Float64Array::new_scalar(2.0));
+        assert_eq!(read(predicate), vec![2]);
 
-        let val = list.value(0);
-        assert_eq!(val.len(), 0);
+        // An integer literal outside the range of the column does not fail
+        let literal = Int64Array::new_scalar(i32::MAX as i64 + 1);
+        let predicate = SimplePredicate::new("int32", CmpOp::Lt, literal);
+        assert_eq!(read(predicate), vec![-2, 1, 2, 1]);
+        let literal = Int64Array::new_scalar(i32::MIN as i64 - 1);
+        let predicate = SimplePredicate::new("int32", CmpOp::LtEq, literal);
+        assert_eq!(read(predicate), Vec::<i32>::new());
     }
 
     #[test]
-    fn test_null_schema_inference() {
-        let testdata = arrow::util::test_util::parquet_test_data();
-        let path = format!("{testdata}/null_list.parquet");
-        let file = File::open(path).unwrap();
+    fn test_row_filter_required_projection() {
+        let message_type = "
+        message test_schema {
+          REQUIRED INT32 a;
+          OPTIONAL group b {
+            REQUIRED INT32 c;
+            REQUIRED INT32 d;
+          }
+          REQUIRED INT64 e;
+          REQUIRED BOOLEAN f;
+        }
+        ";
+        let schema = parse_message_type(message_type).unwrap();
+        let schema_descr = SchemaDescriptor::new(Arc::new(schema));
 
-        let arrow_field = Field::new(
-            "emptylist",
-            ArrowDataType::List(Arc::new(Field::new_list_field(ArrowDataType::Null, true))),
-            true,
+        let predicate = |mask: ProjectionMask| {
+            Box::new(ArrowPredicateFn::new(mask, |batch: RecordBatch| {
+                Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+            })) as Box<dyn ArrowPredicate>
+        };
+
+        let filter = RowFilter::new(vec![
+            predicate(ProjectionMask::leaves(&schema_descr, [0])),
+            predicate(ProjectionMask::roots(&schema_descr, [1])),
+            predicate(ProjectionMask::columns(&schema_descr, ["b.c", "f"])),
+        ]);
+        let projection = filter.required_projection(&schema_descr);
+        assert_eq!(
+            projection,
+            ProjectionMask::leaves(&schema_descr, [0, 1, 2, 4])
+        );
+        assert!(!projection.leaf_included(3));
+
+        let filter = RowFilter::new(vec![]);
+        assert_eq!(
+            filter.required_projection(&schema_descr),
+            ProjectionMask::none(5)
+        );
+
+        let filter = RowFilter::new(vec![
+            predicate(ProjectionMask::leaves(&schema_descr, [0])),
+            predicate(ProjectionMask::all()),
+        ]);
+        assert_eq!(
+            filter.required_projection(&schema_descr),
+            ProjectionMask::all()
+        );
+    }
+
+    /// A [`ChunkReader`] that records the number of bytes read
+    struct CountingReader {
+        data: Bytes,
+        bytes_read: Arc<AtomicUsize>,
+    }
+
+    impl Length for CountingReader {
+        fn len(&self) -> u64 {
+            self.data.len() as u64
+        }
+    }
+
+    impl ChunkReader for CountingReader {
+        type T = <Bytes as ChunkReader>::T;
+
+        fn get_read(&self, start: u64) -> Result<Self::T> {
+            let read = self.data.get_read(start)?;
+            self.bytes_read
+                .fetch_add(self.data.len() - start as usize, Ordering::Relaxed);
+            Ok(read)
+        }
+
+        fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+            self.bytes_read.fetch_add(length, Ordering::Relaxed);
+            self.data.get_bytes(start, length)
+        }
+    }
+
+    #[test]
+    fn test_try_new_with_metadata() {
+        let a = Int32Array::from_iter_values(0..1000);
+        let b = StringArray::from_iter_values((0..1000).map(|i| format!("value{i}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |metadata: Option<ArrowReaderMetadata>| {
+            let bytes_read = Arc::new(AtomicUsize::new(0));
+            let input = CountingReader {
+                data: data.clone(),
+                bytes_read: Arc::clone(&bytes_read),
+            };
+            let builder = match metadata {
+                Some(metadata) => {
+                    ParquetRecordBatchReaderBuilder::new_with_metadata(input, metadata)
+                }
+                None => ParquetRecordBatchReaderBuilder::try_new(input).unwrap(),
+            };
+            let batches = builder
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            (batches, bytes_read.load(Ordering::Relaxed))
+        };
+
+        let (expected, expected_bytes_read) = read(None);
+        assert_eq!(expected, vec![batch]);
+
+        // Reusing the metadata skips reading the footer
+        let metadata = ArrowReaderMetadata::load(&data, Default::default()).unwrap();
+        for _ in 0..2 {
+            let (actual, bytes_read) = read(Some(metadata.clone()));
+            assert_eq!(actual, expected);
+            assert!(
+                bytes_read < expected_bytes_read,
+                "{bytes_read} >= {expected_bytes_read}"
+            );
+        }
+
+        // The metadata is consistent with the file it was loaded from
+        let actual =
+            ParquetRecordBatchReaderBuilder::try_new_with_metadata(data.clone(), metadata.clone())
+                .unwrap()
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        assert_eq!(actual, expected);
+
+        // Metadata from a different, smaller, file is rejected
+        let err = ParquetRecordBatchReaderBuilder::try_new_with_metadata(
+            data.slice(..data.len() / 2),
+            metadata.clone(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("was the metadata loaded from a different file?"),
+            "{err}"
+        );
+
+        // Metadata from a larger file with a different schema is rejected
+        let c = Int64Array::from_iter_values(0..10_000);
+        let other = RecordBatch::try_from_iter([("c", Arc::new(c) as ArrayRef)]).unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, other.schema(), None).unwrap();
+        writer.write(&other).unwrap();
+        writer.close().unwrap();
+        assert!(buf.len() > data.len());
+        let err =
+            ParquetRecordBatchReaderBuilder::try_new_with_metadata(Bytes::from(buf), metadata)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: The parquet schema of the metadata does not match the schema \
+             in the footer of the input, was the metadata loaded from a different file?"
+        );
+    }
+
+    #[test]
+    fn test_row_group_aligned_batches() {
+        let a = Int32Array::from_iter_values(0..400);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        // Write row groups of 100, 250 and 50 rows
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        for (offset, len) in [(0, 100), (100, 250), (350, 50)] {
+            writer.write(&batch.slice(offset, len)).unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |f: &dyn Fn(
+            ParquetRecordBatchReaderBuilder<Bytes>,
+        ) -> ParquetRecordBatchReaderBuilder<Bytes>| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .with_batch_size(7)
+                .with_row_group_aligned_batches(true);
+            let batches = f(builder)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let values: Vec<Vec<i32>> = batches
+                .iter()
+                .map(|b| {
+                    let a = b.column(0).as_primitive::<arrow_array::types::Int32Type>();
+                    a.values().to_vec()
+                })
+                .collect();
+            values
+        };
+        let lens = |values: Vec<Vec<i32>>| values.iter().map(|v| v.len()).collect::<Vec<_>>();
+
+        // One batch per row group
+        let values = read(&|b| b);
+        assert_eq!(lens(values.clone()), vec![100, 250, 50]);
+        assert_eq!(values.concat(), (0..400).collect::<Vec<_>>());
+
+        let values = read(&|b| b.with_row_groups(vec![2, 0]));
+        assert_eq!(lens(values), vec![50, 100]);
+
+        // Selections spanning row groups are split at the row group boundaries,
+        // and row groups with no selected rows produce no batch
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(50),
+            RowSelector::select(100),
+            RowSelector::skip(230),
+            RowSelector::select(20),
+        ]);
+        let values = read(&|b| b.with_row_selection(selection.clone()));
+        assert_eq!(
+            values,
+            vec![
+                (50..100).collect::<Vec<_>>(),
+                (100..150).collect(),
+                (380..400).collect()
+            ]
+        );
+
+        let selection = RowSelection::from(vec![RowSelector::skip(100), RowSelector::select(300)]);
+        let values = read(&|b| b.with_row_selection(selection.clone()).with_offset(240));
+        assert_eq!(
+            values,
+            vec![(340..350).collect::<Vec<_>>(), (350..400).collect()]
+        );
+
+        let values = read(&|b| b.with_offset(90).with_limit(20));
+        assert_eq!(
+            values,
+            vec![(90..100).collect::<Vec<_>>(), (100..110).collect()]
+        );
+
+        let values = read(&|b| {
+            let schema_descr = b.metadata().file_metadata().schema_descr_ptr();
+            let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                |batch: RecordBatch| {
+                    let a = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int32Type>();
+                    Ok(BooleanArray::from_unary(a, |v| v % 10 == 0))
+                },
+            ))]);
+            b.with_row_filter(filter)
+        });
+        assert_eq!(lens(values.clone()), vec![10, 25, 5]);
+        assert_eq!(values.concat(), (0..40).map(|v| v * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_lazy_page_index() {
+        let a = Int32Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |options: ArrowReaderOptions, metrics: ArrowReaderMetrics| {
+            let builder =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap();
+            let metadata = Arc::clone(builder.metadata());
+            let batches = builder
+                .with_row_groups(vec![3])
+                .with_row_selection(RowSelection::from(vec![
+                    RowSelector::skip(5),
+                    RowSelector::select(5),
+                ]))
+                .with_metrics(metrics)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            (metadata, batches)
+        };
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let (eager_metadata, expected) = read(options.clone(), ArrowReaderMetrics::enabled());
+        let offset_index = eager_metadata.offset_index().unwrap();
+        assert_eq!(offset_index.len(), 10);
+        assert_eq!(offset_index[3][0].page_locations.len(), 2);
+
+        let metrics = ArrowReaderMetrics::enabled();
+        let (lazy_metadata, batches) = read(options.with_lazy_page_index(true), metrics.clone());
+        assert!(lazy_metadata.offset_index().is_none());
+        assert!(lazy_metadata.column_index().is_none());
+        assert_eq!(batches, expected);
+        assert_eq!(
+            batches[0].column(0).as_ref(),
+            &Int32Array::from_iter_values(35..40)
+        );
+
+        // Only the offset index of the row group that was read is fetched
+        let row_group_range = row_group_offset_index_range(&eager_metadata, 3)
+            .unwrap()
+            .unwrap();
+        let all_range = (0..10)
+            .filter_map(|i| row_group_offset_index_range(&eager_metadata, i).unwrap())
+            .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+            .unwrap();
+        let bytes_read = metrics.page_index_bytes_read().unwrap() as u64;
+        assert_eq!(bytes_read, row_group_range.end - row_group_range.start);
+        assert!(bytes_read * 5 < all_range.end - all_range.start);
+    }
+
+    /// An [`Executor`] that spawns a new thread for each task
+    #[derive(Debug)]
+    struct ThreadExecutor;
+
+    impl Executor for ThreadExecutor {
+        fn spawn(&self, task: Box<dyn FnOnce() + Send>) {
+            std::thread::spawn(task);
+        }
+    }
+
+    /// An [`Executor`] that runs each task on the calling thread
+    #[derive(Debug)]
+    struct InlineExecutor;
+
+    impl Executor for InlineExecutor {
+        fn spawn(&self, task: Box<dyn FnOnce() + Send>) {
+            task()
+        }
+    }
+
+    /// Writes a ZSTD compressed file with several columns, row groups and pages
+    #[cfg(feature = "zstd")]
+    fn write_multi_column_file() -> Bytes {
+        let a = Int64Array::from_iter_values(0..4000);
+        let b = StringArray::from_iter((0..4000).map(|v| (v % 3 != 0).then(|| format!("v{v}"))));
+        let mut c = ListBuilder::new(Int32Builder::new());
+        for v in 0..4000 {
+            c.values().append_slice(&vec![v; (v % 4) as usize]);
+            c.append(v % 5 != 0);
+        }
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let props = WriterProperties::builder()
+            .set_compression(crate::basic::Compression::ZSTD(Default::default()))
+            .set_max_row_group_size(1000)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_decompress_pool() {
+        let data = write_multi_column_file();
+
+        let read = |pool: Option<Arc<dyn Executor>>,
+                    f: &dyn Fn(
+            ParquetRecordBatchReaderBuilder<Bytes>,
+        ) -> ParquetRecordBatchReaderBuilder<Bytes>| {
+            let mut options = ArrowReaderOptions::new().with_page_index(true);
+            if let Some(pool) = pool {
+                options = options.with_decompress_pool(pool);
+            }
+            let builder =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap();
+            f(builder.with_batch_size(300))
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+
+        let check = |f: &dyn Fn(
+            ParquetRecordBatchReaderBuilder<Bytes>,
+        ) -> ParquetRecordBatchReaderBuilder<Bytes>| {
+            let expected = read(None, f);
+            let batches = read(Some(Arc::new(ThreadExecutor)), f);
+            assert_eq!(batches, expected);
+            // Tasks return once pages are buffered ahead, rather than blocking
+            let batches = read(Some(Arc::new(InlineExecutor)), f);
+            assert_eq!(batches, expected);
+            expected.iter().map(|b| b.num_rows()).sum::<usize>()
+        };
+
+        assert_eq!(check(&|b| b), 4000);
+        assert_eq!(check(&|b| b.with_row_groups(vec![3, 1])), 2000);
+
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(150),
+            RowSelector::select(1000),
+            RowSelector::skip(2000),
+            RowSelector::select(30),
+        ]);
+        assert_eq!(check(&|b| b.with_row_selection(selection.clone())), 1030);
+
+        let filtered = check(&|b| {
+            let schema_descr = b.metadata().file_metadata().schema_descr_ptr();
+            let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                |batch: RecordBatch| {
+                    let a = batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::Int64Type>();
+                    Ok(BooleanArray::from_unary(a, |v| v % 7 == 0))
+                },
+            ))]);
+            b.with_row_filter(filter)
+        });
+        assert_eq!(filtered, 572);
+    }
+
+    #[test]
+    fn test_decompress_pool_task_dropped() {
+        /// An [`Executor`] that never runs its tasks
+        #[derive(Debug)]
+        struct DropExecutor;
+
+        impl Executor for DropExecutor {
+            fn spawn(&self, _task: Box<dyn FnOnce() + Send>) {}
+        }
+
+        let a = Int32Array::from_iter_values(0..10);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_decompress_pool(Arc::new(DropExecutor));
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Column chunk decompression task ended without completing"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_decimal_validation() {
+        // The writer does not validate precision, allowing an out of range
+        // value to be written as INT32 (precision 5) and FIXED_LEN_BYTE_ARRAY
+        // (precision 20)
+        let make_batch = |overflow: bool| {
+            let small = match overflow {
+                true => 100000,
+                false => 99999,
+            };
+            let int32 = Decimal128Array::from(vec![Some(1), None, Some(small)])
+                .with_precision_and_scale(5, 2)
+                .unwrap();
+            let flba = Decimal128Array::from(vec![Some(1), Some(2), None])
+                .with_precision_and_scale(20, 0)
+                .unwrap();
+            RecordBatch::try_from_iter([
+                ("int32", Arc::new(int32) as ArrayRef),
+                ("flba", Arc::new(flba) as ArrayRef),
+            ])
+            .unwrap()
+        };
+
+        let read = |batch: &RecordBatch, decimal_validation: bool| {
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+            writer.write(batch).unwrap();
+            writer.close().unwrap();
+
+            let options = ArrowReaderOptions::new().with_decimal_validation(decimal_validation);
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        // Valid data is read with and without validation
+        let valid = make_batch(false);
+        assert_eq!(read(&valid, false).unwrap(), vec![valid.clone()]);
+        assert_eq!(read(&valid, true).unwrap(), vec![valid]);
+
+        // Invalid data is only detected with validation
+        let invalid = make_batch(true);
+        assert_eq!(read(&invalid, false).unwrap(), vec![invalid.clone()]);
+        let err = read(&invalid, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet argument error: Error decoding column \"int32\": \
+             External: Invalid argument error: 1000.00 is too large to store in a Decimal128 of precision 5. Max is 999.99"
+        );
+    }
+
+    #[test]
+    fn test_reader_options_with_metadata() {
+        let decimal = Decimal128Array::from(vec![1, 100000])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(decimal) as ArrayRef)]).unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new()
+            .with_schema(batch.schema())
+            .with_decimal_validation(true);
+        let read = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            builder
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.to_string())
+        };
+
+        // The options are applied when creating the builder from options
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+                .unwrap();
+        assert!(read(builder).is_err());
+
+        // But are not part of the metadata, and so must be applied to the builder
+        let metadata = ArrowReaderMetadata::load(&data, options.clone()).unwrap();
+        let builder =
+            ParquetRecordBatchReaderBuilder::new_with_metadata(data.clone(), metadata.clone());
+        assert_eq!(read(builder).unwrap(), vec![batch]);
+        let builder = ParquetRecordBatchReaderBuilder::new_with_metadata(data, metadata);
+        let err = read(builder.with_reader_options(&options)).unwrap_err();
+        assert!(err.contains("is too large to store"), "{err}");
+    }
+
+    #[test]
+    fn test_unknown_logical_type_policy() {
+        let values = BinaryArray::from(vec![b"a".as_ref(), b"bc".as_ref(), b"".as_ref()]);
+        let batch = RecordBatch::try_from_iter([("b", Arc::new(values) as ArrayRef)]).unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // The writer can not produce an unknown logical type, so simulate a file
+        // from a newer writer by annotating the column in the decoded metadata
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let leaf = Type::primitive_type_builder("b", PhysicalType::BYTE_ARRAY)
+            .with_repetition(Repetition::REQUIRED)
+            .with_logical_type(Some(LogicalType::_Unknown { field_id: 99 }))
+            .build()
+            .unwrap();
+        let root = Type::group_type_builder("schema")
+            .with_fields(vec![Arc::new(leaf)])
+            .build()
+            .unwrap();
+        let file_metadata = metadata.file_metadata();
+        let file_metadata = FileMetaData::new(
+            file_metadata.version(),
+            file_metadata.num_rows(),
+            None,
+            None,
+            Arc::new(SchemaDescriptor::new(Arc::new(root))),
+            file_metadata.column_orders().cloned(),
+        );
+        let metadata = Arc::new(ParquetMetaData::new(
+            file_metadata,
+            metadata.row_groups().to_vec(),
+        ));
+
+        // Unknown logical types are an error by default
+        let err = ArrowReaderMetadata::try_new(Arc::clone(&metadata), ArrowReaderOptions::new())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Unable to convert parquet BYTE_ARRAY column \"b\" with unknown logical type _Unknown { field_id: 99 }"
+        );
+
+        // But can be read as their physical type
+        let options = ArrowReaderOptions::new()
+            .with_unknown_logical_type_policy(UnknownLogicalTypePolicy::PhysicalType);
+        let arrow_metadata = ArrowReaderMetadata::try_new(metadata, options).unwrap();
+        assert_eq!(
+            arrow_metadata.schema().field(0).data_type(),
+            &ArrowDataType::Binary
+        );
+        let batches = ParquetRecordBatchReaderBuilder::new_with_metadata(data, arrow_metadata)
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    fn test_estimated_decoded_size() {
+        let ints = Int64Array::from_iter_values(0..10_000);
+        let strings = StringArray::from_iter_values((0..10_000).map(|i| format!("value{i:05}")));
+        let batch = RecordBatch::try_from_iter([
+            ("ints", Arc::new(ints) as ArrayRef),
+            ("strings", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+
+        // PLAIN encoded data has a size close to that of the decoded arrays
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_max_row_group_size(2_500)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 4);
+        let estimate = builder.estimated_decoded_size();
+        let actual: usize = builder
+            .build()
+            .unwrap()
+            .map(|batch| {
+                let batch = batch.unwrap();
+                batch
+                    .columns()
+                    .iter()
+                    .map(|c| c.to_data().get_slice_memory_size().unwrap())
+                    .sum::<usize>()
+            })
+            .sum();
+        assert!(
+            estimate.abs_diff(actual) < actual / 10,
+            "estimate {estimate} actual {actual}"
+        );
+
+        // Only projected columns in the selected row groups are included
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let metadata = Arc::clone(builder.metadata());
+        let builder = builder.with_projection(mask).with_row_groups(vec![1, 3]);
+        let expected = [1, 3]
+            .iter()
+            .map(|rg| metadata.row_group(*rg).column(0).uncompressed_size() as usize)
+            .sum::<usize>();
+        assert_eq!(builder.estimated_decoded_size(), expected);
+        assert!(expected.abs_diff(8 * 5_000) < 8 * 5_000 / 10, "{expected}");
+    }
+
+    #[test]
+    fn test_read_maps() {
+        let testdata = arrow::util::test_util::parquet_test_data();
+        let path = format!("{testdata}/nested_maps.snappy.parquet");
+        let file = File::open(path).unwrap();
+        let record_batch_reader = ParquetRecordBatchReader::try_new(file, 60).unwrap();
+
+        for batch in record_batch_reader {
+            batch.unwrap();
+        }
+    }
+
+    #[test]
+    fn test_nested_nullability() {
+        let message_type = "message nested {
+          OPTIONAL Group group {
+            REQUIRED INT32 leaf;
+          }
+        }";
+
+        let file = tempfile::tempfile().unwrap();
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+
+        {
+            // Write using low-level parquet API (#1167)
+            let mut writer =
+                SerializedFileWriter::new(file.try_clone().unwrap(), schema, Default::default())
+                    .unwrap();
+
+            {
+                let mut row_group_writer = writer.next_row_group().unwrap();
+                let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+
+                column_writer
+                    .typed::<Int32Type>()
+                    .write_batch(&[34, 76], Some(&[0, 1, 0, 1]), None)
+                    .unwrap();
+
+                column_writer.close().unwrap();
+                row_group_writer.close().unwrap();
+            }
+
+            writer.close().unwrap();
+        }
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+
+        let reader = builder.with_projection(mask).build().unwrap();
+
+        let expected_schema = Schema::new(vec![Field::new(
+            "group",
+            ArrowDataType::Struct(vec![Field::new("leaf", ArrowDataType::Int32, false)].into()),
+            true,
+        )]);
+
+        let batch = reader.into_iter().next().unwrap().unwrap();
+        assert_eq!(batch.schema().as_ref(), &expected_schema);
+        assert_eq!(batch.num_rows(), 4);
+        assert_eq!(batch.column(0).null_count(), 2);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        // a parquet file with 1 column with invalid utf8
+        let data = vec![
+            80, 65, 82, 49, 21, 6, 21, 22, 21, 22, 92, 21, 2, 21, 0, 21, 2, 21, 0, 21, 4, 21, 0,
+            18, 28, 54, 0, 40, 5, 104, 101, 255, 108, 111, 24, 5, 104, 101, 255, 108, 111, 0, 0, 0,
+            3, 1, 5, 0, 0, 0, 104, 101, 255, 108, 111, 38, 110, 28, 21, 12, 25, 37, 6, 0, 25, 24,
+            2, 99, 49, 21, 0, 22, 2, 22, 102, 22, 102, 38, 8, 60, 54, 0, 40, 5, 104, 101, 255, 108,
+            111, 24, 5, 104, 101, 255, 108, 111, 0, 0, 0, 21, 4, 25, 44, 72, 4, 114, 111, 111, 116,
+            21, 2, 0, 21, 12, 37, 2, 24, 2, 99, 49, 37, 0, 76, 28, 0, 0, 0, 22, 2, 25, 28, 25, 28,
+            38, 110, 28, 21, 12, 25, 37, 6, 0, 25, 24, 2, 99, 49, 21, 0, 22, 2, 22, 102, 22, 102,
+            38, 8, 60, 54, 0, 40, 5, 104, 101, 255, 108, 111, 24, 5, 104, 101, 255, 108, 111, 0, 0,
+            0, 22, 102, 22, 2, 0, 40, 44, 65, 114, 114, 111, 119, 50, 32, 45, 32, 78, 97, 116, 105,
+            118, 101, 32, 82, 117, 115, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97,
+            116, 105, 111, 110, 32, 111, 102, 32, 65, 114, 114, 111, 119, 0, 130, 0, 0, 0, 80, 65,
+            82, 49,
+        ];
+
+        let file = Bytes::from(data);
+        let mut record_batch_reader = ParquetRecordBatchReader::try_new(file, 10).unwrap();
+
+        let error = record_batch_reader.next().unwrap().unwrap_err();
+
+        assert!(
+            error.to_string().contains("invalid utf-8 sequence"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8_string_array() {
+        test_invalid_utf8_string_array_inner::<i32>();
+    }
+
+    #[test]
+    fn test_invalid_utf8_large_string_array() {
+        test_invalid_utf8_string_array_inner::<i64>();
+    }
+
+    fn test_invalid_utf8_string_array_inner<O: OffsetSizeTrait>() {
+        let cases = [
+            invalid_utf8_first_char::<O>(),
+            invalid_utf8_first_char_long_strings::<O>(),
+            invalid_utf8_later_char::<O>(),
+            invalid_utf8_later_char_long_strings::<O>(),
+            invalid_utf8_later_char_really_long_strings::<O>(),
+            invalid_utf8_later_char_really_long_strings2::<O>(),
+        ];
+        for array in &cases {
+            for encoding in STRING_ENCODINGS {
+                // data is not valid utf8 we can not construct a correct StringArray
+                // safely, so purposely create an invalid StringArray
+                let array = unsafe {
+                    GenericStringArray::<O>::new_unchecked(
+                        array.offsets().clone(),
+                        array.values().clone(),
+                        array.nulls().cloned(),
+                    )
+                };
+                let data_type = array.data_type().clone();
+                let data = write_to_parquet_with_encoding(Arc::new(array), *encoding);
+                let err = read_from_parquet(data).unwrap_err();
+                let expected_err = "Parquet error: encountered non UTF-8 data";
+                assert!(
+                    err.to_string().contains(expected_err),
+                    "data type: {data_type}, expected: {expected_err}, got: {err}"
+                );
+                let expected_location = "Error decoding column \"c\" in row group 0 at page";
+                assert!(
+                    err.to_string().contains(expected_location),
+                    "data type: {data_type}, expected: {expected_location}, got: {err}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_corrupt_page_decode_error() {
+        use crate::column::reader::get_typed_column_reader;
+        use crate::data_type::Int32Type;
+        use crate::errors::ParquetError;
+        use crate::file::reader::{FileReader, SerializedFileReader};
+
+        let a = Int32Array::from_iter_values(0..100);
+        let b = Int32Array::from_iter_values(100..200);
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_max_row_group_size(50)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        // Overwrite the header of the first page of column "b" in row group 1
+        let offset = metadata.row_group(1).column(1).data_page_offset() as usize;
+        buf[offset..offset + 8].fill(0xFF);
+        let data = Bytes::from(buf);
+
+        let reader = SerializedFileReader::new(data.clone()).unwrap();
+        let column = reader
+            .get_row_group(1)
+            .unwrap()
+            .get_column_reader(1)
+            .unwrap();
+        let mut column = get_typed_column_reader::<Int32Type>(column);
+        let mut values = Vec::new();
+        let err = column
+            .read_records(50, None, None, &mut values)
+            .unwrap_err();
+        let ParquetError::Decode(e) = &err else {
+            panic!("expected decode error, got {err}")
+        };
+        assert_eq!(e.column(), &ColumnPath::from("b"));
+        assert_eq!(e.row_group(), Some(1));
+        assert_eq!(e.page(), Some(0));
+        assert!(
+            err.to_string()
+                .starts_with("Error decoding column \"b\" in row group 1 at page 0: "),
+            "{err}"
+        );
+
+        // The context is preserved through the arrow reader
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_batch_size(50)
+            .build()
+            .unwrap();
+        reader.next().unwrap().unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Error decoding column \"b\" in row group 1 at page 0: "),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_lazy_column_metadata() {
+        let columns = (0..20).map(|i| {
+            let values = Int32Array::from_iter_values((0..100).map(|v| v * 20 + i));
+            (format!("c{i}"), Arc::new(values) as ArrayRef)
+        });
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(50)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_lazy_column_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let metadata = Arc::clone(builder.metadata());
+        let schema_descr = metadata.file_metadata().schema_descr_ptr();
+        for row_group in metadata.row_groups() {
+            assert_eq!(row_group.num_decoded_columns(), 0);
+        }
+
+        // keep only the even rows
+        let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+            ProjectionMask::leaves(&schema_descr, [7]),
+            |batch: RecordBatch| {
+                let c7 = batch
+                    .column(0)
+                    .as_primitive::<arrow_array::types::Int32Type>();
+                Ok(BooleanArray::from_unary(c7, |v| (v / 20) % 2 == 0))
+            },
+        ))]);
+        let reader = builder
+            .with_projection(ProjectionMask::leaves(&schema_descr, [3, 12]))
+            .with_row_filter(filter)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(batch.num_rows(), 50);
+        assert_eq!(batch.schema().field(0).name(), "c3");
+        assert_eq!(batch.schema().field(1).name(), "c12");
+        let c12 = batch
+            .column(1)
+            .as_primitive::<arrow_array::types::Int32Type>();
+        assert_eq!(c12.value(1), 52);
+
+        // only the projected and predicate columns were decoded
+        for row_group in metadata.row_groups() {
+            assert_eq!(row_group.num_decoded_columns(), 3);
+        }
+    }
+
+    #[test]
+    fn test_invalid_utf8_string_view_array() {
+        let cases = [
+            invalid_utf8_first_char::<i32>(),
+            invalid_utf8_first_char_long_strings::<i32>(),
+            invalid_utf8_later_char::<i32>(),
+            invalid_utf8_later_char_long_strings::<i32>(),
+            invalid_utf8_later_char_really_long_strings::<i32>(),
+            invalid_utf8_later_char_really_long_strings2::<i32>(),
+        ];
+
+        for encoding in STRING_ENCODINGS {
+            for array in &cases {
+                let array = arrow_cast::cast(&array, &ArrowDataType::BinaryView).unwrap();
+                let array = array.as_binary_view();
+
+                // data is not valid utf8 we can not construct a correct StringArray
+                // safely, so purposely create an invalid StringViewArray
+                let array = unsafe {
+                    StringViewArray::new_unchecked(
+                        array.views().clone(),
+                        array.data_buffers().to_vec(),
+                        array.nulls().cloned(),
+                    )
+                };
+
+                let data_type = array.data_type().clone();
+                let data = write_to_parquet_with_encoding(Arc::new(array), *encoding);
+                let err = read_from_parquet(data).unwrap_err();
+                let expected_err = "Parquet error: encountered non UTF-8 data";
+                assert!(
+                    err.to_string().contains(expected_err),
+                    "data type: {data_type}, expected: {expected_err}, got: {err}"
+                );
+                let expected_location = "Error decoding column \"c\" in row group 0 at page";
+                assert!(
+                    err.to_string().contains(expected_location),
+                    "data type: {data_type}, expected: {expected_location}, got: {err}"
+                );
+            }
+        }
+    }
+
+    /// Encodings suitable for string data
+    const STRING_ENCODINGS: &[Option<Encoding>] = &[
+        None,
+        Some(Encoding::PLAIN),
+        Some(Encoding::DELTA_LENGTH_BYTE_ARRAY),
+        Some(Encoding::DELTA_BYTE_ARRAY),
+    ];
+
+    /// Invalid Utf-8 sequence in the first character
+    /// <https://stackoverflow.com/questions/1301402/example-invalid-utf8-string>
+    const INVALID_UTF8_FIRST_CHAR: &[u8] = &[0xa0, 0xa1, 0x20, 0x20];
+
+    /// Invalid Utf=8 sequence in NOT the first character
+    /// <https://stackoverflow.com/questions/1301402/example-invalid-utf8-string>
+    const INVALID_UTF8_LATER_CHAR: &[u8] = &[0x20, 0x20, 0x20, 0xa0, 0xa1, 0x20, 0x20];
+
+    /// returns a BinaryArray with invalid UTF8 data in the first character
+    fn invalid_utf8_first_char<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let invalid = INVALID_UTF8_FIRST_CHAR;
+        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(invalid)])
+    }
+
+    /// Returns a BinaryArray with invalid UTF8 data in the first character of a
+    /// string larger than 12 bytes which is handled specially when reading
+    /// `ByteViewArray`s
+    fn invalid_utf8_first_char_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let mut invalid = vec![];
+        invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
+        invalid.extend_from_slice(INVALID_UTF8_FIRST_CHAR);
+        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
+    }
+
+    /// returns a BinaryArray with invalid UTF8 data in a character other than
+    /// the first (this is checked in a special codepath)
+    fn invalid_utf8_later_char<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let invalid: &[u8] = INVALID_UTF8_LATER_CHAR;
+        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(invalid)])
+    }
+
+    /// returns a BinaryArray with invalid UTF8 data in a character other than
+    /// the first in a string larger than 12 bytes which is handled specially
+    /// when reading `ByteViewArray`s (this is checked in a special codepath)
+    fn invalid_utf8_later_char_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let mut invalid = vec![];
+        invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
+        invalid.extend_from_slice(INVALID_UTF8_LATER_CHAR);
+        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
+    }
+
+    /// returns a BinaryArray with invalid UTF8 data in a character other than
+    /// the first in a string larger than 128 bytes which is handled specially
+    /// when reading `ByteViewArray`s (this is checked in a special codepath)
+    fn invalid_utf8_later_char_really_long_strings<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let mut invalid = vec![];
+        for _ in 0..10 {
+            // each instance is 38 bytes
+            invalid.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
+        }
+        invalid.extend_from_slice(INVALID_UTF8_LATER_CHAR);
+        GenericBinaryArray::<O>::from_iter(vec![None, Some(valid), None, Some(&invalid)])
+    }
+
+    /// returns a BinaryArray with small invalid UTF8 data followed by a large
+    /// invalid UTF8 data in a character other than the first in a string larger
+    fn invalid_utf8_later_char_really_long_strings2<O: OffsetSizeTrait>() -> GenericBinaryArray<O> {
+        let valid: &[u8] = b"   ";
+        let mut valid_long = vec![];
+        for _ in 0..10 {
+            // each instance is 38 bytes
+            valid_long.extend_from_slice(b"ThisStringIsCertainlyLongerThan12Bytes");
+        }
+        let invalid = INVALID_UTF8_LATER_CHAR;
+        GenericBinaryArray::<O>::from_iter(vec![
+            None,
+            Some(valid),
+            Some(invalid),
+            None,
+            Some(&valid_long),
+            Some(valid),
+        ])
+    }
+
+    /// writes the array into a single column parquet file with the specified
+    /// encoding.
+    ///
+    /// If no encoding is specified, use default (dictionary) encoding
+    fn write_to_parquet_with_encoding(array: ArrayRef, encoding: Option<Encoding>) -> Vec<u8> {
+        let batch = RecordBatch::try_from_iter(vec![("c", array)]).unwrap();
+        let mut data = vec![];
+        let schema = batch.schema();
+        let props = encoding.map(|encoding| {
+            WriterProperties::builder()
+                // must disable dictionary encoding to actually use encoding
+                .set_dictionary_enabled(false)
+                .set_encoding(encoding)
+                .build()
+        });
+
+        {
+            let mut writer = ArrowWriter::try_new(&mut data, schema, props).unwrap();
+            writer.write(&batch).unwrap();
+            writer.flush().unwrap();
+            writer.close().unwrap();
+        };
+        data
+    }
+
+    /// read the parquet file into a record batch
+    fn read_from_parquet(data: Vec<u8>) -> Result<Vec<RecordBatch>, ArrowError> {
+        let reader = ArrowReaderBuilder::try_new(bytes::Bytes::from(data))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        reader.collect()
+    }
+
+    #[test]
+    fn test_dictionary_preservation() {
+        let fields = vec![Arc::new(
+            Type::primitive_type_builder("leaf", PhysicalType::BYTE_ARRAY)
+                .with_repetition(Repetition::OPTIONAL)
+                .with_converted_type(ConvertedType::UTF8)
+                .build()
+                .unwrap(),
+        )];
+
+        let schema = Arc::new(
+            Type::group_type_builder("test_schema")
+                .with_fields(fields)
+                .build()
+                .unwrap(),
+        );
+
+        let dict_type = ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::Int32),
+            Box::new(ArrowDataType::Utf8),
+        );
+
+        let arrow_field = Field::new("leaf", dict_type, true);
+
+        let mut file = tempfile::tempfile().unwrap();
+
+        let values = vec![
+            vec![
+                ByteArray::from("hello"),
+                ByteArray::from("a"),
+                ByteArray::from("b"),
+                ByteArray::from("d"),
+            ],
+            vec![
+                ByteArray::from("c"),
+                ByteArray::from("a"),
+                ByteArray::from("b"),
+            ],
+        ];
+
+        let def_levels = vec![
+            vec![1, 0, 0, 1, 0, 0, 1, 1],
+            vec![0, 0, 1, 1, 0, 0, 1, 0, 0],
+        ];
+
+        let opts = TestOptions {
+            encoding: Encoding::RLE_DICTIONARY,
+            ..Default::default()
+        };
+
+        generate_single_column_file_with_data::<ByteArrayType>(
+            &values,
+            Some(&def_levels),
+            file.try_clone().unwrap(), // Cannot use &mut File (#1163)
+            schema,
+            Some(arrow_field),
+            &opts,
+        )
+        .unwrap();
+
+        file.rewind().unwrap();
+
+        let record_reader = ParquetRecordBatchReader::try_new(file, 3).unwrap();
+
+        let batches = record_reader
+            .collect::<Result<Vec<RecordBatch>, _>>()
+            .unwrap();
+
+        assert_eq!(batches.len(), 6);
+        assert!(batches.iter().all(|x| x.num_columns() == 1));
+
+        let row_counts = batches
+            .iter()
+            .map(|x| (x.num_rows(), x.column(0).null_count()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            row_counts,
+            vec![(3, 2), (3, 2), (3, 1), (3, 1), (3, 2), (2, 2)]
+        );
+
+        let get_dict = |batch: &RecordBatch| batch.column(0).to_data().child_data()[0].clone();
+
+        // First and second batch in same row group -> same dictionary
+        assert_eq!(get_dict(&batches[0]), get_dict(&batches[1]));
+        // Third batch spans row group -> computed dictionary
+        assert_ne!(get_dict(&batches[1]), get_dict(&batches[2]));
+        assert_ne!(get_dict(&batches[2]), get_dict(&batches[3]));
+        // Fourth, fifth and sixth from same row group -> same dictionary
+        assert_eq!(get_dict(&batches[3]), get_dict(&batches[4]));
+        assert_eq!(get_dict(&batches[4]), get_dict(&batches[5]));
+    }
+
+    #[test]
+    fn test_read_null_list() {
+        let testdata = arrow::util::test_util::parquet_test_data();
+        let path = format!("{testdata}/null_list.parquet");
+        let file = File::open(path).unwrap();
+        let mut record_batch_reader = ParquetRecordBatchReader::try_new(file, 60).unwrap();
+
+        let batch = record_batch_reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.column(0).len(), 1);
+
+        let list = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(list.len(), 1);
+        assert!(list.is_valid(0));
+
+        let val = list.value(0);
+        assert_eq!(val.len(), 0);
+    }
+
+    #[test]
+    fn test_null_schema_inference() {
+        let testdata = arrow::util::test_util::parquet_test_data();
+        let path = format!("{testdata}/null_list.parquet");
+        let file = File::open(path).unwrap();
+
+        let arrow_field = Field::new(
+            "emptylist",
+            ArrowDataType::List(Arc::new(Field::new_list_field(ArrowDataType::Null, true))),
+            true,
+        );
+
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
+        let schema = builder.schema();
+        assert_eq!(schema.fields().len(), 1);
+        assert_eq!(schema.field(0), &arrow_field);
+    }
+
+    #[test]
+    fn test_skip_metadata() {
+        let col = Arc::new(TimestampNanosecondArray::from_iter_values(vec![0, 1, 2]));
+        let field = Field::new("col", col.data_type().clone(), true);
+
+        let schema_without_metadata = Arc::new(Schema::new(vec![field.clone()]));
+
+        let metadata = [("key".to_string(), "value".to_string())]
+            .into_iter()
+            .collect();
+
+        let schema_with_metadata = Arc::new(Schema::new(vec![field.with_metadata(metadata)]));
+
+        assert_ne!(schema_with_metadata, schema_without_metadata);
+
+        let batch =
+            RecordBatch::try_new(schema_with_metadata.clone(), vec![col as ArrayRef]).unwrap();
+
+        let file = |version: WriterVersion| {
+            let props = WriterProperties::builder()
+                .set_writer_version(version)
+                .build();
+
+            let file = tempfile().unwrap();
+            let mut writer =
+                ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), Some(props))
+                    .unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            file
+        };
+
+        let skip_options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+
+        let v1_reader = file(WriterVersion::PARQUET_1_0);
+        let v2_reader = file(WriterVersion::PARQUET_2_0);
+
+        let arrow_reader =
+            ParquetRecordBatchReader::try_new(v1_reader.try_clone().unwrap(), 1024).unwrap();
+        assert_eq!(arrow_reader.schema(), schema_with_metadata);
+
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(v1_reader, skip_options.clone())
+                .unwrap()
+                .build()
+                .unwrap();
+        assert_eq!(reader.schema(), schema_without_metadata);
+
+        let arrow_reader =
+            ParquetRecordBatchReader::try_new(v2_reader.try_clone().unwrap(), 1024).unwrap();
+        assert_eq!(arrow_reader.schema(), schema_with_metadata);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(v2_reader, skip_options)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(reader.schema(), schema_without_metadata);
+    }
+
+    fn write_parquet_from_iter<I, F>(value: I) -> File
+    where
+        I: IntoIterator<Item = (F, ArrayRef)>,
+        F: AsRef<str>,
+    {
+        let batch = RecordBatch::try_from_iter(value).unwrap();
+        let file = tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema().clone(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        file
+    }
+
+    fn run_schema_test_with_error<I, F>(value: I, schema: SchemaRef, expected_error: &str)
+    where
+        I: IntoIterator<Item = (F, ArrayRef)>,
+        F: AsRef<str>,
+    {
+        let file = write_parquet_from_iter(value);
+        let options_with_schema = ArrowReaderOptions::new().with_schema(schema.clone());
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file.try_clone().unwrap(),
+            options_with_schema,
+        );
+        assert_eq!(builder.err().unwrap().to_string(), expected_error);
+    }
+
+    #[test]
+    fn test_schema_too_few_columns() {
+        run_schema_test_with_error(
+            vec![
+                ("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef),
+                ("int32", Arc::new(Int32Array::from(vec![0])) as ArrayRef),
+            ],
+            Arc::new(Schema::new(vec![Field::new(
+                "int64",
+                ArrowDataType::Int64,
+                false,
+            )])),
+            "Arrow: incompatible arrow schema, expected 2 struct fields got 1",
+        );
+    }
+
+    #[test]
+    fn test_schema_too_many_columns() {
+        run_schema_test_with_error(
+            vec![("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef)],
+            Arc::new(Schema::new(vec![
+                Field::new("int64", ArrowDataType::Int64, false),
+                Field::new("int32", ArrowDataType::Int32, false),
+            ])),
+            "Arrow: incompatible arrow schema, expected 1 struct fields got 2",
+        );
+    }
+
+    #[test]
+    fn test_schema_mismatched_column_names() {
+        run_schema_test_with_error(
+            vec![("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef)],
+            Arc::new(Schema::new(vec![Field::new(
+                "other",
+                ArrowDataType::Int64,
+                false,
+            )])),
+            "Arrow: incompatible arrow schema, expected field named int64 got other",
+        );
+    }
+
+    #[test]
+    fn test_schema_incompatible_columns() {
+        run_schema_test_with_error(
+            vec![
+                (
+                    "col1_invalid",
+                    Arc::new(Int64Array::from(vec![0])) as ArrayRef,
+                ),
+                (
+                    "col2_valid",
+                    Arc::new(Int32Array::from(vec![0])) as ArrayRef,
+                ),
+                (
+                    "col3_invalid",
+                    Arc::new(Date64Array::from(vec![0])) as ArrayRef,
+                ),
+            ],
+            Arc::new(Schema::new(vec![
+                Field::new("col1_invalid", ArrowDataType::Int32, false),
+                Field::new("col2_valid", ArrowDataType::Int32, false),
+                Field::new("col3_invalid", ArrowDataType::Int32, false),
+            ])),
+            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field col1_invalid: requested Int32 but found Int64, data type mismatch for field col3_invalid: requested Int32 but found Int64",
+        );
+    }
+
+    #[test]
+    fn test_one_incompatible_nested_column() {
+        let nested_fields = Fields::from(vec![
+            Field::new("nested1_valid", ArrowDataType::Utf8, false),
+            Field::new("nested1_invalid", ArrowDataType::Int64, false),
+        ]);
+        let nested = StructArray::try_new(
+            nested_fields,
+            vec![
+                Arc::new(StringArray::from(vec!["a"])) as ArrayRef,
+                Arc::new(Int64Array::from(vec![0])) as ArrayRef,
+            ],
+            None,
+        )
+        .expect("struct array");
+        let supplied_nested_fields = Fields::from(vec![
+            Field::new("nested1_valid", ArrowDataType::Utf8, false),
+            Field::new("nested1_invalid", ArrowDataType::Int32, false),
+        ]);
+        run_schema_test_with_error(
+            vec![
+                ("col1", Arc::new(Int64Array::from(vec![0])) as ArrayRef),
+                ("col2", Arc::new(Int32Array::from(vec![0])) as ArrayRef),
+                ("nested", Arc::new(nested) as ArrayRef),
+            ],
+            Arc::new(Schema::new(vec![
+                Field::new("col1", ArrowDataType::Int64, false),
+                Field::new("col2", ArrowDataType::Int32, false),
+                Field::new(
+                    "nested",
+                    ArrowDataType::Struct(supplied_nested_fields),
+                    false,
+                ),
+            ])),
+            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field nested: \
+            requested Struct(\"nested1_valid\": Utf8, \"nested1_invalid\": Int32) \
+            but found Struct(\"nested1_valid\": Utf8, \"nested1_invalid\": Int64)",
+        );
+    }
+
+    /// Return parquet data with a single column of utf8 strings
+    fn utf8_parquet() -> Bytes {
+        let input = StringArray::from_iter_values(vec!["foo", "bar", "baz"]);
+        let batch = RecordBatch::try_from_iter(vec![("column1", Arc::new(input) as _)]).unwrap();
+        let props = None;
+        // write parquet file with non nullable strings
+        let mut parquet_data = vec![];
+        let mut writer = ArrowWriter::try_new(&mut parquet_data, batch.schema(), props).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(parquet_data)
+    }
+
+    #[test]
+    fn test_schema_error_bad_types() {
+        // verify incompatible schemas error on read
+        let parquet_data = utf8_parquet();
+
+        // Ask to read it back with an incompatible schema (int vs string)
+        let input_schema: SchemaRef = Arc::new(Schema::new(vec![Field::new(
+            "column1",
+            arrow::datatypes::DataType::Int32,
+            false,
+        )]));
+
+        // read it back out
+        let reader_options = ArrowReaderOptions::new().with_schema(input_schema.clone());
+        let err =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(parquet_data, reader_options)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field column1: requested Int32 but found Utf8"
+        )
+    }
+
+    #[test]
+    fn test_schema_error_bad_nullability() {
+        // verify incompatible schemas error on read
+        let parquet_data = utf8_parquet();
+
+        // Ask to read it back with an incompatible schema (nullability mismatch)
+        let input_schema: SchemaRef = Arc::new(Schema::new(vec![Field::new(
+            "column1",
+            arrow::datatypes::DataType::Utf8,
+            true,
+        )]));
+
+        // read it back out
+        let reader_options = ArrowReaderOptions::new().with_schema(input_schema.clone());
+        let err =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(parquet_data, reader_options)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Incompatible supplied Arrow schema: nullability mismatch for field column1: expected true but found false"
+        )
+    }
+
+    #[test]
+    fn test_read_binary_as_utf8() {
+        let file = write_parquet_from_iter(vec![
+            (
+                "binary_to_utf8",
+                Arc::new(BinaryArray::from(vec![
+                    b"one".as_ref(),
+                    b"two".as_ref(),
+                    b"three".as_ref(),
+                ])) as ArrayRef,
+            ),
+            (
+                "large_binary_to_large_utf8",
+                Arc::new(LargeBinaryArray::from(vec![
+                    b"one".as_ref(),
+                    b"two".as_ref(),
+                    b"three".as_ref(),
+                ])) as ArrayRef,
+            ),
+            (
+                "binary_view_to_utf8_view",
+                Arc::new(BinaryViewArray::from(vec![
+                    b"one".as_ref(),
+                    b"two".as_ref(),
+                    b"three".as_ref(),
+                ])) as ArrayRef,
+            ),
+        ]);
+        let supplied_fields = Fields::from(vec![
+            Field::new("binary_to_utf8", ArrowDataType::Utf8, false),
+            Field::new(
+                "large_binary_to_large_utf8",
+                ArrowDataType::LargeUtf8,
+                false,
+            ),
+            Field::new("binary_view_to_utf8_view", ArrowDataType::Utf8View, false),
+        ]);
+
+        let options = ArrowReaderOptions::new().with_schema(Arc::new(Schema::new(supplied_fields)));
+        let mut arrow_reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file.try_clone().unwrap(),
+            options,
+        )
+        .expect("reader builder with schema")
+        .build()
+        .expect("reader with schema");
+
+        let batch = arrow_reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_columns(), 3);
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(
+            batch
+                .column(0)
+                .as_string::<i32>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("one"), Some("two"), Some("three")]
+        );
+
+        assert_eq!(
+            batch
+                .column(1)
+                .as_string::<i64>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("one"), Some("two"), Some("three")]
+        );
+
+        assert_eq!(
+            batch.column(2).as_string_view().iter().collect::<Vec<_>>(),
+            vec![Some("one"), Some("two"), Some("three")]
         );
-
-        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
-        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
-        let schema = builder.schema();
-        assert_eq!(schema.fields().len(), 1);
-        assert_eq!(schema.field(0), &arrow_field);
     }
 
     #[test]
-    fn test_skip_metadata() {
-        let col = Arc::new(TimestampNanosecondArray::from_iter_values(vec![0, 1, 2]));
-        let field = Field::new("col", col.data_type().clone(), true);
-
-        let schema_without_metadata = Arc::new(Schema::new(vec![field.clone()]));
-
-        let metadata = [("key".to_string(), "value".to_string())]
-            .into_iter()
-            .collect();
-
-        let schema_with_metadata = Arc::new(Schema::new(vec![field.with_metadata(metadata)]));
+    #[should_panic(expected = "Invalid UTF8 sequence at")]
+    fn test_read_non_utf8_binary_as_utf8() {
+        let file = write_parquet_from_iter(vec![(
+            "non_utf8_binary",
+            Arc::new(BinaryArray::from(vec![
+                b"\xDE\x00\xFF".as_ref(),
+                b"\xDE\x01\xAA".as_ref(),
+                b"\xDE\x02\xFF".as_ref(),
+            ])) as ArrayRef,
+        )]);
+        let supplied_fields = Fields::from(vec![Field::new(
+            "non_utf8_binary",
+            ArrowDataType::Utf8,
+            false,
+        )]);
 
-        assert_ne!(schema_with_metadata, schema_without_metadata);
+        let options = ArrowReaderOptions::new().with_schema(Arc::new(Schema::new(supplied_fields)));
+        let mut arrow_reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file.try_clone().unwrap(),
+            options,
+        )
+        .expect("reader builder with schema")
+        .build()
+        .expect("reader with schema");
+        arrow_reader.next().unwrap().unwrap_err();
+    }
 
-        let batch =
-            RecordBatch::try_new(schema_with_metadata.clone(), vec![col as ArrayRef]).unwrap();
+    #[test]
+    fn test_fixed_size_list_hint() {
+        let vectors = (0..100).map(|i| {
+            (i % 7 != 3).then(|| (0..3).map(|j| Some((i * 3 + j) as f32)).collect::<Vec<_>>())
+        });
+        let list = ListArray::from_iter_primitive::<Float32Type, _, _>(vectors.clone());
+        let expected = FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(vectors, 3);
+        let nested = StructArray::from(vec![(
+            Arc::new(Field::new("w", list.data_type().clone(), true)),
+            Arc::new(list.clone()) as ArrayRef,
+        )]);
+        let variable = ListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>(
+            (0..100).map(|i| Some(vec![Some(i); i as usize % 3])),
+        );
 
-        let file = |version: WriterVersion| {
-            let props = WriterProperties::builder()
-                .set_writer_version(version)
-                .build();
+        let batch = RecordBatch::try_from_iter([
+            ("v", Arc::new(list) as ArrayRef),
+            ("s", Arc::new(nested) as ArrayRef),
+            ("var", Arc::new(variable.clone()) as ArrayRef),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
 
-            let file = tempfile().unwrap();
-            let mut writer =
-                ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), Some(props))
-                    .unwrap();
-            writer.write(&batch).unwrap();
-            writer.close().unwrap();
-            file
-        };
+        let options = ArrowReaderOptions::new()
+            .with_fixed_size_list_hint(ColumnPath::from("v"), 3)
+            .with_fixed_size_list_hint(ColumnPath::new(vec!["s".into(), "w".into()]), 3);
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .with_batch_size(32)
+            .build()
+            .unwrap();
 
-        let skip_options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let schema = reader.schema();
+        assert!(matches!(
+            schema.field(0).data_type(),
+            ArrowDataType::FixedSizeList(_, 3)
+        ));
+        assert_eq!(schema.field(2).data_type(), variable.data_type());
 
-        let v1_reader = file(WriterVersion::PARQUET_1_0);
-        let v2_reader = file(WriterVersion::PARQUET_2_0);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 4);
+        let batch = concat_batches(&schema, &batches).unwrap();
+        assert_eq!(batch.column(0).as_fixed_size_list(), &expected);
+        let nested = batch.column(1).as_struct();
+        assert_eq!(nested.column(0).as_fixed_size_list(), &expected);
+        assert_eq!(batch.column(2).as_list::<i32>(), &variable);
+    }
 
-        let arrow_reader =
-            ParquetRecordBatchReader::try_new(v1_reader.try_clone().unwrap(), 1024).unwrap();
-        assert_eq!(arrow_reader.schema(), schema_with_metadata);
+    #[test]
+    fn test_fixed_size_list_hint_invalid() {
+        let list = ListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>([
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("l", Arc::new(list) as ArrayRef),
+            ("i", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
 
-        let reader =
-            ParquetRecordBatchReaderBuilder::try_new_with_options(v1_reader, skip_options.clone())
+        // The second list has a different length
+        let options = ArrowReaderOptions::new().with_fixed_size_list_hint(ColumnPath::from("l"), 2);
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
                 .unwrap()
                 .build()
                 .unwrap();
-        assert_eq!(reader.schema(), schema_without_metadata);
-
-        let arrow_reader =
-            ParquetRecordBatchReader::try_new(v2_reader.try_clone().unwrap(), 1024).unwrap();
-        assert_eq!(arrow_reader.schema(), schema_with_metadata);
-
-        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(v2_reader, skip_options)
-            .unwrap()
-            .build()
-            .unwrap();
-        assert_eq!(reader.schema(), schema_without_metadata);
-    }
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("fixed-size list length must be a multiple of 2"),
+            "{err}"
+        );
 
-    fn write_parquet_from_iter<I, F>(value: I) -> File
-    where
-        I: IntoIterator<Item = (F, ArrayRef)>,
-        F: AsRef<str>,
-    {
-        let batch = RecordBatch::try_from_iter(value).unwrap();
-        let file = tempfile().unwrap();
-        let mut writer =
-            ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema().clone(), None).unwrap();
+        // An empty list cannot be read as a list of 2 nulls
+        let list = ListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>([
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+        ]);
+        let batch = RecordBatch::try_from_iter([("l", Arc::new(list) as ArrayRef)]).unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
         writer.write(&batch).unwrap();
         writer.close().unwrap();
-        file
-    }
 
-    fn run_schema_test_with_error<I, F>(value: I, schema: SchemaRef, expected_error: &str)
-    where
-        I: IntoIterator<Item = (F, ArrayRef)>,
-        F: AsRef<str>,
-    {
-        let file = write_parquet_from_iter(value);
-        let options_with_schema = ArrowReaderOptions::new().with_schema(schema.clone());
-        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
-            file.try_clone().unwrap(),
-            options_with_schema,
+        let options = ArrowReaderOptions::new().with_fixed_size_list_hint(ColumnPath::from("l"), 2);
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Encountered misaligned row with length 0 (expected length 2)"),
+            "{err}"
         );
-        assert_eq!(builder.err().unwrap().to_string(), expected_error);
+
+        for (path, size, expected) in [
+            (
+                "i",
+                2,
+                "Cannot read column \"i\" as a fixed size list, as it is not a list column",
+            ),
+            (
+                "x",
+                2,
+                "Cannot read column \"x\" as a fixed size list, as it is not a list column",
+            ),
+            ("l", -1, "Invalid fixed size list size -1 for column \"l\""),
+        ] {
+            let options =
+                ArrowReaderOptions::new().with_fixed_size_list_hint(ColumnPath::from(path), size);
+            let err = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                .unwrap_err();
+            assert_eq!(err.to_string(), format!("Arrow: {expected}"));
+        }
     }
 
     #[test]
-    fn test_schema_too_few_columns() {
-        run_schema_test_with_error(
-            vec![
-                ("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef),
-                ("int32", Arc::new(Int32Array::from(vec![0])) as ArrayRef),
-            ],
-            Arc::new(Schema::new(vec![Field::new(
-                "int64",
-                ArrowDataType::Int64,
-                false,
-            )])),
-            "Arrow: incompatible arrow schema, expected 2 struct fields got 1",
+    fn test_dictionary_output() {
+        let strings = StringArray::from_iter_values((0..1000).map(|i| format!("value{}", i % 3)));
+        let views = StringViewArray::from_iter(strings.iter());
+        let binary = BinaryArray::from_iter_values((0..1000).map(|i| [i as u8 % 4; 3]));
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(ArrowDataType::Utf8, true)),
+            OffsetBuffer::from_lengths(std::iter::repeat_n(2, 500)),
+            Arc::new(strings.clone()),
+            None,
         );
-    }
+        let list = list.slice(0, 500);
+        let nested = StructArray::from(vec![(
+            Arc::new(Field::new("x", ArrowDataType::Utf8, false)),
+            Arc::new(strings.slice(0, 500)) as ArrayRef,
+        )]);
+        let ints = Int32Array::from_iter_values(0..500);
 
-    #[test]
-    fn test_schema_too_many_columns() {
-        run_schema_test_with_error(
-            vec![("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef)],
-            Arc::new(Schema::new(vec![
-                Field::new("int64", ArrowDataType::Int64, false),
-                Field::new("int32", ArrowDataType::Int32, false),
-            ])),
-            "Arrow: incompatible arrow schema, expected 1 struct fields got 2",
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(strings.slice(0, 500)) as ArrayRef),
+            ("v", Arc::new(views.slice(0, 500)) as ArrayRef),
+            ("b", Arc::new(binary.slice(0, 500)) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+            ("struct", Arc::new(nested) as ArrayRef),
+            ("i", Arc::new(ints) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_dictionary_output(
+            [
+                vec!["s"],
+                vec!["v"],
+                vec!["b"],
+                vec!["list", "list", "item"],
+                vec!["struct", "x"],
+                vec!["i"],
+            ]
+            .map(|parts| ColumnPath::new(parts.into_iter().map(String::from).collect())),
         );
-    }
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .build()
+            .unwrap();
 
-    #[test]
-    fn test_schema_mismatched_column_names() {
-        run_schema_test_with_error(
-            vec![("int64", Arc::new(Int64Array::from(vec![0])) as ArrayRef)],
-            Arc::new(Schema::new(vec![Field::new(
-                "other",
-                ArrowDataType::Int64,
-                false,
-            )])),
-            "Arrow: incompatible arrow schema, expected field named int64 got other",
+        let dictionary = |value_type: ArrowDataType| {
+            ArrowDataType::Dictionary(Box::new(ArrowDataType::Int32), Box::new(value_type))
+        };
+        let schema = reader.schema();
+        assert_eq!(
+            schema.field(0).data_type(),
+            &dictionary(ArrowDataType::Utf8)
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &dictionary(ArrowDataType::Utf8)
+        );
+        assert_eq!(
+            schema.field(2).data_type(),
+            &dictionary(ArrowDataType::Binary)
+        );
+        assert_eq!(
+            schema.field(3).data_type(),
+            &ArrowDataType::List(Arc::new(Field::new_list_field(
+                dictionary(ArrowDataType::Utf8),
+                true
+            )))
+        );
+        assert_eq!(
+            schema.field(4).data_type(),
+            &ArrowDataType::Struct(
+                vec![Field::new("x", dictionary(ArrowDataType::Utf8), false)].into()
+            )
         );
+        assert_eq!(schema.field(5).data_type(), &ArrowDataType::Int32);
+
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let read = concat_batches(&schema, &read).unwrap();
+
+        // The parquet dictionary is preserved
+        for idx in 0..3 {
+            let values = read.column(idx).as_any_dictionary().values();
+            assert!(values.len() <= 4, "{idx}: {}", values.len());
+        }
+
+        for (idx, expected) in batch.columns().iter().enumerate() {
+            let actual = arrow_cast::cast(read.column(idx), expected.data_type()).unwrap();
+            assert_eq!(&actual, expected, "{idx}");
+        }
     }
 
     #[test]
-    fn test_schema_incompatible_columns() {
-        run_schema_test_with_error(
-            vec![
-                (
-                    "col1_invalid",
-                    Arc::new(Int64Array::from(vec![0])) as ArrayRef,
-                ),
-                (
-                    "col2_valid",
-                    Arc::new(Int32Array::from(vec![0])) as ArrayRef,
-                ),
-                (
-                    "col3_invalid",
-                    Arc::new(Date64Array::from(vec![0])) as ArrayRef,
-                ),
-            ],
-            Arc::new(Schema::new(vec![
-                Field::new("col1_invalid", ArrowDataType::Int32, false),
-                Field::new("col2_valid", ArrowDataType::Int32, false),
-                Field::new("col3_invalid", ArrowDataType::Int32, false),
-            ])),
-            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field col1_invalid: requested Int32 but found Int64, data type mismatch for field col3_invalid: requested Int32 but found Int64",
-        );
+    fn test_dictionary_output_fallback() {
+        // A column whose dictionary exceeds the page size limit part way through a
+        // row group, such that the remaining pages are plain encoded
+        let strings =
+            StringArray::from_iter_values((0..2000).map(|i| format!("{}{i}", "a".repeat(i % 50))));
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(strings) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(1024)
+            .set_data_page_size_limit(1024)
+            .set_write_batch_size(100)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let encodings: Vec<_> = metadata.row_groups()[0].column(0).encodings().collect();
+        assert!(encodings.contains(&Encoding::PLAIN));
+        assert!(encodings.contains(&Encoding::RLE_DICTIONARY));
+
+        for batch_size in [2000, 150] {
+            let options = ArrowReaderOptions::new().with_dictionary_output([ColumnPath::from("s")]);
+            let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                Bytes::from(buf.clone()),
+                options,
+            )
+            .unwrap()
+            .with_batch_size(batch_size)
+            .build()
+            .unwrap();
+
+            let mut offset = 0;
+            for read in reader {
+                let read = read.unwrap();
+                assert!(read.column(0).as_any_dictionary_opt().is_some());
+
+                let actual = arrow_cast::cast(read.column(0), &ArrowDataType::Utf8).unwrap();
+                assert_eq!(&actual, &batch.column(0).slice(offset, read.num_rows()));
+                offset += read.num_rows();
+            }
+            assert_eq!(offset, batch.num_rows());
+        }
     }
 
     #[test]
-    fn test_one_incompatible_nested_column() {
-        let nested_fields = Fields::from(vec![
-            Field::new("nested1_valid", ArrowDataType::Utf8, false),
-            Field::new("nested1_invalid", ArrowDataType::Int64, false),
-        ]);
-        let nested = StructArray::try_new(
-            nested_fields,
-            vec![
-                Arc::new(StringArray::from(vec!["a"])) as ArrayRef,
-                Arc::new(Int64Array::from(vec![0])) as ArrayRef,
-            ],
+    fn test_extension_metadata_roundtrip() {
+        use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+
+        let extension = |field: Field, name: &str, metadata: Option<&str>| {
+            let mut field_metadata =
+                HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string())]);
+            if let Some(metadata) = metadata {
+                field_metadata.insert(EXTENSION_TYPE_METADATA_KEY.to_string(), metadata.into());
+            }
+            field.with_metadata(field_metadata)
+        };
+
+        let uuid = extension(
+            Field::new("uuid", ArrowDataType::FixedSizeBinary(16), true),
+            "arrow.uuid",
             None,
-        )
-        .expect("struct array");
-        let supplied_nested_fields = Fields::from(vec![
-            Field::new("nested1_valid", ArrowDataType::Utf8, false),
-            Field::new("nested1_invalid", ArrowDataType::Int32, false),
+        );
+        let json = extension(
+            Field::new("json", ArrowDataType::Utf8, true),
+            "arrow.json",
+            Some(""),
+        );
+        let custom = extension(
+            Field::new("custom", ArrowDataType::Int32, true),
+            "my.custom",
+            Some(r#"{"unit":"m"}"#),
+        );
+        let element = Arc::new(extension(
+            Field::new_list_field(ArrowDataType::Int32, true),
+            "my.element",
+            None,
+        ));
+        let point = extension(
+            Field::new("point", ArrowDataType::Int64, true),
+            "my.point",
+            Some("xy"),
+        );
+        let entries = Fields::from(vec![
+            Field::new("keys", ArrowDataType::Utf8, false),
+            extension(
+                Field::new("values", ArrowDataType::Int32, true),
+                "my.value",
+                None,
+            ),
         ]);
-        run_schema_test_with_error(
-            vec![
-                ("col1", Arc::new(Int64Array::from(vec![0])) as ArrayRef),
-                ("col2", Arc::new(Int32Array::from(vec![0])) as ArrayRef),
-                ("nested", Arc::new(nested) as ArrayRef),
-            ],
+
+        let uuid_values =
+            FixedSizeBinaryArray::try_from_iter([[1u8; 16], [2u8; 16]].into_iter()).unwrap();
+        let list = ListArray::new(
+            Arc::clone(&element),
+            arrow_buffer::OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            None,
+        );
+        let nested = StructArray::new(
+            Fields::from(vec![point.clone()]),
+            vec![Arc::new(Int64Array::from(vec![4, 5])) as ArrayRef],
+            None,
+        );
+        let map = MapArray::new(
+            Arc::new(Field::new_struct("entries", entries.clone(), false)),
+            arrow_buffer::OffsetBuffer::from_lengths([1, 0]),
+            StructArray::new(
+                entries.clone(),
+                vec![
+                    Arc::new(StringArray::from(vec!["a"])) as ArrayRef,
+                    Arc::new(Int32Array::from(vec![6])) as ArrayRef,
+                ],
+                None,
+            ),
+            None,
+            false,
+        );
+        let batch = RecordBatch::try_new(
             Arc::new(Schema::new(vec![
-                Field::new("col1", ArrowDataType::Int64, false),
-                Field::new("col2", ArrowDataType::Int32, false),
-                Field::new(
-                    "nested",
-                    ArrowDataType::Struct(supplied_nested_fields),
+                uuid,
+                json,
+                custom,
+                Field::new_list("list", element, true),
+                Field::new_struct("nested", vec![point], true),
+                Field::new_map(
+                    "map",
+                    "entries",
+                    entries[0].clone(),
+                    entries[1].clone(),
                     false,
+                    true,
                 ),
             ])),
-            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field nested: \
-            requested Struct(\"nested1_valid\": Utf8, \"nested1_invalid\": Int32) \
-            but found Struct(\"nested1_valid\": Utf8, \"nested1_invalid\": Int64)",
-        );
-    }
+            vec![
+                Arc::new(uuid_values),
+                Arc::new(StringArray::from(vec!["{}", "[]"])),
+                Arc::new(Int32Array::from(vec![7, 8])),
+                Arc::new(list),
+                Arc::new(nested),
+                Arc::new(map),
+            ],
+        )
+        .unwrap();
 
-    /// Return parquet data with a single column of utf8 strings
-    fn utf8_parquet() -> Bytes {
-        let input = StringArray::from_iter_values(vec!["foo", "bar", "baz"]);
-        let batch = RecordBatch::try_from_iter(vec![("column1", Arc::new(input) as _)]).unwrap();
-        let props = None;
-        // write parquet file with non nullable strings
-        let mut parquet_data = vec![];
-        let mut writer = ArrowWriter::try_new(&mut parquet_data, batch.schema(), props).unwrap();
+        // Do not embed the arrow schema, so only the parquet schema and
+        // key-value metadata are available to the reader
+        let mut buf = Vec::with_capacity(1024);
+        let options = ArrowWriterOptions::new().with_skip_arrow_metadata(true);
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buf, batch.schema(), options).unwrap();
         writer.write(&batch).unwrap();
         writer.close().unwrap();
-        Bytes::from(parquet_data)
-    }
+        let data = Bytes::from(buf);
 
-    #[test]
-    fn test_schema_error_bad_types() {
-        // verify incompatible schemas error on read
-        let parquet_data = utf8_parquet();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let key_value_metadata = builder.metadata().file_metadata().key_value_metadata();
+        let keys: Vec<_> = key_value_metadata
+            .unwrap()
+            .iter()
+            .map(|kv| (kv.key.as_str(), kv.value.as_deref()))
+            .collect();
+        #[cfg(feature = "arrow_canonical_extension_types")]
+        let canonical: Vec<(&str, Option<&str>)> = vec![];
+        #[cfg(not(feature = "arrow_canonical_extension_types"))]
+        let canonical = vec![
+            ("ARROW:extension:name:uuid", Some("arrow.uuid")),
+            ("ARROW:extension:name:json", Some("arrow.json")),
+            ("ARROW:extension:metadata:json", Some("")),
+        ];
+        let custom = vec![
+            ("ARROW:extension:name:custom", Some("my.custom")),
+            ("ARROW:extension:metadata:custom", Some(r#"{"unit":"m"}"#)),
+            ("ARROW:extension:name:list.element", Some("my.element")),
+            ("ARROW:extension:name:nested.point", Some("my.point")),
+            ("ARROW:extension:metadata:nested.point", Some("xy")),
+            ("ARROW:extension:name:map.value", Some("my.value")),
+        ];
+        assert_eq!(keys, [canonical, custom].concat());
 
-        // Ask to read it back with an incompatible schema (int vs string)
-        let input_schema: SchemaRef = Arc::new(Schema::new(vec![Field::new(
-            "column1",
-            arrow::datatypes::DataType::Int32,
-            false,
-        )]));
+        // Without the option, only extension types represented by a logical type are restored
+        let schema = builder.schema();
+        assert_eq!(schema.field(2).extension_type_name(), None);
+        #[cfg(feature = "arrow_canonical_extension_types")]
+        {
+            use crate::basic::LogicalType;
+            let parquet_schema = builder.parquet_schema();
+            assert_eq!(
+                parquet_schema.column(0).logical_type(),
+                Some(LogicalType::Uuid)
+            );
+            assert_eq!(
+                parquet_schema.column(1).logical_type(),
+                Some(LogicalType::Json)
+            );
+            assert_eq!(schema.field(0).extension_type_name(), Some("arrow.uuid"));
+            assert_eq!(schema.field(1).extension_type_name(), Some("arrow.json"));
+        }
 
-        // read it back out
-        let reader_options = ArrowReaderOptions::new().with_schema(input_schema.clone());
-        let err =
-            ParquetRecordBatchReaderBuilder::try_new_with_options(parquet_data, reader_options)
-                .unwrap_err();
-        assert_eq!(
-            err.to_string(),
-            "Arrow: Incompatible supplied Arrow schema: data type mismatch for field column1: requested Int32 but found Utf8"
-        )
+        let options = ArrowReaderOptions::new().with_extension_metadata(true);
+        let read = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+        assert_eq!(read.schema(), batch.schema());
     }
 
     #[test]
-    fn test_schema_error_bad_nullability() {
-        // verify incompatible schemas error on read
-        let parquet_data = utf8_parquet();
+    fn test_extension_metadata_dotted_names() {
+        use arrow_schema::extension::EXTENSION_TYPE_NAME_KEY;
+
+        let extension = |name: &str, extension_name: &str| {
+            Field::new(name, ArrowDataType::Int32, true).with_metadata(HashMap::from([(
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                extension_name.to_string(),
+            )]))
+        };
 
-        // Ask to read it back with an incompatible schema (nullability mismatch)
-        let input_schema: SchemaRef = Arc::new(Schema::new(vec![Field::new(
-            "column1",
-            arrow::datatypes::DataType::Utf8,
-            true,
-        )]));
+        // The paths of "a.b" and of "b" within "a" must not collide
+        let dotted = extension("a.b", "my.dotted");
+        let child = extension("b", "my.child");
+        let nested = StructArray::new(
+            Fields::from(vec![child.clone()]),
+            vec![Arc::new(Int32Array::from(vec![2])) as ArrayRef],
+            None,
+        );
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                dotted,
+                Field::new_struct("a", vec![child], true),
+            ])),
+            vec![Arc::new(Int32Array::from(vec![1])), Arc::new(nested)],
+        )
+        .unwrap();
 
-        // read it back out
-        let reader_options = ArrowReaderOptions::new().with_schema(input_schema.clone());
-        let err =
-            ParquetRecordBatchReaderBuilder::try_new_with_options(parquet_data, reader_options)
-                .unwrap_err();
+        let mut buf = Vec::with_capacity(1024);
+        let options = ArrowWriterOptions::new().with_skip_arrow_metadata(true);
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buf, batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_extension_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let keys: Vec<_> = builder
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .unwrap()
+            .iter()
+            .map(|kv| (kv.key.as_str(), kv.value.as_deref()))
+            .collect();
         assert_eq!(
-            err.to_string(),
-            "Arrow: Incompatible supplied Arrow schema: nullability mismatch for field column1: expected true but found false"
-        )
+            keys,
+            [
+                (r"ARROW:extension:name:a\.b", Some("my.dotted")),
+                ("ARROW:extension:name:a.b", Some("my.child")),
+            ]
+        );
+
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read.schema(), batch.schema());
     }
 
     #[test]
-    fn test_read_binary_as_utf8() {
-        let file = write_parquet_from_iter(vec![
-            (
-                "binary_to_utf8",
-                Arc::new(BinaryArray::from(vec![
-                    b"one".as_ref(),
-                    b"two".as_ref(),
-                    b"three".as_ref(),
-                ])) as ArrayRef,
-            ),
-            (
-                "large_binary_to_large_utf8",
-                Arc::new(LargeBinaryArray::from(vec![
-                    b"one".as_ref(),
-                    b"two".as_ref(),
-                    b"three".as_ref(),
-                ])) as ArrayRef,
-            ),
-            (
-                "binary_view_to_utf8_view",
-                Arc::new(BinaryViewArray::from(vec![
-                    b"one".as_ref(),
-                    b"two".as_ref(),
-                    b"three".as_ref(),
-                ])) as ArrayRef,
-            ),
+    fn test_read_with_view_types() {
+        let binary = BinaryArray::from(vec![
+            Some(b"short".as_ref()),
+            None,
+            Some(b"a value longer than twelve bytes".as_ref()),
         ]);
-        let supplied_fields = Fields::from(vec![
-            Field::new("binary_to_utf8", ArrowDataType::Utf8, false),
-            Field::new(
-                "large_binary_to_large_utf8",
-                ArrowDataType::LargeUtf8,
-                false,
-            ),
-            Field::new("binary_view_to_utf8_view", ArrowDataType::Utf8View, false),
+        let strings = StringArray::from(vec![Some("one"), Some("two"), None]);
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(ArrowDataType::Binary, true)),
+            arrow_buffer::OffsetBuffer::from_lengths([1, 0, 2]),
+            Arc::new(BinaryArray::from(vec![
+                b"x".as_ref(),
+                b"yy".as_ref(),
+                b"a value longer than twelve bytes".as_ref(),
+            ])),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let nested = StructArray::from(vec![(
+            Arc::new(Field::new("s", ArrowDataType::Utf8, true)),
+            Arc::new(strings.clone()) as ArrayRef,
+        )]);
+
+        let file = write_parquet_from_iter(vec![
+            ("binary", Arc::new(binary) as ArrayRef),
+            ("utf8", Arc::new(strings) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+            ("nested", Arc::new(nested) as ArrayRef),
         ]);
+        let expected = ParquetRecordBatchReader::try_new(file.try_clone().unwrap(), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
 
-        let options = ArrowReaderOptions::new().with_schema(Arc::new(Schema::new(supplied_fields)));
-        let mut arrow_reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
-            file.try_clone().unwrap(),
-            options,
-        )
-        .expect("reader builder with schema")
-        .build()
-        .expect("reader with schema");
+        let read = |options: ArrowReaderOptions| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap();
+            let schema = builder.schema().clone();
+            let batch = builder.build().unwrap().next().unwrap().unwrap();
+            assert_eq!(batch.schema(), schema);
+            batch
+        };
 
-        let batch = arrow_reader.next().unwrap().unwrap();
-        assert_eq!(batch.num_columns(), 3);
-        assert_eq!(batch.num_rows(), 3);
+        // binary columns only
+        let batch = read(ArrowReaderOptions::new().with_binary_view(true));
+        let schema = batch.schema();
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::BinaryView);
+        assert_eq!(schema.field(1).data_type(), &ArrowDataType::Utf8);
         assert_eq!(
-            batch
-                .column(0)
-                .as_string::<i32>()
-                .iter()
-                .collect::<Vec<_>>(),
-            vec![Some("one"), Some("two"), Some("three")]
+            schema.field(2).data_type(),
+            &ArrowDataType::List(Arc::new(Field::new_list_field(
+                ArrowDataType::BinaryView,
+                true
+            )))
         );
-
         assert_eq!(
-            batch
-                .column(1)
-                .as_string::<i64>()
-                .iter()
-                .collect::<Vec<_>>(),
-            vec![Some("one"), Some("two"), Some("three")]
+            schema.field(3).data_type(),
+            expected.schema().field(3).data_type()
         );
 
+        // string and binary columns
+        let batch = read(
+            ArrowReaderOptions::new()
+                .with_binary_view(true)
+                .with_string_view(true),
+        );
+        let schema = batch.schema();
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::BinaryView);
+        assert_eq!(schema.field(1).data_type(), &ArrowDataType::Utf8View);
         assert_eq!(
-            batch.column(2).as_string_view().iter().collect::<Vec<_>>(),
-            vec![Some("one"), Some("two"), Some("three")]
+            schema.field(3).data_type(),
+            &ArrowDataType::Struct(Fields::from(vec![Field::new(
+                "s",
+                ArrowDataType::Utf8View,
+                true
+            )]))
         );
+
+        for (actual, expected) in batch.columns().iter().zip(expected.columns()) {
+            let expected = arrow::compute::cast(expected, actual.data_type()).unwrap();
+            assert_eq!(actual.as_ref(), expected.as_ref());
+        }
     }
 
     #[test]
-    #[should_panic(expected = "Invalid UTF8 sequence at")]
-    fn test_read_non_utf8_binary_as_utf8() {
+    fn test_binary_view_row_filter() {
+        let values = vec![
+            Some(b"arrow".as_ref()),
+            Some(b"arrow-rs is a rust implementation".as_ref()),
+            None,
+            Some(b"parquet".as_ref()),
+            Some(b"parquet and arrow".as_ref()),
+            Some(b"\xDE\xAD\xBE\xEF".as_ref()),
+        ];
         let file = write_parquet_from_iter(vec![(
-            "non_utf8_binary",
-            Arc::new(BinaryArray::from(vec![
-                b"\xDE\x00\xFF".as_ref(),
-                b"\xDE\x01\xAA".as_ref(),
-                b"\xDE\x02\xFF".as_ref(),
-            ])) as ArrayRef,
-        )]);
-        let supplied_fields = Fields::from(vec![Field::new(
-            "non_utf8_binary",
-            ArrowDataType::Utf8,
-            false,
+            "b",
+            Arc::new(BinaryArray::from(values.clone())) as ArrayRef,
         )]);
 
-        let options = ArrowReaderOptions::new().with_schema(Arc::new(Schema::new(supplied_fields)));
-        let mut arrow_reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
-            file.try_clone().unwrap(),
-            options,
-        )
-        .expect("reader builder with schema")
-        .build()
-        .expect("reader with schema");
-        arrow_reader.next().unwrap().unwrap_err();
+        let read = |predicate: fn(&BinaryViewArray) -> BooleanArray| {
+            let options = ArrowReaderOptions::new().with_binary_view(true);
+            let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap();
+            let mask = ProjectionMask::roots(builder.parquet_schema(), [0]);
+            let filter = ArrowPredicateFn::new(mask, move |batch: RecordBatch| {
+                Ok(predicate(batch.column(0).as_binary_view()))
+            });
+            let reader = builder
+                .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+                .build()
+                .unwrap();
+            reader
+                .flat_map(|b| {
+                    let b = b.unwrap();
+                    let col = b.column(0).as_binary_view();
+                    col.iter()
+                        .map(|v| v.map(|v| v.to_vec()))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let starts_with = read(|array| {
+            arrow::compute::kernels::comparison::starts_with(
+                array,
+                &BinaryViewArray::new_scalar(b"arrow"),
+            )
+            .unwrap()
+        });
+        assert_eq!(
+            starts_with,
+            vec![
+                Some(b"arrow".to_vec()),
+                Some(b"arrow-rs is a rust implementation".to_vec()),
+            ]
+        );
+
+        let contains = read(|array| {
+            arrow::compute::kernels::comparison::contains(
+                array,
+                &BinaryViewArray::new_scalar(b"\xAD\xBE"),
+            )
+            .unwrap()
+        });
+        assert_eq!(contains, vec![Some(b"\xDE\xAD\xBE\xEF".to_vec())]);
+
+        let contains = read(|array| {
+            arrow::compute::kernels::comparison::contains(
+                array,
+                &BinaryViewArray::new_scalar(b"arrow"),
+            )
+            .unwrap()
+        });
+        assert_eq!(contains.len(), 3);
     }
 
     #[test]
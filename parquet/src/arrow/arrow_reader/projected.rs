@@ -0,0 +1,236 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ProjectedReader`] for selecting columns from the batches of a reader
+
+use crate::arrow::arrow_reader::ParquetRecordBatchReader;
+use arrow_array::{RecordBatch, RecordBatchOptions, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+
+/// A [`RecordBatchReader`] that yields the batches of another reader restricted
+/// to a subset of their columns
+///
+/// The columns are resolved by name once, in [`Self::try_new`], and each batch
+/// is then projected by cloning the [`ArrayRef`]s of the selected columns, so no
+/// data is copied.
+///
+/// Note that all columns of the inner reader are still decoded. Where the
+/// columns are known before the reader is built, prefer to only decode them by
+/// using [`ArrowReaderBuilder::with_projection`] instead.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, ProjectedReader};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+/// #     ("b", Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+/// # ]).unwrap();
+/// # let mut buf = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let data = Bytes::from(buf);
+/// let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// // Only keep column "b" of each batch
+/// let projected = ProjectedReader::try_new(reader, &["b"]).unwrap();
+/// for batch in projected {
+///     let batch = batch.unwrap();
+///     assert_eq!(batch.num_columns(), 1);
+///     assert_eq!(batch.schema().field(0).name(), "b");
+/// }
+/// ```
+///
+/// [`ArrayRef`]: arrow_array::ArrayRef
+/// [`ArrowReaderBuilder::with_projection`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_projection
+#[derive(Debug)]
+pub struct ProjectedReader<R = ParquetRecordBatchReader> {
+    reader: R,
+    indices: Vec<usize>,
+    schema: SchemaRef,
+}
+
+impl<R: RecordBatchReader> ProjectedReader<R> {
+    /// Creates a new [`ProjectedReader`] yielding the columns of `reader` named
+    /// by `columns`, in the order they are given
+    ///
+    /// Returns an error if the schema of `reader` has no column with one of the
+    /// given names
+    pub fn try_new<S: AsRef<str>>(reader: R, columns: &[S]) -> Result<Self, ArrowError> {
+        let schema = reader.schema();
+        let indices = columns
+            .iter()
+            .map(|name| schema.index_of(name.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema = SchemaRef::new(schema.project(&indices)?);
+        Ok(Self {
+            reader,
+            indices,
+            schema,
+        })
+    }
+
+    /// Returns the indices of the projected columns in the schema of the inner reader
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns a reference to the inner reader
+    pub fn inner(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes this reader, returning the inner reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: RecordBatchReader> Iterator for ProjectedReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = match self.reader.next()? {
+            Ok(batch) => batch,
+            Err(e) => return Some(Err(e)),
+        };
+        let columns = self
+            .indices
+            .iter()
+            .map(|i| batch.column(*i).clone())
+            .collect();
+        // Preserve the row count of batches without any projected columns
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        Some(RecordBatch::try_new_with_options(
+            self.schema.clone(),
+            columns,
+            &options,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.reader.size_hint()
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReader for ProjectedReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use arrow_array::{Array, ArrayRef, Int32Array, RecordBatchIterator, StringArray};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..10).map(|i| i.to_string()),
+                )) as ArrayRef,
+            ),
+            (
+                "c",
+                Arc::new(Int32Array::from_iter_values(10..20)) as ArrayRef,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_projected_reader() {
+        let batch = batch();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))
+            .unwrap()
+            .with_batch_size(4)
+            .build()
+            .unwrap();
+        let projected = ProjectedReader::try_new(reader, &["c", "a"]).unwrap();
+        assert_eq!(projected.indices(), &[2, 0]);
+
+        let expected = batch.project(&[2, 0]).unwrap();
+        assert_eq!(projected.schema(), expected.schema());
+
+        let batches = projected.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 3);
+        assert_eq!(
+            arrow_select::concat::concat_batches(&expected.schema(), &batches).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_projected_reader_shares_arrays() {
+        let batch = batch();
+        let reader = RecordBatchIterator::new([Ok(batch.clone())], batch.schema());
+        let mut projected = ProjectedReader::try_new(reader, &["b"]).unwrap();
+
+        let read = projected.next().unwrap().unwrap();
+        assert_eq!(read.num_columns(), 1);
+        assert_eq!(read.num_rows(), 10);
+        assert!(Arc::ptr_eq(read.column(0), batch.column(1)));
+        assert_eq!(
+            read.column(0).to_data().buffers()[1].as_ptr(),
+            batch.column(1).to_data().buffers()[1].as_ptr()
+        );
+        assert!(projected.next().is_none());
+    }
+
+    #[test]
+    fn test_projected_reader_empty_projection() {
+        let batch = batch();
+        let reader = RecordBatchIterator::new([Ok(batch.clone())], batch.schema());
+        let mut projected = ProjectedReader::try_new(reader, &[] as &[&str]).unwrap();
+
+        let read = projected.next().unwrap().unwrap();
+        assert_eq!(read.num_columns(), 0);
+        assert_eq!(read.num_rows(), 10);
+    }
+
+    #[test]
+    fn test_projected_reader_unknown_column() {
+        let batch = batch();
+        let reader = RecordBatchIterator::new([Ok(batch.clone())], batch.schema());
+        let err = ProjectedReader::try_new(reader, &["a", "d"]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unable to get field named \"d\". Valid fields: [\"a\", \"b\", \"c\"]"
+        );
+    }
+}
@@ -20,11 +20,9 @@
 
 use crate::arrow::array_reader::ArrayReader;
 use crate::arrow::arrow_reader::{
-    ArrowPredicate, ParquetRecordBatchReader, RowSelection, RowSelector,
+    ArrowPredicate, ParquetRecordBatchReader, PredicateStats, RowSelection, RowSelector,
 };
 use crate::errors::{ParquetError, Result};
-use arrow_array::Array;
-use arrow_select::filter::prep_null_mask_filter;
 use std::collections::VecDeque;
 
 /// A builder for [`ReadPlan`]
@@ -44,6 +42,11 @@ impl ReadPlanBuilder {
         }
     }
 
+    /// Returns the batch size of this plan
+    pub(crate) fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
     /// Set the current selection to the given value
     pub fn with_selection(mut self, selection: Option<RowSelection>) -> Self {
         self.selection = selection;
@@ -89,9 +92,29 @@ impl ReadPlanBuilder {
     /// or if the [`ParquetRecordBatchReader`] specified an explicit
     /// [`RowSelection`] in addition to one or more predicates.
     pub fn with_predicate(
+        self,
+        array_reader: Box<dyn ArrayReader>,
+        predicate: &mut dyn ArrowPredicate,
+    ) -> Result<Self> {
+        self.evaluate_predicate(array_reader, predicate, None)
+    }
+
+    /// Like [`Self::with_predicate`], also adding the number of rows evaluated
+    /// and selected by `predicate` to `stats`
+    pub(crate) fn with_predicate_stats(
+        self,
+        array_reader: Box<dyn ArrayReader>,
+        predicate: &mut dyn ArrowPredicate,
+        stats: &mut PredicateStats,
+    ) -> Result<Self> {
+        self.evaluate_predicate(array_reader, predicate, Some(stats))
+    }
+
+    fn evaluate_predicate(
         mut self,
         array_reader: Box<dyn ArrayReader>,
         predicate: &mut dyn ArrowPredicate,
+        mut stats: Option<&mut PredicateStats>,
     ) -> Result<Self> {
         let reader = ParquetRecordBatchReader::new(array_reader, self.clone().build());
         let mut filters = vec![];
@@ -106,10 +129,11 @@ impl ReadPlanBuilder {
                     filter.len()
                 ));
             }
-            match filter.null_count() {
-                0 => filters.push(filter),
-                _ => filters.push(prep_null_mask_filter(&filter)),
-            };
+            if let Some(stats) = stats.as_deref_mut() {
+                stats.rows_evaluated += input_rows;
+                stats.rows_selected += filter.true_count();
+            }
+            filters.push(filter);
         }
 
         let raw = RowSelection::from_filters(&filters);
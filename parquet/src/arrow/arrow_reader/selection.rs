@@ -16,7 +16,8 @@
 // under the License.
 
 use arrow_array::{Array, BooleanArray};
-use arrow_select::filter::SlicesIterator;
+use arrow_select::filter::{SlicesIterator, prep_null_mask_filter};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::ops::Range;
@@ -106,17 +107,45 @@ pub struct RowSelection {
 impl RowSelection {
     /// Creates a [`RowSelection`] from a slice of [`BooleanArray`]
     ///
-    /// # Panic
+    /// The filters are treated as a single contiguous sequence of rows, with
+    /// `true` values selecting rows and `false` values skipping them. Runs that
+    /// span the boundary between successive filters are coalesced into a
+    /// single [`RowSelector`].
     ///
-    /// Panics if any of the [`BooleanArray`] contain nulls
+    /// Null values are treated as `false`, matching the behaviour of
+    /// [`RowFilter`](crate::arrow::arrow_reader::RowFilter).
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow_array::BooleanArray;
+    /// # use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
+    /// let filters = vec![
+    ///     BooleanArray::from(vec![false, true, true]),
+    ///     BooleanArray::from(vec![Some(true), None, Some(false)]),
+    /// ];
+    /// let selection = RowSelection::from_filters(&filters);
+    ///
+    /// let actual: Vec<RowSelector> = selection.into();
+    /// assert_eq!(
+    ///     actual,
+    ///     vec![RowSelector::skip(1), RowSelector::select(3), RowSelector::skip(2)]
+    /// );
+    /// ```
     pub fn from_filters(filters: &[BooleanArray]) -> Self {
+        let filters: Vec<_> = filters
+            .iter()
+            .map(|filter| match filter.null_count() {
+                0 => Cow::Borrowed(filter),
+                _ => Cow::Owned(prep_null_mask_filter(filter)),
+            })
+            .collect();
+
         let mut next_offset = 0;
         let total_rows = filters.iter().map(|x| x.len()).sum();
 
         let iter = filters.iter().flat_map(|filter| {
             let offset = next_offset;
             next_offset += filter.len();
-            assert_eq!(filter.null_count(), 0);
             SlicesIterator::new(filter).map(move |(start, end)| start + offset..end + offset)
         });
 
@@ -742,6 +771,67 @@ mod tests {
         assert_eq!(selection.selectors, vec![RowSelector::skip(4)]);
     }
 
+    #[test]
+    fn test_from_filters_patterns() {
+        // all true, coalesced across filters
+        let filters = vec![
+            BooleanArray::from(vec![true; 3]),
+            BooleanArray::from(vec![true; 5]),
+        ];
+        let selection = RowSelection::from_filters(&filters);
+        assert_eq!(selection.selectors, vec![RowSelector::select(8)]);
+        assert_eq!(selection.row_count(), 8);
+
+        // all false, coalesced across filters
+        let filters = vec![
+            BooleanArray::from(vec![false; 4]),
+            BooleanArray::from(vec![false; 2]),
+        ];
+        let selection = RowSelection::from_filters(&filters);
+        assert!(!selection.selects_any());
+        assert_eq!(selection.selectors, vec![RowSelector::skip(6)]);
+        assert_eq!(selection.skipped_row_count(), 6);
+
+        // alternating, with runs that span the boundary between filters
+        let filters = vec![
+            BooleanArray::from(vec![true, false, true, true]),
+            BooleanArray::from(vec![true, false, false, true]),
+            BooleanArray::from(vec![false, true]),
+        ];
+        let selection = RowSelection::from_filters(&filters);
+        assert_eq!(
+            selection.selectors,
+            vec![
+                RowSelector::select(1),
+                RowSelector::skip(1),
+                RowSelector::select(3),
+                RowSelector::skip(2),
+                RowSelector::select(1),
+                RowSelector::skip(1),
+                RowSelector::select(1),
+            ]
+        );
+
+        // nulls are treated as false
+        let filters = vec![
+            BooleanArray::from(vec![Some(true), None]),
+            BooleanArray::from(vec![None, Some(true)]),
+        ];
+        let selection = RowSelection::from_filters(&filters);
+        assert_eq!(
+            selection.selectors,
+            vec![
+                RowSelector::select(1),
+                RowSelector::skip(2),
+                RowSelector::select(1),
+            ]
+        );
+
+        // no filters
+        let selection = RowSelection::from_filters(&[]);
+        assert!(selection.selectors.is_empty());
+    }
+
     #[test]
     fn test_split_off() {
         let mut selection = RowSelection::from(vec![
@@ -19,12 +19,15 @@
 
 /// Notice that all the corresponding tests are in
 /// `arrow-rs/parquet/tests/arrow_reader/statistics.rs`.
+use crate::arrow::arrow_reader::{RowSelection, RowSelector};
 use crate::arrow::buffer::bit_util::sign_extend_be;
 use crate::arrow::parquet_column;
-use crate::basic::Type as PhysicalType;
+use crate::basic::{SortOrder, Type as PhysicalType};
 use crate::data_type::{ByteArray, FixedLenByteArray};
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{ParquetColumnIndex, ParquetOffsetIndex, RowGroupMetaData};
+use crate::file::metadata::{
+    ParquetColumnIndex, ParquetMetaData, ParquetOffsetIndex, RowGroupMetaData,
+};
 use crate::file::page_index::column_index::{ColumnIndexIterators, ColumnIndexMetaData};
 use crate::file::statistics::Statistics as ParquetStatistics;
 use crate::schema::types::SchemaDescriptor;
@@ -33,9 +36,9 @@ use arrow_array::builder::{
     StringViewBuilder,
 };
 use arrow_array::{
-    ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, Decimal32Array, Decimal64Array,
-    Decimal128Array, Decimal256Array, Float16Array, Float32Array, Float64Array, Int8Array,
-    Int16Array, Int32Array, Int64Array, LargeBinaryArray, Time32MillisecondArray,
+    Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, Decimal32Array,
+    Decimal64Array, Decimal128Array, Decimal256Array, Float16Array, Float32Array, Float64Array,
+    Int8Array, Int16Array, Int32Array, Int64Array, LargeBinaryArray, Time32MillisecondArray,
     Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
     TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
     UInt16Array, UInt32Array, UInt64Array, new_empty_array, new_null_array,
@@ -44,33 +47,38 @@ use arrow_buffer::i256;
 use arrow_schema::{DataType, Field, Schema, TimeUnit};
 use half::f16;
 use paste::paste;
+use std::ops::Range;
 use std::sync::Arc;
 
 // Convert the bytes array to i32.
 // The endian of the input bytes array must be big-endian.
-pub(crate) fn from_bytes_to_i32(b: &[u8]) -> i32 {
+// Returns `None` if the bytes array is empty or too long.
+pub(crate) fn from_bytes_to_i32(b: &[u8]) -> Option<i32> {
     // The bytes array are from parquet file and must be the big-endian.
     // The endian is defined by parquet format, and the reference document
     // https://github.com/apache/parquet-format/blob/54e53e5d7794d383529dd30746378f19a12afd58/src/main/thrift/parquet.thrift#L66
-    i32::from_be_bytes(sign_extend_be::<4>(b))
+    (!b.is_empty() && b.len() <= 4).then(|| i32::from_be_bytes(sign_extend_be::<4>(b)))
 }
 
 // Convert the bytes array to i64.
 // The endian of the input bytes array must be big-endian.
-pub(crate) fn from_bytes_to_i64(b: &[u8]) -> i64 {
-    i64::from_be_bytes(sign_extend_be::<8>(b))
+// Returns `None` if the bytes array is empty or too long.
+pub(crate) fn from_bytes_to_i64(b: &[u8]) -> Option<i64> {
+    (!b.is_empty() && b.len() <= 8).then(|| i64::from_be_bytes(sign_extend_be::<8>(b)))
 }
 
 // Convert the bytes array to i128.
 // The endian of the input bytes array must be big-endian.
-pub(crate) fn from_bytes_to_i128(b: &[u8]) -> i128 {
-    i128::from_be_bytes(sign_extend_be::<16>(b))
+// Returns `None` if the bytes array is empty or too long.
+pub(crate) fn from_bytes_to_i128(b: &[u8]) -> Option<i128> {
+    (!b.is_empty() && b.len() <= 16).then(|| i128::from_be_bytes(sign_extend_be::<16>(b)))
 }
 
 // Convert the bytes array to i256.
 // The endian of the input bytes array must be big-endian.
-pub(crate) fn from_bytes_to_i256(b: &[u8]) -> i256 {
-    i256::from_be_bytes(sign_extend_be::<32>(b))
+// Returns `None` if the bytes array is empty or too long.
+pub(crate) fn from_bytes_to_i256(b: &[u8]) -> Option<i256> {
+    (!b.is_empty() && b.len() <= 32).then(|| i256::from_be_bytes(sign_extend_be::<32>(b)))
 }
 
 // Convert the bytes array to f16
@@ -244,6 +252,9 @@ make_stats_iterator!(
 /// * `$bytes_func` is the function to call to get the value as bytes (e.g. `min_bytes` or `max_bytes`)
 /// * `$stat_value_type` is the type of the statistics value (e.g. `i128`)
 /// * `convert_func` is the function to convert the bytes to stats value (e.g. `from_bytes_to_i128`)
+///
+/// `FIXED_LEN_BYTE_ARRAY` statistics whose length differs from the column's
+/// `type_length` (e.g. because they were truncated) are returned as `None`
 macro_rules! make_decimal_stats_iterator {
     ($iterator_type:ident, $func:ident, $bytes_func:ident, $stat_value_type:ident, $convert_func: ident) => {
         struct $iterator_type<'a, I>
@@ -251,14 +262,15 @@ macro_rules! make_decimal_stats_iterator {
             I: Iterator<Item = Option<&'a ParquetStatistics>>,
         {
             iter: I,
+            type_length: Option<usize>,
         }
 
         impl<'a, I> $iterator_type<'a, I>
         where
             I: Iterator<Item = Option<&'a ParquetStatistics>>,
         {
-            fn new(iter: I) -> Self {
-                Self { iter }
+            fn new(iter: I, type_length: Option<usize>) -> Self {
+                Self { iter, type_length }
             }
         }
 
@@ -270,6 +282,7 @@ macro_rules! make_decimal_stats_iterator {
 
             fn next(&mut self) -> Option<Self::Item> {
                 let next = self.iter.next();
+                let type_length = self.type_length;
                 next.map(|x| {
                     x.and_then(|stats| match stats {
                         ParquetStatistics::Int32(s) => {
@@ -279,10 +292,11 @@ macro_rules! make_decimal_stats_iterator {
                             .$func()
                             .map(|x| $stat_value_type::try_from(*x).ok())
                             .flatten(),
-                        ParquetStatistics::ByteArray(s) => s.$bytes_func().map($convert_func),
-                        ParquetStatistics::FixedLenByteArray(s) => {
-                            s.$bytes_func().map($convert_func)
-                        }
+                        ParquetStatistics::ByteArray(s) => s.$bytes_func().and_then($convert_func),
+                        ParquetStatistics::FixedLenByteArray(s) => s
+                            .$bytes_func()
+                            .filter(|b| type_length.is_none_or(|len| b.len() == len))
+                            .and_then($convert_func),
                         _ => None,
                     })
                 })
@@ -360,7 +374,7 @@ make_decimal_stats_iterator!(
 /// data_type: The data type of the statistics (e.g. `DataType::Int32`)
 /// iterator: The iterator of [`ParquetStatistics`] to extract the statistics from.
 macro_rules! get_statistics {
-    ($stat_type_prefix: ident, $data_type: ident, $iterator: ident, $physical_type: ident) => {
+    ($stat_type_prefix: ident, $data_type: ident, $iterator: ident, $physical_type: ident, $type_length: ident) => {
         paste! {
         match $data_type {
             DataType::Boolean => Ok(Arc::new(BooleanArray::from_iter(
@@ -519,30 +533,30 @@ macro_rules! get_statistics {
             },
             DataType::Decimal32(precision, scale) => {
                 let arr = Decimal32Array::from_iter(
-                    [<$stat_type_prefix Decimal32StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal32StatsIterator>]::new($iterator, $type_length)
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal64(precision, scale) => {
                 let arr = Decimal64Array::from_iter(
-                    [<$stat_type_prefix Decimal64StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal64StatsIterator>]::new($iterator, $type_length)
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal128(precision, scale) => {
                 let arr = Decimal128Array::from_iter(
-                    [<$stat_type_prefix Decimal128StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal128StatsIterator>]::new($iterator, $type_length)
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal256(precision, scale) => {
                 let arr = Decimal256Array::from_iter(
-                    [<$stat_type_prefix Decimal256StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal256StatsIterator>]::new($iterator, $type_length)
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Dictionary(_, value_type) => {
-                [<$stat_type_prefix:lower _ statistics>](value_type, $iterator, $physical_type)
+                [<$stat_type_prefix:lower _ statistics>](value_type, $iterator, $physical_type, $type_length)
             },
             DataType::Utf8View => {
                 let iterator = [<$stat_type_prefix ByteArrayStatsIterator>]::new($iterator);
@@ -693,14 +707,15 @@ macro_rules! get_decimal_page_stats_iterator {
             I: Iterator<Item = (usize, &'a ColumnIndexMetaData)>,
         {
             iter: I,
+            type_length: Option<usize>,
         }
 
         impl<'a, I> $iterator_type<'a, I>
         where
             I: Iterator<Item = (usize, &'a ColumnIndexMetaData)>,
         {
-            fn new(iter: I) -> Self {
-                Self { iter }
+            fn new(iter: I, type_length: Option<usize>) -> Self {
+                Self { iter, type_length }
             }
         }
 
@@ -724,19 +739,22 @@ macro_rules! get_decimal_page_stats_iterator {
                         ColumnIndexMetaData::INT64(native_index) => Some(
                             native_index
                                 .$func()
-                                .map(|x| x.map(|x| $stat_value_type::try_from(*x).unwrap()))
+                                .map(|x| x.and_then(|x| $stat_value_type::try_from(*x).ok()))
                                 .collect::<Vec<_>>(),
                         ),
                         ColumnIndexMetaData::BYTE_ARRAY(native_index) => Some(
                             native_index
                                 .$func()
-                                .map(|x| x.map(|x| $convert_func(x)))
+                                .map(|x| x.and_then(|x| $convert_func(x)))
                                 .collect::<Vec<_>>(),
                         ),
                         ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(native_index) => Some(
                             native_index
                                 .$func()
-                                .map(|x| x.map(|x| $convert_func(x)))
+                                .map(|x| {
+                                    x.filter(|x| self.type_length.is_none_or(|len| x.len() == len))
+                                        .and_then(|x| $convert_func(x))
+                                })
                                 .collect::<Vec<_>>(),
                         ),
                         _ => Some(vec![None; len]),
@@ -809,7 +827,7 @@ get_decimal_page_stats_iterator!(
 );
 
 macro_rules! get_data_page_statistics {
-    ($stat_type_prefix: ident, $data_type: ident, $iterator: ident, $physical_type: ident) => {
+    ($stat_type_prefix: ident, $data_type: ident, $iterator: ident, $physical_type: ident, $type_length: ident) => {
         paste! {
             match $data_type {
                 DataType::Boolean => {
@@ -948,7 +966,7 @@ macro_rules! get_data_page_statistics {
                     Ok(Arc::new(builder.finish()))
                 },
                 DataType::Dictionary(_, value_type) => {
-                    [<$stat_type_prefix:lower _ page_statistics>](value_type, $iterator, $physical_type)
+                    [<$stat_type_prefix:lower _ page_statistics>](value_type, $iterator, $physical_type, $type_length)
                 },
                 DataType::Timestamp(unit, timezone) => {
                     let iter = [<$stat_type_prefix Int64DataPageStatsIterator>]::new($iterator).flatten();
@@ -975,13 +993,13 @@ macro_rules! get_data_page_statistics {
                 ),
                 DataType::Date64 if $physical_type == Some(PhysicalType::INT64) => Ok(Arc::new(Date64Array::from_iter([<$stat_type_prefix Int64DataPageStatsIterator>]::new($iterator).flatten()))),
                 DataType::Decimal32(precision, scale) => Ok(Arc::new(
-                    Decimal32Array::from_iter([<$stat_type_prefix Decimal32DataPageStatsIterator>]::new($iterator).flatten()).with_precision_and_scale(*precision, *scale)?)),
+                    Decimal32Array::from_iter([<$stat_type_prefix Decimal32DataPageStatsIterator>]::new($iterator, $type_length).flatten()).with_precision_and_scale(*precision, *scale)?)),
                 DataType::Decimal64(precision, scale) => Ok(Arc::new(
-                    Decimal64Array::from_iter([<$stat_type_prefix Decimal64DataPageStatsIterator>]::new($iterator).flatten()).with_precision_and_scale(*precision, *scale)?)),
+                    Decimal64Array::from_iter([<$stat_type_prefix Decimal64DataPageStatsIterator>]::new($iterator, $type_length).flatten()).with_precision_and_scale(*precision, *scale)?)),
                 DataType::Decimal128(precision, scale) => Ok(Arc::new(
-                    Decimal128Array::from_iter([<$stat_type_prefix Decimal128DataPageStatsIterator>]::new($iterator).flatten()).with_precision_and_scale(*precision, *scale)?)),
+                    Decimal128Array::from_iter([<$stat_type_prefix Decimal128DataPageStatsIterator>]::new($iterator, $type_length).flatten()).with_precision_and_scale(*precision, *scale)?)),
                 DataType::Decimal256(precision, scale) => Ok(Arc::new(
-                    Decimal256Array::from_iter([<$stat_type_prefix Decimal256DataPageStatsIterator>]::new($iterator).flatten()).with_precision_and_scale(*precision, *scale)?)),
+                    Decimal256Array::from_iter([<$stat_type_prefix Decimal256DataPageStatsIterator>]::new($iterator, $type_length).flatten()).with_precision_and_scale(*precision, *scale)?)),
                 DataType::Time32(unit) => {
                     Ok(match unit {
                         TimeUnit::Second =>  Arc::new(Time32SecondArray::from_iter(
@@ -1093,8 +1111,9 @@ fn min_statistics<'a, I: Iterator<Item = Option<&'a ParquetStatistics>>>(
     data_type: &DataType,
     iterator: I,
     physical_type: Option<PhysicalType>,
+    type_length: Option<usize>,
 ) -> Result<ArrayRef> {
-    get_statistics!(Min, data_type, iterator, physical_type)
+    get_statistics!(Min, data_type, iterator, physical_type, type_length)
 }
 
 /// Extracts the max statistics from an iterator of [`ParquetStatistics`] to an [`ArrayRef`]
@@ -1104,8 +1123,9 @@ fn max_statistics<'a, I: Iterator<Item = Option<&'a ParquetStatistics>>>(
     data_type: &DataType,
     iterator: I,
     physical_type: Option<PhysicalType>,
+    type_length: Option<usize>,
 ) -> Result<ArrayRef> {
-    get_statistics!(Max, data_type, iterator, physical_type)
+    get_statistics!(Max, data_type, iterator, physical_type, type_length)
 }
 
 /// Extracts the min statistics from an iterator
@@ -1114,11 +1134,12 @@ pub(crate) fn min_page_statistics<'a, I>(
     data_type: &DataType,
     iterator: I,
     physical_type: Option<PhysicalType>,
+    type_length: Option<usize>,
 ) -> Result<ArrayRef>
 where
     I: Iterator<Item = (usize, &'a ColumnIndexMetaData)>,
 {
-    get_data_page_statistics!(Min, data_type, iterator, physical_type)
+    get_data_page_statistics!(Min, data_type, iterator, physical_type, type_length)
 }
 
 /// Extracts the max statistics from an iterator
@@ -1127,11 +1148,12 @@ pub(crate) fn max_page_statistics<'a, I>(
     data_type: &DataType,
     iterator: I,
     physical_type: Option<PhysicalType>,
+    type_length: Option<usize>,
 ) -> Result<ArrayRef>
 where
     I: Iterator<Item = (usize, &'a ColumnIndexMetaData)>,
 {
-    get_data_page_statistics!(Max, data_type, iterator, physical_type)
+    get_data_page_statistics!(Max, data_type, iterator, physical_type, type_length)
 }
 
 /// Extracts the null count statistics from an iterator
@@ -1152,6 +1174,134 @@ where
     Ok(UInt64Array::from_iter(iter))
 }
 
+/// Statistics for a single data page of a column, as returned by
+/// [`StatisticsConverter::page_statistics_iter`]
+#[derive(Debug, Clone)]
+pub struct PageStats {
+    /// The index of the row group containing the page
+    pub row_group_index: usize,
+    /// The ordinal of the page within its column chunk, or `None` if the row
+    /// group has no offset index and this entry describes the whole column chunk
+    pub page_index: Option<usize>,
+    /// The index of the first row of the page, relative to the start of the file
+    pub first_row_index: u64,
+    /// The number of rows in the page
+    pub row_count: u64,
+    /// An array of length 1 with the minimum value of the page, or a null if unknown
+    pub min: ArrayRef,
+    /// An array of length 1 with the maximum value of the page, or a null if unknown
+    pub max: ArrayRef,
+    /// The number of null values in the page, or `None` if unknown
+    pub null_count: Option<u64>,
+    /// Whether the page only contains null values, `false` if unknown
+    pub is_null_page: bool,
+}
+
+impl PageStats {
+    /// Returns the range of rows of the file covered by this page
+    pub fn row_range(&self) -> Range<u64> {
+        self.first_row_index..self.first_row_index + self.row_count
+    }
+}
+
+/// The name and version of the application that wrote a parquet file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ApplicationVersion<'a> {
+    application: &'a str,
+    version: (u32, u32, u32),
+}
+
+impl<'a> ApplicationVersion<'a> {
+    /// Parse a `created_by` string of the form
+    /// `<application> version <major>.<minor>.<patch> (build <hash>)`
+    fn parse(created_by: &'a str) -> Option<Self> {
+        let (application, version) = created_by.split_once(" version ")?;
+        let version = version.split('(').next()?.trim();
+        // Ignore any suffix such as `-SNAPSHOT`
+        let mut parts = version.splitn(3, '.').map(|part| {
+            let end = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            part[..end].parse::<u32>().ok()
+        });
+        let major = parts.next()??;
+        let minor = parts.next().flatten().unwrap_or(0);
+        let patch = parts.next().flatten().unwrap_or(0);
+        Some(Self {
+            application: application.trim(),
+            version: (major, minor, patch),
+        })
+    }
+
+    /// Returns true if this is `application` before `version`
+    fn is_before(&self, application: &str, version: (u32, u32, u32)) -> bool {
+        self.application == application && self.version < version
+    }
+}
+
+/// Whether the deprecated `min` and `max` statistics of a column written by
+/// an old writer can be trusted, see [`StatisticsConverter::with_ignore_corrupt_statistics`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LegacyMinMax {
+    /// The statistics are correct
+    Valid,
+    /// The statistics were computed with the wrong sort order, and so are
+    /// only correct if the min and max are equal
+    ValidIfEqual,
+    /// The statistics are incorrect
+    Invalid,
+}
+
+impl LegacyMinMax {
+    /// Determine whether the deprecated min and max statistics written by
+    /// `created_by` can be trusted, following the heuristics of parquet-mr and
+    /// parquet-cpp
+    fn new(created_by: &str, physical_type: PhysicalType, sort_order: SortOrder) -> Self {
+        let Some(version) = ApplicationVersion::parse(created_by) else {
+            return Self::Valid;
+        };
+
+        // parquet-mr before 1.8.0 computed incorrect statistics for binary
+        // columns (PARQUET-251)
+        let is_binary = matches!(
+            physical_type,
+            PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY
+        );
+        if is_binary && version.is_before("parquet-mr", (1, 8, 0)) {
+            return Self::Invalid;
+        }
+
+        // parquet-mr before 1.10.0 and parquet-cpp before 1.3.0 always used a
+        // signed comparison (PARQUET-686)
+        if !sort_order.is_signed()
+            && (version.is_before("parquet-mr", (1, 10, 0))
+                || version.is_before("parquet-cpp", (1, 3, 0)))
+        {
+            return Self::ValidIfEqual;
+        }
+        Self::Valid
+    }
+
+    /// Returns `statistics`, or `None` if its min and max can not be trusted
+    fn filter<'b>(
+        &self,
+        statistics: Option<&'b ParquetStatistics>,
+    ) -> Option<&'b ParquetStatistics> {
+        let statistics = statistics?;
+        // Only the deprecated fields were written by the affected writers
+        if !statistics.is_min_max_deprecated() {
+            return Some(statistics);
+        }
+        match self {
+            Self::Valid => Some(statistics),
+            Self::ValidIfEqual => {
+                (statistics.min_bytes_opt() == statistics.max_bytes_opt()).then_some(statistics)
+            }
+            Self::Invalid => None,
+        }
+    }
+}
+
 /// Extracts Parquet statistics as Arrow arrays
 ///
 /// This is used to convert Parquet statistics to Arrow [`ArrayRef`], with
@@ -1181,6 +1331,14 @@ pub struct StatisticsConverter<'a> {
     missing_null_counts_as_zero: bool,
     /// The physical type of the matched column in the Parquet schema
     physical_type: Option<PhysicalType>,
+    /// The length of the matched column in the Parquet schema, if it is a
+    /// `FIXED_LEN_BYTE_ARRAY` column
+    type_length: Option<usize>,
+    /// The sort order of the matched column in the Parquet schema
+    sort_order: Option<SortOrder>,
+    /// The application that wrote the parquet file, if min and max statistics
+    /// known to be incorrect for it should be ignored
+    created_by: Option<&'a str>,
 }
 
 impl<'a> StatisticsConverter<'a> {
@@ -1214,6 +1372,41 @@ impl<'a> StatisticsConverter<'a> {
         self
     }
 
+    /// Set the statistics converter to ignore min and max statistics known to
+    /// be incorrect for files written by `created_by`, as returned by
+    /// [`FileMetaData::created_by`]
+    ///
+    /// Old versions of some writers computed min and max statistics using the
+    /// wrong sort order. In particular parquet-mr before 1.10.0 and parquet-cpp
+    /// before 1.3.0 compared unsigned integers and strings as signed values,
+    /// and parquet-mr before 1.8.0 computed incorrect statistics for all
+    /// binary columns. Using these statistics for pruning can incorrectly
+    /// skip data.
+    ///
+    /// When `created_by` is such a writer, the min and max values of the
+    /// affected row groups are returned as null. As in parquet-mr, statistics
+    /// with equal min and max values are kept, as they do not depend on the
+    /// sort order.
+    ///
+    /// Defaults to `None`, in which case all statistics are returned as is.
+    ///
+    /// [`FileMetaData::created_by`]: crate::file::metadata::FileMetaData::created_by
+    pub fn with_ignore_corrupt_statistics(mut self, created_by: Option<&'a str>) -> Self {
+        self.created_by = created_by;
+        self
+    }
+
+    /// Returns whether the deprecated min and max statistics of the column can
+    /// be trusted
+    fn legacy_min_max(&self) -> LegacyMinMax {
+        match (self.created_by, self.physical_type, self.sort_order) {
+            (Some(created_by), Some(physical_type), Some(sort_order)) => {
+                LegacyMinMax::new(created_by, physical_type, sort_order)
+            }
+            _ => LegacyMinMax::Valid,
+        }
+    }
+
     /// Returns a [`UInt64Array`] with row counts for each row group
     ///
     /// # Return Value
@@ -1309,6 +1502,12 @@ impl<'a> StatisticsConverter<'a> {
             arrow_field,
             missing_null_counts_as_zero: true,
             physical_type: parquet_index.map(|idx| parquet_schema.column(idx).physical_type()),
+            type_length: parquet_index
+                .map(|idx| parquet_schema.column(idx))
+                .filter(|c| c.physical_type() == PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .and_then(|c| usize::try_from(c.type_length()).ok()),
+            sort_order: parquet_index.map(|idx| parquet_schema.column(idx).sort_order()),
+            created_by: None,
         })
     }
 
@@ -1365,10 +1564,11 @@ impl<'a> StatisticsConverter<'a> {
             return Ok(self.make_null_array(data_type, metadatas));
         };
 
+        let legacy_min_max = self.legacy_min_max();
         let iter = metadatas
             .into_iter()
-            .map(|x| x.column(parquet_index).statistics());
-        min_statistics(data_type, iter, self.physical_type)
+            .map(|x| legacy_min_max.filter(x.column(parquet_index).statistics()));
+        min_statistics(data_type, iter, self.physical_type, self.type_length)
     }
 
     /// Extract the maximum values from row group statistics in [`RowGroupMetaData`]
@@ -1384,10 +1584,11 @@ impl<'a> StatisticsConverter<'a> {
             return Ok(self.make_null_array(data_type, metadatas));
         };
 
+        let legacy_min_max = self.legacy_min_max();
         let iter = metadatas
             .into_iter()
-            .map(|x| x.column(parquet_index).statistics());
-        max_statistics(data_type, iter, self.physical_type)
+            .map(|x| legacy_min_max.filter(x.column(parquet_index).statistics()));
+        max_statistics(data_type, iter, self.physical_type, self.type_length)
     }
 
     /// Extract the `is_max_value_exact` flags from row group statistics in [`RowGroupMetaData`]
@@ -1464,6 +1665,36 @@ impl<'a> StatisticsConverter<'a> {
         Ok(UInt64Array::from_iter(null_counts))
     }
 
+    /// Extract the distinct counts from row group statistics in [`RowGroupMetaData`]
+    ///
+    /// The distinct count is optional in the Parquet format and most writers
+    /// do not populate it, in which case the returned value is null. The
+    /// parquet-rs writer populates it for fully dictionary encoded column
+    /// chunks when [`WriterPropertiesBuilder::set_dictionary_distinct_count_enabled`]
+    /// is set.
+    ///
+    /// See docs on [`Self::row_group_mins`] for details
+    ///
+    /// [`WriterPropertiesBuilder::set_dictionary_distinct_count_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_dictionary_distinct_count_enabled
+    pub fn row_group_distinct_counts<I>(&self, metadatas: I) -> Result<UInt64Array>
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        let Some(parquet_index) = self.parquet_column_index else {
+            let num_row_groups = metadatas.into_iter().count();
+            return Ok(UInt64Array::from_iter(std::iter::repeat_n(
+                None,
+                num_row_groups,
+            )));
+        };
+
+        let distinct_counts = metadatas
+            .into_iter()
+            .map(|x| x.column(parquet_index).statistics())
+            .map(|s| s.and_then(|s| s.distinct_count_opt()));
+        Ok(UInt64Array::from_iter(distinct_counts))
+    }
+
     /// Extract the minimum values from Data Page statistics.
     ///
     /// In Parquet files, in addition to the Column Chunk level statistics
@@ -1540,7 +1771,7 @@ impl<'a> StatisticsConverter<'a> {
             (*num_data_pages, column_page_index_per_row_group_per_column)
         });
 
-        min_page_statistics(data_type, iter, self.physical_type)
+        min_page_statistics(data_type, iter, self.physical_type, self.type_length)
     }
 
     /// Extract the maximum values from Data Page statistics.
@@ -1571,7 +1802,7 @@ impl<'a> StatisticsConverter<'a> {
             (*num_data_pages, column_page_index_per_row_group_per_column)
         });
 
-        max_page_statistics(data_type, iter, self.physical_type)
+        max_page_statistics(data_type, iter, self.physical_type, self.type_length)
     }
 
     /// Returns a [`UInt64Array`] with null counts for each data page.
@@ -1662,6 +1893,211 @@ impl<'a> StatisticsConverter<'a> {
         Ok(Some(UInt64Array::from_iter(row_count_total)))
     }
 
+    /// Returns the statistics of each data page of this column across all row
+    /// groups of a file, in file order.
+    ///
+    /// This combines the [`ParquetColumnIndex`], [`ParquetOffsetIndex`] and the
+    /// row group row counts of `metadata`, so that the row ranges of the returned
+    /// [`PageStats`] cover every row of the file exactly once.
+    ///
+    /// Missing page indexes are handled as follows:
+    /// * if the offset index is not present for a row group, a single entry with
+    ///   unknown statistics covers the whole row group
+    /// * if the column index is not present for a row group, or does not match
+    ///   the offset index, the min, max and null counts of its pages are unknown
+    ///
+    /// If the column is not present in the parquet file, the statistics of
+    /// all entries are unknown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arrow::datatypes::Schema;
+    /// # use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+    /// # use parquet::file::metadata::ParquetMetaData;
+    /// # fn get_parquet_metadata() -> ParquetMetaData { unimplemented!() }
+    /// # fn get_arrow_schema() -> Schema { unimplemented!() }
+    /// // Given the metadata for a parquet file, including the page index
+    /// let metadata: ParquetMetaData = get_parquet_metadata();
+    /// let arrow_schema: Schema = get_arrow_schema();
+    /// let parquet_schema = metadata.file_metadata().schema_descr();
+    /// let converter = StatisticsConverter::try_new("foo", &arrow_schema, parquet_schema)
+    ///   .unwrap();
+    /// for page in converter.page_statistics_iter(&metadata).unwrap() {
+    ///     println!("rows {:?}: min {:?} max {:?}", page.row_range(), page.min, page.max);
+    /// }
+    /// ```
+    pub fn page_statistics_iter(
+        &self,
+        metadata: &ParquetMetaData,
+    ) -> Result<impl Iterator<Item = PageStats> + use<>> {
+        let data_type = self.arrow_field.data_type();
+        let mut pages = Vec::new();
+        let mut first_row_index = 0_u64;
+
+        for (rg_index, row_group) in metadata.row_groups().iter().enumerate() {
+            let num_rows = row_group.num_rows();
+            let num_rows: u64 = num_rows.try_into().map_err(|e| {
+                arrow_err!(format!(
+                    "Parquet row count {num_rows} too large to convert to u64: {e}"
+                ))
+            })?;
+
+            let locations = self.parquet_column_index.and_then(|parquet_index| {
+                let offset_index = metadata.offset_index()?.get(rg_index)?.get(parquet_index)?;
+                let locations = offset_index.page_locations();
+                (!locations.is_empty()).then_some(locations)
+            });
+
+            let Some(locations) = locations else {
+                pages.push(PageStats {
+                    row_group_index: rg_index,
+                    page_index: None,
+                    first_row_index,
+                    row_count: num_rows,
+                    min: new_null_array(data_type, 1),
+                    max: new_null_array(data_type, 1),
+                    null_count: None,
+                    is_null_page: false,
+                });
+                first_row_index += num_rows;
+                continue;
+            };
+
+            let num_pages = locations.len();
+            let column_index = self
+                .parquet_column_index
+                .and_then(|parquet_index| {
+                    metadata.column_index()?.get(rg_index)?.get(parquet_index)
+                })
+                .filter(|index| match index {
+                    ColumnIndexMetaData::NONE => false,
+                    index => index.num_pages() as usize == num_pages,
+                })
+                .unwrap_or(&ColumnIndexMetaData::NONE);
+
+            let page_index_iter = std::iter::once((num_pages, column_index));
+            let mins = min_page_statistics(
+                data_type,
+                page_index_iter.clone(),
+                self.physical_type,
+                self.type_length,
+            )?;
+            let maxes = max_page_statistics(
+                data_type,
+                page_index_iter.clone(),
+                self.physical_type,
+                self.type_length,
+            )?;
+            let null_counts = null_counts_page_statistics(page_index_iter)?;
+
+            for (page_index, location) in locations.iter().enumerate() {
+                let start = location.first_row_index as u64;
+                let end = match locations.get(page_index + 1) {
+                    Some(next) => next.first_row_index as u64,
+                    None => num_rows,
+                };
+                if start > end || end > num_rows {
+                    return Err(arrow_err!(format!(
+                        "Invalid offset index for row group {rg_index}: page {page_index} \
+                         covers rows {start}..{end} of {num_rows}"
+                    )));
+                }
+                let is_null_page = match column_index {
+                    ColumnIndexMetaData::NONE => false,
+                    index => index.is_null_page(page_index),
+                };
+                pages.push(PageStats {
+                    row_group_index: rg_index,
+                    page_index: Some(page_index),
+                    first_row_index: first_row_index + start,
+                    row_count: end - start,
+                    min: mins.slice(page_index, 1),
+                    max: maxes.slice(page_index, 1),
+                    null_count: null_counts
+                        .is_valid(page_index)
+                        .then(|| null_counts.value(page_index)),
+                    is_null_page,
+                });
+            }
+            first_row_index += num_rows;
+        }
+
+        Ok(pages.into_iter())
+    }
+
+    /// Returns a [`RowSelection`] over the rows of `row_groups` that skips the
+    /// data pages of this column for which `predicate` returns `false`.
+    ///
+    /// `predicate` is called with the [`PageStats`] of each page of the row
+    /// groups, see [`Self::page_statistics_iter`], and should return `true`
+    /// if the page may contain matching rows. Pages with unknown statistics
+    /// are also passed to `predicate`, which will usually keep them.
+    ///
+    /// The returned selection covers the rows of `row_groups` in the given
+    /// order, and so can be passed to [`ArrowReaderBuilder::with_row_selection`]
+    /// along with the same [`ArrowReaderBuilder::with_row_groups`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arrow::datatypes::Schema;
+    /// # use arrow_array::Array;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::TimestampMicrosecondType;
+    /// # use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+    /// # use parquet::file::metadata::ParquetMetaData;
+    /// # fn get_parquet_metadata() -> ParquetMetaData { unimplemented!() }
+    /// # fn get_arrow_schema() -> Schema { unimplemented!() }
+    /// // Given the metadata for a parquet file, including the page index
+    /// let metadata: ParquetMetaData = get_parquet_metadata();
+    /// let arrow_schema: Schema = get_arrow_schema();
+    /// let parquet_schema = metadata.file_metadata().schema_descr();
+    /// let converter = StatisticsConverter::try_new("ts", &arrow_schema, parquet_schema)
+    ///   .unwrap();
+    /// // skip the pages that can not contain rows with `ts > 50_000`
+    /// let row_groups: Vec<_> = (0..metadata.num_row_groups()).collect();
+    /// let selection = converter
+    ///     .page_row_selection(&metadata, &row_groups, |page| {
+    ///         let max = page.max.as_primitive::<TimestampMicrosecondType>();
+    ///         max.is_null(0) || max.value(0) > 50_000
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`ArrowReaderBuilder::with_row_selection`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_selection
+    /// [`ArrowReaderBuilder::with_row_groups`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_groups
+    pub fn page_row_selection<F>(
+        &self,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+        mut predicate: F,
+    ) -> Result<RowSelection>
+    where
+        F: FnMut(&PageStats) -> bool,
+    {
+        let mut row_group_pages = vec![vec![]; metadata.num_row_groups()];
+        for page in self.page_statistics_iter(metadata)? {
+            row_group_pages[page.row_group_index].push(page);
+        }
+
+        let mut selectors = Vec::new();
+        for &row_group in row_groups {
+            let pages = row_group_pages.get(row_group).ok_or_else(|| {
+                arrow_err!(format!(
+                    "Row group {row_group} out of bounds, file has {} row groups",
+                    metadata.num_row_groups()
+                ))
+            })?;
+            for page in pages {
+                let row_count = page.row_count as usize;
+                selectors.push(match predicate(page) {
+                    true => RowSelector::select(row_count),
+                    false => RowSelector::skip(row_count),
+                });
+            }
+        }
+        Ok(selectors.into())
+    }
+
     /// Returns a null array of data_type with one element per row group
     fn make_null_array<I, A>(&self, data_type: &DataType, metadatas: I) -> ArrayRef
     where
@@ -33,6 +33,7 @@ use arrow_array::{
     LargeBinaryArray, LargeStringArray, StringArray, StringViewArray,
 };
 use arrow_schema::DataType;
+use hashbrown::HashMap;
 
 macro_rules! downcast_dict_impl {
     ($array:ident, $key:ident, $val:ident, $op:expr $(, $arg:expr)*) => {{
@@ -127,13 +128,12 @@ impl FallbackEncoder {
     /// Create the fallback encoder for the given [`ColumnDescPtr`] and [`WriterProperties`]
     fn new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
         // Set either main encoder or fallback encoder.
-        let encoding =
-            props
-                .encoding(descr.path())
-                .unwrap_or_else(|| match props.writer_version() {
-                    WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
-                    WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
-                });
+        let encoding = props
+            .value_encoding(descr.path(), props.dictionary_enabled(descr.path()))
+            .unwrap_or_else(|| match props.writer_version() {
+                WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
+                WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
+            });
 
         let encoder = match encoding {
             Encoding::PLAIN => FallbackEncoderImpl::Plain { buffer: vec![] },
@@ -337,9 +337,18 @@ struct DictEncoder {
     interner: Interner<ByteArrayStorage>,
     indices: Vec<u64>,
     variable_length_bytes: i64,
+    /// If deduplicating views, the keys of the views seen in the array being encoded
+    view_keys: Option<HashMap<u128, u64, ahash::RandomState>>,
 }
 
 impl DictEncoder {
+    fn new(deduplicate_views: bool) -> Self {
+        Self {
+            view_keys: deduplicate_views.then(Default::default),
+            ..Default::default()
+        }
+    }
+
     /// Encode `values` to the in-progress page
     fn encode<T>(&mut self, values: T, indices: &[usize])
     where
@@ -348,6 +357,22 @@ impl DictEncoder {
     {
         self.indices.reserve(indices.len());
 
+        if let (Some(view_keys), Some(views)) = (&mut self.view_keys, views(&values)) {
+            // Equal views refer to equal values, so each distinct view only
+            // needs to be interned once. Views are only comparable within
+            // the same array, so the keys are not retained across calls
+            view_keys.clear();
+            for idx in indices {
+                let view = views[*idx];
+                let interned = *view_keys
+                    .entry(view)
+                    .or_insert_with(|| self.interner.intern(values.value(*idx).as_ref()));
+                self.indices.push(interned);
+                self.variable_length_bytes += (view as u32) as i64;
+            }
+            return;
+        }
+
         for idx in indices {
             let value = values.value(*idx);
             let interned = self.interner.intern(value.as_ref());
@@ -362,7 +387,12 @@ impl DictEncoder {
     }
 
     fn estimated_memory_size(&self) -> usize {
-        self.interner.estimated_memory_size() + self.indices.capacity() * std::mem::size_of::<u64>()
+        let view_keys_size = self.view_keys.as_ref().map_or(0, |keys| {
+            keys.capacity() * std::mem::size_of::<(u128, u64)>()
+        });
+        self.interner.estimated_memory_size()
+            + self.indices.capacity() * std::mem::size_of::<u64>()
+            + view_keys_size
     }
 
     fn estimated_data_page_size(&self) -> usize {
@@ -419,6 +449,10 @@ impl DictEncoder {
 pub struct ByteArrayEncoder {
     fallback: FallbackEncoder,
     dict_encoder: Option<DictEncoder>,
+    /// Values longer than this are not dictionary encoded
+    dict_max_value_length: Option<usize>,
+    /// Set if a value longer than `dict_max_value_length` has been written
+    dict_fallback: bool,
     statistics_enabled: EnabledStatistics,
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
@@ -437,9 +471,10 @@ impl ColumnValueEncoder for ByteArrayEncoder {
     where
         Self: Sized,
     {
+        let deduplicate_views = props.dictionary_deduplicate_views(descr.path());
         let dictionary = props
             .dictionary_enabled(descr.path())
-            .then(DictEncoder::default);
+            .then(|| DictEncoder::new(deduplicate_views));
 
         let fallback = FallbackEncoder::new(descr, props)?;
 
@@ -457,6 +492,8 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             statistics_enabled,
             bloom_filter,
             dict_encoder: dictionary,
+            dict_max_value_length: props.dictionary_max_value_length(descr.path()),
+            dict_fallback: false,
             min_value: None,
             max_value: None,
             geo_stats_accumulator,
@@ -507,6 +544,10 @@ impl ColumnValueEncoder for ByteArrayEncoder {
         Some(self.dict_encoder.as_ref()?.estimated_dict_page_size())
     }
 
+    fn should_dict_fallback(&self) -> bool {
+        self.dict_fallback
+    }
+
     /// Returns an estimate of the data page size in bytes
     ///
     /// This includes:
@@ -579,11 +620,32 @@ where
     }
 
     match &mut encoder.dict_encoder {
-        Some(dict_encoder) => dict_encoder.encode(values, indices),
+        Some(dict_encoder) => {
+            if let Some(max_length) = encoder
+                .dict_max_value_length
+                .filter(|_| !encoder.dict_fallback)
+            {
+                encoder.dict_fallback = indices
+                    .iter()
+                    .any(|idx| values.value(*idx).as_ref().len() > max_length);
+            }
+            dict_encoder.encode(values, indices)
+        }
         None => encoder.fallback.encode(values, indices),
     }
 }
 
+/// Returns the views of `values` if it is a [`StringViewArray`] or [`BinaryViewArray`]
+fn views<T: Array>(values: &T) -> Option<&[u128]> {
+    let values = values.as_any();
+    if let Some(array) = values.downcast_ref::<StringViewArray>() {
+        return Some(array.views());
+    }
+    values
+        .downcast_ref::<BinaryViewArray>()
+        .map(|array| array.views().as_ref())
+}
+
 /// Computes the min and max for the provided array and indices
 ///
 /// This is a free function so it can be used with `downcast_op!`
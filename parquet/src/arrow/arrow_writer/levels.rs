@@ -42,9 +42,9 @@
 
 use crate::errors::{ParquetError, Result};
 use arrow_array::cast::AsArray;
-use arrow_array::{Array, ArrayRef, OffsetSizeTrait};
+use arrow_array::{Array, ArrayRef, OffsetSizeTrait, downcast_run_array};
 use arrow_buffer::bit_iterator::BitIndexIterator;
-use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
 use arrow_schema::{DataType, Field};
 use std::ops::Range;
 use std::sync::Arc;
@@ -133,6 +133,11 @@ enum LevelInfoBuilder {
     ),
     /// A struct array
     Struct(Vec<LevelInfoBuilder>, LevelContext, Option<NullBuffer>),
+    /// A run-end encoded array of primitive values
+    RunEndEncoded(
+        ArrayLevels, // Levels of the run values
+        Vec<usize>,  // Run ends, relative to the logical start of the array
+    ),
 }
 
 impl LevelInfoBuilder {
@@ -158,6 +163,26 @@ impl LevelInfoBuilder {
                 let levels = ArrayLevels::new(parent_ctx, is_nullable, array.clone());
                 Ok(Self::Primitive(levels))
             }
+            DataType::RunEndEncoded(_, v) if is_leaf(v.data_type()) => {
+                // Write the run values directly, rather than expanding the runs,
+                // mapping logical indices to the run containing them
+                let (run_ends, values) = downcast_run_array!(
+                    array => {
+                        let offset = array.offset();
+                        let run_ends = array
+                            .run_ends()
+                            .values()
+                            .iter()
+                            .map(|end| end.as_usize().saturating_sub(offset))
+                            .collect();
+                        (run_ends, array.values().clone())
+                    },
+                    _ => unreachable!()
+                );
+                let mut levels = ArrayLevels::new(parent_ctx, is_nullable, values);
+                levels.logical_nulls = array.logical_nulls();
+                Ok(Self::RunEndEncoded(levels, run_ends))
+            }
             DataType::Struct(children) => {
                 let array = array.as_struct();
                 let def_level = match is_nullable {
@@ -230,7 +255,7 @@ impl LevelInfoBuilder {
     /// as enumerated by a depth-first search
     fn finish(self) -> Vec<ArrayLevels> {
         match self {
-            LevelInfoBuilder::Primitive(v) => vec![v],
+            LevelInfoBuilder::Primitive(v) | LevelInfoBuilder::RunEndEncoded(v, _) => vec![v],
             LevelInfoBuilder::List(v, _, _, _)
             | LevelInfoBuilder::LargeList(v, _, _, _)
             | LevelInfoBuilder::FixedSizeList(v, _, _, _) => v.finish(),
@@ -242,6 +267,9 @@ impl LevelInfoBuilder {
     fn write(&mut self, range: Range<usize>) {
         match self {
             LevelInfoBuilder::Primitive(info) => Self::write_leaf(info, range),
+            LevelInfoBuilder::RunEndEncoded(info, run_ends) => {
+                Self::write_run_end_encoded(info, run_ends, range)
+            }
             LevelInfoBuilder::List(child, ctx, offsets, nulls) => {
                 Self::write_list(child, ctx, offsets, nulls.as_ref(), range)
             }
@@ -529,10 +557,22 @@ impl LevelInfoBuilder {
         }
     }
 
+    /// Write a run-end encoded array, translating the logical indices of the
+    /// non-null values into the physical indices of the runs that contain them
+    fn write_run_end_encoded(info: &mut ArrayLevels, run_ends: &[usize], range: Range<usize>) {
+        let start = info.non_null_indices.len();
+        Self::write_leaf(info, range);
+        for idx in &mut info.non_null_indices[start..] {
+            *idx = run_ends.partition_point(|end| *end <= *idx);
+        }
+    }
+
     /// Visits all children of this node in depth first order
     fn visit_leaves(&mut self, visit: impl Fn(&mut ArrayLevels) + Copy) {
         match self {
-            LevelInfoBuilder::Primitive(info) => visit(info),
+            LevelInfoBuilder::Primitive(info) | LevelInfoBuilder::RunEndEncoded(info, _) => {
+                visit(info)
+            }
             LevelInfoBuilder::List(c, _, _, _)
             | LevelInfoBuilder::LargeList(c, _, _, _)
             | LevelInfoBuilder::FixedSizeList(c, _, _, _) => c.visit_leaves(visit),
@@ -779,6 +819,31 @@ mod tests {
         assert_eq!(&levels[0], &expected_levels);
     }
 
+    #[test]
+    fn test_calculate_run_end_encoded_levels() {
+        // [1, 1, null, 2, 2, 2] sliced to [1, null, 2, 2]
+        let values = Int32Array::from(vec![Some(1), None, Some(2)]);
+        let run_ends = Int32Array::from(vec![2, 3, 6]);
+        let run_array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(run_array.slice(1, 4)) as ArrayRef;
+        let field = Field::new("item", array.data_type().clone(), true);
+
+        let levels = calculate_array_levels(&array, &field).unwrap();
+        assert_eq!(levels.len(), 1);
+
+        // Non-null indices refer to the runs, rather than the logical rows
+        let expected_levels = ArrayLevels {
+            def_levels: Some(vec![1, 0, 1, 1]),
+            rep_levels: None,
+            non_null_indices: vec![0, 2, 2],
+            max_def_level: 1,
+            max_rep_level: 0,
+            array: Arc::new(values),
+            logical_nulls: array.logical_nulls(),
+        };
+        assert_eq!(&levels[0], &expected_levels);
+    }
+
     #[test]
     fn test_calculate_array_levels_1() {
         let leaf_field = Field::new_list_field(DataType::Int32, false);
@@ -20,16 +20,25 @@
 use bytes::Bytes;
 use std::io::{Read, Write};
 use std::iter::Peekable;
+use std::ops::Range;
 use std::slice::Iter;
 use std::sync::{Arc, Mutex};
 use std::vec::IntoIter;
 
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
-use arrow_array::{ArrayRef, RecordBatch, RecordBatchWriter};
-use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, IntervalUnit, SchemaRef};
+use arrow_array::{
+    Array, ArrayRef, ArrowNativeTypeOp, RecordBatch, RecordBatchReader, RecordBatchWriter,
+    downcast_primitive_array,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{
+    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit,
+};
 
-use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
+use super::schema::{
+    add_encoded_arrow_schema_to_metadata, add_extension_metadata, decimal_length_from_precision,
+};
 
 use crate::arrow::ArrowSchemaConverter;
 use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
@@ -39,7 +48,7 @@ use crate::column::writer::encoder::ColumnValueEncoder;
 use crate::column::writer::{
     ColumnCloseResult, ColumnWriter, GenericColumnWriter, get_column_writer,
 };
-use crate::data_type::{ByteArray, FixedLenByteArray};
+use crate::data_type::{ByteArray, FixedLenByteArray, Int96};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptor;
 use crate::errors::{ParquetError, Result};
@@ -118,15 +127,18 @@ mod levels;
 /// The writer supports writing all Arrow [`DataType`]s that have a direct mapping to
 /// Parquet types including  [`StructArray`] and [`ListArray`].
 ///
-/// The following are not supported:
-///
-/// * [`IntervalMonthDayNanoArray`]: Parquet does not [support nanosecond intervals].
+/// [`IntervalMonthDayNanoArray`]s are written as parquet [`INTERVAL`]s, which store
+/// milliseconds rather than nanoseconds. Writing a value with sub-millisecond precision
+/// returns an error, unless [`WriterPropertiesBuilder::set_truncate_interval_nanoseconds`]
+/// is enabled. When the Arrow schema is embedded in the file, such columns are read back
+/// as `MonthDayNano` intervals.
 ///
 /// [`DataType`]: https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html
 /// [`StructArray`]: https://docs.rs/arrow/latest/arrow/array/struct.StructArray.html
 /// [`ListArray`]: https://docs.rs/arrow/latest/arrow/array/type.ListArray.html
 /// [`IntervalMonthDayNanoArray`]: https://docs.rs/arrow/latest/arrow/array/type.IntervalMonthDayNanoArray.html
-/// [support nanosecond intervals]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#interval
+/// [`INTERVAL`]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#interval
+/// [`WriterPropertiesBuilder::set_truncate_interval_nanoseconds`]: crate::file::properties::WriterPropertiesBuilder::set_truncate_interval_nanoseconds
 ///
 /// ## Type Compatibility
 /// The writer can write Arrow [`RecordBatch`]s that are logically equivalent. This means that for
@@ -187,6 +199,9 @@ pub struct ArrowWriter<W: Write> {
 
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
+
+    /// Whether to check that the keys of maps with sorted keys are sorted
+    validate_sorted_map_keys: bool,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -232,7 +247,11 @@ impl<W: Write + Send> ArrowWriter<W> {
         let schema = if let Some(parquet_schema) = options.schema_descr {
             parquet_schema.clone()
         } else {
-            let mut converter = ArrowSchemaConverter::new().with_coerce_types(props.coerce_types());
+            let mut converter = ArrowSchemaConverter::new()
+                .with_coerce_types(props.coerce_types())
+                .with_int96_timestamps(props.int96_timestamps())
+                .with_decimal256_byte_width(props.decimal256_byte_width())
+                .with_fixed_len_byte_array_decimals(props.fixed_len_byte_array_decimals());
             if let Some(schema_root) = &options.schema_root {
                 converter = converter.schema_root(schema_root);
             }
@@ -244,6 +263,8 @@ impl<W: Write + Send> ArrowWriter<W> {
             // add serialized arrow schema
             add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
         }
+        // add extension types not represented by a parquet logical type
+        add_extension_metadata(&arrow_schema, &mut props);
 
         let max_row_group_size = props.max_row_group_size();
 
@@ -260,6 +281,7 @@ impl<W: Write + Send> ArrowWriter<W> {
             arrow_schema,
             row_group_writer_factory,
             max_row_group_size,
+            validate_sorted_map_keys: options.validate_sorted_map_keys,
         })
     }
 
@@ -304,11 +326,25 @@ impl<W: Write + Send> ArrowWriter<W> {
             .unwrap_or_default()
     }
 
+    /// Returns the maximum number of rows in each row group
+    #[cfg(feature = "async")]
+    pub(crate) fn max_row_group_size(&self) -> usize {
+        self.max_row_group_size
+    }
+
     /// Returns the number of bytes written by this instance
     pub fn bytes_written(&self) -> usize {
         self.writer.bytes_written()
     }
 
+    /// Returns the number of bytes handed to the underlying writer
+    ///
+    /// Unlike [`Self::bytes_written`] this excludes any encoded bytes still
+    /// held in an internal buffer, and so is less than or equal to it.
+    pub fn flushed_bytes(&self) -> usize {
+        self.writer.flushed_bytes()
+    }
+
     /// Encodes the provided [`RecordBatch`]
     ///
     /// If this would cause the current row group to exceed [`WriterProperties::max_row_group_size`]
@@ -338,6 +374,12 @@ impl<W: Write + Send> ArrowWriter<W> {
             return self.write(&b);
         }
 
+        if self.validate_sorted_map_keys {
+            for column in batch.columns() {
+                validate_sorted_map_keys(column.as_ref())?;
+            }
+        }
+
         in_progress.write(batch)?;
 
         if in_progress.buffered_rows >= self.max_row_group_size {
@@ -346,6 +388,75 @@ impl<W: Write + Send> ArrowWriter<W> {
         Ok(())
     }
 
+    /// Encodes all [`RecordBatch`]es produced by `reader`, returning the number of rows written
+    ///
+    /// The schema of each batch is checked against the writer's schema with
+    /// [`Self::check_schema`] before it is written. As with [`Self::write`], batches are
+    /// split so that all but the final row group contain exactly
+    /// [`WriterProperties::max_row_group_size`] rows, regardless of the size of the batches
+    /// returned by `reader`.
+    ///
+    /// This does not close the writer, call [`Self::close`] once all data has been written.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int64Array, RecordBatch, RecordBatchIterator};
+    /// # use parquet::arrow::ArrowWriter;
+    /// let col = Arc::new(Int64Array::from_iter_values(0..100)) as ArrayRef;
+    /// let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+    /// let schema = batch.schema();
+    /// let reader = RecordBatchIterator::new(vec![Ok(batch.clone()), Ok(batch)], schema.clone());
+    ///
+    /// let mut writer = ArrowWriter::try_new(Vec::new(), schema, None).unwrap();
+    /// assert_eq!(writer.write_reader(reader).unwrap(), 200);
+    /// writer.close().unwrap();
+    /// ```
+    pub fn write_reader(&mut self, reader: impl RecordBatchReader) -> Result<usize> {
+        let mut rows = 0;
+        for batch in reader {
+            let batch = batch?;
+            self.check_schema(batch.schema_ref())?;
+            self.write(&batch)?;
+            rows += batch.num_rows();
+        }
+        Ok(rows)
+    }
+
+    /// Verifies that `schema` is compatible with the writer's schema
+    ///
+    /// The schemas are compatible if they have the same number of fields, and each field
+    /// has the same name and data type. Nullability and metadata, including that of nested
+    /// fields, are ignored.
+    pub fn check_schema(&self, schema: &Schema) -> Result<()> {
+        let expected = self.arrow_schema.fields();
+        if schema.fields().len() != expected.len() {
+            return Err(general_err!(
+                "Incompatible schema, expected {} fields but got {}",
+                expected.len(),
+                schema.fields().len()
+            ));
+        }
+        for (idx, (expected, actual)) in expected.iter().zip(schema.fields()).enumerate() {
+            if expected.name() != actual.name() {
+                return Err(general_err!(
+                    "Incompatible schema, field {} should be named '{}' but got '{}'",
+                    idx,
+                    expected.name(),
+                    actual.name()
+                ));
+            }
+            if !expected.data_type().equals_datatype(actual.data_type()) {
+                return Err(general_err!(
+                    "Incompatible schema, field '{}' should have type {} but got {}",
+                    expected.name(),
+                    expected.data_type(),
+                    actual.data_type()
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Writes the given buf bytes to the internal buffer.
     ///
     /// It's safe to use this method to write data to the underlying writer,
@@ -377,6 +488,42 @@ impl<W: Write + Send> ArrowWriter<W> {
         Ok(())
     }
 
+    /// Start a new row group that is written one column at a time
+    ///
+    /// This flushes any buffered rows, and returns a [`ColumnwiseRowGroupWriter`]
+    /// to which the data for each column can be written independently, without
+    /// first assembling a [`RecordBatch`]. See [`ColumnwiseRowGroupWriter`] for
+    /// more details.
+    pub fn start_columnwise_row_group(&mut self) -> Result<ColumnwiseRowGroupWriter<'_, W>> {
+        self.flush()?;
+        let row_group = self
+            .row_group_writer_factory
+            .create_row_group_writer(self.writer.flushed_row_groups().len())?;
+
+        // The leaf columns of each field are contiguous in the parquet schema
+        let schema_descr = self.writer.schema_descr();
+        let mut leaf_ranges = Vec::with_capacity(self.arrow_schema.fields().len());
+        let mut start = 0;
+        for root_idx in 0..self.arrow_schema.fields().len() {
+            let mut end = start;
+            while end < schema_descr.num_columns()
+                && schema_descr.get_column_root_idx(end) == root_idx
+            {
+                end += 1;
+            }
+            leaf_ranges.push(start..end);
+            start = end;
+        }
+
+        Ok(ColumnwiseRowGroupWriter {
+            rows: vec![0; leaf_ranges.len()],
+            poisoned: None,
+            leaf_ranges,
+            row_group,
+            writer: self,
+        })
+    }
+
     /// Additional [`KeyValue`] metadata to be written in addition to those from [`WriterProperties`]
     ///
     /// This method provide a way to append kv_metadata after write RecordBatch
@@ -474,6 +621,179 @@ impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
     }
 }
 
+/// Writes a single row group one column at a time
+///
+/// Created by [`ArrowWriter::start_columnwise_row_group`], this allows writing
+/// data that is produced column by column without first assembling a
+/// [`RecordBatch`]. The data for each column can be written in one or more
+/// chunks, and the columns can be written in any order.
+///
+/// Once all columns have been written, [`Self::finalize`] verifies that every
+/// column received the same number of rows and appends the row group to the
+/// file, with its column chunks in schema order. If the row counts differ, an
+/// error is returned and the row group is discarded, leaving the file
+/// unchanged. Dropping this writer without calling [`Self::finalize`] also
+/// discards the row group.
+///
+/// If writing the data of a column fails partway through, for example after
+/// only some of the leaf columns of a nested column have been written, the
+/// column is left in an inconsistent state. All subsequent calls to
+/// [`Self::write_column`] and [`Self::finalize`] then return an error, and the
+/// row group is discarded.
+///
+/// Note: the row group is always written as a single row group, regardless of
+/// [`WriterProperties::max_row_group_size`]
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use parquet::arrow::ArrowWriter;
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("id", DataType::Int32, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]));
+/// let mut writer = ArrowWriter::try_new(Vec::new(), schema, None).unwrap();
+///
+/// let mut row_group = writer.start_columnwise_row_group().unwrap();
+/// // columns can be written in any order, in one or more chunks
+/// let names: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None, Some("c")]));
+/// row_group.write_column(1, &names).unwrap();
+/// let ids: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// row_group.write_column(0, &ids).unwrap();
+/// let ids: ArrayRef = Arc::new(Int32Array::from(vec![3]));
+/// row_group.write_column(0, &ids).unwrap();
+/// row_group.finalize().unwrap();
+///
+/// let metadata = writer.close().unwrap();
+/// assert_eq!(metadata.file_metadata().num_rows(), 3);
+/// ```
+pub struct ColumnwiseRowGroupWriter<'a, W: Write + Send> {
+    writer: &'a mut ArrowWriter<W>,
+    row_group: ArrowRowGroupWriter,
+    /// The range of leaf column writers for each field in the arrow schema
+    leaf_ranges: Vec<Range<usize>>,
+    /// The number of rows written to each field in the arrow schema
+    rows: Vec<usize>,
+    /// The index of the column that failed while being written, if any
+    poisoned: Option<usize>,
+}
+
+impl<W: Write + Send> std::fmt::Debug for ColumnwiseRowGroupWriter<'_, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnwiseRowGroupWriter")
+            .field("rows", &self.rows)
+            .field("poisoned", &self.poisoned)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write + Send> ColumnwiseRowGroupWriter<'_, W> {
+    /// Write `array` to the column at index `column` in the arrow schema
+    ///
+    /// This may be called multiple times for the same column, in which case
+    /// the arrays are appended to the column in the order they are written.
+    ///
+    /// Returns an error if `column` is out of bounds or the data type of
+    /// `array` does not match the field in the arrow schema. If the error
+    /// occurs while writing the data of `array`, this writer can no longer be
+    /// used, see [`Self`].
+    pub fn write_column(&mut self, column: usize, array: &ArrayRef) -> Result<()> {
+        self.check_poisoned()?;
+        let schema = &self.row_group.schema;
+        let field = schema.fields().get(column).ok_or_else(|| {
+            general_err!(
+                "Column index {} out of bounds for schema with {} columns",
+                column,
+                schema.fields().len()
+            )
+        })?;
+        if array.data_type() != field.data_type() {
+            return Err(arrow_err!(
+                "Incompatible type for column \"{}\": expected {} but got {}",
+                field.name(),
+                field.data_type(),
+                array.data_type()
+            ));
+        }
+
+        let leaves = compute_leaves(field.as_ref(), array)?;
+        let writers = &mut self.row_group.writers[self.leaf_ranges[column].clone()];
+        for (writer, leaf) in writers.iter_mut().zip(&leaves) {
+            if let Err(e) = writer.write(leaf) {
+                // Some of the values of `array` may have been written
+                self.poisoned = Some(column);
+                return Err(e);
+            }
+        }
+        self.rows[column] += array.len();
+        Ok(())
+    }
+
+    /// Returns the number of rows written to the column at index `column`, or
+    /// `None` if `column` is out of bounds
+    pub fn column_rows(&self, column: usize) -> Option<usize> {
+        self.rows.get(column).copied()
+    }
+
+    /// Returns an error if a previous call to [`Self::write_column`] failed
+    /// while writing the data of a column
+    fn check_poisoned(&self) -> Result<()> {
+        match self.poisoned {
+            Some(idx) => Err(general_err!(
+                "Cannot use row group writer after column \"{}\" failed partway through being written",
+                self.row_group.schema.field(idx).name()
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the estimated memory usage of the columns buffered so far
+    pub fn memory_size(&self) -> usize {
+        self.row_group.writers.iter().map(|x| x.memory_size()).sum()
+    }
+
+    /// Verify every column has the same number of rows, and append the
+    /// row group to the file
+    ///
+    /// Returns an error if the columns have a different number of rows, or
+    /// this writer is unusable after an earlier error, in which case the row
+    /// group is discarded and nothing is written to the file. If no rows have
+    /// been written, no row group is added.
+    pub fn finalize(self) -> Result<()> {
+        self.check_poisoned()?;
+        let Self {
+            writer,
+            row_group,
+            rows,
+            ..
+        } = self;
+
+        let num_rows = rows.first().copied().unwrap_or_default();
+        let fields = row_group.schema.fields();
+        if let Some(idx) = rows.iter().position(|x| *x != num_rows) {
+            return Err(general_err!(
+                "Cannot write row group with inconsistent column lengths: column \"{}\" has {} rows but column \"{}\" has {} rows",
+                fields[0].name(),
+                num_rows,
+                fields[idx].name(),
+                rows[idx]
+            ));
+        }
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        let mut row_group_writer = writer.writer.next_row_group()?;
+        for chunk in row_group.close()? {
+            chunk.append_to_row_group(&mut row_group_writer)?;
+        }
+        row_group_writer.close()?;
+        Ok(())
+    }
+}
+
 /// Arrow-specific configuration settings for writing parquet files.
 ///
 /// See [`ArrowWriter`] for how to configure the writer.
@@ -483,6 +803,7 @@ pub struct ArrowWriterOptions {
     skip_arrow_metadata: bool,
     schema_root: Option<String>,
     schema_descr: Option<SchemaDescriptor>,
+    validate_sorted_map_keys: bool,
 }
 
 impl ArrowWriterOptions {
@@ -502,6 +823,12 @@ impl ArrowWriterOptions {
     /// by default.
     ///
     /// Set `skip_arrow_metadata` to true, to skip encoding the embedded metadata.
+    ///
+    /// Extension types that are not represented by a Parquet logical type are
+    /// still stored in the key-value metadata, and can be restored using
+    /// [`ArrowReaderOptions::with_extension_metadata`].
+    ///
+    /// [`ArrowReaderOptions::with_extension_metadata`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_extension_metadata
     pub fn with_skip_arrow_metadata(self, skip_arrow_metadata: bool) -> Self {
         Self {
             skip_arrow_metadata,
@@ -528,6 +855,127 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Check that the keys of each map with sorted keys are sorted (defaults to `false`)
+    ///
+    /// The `keys_sorted` flag of a [`DataType::Map`] is stored in the embedded arrow
+    /// schema, and is restored when the file is read. Readers may rely on this flag
+    /// without checking the data, and so if enabled, [`ArrowWriter::write`] returns
+    /// an error if the keys of a map entry are not in ascending order. This requires
+    /// comparing all keys of such maps, and so is disabled by default.
+    ///
+    /// [`DataType::Map`]: arrow_schema::DataType::Map
+    pub fn with_validate_sorted_map_keys(self, validate_sorted_map_keys: bool) -> Self {
+        Self {
+            validate_sorted_map_keys,
+            ..self
+        }
+    }
+}
+
+/// Returns an error if the keys of a map with sorted keys within `array` are not sorted
+///
+/// Only the values referenced by the non-null slots of `array` are validated, as
+/// only these are written
+fn validate_sorted_map_keys(array: &dyn Array) -> Result<()> {
+    match array.data_type() {
+        ArrowDataType::Map(_, sorted) => {
+            let map = array.as_map();
+            let offsets = map.value_offsets();
+            if *sorted {
+                for (idx, w) in offsets.windows(2).enumerate() {
+                    let range = w[0].as_usize()..w[1].as_usize();
+                    if map.is_valid(idx) && !keys_sorted(map.keys().as_ref(), range)? {
+                        return Err(general_err!(
+                            "Map at index {} is marked as having sorted keys, but its keys are not sorted",
+                            idx
+                        ));
+                    }
+                }
+            }
+            validate_sorted_map_keys_in_slots(array, |start, end| {
+                let (start, end) = (offsets[start].as_usize(), offsets[end].as_usize());
+                validate_sorted_map_keys(&map.entries().slice(start, end - start))
+            })
+        }
+        ArrowDataType::Struct(_) => validate_sorted_map_keys_in_slots(array, |start, end| {
+            let slice = array.slice(start, end - start);
+            slice
+                .as_struct()
+                .columns()
+                .iter()
+                .try_for_each(|c| validate_sorted_map_keys(c.as_ref()))
+        }),
+        ArrowDataType::List(_) => {
+            let list = array.as_list::<i32>();
+            validate_sorted_map_keys_in_slots(array, |start, end| {
+                let offsets = list.value_offsets();
+                let (start, end) = (offsets[start].as_usize(), offsets[end].as_usize());
+                validate_sorted_map_keys(list.values().slice(start, end - start).as_ref())
+            })
+        }
+        ArrowDataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            validate_sorted_map_keys_in_slots(array, |start, end| {
+                let offsets = list.value_offsets();
+                let (start, end) = (offsets[start].as_usize(), offsets[end].as_usize());
+                validate_sorted_map_keys(list.values().slice(start, end - start).as_ref())
+            })
+        }
+        ArrowDataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            validate_sorted_map_keys_in_slots(array, |start, end| {
+                let values = list.values().slice(start * size, (end - start) * size);
+                validate_sorted_map_keys(values.as_ref())
+            })
+        }
+        ArrowDataType::Dictionary(_, _) => {
+            validate_sorted_map_keys(array.as_any_dictionary().values())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Calls `f` with the start and end of each run of non-null slots of `array`
+fn validate_sorted_map_keys_in_slots(
+    array: &dyn Array,
+    mut f: impl FnMut(usize, usize) -> Result<()>,
+) -> Result<()> {
+    match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => nulls
+            .valid_slices()
+            .try_for_each(|(start, end)| f(start, end)),
+        None if array.is_empty() => Ok(()),
+        None => f(0, array.len()),
+    }
+}
+
+/// Returns true if the map keys in `range` of `keys` are sorted in ascending order
+fn keys_sorted(keys: &dyn Array, range: Range<usize>) -> Result<bool> {
+    fn sorted<T: Ord>(values: impl Iterator<Item = T>) -> bool {
+        values.is_sorted()
+    }
+
+    Ok(downcast_primitive_array!(
+        keys => keys.values()[range].is_sorted_by(|a, b| a.is_le(*b)),
+        ArrowDataType::Boolean => sorted(range.map(|i| keys.as_boolean().value(i))),
+        ArrowDataType::Utf8 => sorted(range.map(|i| keys.as_string::<i32>().value(i))),
+        ArrowDataType::LargeUtf8 => sorted(range.map(|i| keys.as_string::<i64>().value(i))),
+        ArrowDataType::Utf8View => sorted(range.map(|i| keys.as_string_view().value(i))),
+        ArrowDataType::Binary => sorted(range.map(|i| keys.as_binary::<i32>().value(i))),
+        ArrowDataType::LargeBinary => sorted(range.map(|i| keys.as_binary::<i64>().value(i))),
+        ArrowDataType::BinaryView => sorted(range.map(|i| keys.as_binary_view().value(i))),
+        ArrowDataType::FixedSizeBinary(_) => {
+            sorted(range.map(|i| keys.as_fixed_size_binary().value(i)))
+        }
+        d => {
+            return Err(nyi_err!(
+                "Validating the sorted map keys of type {} is not supported",
+                d
+            ));
+        }
+    ))
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`]
@@ -1122,6 +1570,9 @@ impl ArrowColumnWriterFactory {
                 ArrowDataType::FixedSizeBinary(_) => out.push(bytes(leaves.next().unwrap())?),
                 _ => out.push(col(leaves.next().unwrap())?),
             },
+            ArrowDataType::RunEndEncoded(_, value_type) => {
+                self.get_arrow_column_writer(value_type.data_type(), props, leaves, out)?
+            }
             _ => {
                 return Err(ParquetError::NYI(format!(
                     "Attempting to write an Arrow type {data_type} to parquet that is not yet implemented"
@@ -1304,8 +1755,22 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                 }
             }
         }
-        ColumnWriter::Int96ColumnWriter(_typed) => {
-            unreachable!("Currently unreachable because data type not supported")
+        ColumnWriter::Int96ColumnWriter(typed) => {
+            let array = match column.data_type() {
+                ArrowDataType::Dictionary(_, value_type) => arrow_cast::cast(column, value_type)?,
+                _ => levels.array().clone(),
+            };
+            let values = match array.data_type() {
+                ArrowDataType::Timestamp(unit, _) => {
+                    get_int96_array_slice(array.as_ref(), *unit, indices)
+                }
+                d => {
+                    return Err(ParquetError::NYI(format!(
+                        "Attempting to write an Arrow type {d} as INT96 that is not yet implemented"
+                    )));
+                }
+            };
+            typed.write_batch(&values, levels.def_levels(), levels.rep_levels())
         }
         ColumnWriter::FloatColumnWriter(typed) => {
             let array = column.as_primitive::<Float32Type>();
@@ -1335,10 +1800,13 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                             .unwrap();
                         get_interval_dt_array_slice(array, indices)
                     }
-                    _ => {
-                        return Err(ParquetError::NYI(format!(
-                            "Attempting to write an Arrow interval type {interval_unit:?} to parquet that is not yet implemented"
-                        )));
+                    IntervalUnit::MonthDayNano => {
+                        let array = column
+                            .as_any()
+                            .downcast_ref::<arrow_array::IntervalMonthDayNanoArray>()
+                            .unwrap();
+                        let truncate = typed.props().truncate_interval_nanoseconds();
+                        get_interval_mdn_array_slice(array, indices, truncate)?
                     }
                 },
                 ArrowDataType::FixedSizeBinary(_) => {
@@ -1365,7 +1833,8 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                         .as_any()
                         .downcast_ref::<arrow_array::Decimal256Array>()
                         .unwrap();
-                    get_decimal_256_array_slice(array, indices)
+                    let size = typed.get_descriptor().type_length() as usize;
+                    get_decimal_256_array_slice(array, indices, size)
                 }
                 ArrowDataType::Float16 => {
                     let array = column.as_primitive::<Float16Type>();
@@ -1406,6 +1875,30 @@ fn get_bool_array_slice(array: &arrow_array::BooleanArray, indices: &[usize]) ->
     values
 }
 
+/// Returns legacy INT96 timestamps, each storing the nanoseconds within the day in the
+/// first 8 bytes followed by the Julian day number in the last 4 bytes.
+fn get_int96_array_slice(array: &dyn Array, unit: TimeUnit, indices: &[usize]) -> Vec<Int96> {
+    let (values, convert): (_, fn(i64) -> Int96) = match unit {
+        TimeUnit::Second => (
+            array.as_primitive::<TimestampSecondType>().values(),
+            Int96::from_seconds,
+        ),
+        TimeUnit::Millisecond => (
+            array.as_primitive::<TimestampMillisecondType>().values(),
+            Int96::from_millis,
+        ),
+        TimeUnit::Microsecond => (
+            array.as_primitive::<TimestampMicrosecondType>().values(),
+            Int96::from_micros,
+        ),
+        TimeUnit::Nanosecond => (
+            array.as_primitive::<TimestampNanosecondType>().values(),
+            Int96::from_nanos,
+        ),
+    };
+    indices.iter().map(|i| convert(values[*i])).collect()
+}
+
 /// Returns 12-byte values representing 3 values of months, days and milliseconds (4-bytes each).
 /// An Arrow YearMonth interval only stores months, thus only the first 4 bytes are populated.
 fn get_interval_ym_array_slice(
@@ -1439,6 +1932,41 @@ fn get_interval_dt_array_slice(
     values
 }
 
+/// Returns 12-byte values representing 3 values of months, days and milliseconds (4-bytes each).
+/// An Arrow MonthDayNano interval stores nanoseconds, which must be converted to milliseconds.
+/// Returns an error if this would lose precision and `truncate` is `false`, or if the
+/// milliseconds do not fit in 4 bytes.
+fn get_interval_mdn_array_slice(
+    array: &arrow_array::IntervalMonthDayNanoArray,
+    indices: &[usize],
+    truncate: bool,
+) -> Result<Vec<FixedLenByteArray>> {
+    let mut values = Vec::with_capacity(indices.len());
+    for i in indices {
+        let value = array.value(*i);
+        if !truncate && value.nanoseconds % 1_000_000 != 0 {
+            return Err(general_err!(
+                "Cannot write interval with {} nanoseconds as milliseconds without loss of precision, \
+                 see WriterPropertiesBuilder::set_truncate_interval_nanoseconds",
+                value.nanoseconds
+            ));
+        }
+        let milliseconds = i32::try_from(value.nanoseconds / 1_000_000).map_err(|_| {
+            general_err!(
+                "Cannot write interval with {} nanoseconds as 32-bit milliseconds",
+                value.nanoseconds
+            )
+        })?;
+
+        let mut out = [0; 12];
+        out[0..4].copy_from_slice(&value.months.to_le_bytes());
+        out[4..8].copy_from_slice(&value.days.to_le_bytes());
+        out[8..12].copy_from_slice(&milliseconds.to_le_bytes());
+        values.push(FixedLenByteArray::from(ByteArray::from(out.to_vec())));
+    }
+    Ok(values)
+}
+
 fn get_decimal_32_array_slice(
     array: &arrow_array::Decimal32Array,
     indices: &[usize],
@@ -1481,12 +2009,15 @@ fn get_decimal_128_array_slice(
     values
 }
 
+/// Returns the values of `array` at `indices` as big-endian two's complement
+/// integers of `size` bytes, which may be larger than the minimal width for
+/// the precision if overridden via [`WriterProperties::decimal256_byte_width`]
 fn get_decimal_256_array_slice(
     array: &arrow_array::Decimal256Array,
     indices: &[usize],
+    size: usize,
 ) -> Vec<FixedLenByteArray> {
     let mut values = Vec::with_capacity(indices.len());
-    let size = decimal_length_from_precision(array.precision());
     for i in indices {
         let as_be_bytes = array.value(*i).to_be_bytes();
         let resized_value = as_be_bytes[(32 - size)..].to_vec();
@@ -1526,30 +2057,39 @@ mod tests {
     use std::fs::File;
 
     use crate::arrow::ARROW_SCHEMA_META_KEY;
-    use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+    use crate::arrow::arrow_reader::{
+        ArrowReaderOptions, ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder,
+    };
+    use crate::arrow::buffer::bit_util::sign_extend_be;
     use crate::column::page::{Page, PageReader};
     use crate::file::metadata::thrift::PageHeader;
     use crate::file::page_index::column_index::ColumnIndexMetaData;
     use crate::file::reader::SerializedPageReader;
     use crate::parquet_thrift::{ReadThrift, ThriftSliceInputProtocol};
     use crate::schema::types::{ColumnPath, Type};
+    use arrow::compute::concat_batches;
     use arrow::datatypes::ToByteSlice;
     use arrow::datatypes::{DataType, Schema};
     use arrow::error::Result as ArrowResult;
     use arrow::util::data_gen::create_random_array;
     use arrow::util::pretty::pretty_format_batches;
     use arrow::{array::*, buffer::Buffer};
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer, i256};
+    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer, OffsetBuffer, i256};
     use arrow_schema::Fields;
     use half::f16;
     use num_traits::{FromPrimitive, ToPrimitive};
     use tempfile::tempfile;
 
-    use crate::basic::Encoding;
+    use crate::basic::{
+        Compression, ConvertedType, Encoding, PageType, Type as PhysicalType, ZstdLevel,
+    };
     use crate::data_type::AsBytes;
-    use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
+    use crate::file::metadata::{
+        ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader,
+    };
     use crate::file::properties::{
-        BloomFilterPosition, EnabledStatistics, ReaderProperties, WriterVersion,
+        BloomFilterPosition, EnabledStatistics, ReaderProperties, WriterCompatibility,
+        WriterVersion,
     };
     use crate::file::serialized_reader::ReadOptionsBuilder;
     use crate::file::{
@@ -1963,6 +2503,142 @@ mod tests {
         roundtrip(batch, None);
     }
 
+    /// Returns a map with sorted keys, for each of `entries` holding `(key, value)` pairs
+    fn sorted_map(entries: &[Option<&[(&str, i32)]>]) -> MapArray {
+        let (keys, values): (Vec<&str>, Vec<i32>) =
+            entries.iter().flatten().copied().flatten().copied().unzip();
+        let keys = StringArray::from(keys);
+        let values = Int32Array::from(values);
+        let entries_struct = StructArray::from(vec![
+            (
+                Arc::new(Field::new("key", DataType::Utf8, false)),
+                Arc::new(keys) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("value", DataType::Int32, true)),
+                Arc::new(values) as ArrayRef,
+            ),
+        ]);
+        let offsets = OffsetBuffer::from_lengths(entries.iter().map(|e| e.map_or(0, |e| e.len())));
+        let nulls = NullBuffer::from_iter(entries.iter().map(Option::is_some));
+        let field = Arc::new(Field::new(
+            "entries",
+            entries_struct.data_type().clone(),
+            false,
+        ));
+        MapArray::new(field, offsets, entries_struct, Some(nulls), true)
+    }
+
+    #[test]
+    fn arrow_writer_sorted_map() {
+        let map = sorted_map(&[
+            Some(&[("a", 1), ("b", 2)]),
+            None,
+            Some(&[]),
+            Some(&[("c", 3), ("d", 4), ("e", 5)]),
+        ]);
+        let map_field = Arc::new(Field::new("map", map.data_type().clone(), true));
+
+        // Map within a struct
+        let struct_array = StructArray::from(vec![
+            (Arc::clone(&map_field), Arc::new(map.clone()) as ArrayRef),
+            (
+                Arc::new(Field::new("id", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+        ]);
+
+        // Map within a list
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(map.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([1, 0, 3]),
+            Arc::new(map.slice(0, 4)),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let list = list.slice(0, 3);
+
+        for (array, len) in [
+            (Arc::new(map.clone()) as ArrayRef, 4),
+            (Arc::new(struct_array) as ArrayRef, 4),
+            (Arc::new(list) as ArrayRef, 3),
+        ] {
+            let array = array.slice(0, len);
+            let batch = RecordBatch::try_from_iter([("col", array)]).unwrap();
+
+            let files = roundtrip(batch.clone(), None);
+            let reader = ParquetRecordBatchReaderBuilder::try_new(files[0].clone()).unwrap();
+            assert_eq!(reader.schema(), &batch.schema());
+
+            // Validation succeeds for sorted keys
+            let options = ArrowWriterOptions::new().with_validate_sorted_map_keys(true);
+            let mut writer =
+                ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+        }
+    }
+
+    #[test]
+    fn arrow_writer_sorted_map_validation() {
+        let unsorted = sorted_map(&[Some(&[("a", 1), ("b", 2)]), Some(&[("d", 3), ("c", 4)])]);
+        let struct_array = StructArray::from(vec![(
+            Arc::new(Field::new("map", unsorted.data_type().clone(), true)),
+            Arc::new(unsorted) as ArrayRef,
+        )]);
+        let batch =
+            RecordBatch::try_from_iter([("col", Arc::new(struct_array) as ArrayRef)]).unwrap();
+
+        // Not validated by default
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowWriterOptions::new().with_validate_sorted_map_keys(true);
+        let mut writer =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Map at index 1 is marked as having sorted keys, but its keys are not sorted"
+        );
+
+        // Only the written slice is validated
+        writer.write(&batch.slice(0, 1)).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn arrow_writer_sorted_map_validation_unreferenced_entries() {
+        // The entries of the null map slot are not sorted
+        let entries = sorted_map(&[Some(&[("a", 1), ("b", 2), ("d", 3), ("c", 4)])])
+            .entries()
+            .clone();
+        let field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+        let offsets = OffsetBuffer::new(vec![0, 2, 4].into());
+        let nulls = NullBuffer::from(vec![true, false]);
+        let map = MapArray::new(field, offsets, entries, Some(nulls), true);
+        validate_sorted_map_keys(&map).unwrap();
+
+        // Maps that are not referenced by a non-null list slot are not validated
+        let map = Arc::new(sorted_map(&[
+            Some(&[("a", 1), ("b", 2)]),
+            Some(&[("d", 3), ("c", 4)]),
+        ])) as ArrayRef;
+        let field = Arc::new(Field::new("element", map.data_type().clone(), true));
+        let offsets = OffsetBuffer::new(vec![0, 1, 2].into());
+        let list = ListArray::new(field.clone(), offsets.clone(), map.clone(), None);
+        let err = validate_sorted_map_keys(&list).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Map at index 1 is marked as having sorted keys, but its keys are not sorted"
+        );
+        validate_sorted_map_keys(&list.slice(0, 1)).unwrap();
+
+        let nulls = NullBuffer::from(vec![true, false]);
+        let list = ListArray::new(field, offsets, map, Some(nulls));
+        validate_sorted_map_keys(&list).unwrap();
+    }
+
     #[test]
     fn arrow_writer_2_level_struct() {
         // tests writing <struct<struct<primitive>>
@@ -2295,58 +2971,824 @@ mod tests {
         roundtrip(batch, None);
     }
 
-    const SMALL_SIZE: usize = 7;
-    const MEDIUM_SIZE: usize = 63;
+    #[test]
+    fn arrow_writer_run_end_encoded() {
+        let strings = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+        let run_ends = Int32Array::from(vec![2, 3, 7, 10]);
+        let a = RunArray::try_new(&run_ends, &strings).unwrap();
 
-    fn roundtrip(expected_batch: RecordBatch, max_row_group_size: Option<usize>) -> Vec<Bytes> {
-        let mut files = vec![];
-        for version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
-            let mut props = WriterProperties::builder().set_writer_version(version);
+        let ints = Int64Array::from(vec![Some(1), Some(2), None, Some(3)]);
+        let run_ends = Int16Array::from(vec![1, 5, 6, 10]);
+        let b = RunArray::try_new(&run_ends, &ints).unwrap();
 
-            if let Some(size) = max_row_group_size {
-                props = props.set_max_row_group_size(size)
-            }
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", a.data_type().clone(), true),
+            Field::new("b", b.data_type().clone(), true),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(b)]).unwrap();
 
-            let props = props.build();
-            files.push(roundtrip_opts(&expected_batch, props))
+        // Sliced run arrays start and end partway through a run
+        let expected = cast_run_end_encoded(&batch);
+        for (batch, expected) in [
+            (batch.clone(), expected.clone()),
+            (batch.slice(1, 8), expected.slice(1, 8)),
+        ] {
+            let file = Bytes::from(get_bytes_after_close(batch.schema(), &batch));
+            let reader = ParquetRecordBatchReader::try_new(file, 1024).unwrap();
+            let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+            assert_eq!(batches, vec![expected]);
         }
-        files
     }
 
-    // Round trip the specified record batch with the specified writer properties,
-    // to an in-memory file, and validate the arrays using the specified function.
-    // Returns the in-memory file.
-    fn roundtrip_opts_with_array_validation<F>(
-        expected_batch: &RecordBatch,
-        props: WriterProperties,
-        validate: F,
-    ) -> Bytes
-    where
-        F: Fn(&ArrayData, &ArrayData),
-    {
-        let mut file = vec![];
-
-        let mut writer = ArrowWriter::try_new(&mut file, expected_batch.schema(), Some(props))
-            .expect("Unable to write file");
-        writer.write(expected_batch).unwrap();
-        writer.close().unwrap();
-
-        let file = Bytes::from(file);
-        let mut record_batch_reader =
-            ParquetRecordBatchReader::try_new(file.clone(), 1024).unwrap();
+    #[test]
+    fn arrow_writer_non_nullable_run_end_encoded() {
+        // Without definition levels every logical row must still be written,
+        // rather than one row per run
+        let ints = Int64Array::from(vec![1, 2, 3]);
+        let run_ends = Int32Array::from(vec![4, 5, 9]);
+        let a = RunArray::try_new(&run_ends, &ints).unwrap();
 
-        let actual_batch = record_batch_reader
-            .next()
-            .expect("No batch found")
-            .expect("Unable to get batch");
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            a.data_type().clone(),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
 
-        assert_eq!(expected_batch.schema(), actual_batch.schema());
-        assert_eq!(expected_batch.num_columns(), actual_batch.num_columns());
-        assert_eq!(expected_batch.num_rows(), actual_batch.num_rows());
-        for i in 0..expected_batch.num_columns() {
-            let expected_data = expected_batch.column(i).to_data();
-            let actual_data = actual_batch.column(i).to_data();
-            validate(&expected_data, &actual_data);
+        let expected = cast_run_end_encoded(&batch);
+        for (batch, expected) in [
+            (batch.clone(), expected.clone()),
+            (batch.slice(2, 5), expected.slice(2, 5)),
+        ] {
+            let file = Bytes::from(get_bytes_after_close(batch.schema(), &batch));
+            let reader = ParquetRecordBatchReader::try_new(file, 1024).unwrap();
+            let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+            assert_eq!(batches, vec![expected]);
+        }
+    }
+
+    #[test]
+    fn arrow_writer_list_of_run_end_encoded() {
+        let strings = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let run_ends = Int32Array::from(vec![3, 4, 8]);
+        let values = RunArray::try_new(&run_ends, &strings).unwrap();
+
+        let item = Arc::new(Field::new("item", values.data_type().clone(), true));
+        let offsets = OffsetBuffer::new(vec![0, 2, 2, 5, 8].into());
+        let nulls = NullBuffer::from(vec![true, true, false, true]);
+        let list = ListArray::new(item.clone(), offsets, Arc::new(values), Some(nulls));
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "list",
+            DataType::List(item),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(list)]).unwrap();
+
+        let expected = cast_run_end_encoded(&batch);
+        let file = Bytes::from(get_bytes_after_close(batch.schema(), &batch));
+        let reader = ParquetRecordBatchReader::try_new(file, 1024).unwrap();
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(batches, vec![expected]);
+    }
+
+    #[test]
+    fn arrow_writer_run_end_encoded_roundtrip() {
+        // 100,000 rows made up of 10 runs
+        let run_ends = Int32Array::from_iter_values((1..=10).map(|i| i * 10_000));
+        let strings = StringArray::from_iter((0..10).map(|i| match i % 3 {
+            0 => Some("foo"),
+            1 => None,
+            _ => Some("bar"),
+        }));
+        let a = RunArray::try_new(&run_ends, &strings).unwrap();
+
+        let run_ends = Int64Array::from_iter_values((1..=10).map(|i| i * 10_000));
+        let ints = Int64Array::from_iter_values((0..10).map(|i| i * i));
+        let b = RunArray::try_new(&run_ends, &ints).unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", a.data_type().clone(), true),
+            Field::new("b", b.data_type().clone(), false),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        // The runs are compressed by the dictionary and RLE encodings
+        for column in metadata.row_groups()[0].columns() {
+            assert!(column.compressed_size() < 1024, "{column:?}");
+        }
+
+        let file = Bytes::from(buf);
+        let read = |options: ArrowReaderOptions| {
+            ParquetRecordBatchReaderBuilder::try_new_with_options(file.clone(), options)
+                .unwrap()
+                .with_batch_size(100_000)
+                .build()
+                .unwrap()
+                .collect::<ArrowResult<Vec<_>>>()
+                .unwrap()
+        };
+
+        // By default the run values are read
+        let expected = cast_run_end_encoded(&batch);
+        assert_eq!(read(ArrowReaderOptions::new()), vec![expected]);
+
+        // The run-end encoding can be restored from the embedded schema
+        let options = ArrowReaderOptions::new().with_run_end_encoded_output(true);
+        assert_eq!(read(options), vec![batch.clone()]);
+
+        // Or requested with a different run end type
+        let a_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int16, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        let supplied = Arc::new(Schema::new(vec![
+            Field::new("a", a_type.clone(), true),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let options = ArrowReaderOptions::new().with_schema(supplied);
+        let batches = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        let expected = arrow_cast::cast(batch.column(0), &DataType::Utf8).unwrap();
+        let mut offset = 0;
+        for read in batches {
+            assert_eq!(read.column(0).data_type(), &a_type);
+            let values = arrow_cast::cast(read.column(0), &DataType::Utf8).unwrap();
+            assert_eq!(&values, &expected.slice(offset, read.num_rows()));
+            offset += read.num_rows();
+        }
+        assert_eq!(offset, 100_000);
+    }
+
+    /// Returns `batch` with any run-end encoded arrays expanded to their values
+    fn cast_run_end_encoded(batch: &RecordBatch) -> RecordBatch {
+        fn expand(data_type: &DataType) -> DataType {
+            match data_type {
+                DataType::RunEndEncoded(_, values) => values.data_type().clone(),
+                DataType::List(f) => DataType::List(Arc::new(
+                    f.as_ref().clone().with_data_type(expand(f.data_type())),
+                )),
+                d => d.clone(),
+            }
+        }
+
+        let fields: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.as_ref().clone().with_data_type(expand(f.data_type())))
+            .collect();
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(&fields)
+            .map(|(c, f)| arrow_cast::cast(c, f.data_type()).unwrap())
+            .collect();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).unwrap()
+    }
+
+    #[test]
+    fn arrow_writer_column_pattern_properties() {
+        let mut list = ListBuilder::new(Int32Builder::new());
+        list.append_value([Some(1), None, Some(3)]);
+        list.append_null();
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("list", Arc::new(list.finish()) as ArrayRef),
+            ("map", Arc::new(map.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let zstd = Compression::ZSTD(Default::default());
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_column_pattern_compression("list.list.*".parse().unwrap(), zstd)
+            .set_column_pattern_compression("map.**".parse().unwrap(), Compression::LZ4_RAW)
+            .set_column_pattern_compression("*.entries.values".parse().unwrap(), zstd)
+            .build();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let compression: Vec<_> = metadata.row_groups()[0]
+            .columns()
+            .iter()
+            .map(|c| (c.column_path().string(), c.compression()))
+            .collect();
+        assert_eq!(
+            compression,
+            vec![
+                ("id".to_string(), Compression::SNAPPY),
+                ("list.list.item".to_string(), zstd),
+                ("map.entries.keys".to_string(), Compression::LZ4_RAW),
+                ("map.entries.values".to_string(), zstd),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "snap", feature = "zstd"))]
+    fn arrow_writer_auto_compression() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "string",
+                Arc::new(StringArray::from(vec!["a", "b", "a"])) as ArrayRef,
+            ),
+            (
+                "int64",
+                Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![1, 2, 3])
+                        .with_precision_and_scale(20, 2)
+                        .unwrap(),
+                ) as ArrayRef,
+            ),
+            (
+                "sorted",
+                Arc::new(Int64Array::from(vec![10, 20, 30])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_auto_compression(true)
+            .set_column_compression(ColumnPath::from("int64"), Compression::LZ4_RAW)
+            .set_column_dictionary_enabled(ColumnPath::from("sorted"), false)
+            .build();
+        let schema_descr = ArrowSchemaConverter::new()
+            .convert(&batch.schema())
+            .unwrap();
+        let expected: Vec<_> = schema_descr
+            .columns()
+            .iter()
+            .map(|c| props.column_compression(c))
+            .collect();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let columns = metadata.row_groups()[0].columns();
+        let compression: Vec<_> = columns.iter().map(|c| c.compression()).collect();
+        let zstd = Compression::ZSTD(Default::default());
+        assert_eq!(
+            compression,
+            vec![
+                zstd,
+                Compression::LZ4_RAW,
+                Compression::UNCOMPRESSED,
+                zstd,
+                Compression::SNAPPY
+            ]
+        );
+        assert_eq!(compression, expected);
+
+        // Integers that are not dictionary encoded are delta encoded
+        let encodings: Vec<_> = columns[4].encodings().collect();
+        assert!(
+            encodings.contains(&Encoding::DELTA_BINARY_PACKED),
+            "{encodings:?}"
+        );
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn arrow_writer_column_compression_levels() {
+        let values: Vec<_> = (0..10_000_u64)
+            .map(|i| format!("{}-{}", i.wrapping_mul(2654435761) % 9973, i % 17))
+            .collect();
+        let strings: ArrayRef = Arc::new(StringArray::from(values));
+        let batch = RecordBatch::try_from_iter([
+            ("cold", strings.clone()),
+            ("hot", strings.clone()),
+            ("lz4", strings.clone()),
+            ("default", strings),
+        ])
+        .unwrap();
+
+        let cold = Compression::ZSTD(ZstdLevel::try_new(9).unwrap());
+        let hot = Compression::ZSTD(ZstdLevel::try_new(1).unwrap());
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_compression(Compression::SNAPPY)
+            .set_column_compression(ColumnPath::from("cold"), cold)
+            .set_column_compression(ColumnPath::from("hot"), hot)
+            .set_column_compression(ColumnPath::from("lz4"), Compression::LZ4_RAW)
+            .build();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let columns = metadata.row_groups()[0].columns();
+        let compression: Vec<_> = columns.iter().map(|c| c.compression()).collect();
+        assert_eq!(
+            compression,
+            vec![cold, hot, Compression::LZ4_RAW, Compression::SNAPPY]
+        );
+        // The level applied to each column chunk is visible when debugging the written metadata
+        let debug = format!("{:?}", columns[0]);
+        assert!(debug.contains("compression: ZSTD(ZstdLevel(9))"), "{debug}");
+
+        // The identical data compresses better with the higher level
+        assert!(columns[0].compressed_size() < columns[1].compressed_size());
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 10_000)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn arrow_writer_byte_stream_split() {
+        // A slowly varying signal, as produced by a sensor
+        let values: Vec<f64> = (0..10_000).map(|i| (i as f64 / 100.0).sin()).collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "double",
+                Arc::new(Float64Array::from(values.clone())) as ArrayRef,
+            ),
+            (
+                "float",
+                Arc::new(Float32Array::from_iter_values(
+                    values.iter().map(|v| *v as f32),
+                )) as ArrayRef,
+            ),
+            (
+                "int32",
+                Arc::new(Int32Array::from_iter_values(
+                    values.iter().map(|v| (v * 1_000_000.0) as i32),
+                )) as ArrayRef,
+            ),
+            (
+                "int64",
+                Arc::new(Int64Array::from_iter_values(
+                    values.iter().map(|v| (v * 1_000_000_000.0) as i64),
+                )) as ArrayRef,
+            ),
+            (
+                "flba",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_iter(
+                        values.iter().map(|v| (*v as f32).to_le_bytes()),
+                    )
+                    .unwrap(),
+                ) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let write = |encoding: Encoding| {
+            let mut builder = WriterProperties::builder()
+                .set_dictionary_enabled(false)
+                .set_compression(Compression::ZSTD(Default::default()));
+            for field in batch.schema().fields() {
+                builder =
+                    builder.set_column_encoding(ColumnPath::from(field.name().as_str()), encoding);
+            }
+
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer =
+                ArrowWriter::try_new(&mut buf, batch.schema(), Some(builder.build())).unwrap();
+            writer.write(&batch).unwrap();
+            let metadata = writer.close().unwrap();
+            (Bytes::from(buf), metadata)
+        };
+
+        let (plain, plain_metadata) = write(Encoding::PLAIN);
+        let (split, split_metadata) = write(Encoding::BYTE_STREAM_SPLIT);
+
+        for column in split_metadata.row_groups()[0].columns() {
+            let encodings: Vec<_> = column.encodings().collect();
+            assert!(encodings.contains(&Encoding::BYTE_STREAM_SPLIT));
+            assert!(!encodings.contains(&Encoding::PLAIN));
+        }
+
+        // Splitting the bytes of the floating point values into streams makes
+        // them considerably more compressible
+        let compressed_size = |metadata: &ParquetMetaData, idx: usize| {
+            metadata.row_groups()[0].column(idx).compressed_size()
+        };
+        for idx in 0..2 {
+            assert!(
+                compressed_size(&split_metadata, idx) < compressed_size(&plain_metadata, idx),
+                "column {idx}: {} >= {}",
+                compressed_size(&split_metadata, idx),
+                compressed_size(&plain_metadata, idx)
+            );
+        }
+
+        for data in [plain, split] {
+            let read = ParquetRecordBatchReader::try_new(data, 10_000)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert_eq!(read, batch);
+        }
+    }
+
+    #[test]
+    fn arrow_writer_byte_stream_split_unsupported_type() {
+        for (name, array) in [
+            (
+                "string",
+                Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+            ),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false])) as ArrayRef,
+            ),
+        ] {
+            let batch = RecordBatch::try_from_iter([(name, array)]).unwrap();
+            let props = WriterProperties::builder()
+                .set_column_encoding(ColumnPath::from(name), Encoding::BYTE_STREAM_SPLIT)
+                .build();
+
+            let err = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap_err();
+            assert!(
+                err.to_string().contains(&format!(
+                    "Encoding BYTE_STREAM_SPLIT is not supported for column \"{name}\""
+                )),
+                "{err}"
+            );
+        }
+
+        // Encodings set for all columns are also validated against each column
+        let batch = RecordBatch::try_from_iter([(
+            "string",
+            Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_encoding(Encoding::BYTE_STREAM_SPLIT)
+            .build();
+        let err = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encoding BYTE_STREAM_SPLIT is not supported for column \"string\" with physical type BYTE_ARRAY"
+        );
+    }
+
+    #[test]
+    fn arrow_writer_delta_binary_packed() {
+        // Strictly increasing values, such as an id or timestamp column
+        let batch = RecordBatch::try_from_iter([
+            (
+                "int32",
+                Arc::new(Int32Array::from_iter_values((0..10_000).map(|i| i * 3))) as ArrayRef,
+            ),
+            (
+                "int64",
+                Arc::new(Int64Array::from_iter_values(
+                    (0..10_000).map(|i| 1_700_000_000_000 + i * 7),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let write = |encoding: Encoding| {
+            let mut builder = WriterProperties::builder();
+            for field in batch.schema().fields() {
+                let path = ColumnPath::from(field.name().as_str());
+                builder = builder
+                    .set_column_dictionary_enabled(path.clone(), false)
+                    .set_column_encoding(path, encoding);
+            }
+
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer =
+                ArrowWriter::try_new(&mut buf, batch.schema(), Some(builder.build())).unwrap();
+            writer.write(&batch).unwrap();
+            let metadata = writer.close().unwrap();
+            (Bytes::from(buf), metadata)
+        };
+
+        let (plain, plain_metadata) = write(Encoding::PLAIN);
+        let (delta, delta_metadata) = write(Encoding::DELTA_BINARY_PACKED);
+
+        for (idx, column) in delta_metadata.row_groups()[0].columns().iter().enumerate() {
+            let encodings: Vec<_> = column.encodings().collect();
+            assert!(encodings.contains(&Encoding::DELTA_BINARY_PACKED));
+            assert!(!encodings.contains(&Encoding::PLAIN));
+            assert!(!encodings.contains(&Encoding::RLE_DICTIONARY));
+
+            let plain_size = plain_metadata.row_groups()[0].column(idx).compressed_size();
+            assert!(
+                column.compressed_size() * 10 < plain_size,
+                "column {idx}: {} vs {plain_size}",
+                column.compressed_size()
+            );
+        }
+
+        for data in [plain, delta] {
+            let read = ParquetRecordBatchReader::try_new(data, 10_000)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert_eq!(read, batch);
+        }
+
+        // Delta binary packing is only defined for integer columns
+        let batch = RecordBatch::try_from_iter([(
+            "double",
+            Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_column_encoding(ColumnPath::from("double"), Encoding::DELTA_BINARY_PACKED)
+            .build();
+        let err = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Encoding DELTA_BINARY_PACKED is not supported for column \"double\""),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn arrow_writer_fallback_encoding() {
+        let urls: Vec<_> = (0..2000)
+            .map(|i| format!("https://example.com/some/long/path/{i}"))
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            ("url", Arc::new(StringArray::from(urls)) as ArrayRef),
+            (
+                "id",
+                Arc::new(Int64Array::from_iter_values(0..2000)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        // Both columns exceed their dictionary page size limit part way through the chunk
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .set_column_dictionary_page_size_limit(ColumnPath::from("url"), 4096)
+            .set_column_fallback_encoding(ColumnPath::from("url"), Encoding::DELTA_BYTE_ARRAY)
+            .set_column_dictionary_page_size_limit(ColumnPath::from("id"), 1024)
+            .set_column_fallback_encoding(ColumnPath::from("id"), Encoding::PLAIN)
+            .build();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let columns = metadata.row_group(0).columns();
+        for (column, fallback) in columns
+            .iter()
+            .zip([Encoding::DELTA_BYTE_ARRAY, Encoding::PLAIN])
+        {
+            let encodings: Vec<_> = column.encodings().collect();
+            assert!(
+                encodings.contains(&Encoding::RLE_DICTIONARY),
+                "{encodings:?}"
+            );
+            assert!(encodings.contains(&fallback), "{encodings:?}");
+        }
+        // Without a fallback encoding, version 2 integer columns fall back to delta encoding
+        let encodings: Vec<_> = columns[1].encodings().collect();
+        assert!(!encodings.contains(&Encoding::DELTA_BINARY_PACKED));
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 10_000)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+
+        // The fallback encoding is validated against the physical type of the column
+        let props = WriterProperties::builder()
+            .set_column_fallback_encoding(ColumnPath::from("id"), Encoding::DELTA_BYTE_ARRAY)
+            .build();
+        let err = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encoding DELTA_BYTE_ARRAY is not supported for column \"id\" with physical type INT64"
+        );
+    }
+
+    #[test]
+    fn arrow_writer_int96_timestamps() {
+        // Values before EPOCH, at EPOCH, after EPOCH and null
+        let millis = vec![
+            Some(-86_400_001),
+            Some(-1),
+            Some(0),
+            None,
+            Some(1_700_000_000_123),
+        ];
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(TimestampSecondArray::from(vec![
+                Some(-86_401),
+                Some(-1),
+                Some(0),
+                None,
+                Some(1_700_000_000),
+            ])),
+            Arc::new(TimestampMillisecondArray::from(millis.clone()).with_timezone("+05:00")),
+            Arc::new(TimestampMicrosecondArray::from_iter(
+                millis.iter().map(|v| v.map(|v| v * 1_000 + 7)),
+            )),
+            Arc::new(
+                TimestampNanosecondArray::from_iter(
+                    millis.iter().map(|v| v.map(|v| v * 1_000_000 + 7)),
+                )
+                .with_timezone("UTC"),
+            ),
+        ];
+        let fields: Vec<_> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Field::new(format!("c{i}"), c.data_type().clone(), true))
+            .collect();
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_int96_timestamps(true)
+            .build();
+        let file = roundtrip_opts(&batch, props);
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata();
+        for column in metadata.file_metadata().schema_descr().columns() {
+            assert_eq!(column.physical_type(), crate::basic::Type::INT96);
+            assert!(column.logical_type().is_none());
+        }
+
+        // INT96 statistics are ordered by Julian day and nanoseconds
+        let stats = metadata.row_group(0).column(1).statistics().unwrap();
+        let Statistics::Int96(stats) = stats else {
+            panic!("expected Statistics::Int96, got {stats:?}");
+        };
+        assert_eq!(stats.min_opt().unwrap().to_millis(), -86_400_001);
+        assert_eq!(stats.max_opt().unwrap().to_millis(), 1_700_000_000_123);
+    }
+
+    #[test]
+    fn arrow_writer_float16_statistics_and_column_index() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Float16, true)]));
+        let subnormal = f16::MIN_POSITIVE_SUBNORMAL;
+
+        // Four pages of two rows each: NaN and -0.0, +0.0 and a subnormal,
+        // only nulls, and a negative subnormal with 1.0
+        let values = Float16Array::from(vec![
+            Some(f16::NAN),
+            Some(f16::NEG_ZERO),
+            Some(f16::ZERO),
+            Some(subnormal),
+            None,
+            None,
+            Some(-subnormal),
+            Some(f16::ONE),
+        ]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(values)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .set_data_page_row_count_limit(2)
+            .set_write_batch_size(2)
+            .build();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let reader = SerializedFileReader::new_with_options(Bytes::from(buf), options).unwrap();
+
+        // Chunk statistics ignore NaN and are stored as 2-byte little-endian values
+        let column = reader.metadata().row_group(0).column(0);
+        let stats = column.statistics().unwrap();
+        assert_eq!(stats.null_count_opt(), Some(2));
+        assert_eq!(stats.min_bytes_opt().unwrap(), (-subnormal).to_le_bytes());
+        assert_eq!(stats.max_bytes_opt().unwrap(), f16::ONE.to_le_bytes());
+
+        let column_index = reader.metadata().column_index().unwrap();
+        let ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(index) = &column_index[0][0] else {
+            panic!("expected FIXED_LEN_BYTE_ARRAY column index");
+        };
+        assert_eq!(index.num_pages(), 4);
+
+        let null_pages: Vec<_> = (0..4).map(|i| index.is_null_page(i)).collect();
+        assert_eq!(null_pages, vec![false, false, true, false]);
+
+        let bytes = |v: f16| Some(v.to_le_bytes().to_vec());
+        let mins: Vec<_> = index
+            .min_values_iter()
+            .map(|v| v.map(<[u8]>::to_vec))
+            .collect();
+        let maxes: Vec<_> = index
+            .max_values_iter()
+            .map(|v| v.map(<[u8]>::to_vec))
+            .collect();
+
+        // A zero minimum is written as -0.0 and a zero maximum as +0.0
+        assert_eq!(
+            mins,
+            vec![
+                bytes(f16::NEG_ZERO),
+                bytes(f16::NEG_ZERO),
+                None,
+                bytes(-subnormal)
+            ]
+        );
+        assert_eq!(
+            maxes,
+            vec![bytes(f16::ZERO), bytes(subnormal), None, bytes(f16::ONE)]
+        );
+    }
+
+    const SMALL_SIZE: usize = 7;
+    const MEDIUM_SIZE: usize = 63;
+
+    fn roundtrip(expected_batch: RecordBatch, max_row_group_size: Option<usize>) -> Vec<Bytes> {
+        let mut files = vec![];
+        for version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+            let mut props = WriterProperties::builder().set_writer_version(version);
+
+            if let Some(size) = max_row_group_size {
+                props = props.set_max_row_group_size(size)
+            }
+
+            let props = props.build();
+            files.push(roundtrip_opts(&expected_batch, props))
+        }
+        files
+    }
+
+    // Round trip the specified record batch with the specified writer properties,
+    // to an in-memory file, and validate the arrays using the specified function.
+    // Returns the in-memory file.
+    fn roundtrip_opts_with_array_validation<F>(
+        expected_batch: &RecordBatch,
+        props: WriterProperties,
+        validate: F,
+    ) -> Bytes
+    where
+        F: Fn(&ArrayData, &ArrayData),
+    {
+        let mut file = vec![];
+
+        let mut writer = ArrowWriter::try_new(&mut file, expected_batch.schema(), Some(props))
+            .expect("Unable to write file");
+        writer.write(expected_batch).unwrap();
+        writer.close().unwrap();
+
+        let file = Bytes::from(file);
+        let mut record_batch_reader =
+            ParquetRecordBatchReader::try_new(file.clone(), 1024).unwrap();
+
+        let actual_batch = record_batch_reader
+            .next()
+            .expect("No batch found")
+            .expect("Unable to get batch");
+
+        assert_eq!(expected_batch.schema(), actual_batch.schema());
+        assert_eq!(expected_batch.num_columns(), actual_batch.num_columns());
+        assert_eq!(expected_batch.num_rows(), actual_batch.num_rows());
+        for i in 0..expected_batch.num_columns() {
+            let expected_data = expected_batch.column(i).to_data();
+            let actual_data = actual_batch.column(i).to_data();
+            validate(&expected_data, &actual_data);
         }
 
         file
@@ -2762,18 +4204,127 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Attempting to write an Arrow interval type MonthDayNano to parquet that is not yet implemented"
-    )]
     fn interval_month_day_nano_single_column() {
         required_and_optional::<IntervalMonthDayNanoArray, _>(vec![
-            IntervalMonthDayNano::new(0, 1, 5),
-            IntervalMonthDayNano::new(0, 3, 2),
-            IntervalMonthDayNano::new(3, -2, -5),
-            IntervalMonthDayNano::new(-200, 4, -1),
+            IntervalMonthDayNano::new(0, 1, 5_000_000),
+            IntervalMonthDayNano::new(0, 3, 2_000_000),
+            IntervalMonthDayNano::new(3, -2, -5_000_000),
+            IntervalMonthDayNano::new(-200, 4, -1_000_000),
         ]);
     }
 
+    #[test]
+    fn arrow_writer_intervals() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "year_month",
+                Arc::new(IntervalYearMonthArray::from(vec![
+                    Some(14),
+                    None,
+                    Some(-3),
+                    Some(i32::MIN),
+                ])) as ArrayRef,
+            ),
+            (
+                "day_time",
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    Some(IntervalDayTime::new(-1, 500)),
+                    Some(IntervalDayTime::new(2, -86_399_999)),
+                    None,
+                    Some(IntervalDayTime::new(i32::MAX, i32::MIN)),
+                ])) as ArrayRef,
+            ),
+            (
+                "month_day_nano",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    Some(IntervalMonthDayNano::new(-1, 2, -3_000_000)),
+                    None,
+                    Some(IntervalMonthDayNano::new(12, -30, 86_400_000_000_000)),
+                    Some(IntervalMonthDayNano::new(0, 0, i32::MIN as i64 * 1_000_000)),
+                ])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let schema = metadata.file_metadata().schema_descr();
+        for column in schema.columns() {
+            assert_eq!(column.physical_type(), PhysicalType::FIXED_LEN_BYTE_ARRAY);
+            assert_eq!(column.converted_type(), ConvertedType::INTERVAL);
+            assert_eq!(column.type_length(), 12);
+        }
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn arrow_writer_interval_nanoseconds() {
+        let array = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, -2, 3_999_999)),
+            None,
+            Some(IntervalMonthDayNano::new(-1, 2, -1_500_000)),
+        ]);
+        let batch =
+            RecordBatch::try_from_iter([("interval", Arc::new(array) as ArrayRef)]).unwrap();
+
+        // Sub-millisecond precision cannot be stored by default
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), None).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot write interval with 3999999 nanoseconds as milliseconds \
+             without loss of precision, see WriterPropertiesBuilder::set_truncate_interval_nanoseconds"
+        );
+
+        // Unless explicitly truncated
+        let props = WriterProperties::builder()
+            .set_truncate_interval_nanoseconds(true)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, -2, 3_000_000)),
+            None,
+            Some(IntervalMonthDayNano::new(-1, 2, -1_000_000)),
+        ]);
+        assert_eq!(read.column(0).as_ref(), &expected);
+
+        // Milliseconds that do not fit in 32 bits are always an error
+        let array = IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(
+            0,
+            0,
+            (i32::MAX as i64 + 1) * 1_000_000,
+        )]);
+        let batch =
+            RecordBatch::try_from_iter([("interval", Arc::new(array) as ArrayRef)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_truncate_interval_nanoseconds(true)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot write interval with 2147483648000000 nanoseconds as 32-bit milliseconds"
+        );
+    }
+
     #[test]
     fn binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3436,6 +4987,306 @@ mod tests {
         one_column_roundtrip(Arc::new(array), true);
     }
 
+    #[test]
+    fn arrow_writer_decimal256_byte_width() {
+        for (precision, min_width) in [(39, 17), (50, 21), (76, 32)] {
+            let max = i256::from_string(&"9".repeat(precision as usize)).unwrap();
+            let values = vec![
+                Some(max.neg_wrapping()),
+                Some(i256::from_i128(-12345)),
+                None,
+                Some(i256::MINUS_ONE),
+                Some(i256::ZERO),
+                Some(i256::from_i128(i128::MIN)),
+                Some(max),
+            ];
+            let array = Decimal256Array::from(values)
+                .with_precision_and_scale(precision, 3)
+                .unwrap();
+            let batch = RecordBatch::try_from_iter([("d", Arc::new(array) as ArrayRef)]).unwrap();
+
+            for (byte_width, expected_width) in [(None, min_width), (Some(32), 32)] {
+                let props = WriterProperties::builder()
+                    .set_decimal256_byte_width(byte_width)
+                    .build();
+                let mut buf = Vec::new();
+                let mut writer =
+                    ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+                writer.write(&batch).unwrap();
+                let metadata = writer.close().unwrap();
+
+                let column = metadata.row_group(0).column(0);
+                assert_eq!(column.column_descr().type_length(), expected_width);
+                let Some(Statistics::FixedLenByteArray(stats)) = column.statistics() else {
+                    panic!("expected FIXED_LEN_BYTE_ARRAY statistics")
+                };
+                let (min, max_stat) = (stats.min_opt().unwrap(), stats.max_opt().unwrap());
+                assert_eq!(min.len(), expected_width as usize);
+                assert_eq!(max_stat.len(), expected_width as usize);
+                assert_eq!(
+                    i256::from_be_bytes(sign_extend_be(min.as_bytes())),
+                    max.neg_wrapping()
+                );
+                assert_eq!(
+                    i256::from_be_bytes(sign_extend_be(max_stat.as_bytes())),
+                    max
+                );
+
+                let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+                    .unwrap()
+                    .next()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(read, batch);
+            }
+        }
+    }
+
+    #[test]
+    fn arrow_writer_writer_version_round_trip() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "int",
+                Arc::new(Int64Array::from_iter(
+                    (0..1000).map(|i| (i % 7 != 0).then_some(i)),
+                )) as ArrayRef,
+            ),
+            (
+                "string",
+                Arc::new(StringArray::from_iter_values(
+                    (0..1000).map(|i| format!("{i}")),
+                )),
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from_iter_values(0..1000)
+                        .with_precision_and_scale(9, 2)
+                        .unwrap(),
+                ),
+            ),
+        ])
+        .unwrap();
+
+        for (version, expected) in [
+            (WriterVersion::PARQUET_1_0, PageType::DATA_PAGE),
+            (WriterVersion::PARQUET_2_0, PageType::DATA_PAGE_V2),
+        ] {
+            let props = WriterProperties::builder()
+                .set_writer_version(version)
+                .set_dictionary_enabled(false)
+                .set_data_page_row_count_limit(100)
+                .set_write_batch_size(100)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            let buf = Bytes::from(buf);
+
+            let reader = SerializedFileReader::new(buf.clone()).unwrap();
+            assert_eq!(
+                reader.metadata().file_metadata().version(),
+                version.as_num()
+            );
+            let row_group = reader.get_row_group(0).unwrap();
+            for column in 0..row_group.num_columns() {
+                let pages = row_group
+                    .get_column_page_reader(column)
+                    .unwrap()
+                    .collect::<Result<Vec<_>>>()
+                    .unwrap();
+                assert_eq!(pages.len(), 10);
+                assert!(pages.iter().all(|p| p.page_type() == expected));
+            }
+
+            let read = ParquetRecordBatchReader::try_new(buf, 1024)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert_eq!(read, batch);
+        }
+    }
+
+    #[test]
+    fn arrow_writer_compatibility() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "ts",
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    Some(1_000_000),
+                    None,
+                    Some(-1),
+                ])) as ArrayRef,
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![Some(12345), Some(-1), None])
+                        .with_precision_and_scale(9, 2)
+                        .unwrap(),
+                ),
+            ),
+        ])
+        .unwrap();
+
+        for (target, decimal_type) in [
+            (WriterCompatibility::Spark, PhysicalType::INT32),
+            (
+                WriterCompatibility::Hive,
+                PhysicalType::FIXED_LEN_BYTE_ARRAY,
+            ),
+        ] {
+            let props = WriterProperties::builder()
+                .set_writer_version(WriterVersion::PARQUET_2_0)
+                .set_compatibility(target)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            let metadata = writer.close().unwrap();
+
+            let file_metadata = metadata.file_metadata();
+            assert_eq!(file_metadata.version(), 1);
+            let schema = file_metadata.schema_descr();
+            assert_eq!(schema.column(0).physical_type(), PhysicalType::INT96);
+            assert_eq!(schema.column(1).physical_type(), decimal_type);
+            assert_eq!(
+                schema.column(1).logical_type(),
+                Some(crate::basic::LogicalType::Decimal {
+                    scale: 2,
+                    precision: 9
+                })
+            );
+
+            let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert_eq!(read, batch);
+        }
+
+        // Options can be overridden after applying a preset
+        let props = WriterProperties::builder()
+            .set_compatibility(WriterCompatibility::Hive)
+            .set_int96_timestamps(false)
+            .build();
+        assert!(!props.int96_timestamps());
+        assert!(props.coerce_types());
+        assert!(props.fixed_len_byte_array_decimals());
+    }
+
+    #[test]
+    fn arrow_writer_fixed_len_byte_array_decimals() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal32(5, 2), true),
+            Field::new("b", DataType::Decimal64(18, 2), true),
+            Field::new("c", DataType::Decimal128(12, 0), true),
+            Field::new("d", DataType::Decimal256(10, 0), true),
+            Field::new("e", DataType::Decimal128(30, 0), true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(
+                    Decimal32Array::from(vec![Some(99999), None, Some(-99999)])
+                        .with_precision_and_scale(5, 2)
+                        .unwrap(),
+                ),
+                Arc::new(
+                    Decimal64Array::from(vec![Some(999_999_999_999_999_999), Some(-1), None])
+                        .with_precision_and_scale(18, 2)
+                        .unwrap(),
+                ),
+                Arc::new(
+                    Decimal128Array::from(vec![Some(1), Some(-999_999_999_999), None])
+                        .with_precision_and_scale(12, 0)
+                        .unwrap(),
+                ),
+                Arc::new(
+                    Decimal256Array::from(vec![
+                        Some(i256::from_i128(-9_999_999_999)),
+                        None,
+                        Some(i256::ZERO),
+                    ])
+                    .with_precision_and_scale(10, 0)
+                    .unwrap(),
+                ),
+                Arc::new(
+                    Decimal128Array::from(vec![None, Some(i128::from(i64::MAX) * 1000), Some(7)])
+                        .with_precision_and_scale(30, 0)
+                        .unwrap(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_fixed_len_byte_array_decimals(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let widths: Vec<_> = parquet_schema
+            .columns()
+            .iter()
+            .map(|c| {
+                assert_eq!(c.physical_type(), PhysicalType::FIXED_LEN_BYTE_ARRAY);
+                c.type_length()
+            })
+            .collect();
+        assert_eq!(widths, vec![3, 8, 6, 5, 13]);
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn arrow_writer_decimal256_invalid_byte_width() {
+        let array = Decimal256Array::from(vec![i256::from_i128(1)])
+            .with_precision_and_scale(50, 0)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(array) as ArrayRef)]).unwrap();
+
+        for byte_width in [20, 33] {
+            let props = WriterProperties::builder()
+                .set_decimal256_byte_width(Some(byte_width))
+                .build();
+            let err = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Arrow: Invalid byte width {byte_width} for Decimal256 field 'd' with \
+                     precision 50, expected a value between 21 and 32"
+                )
+            );
+        }
+
+        // Decimal128 and narrow Decimal256 columns are unaffected
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal128(38, 0), false),
+            Field::new("b", DataType::Decimal256(10, 0), false),
+        ]));
+        let parquet_schema = ArrowSchemaConverter::new()
+            .with_decimal256_byte_width(Some(32))
+            .convert(&schema)
+            .unwrap();
+        assert_eq!(parquet_schema.column(0).type_length(), 16);
+        assert_eq!(
+            parquet_schema.column(1).physical_type(),
+            PhysicalType::INT64
+        );
+    }
+
     #[test]
     fn arrow_writer_string_dictionary_unsigned_index() {
         // define schema
@@ -3862,17 +5713,81 @@ mod tests {
                 .into_iter()
                 .collect(),
         );
-
+
+        let batch = RecordBatch::try_new(
+            Arc::new(batch_schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as _],
+        )
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, Arc::new(file_schema), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn test_arrow_writer_field_metadata_roundtrip() {
+        let metadata = |key: &str, value: &str| {
+            std::collections::HashMap::from([(key.to_string(), value.to_string())])
+        };
+        let child =
+            Field::new("celsius", DataType::Float64, true).with_metadata(metadata("unit", "degC"));
+        let struct_field = Field::new_struct("reading", vec![child.clone()], true)
+            .with_metadata(metadata("sensor", "thermometer"));
+        let item =
+            Field::new_list_field(DataType::Int32, true).with_metadata(metadata("unit", "ms"));
+        let list_field = Field::new_list("latencies", item.clone(), true);
+        let id_field = Field::new("id", DataType::Int64, false)
+            .with_metadata(metadata("semantics", "primary key"));
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![id_field, struct_field, list_field],
+            metadata("origin", "test"),
+        ));
+
+        let struct_array = StructArray::from(vec![(
+            Arc::new(child),
+            Arc::new(Float64Array::from(vec![21.5, 22.0])) as ArrayRef,
+        )]);
+        let list_array = ListArray::new(
+            Arc::new(item),
+            OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            None,
+        );
         let batch = RecordBatch::try_new(
-            Arc::new(batch_schema),
-            vec![Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as _],
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(struct_array),
+                Arc::new(list_array),
+            ],
         )
         .unwrap();
 
         let mut buf = Vec::with_capacity(1024);
-        let mut writer = ArrowWriter::try_new(&mut buf, Arc::new(file_schema), None).unwrap();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), None).unwrap();
         writer.write(&batch).unwrap();
+        writer.append_key_value_metadata(KeyValue::new("custom".to_string(), "value".to_string()));
         writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let key_value_metadata = builder.metadata().file_metadata().key_value_metadata();
+        let custom = key_value_metadata
+            .unwrap()
+            .iter()
+            .find(|kv| kv.key == "custom")
+            .unwrap();
+        assert_eq!(custom.value.as_deref(), Some("value"));
+
+        let read_schema = builder.schema().clone();
+        assert_eq!(read_schema.fields(), schema.fields());
+        assert_eq!(read_schema.metadata().get("origin").unwrap(), "test");
+        assert_eq!(read_schema.metadata().get("custom").unwrap(), "value");
+
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read.schema().fields(), schema.fields());
+        assert_eq!(read.columns(), batch.columns());
     }
 
     #[test]
@@ -3899,6 +5814,148 @@ mod tests {
         assert_eq!(back.column(0).as_ref(), batch.column(0).as_ref());
     }
 
+    #[test]
+    fn columnwise_row_group_roundtrip() {
+        let struct_fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("nested", DataType::Struct(struct_fields.clone()), true),
+            Field::new_list("list", Field::new_list_field(DataType::Int32, true), true),
+        ]));
+
+        let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(0..6));
+        let nested: ArrayRef = Arc::new(StructArray::new(
+            struct_fields,
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(3),
+                    Some(4),
+                    None,
+                    Some(6),
+                ])),
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e", "f"])),
+            ],
+            Some(NullBuffer::from(vec![true, true, false, true, true, true])),
+        ));
+        let list: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(2), None]),
+            Some(vec![Some(3), Some(4), Some(5)]),
+            None,
+        ]));
+        let expected = RecordBatch::try_new(
+            schema.clone(),
+            vec![ids.clone(), nested.clone(), list.clone()],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+        let mut row_group = writer.start_columnwise_row_group().unwrap();
+        // write the columns out of order, and in chunks of different sizes
+        row_group.write_column(2, &list.slice(0, 4)).unwrap();
+        row_group.write_column(1, &nested).unwrap();
+        row_group.write_column(2, &list.slice(4, 2)).unwrap();
+        row_group.write_column(0, &ids.slice(0, 1)).unwrap();
+        row_group.write_column(0, &ids.slice(1, 5)).unwrap();
+        assert_eq!(row_group.column_rows(0), Some(6));
+        assert_eq!(row_group.column_rows(2), Some(6));
+        row_group.finalize().unwrap();
+
+        // subsequent batches are written to a new row group
+        writer.write(&expected).unwrap();
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+        assert_eq!(metadata.row_group(0).num_rows(), 6);
+
+        let batches = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        let expected = concat_batches(&expected.schema(), [&expected, &expected]).unwrap();
+        assert_eq!(batches, vec![expected]);
+    }
+
+    #[test]
+    fn columnwise_row_group_mismatched_lengths() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let expected = RecordBatch::try_new(schema.clone(), vec![a.clone(), b.clone()]).unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+        writer.write(&expected).unwrap();
+
+        let mut row_group = writer.start_columnwise_row_group().unwrap();
+        row_group.write_column(0, &a).unwrap();
+        row_group.write_column(1, &b.slice(0, 2)).unwrap();
+        let err = row_group.finalize().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot write row group with inconsistent column lengths: \
+             column \"a\" has 3 rows but column \"b\" has 2 rows"
+        );
+
+        let mut row_group = writer.start_columnwise_row_group().unwrap();
+        let err = row_group.write_column(0, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Incompatible type for column \"a\": expected Int32 but got Utf8"
+        );
+        let err = row_group.write_column(2, &a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column index 2 out of bounds for schema with 2 columns"
+        );
+        assert_eq!(row_group.column_rows(2), None);
+        // dropping the row group writer discards it
+        drop(row_group);
+
+        // a column that failed partway through being written poisons the writer
+        let mut row_group = writer.start_columnwise_row_group().unwrap();
+        row_group.write_column(0, &a).unwrap();
+        row_group.poisoned = Some(1);
+        let expected_err = "Parquet error: Cannot use row group writer after column \"b\" \
+                            failed partway through being written";
+        let err = row_group.write_column(1, &b).unwrap_err();
+        assert_eq!(err.to_string(), expected_err);
+        let err = row_group.finalize().unwrap_err();
+        assert_eq!(err.to_string(), expected_err);
+
+        let mut row_group = writer.start_columnwise_row_group().unwrap();
+        row_group.write_column(1, &b).unwrap();
+        row_group.write_column(0, &a).unwrap();
+        row_group.finalize().unwrap();
+
+        // no rows, no row group
+        writer
+            .start_columnwise_row_group()
+            .unwrap()
+            .finalize()
+            .unwrap();
+
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        let batches = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        let expected = concat_batches(&expected.schema(), [&expected, &expected]).unwrap();
+        assert_eq!(batches, vec![expected]);
+    }
+
     #[test]
     fn in_progress_accounting() {
         // define schema
@@ -3953,6 +6010,44 @@ mod tests {
         writer.close().unwrap();
     }
 
+    #[test]
+    fn memory_accounting_large_strings() {
+        // 1000 distinct strings of 1KiB each
+        let data_size = 1000 * 1024;
+        let values = StringArray::from_iter_values((0..1000).map(|i| format!("{i:01024}")));
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(values) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build();
+        let mut writer = ArrowWriter::try_new(vec![], batch.schema(), Some(props)).unwrap();
+        let header_bytes = writer.flushed_bytes();
+        assert_eq!(writer.memory_size(), 0);
+
+        writer.write(&batch).unwrap();
+        let memory_size = writer.memory_size();
+        assert_eq!(writer.in_progress_rows(), 1000);
+        assert!(
+            memory_size >= data_size && memory_size < data_size * 2,
+            "memory_size {memory_size} should be close to {data_size}"
+        );
+        // nothing is flushed until the row group is complete
+        assert_eq!(writer.flushed_bytes(), header_bytes);
+
+        writer.write(&batch).unwrap();
+        assert!(writer.memory_size() >= memory_size + data_size);
+        assert_eq!(writer.in_progress_rows(), 2000);
+
+        writer.flush().unwrap();
+        assert_eq!(writer.memory_size(), 0);
+        assert_eq!(writer.in_progress_rows(), 0);
+        let flushed = writer.flushed_bytes();
+        assert!(flushed >= 2 * data_size, "{flushed}");
+        assert!(flushed <= writer.bytes_written());
+
+        writer.close().unwrap();
+    }
+
     #[test]
     fn test_writer_all_null() {
         let a = Int32Array::from(vec![1, 2, 3, 4, 5]);
@@ -4243,6 +6338,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arrow_writer_write_reader() {
+        let values = Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef;
+        let field = Field::new("a", DataType::Int32, false);
+        let writer_schema = Arc::new(Schema::new(vec![field.clone()]));
+
+        // The input only differs from the writer schema in its metadata
+        let metadata = std::collections::HashMap::from([("key".to_string(), "value".to_string())]);
+        let input_schema = Arc::new(Schema::new_with_metadata(
+            vec![field.with_metadata(metadata.clone())],
+            metadata,
+        ));
+        let batch = RecordBatch::try_new(input_schema.clone(), vec![values]).unwrap();
+        let batches = (0..5).map(|_| Ok(batch.clone()));
+        let reader = RecordBatchIterator::new(batches, input_schema);
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(15)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), writer_schema, Some(props)).unwrap();
+        assert_eq!(writer.write_reader(reader).unwrap(), 50);
+        let metadata = writer.close().unwrap();
+        let row_groups: Vec<_> = metadata.row_groups().iter().map(|r| r.num_rows()).collect();
+        assert_eq!(row_groups, vec![15, 15, 15, 5]);
+    }
+
+    #[test]
+    fn arrow_writer_write_reader_mismatch() {
+        let writer_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let write = |batch: RecordBatch| {
+            let reader = RecordBatchIterator::new([Ok(batch.clone())], batch.schema());
+            let mut writer = ArrowWriter::try_new(Vec::new(), writer_schema.clone(), None).unwrap();
+            writer.write_reader(reader).unwrap_err().to_string()
+        };
+
+        let a = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec!["b"])) as ArrayRef;
+        let c = Arc::new(Int64Array::from(vec![1])) as ArrayRef;
+
+        let batch = RecordBatch::try_from_iter([("a", a.clone())]).unwrap();
+        assert_eq!(
+            write(batch),
+            "Parquet error: Incompatible schema, expected 2 fields but got 1"
+        );
+
+        let batch = RecordBatch::try_from_iter([("a", a.clone()), ("c", b.clone())]).unwrap();
+        assert_eq!(
+            write(batch),
+            "Parquet error: Incompatible schema, field 1 should be named 'b' but got 'c'"
+        );
+
+        let batch = RecordBatch::try_from_iter([("a", c), ("b", b)]).unwrap();
+        assert_eq!(
+            write(batch),
+            "Parquet error: Incompatible schema, field 'a' should have type Int32 but got Int64"
+        );
+    }
+
     #[test]
     // https://github.com/apache/arrow-rs/issues/6988
     fn test_roundtrip_empty_schema() {
@@ -4398,6 +6554,102 @@ mod tests {
         assert_eq!(stats.min_value.unwrap(), "Bl".as_bytes());
     }
 
+    #[test]
+    fn test_statistics_truncation_brackets_values() {
+        let strings: Vec<String> = (0..200)
+            .map(|i| match i % 4 {
+                0 => format!("prefix-{i:04}-{}", "x".repeat(i % 30)),
+                1 => format!("préfixé-{i}-日本語テキスト"),
+                2 => "\u{10FFFF}".repeat(i % 5 + 3),
+                _ => format!("zz{}", "\u{7F}".repeat(i % 9 + 8)),
+            })
+            .collect();
+        let binaries: Vec<Vec<u8>> = (0..200_usize)
+            .map(|i| match i % 3 {
+                0 => vec![0xFF; i % 7 + 9],
+                1 => [b"bin".as_slice(), &(i as u64).to_be_bytes()].concat(),
+                _ => vec![
+                    0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, i as u8,
+                ],
+            })
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "utf8View",
+                Arc::new(StringViewArray::from_iter_values(&strings)) as ArrayRef,
+            ),
+            (
+                "binaryView",
+                Arc::new(BinaryViewArray::from_iter_values(&binaries)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        const TRUNCATE_LENGTH: usize = 8;
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(TRUNCATE_LENGTH))
+            .set_column_index_truncate_length(Some(TRUNCATE_LENGTH))
+            .set_data_page_row_count_limit(32)
+            .set_write_batch_size(32)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let reader = SerializedFileReader::new_with_options(Bytes::from(buf), options).unwrap();
+        let metadata = reader.metadata();
+        let offset_index = &metadata.offset_index().unwrap()[0];
+        let column_index = &metadata.column_index().unwrap()[0];
+
+        let values: [Vec<&[u8]>; 2] = [
+            strings.iter().map(|s| s.as_bytes()).collect(),
+            binaries.iter().map(|b| b.as_slice()).collect(),
+        ];
+        for (col, values) in values.iter().enumerate() {
+            let is_utf8 = col == 0;
+            let check_bounds = |values: &[&[u8]], min: &[u8], max: &[u8]| {
+                for v in values {
+                    assert!(min <= *v, "{min:?} > {v:?}");
+                    assert!(*v <= max, "{v:?} > {max:?}");
+                }
+                // A bound that could not be truncated is the exact value
+                assert!(min.len() <= TRUNCATE_LENGTH || values.contains(&min));
+                assert!(max.len() <= TRUNCATE_LENGTH || values.contains(&max));
+                if is_utf8 {
+                    std::str::from_utf8(min).unwrap();
+                    std::str::from_utf8(max).unwrap();
+                }
+            };
+
+            // Column chunk statistics
+            let stats = metadata.row_group(0).column(col).statistics().unwrap();
+            let (min, max) = (
+                stats.min_bytes_opt().unwrap(),
+                stats.max_bytes_opt().unwrap(),
+            );
+            check_bounds(values, min, max);
+            assert!(!stats.min_is_exact());
+
+            // Page statistics in the column index
+            let ColumnIndexMetaData::BYTE_ARRAY(index) = &column_index[col] else {
+                panic!("expected byte array column index");
+            };
+            let locations = offset_index[col].page_locations();
+            assert!(locations.len() > 1);
+            for (page, location) in locations.iter().enumerate() {
+                let start = location.first_row_index as usize;
+                let end = locations
+                    .get(page + 1)
+                    .map_or(values.len(), |l| l.first_row_index as usize);
+                let min = index.min_value(page).unwrap();
+                let max = index.max_value(page).unwrap();
+                check_bounds(&values[start..end], min, max);
+            }
+        }
+    }
+
     #[test]
     fn test_page_encoding_statistics_roundtrip() {
         let batch_schema = Schema::new(vec![Field::new(
@@ -4444,6 +6696,304 @@ mod tests {
         assert_eq!(chunk_page_stats, file_page_stats);
     }
 
+    #[test]
+    fn test_page_encoding_statistics_dictionary_fallback() {
+        let fallback = Arc::new(Int64Array::from_iter_values(0..4096)) as ArrayRef;
+        let repeated = Arc::new(Int64Array::from_iter_values((0..4096).map(|i| i % 4))) as ArrayRef;
+        let batch =
+            RecordBatch::try_from_iter([("fallback", fallback), ("repeated", repeated)]).unwrap();
+
+        // The dictionary of "fallback" exceeds the limit part way through the chunk
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(1024)
+            .set_data_page_row_count_limit(256)
+            .set_write_batch_size(256)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let written = writer.close().unwrap();
+
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let reader = SerializedFileReader::new_with_options(Bytes::from(buf), options).unwrap();
+        let read = reader.metadata().row_group(0);
+
+        let fallback = written.row_group(0).column(0);
+        let stats = fallback.page_encoding_stats().unwrap();
+        assert_eq!(stats[0].page_type, PageType::DICTIONARY_PAGE);
+        let data_page_encodings: Vec<_> = stats
+            .iter()
+            .filter(|s| s.page_type == PageType::DATA_PAGE)
+            .map(|s| s.encoding)
+            .collect();
+        assert_eq!(
+            data_page_encodings,
+            vec![Encoding::RLE_DICTIONARY, Encoding::PLAIN]
+        );
+        assert!(!fallback.dictionary_encoded_fully());
+        assert_eq!(read.column(0).page_encoding_stats(), Some(stats));
+        assert!(!read.column(0).dictionary_encoded_fully());
+
+        let repeated = written.row_group(0).column(1);
+        assert!(repeated.dictionary_encoded_fully());
+        assert_eq!(
+            read.column(1).page_encoding_stats(),
+            repeated.page_encoding_stats()
+        );
+        assert!(read.column(1).dictionary_encoded_fully());
+    }
+
+    #[test]
+    fn arrow_writer_size_statistics() {
+        let strings: Vec<_> = (0..100)
+            .map(|i| (i % 7 != 0).then(|| "x".repeat(i % 13)))
+            .collect();
+        let string_bytes: i64 = strings.iter().flatten().map(|s| s.len() as i64).sum();
+
+        // [["0", "1", "2"], ["3", "4", "5"], ...]
+        let offsets = OffsetBuffer::new((0..=100).map(|i| i * 3).collect::<Vec<i32>>().into());
+        let items = StringArray::from_iter_values((0..300).map(|i| i.to_string()));
+        let item_bytes: i64 = (0..300).map(|i| i.to_string().len() as i64).sum();
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, true)),
+            offsets,
+            Arc::new(items),
+            None,
+        );
+
+        let batch = RecordBatch::try_from_iter([
+            (
+                "string",
+                Arc::new(StringArray::from(strings.clone())) as ArrayRef,
+            ),
+            ("view", Arc::new(StringViewArray::from(strings)) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&Bytes::from(buf))
+            .unwrap();
+        let offset_index = metadata.offset_index().unwrap();
+        let row_group = metadata.row_group(0);
+
+        for (idx, expected) in [string_bytes, string_bytes, item_bytes]
+            .into_iter()
+            .enumerate()
+        {
+            let column = row_group.column(idx);
+            assert_eq!(column.unencoded_byte_array_data_bytes(), Some(expected));
+
+            // The per-page values in the offset index sum to the chunk value
+            let pages = offset_index[0][idx]
+                .unencoded_byte_array_data_bytes()
+                .unwrap();
+            assert!(pages.len() > 1);
+            assert_eq!(pages.iter().sum::<i64>(), expected);
+        }
+
+        let null_strings = (0..100).filter(|i| i % 7 == 0).count() as i64;
+        for idx in [0, 1] {
+            let column = row_group.column(idx);
+            assert!(column.repetition_level_histogram().is_none());
+            let def = column.definition_level_histogram().unwrap().values();
+            assert_eq!(def, &[null_strings, 100 - null_strings]);
+        }
+
+        let list = row_group.column(2);
+        let rep = list.repetition_level_histogram().unwrap().values();
+        assert_eq!(rep, &[100, 200]);
+        let def = list.definition_level_histogram().unwrap().values();
+        assert_eq!(def, &[0, 0, 300]);
+    }
+
+    #[test]
+    fn test_dictionary_max_value_length() {
+        let long = "x".repeat(100);
+        let values: Vec<_> = (0..50)
+            .map(|i| match i {
+                25 => long.as_str(),
+                _ if i % 2 == 0 => "even",
+                _ => "odd",
+            })
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(StringViewArray::from(values.clone())) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringViewArray::from(vec!["short"; 50])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_write_batch_size(10)
+            .set_data_page_row_count_limit(10)
+            .set_dictionary_max_value_length(64)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let data_page_encodings = |col: usize| {
+            let stats = metadata.row_group(0).column(col).page_encoding_stats();
+            stats
+                .unwrap()
+                .iter()
+                .filter(|s| s.page_type == PageType::DATA_PAGE)
+                .map(|s| (s.encoding, s.count))
+                .collect::<Vec<_>>()
+        };
+        // "a" falls back to PLAIN after the mini batch containing the long value
+        assert_eq!(
+            data_page_encodings(0),
+            vec![(Encoding::RLE_DICTIONARY, 3), (Encoding::PLAIN, 2)]
+        );
+        assert_eq!(data_page_encodings(1), vec![(Encoding::RLE_DICTIONARY, 5)]);
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn test_dictionary_deduplicate_views() {
+        let pool = StringViewArray::from(vec![
+            Some("a string that is longer than twelve bytes"),
+            None,
+            Some("short"),
+            Some("another string that is not inlined in the view"),
+        ]);
+        let indices = UInt32Array::from_iter_values((0..1000).map(|i| i * 7 % 4));
+        let strings = arrow::compute::take(&pool, &indices, None).unwrap();
+        let binary = Arc::new(strings.as_string_view().clone().to_binary_view()) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([
+            ("string", strings.slice(3, 990)),
+            ("binary", binary.slice(3, 990)),
+        ])
+        .unwrap();
+
+        let write = |deduplicate_views: bool| {
+            let props = WriterProperties::builder()
+                .set_write_batch_size(100)
+                .set_dictionary_deduplicate_views(deduplicate_views)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            buf
+        };
+
+        let deduplicated = write(true);
+        // deduplicating views does not change the encoded data
+        assert_eq!(deduplicated, write(false));
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(deduplicated), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    /// Writes `batch` as a single row group with `props`, returning the file
+    /// and the number of rows in each data page of column 0
+    fn write_page_row_counts(batch: &RecordBatch, props: WriterProperties) -> (Bytes, Vec<i64>) {
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&data)
+            .unwrap();
+        assert_eq!(metadata.num_row_groups(), 1);
+        let num_rows = metadata.row_group(0).num_rows();
+        let locations = &metadata.offset_index().unwrap()[0][0].page_locations;
+        let row_counts = locations
+            .iter()
+            .map(|l| l.first_row_index)
+            .chain(std::iter::once(num_rows))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect();
+        (data, row_counts)
+    }
+
+    #[test]
+    fn test_data_page_row_count_limit_list() {
+        // mostly short lists, with a very long list every 100 records
+        let lengths = (0..1000).map(|i| if i % 100 == 0 { 10_000 } else { i % 3 });
+        let offsets = OffsetBuffer::<i32>::from_lengths(lengths);
+        let num_values = *offsets.last().unwrap() as i64;
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Int64, false)),
+            offsets,
+            Arc::new(Int64Array::from_iter_values(0..num_values)),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([("list", Arc::new(list) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(50)
+            .build();
+        let (data, row_counts) = write_page_row_counts(&batch, props);
+
+        // rows are counted as records, and a page never splits a record
+        assert_eq!(row_counts, vec![50; 20]);
+
+        let read = ParquetRecordBatchReader::try_new(data, 1000)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn test_data_page_row_count_limit_boolean() {
+        let values = BooleanArray::from_iter((0..100_000).map(|i| Some(i % 3 == 0)));
+        let batch = RecordBatch::try_from_iter([("bool", Arc::new(values) as ArrayRef)]).unwrap();
+
+        // the limit is respected even when smaller than the write batch size
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(1000)
+            .set_write_batch_size(4096)
+            .build();
+        let (data, row_counts) = write_page_row_counts(&batch, props);
+        assert_eq!(row_counts, vec![1000; 100]);
+
+        let read = ParquetRecordBatchReader::try_new(data, 100_000)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
     #[test]
     fn test_different_dict_page_size_limit() {
         let array = Arc::new(Int64Array::from_iter(0..1024 * 1024));
@@ -4454,9 +7004,12 @@ mod tests {
         let batch =
             arrow_array::RecordBatch::try_new(schema.clone(), vec![array.clone(), array]).unwrap();
 
+        // The dictionary limit is checked after each write batch, so keep pages a
+        // multiple of the write batch size to reach exactly the configured limit
         let props = WriterProperties::builder()
             .set_dictionary_page_size_limit(1024 * 1024)
             .set_column_dictionary_page_size_limit(ColumnPath::from("col1"), 1024 * 1024 * 4)
+            .set_data_page_row_count_limit(16 * 1024)
             .build();
         let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(props)).unwrap();
         writer.write(&batch).unwrap();
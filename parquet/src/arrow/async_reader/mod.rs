@@ -39,8 +39,8 @@ use arrow_array::RecordBatch;
 use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 
 use crate::arrow::arrow_reader::{
-    ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
-    RowFilter, RowSelection,
+    ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, BatchTransform, Executor,
+    ParquetRecordBatchReader, RowFilter, RowSelection,
 };
 
 use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
@@ -48,7 +48,11 @@ use crate::bloom_filter::{
     SBBF_HEADER_SIZE_ESTIMATE, Sbbf, chunk_read_bloom_filter_header_and_offset,
 };
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader};
+use crate::file::metadata::{
+    PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, parse_row_group_offset_index,
+    row_group_offset_index_range,
+};
+use crate::file::page_index::offset_index::OffsetIndexMetaData;
 
 mod metadata;
 pub use metadata::*;
@@ -171,13 +175,18 @@ impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
         options: Option<&'a ArrowReaderOptions>,
     ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
         async move {
-            let metadata_reader = ParquetMetaDataReader::new().with_page_index_policy(
-                PageIndexPolicy::from(options.is_some_and(|o| o.page_index())),
-            );
+            let metadata_reader = ParquetMetaDataReader::new()
+                .with_page_index_policy(PageIndexPolicy::from(
+                    options.is_some_and(|o| o.page_index()),
+                ))
+                .with_lazy_column_metadata(options.is_some_and(|o| o.lazy_column_metadata()));
 
             #[cfg(feature = "encryption")]
             let metadata_reader = metadata_reader.with_decryption_properties(
-                options.and_then(|o| o.file_decryption_properties.as_ref().map(Arc::clone)),
+                options
+                    .map(|o| o.metadata_decryption_properties())
+                    .transpose()?
+                    .flatten(),
             );
 
             let parquet_metadata = metadata_reader.load_via_suffix_and_finish(self).await?;
@@ -195,7 +204,15 @@ impl ArrowReaderMetadata {
         input: &mut T,
         options: ArrowReaderOptions,
     ) -> Result<Self> {
-        let metadata = input.get_metadata(Some(&options)).await?;
+        let metadata = match options.lazy_page_index {
+            true => {
+                let metadata_options = options
+                    .clone()
+                    .with_page_index_policy(PageIndexPolicy::Skip);
+                input.get_metadata(Some(&metadata_options)).await?
+            }
+            false => input.get_metadata(Some(&options)).await?,
+        };
         Self::try_new(metadata, options)
     }
 }
@@ -359,14 +376,15 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// Create a new [`ParquetRecordBatchStreamBuilder`] with the provided async source
     /// and [`ArrowReaderOptions`].
     pub async fn new_with_options(mut input: T, options: ArrowReaderOptions) -> Result<Self> {
-        let metadata = ArrowReaderMetadata::load_async(&mut input, options).await?;
-        Ok(Self::new_with_metadata(input, metadata))
+        let metadata = ArrowReaderMetadata::load_async(&mut input, options.clone()).await?;
+        Ok(Self::new_with_metadata(input, metadata).with_reader_options(&options))
     }
 
     /// Create a [`ParquetRecordBatchStreamBuilder`] from the provided [`ArrowReaderMetadata`]
     ///
     /// This allows loading metadata once and using it to create multiple builders with
-    /// potentially different settings, that can be read in parallel.
+    /// potentially different settings, that can be read in parallel. Options that control
+    /// how column data is read are not part of the metadata, see [`Self::with_reader_options`]
     ///
     /// # Example of reading from multiple streams in parallel
     ///
@@ -483,6 +501,10 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     ///
     /// See examples on [`ParquetRecordBatchStreamBuilder::new`]
     pub fn build(self) -> Result<ParquetRecordBatchStream<T>> {
+        self.check_column_metadata()?;
+        #[cfg(feature = "encryption")]
+        self.check_column_keys()?;
+
         let num_row_groups = self.metadata.row_groups().len();
 
         let row_groups = match self.row_groups {
@@ -512,6 +534,13 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             offset: self.offset,
             metrics: self.metrics,
             max_predicate_cache_size: self.max_predicate_cache_size,
+            batch_transform: self.batch_transform,
+            verify_page_checksums: self.verify_page_checksums,
+            preallocate_from_stats: self.preallocate_from_stats,
+            decimal_validation: self.decimal_validation,
+            decompress_pool: self.decompress_pool,
+            lazy_page_index_policy: self.lazy_page_index_policy,
+            fetch_coalesce_gap: self.fetch_coalesce_gap,
         };
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
@@ -528,6 +557,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Ok(ParquetRecordBatchStream {
             metadata: self.metadata,
             batch_size,
+            row_group_aligned_batches: self.row_group_aligned_batches,
             row_groups,
             projection: self.projection,
             selection: self.selection,
@@ -570,12 +600,69 @@ struct ReaderFactory<T> {
     ///
     /// See [`RowGroupCache`] for details.
     max_predicate_cache_size: usize,
+
+    /// Optional transform applied to each decoded batch
+    batch_transform: Option<BatchTransform>,
+
+    /// Whether to verify page checksums
+    verify_page_checksums: bool,
+
+    /// Whether to preallocate byte array buffers from the column chunk metadata
+    ///
+    /// See [`ArrowReaderOptions::with_preallocate_from_stats`]
+    preallocate_from_stats: bool,
+
+    /// Whether to verify decoded decimals fit the precision of their column
+    ///
+    /// See [`ArrowReaderOptions::with_decimal_validation`]
+    decimal_validation: bool,
+
+    /// The executor on which to decompress column chunks, if any
+    ///
+    /// See [`ArrowReaderOptions::with_decompress_pool`]
+    decompress_pool: Option<Arc<dyn Executor>>,
+
+    /// Policy for reading the offset index of each row group as it is read
+    ///
+    /// See [`ArrowReaderOptions::with_lazy_page_index`]
+    lazy_page_index_policy: PageIndexPolicy,
+
+    /// The maximum gap between byte ranges merged into a single fetch, if any
+    ///
+    /// See [`ArrowReaderOptions::with_fetch_coalesce_gap`]
+    fetch_coalesce_gap: Option<usize>,
 }
 
 impl<T> ReaderFactory<T>
 where
     T: AsyncFileReader + Send,
 {
+    /// Reads the offset index of row group `row_group_idx` if the page index is
+    /// read lazily, see [`ArrowReaderOptions::with_lazy_page_index`]
+    async fn read_lazy_offset_index(
+        &mut self,
+        row_group_idx: usize,
+    ) -> Result<Option<Vec<OffsetIndexMetaData>>> {
+        if self.lazy_page_index_policy == PageIndexPolicy::Skip {
+            return Ok(None);
+        }
+        let range =
+            row_group_offset_index_range(&self.metadata, row_group_idx)?.unwrap_or_default();
+        let bytes = match range.end == range.start {
+            true => Bytes::new(),
+            false => self.input.get_bytes(range.clone()).await?,
+        };
+        self.metrics.increment_page_index_bytes_read(bytes.len());
+
+        parse_row_group_offset_index(
+            &self.metadata,
+            self.lazy_page_index_policy,
+            row_group_idx,
+            &bytes,
+            range.start,
+        )
+    }
+
     /// Reads the next row group with the provided `selection`, `projection` and `batch_size`
     ///
     /// Updates the `limit` and `offset` of the reader factory
@@ -590,18 +677,23 @@ where
     ) -> ReadResult<T> {
         // TODO: calling build_array multiple times is wasteful
 
+        let lazy_offset_index = self.read_lazy_offset_index(row_group_idx).await?;
+
         let meta = self.metadata.row_group(row_group_idx);
-        let offset_index = self
-            .metadata
-            .offset_index()
-            // filter out empty offset indexes (old versions specified Some(vec![]) when no present)
-            .filter(|index| !index.is_empty())
-            .map(|x| x[row_group_idx].as_slice());
+        let offset_index = match &lazy_offset_index {
+            Some(index) => Some(index.as_slice()),
+            None => self
+                .metadata
+                .offset_index()
+                // filter out empty offset indexes (old versions specified Some(vec![]) when no present)
+                .filter(|index| !index.is_empty())
+                .map(|x| x[row_group_idx].as_slice()),
+        };
 
         // Reuse columns that are selected and used by the filters
         let cache_projection = match self.compute_cache_projection(&projection) {
             Some(projection) => projection,
-            None => ProjectionMask::none(meta.columns().len()),
+            None => ProjectionMask::none(meta.num_columns()),
         };
         let row_group_cache = Arc::new(Mutex::new(RowGroupCache::new(
             batch_size,
@@ -611,10 +703,12 @@ where
         let mut row_group = InMemoryRowGroup {
             // schema: meta.schema_descr_ptr(),
             row_count: meta.num_rows() as usize,
-            column_chunks: vec![None; meta.columns().len()],
+            column_chunks: vec![None; meta.num_columns()],
             offset_index,
             row_group_idx,
             metadata: self.metadata.as_ref(),
+            verify_page_checksums: self.verify_page_checksums,
+            decompress_pool: self.decompress_pool.clone(),
         };
 
         let cache_options_builder = CacheOptionsBuilder::new(&cache_projection, &row_group_cache);
@@ -626,7 +720,9 @@ where
         if let Some(filter) = filter {
             let cache_options = cache_options_builder.clone().producer();
 
-            for predicate in filter.predicates.iter_mut() {
+            for index in 0..filter.predicates.len() {
+                // a mutable reference, as the predicate is `Send` but not `Sync`
+                let predicate = &mut filter.predicates[index];
                 if !plan_builder.selects_any() {
                     return Ok((self, None)); // ruled out entire row group
                 }
@@ -642,6 +738,7 @@ where
                         selection,
                         batch_size,
                         cache_mask,
+                        self.fetch_coalesce_gap,
                     )
                     .await?;
 
@@ -649,7 +746,13 @@ where
                     .with_cache_options(Some(&cache_options))
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
-                plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                plan_builder = match filter.evaluate_predicate(index, plan_builder, array_reader) {
+                    Ok(plan_builder) => plan_builder,
+                    Err(e) => {
+                        filter.handle_error(row_group_idx, e, &self.metrics)?;
+                        return Ok((self, None)); // skip entire row group
+                    }
+                };
             }
         }
 
@@ -696,6 +799,7 @@ where
                 plan_builder.selection(),
                 batch_size,
                 None,
+                self.fetch_coalesce_gap,
             )
             .await?;
 
@@ -704,9 +808,12 @@ where
         let cache_options = cache_options_builder.consumer();
         let array_reader = ArrayReaderBuilder::new(&row_group, &self.metrics)
             .with_cache_options(Some(&cache_options))
+            .with_preallocate_from_stats(self.preallocate_from_stats)
+            .with_decimal_validation(self.decimal_validation)
             .build_array_reader(self.fields.as_deref(), &projection)?;
 
-        let reader = ParquetRecordBatchReader::new(array_reader, plan);
+        let reader = ParquetRecordBatchReader::new(array_reader, plan)
+            .with_batch_transform(self.batch_transform.clone());
 
         Ok((self, Some(reader)))
     }
@@ -809,6 +916,9 @@ pub struct ParquetRecordBatchStream<T> {
 
     batch_size: usize,
 
+    /// If true, `batch_size` is ignored and one batch is produced per row group
+    row_group_aligned_batches: bool,
+
     selection: Option<RowSelection>,
 
     /// This is an option so it can be moved into a future
@@ -837,6 +947,14 @@ impl<T> ParquetRecordBatchStream<T> {
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
+
+    /// Returns the batch size to use when reading a row group with `row_count` rows
+    fn row_group_batch_size(&self, row_count: usize) -> usize {
+        match self.row_group_aligned_batches {
+            true => row_count.max(1),
+            false => self.batch_size,
+        }
+    }
 }
 
 impl<T> ParquetRecordBatchStream<T>
@@ -873,6 +991,7 @@ where
                     let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
 
                     let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
+                    let batch_size = self.row_group_batch_size(row_count);
 
                     let reader_factory = self.reader_factory.take().expect("lost reader factory");
 
@@ -881,7 +1000,7 @@ where
                             row_group_idx,
                             selection,
                             self.projection.clone(),
-                            self.batch_size,
+                            batch_size,
                         )
                         .await
                         .inspect_err(|_| {
@@ -932,13 +1051,14 @@ where
                     let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
 
                     let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
+                    let batch_size = self.row_group_batch_size(row_count);
 
                     let fut = reader
                         .read_row_group(
                             row_group_idx,
                             selection,
                             self.projection.clone(),
-                            self.batch_size,
+                            batch_size,
                         )
                         .boxed();
 
@@ -974,6 +1094,9 @@ impl InMemoryRowGroup<'_> {
     ///
     /// If `selection` is provided, only the pages required for the selection
     /// are fetched. Otherwise, all pages are fetched.
+    ///
+    /// If `coalesce_gap` is provided, ranges separated by at most that many bytes
+    /// are fetched as a single range, see [`coalesce_ranges`]
     pub(crate) async fn fetch<T: AsyncFileReader + Send>(
         &mut self,
         input: &mut T,
@@ -981,6 +1104,7 @@ impl InMemoryRowGroup<'_> {
         selection: Option<&RowSelection>,
         batch_size: usize,
         cache_mask: Option<&ProjectionMask>,
+        coalesce_gap: Option<usize>,
     ) -> Result<()> {
         // Figure out what ranges to fetch
         let FetchRanges {
@@ -988,18 +1112,64 @@ impl InMemoryRowGroup<'_> {
             page_start_offsets,
         } = self.fetch_ranges(projection, selection, batch_size, cache_mask);
         // do the actual fetch
-        let chunk_data = input.get_byte_ranges(ranges).await?.into_iter();
+        let chunk_data = match coalesce_gap {
+            Some(gap) => {
+                let coalesced = coalesce_ranges(&ranges, gap as u64);
+                let fetched = input.get_byte_ranges(coalesced.clone()).await?;
+                split_coalesced_ranges(&ranges, &coalesced, &fetched)
+            }
+            None => input.get_byte_ranges(ranges).await?,
+        }
+        .into_iter();
         // update our in memory buffers (self.column_chunks) with the fetched data
         self.fill_column_chunks(projection, page_start_offsets, chunk_data);
         Ok(())
     }
 }
+
+/// Merges `ranges` separated by at most `gap` bytes, returning the sorted merged ranges
+fn coalesce_ranges(ranges: &[Range<u64>], gap: u64) -> Vec<Range<u64>> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable_by_key(|r| r.start);
+
+    let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(gap) => {
+                last.end = last.end.max(range.end)
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// Slices the data of each of `ranges` out of `fetched`, the data of the ranges
+/// returned by [`coalesce_ranges`]
+fn split_coalesced_ranges(
+    ranges: &[Range<u64>],
+    coalesced: &[Range<u64>],
+    fetched: &[Bytes],
+) -> Vec<Bytes> {
+    ranges
+        .iter()
+        .map(|range| {
+            // The first coalesced range ending at or after the end of `range` contains it
+            let idx = coalesced.partition_point(|c| c.end < range.end);
+            let start = (range.start - coalesced[idx].start) as usize;
+            let end = (range.end - coalesced[idx].start) as usize;
+            fetched[idx].slice(start..end)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::arrow::ArrowWriter;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowSelector,
+        ArrowPredicate, ArrowPredicateFn, ErrorPolicy, Executor, ParquetRecordBatchReaderBuilder,
+        PredicateStats, RowSelector,
     };
     use crate::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
@@ -1011,10 +1181,10 @@ mod tests {
     use arrow_array::cast::AsArray;
     use arrow_array::types::Int32Type;
     use arrow_array::{
-        Array, ArrayRef, Int8Array, Int32Array, RecordBatchReader, Scalar, StringArray,
-        StructArray, UInt64Array,
+        Array, ArrayRef, BooleanArray, Int8Array, Int32Array, RecordBatchReader, Scalar,
+        StringArray, StructArray, UInt64Array,
     };
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_schema::{ArrowError, DataType, Field, Schema};
     use futures::{StreamExt, TryStreamExt};
     use rand::{Rng, rng};
     use std::collections::HashMap;
@@ -1273,6 +1443,42 @@ mod tests {
         assert_eq!(async_batches, sync_batches);
     }
 
+    #[tokio::test]
+    async fn test_async_reader_row_group_aligned_batches() {
+        let a = Int32Array::from_iter_values(0..400);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        // Write row groups of 100, 250 and 50 rows
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        for (offset, len) in [(0, 100), (100, 250), (350, 50)] {
+            writer.write(&batch.slice(offset, len)).unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(Bytes::from(buf)))
+            .await
+            .unwrap();
+        let num_row_groups = builder.metadata().num_row_groups();
+        let batches: Vec<_> = builder
+            .with_batch_size(7)
+            .with_row_group_aligned_batches(true)
+            .build()
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(batches.len(), num_row_groups);
+        let lens: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(lens, vec![100, 250, 50]);
+        assert_eq!(
+            arrow::compute::concat_batches(&batch.schema(), &batches).unwrap(),
+            batch
+        );
+    }
+
     #[tokio::test]
     async fn test_async_reader_skip_pages() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -1677,6 +1883,137 @@ mod tests {
         assert_eq!(col2.values(), &[4, 5]);
     }
 
+    #[tokio::test]
+    async fn test_row_filter_stats_callback() {
+        let a = Int32Array::from_iter_values(0..100);
+        let data = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(50)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let test = TestReader::new(buf.into());
+        let builder = ParquetRecordBatchStreamBuilder::new(test).await.unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+        let predicate = |f: fn(i32) -> bool| -> Box<dyn ArrowPredicate> {
+            Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                move |batch: RecordBatch| {
+                    let a = batch.column(0).as_primitive::<Int32Type>();
+                    Ok(BooleanArray::from_unary(a, f))
+                },
+            ))
+        };
+
+        let stats = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&stats);
+        let filter = RowFilter::new(vec![
+            predicate(|v| v % 2 == 0),
+            predicate(|v| v < 30),
+            predicate(|v| v < 20),
+        ])
+        .with_stats_callback(move |s| captured.lock().unwrap().push(s));
+
+        let stream = builder.with_row_filter(filter).build().unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 10);
+
+        // The predicates are evaluated per row group, the third predicate is
+        // not evaluated for the second row group as no rows survive
+        let stats = stats.lock().unwrap();
+        let expected = [
+            (0, 50, 25),
+            (1, 25, 15),
+            (2, 15, 10),
+            (0, 50, 25),
+            (1, 25, 0),
+        ]
+        .map(|(i, evaluated, selected)| PredicateStats {
+            predicate_index: i,
+            rows_evaluated: evaluated,
+            rows_selected: selected,
+        });
+        assert_eq!(stats.as_slice(), &expected);
+    }
+
+    #[tokio::test]
+    async fn test_row_filter_error_policy() {
+        let a = Int32Array::from_iter_values(0..9);
+        let data = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let test = TestReader::new(buf.into());
+        let builder = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+        // fails for the second row group
+        let make_filter = || {
+            RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                |batch: RecordBatch| {
+                    let a = batch.column(0).as_primitive::<Int32Type>();
+                    if a.values().contains(&4) {
+                        return Err(ArrowError::ComputeError("corrupt".to_string()));
+                    }
+                    Ok(BooleanArray::from(vec![true; a.len()]))
+                },
+            ))])
+        };
+
+        let stream = builder.with_row_filter(make_filter()).build().unwrap();
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        assert_eq!(err.to_string(), "External: Compute error: corrupt");
+
+        let metrics = ArrowReaderMetrics::enabled();
+        let errors = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&errors);
+        let filter = make_filter()
+            .with_error_policy(ErrorPolicy::SkipRowGroup)
+            .with_error_callback(move |row_group_idx, error| {
+                captured
+                    .lock()
+                    .unwrap()
+                    .push((row_group_idx, error.to_string()));
+            });
+        let stream = ParquetRecordBatchStreamBuilder::new(test)
+            .await
+            .unwrap()
+            .with_row_filter(filter)
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[0].column(0).as_primitive::<Int32Type>().values(),
+            &[0, 1, 2]
+        );
+        assert_eq!(
+            batches[1].column(0).as_primitive::<Int32Type>().values(),
+            &[6, 7, 8]
+        );
+        assert_eq!(metrics.row_groups_skipped(), Some(1));
+        assert_eq!(
+            errors.lock().unwrap().as_slice(),
+            &[(1, "Compute error: corrupt".to_string())]
+        );
+    }
+
     #[tokio::test]
     async fn test_row_filter_with_index() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -1762,6 +2099,7 @@ mod tests {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             None,
+            Default::default(),
         )
         .unwrap();
 
@@ -1778,6 +2116,13 @@ mod tests {
             offset: None,
             metrics: ArrowReaderMetrics::disabled(),
             max_predicate_cache_size: 0,
+            batch_transform: None,
+            verify_page_checksums: true,
+            preallocate_from_stats: false,
+            decimal_validation: false,
+            decompress_pool: None,
+            lazy_page_index_policy: PageIndexPolicy::Skip,
+            fetch_coalesce_gap: None,
         };
 
         let mut skip = true;
@@ -2243,6 +2588,13 @@ mod tests {
             offset: None,
             metrics: ArrowReaderMetrics::disabled(),
             max_predicate_cache_size: 0,
+            batch_transform: None,
+            verify_page_checksums: true,
+            preallocate_from_stats: false,
+            decimal_validation: false,
+            decompress_pool: None,
+            lazy_page_index_policy: PageIndexPolicy::Skip,
+            fetch_coalesce_gap: None,
         };
 
         // Provide an output projection that also selects the same nested leaf
@@ -2303,6 +2655,334 @@ mod tests {
         assert_eq!(result.len(), 8);
     }
 
+    #[tokio::test]
+    async fn test_lazy_page_index() {
+        let a = Int32Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let metrics = ArrowReaderMetrics::enabled();
+        let options = ArrowReaderOptions::new()
+            .with_page_index(true)
+            .with_lazy_page_index(true);
+        let builder = ParquetRecordBatchStreamBuilder::new_with_options(
+            TestReader::new(data.clone()),
+            options,
+        )
+        .await
+        .unwrap();
+        assert!(builder.metadata().offset_index().is_none());
+
+        let stream = builder
+            .with_row_groups(vec![3, 7])
+            .with_row_selection(RowSelection::from(vec![
+                RowSelector::skip(5),
+                RowSelector::select(10),
+                RowSelector::skip(5),
+            ]))
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+        let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        let expected: Vec<_> = (35..40).chain(70..75).collect();
+        assert_eq!(values, expected);
+
+        // Only the offset indexes of the row groups that were read are fetched
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&data)
+            .unwrap();
+        let expected_bytes: u64 = [3, 7]
+            .into_iter()
+            .map(|i| {
+                let range = row_group_offset_index_range(&metadata, i).unwrap().unwrap();
+                range.end - range.start
+            })
+            .sum();
+        assert_eq!(
+            metrics.page_index_bytes_read(),
+            Some(expected_bytes as usize)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_coalesce_gap() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..200)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(Int32Array::from_iter_values(200..400)) as ArrayRef,
+            ),
+            (
+                "c",
+                Arc::new(Int32Array::from_iter_values(400..600)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Returns the batches read and the number of column data requests made
+        let read = async |gap: Option<usize>, leaves: &[usize]| {
+            let reader = TestReader::new(data.clone());
+            let requests = reader.requests.clone();
+            let mut options = ArrowReaderOptions::new();
+            if let Some(gap) = gap {
+                options = options.with_fetch_coalesce_gap(gap);
+            }
+            let builder = ParquetRecordBatchStreamBuilder::new_with_options(reader, options)
+                .await
+                .unwrap();
+            let mask = ProjectionMask::leaves(
+                builder.metadata().file_metadata().schema_descr(),
+                leaves.iter().copied(),
+            );
+            let batches: Vec<_> = builder
+                .with_projection(mask)
+                .build()
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let requests = requests.lock().unwrap().len();
+            (batches, requests)
+        };
+
+        // One request per column chunk
+        let (expected, requests) = read(None, &[0, 1, 2]).await;
+        assert_eq!(requests, 6);
+
+        // Adjacent column chunks are merged into one request per row group
+        let (batches, requests) = read(Some(0), &[0, 1, 2]).await;
+        assert_eq!(batches, expected);
+        assert_eq!(requests, 2);
+
+        // Column "b" separates "a" and "c", so these are only merged with a larger gap
+        let (expected, requests) = read(None, &[0, 2]).await;
+        assert_eq!(requests, 4);
+        let (batches, requests) = read(Some(0), &[0, 2]).await;
+        assert_eq!(batches, expected);
+        assert_eq!(requests, 4);
+        let (batches, requests) = read(Some(1024 * 1024), &[0, 2]).await;
+        assert_eq!(batches, expected);
+        assert_eq!(requests, 2);
+    }
+
+    #[test]
+    fn test_coalesce_ranges() {
+        let ranges = vec![10..20, 0..5, 22..30, 40..50, 25..28, 50..50];
+        assert_eq!(
+            coalesce_ranges(&ranges, 0),
+            vec![0..5, 10..20, 22..30, 40..50]
+        );
+        assert_eq!(coalesce_ranges(&ranges, 2), vec![0..5, 10..30, 40..50]);
+        assert_eq!(coalesce_ranges(&ranges, 10), vec![0..50]);
+        assert_eq!(coalesce_ranges(&[], 10), vec![]);
+
+        let data = Bytes::from_iter(0..60_u8);
+        for gap in [0, 2, 10] {
+            let coalesced = coalesce_ranges(&ranges, gap);
+            let fetched: Vec<_> = coalesced
+                .iter()
+                .map(|r| data.slice(r.start as usize..r.end as usize))
+                .collect();
+            let split = split_coalesced_ranges(&ranges, &coalesced, &fetched);
+            let expected: Vec<_> = ranges
+                .iter()
+                .map(|r| data.slice(r.start as usize..r.end as usize))
+                .collect();
+            assert_eq!(split, expected);
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "zstd")]
+    async fn test_decompress_pool() {
+        /// An [`Executor`] that spawns a new thread for each task
+        #[derive(Debug)]
+        struct ThreadExecutor;
+
+        impl Executor for ThreadExecutor {
+            fn spawn(&self, task: Box<dyn FnOnce() + Send>) {
+                std::thread::spawn(task);
+            }
+        }
+
+        let a = Int32Array::from_iter_values(0..1000);
+        let b = StringArray::from_iter((0..1000).map(|v| (v % 3 != 0).then(|| format!("v{v}"))));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let props = WriterProperties::builder()
+            .set_compression(crate::basic::Compression::ZSTD(Default::default()))
+            .set_max_row_group_size(250)
+            .set_data_page_row_count_limit(50)
+            .set_write_batch_size(50)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = async |pool: Option<Arc<dyn Executor>>, selection: Option<RowSelection>| {
+            let mut options = ArrowReaderOptions::new().with_page_index(true);
+            if let Some(pool) = pool {
+                options = options.with_decompress_pool(pool);
+            }
+            let builder = ParquetRecordBatchStreamBuilder::new_with_options(
+                TestReader::new(data.clone()),
+                options,
+            )
+            .await
+            .unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+            let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::leaves(&schema_descr, [0]),
+                |batch: RecordBatch| {
+                    let a = batch.column(0).as_primitive::<Int32Type>();
+                    Ok(BooleanArray::from_unary(a, |v| v % 2 == 0))
+                },
+            ))]);
+            let mut builder = builder.with_batch_size(100).with_row_filter(filter);
+            if let Some(selection) = selection {
+                builder = builder.with_row_selection(selection);
+            }
+            let stream = builder.build().unwrap();
+            stream.try_collect::<Vec<_>>().await.unwrap()
+        };
+
+        let expected = read(None, None).await;
+        let batches = read(Some(Arc::new(ThreadExecutor)), None).await;
+        assert_eq!(batches, expected);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 500);
+
+        // Only some pages are fetched, which are decompressed on the reading thread
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(120),
+            RowSelector::select(400),
+            RowSelector::skip(300),
+            RowSelector::select(10),
+        ]);
+        let expected = read(None, Some(selection.clone())).await;
+        let batches = read(Some(Arc::new(ThreadExecutor)), Some(selection)).await;
+        assert_eq!(batches, expected);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 205);
+    }
+
+    #[tokio::test]
+    async fn test_page_index_columns() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(Int32Array::from_iter_values(100..200)) as ArrayRef,
+            ),
+            (
+                "c",
+                Arc::new(Int32Array::from_iter_values(200..300)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(50)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let mut reader = TestReader::new(data.clone());
+        let requests = reader.requests.clone();
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .with_page_index_columns([1])
+            .load_and_finish(&mut reader, data.len() as u64)
+            .await
+            .unwrap();
+
+        // Only the page index of column "b" is fetched, after the footer and metadata,
+        // with the nearby ranges of the different row groups coalesced into one request
+        let needed: Vec<_> = metadata
+            .row_groups()
+            .iter()
+            .flat_map(|rg| {
+                let c = rg.column(1);
+                [c.column_index_range(), c.offset_index_range()]
+            })
+            .map(Option::unwrap)
+            .collect();
+        let start = needed.iter().map(|r| r.start).min().unwrap() as usize;
+        let end = needed.iter().map(|r| r.end).max().unwrap() as usize;
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2], start..end);
+
+        let offset_index = metadata.offset_index().unwrap();
+        assert!(offset_index[0][0].page_locations().is_empty());
+        assert_eq!(offset_index[0][1].page_locations().len(), 5);
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let metadata = ArrowReaderMetadata::try_new(Arc::new(metadata), options).unwrap();
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(15),
+            RowSelector::select(10),
+            RowSelector::skip(50),
+            RowSelector::select(5),
+            RowSelector::skip(20),
+        ]);
+        let stream = ParquetRecordBatchStreamBuilder::new_with_metadata(
+            TestReader::new(data.clone()),
+            metadata,
+        )
+        .with_row_selection(selection.clone())
+        .build()
+        .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+
+        let expected = arrow::compute::concat_batches(
+            &batch.schema(),
+            &[batch.slice(15, 10), batch.slice(75, 5)],
+        )
+        .unwrap();
+        let actual = arrow::compute::concat_batches(&batch.schema(), &batches).unwrap();
+        assert_eq!(actual, expected);
+    }
+
     #[tokio::test]
     #[allow(deprecated)]
     async fn empty_offset_index_doesnt_panic_in_column_chunks() {
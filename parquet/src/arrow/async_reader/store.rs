@@ -20,7 +20,9 @@ use std::{ops::Range, sync::Arc};
 use crate::arrow::arrow_reader::ArrowReaderOptions;
 use crate::arrow::async_reader::{AsyncFileReader, MetadataSuffixFetch};
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader};
+use crate::file::metadata::{
+    DEFAULT_PREFETCH_HINT, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader,
+};
 use bytes::Bytes;
 use futures::{FutureExt, TryFutureExt, future::BoxFuture};
 use object_store::{GetOptions, GetRange};
@@ -78,6 +80,9 @@ impl ParquetObjectReader {
 
     /// Provide a hint as to the size of the parquet file's footer,
     /// see [`ParquetMetaDataReader::with_prefetch_hint`]
+    ///
+    /// Defaults to [`DEFAULT_PREFETCH_HINT`], so that the metadata of most
+    /// files is read in a single request
     pub fn with_footer_size_hint(self, hint: usize) -> Self {
         Self {
             metadata_size_hint: Some(hint),
@@ -202,13 +207,15 @@ impl AsyncFileReader for ParquetObjectReader {
             let mut metadata = ParquetMetaDataReader::new()
                 .with_column_index_policy(PageIndexPolicy::from(self.preload_column_index))
                 .with_offset_index_policy(PageIndexPolicy::from(self.preload_offset_index))
-                .with_prefetch_hint(self.metadata_size_hint);
+                .with_prefetch_hint(Some(
+                    self.metadata_size_hint.unwrap_or(DEFAULT_PREFETCH_HINT),
+                ))
+                .with_lazy_column_metadata(options.is_some_and(|o| o.lazy_column_metadata()));
 
             #[cfg(feature = "encryption")]
             if let Some(options) = options {
-                metadata = metadata.with_decryption_properties(
-                    options.file_decryption_properties.as_ref().map(Arc::clone),
-                );
+                metadata =
+                    metadata.with_decryption_properties(options.metadata_decryption_properties()?);
             }
 
             let metadata = if let Some(file_size) = self.file_size {
@@ -72,8 +72,8 @@ use crate::{
 use arrow_array::RecordBatch;
 use arrow_schema::SchemaRef;
 use bytes::Bytes;
-use futures::FutureExt;
 use futures::future::BoxFuture;
+use futures::{FutureExt, Stream, StreamExt};
 use std::mem;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
@@ -91,6 +91,50 @@ pub trait AsyncFileWriter: Send {
     ///
     /// After `complete` returns `Ok(())`, caller SHOULD not call write again.
     fn complete(&mut self) -> BoxFuture<'_, Result<()>>;
+
+    /// Write a [`FileChunk`] of the file, that is the bytes of a single row group or the footer
+    ///
+    /// [`AsyncArrowWriter`] writes all data with this method, as soon as each row group
+    /// is flushed, and the footer when it is closed. The chunks are passed in file order,
+    /// and their concatenation is the complete parquet file. Implementations can override
+    /// this to process each row group independently, for example to upload each as a part
+    /// of a multipart upload whilst the next row group is being encoded.
+    ///
+    /// The default implementation calls [`Self::write`] with the bytes of the chunk.
+    fn write_chunk(&mut self, chunk: FileChunk) -> BoxFuture<'_, Result<()>> {
+        self.write(chunk.into_bytes())
+    }
+}
+
+/// A part of a parquet file written by [`AsyncArrowWriter`], see [`AsyncFileWriter::write_chunk`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChunk {
+    /// The row group at `index` in the file metadata, including any bloom filters
+    /// written after it
+    ///
+    /// The first row group is preceded by the magic bytes at the start of the file
+    RowGroup {
+        /// The index of the row group
+        index: usize,
+        /// The encoded row group
+        data: Bytes,
+    },
+    /// Everything following the last row group, such as the page index and the file
+    /// metadata, ending with the magic bytes at the end of the file
+    ///
+    /// If the file contains no row groups this also contains the magic bytes at the
+    /// start of the file
+    Footer(Bytes),
+}
+
+impl FileChunk {
+    /// Returns the bytes of this chunk
+    pub fn into_bytes(self) -> Bytes {
+        match self {
+            Self::RowGroup { data, .. } => data,
+            Self::Footer(data) => data,
+        }
+    }
 }
 
 impl AsyncFileWriter for Box<dyn AsyncFileWriter + '_> {
@@ -98,6 +142,10 @@ impl AsyncFileWriter for Box<dyn AsyncFileWriter + '_> {
         self.as_mut().write(bs)
     }
 
+    fn write_chunk(&mut self, chunk: FileChunk) -> BoxFuture<'_, Result<()>> {
+        self.as_mut().write_chunk(chunk)
+    }
+
     fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
         self.as_mut().complete()
     }
@@ -156,6 +204,9 @@ pub struct AsyncArrowWriter<W> {
 
     /// Async writer provided by caller
     async_writer: W,
+
+    /// Number of bytes written to `async_writer`
+    flushed_bytes: usize,
 }
 
 impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
@@ -180,6 +231,7 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         Ok(Self {
             sync_writer,
             async_writer: writer,
+            flushed_bytes: 0,
         })
     }
 
@@ -212,24 +264,73 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         self.sync_writer.bytes_written()
     }
 
+    /// Returns the number of bytes handed to the underlying [`AsyncFileWriter`]
+    ///
+    /// Unlike [`Self::bytes_written`] this excludes any encoded bytes still
+    /// buffered in memory, and so is less than or equal to it.
+    pub fn flushed_bytes(&self) -> usize {
+        self.flushed_bytes
+    }
+
     /// Enqueues the provided `RecordBatch` to be written
     ///
-    /// After every sync write by the inner [ArrowWriter], the inner buffer will be
-    /// checked and flush if at least half full
+    /// Each row group completed by this batch is written to the [`AsyncFileWriter`]
+    /// as a separate [`FileChunk`]
     pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
-        let before = self.sync_writer.flushed_row_groups().len();
-        self.sync_writer.write(batch)?;
-        if before != self.sync_writer.flushed_row_groups().len() {
-            self.do_write().await?;
+        let max_rows = self.sync_writer.max_row_group_size();
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            // Write at most one row group at a time
+            let len = (batch.num_rows() - offset).min(
+                max_rows
+                    .saturating_sub(self.sync_writer.in_progress_rows())
+                    .max(1),
+            );
+            let before = self.sync_writer.flushed_row_groups().len();
+            self.sync_writer.write(&batch.slice(offset, len))?;
+            if before != self.sync_writer.flushed_row_groups().len() {
+                self.write_row_group().await?;
+            }
+            offset += len;
         }
         Ok(())
     }
 
+    /// Encodes all [`RecordBatch`]es produced by `stream`, returning the number of rows written
+    ///
+    /// This is the async equivalent of [`ArrowWriter::write_reader`], the schema of each
+    /// batch is checked with [`ArrowWriter::check_schema`] before it is written.
+    ///
+    /// Each batch is written before the next is polled from `stream`, so a slow
+    /// [`AsyncFileWriter`] applies backpressure to the producer of `stream`.
+    ///
+    /// This does not close the writer, call [`Self::close`] once all data has been written.
+    /// The first error from `stream` or from writing is returned immediately; rows
+    /// of the preceding batches have already been written, and the writer can
+    /// still be closed to produce a valid file containing them.
+    pub async fn write_stream<S, E>(&mut self, stream: S) -> Result<usize>
+    where
+        S: Stream<Item = std::result::Result<RecordBatch, E>>,
+        E: Into<ParquetError>,
+    {
+        let mut stream = std::pin::pin!(stream);
+        let mut rows = 0;
+        while let Some(batch) = stream.next().await {
+            let batch = batch.map_err(Into::into)?;
+            self.sync_writer.check_schema(batch.schema_ref())?;
+            self.write(&batch).await?;
+            rows += batch.num_rows();
+        }
+        Ok(rows)
+    }
+
     /// Flushes all buffered rows into a new row group
     pub async fn flush(&mut self) -> Result<()> {
+        let before = self.sync_writer.flushed_row_groups().len();
         self.sync_writer.flush()?;
-        self.do_write().await?;
-
+        if before != self.sync_writer.flushed_row_groups().len() {
+            self.write_row_group().await?;
+        }
         Ok(())
     }
 
@@ -248,10 +349,13 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     ///
     /// Attempting to write after calling finish will result in an error
     pub async fn finish(&mut self) -> Result<ParquetMetaData> {
+        // Write any in progress row group separately from the footer
+        self.flush().await?;
         let metadata = self.sync_writer.finish()?;
 
         // Force to flush the remaining data.
-        self.do_write().await?;
+        let footer = FileChunk::Footer(self.take_buffer());
+        self.do_write(footer).await?;
         self.async_writer.complete().await?;
 
         Ok(metadata)
@@ -274,17 +378,24 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         self.async_writer
     }
 
-    /// Flush the data written by `sync_writer` into the `async_writer`
-    ///
-    /// # Notes
-    ///
-    /// This method will take the inner buffer from the `sync_writer` and write it into the
-    /// async writer. After the write, the inner buffer will be empty.
-    async fn do_write(&mut self) -> Result<()> {
+    /// Takes the inner buffer of the `sync_writer`, leaving it empty
+    fn take_buffer(&mut self) -> Bytes {
         let buffer = mem::take(self.sync_writer.inner_mut());
+        self.flushed_bytes += buffer.len();
+        Bytes::from(buffer)
+    }
+
+    /// Write the row group most recently flushed by `sync_writer` into the `async_writer`
+    async fn write_row_group(&mut self) -> Result<()> {
+        let index = self.sync_writer.flushed_row_groups().len() - 1;
+        let data = self.take_buffer();
+        self.do_write(FileChunk::RowGroup { index, data }).await
+    }
 
+    /// Write `chunk` into the `async_writer`
+    async fn do_write(&mut self, chunk: FileChunk) -> Result<()> {
         self.async_writer
-            .write(Bytes::from(buffer))
+            .write_chunk(chunk)
             .await
             .map_err(|e| ParquetError::External(Box::new(e)))?;
 
@@ -296,7 +407,9 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
 mod tests {
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
     use arrow::datatypes::{DataType, Field, Schema};
-    use arrow_array::{ArrayRef, BinaryArray, Int32Array, Int64Array, RecordBatchReader};
+    use arrow_array::{
+        ArrayRef, BinaryArray, Int32Array, Int64Array, RecordBatchReader, StringArray,
+    };
     use bytes::Bytes;
     use std::sync::Arc;
 
@@ -333,6 +446,190 @@ mod tests {
         assert_eq!(to_write, read);
     }
 
+    #[tokio::test]
+    async fn test_async_writer_stream() {
+        let col = Arc::new(Int64Array::from_iter_values(0..10)) as ArrayRef;
+        let to_write = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        let batches = (0..5).map(|_| Ok::<_, ParquetError>(to_write.clone()));
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(15)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer =
+            AsyncArrowWriter::try_new(&mut buffer, to_write.schema(), Some(props)).unwrap();
+        let rows = writer
+            .write_stream(futures::stream::iter(batches))
+            .await
+            .unwrap();
+        assert_eq!(rows, 50);
+        let metadata = writer.close().await.unwrap();
+        let row_groups: Vec<_> = metadata.row_groups().iter().map(|r| r.num_rows()).collect();
+        assert_eq!(row_groups, vec![15, 15, 15, 5]);
+
+        let mut writer = AsyncArrowWriter::try_new(Vec::new(), to_write.schema(), None).unwrap();
+        let col = Arc::new(StringArray::from(vec!["a"])) as ArrayRef;
+        let other = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        let err = writer
+            .write_stream(futures::stream::iter([Ok::<_, ParquetError>(other)]))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Incompatible schema, field 'col' should have type Int64 but got Utf8"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_bounded_stream() {
+        let batches: Vec<_> = (0..10)
+            .map(|i| {
+                let a = Arc::new(Int64Array::from_iter_values(i * 100..(i + 1) * 100)) as ArrayRef;
+                let b = Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|j| format!("{i}-{j}")),
+                )) as ArrayRef;
+                RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap()
+            })
+            .collect();
+        let schema = batches[0].schema();
+
+        // The producer can only run one batch ahead of the writer
+        let (mut sender, receiver) = futures::channel::mpsc::channel(1);
+        let producer = tokio::spawn({
+            let batches = batches.clone();
+            async move {
+                for batch in batches {
+                    futures::SinkExt::send(&mut sender, Ok::<_, ParquetError>(batch))
+                        .await
+                        .unwrap();
+                }
+            }
+        });
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(250)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = AsyncArrowWriter::try_new(&mut buffer, schema, Some(props)).unwrap();
+        let rows = writer.write_stream(receiver).await.unwrap();
+        producer.await.unwrap();
+        assert_eq!(rows, 1000);
+        let metadata = writer.close().await.unwrap();
+        assert_eq!(metadata.num_row_groups(), 4);
+
+        let read = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))
+            .unwrap()
+            .with_batch_size(100)
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_stream_error() {
+        let col = Arc::new(Int64Array::from_iter_values(0..10)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        let stream = futures::stream::iter([
+            Ok(batch.clone()),
+            Err(ParquetError::General("stream failed".to_string())),
+            Ok(batch.clone()),
+        ]);
+
+        let mut buffer = Vec::new();
+        let mut writer = AsyncArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        let err = writer.write_stream(stream).await.unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: stream failed");
+
+        // The rows preceding the error are written when the writer is closed
+        writer.close().await.unwrap();
+        let read = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+
+    #[derive(Default)]
+    struct ChunkWriter {
+        chunks: Vec<FileChunk>,
+    }
+
+    impl AsyncFileWriter for ChunkWriter {
+        fn write(&mut self, _: Bytes) -> BoxFuture<'_, Result<()>> {
+            unreachable!("all data is written with write_chunk")
+        }
+
+        fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
+            async { Ok(()) }.boxed()
+        }
+
+        fn write_chunk(&mut self, chunk: FileChunk) -> BoxFuture<'_, Result<()>> {
+            self.chunks.push(chunk);
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_chunks() {
+        let a = Arc::new(Int64Array::from_iter_values(0..1000)) as ArrayRef;
+        let b = Arc::new(StringArray::from_iter_values(
+            (0..1000).map(|i| format!("{i}")),
+        )) as _;
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(300)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut writer =
+            AsyncArrowWriter::try_new(ChunkWriter::default(), batch.schema(), Some(props)).unwrap();
+        // A single batch spanning multiple row groups
+        writer.write(&batch.slice(0, 700)).await.unwrap();
+        writer.write(&batch.slice(700, 200)).await.unwrap();
+        // An explicit flush of a partial row group
+        writer.flush().await.unwrap();
+        writer.flush().await.unwrap();
+        writer.write(&batch.slice(900, 100)).await.unwrap();
+        let metadata = writer.finish().await.unwrap();
+        let chunks = writer.into_inner().chunks;
+
+        let row_groups: Vec<_> = metadata.row_groups().iter().map(|r| r.num_rows()).collect();
+        assert_eq!(row_groups, vec![300, 300, 300, 100]);
+        assert_eq!(chunks.len(), 5);
+
+        // Each row group is written as a separate chunk, in order, followed by the footer
+        let mut file = Vec::new();
+        for (idx, chunk) in chunks.into_iter().enumerate() {
+            let start = file.len() as u64;
+            match chunk {
+                FileChunk::RowGroup { index, data } => {
+                    assert_eq!(index, idx);
+                    file.extend_from_slice(&data);
+                    for column in metadata.row_group(index).columns() {
+                        let (offset, len) = column.byte_range();
+                        assert!(offset >= start && offset + len <= file.len() as u64);
+                        let bloom_filter = column.bloom_filter_offset().unwrap() as u64;
+                        assert!(bloom_filter >= start && bloom_filter < file.len() as u64);
+                    }
+                }
+                FileChunk::Footer(data) => {
+                    assert_eq!(idx, 4);
+                    file.extend_from_slice(&data);
+                }
+            }
+        }
+
+        let read: Vec<_> = ParquetRecordBatchReader::try_new(Bytes::from(file), 1000)
+            .unwrap()
+            .collect::<std::result::Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+
     // Read the data from the test file and write it by the async writer and sync writer.
     // And then compares the results of the two writers.
     #[tokio::test]
@@ -387,6 +684,44 @@ mod tests {
         assert_eq!(reported, actual);
     }
 
+    #[tokio::test]
+    async fn test_async_writer_memory_accounting() {
+        // 1000 distinct strings of 1KiB each
+        let data_size = 1000 * 1024;
+        let values = StringArray::from_iter_values((0..1000).map(|i| format!("{i:01024}")));
+        let to_write = RecordBatch::try_from_iter([("s", Arc::new(values) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer =
+            AsyncArrowWriter::try_new(&mut buffer, to_write.schema(), Some(props)).unwrap();
+
+        writer.write(&to_write).await.unwrap();
+        let memory_size = writer.memory_size();
+        assert!(
+            memory_size >= data_size && memory_size < data_size * 2,
+            "memory_size {memory_size} should be close to {data_size}"
+        );
+        assert_eq!(writer.in_progress_rows(), 1000);
+        assert_eq!(writer.flushed_bytes(), 0);
+
+        writer.flush().await.unwrap();
+        assert_eq!(writer.memory_size(), 0);
+        assert_eq!(writer.in_progress_rows(), 0);
+        let flushed = writer.flushed_bytes();
+        assert!(flushed >= data_size, "{flushed}");
+        assert!(flushed <= writer.bytes_written());
+
+        // after finishing all bytes have been handed to the underlying writer
+        writer.finish().await.unwrap();
+        let flushed = writer.flushed_bytes();
+        assert_eq!(flushed, writer.bytes_written());
+        drop(writer);
+        assert_eq!(flushed, buffer.len());
+    }
+
     #[tokio::test]
     async fn test_async_writer_file() {
         let col = Arc::new(Int64Array::from_iter_values([1, 2, 3])) as ArrayRef;
@@ -0,0 +1,502 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ArrowSbbf`] for checking Arrow values against a parquet bloom filter
+
+use crate::arrow::schema::parquet_to_arrow_field;
+use crate::basic::Type as PhysicalType;
+use crate::bloom_filter::Sbbf;
+use crate::data_type::AsBytes;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnDescPtr;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal256Type, Float16Type, Float32Type, Float64Type, Int32Type};
+use arrow_array::types::{Int64Type, UInt32Type, UInt64Type};
+use arrow_array::{Array, BooleanArray, Datum, Decimal256Array};
+use arrow_cast::cast;
+use arrow_schema::DataType;
+use arrow_select::take::take;
+
+/// A bloom filter of a parquet column, which can be checked for Arrow values
+///
+/// [`Sbbf::check`] takes values of the parquet physical type of the column,
+/// whereas this converts Arrow values to the representation written by
+/// [`ArrowWriter`], e.g. decimals are converted to the width of the column and
+/// unsigned integers are reinterpreted as signed.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, Scalar};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+/// # use parquet::arrow::bloom_filter::ArrowSbbf;
+/// # use parquet::file::properties::WriterProperties;
+/// # let a = Int64Array::from(vec![1, 2, 3]);
+/// # let batch = RecordBatch::try_from_iter([("a", Arc::new(a) as ArrayRef)]).unwrap();
+/// # let props = WriterProperties::builder().set_bloom_filter_enabled(true).build();
+/// # let mut buf = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let data = Bytes::from(buf);
+/// let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+/// let sbbf = builder.get_row_group_column_bloom_filter(0, 0).unwrap().unwrap();
+/// let column = builder.metadata().file_metadata().schema_descr().column(0);
+/// let bloom_filter = ArrowSbbf::try_new(sbbf, column).unwrap();
+///
+/// // Values that were written are always reported as possibly present
+/// let values = Int64Array::from(vec![Some(2), None]);
+/// let result = bloom_filter.might_contain_datum(&values).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), None]));
+///
+/// // Scalars are also supported
+/// let value = Scalar::new(Int64Array::from(vec![3]));
+/// assert!(bloom_filter.might_contain_datum(&value).unwrap().value(0));
+/// ```
+///
+/// [`ArrowWriter`]: crate::arrow::ArrowWriter
+#[derive(Debug, Clone)]
+pub struct ArrowSbbf {
+    sbbf: Sbbf,
+    column: ColumnDescPtr,
+    /// The Arrow type of `column`, to which checked values are cast
+    arrow_type: DataType,
+}
+
+impl ArrowSbbf {
+    /// Create a new [`ArrowSbbf`] from the bloom filter of `column`
+    pub fn try_new(sbbf: Sbbf, column: ColumnDescPtr) -> Result<Self> {
+        let arrow_type = parquet_to_arrow_field(&column)?.data_type().clone();
+        Ok(Self {
+            sbbf,
+            column,
+            arrow_type,
+        })
+    }
+
+    /// Returns the underlying [`Sbbf`]
+    pub fn sbbf(&self) -> &Sbbf {
+        &self.sbbf
+    }
+
+    /// Returns the descriptor of the column of this bloom filter
+    pub fn column(&self) -> &ColumnDescPtr {
+        &self.column
+    }
+
+    /// Check whether each value of `datum` might be present in the filter
+    ///
+    /// Returns `false` for values that are definitely absent, `true` for
+    /// values that are probably present and null for null values. Dictionary
+    /// arrays are checked by their values.
+    ///
+    /// Values are cast to the Arrow type of the column, for example an `Int8`
+    /// value may be checked against an `INT64` column, or a `Utf8View` value
+    /// against a `BYTE_ARRAY` column. Returns an error if the type of `datum`
+    /// is not compatible with the column.
+    pub fn might_contain_datum(&self, datum: &dyn Datum) -> Result<BooleanArray> {
+        let (array, _) = datum.get();
+        self.might_contain(array)
+    }
+
+    fn might_contain(&self, array: &dyn Array) -> Result<BooleanArray> {
+        if let Some(dictionary) = array.as_any_dictionary_opt() {
+            let values = self.might_contain(dictionary.values().as_ref())?;
+            let result = take(&values, dictionary.keys(), None)?;
+            return Ok(result.as_boolean().clone());
+        }
+
+        let data_type = array.data_type();
+        if !is_compatible(data_type, &self.arrow_type) {
+            return Err(self.type_err(data_type));
+        }
+
+        let result = self.check_array(array)?;
+        if result.null_count() == array.null_count() {
+            return Ok(result);
+        }
+        // Values that could not be cast to the column type, e.g. as they are out
+        // of range, cannot be present
+        let nulls = array.logical_nulls();
+        Ok(result
+            .iter()
+            .enumerate()
+            .map(|(idx, result)| {
+                let valid = nulls.as_ref().is_none_or(|nulls| nulls.is_valid(idx));
+                valid.then(|| result.unwrap_or(false))
+            })
+            .collect())
+    }
+
+    fn check_array(&self, array: &dyn Array) -> Result<BooleanArray> {
+        let data_type = array.data_type();
+        match self.column.physical_type() {
+            PhysicalType::BOOLEAN => Ok(self.check(array.as_boolean().iter())),
+            PhysicalType::INT32 => match &self.arrow_type {
+                DataType::UInt32 => {
+                    let array = cast(array, &DataType::UInt32)?;
+                    let values = array.as_primitive::<UInt32Type>().iter();
+                    Ok(self.check(values.map(|v| v.map(|v| v as i32))))
+                }
+                d if is_decimal(d) => {
+                    let values = self.decimal_values(array)?;
+                    Ok(self.check(values.iter().map(|v| v.map(|v| v.as_i128() as i32))))
+                }
+                d => {
+                    // Cast via the column type, e.g. so `Date64` is converted to days
+                    let array = cast(
+                        &cast(array, &self.cast_type(d, data_type))?,
+                        &DataType::Int32,
+                    )?;
+                    Ok(self.check(array.as_primitive::<Int32Type>().iter()))
+                }
+            },
+            PhysicalType::INT64 => match &self.arrow_type {
+                DataType::UInt64 => {
+                    let array = cast(array, &DataType::UInt64)?;
+                    let values = array.as_primitive::<UInt64Type>().iter();
+                    Ok(self.check(values.map(|v| v.map(|v| v as i64))))
+                }
+                d if is_decimal(d) => {
+                    let values = self.decimal_values(array)?;
+                    Ok(self.check(values.iter().map(|v| v.map(|v| v.as_i128() as i64))))
+                }
+                d => {
+                    let array = cast(
+                        &cast(array, &self.cast_type(d, data_type))?,
+                        &DataType::Int64,
+                    )?;
+                    Ok(self.check(array.as_primitive::<Int64Type>().iter()))
+                }
+            },
+            PhysicalType::FLOAT => {
+                let array = cast(array, &DataType::Float32)?;
+                Ok(self.check(array.as_primitive::<Float32Type>().iter()))
+            }
+            PhysicalType::DOUBLE => {
+                let array = cast(array, &DataType::Float64)?;
+                Ok(self.check(array.as_primitive::<Float64Type>().iter()))
+            }
+            PhysicalType::BYTE_ARRAY => match data_type {
+                DataType::Utf8 => Ok(self.check_bytes(array.as_string::<i32>().iter())),
+                DataType::LargeUtf8 => Ok(self.check_bytes(array.as_string::<i64>().iter())),
+                DataType::Utf8View => Ok(self.check_bytes(array.as_string_view().iter())),
+                DataType::Binary => Ok(self.check_bytes(array.as_binary::<i32>().iter())),
+                DataType::LargeBinary => Ok(self.check_bytes(array.as_binary::<i64>().iter())),
+                DataType::BinaryView => Ok(self.check_bytes(array.as_binary_view().iter())),
+                _ => Err(self.type_err(data_type)),
+            },
+            PhysicalType::FIXED_LEN_BYTE_ARRAY => match &self.arrow_type {
+                DataType::FixedSizeBinary(_) => {
+                    Ok(self.check_bytes(array.as_fixed_size_binary().iter()))
+                }
+                DataType::Float16 => {
+                    let array = cast(array, &DataType::Float16)?;
+                    let values = array.as_primitive::<Float16Type>().iter();
+                    Ok(self.check_bytes(values.map(|v| v.map(|v| v.to_le_bytes()))))
+                }
+                d if is_decimal(d) => {
+                    // Big-endian two's complement, truncated to the width of the column
+                    let width = self.column.type_length() as usize;
+                    if width > 32 {
+                        return Err(self.type_err(data_type));
+                    }
+                    let values = self.decimal_values(array)?;
+                    Ok(values
+                        .iter()
+                        .map(|v| v.map(|v| self.sbbf.check(&v.to_be_bytes()[32 - width..])))
+                        .collect())
+                }
+                _ => Err(self.type_err(data_type)),
+            },
+            PhysicalType::INT96 => Err(nyi_err!(
+                "Checking the INT96 bloom filter of column {} is not supported",
+                self.column.path()
+            )),
+        }
+    }
+
+    /// Returns the type to cast `data_type` to before converting it to the
+    /// physical type of the column, preserving the timezone of timestamps
+    fn cast_type(&self, column_type: &DataType, data_type: &DataType) -> DataType {
+        match (column_type, data_type) {
+            (DataType::Timestamp(unit, _), DataType::Timestamp(_, tz)) => {
+                DataType::Timestamp(*unit, tz.clone())
+            }
+            _ => column_type.clone(),
+        }
+    }
+
+    /// Returns the unscaled values of `array` at the scale of the column
+    fn decimal_values(&self, array: &dyn Array) -> Result<Decimal256Array> {
+        let (precision, scale) = match self.arrow_type {
+            DataType::Decimal32(p, s)
+            | DataType::Decimal64(p, s)
+            | DataType::Decimal128(p, s)
+            | DataType::Decimal256(p, s) => (p, s),
+            _ => unreachable!("column type is a decimal"),
+        };
+        // Values that cannot be represented at the precision of the column are null
+        let array = cast(array, &DataType::Decimal256(precision, scale))?;
+        Ok(array.as_primitive::<Decimal256Type>().clone())
+    }
+
+    fn check<T: AsBytes>(&self, values: impl Iterator<Item = Option<T>>) -> BooleanArray {
+        values.map(|v| v.map(|v| self.sbbf.check(&v))).collect()
+    }
+
+    fn check_bytes<T: AsRef<[u8]>>(&self, values: impl Iterator<Item = Option<T>>) -> BooleanArray {
+        values
+            .map(|v| v.map(|v| self.sbbf.check(v.as_ref())))
+            .collect()
+    }
+
+    fn type_err(&self, data_type: &DataType) -> ParquetError {
+        arrow_err!(
+            "Cannot check the bloom filter of column {} of type {} for values of type {}",
+            self.column.path(),
+            self.arrow_type,
+            data_type
+        )
+    }
+}
+
+fn is_decimal(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _)
+    )
+}
+
+fn is_bytes(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+    )
+}
+
+/// Returns true if values of type `data_type` can be checked against a column of `column_type`
+fn is_compatible(data_type: &DataType, column_type: &DataType) -> bool {
+    use DataType::*;
+    match (data_type, column_type) {
+        (a, b) if a == b => true,
+        (a, b) if a.is_integer() => b.is_integer() || is_decimal(b),
+        (a, b) if is_decimal(a) => is_decimal(b),
+        (a, b) if a.is_floating() => b.is_floating(),
+        (a, b) if is_bytes(a) => is_bytes(b),
+        (Date32 | Date64, Date32 | Date64) => true,
+        (Time32(_) | Time64(_), Time32(_) | Time64(_)) => true,
+        (Timestamp(_, _), Timestamp(_, _)) => true,
+        (FixedSizeBinary(a), FixedSizeBinary(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::file::properties::WriterProperties;
+    use arrow_array::types::Int32Type as DictKey;
+    use arrow_array::{
+        ArrayRef, Decimal128Array, DictionaryArray, FixedSizeBinaryArray, Int8Array, Int64Array,
+        RecordBatch, Scalar, StringArray, StringViewArray, UInt64Array,
+    };
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    /// Writes `array` with a bloom filter, returning the bloom filter read back
+    fn bloom_filter(array: ArrayRef) -> ArrowSbbf {
+        let batch = RecordBatch::try_from_iter([("col", array)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let sbbf = builder
+            .get_row_group_column_bloom_filter(0, 0)
+            .unwrap()
+            .unwrap();
+        let column = builder.metadata().file_metadata().schema_descr().column(0);
+        ArrowSbbf::try_new(sbbf, column).unwrap()
+    }
+
+    #[test]
+    fn test_utf8() {
+        let values = StringArray::from(vec!["apple", "banana", "cherry"]);
+        let filter = bloom_filter(Arc::new(values));
+
+        let query = StringArray::from(vec![Some("banana"), Some("durian"), None]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None]);
+        assert_eq!(filter.might_contain_datum(&query).unwrap(), expected);
+
+        let query = StringViewArray::from(vec![Some("banana"), Some("durian"), None]);
+        assert_eq!(filter.might_contain_datum(&query).unwrap(), expected);
+
+        let query: DictionaryArray<DictKey> =
+            vec!["cherry", "durian", "cherry"].into_iter().collect();
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![true, false, true])
+        );
+
+        let query = Scalar::new(StringArray::from(vec!["apple"]));
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![true])
+        );
+    }
+
+    #[test]
+    fn test_int64() {
+        let values = Int64Array::from(vec![-5, 0, 42, i64::MAX]);
+        let filter = bloom_filter(Arc::new(values));
+
+        let query = Int64Array::from(vec![42, 43, i64::MAX, -5]);
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![true, false, true, true])
+        );
+
+        // Other integer types are cast to the column type
+        let query = Int8Array::from(vec![Some(42), Some(7), None]);
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(false), None])
+        );
+
+        // Values that cannot be cast to the column type are absent
+        let query = UInt64Array::from(vec![u64::MAX, 0]);
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![false, true])
+        );
+    }
+
+    #[test]
+    fn test_uint64() {
+        let values = UInt64Array::from(vec![1, u64::MAX]);
+        let filter = bloom_filter(Arc::new(values));
+
+        // Unsigned values are reinterpreted as signed, as when written
+        let query = UInt64Array::from(vec![u64::MAX, 2]);
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![true, false])
+        );
+    }
+
+    #[test]
+    fn test_fixed_len_byte_array() {
+        let uuids = [[1_u8; 16], [2; 16], [3; 16]];
+        let values = FixedSizeBinaryArray::try_from_iter(uuids.iter()).unwrap();
+        let filter = bloom_filter(Arc::new(values));
+        assert_eq!(
+            filter.column().physical_type(),
+            PhysicalType::FIXED_LEN_BYTE_ARRAY
+        );
+
+        let query = FixedSizeBinaryArray::try_from_iter([[2_u8; 16], [4; 16]].iter()).unwrap();
+        assert_eq!(
+            filter.might_contain_datum(&query).unwrap(),
+            BooleanArray::from(vec![true, false])
+        );
+
+        let query = FixedSizeBinaryArray::try_from_iter([[2_u8; 8]].iter()).unwrap();
+        let err = filter.might_contain_datum(&query).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Cannot check the bloom filter of column \"col\" of type \
+             FixedSizeBinary(16) for values of type FixedSizeBinary(8)"
+        );
+    }
+
+    #[test]
+    fn test_decimal() {
+        for precision in [5, 15, 30] {
+            let values = Decimal128Array::from(vec![12345, -100, 0])
+                .with_precision_and_scale(precision, 2)
+                .unwrap();
+            let filter = bloom_filter(Arc::new(values));
+
+            let query = Decimal128Array::from(vec![Some(-100), Some(101), None])
+                .with_precision_and_scale(precision, 2)
+                .unwrap();
+            assert_eq!(
+                filter.might_contain_datum(&query).unwrap(),
+                BooleanArray::from(vec![Some(true), Some(false), None]),
+                "precision {precision}"
+            );
+
+            // Values are rescaled to the scale of the column
+            let query = Decimal128Array::from(vec![123450, 1000])
+                .with_precision_and_scale(20, 3)
+                .unwrap();
+            assert_eq!(
+                filter.might_contain_datum(&query).unwrap(),
+                BooleanArray::from(vec![true, false]),
+                "precision {precision}"
+            );
+
+            let query = Int64Array::from(vec![0, 1]);
+            assert_eq!(
+                filter.might_contain_datum(&query).unwrap(),
+                BooleanArray::from(vec![true, false]),
+                "precision {precision}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let filter = bloom_filter(Arc::new(Int64Array::from(vec![1, 2, 3])));
+        let err = filter
+            .might_contain_datum(&StringArray::from(vec!["1"]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Cannot check the bloom filter of column \"col\" of type Int64 \
+             for values of type Utf8"
+        );
+
+        let filter = bloom_filter(Arc::new(StringArray::from(vec!["a"])));
+        let err = filter
+            .might_contain_datum(&Int64Array::from(vec![1]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Cannot check the bloom filter of column \"col\" of type Utf8 \
+             for values of type Int64"
+        );
+    }
+}
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::arrow::buffer::bit_util::iter_set_bits_rev;
-use crate::arrow::record_reader::buffer::ValuesBuffer;
+use crate::arrow::record_reader::buffer::{ValuesBuffer, capped_preallocation};
 use crate::errors::{ParquetError, Result};
 use crate::util::utf8::check_valid_utf8;
 use arrow_array::{ArrayRef, OffsetSizeTrait, make_array};
@@ -139,6 +139,12 @@ impl<I: OffsetSizeTrait> OffsetBuffer<I> {
 }
 
 impl<I: OffsetSizeTrait> ValuesBuffer for OffsetBuffer<I> {
+    fn reserve(&mut self, num_values: usize, num_bytes: usize) {
+        self.offsets
+            .reserve(capped_preallocation(num_values, std::mem::size_of::<I>()));
+        self.values.reserve(capped_preallocation(num_bytes, 1));
+    }
+
     fn pad_nulls(
         &mut self,
         read_offset: usize,
@@ -242,6 +248,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_offset_buffer_reserve() {
+        use crate::arrow::record_reader::buffer::{MAX_PREALLOCATION_BYTES, PreallocationHint};
+
+        let hint = PreallocationHint::new(100, 200, 4000);
+        assert_eq!(hint.estimate(10), (20, 400));
+        assert_eq!(hint.estimate(0), (0, 0));
+        assert_eq!(PreallocationHint::default().estimate(10), (0, 0));
+
+        let mut buffer = OffsetBuffer::<i32>::default();
+        buffer.reserve(20, 400);
+        assert!(buffer.offsets.capacity() >= 21);
+        assert!(buffer.values.capacity() >= 400);
+
+        // Metadata claiming huge sizes must not cause unbounded allocation
+        let hint = PreallocationHint::new(1, u64::MAX, u64::MAX);
+        let (num_values, num_bytes) = hint.estimate(1024);
+        assert_eq!((num_values, num_bytes), (usize::MAX, usize::MAX));
+
+        let mut buffer = OffsetBuffer::<i64>::default();
+        buffer.reserve(num_values, num_bytes);
+        assert!(buffer.offsets.capacity() * 8 <= 2 * MAX_PREALLOCATION_BYTES);
+        assert!(buffer.values.capacity() <= 2 * MAX_PREALLOCATION_BYTES);
+
+        buffer.try_push("hello".as_bytes(), false).unwrap();
+        let array = buffer.into_array(None, ArrowType::LargeUtf8);
+        let strings = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(strings.value(0), "hello");
+    }
+
     #[test]
     fn test_offset_buffer_pad_nulls() {
         let mut buffer = OffsetBuffer::<i32>::default();
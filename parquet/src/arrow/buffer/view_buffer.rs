@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::arrow::record_reader::buffer::ValuesBuffer;
+use crate::arrow::record_reader::buffer::{ValuesBuffer, capped_preallocation};
 use arrow_array::{ArrayRef, builder::make_view, make_array};
 use arrow_buffer::Buffer;
 use arrow_data::ArrayDataBuilder;
@@ -97,6 +97,14 @@ impl ViewBuffer {
 }
 
 impl ValuesBuffer for ViewBuffer {
+    fn reserve(&mut self, num_values: usize, _num_bytes: usize) {
+        // The data of non-inlined views is not copied, but references the pages
+        self.views.reserve(capped_preallocation(
+            num_values,
+            std::mem::size_of::<u128>(),
+        ));
+    }
+
     fn pad_nulls(
         &mut self,
         read_offset: usize,
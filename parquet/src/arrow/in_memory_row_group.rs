@@ -17,10 +17,10 @@
 
 use crate::arrow::ProjectionMask;
 use crate::arrow::array_reader::RowGroups;
-use crate::arrow::arrow_reader::RowSelection;
+use crate::arrow::arrow_reader::{Executor, ExecutorPageReader, RowSelection};
 use crate::column::page::{PageIterator, PageReader};
 use crate::errors::ParquetError;
-use crate::file::metadata::ParquetMetaData;
+use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
 use crate::file::reader::{ChunkReader, Length, SerializedPageReader};
 use bytes::{Buf, Bytes};
@@ -36,6 +36,10 @@ pub(crate) struct InMemoryRowGroup<'a> {
     pub(crate) row_count: usize,
     pub(crate) row_group_idx: usize,
     pub(crate) metadata: &'a ParquetMetaData,
+    /// Whether to verify page checksums when reading column chunks
+    pub(crate) verify_page_checksums: bool,
+    /// The executor on which to decompress fully fetched column chunks, if any
+    pub(crate) decompress_pool: Option<Arc<dyn Executor>>,
 }
 
 /// What ranges to fetch for the columns in this row group
@@ -75,16 +79,21 @@ impl InMemoryRowGroup<'_> {
             let ranges = self
                 .column_chunks
                 .iter()
-                .zip(metadata.columns())
                 .enumerate()
-                .filter(|&(idx, (chunk, _chunk_meta))| {
-                    chunk.is_none() && projection.leaf_included(idx)
-                })
-                .flat_map(|(idx, (_chunk, chunk_meta))| {
+                .filter(|&(idx, chunk)| chunk.is_none() && projection.leaf_included(idx))
+                .flat_map(|(idx, _chunk)| {
+                    let mut ranges: Vec<Range<u64>> = vec![];
+                    let (start, len) = metadata.column(idx).byte_range();
+                    // Columns without page locations, e.g. as their page index was not
+                    // read, are fetched in full
+                    if offset_index[idx].page_locations.is_empty() {
+                        ranges.push(start..start + len);
+                        page_start_offsets.push(vec![start]);
+                        return ranges;
+                    }
+
                     // If the first page does not start at the beginning of the column,
                     // then we need to also fetch a dictionary page.
-                    let mut ranges: Vec<Range<u64>> = vec![];
-                    let (start, _len) = chunk_meta.byte_range();
                     match offset_index[idx].page_locations.first() {
                         Some(first) if first.offset as u64 != start => {
                             ranges.push(start..first.offset as u64);
@@ -148,12 +157,27 @@ impl InMemoryRowGroup<'_> {
             // If we have a `RowSelection` and an `OffsetIndex` then only fetch pages required for the
             // `RowSelection`
             let mut page_start_offsets = page_start_offsets.into_iter();
+            let offset_index = self.offset_index.unwrap_or_default();
 
             for (idx, chunk) in self.column_chunks.iter_mut().enumerate() {
                 if chunk.is_some() || !projection.leaf_included(idx) {
                     continue;
                 }
 
+                // Columns without page locations were fetched in full
+                let fetched_in_full = offset_index
+                    .get(idx)
+                    .is_none_or(|index| index.page_locations.is_empty());
+                if fetched_in_full {
+                    if page_start_offsets.next().is_some() {
+                        *chunk = Some(Arc::new(ColumnChunkData::Dense {
+                            offset: metadata.column(idx).byte_range().0 as usize,
+                            data: chunk_data.next().unwrap(),
+                        }));
+                    }
+                    continue;
+                }
+
                 if let Some(offsets) = page_start_offsets.next() {
                     let mut chunks = Vec::with_capacity(offsets.len());
                     for _ in 0..offsets.len() {
@@ -192,6 +216,10 @@ impl RowGroups for InMemoryRowGroup<'_> {
         self.row_count
     }
 
+    fn row_group_metadata(&self) -> Box<dyn Iterator<Item = &RowGroupMetaData> + '_> {
+        Box::new(std::iter::once(self.metadata.row_group(self.row_group_idx)))
+    }
+
     /// Return chunks for column i
     fn column_chunks(&self, i: usize) -> crate::errors::Result<Box<dyn PageIterator>> {
         match &self.column_chunks[i] {
@@ -210,7 +238,9 @@ impl RowGroups for InMemoryRowGroup<'_> {
                     column_chunk_metadata,
                     self.row_count,
                     page_locations,
-                )?;
+                )?
+                .with_verify_checksums(self.verify_page_checksums)
+                .with_row_group_index(self.row_group_idx);
                 let page_reader = page_reader.add_crypto_context(
                     self.row_group_idx,
                     i,
@@ -218,7 +248,13 @@ impl RowGroups for InMemoryRowGroup<'_> {
                     column_chunk_metadata,
                 )?;
 
-                let page_reader: Box<dyn PageReader> = Box::new(page_reader);
+                let page_reader: Box<dyn PageReader> = match (&self.decompress_pool, &**data) {
+                    // Sparse column chunks are missing pages, and so cannot be read ahead
+                    (Some(pool), ColumnChunkData::Dense { .. }) => {
+                        Box::new(ExecutorPageReader::new(pool, Box::new(page_reader)))
+                    }
+                    _ => Box::new(page_reader),
+                };
 
                 Ok(Box::new(ColumnChunkIterator {
                     reader: Some(Ok(page_reader)),
@@ -182,6 +182,7 @@
 experimental!(mod array_reader);
 pub mod arrow_reader;
 pub mod arrow_writer;
+pub mod bloom_filter;
 mod buffer;
 mod decoder;
 
@@ -209,7 +210,8 @@ use arrow_schema::{FieldRef, Schema};
 
 pub use self::schema::{
     ArrowSchemaConverter, FieldLevels, add_encoded_arrow_schema_to_metadata, encode_arrow_schema,
-    parquet_to_arrow_field_levels, parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns,
+    parquet_column_to_arrow_field, parquet_metadata_to_arrow_schema, parquet_to_arrow_field_levels,
+    parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns,
 };
 
 /// Schema metadata key used to store serialized Arrow schema
@@ -448,10 +450,19 @@ pub fn parquet_column<'a>(
 #[cfg(test)]
 mod test {
     use crate::arrow::ArrowWriter;
-    use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader, ParquetMetaDataWriter};
+    use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+    use crate::arrow::arrow_reader::{
+        ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection,
+        RowSelector,
+    };
+    use crate::file::metadata::{
+        KeyValue, PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, ParquetMetaDataWriter,
+    };
     use crate::file::properties::{EnabledStatistics, WriterProperties};
     use crate::schema::parser::parse_message_type;
     use crate::schema::types::SchemaDescriptor;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
     use arrow_array::{ArrayRef, Int32Array, RecordBatch};
     use bytes::Bytes;
     use std::sync::Arc;
@@ -535,6 +546,126 @@ mod test {
         assert_eq!(original_metadata, roundtrip_metadata);
     }
 
+    #[test]
+    fn test_metadata_to_from_bytes_pruning() {
+        let mut buf = vec![];
+        let array: ArrayRef = Arc::new(Int32Array::from_iter_values(0..1000));
+        let batch = RecordBatch::try_from_iter(vec![("id", array)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(400)
+            .set_data_page_row_count_limit(50)
+            .set_write_batch_size(50)
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .set_bloom_filter_enabled(true)
+            .set_key_value_metadata(Some(vec![KeyValue::new(
+                "key".to_string(),
+                "value".to_string(),
+            )]))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        let fresh = ArrowReaderMetadata::load(&data, options.clone()).unwrap();
+        let bytes = fresh.metadata().try_to_bytes().unwrap();
+        let cached = Arc::new(ParquetMetaData::try_from_bytes(&bytes).unwrap());
+        assert_eq!(fresh.metadata().as_ref(), cached.as_ref());
+        assert!(cached.column_index().is_some());
+        assert!(cached.offset_index().is_some());
+        let cached = ArrowReaderMetadata::try_new(cached, options).unwrap();
+
+        // Prunes row groups and pages for the predicate `id >= 650`
+        let prune = |metadata: &ArrowReaderMetadata| {
+            let parquet_metadata = metadata.metadata();
+            let converter =
+                StatisticsConverter::try_new("id", metadata.schema(), metadata.parquet_schema())
+                    .unwrap();
+            let maxes = converter
+                .row_group_maxes(parquet_metadata.row_groups())
+                .unwrap();
+            let row_groups: Vec<_> = (0..maxes.len())
+                .filter(|i| maxes.as_primitive::<Int32Type>().value(*i) >= 650)
+                .collect();
+
+            let column_index = parquet_metadata.column_index().unwrap();
+            let offset_index = parquet_metadata.offset_index().unwrap();
+            let page_maxes = converter
+                .data_page_maxes(column_index, offset_index, &row_groups)
+                .unwrap();
+            let page_row_counts = converter
+                .data_page_row_counts(offset_index, parquet_metadata.row_groups(), &row_groups)
+                .unwrap()
+                .unwrap();
+            let selectors: Vec<_> = page_maxes
+                .as_primitive::<Int32Type>()
+                .values()
+                .iter()
+                .zip(page_row_counts.values())
+                .map(|(max, count)| match *max >= 650 {
+                    true => RowSelector::select(*count as usize),
+                    false => RowSelector::skip(*count as usize),
+                })
+                .collect();
+            (row_groups, RowSelection::from(selectors))
+        };
+
+        let (fresh_row_groups, fresh_selection) = prune(&fresh);
+        let (cached_row_groups, cached_selection) = prune(&cached);
+        assert_eq!(fresh_row_groups, vec![1, 2]);
+        assert_eq!(fresh_row_groups, cached_row_groups);
+        assert_eq!(fresh_selection, cached_selection);
+
+        let read = |metadata: ArrowReaderMetadata, row_groups, selection| {
+            let builder =
+                ParquetRecordBatchReaderBuilder::new_with_metadata(data.clone(), metadata);
+            let bloom_filter = builder.get_row_group_column_bloom_filter(1, 0).unwrap();
+            assert!(bloom_filter.unwrap().check(&700));
+            builder
+                .with_row_groups(row_groups)
+                .with_row_selection(selection)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        let fresh_batches = read(fresh, fresh_row_groups, fresh_selection);
+        let cached_batches = read(cached, cached_row_groups, cached_selection);
+        assert_eq!(fresh_batches, cached_batches);
+        let num_rows: usize = cached_batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 350);
+    }
+
+    #[test]
+    fn test_metadata_from_invalid_bytes() {
+        let parquet_bytes = create_parquet_file();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&parquet_bytes)
+            .unwrap();
+        let mut bytes = metadata.try_to_bytes().unwrap();
+        assert_eq!(ParquetMetaData::try_from_bytes(&bytes).unwrap(), metadata);
+
+        let err = ParquetMetaData::try_from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "EOF: Unexpected end of serialized ParquetMetaData"
+        );
+
+        let err = ParquetMetaData::try_from_bytes(&parquet_bytes).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid serialized ParquetMetaData magic bytes"
+        );
+
+        bytes[4] = 2;
+        let err = ParquetMetaData::try_from_bytes(&bytes).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unsupported serialized ParquetMetaData version 2, expected 1"
+        );
+    }
+
     /// Sets the page index offset locations in the metadata to `None`
     ///
     /// This is because the offsets are used to find the relative location of the index
@@ -26,7 +26,7 @@ use crate::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
 };
 use crate::errors::ParquetError;
-use crate::file::metadata::ParquetMetaData;
+use crate::file::metadata::{PageIndexPolicy, ParquetMetaData};
 use crate::util::push_buffers::PushBuffers;
 use arrow_array::RecordBatch;
 use bytes::Bytes;
@@ -144,20 +144,26 @@ impl ParquetPushDecoderBuilder {
         arrow_reader_options: ArrowReaderOptions,
     ) -> Result<Self, ParquetError> {
         let arrow_reader_metadata =
-            ArrowReaderMetadata::try_new(parquet_metadata, arrow_reader_options)?;
-        Ok(Self::new_with_metadata(file_len, arrow_reader_metadata))
+            ArrowReaderMetadata::try_new(parquet_metadata, arrow_reader_options.clone())?;
+        Ok(Self::new_with_metadata(file_len, arrow_reader_metadata)
+            .with_reader_options(&arrow_reader_options))
     }
 
     /// Create a new `ParquetDecoderBuilder` given [`ArrowReaderMetadata`].
     ///
     /// See [`ArrowReaderMetadata::try_new`] for how to create the metadata from
-    /// the Parquet metadata and reader options.
+    /// the Parquet metadata and reader options, and [`Self::with_reader_options`]
+    /// for the options that control how column data is read.
     pub fn new_with_metadata(file_len: u64, arrow_reader_metadata: ArrowReaderMetadata) -> Self {
         Self::new_builder(file_len, arrow_reader_metadata)
     }
 
     /// Create a [`ParquetPushDecoder`] with the configured options
     pub fn build(self) -> Result<ParquetPushDecoder, ParquetError> {
+        self.check_column_metadata()?;
+        #[cfg(feature = "encryption")]
+        self.check_column_keys()?;
+
         let Self {
             input: file_len,
             metadata: parquet_metadata,
@@ -172,8 +178,26 @@ impl ParquetPushDecoderBuilder {
             offset,
             metrics,
             max_predicate_cache_size,
+            batch_transform,
+            verify_page_checksums,
+            preallocate_from_stats,
+            decimal_validation,
+            decompress_pool,
+            lazy_page_index_policy,
+            // Not used for the push decoder, the caller fetches the requested ranges
+            fetch_coalesce_gap: _,
+            row_group_aligned_batches,
         } = self;
 
+        // The caller provides the metadata, including any offset index, up front
+        if lazy_page_index_policy != PageIndexPolicy::Skip {
+            return Err(ParquetError::General(
+                "The push decoder does not support reading the page index lazily, \
+                 load the page index into the ParquetMetaData instead"
+                    .to_string(),
+            ));
+        }
+
         // If no row groups were specified, read all of them
         let row_groups =
             row_groups.unwrap_or_else(|| (0..parquet_metadata.num_row_groups()).collect());
@@ -190,6 +214,12 @@ impl ParquetPushDecoderBuilder {
             offset,
             metrics,
             max_predicate_cache_size,
+            batch_transform,
+            verify_page_checksums,
+            preallocate_from_stats,
+            decimal_validation,
+            decompress_pool,
+            row_group_aligned_batches,
             buffers,
         );
 
@@ -458,21 +488,25 @@ impl ParquetDecoderState {
 mod test {
     use super::*;
     use crate::DecodeResult;
-    use crate::arrow::arrow_reader::{ArrowPredicateFn, RowFilter, RowSelection, RowSelector};
+    use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
+    use crate::arrow::arrow_reader::{
+        ArrowPredicateFn, ErrorPolicy, PredicateStats, RowFilter, RowSelection, RowSelector,
+    };
     use crate::arrow::push_decoder::{ParquetPushDecoder, ParquetPushDecoderBuilder};
     use crate::arrow::{ArrowWriter, ProjectionMask};
     use crate::errors::ParquetError;
-    use crate::file::metadata::ParquetMetaDataPushDecoder;
+    use crate::file::metadata::{ParquetMetaDataPushDecoder, ParquetMetaDataReader};
     use crate::file::properties::WriterProperties;
     use arrow::compute::kernels::cmp::{gt, lt};
     use arrow_array::cast::AsArray;
     use arrow_array::types::Int64Type;
-    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringViewArray};
+    use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, StringViewArray};
+    use arrow_schema::ArrowError;
     use arrow_select::concat::concat_batches;
     use bytes::Bytes;
     use std::fmt::Debug;
     use std::ops::Range;
-    use std::sync::{Arc, LazyLock};
+    use std::sync::{Arc, LazyLock, Mutex};
 
     /// Test decoder struct size (as they are copied around on each transition, they
     /// should not grow too large)
@@ -593,6 +627,44 @@ mod test {
         expect_finished(decoder.try_decode());
     }
 
+    #[test]
+    fn test_decoder_lazy_page_index() {
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&*TEST_FILE_DATA)
+            .unwrap();
+        assert!(metadata.offset_index().is_none());
+        let options = ArrowReaderOptions::new()
+            .with_page_index(true)
+            .with_lazy_page_index(true);
+        let err = ParquetPushDecoderBuilder::try_new_decoder_with_options(
+            test_file_len(),
+            Arc::new(metadata),
+            options.clone(),
+        )
+        .unwrap()
+        .build()
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: The push decoder does not support reading the page index lazily, \
+             load the page index into the ParquetMetaData instead"
+        );
+
+        // The page index is already loaded, and so is not read lazily
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&*TEST_FILE_DATA)
+            .unwrap();
+        ParquetPushDecoderBuilder::try_new_decoder_with_options(
+            test_file_len(),
+            Arc::new(metadata),
+            options,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    }
+
     /// Decode multiple columns "a" and "b", expect that the decoder requests
     /// only a single request per row group
     #[test]
@@ -927,6 +999,106 @@ mod test {
         expect_finished(decoder.try_decode());
     }
 
+    #[test]
+    fn test_decoder_filter_error_skips_row_group() {
+        let builder = ParquetPushDecoderBuilder::try_new_decoder(
+            test_file_len(),
+            test_file_parquet_metadata(),
+        )
+        .unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+        // fails for every batch of the first row group
+        let row_filter_a = ArrowPredicateFn::new(
+            ProjectionMask::columns(&schema_descr, ["a"]),
+            |batch: RecordBatch| {
+                let a = batch.column(0).as_primitive::<Int64Type>();
+                if a.value(0) < 200 {
+                    return Err(ArrowError::ComputeError("corrupt".to_string()));
+                }
+                Ok(BooleanArray::from(vec![true; a.len()]))
+            },
+        );
+
+        let metrics = ArrowReaderMetrics::enabled();
+        let errors = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&errors);
+        let mut decoder = builder
+            .with_row_filter(
+                RowFilter::new(vec![Box::new(row_filter_a)])
+                    .with_error_policy(ErrorPolicy::SkipRowGroup)
+                    .with_error_callback(move |row_group_idx, error| {
+                        captured
+                            .lock()
+                            .unwrap()
+                            .push((row_group_idx, error.to_string()));
+                    }),
+            )
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        decoder
+            .push_range(test_file_range(), TEST_FILE_DATA.clone())
+            .unwrap();
+
+        // the first row group is skipped entirely
+        let batch = expect_data(decoder.try_decode());
+        assert_eq!(batch, TEST_BATCH.slice(200, 200));
+        expect_finished(decoder.try_decode());
+        assert_eq!(metrics.row_groups_skipped(), Some(1));
+        assert_eq!(
+            errors.lock().unwrap().as_slice(),
+            &[(0, "Compute error: corrupt".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_decoder_filter_stats_callback() {
+        let builder = ParquetPushDecoderBuilder::try_new_decoder(
+            test_file_len(),
+            test_file_parquet_metadata(),
+        )
+        .unwrap();
+        let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+        let row_filter_a = ArrowPredicateFn::new(
+            ProjectionMask::columns(&schema_descr, ["a"]),
+            |batch: RecordBatch| {
+                let a = batch.column(0).as_primitive::<Int64Type>();
+                Ok(BooleanArray::from_unary(a, |v| v < 250))
+            },
+        );
+
+        let stats = Arc::new(Mutex::new(vec![]));
+        let captured = Arc::clone(&stats);
+        let mut decoder = builder
+            .with_row_filter(
+                RowFilter::new(vec![Box::new(row_filter_a)])
+                    .with_stats_callback(move |s| captured.lock().unwrap().push(s)),
+            )
+            .build()
+            .unwrap();
+
+        decoder
+            .push_range(test_file_range(), TEST_FILE_DATA.clone())
+            .unwrap();
+
+        let batch = expect_data(decoder.try_decode());
+        assert_eq!(batch, TEST_BATCH.slice(0, 200));
+        let batch = expect_data(decoder.try_decode());
+        assert_eq!(batch, TEST_BATCH.slice(200, 50));
+        expect_finished(decoder.try_decode());
+
+        let stats = stats.lock().unwrap();
+        let expected = [(200, 200), (200, 50)].map(|(evaluated, selected)| PredicateStats {
+            predicate_index: 0,
+            rows_evaluated: evaluated,
+            rows_selected: selected,
+        });
+        assert_eq!(stats.as_slice(), &expected);
+    }
+
     #[test]
     fn test_decoder_offset_limit() {
         let mut decoder = ParquetPushDecoderBuilder::try_new_decoder(
@@ -955,6 +1127,33 @@ mod test {
         expect_finished(decoder.try_decode());
     }
 
+    #[test]
+    fn test_decoder_row_group_aligned_batches() {
+        let mut decoder = ParquetPushDecoderBuilder::try_new_decoder(
+            test_file_len(),
+            test_file_parquet_metadata(),
+        )
+        .unwrap()
+        .with_batch_size(30)
+        .with_row_group_aligned_batches(true)
+        .with_offset(150)
+        .build()
+        .unwrap();
+
+        // One batch per row group, regardless of the batch size
+        let ranges = expect_needs_data(decoder.try_decode());
+        push_ranges_to_decoder(&mut decoder, ranges);
+        let batch1 = expect_data(decoder.try_decode());
+        assert_eq!(batch1, TEST_BATCH.slice(150, 50));
+
+        let ranges = expect_needs_data(decoder.try_decode());
+        push_ranges_to_decoder(&mut decoder, ranges);
+        let batch2 = expect_data(decoder.try_decode());
+        assert_eq!(batch2, TEST_BATCH.slice(200, 200));
+
+        expect_finished(decoder.try_decode());
+    }
+
     #[test]
     fn test_decoder_row_group_selection() {
         // take only the second row group
@@ -18,7 +18,7 @@
 //! [`DataRequest`] tracks and holds data needed to construct InMemoryRowGroups
 
 use crate::arrow::ProjectionMask;
-use crate::arrow::arrow_reader::RowSelection;
+use crate::arrow::arrow_reader::{Executor, RowSelection};
 use crate::arrow::in_memory_row_group::{ColumnChunkData, FetchRanges, InMemoryRowGroup};
 use crate::errors::ParquetError;
 use crate::file::metadata::ParquetMetaData;
@@ -76,11 +76,14 @@ impl DataRequest {
     ///
     /// Assumes that all needed data is present in the buffers
     /// and clears any explicitly requested ranges
+    #[expect(clippy::too_many_arguments)]
     pub fn try_into_in_memory_row_group<'a>(
         self,
         row_group_idx: usize,
         row_count: usize,
         parquet_metadata: &'a ParquetMetaData,
+        verify_page_checksums: bool,
+        decompress_pool: Option<&Arc<dyn Executor>>,
         projection: &ProjectionMask,
         buffers: &mut PushBuffers,
     ) -> Result<InMemoryRowGroup<'a>, ParquetError> {
@@ -101,6 +104,8 @@ impl DataRequest {
             offset_index: get_offset_index(parquet_metadata, row_group_idx),
             row_group_idx,
             metadata: parquet_metadata,
+            verify_page_checksums,
+            decompress_pool: decompress_pool.cloned(),
         };
 
         in_memory_row_group.fill_column_chunks(projection, page_start_offsets, chunks);
@@ -194,7 +199,7 @@ impl<'a> DataRequestBuilder<'a> {
 
         // If no previously read column chunks are provided, create a new location to hold them
         let column_chunks =
-            column_chunks.unwrap_or_else(|| vec![None; row_group_meta_data.columns().len()]);
+            column_chunks.unwrap_or_else(|| vec![None; row_group_meta_data.num_columns()]);
 
         // Create an InMemoryRowGroup to hold the column chunks, this is a
         // temporary structure used to tell the ArrowReaders what pages are
@@ -205,6 +210,9 @@ impl<'a> DataRequestBuilder<'a> {
             offset_index: get_offset_index(parquet_metadata, row_group_idx),
             row_group_idx,
             metadata: parquet_metadata,
+            // only used to compute the ranges to fetch, no pages are read
+            verify_page_checksums: false,
+            decompress_pool: None,
         };
 
         let FetchRanges {
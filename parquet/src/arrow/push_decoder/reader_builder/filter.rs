@@ -18,8 +18,9 @@
 //! [`FilterInfo`] state machine for evaluating row filters
 
 use crate::arrow::ProjectionMask;
-use crate::arrow::array_reader::{CacheOptionsBuilder, RowGroupCache};
-use crate::arrow::arrow_reader::{ArrowPredicate, RowFilter};
+use crate::arrow::array_reader::{ArrayReader, CacheOptionsBuilder, RowGroupCache};
+use crate::arrow::arrow_reader::{ArrowPredicate, ReadPlanBuilder, RowFilter};
+use crate::errors::ParquetError;
 use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
 
@@ -106,14 +107,16 @@ impl FilterInfo {
         }
     }
 
-    /// Return a mutable reference to the current predicate
-    pub(super) fn current_mut(&mut self) -> &mut dyn ArrowPredicate {
+    /// Evaluate the current predicate with `array_reader`, see
+    /// [`RowFilter::evaluate_predicate`]
+    pub(super) fn evaluate_current(
+        &mut self,
+        plan_builder: ReadPlanBuilder,
+        array_reader: Box<dyn ArrayReader>,
+    ) -> Result<ReadPlanBuilder, ParquetError> {
+        // advance ensures next_predicate is always in bounds
         self.filter
-            .predicates
-            .get_mut(self.next_predicate.get() - 1)
-            // advance ensures next_predicate is always in bounds
-            .unwrap()
-            .as_mut()
+            .evaluate_predicate(self.next_predicate.get() - 1, plan_builder, array_reader)
     }
 
     /// Return the current predicate to evaluate
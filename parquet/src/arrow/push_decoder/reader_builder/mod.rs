@@ -23,7 +23,7 @@ use crate::arrow::ProjectionMask;
 use crate::arrow::array_reader::{ArrayReaderBuilder, RowGroupCache};
 use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
 use crate::arrow::arrow_reader::{
-    ParquetRecordBatchReader, ReadPlanBuilder, RowFilter, RowSelection,
+    BatchTransform, Executor, ParquetRecordBatchReader, ReadPlanBuilder, RowFilter, RowSelection,
 };
 use crate::arrow::in_memory_row_group::ColumnChunkData;
 use crate::arrow::push_decoder::reader_builder::data::DataRequestBuilder;
@@ -124,11 +124,13 @@ impl NextState {
 /// This struct drives the main state machine for decoding each row group -- it
 /// determines what data is needed, and then assembles the
 /// `ParquetRecordBatchReader` when all data is available.
-#[derive(Debug)]
 pub(crate) struct RowGroupReaderBuilder {
     /// The output batch size
     batch_size: usize,
 
+    /// If true, `batch_size` is ignored and one batch is produced per row group
+    row_group_aligned_batches: bool,
+
     /// What columns to project (produce in each output batch)
     projection: ProjectionMask,
 
@@ -152,6 +154,21 @@ pub(crate) struct RowGroupReaderBuilder {
     /// See [`RowGroupCache`] for details.
     max_predicate_cache_size: usize,
 
+    /// Optional transform applied to each decoded batch
+    batch_transform: Option<BatchTransform>,
+
+    /// Whether to verify page checksums
+    verify_page_checksums: bool,
+
+    /// Whether to preallocate byte array buffers from the column chunk metadata
+    preallocate_from_stats: bool,
+
+    /// Whether to verify decoded decimals fit the precision of their column
+    decimal_validation: bool,
+
+    /// The executor on which to decompress column chunks, if any
+    decompress_pool: Option<Arc<dyn Executor>>,
+
     /// The metrics collector
     metrics: ArrowReaderMetrics,
 
@@ -165,6 +182,33 @@ pub(crate) struct RowGroupReaderBuilder {
     buffers: PushBuffers,
 }
 
+impl std::fmt::Debug for RowGroupReaderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowGroupReaderBuilder")
+            .field("batch_size", &self.batch_size)
+            .field("row_group_aligned_batches", &self.row_group_aligned_batches)
+            .field("projection", &self.projection)
+            .field("metadata", &self.metadata)
+            .field("fields", &self.fields)
+            .field("filter", &self.filter)
+            .field("limit", &self.limit)
+            .field("offset", &self.offset)
+            .field("max_predicate_cache_size", &self.max_predicate_cache_size)
+            .field(
+                "batch_transform",
+                &self.batch_transform.as_ref().map(|_| "..."),
+            )
+            .field("verify_page_checksums", &self.verify_page_checksums)
+            .field("preallocate_from_stats", &self.preallocate_from_stats)
+            .field("decimal_validation", &self.decimal_validation)
+            .field("decompress_pool", &self.decompress_pool)
+            .field("metrics", &self.metrics)
+            .field("state", &self.state)
+            .field("buffers", &self.buffers)
+            .finish()
+    }
+}
+
 impl RowGroupReaderBuilder {
     /// Create a new RowGroupReaderBuilder
     #[expect(clippy::too_many_arguments)]
@@ -178,10 +222,17 @@ impl RowGroupReaderBuilder {
         offset: Option<usize>,
         metrics: ArrowReaderMetrics,
         max_predicate_cache_size: usize,
+        batch_transform: Option<BatchTransform>,
+        verify_page_checksums: bool,
+        preallocate_from_stats: bool,
+        decimal_validation: bool,
+        decompress_pool: Option<Arc<dyn Executor>>,
+        row_group_aligned_batches: bool,
         buffers: PushBuffers,
     ) -> Self {
         Self {
             batch_size,
+            row_group_aligned_batches,
             projection,
             metadata,
             fields,
@@ -190,6 +241,11 @@ impl RowGroupReaderBuilder {
             offset,
             metrics,
             max_predicate_cache_size,
+            batch_transform,
+            verify_page_checksums,
+            preallocate_from_stats,
+            decimal_validation,
+            decompress_pool,
             state: Some(RowGroupDecoderState::Finished),
             buffers,
         }
@@ -233,7 +289,11 @@ impl RowGroupReaderBuilder {
                 "Internal Error: next_row_group called while still reading a row group. Expected Finished state, got {state:?}"
             )));
         }
-        let plan_builder = ReadPlanBuilder::new(self.batch_size).with_selection(selection);
+        let batch_size = match self.row_group_aligned_batches {
+            true => row_count.max(1),
+            false => self.batch_size,
+        };
+        let plan_builder = ReadPlanBuilder::new(batch_size).with_selection(selection);
 
         let row_group_info = RowGroupInfo {
             row_group_idx,
@@ -417,6 +477,8 @@ impl RowGroupReaderBuilder {
                     row_group_idx,
                     row_count,
                     &self.metadata,
+                    self.verify_page_checksums,
+                    self.decompress_pool.as_ref(),
                     predicate.projection(),
                     &mut self.buffers,
                 )?;
@@ -427,8 +489,19 @@ impl RowGroupReaderBuilder {
                     .with_cache_options(Some(&cache_options))
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
-                plan_builder =
-                    plan_builder.with_predicate(array_reader, filter_info.current_mut())?;
+                plan_builder = match filter_info.evaluate_current(plan_builder, array_reader) {
+                    Ok(plan_builder) => plan_builder,
+                    Err(e) => {
+                        let filter = filter_info.into_filter();
+                        filter.handle_error(row_group_idx, e, &self.metrics)?;
+                        // skip entire row group
+                        self.filter = Some(filter);
+                        return Ok(NextState::result(
+                            RowGroupDecoderState::Finished,
+                            DecodeResult::Finished,
+                        ));
+                    }
+                };
 
                 let row_group_info = RowGroupInfo {
                     row_group_idx,
@@ -566,6 +639,8 @@ impl RowGroupReaderBuilder {
                     row_group_idx,
                     row_count,
                     &self.metadata,
+                    self.verify_page_checksums,
+                    self.decompress_pool.as_ref(),
                     &self.projection,
                     &mut self.buffers,
                 )?;
@@ -573,7 +648,9 @@ impl RowGroupReaderBuilder {
                 let plan = plan_builder.build();
 
                 // if we have any cached results, connect them up
-                let array_reader_builder = ArrayReaderBuilder::new(&row_group, &self.metrics);
+                let array_reader_builder = ArrayReaderBuilder::new(&row_group, &self.metrics)
+                    .with_preallocate_from_stats(self.preallocate_from_stats)
+                    .with_decimal_validation(self.decimal_validation);
                 let array_reader = if let Some(cache_info) = cache_info.as_ref() {
                     let cache_options = cache_info.builder().consumer();
                     array_reader_builder
@@ -584,7 +661,8 @@ impl RowGroupReaderBuilder {
                         .build_array_reader(self.fields.as_deref(), &self.projection)
                 }?;
 
-                let reader = ParquetRecordBatchReader::new(array_reader, plan);
+                let reader = ParquetRecordBatchReader::new(array_reader, plan)
+                    .with_batch_transform(self.batch_transform.clone());
                 NextState::result(RowGroupDecoderState::Finished, DecodeResult::Data(reader))
             }
             RowGroupDecoderState::Finished => {
@@ -603,7 +681,7 @@ impl RowGroupReaderBuilder {
         let meta = self.metadata.row_group(row_group_idx);
         match self.compute_cache_projection_inner(filter) {
             Some(projection) => projection,
-            None => ProjectionMask::none(meta.columns().len()),
+            None => ProjectionMask::none(meta.num_columns()),
         }
     }
 
@@ -654,6 +732,6 @@ mod tests {
     #[test]
     // Verify that the size of RowGroupDecoderState does not grow too large
     fn test_structure_size() {
-        assert_eq!(std::mem::size_of::<RowGroupDecoderState>(), 184);
+        assert_eq!(std::mem::size_of::<RowGroupDecoderState>(), 224);
     }
 }
@@ -17,6 +17,64 @@
 
 use crate::arrow::buffer::bit_util::iter_set_bits_rev;
 
+/// The maximum number of bytes preallocated for a single buffer by
+/// [`ValuesBuffer::reserve`], regardless of [`PreallocationHint`]
+pub const MAX_PREALLOCATION_BYTES: usize = 16 * 1024 * 1024;
+
+/// Returns the number of elements of `size` bytes to preallocate for `len`
+/// requested elements, bounded by [`MAX_PREALLOCATION_BYTES`]
+pub fn capped_preallocation(len: usize, size: usize) -> usize {
+    len.min(MAX_PREALLOCATION_BYTES / size.max(1))
+}
+
+/// An estimate of the amount of data decoded per record of a column, computed
+/// from the column chunk metadata, used to preallocate a [`ValuesBuffer`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PreallocationHint {
+    /// The number of records
+    num_records: u64,
+    /// The number of values, including nulls, in `num_records`
+    num_values: u64,
+    /// The number of bytes of variable length data in `num_records`
+    num_bytes: u64,
+}
+
+impl PreallocationHint {
+    /// Create a new [`PreallocationHint`] from the totals of a column
+    pub fn new(num_records: u64, num_values: u64, num_bytes: u64) -> Self {
+        Self {
+            num_records,
+            num_values,
+            num_bytes,
+        }
+    }
+
+    /// Returns the estimated number of values and bytes of variable length
+    /// data in `num_records` records
+    pub fn estimate(&self, num_records: usize) -> (usize, usize) {
+        let scale = |total: u64| {
+            let estimate = (num_records as u128 * total as u128).div_ceil(self.num_records as u128);
+            usize::try_from(estimate).unwrap_or(usize::MAX)
+        };
+        match self.num_records {
+            0 => (0, 0),
+            _ => (scale(self.num_values), scale(self.num_bytes)),
+        }
+    }
+}
+
+impl std::ops::Add for PreallocationHint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            num_records: self.num_records + rhs.num_records,
+            num_values: self.num_values + rhs.num_values,
+            num_bytes: self.num_bytes + rhs.num_bytes,
+        }
+    }
+}
+
 /// A buffer that supports padding with nulls
 pub trait ValuesBuffer: Default {
     /// If a column contains nulls, more level data may be read than value data, as null
@@ -40,6 +98,13 @@ pub trait ValuesBuffer: Default {
         levels_read: usize,
         valid_mask: &[u8],
     );
+
+    /// Reserves capacity for an estimated `num_values` values, and `num_bytes`
+    /// bytes of variable length data, before decoding into an empty buffer
+    ///
+    /// Implementations must bound each allocation by [`MAX_PREALLOCATION_BYTES`],
+    /// see [`capped_preallocation`]. The default implementation does nothing.
+    fn reserve(&mut self, _num_values: usize, _num_bytes: usize) {}
 }
 
 impl<T: Copy + Default> ValuesBuffer for Vec<T> {
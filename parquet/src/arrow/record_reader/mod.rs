@@ -18,7 +18,7 @@
 use arrow_buffer::Buffer;
 
 use crate::arrow::record_reader::{
-    buffer::ValuesBuffer,
+    buffer::{PreallocationHint, ValuesBuffer},
     definition_levels::{DefinitionLevelBuffer, DefinitionLevelBufferDecoder},
 };
 use crate::column::reader::decoder::RepetitionLevelDecoderImpl;
@@ -58,6 +58,8 @@ pub struct GenericRecordReader<V, CV> {
     num_values: usize,
     /// Number of buffered records
     num_records: usize,
+    /// Used to preallocate `values` before reading into it
+    preallocation_hint: Option<PreallocationHint>,
 }
 
 impl<V, CV> GenericRecordReader<V, CV>
@@ -80,9 +82,17 @@ where
             column_desc: desc,
             num_values: 0,
             num_records: 0,
+            preallocation_hint: None,
         }
     }
 
+    /// Preallocate the values buffer according to `hint` before reading
+    /// records into an empty buffer, see [`ValuesBuffer::reserve`]
+    pub fn with_preallocation_hint(mut self, hint: Option<PreallocationHint>) -> Self {
+        self.preallocation_hint = hint;
+        self
+    }
+
     /// Set the current page reader.
     pub fn set_page_reader(&mut self, page_reader: Box<dyn PageReader>) -> Result<()> {
         let descr = &self.column_desc;
@@ -115,6 +125,11 @@ where
             return Ok(0);
         }
 
+        if let (0, Some(hint)) = (self.num_values, &self.preallocation_hint) {
+            let (num_values, num_bytes) = hint.estimate(num_records);
+            self.values.reserve(num_values, num_bytes);
+        }
+
         let mut records_read = 0;
 
         loop {
@@ -18,6 +18,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::arrow::arrow_reader::UnknownLogicalTypePolicy;
 use crate::arrow::schema::extension::try_add_extension_type;
 use crate::arrow::schema::primitive::convert_primitive;
 use crate::arrow::{PARQUET_FIELD_ID_META_KEY, ProjectionMask};
@@ -125,6 +126,9 @@ struct Visitor {
 
     /// Mask of columns to include
     mask: ProjectionMask,
+
+    /// How to read columns annotated with an unknown logical type
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
 }
 
 impl Visitor {
@@ -143,7 +147,11 @@ impl Visitor {
         let repetition = get_repetition(primitive_type);
         let (def_level, rep_level, nullable) = context.levels(repetition);
 
-        let arrow_type = convert_primitive(primitive_type, context.data_type)?;
+        let arrow_type = convert_primitive(
+            primitive_type,
+            context.data_type,
+            self.unknown_logical_type_policy,
+        )?;
 
         let primitive_field = ParquetField {
             rep_level,
@@ -594,10 +602,12 @@ pub fn convert_schema(
     schema: &SchemaDescriptor,
     mask: ProjectionMask,
     embedded_arrow_schema: Option<&Fields>,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
 ) -> Result<Option<ParquetField>> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask,
+        unknown_logical_type_policy,
     };
 
     let context = VisitorContext {
@@ -614,6 +624,7 @@ pub fn convert_type(parquet_type: &TypePtr) -> Result<ParquetField> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask: ProjectionMask::all(),
+        unknown_logical_type_policy: UnknownLogicalTypePolicy::default(),
     };
 
     let context = VisitorContext {
@@ -23,11 +23,17 @@
 //! Extension types are represented using the metadata from Arrow [`Field`]s
 //! with the key "ARROW:extension:name".
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use crate::basic::LogicalType;
 use crate::errors::ParquetError;
+use crate::file::metadata::KeyValue;
+use crate::file::properties::WriterProperties;
 use crate::schema::types::Type;
-use arrow_schema::Field;
 use arrow_schema::extension::ExtensionType;
+use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+use arrow_schema::{DataType, Field, Fields, Schema};
 
 /// Adds extension type metadata, if necessary, based on the Parquet field's
 /// [`LogicalType`]
@@ -133,3 +139,195 @@ pub(crate) fn logical_type_for_string(field: &Field) -> Option<LogicalType> {
 pub(crate) fn logical_type_for_string(_field: &Field) -> Option<LogicalType> {
     Some(LogicalType::String)
 }
+
+/// Prefix of the key-value metadata keys storing the extension name of a field
+const EXTENSION_NAME_PREFIX: &str = "ARROW:extension:name:";
+
+/// Prefix of the key-value metadata keys storing the extension metadata of a field
+const EXTENSION_METADATA_PREFIX: &str = "ARROW:extension:metadata:";
+
+/// Returns true if the extension type of `field` is represented by the
+/// Parquet [`LogicalType`] it is written with
+fn has_logical_type(field: &Field) -> bool {
+    match field.data_type() {
+        DataType::Struct(_) => logical_type_for_struct(field).is_some(),
+        DataType::FixedSizeBinary(_) => logical_type_for_fixed_size_binary(field).is_some(),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+            logical_type_for_string(field) == Some(LogicalType::Json)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the children of `data_type`, along with the name used for each of
+/// them in the path of a field
+///
+/// The elements of lists are always named `element`, and the keys and values
+/// of maps `key` and `value`, so that paths do not depend on the names chosen
+/// for them, which may be changed when coercing types.
+fn child_fields(data_type: &DataType) -> Vec<(&str, &Field)> {
+    match data_type {
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(|f| (f.name().as_str(), f.as_ref()))
+            .collect(),
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::ListView(f)
+        | DataType::LargeListView(f) => vec![("element", f.as_ref())],
+        DataType::Map(f, _) => match f.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                vec![("key", fields[0].as_ref()), ("value", fields[1].as_ref())]
+            }
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+/// Returns `name` as it appears in the path of a field, with any `.` or `\\`
+/// escaped with a `\\`, so that a path identifies a single field even if the
+/// names contain a `.`
+fn escape_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '.' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Returns the path of the child `name` of the field with path `path`
+fn child_path(path: &str, name: &str) -> String {
+    format!("{path}.{}", escape_name(name))
+}
+
+fn collect_extension_metadata(field: &Field, path: &str, metadata: &mut Vec<KeyValue>) {
+    if let Some(name) = field.extension_type_name() {
+        if !has_logical_type(field) {
+            metadata.push(KeyValue::new(
+                format!("{EXTENSION_NAME_PREFIX}{path}"),
+                name.to_string(),
+            ));
+            if let Some(value) = field.extension_type_metadata() {
+                metadata.push(KeyValue::new(
+                    format!("{EXTENSION_METADATA_PREFIX}{path}"),
+                    value.to_string(),
+                ));
+            }
+        }
+    }
+    for (name, child) in child_fields(field.data_type()) {
+        collect_extension_metadata(child, &child_path(path, name), metadata);
+    }
+}
+
+/// Adds the extension type of each field in `schema` that is not represented
+/// by a Parquet [`LogicalType`] to the key-value metadata of `props`
+///
+/// For a field with path `path`, the extension name is stored under the key
+/// `ARROW:extension:name:{path}` and any extension metadata under
+/// `ARROW:extension:metadata:{path}`, where `path` is the dot separated names
+/// of the field and its ancestors, see [`child_fields`] and [`escape_name`].
+///
+/// This allows the extension types to be restored by
+/// [`restore_extension_metadata`], even if the Arrow schema is not embedded.
+pub(crate) fn add_extension_metadata(schema: &Schema, props: &mut WriterProperties) {
+    let mut extension_metadata = vec![];
+    for field in schema.fields() {
+        collect_extension_metadata(field, &escape_name(field.name()), &mut extension_metadata);
+    }
+    if extension_metadata.is_empty() {
+        return;
+    }
+
+    let meta = props
+        .key_value_metadata
+        .get_or_insert_with(Default::default);
+    meta.retain(|kv| {
+        !kv.key.starts_with(EXTENSION_NAME_PREFIX) && !kv.key.starts_with(EXTENSION_METADATA_PREFIX)
+    });
+    meta.extend(extension_metadata);
+}
+
+fn restore_field(field: &Field, path: &str, metadata: &HashMap<&str, &str>) -> Field {
+    let data_type = match field.data_type() {
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| restore_field(f, &child_path(path, f.name()), metadata))
+                .collect::<Fields>(),
+        ),
+        DataType::List(f) => DataType::List(restore_element(f, path, metadata)),
+        DataType::LargeList(f) => DataType::LargeList(restore_element(f, path, metadata)),
+        DataType::FixedSizeList(f, size) => {
+            DataType::FixedSizeList(restore_element(f, path, metadata), *size)
+        }
+        DataType::ListView(f) => DataType::ListView(restore_element(f, path, metadata)),
+        DataType::LargeListView(f) => DataType::LargeListView(restore_element(f, path, metadata)),
+        DataType::Map(f, sorted) => match f.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                let key = restore_field(&fields[0], &child_path(path, "key"), metadata);
+                let value = restore_field(&fields[1], &child_path(path, "value"), metadata);
+                let entries = f
+                    .as_ref()
+                    .clone()
+                    .with_data_type(DataType::Struct(Fields::from(vec![key, value])));
+                DataType::Map(Arc::new(entries), *sorted)
+            }
+            _ => field.data_type().clone(),
+        },
+        data_type => data_type.clone(),
+    };
+    let mut field = field.clone().with_data_type(data_type);
+
+    if field.extension_type_name().is_none() {
+        let name_key = format!("{EXTENSION_NAME_PREFIX}{path}");
+        if let Some(name) = metadata.get(name_key.as_str()) {
+            let mut field_metadata = field.metadata().clone();
+            field_metadata.insert(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string());
+            let metadata_key = format!("{EXTENSION_METADATA_PREFIX}{path}");
+            if let Some(value) = metadata.get(metadata_key.as_str()) {
+                field_metadata.insert(EXTENSION_TYPE_METADATA_KEY.to_string(), value.to_string());
+            }
+            field.set_metadata(field_metadata);
+        }
+    }
+    field
+}
+
+fn restore_element(field: &Arc<Field>, path: &str, metadata: &HashMap<&str, &str>) -> Arc<Field> {
+    Arc::new(restore_field(field, &child_path(path, "element"), metadata))
+}
+
+/// Restores the extension types stored by [`add_extension_metadata`] in
+/// `key_value_metadata` to the fields of `schema`
+///
+/// Fields that already have an extension type are left unchanged.
+pub(crate) fn restore_extension_metadata(
+    schema: &Schema,
+    key_value_metadata: Option<&Vec<KeyValue>>,
+) -> Schema {
+    let metadata: HashMap<_, _> = key_value_metadata
+        .into_iter()
+        .flatten()
+        .filter(|kv| {
+            kv.key.starts_with(EXTENSION_NAME_PREFIX)
+                || kv.key.starts_with(EXTENSION_METADATA_PREFIX)
+        })
+        .filter_map(|kv| Some((kv.key.as_str(), kv.value.as_deref()?)))
+        .collect();
+    if metadata.is_empty() {
+        return schema.clone();
+    }
+
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| restore_field(f, &escape_name(f.name()), &metadata))
+        .collect::<Fields>();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
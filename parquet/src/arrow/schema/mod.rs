@@ -23,13 +23,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_ipc::writer;
-use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, TimeUnit};
 
 use crate::basic::{
     ConvertedType, LogicalType, Repetition, TimeUnit as ParquetTimeUnit, Type as PhysicalType,
 };
 use crate::errors::{ParquetError, Result};
-use crate::file::{metadata::KeyValue, properties::WriterProperties};
+use crate::file::metadata::{KeyValue, ParquetMetaData};
+use crate::file::properties::WriterProperties;
 use crate::schema::types::{ColumnDescriptor, SchemaDescriptor, Type};
 
 mod complex;
@@ -38,6 +39,10 @@ mod primitive;
 
 use super::PARQUET_FIELD_ID_META_KEY;
 use crate::arrow::ProjectionMask;
+use crate::arrow::arrow_reader::UnknownLogicalTypePolicy;
+pub(crate) use crate::arrow::schema::extension::{
+    add_extension_metadata, restore_extension_metadata,
+};
 use crate::arrow::schema::extension::{
     has_extension_type, logical_type_for_fixed_size_binary, logical_type_for_string,
     logical_type_for_struct, try_add_extension_type,
@@ -62,7 +67,13 @@ pub fn parquet_to_arrow_schema_by_columns(
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Result<Schema> {
-    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata)?.0)
+    Ok(parquet_to_arrow_schema_and_fields(
+        parquet_schema,
+        mask,
+        key_value_metadata,
+        UnknownLogicalTypePolicy::default(),
+    )?
+    .0)
 }
 
 /// Determines the Arrow Schema from a Parquet schema
@@ -74,13 +85,31 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     parquet_schema: &SchemaDescriptor,
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
 ) -> Result<(Schema, Option<ParquetField>)> {
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     let maybe_schema = metadata
         .remove(super::ARROW_SCHEMA_META_KEY)
-        .map(|value| get_arrow_schema_from_metadata(&value))
+        .map(|value| get_arrow_schema_hint(&value))
         .transpose()?;
+    arrow_schema_and_fields(
+        parquet_schema,
+        mask,
+        metadata,
+        maybe_schema,
+        unknown_logical_type_policy,
+    )
+}
 
+/// Determines the Arrow Schema from a Parquet schema, the Parquet key value
+/// `metadata` and the decoded Arrow schema metadata "hint", if any
+fn arrow_schema_and_fields(
+    parquet_schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    mut metadata: HashMap<String, String>,
+    maybe_schema: Option<Schema>,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
+) -> Result<(Schema, Option<ParquetField>)> {
     // Add the Arrow metadata to the Parquet metadata skipping keys that collide
     if let Some(arrow_schema) = &maybe_schema {
         arrow_schema.metadata().iter().for_each(|(k, v)| {
@@ -89,11 +118,88 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     }
 
     let hint = maybe_schema.as_ref().map(|s| s.fields());
-    let field_levels = parquet_to_arrow_field_levels(parquet_schema, mask, hint)?;
+    let field_levels = parquet_to_arrow_field_levels_with_policy(
+        parquet_schema,
+        mask,
+        hint,
+        unknown_logical_type_policy,
+    )?;
     let schema = Schema::new_with_metadata(field_levels.fields, metadata);
     Ok((schema, field_levels.levels))
 }
 
+/// Returns the Arrow schema stored in the key value metadata of a parquet file
+/// under [`ARROW_SCHEMA_META_KEY`], as it would be returned by an
+/// [`ArrowReaderBuilder`] for the file, without needing to create a reader.
+///
+/// Returns `None` if the file has no embedded Arrow schema, or if it can not be
+/// decoded, in which case the schema is determined from the Parquet schema
+/// alone, see [`parquet_to_arrow_schema`].
+///
+/// Returns an error if the Parquet schema can not be converted to Arrow.
+///
+/// # Example
+/// ```no_run
+/// # use parquet::arrow::parquet_metadata_to_arrow_schema;
+/// # use parquet::file::metadata::ParquetMetaData;
+/// # fn get_parquet_metadata() -> ParquetMetaData { unimplemented!() }
+/// // Given cached metadata for a parquet file
+/// let metadata: ParquetMetaData = get_parquet_metadata();
+/// if let Some(schema) = parquet_metadata_to_arrow_schema(&metadata).unwrap() {
+///     println!("arrow schema: {schema}");
+/// }
+/// ```
+///
+/// [`ARROW_SCHEMA_META_KEY`]: crate::arrow::ARROW_SCHEMA_META_KEY
+/// [`ArrowReaderBuilder`]: crate::arrow::arrow_reader::ArrowReaderBuilder
+pub fn parquet_metadata_to_arrow_schema(metadata: &ParquetMetaData) -> Result<Option<SchemaRef>> {
+    let file_metadata = metadata.file_metadata();
+    let mut key_value_metadata =
+        parse_key_value_metadata(file_metadata.key_value_metadata()).unwrap_or_default();
+    let Some(Ok(schema)) = key_value_metadata
+        .remove(super::ARROW_SCHEMA_META_KEY)
+        .map(|value| get_arrow_schema_hint(&value))
+    else {
+        return Ok(None);
+    };
+    let (schema, _) = arrow_schema_and_fields(
+        file_metadata.schema_descr(),
+        ProjectionMask::all(),
+        key_value_metadata,
+        Some(schema),
+        UnknownLogicalTypePolicy::default(),
+    )?;
+    Ok(Some(Arc::new(schema)))
+}
+
+/// Returns the Arrow field of leaf column `column` of a parquet file, using
+/// the Arrow schema stored in its key value metadata, if any, see
+/// [`parquet_metadata_to_arrow_schema`].
+///
+/// For a column nested within a struct, list or map, this returns the root
+/// field containing only the path to the column, as it would be read with a
+/// [`ProjectionMask`] of that single leaf.
+pub fn parquet_column_to_arrow_field(
+    metadata: &ParquetMetaData,
+    column: usize,
+) -> Result<FieldRef> {
+    let file_metadata = metadata.file_metadata();
+    let parquet_schema = file_metadata.schema_descr();
+    if column >= parquet_schema.num_columns() {
+        return Err(ParquetError::IndexOutOfBound(
+            column,
+            parquet_schema.num_columns(),
+        ));
+    }
+    let hint = parse_key_value_metadata(file_metadata.key_value_metadata())
+        .and_then(|mut metadata| metadata.remove(super::ARROW_SCHEMA_META_KEY))
+        .and_then(|value| get_arrow_schema_hint(&value).ok());
+    let mask = ProjectionMask::leaves(parquet_schema, [column]);
+    let field_levels =
+        parquet_to_arrow_field_levels(parquet_schema, mask, hint.as_ref().map(|s| s.fields()))?;
+    Ok(Arc::clone(&field_levels.fields[0]))
+}
+
 /// Schema information necessary to decode a parquet file as arrow [`Fields`]
 ///
 /// In particular this stores the dremel-level information necessary to correctly
@@ -132,7 +238,25 @@ pub fn parquet_to_arrow_field_levels(
     mask: ProjectionMask,
     hint: Option<&Fields>,
 ) -> Result<FieldLevels> {
-    match complex::convert_schema(schema, mask, hint)? {
+    parquet_to_arrow_field_levels_with_policy(
+        schema,
+        mask,
+        hint,
+        UnknownLogicalTypePolicy::default(),
+    )
+}
+
+/// Convert a parquet [`SchemaDescriptor`] to [`FieldLevels`], reading columns
+/// annotated with an unknown logical type according to `unknown_logical_type_policy`
+///
+/// See [`parquet_to_arrow_field_levels`]
+pub(crate) fn parquet_to_arrow_field_levels_with_policy(
+    schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    hint: Option<&Fields>,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
+) -> Result<FieldLevels> {
+    match complex::convert_schema(schema, mask, hint, unknown_logical_type_policy)? {
         Some(field) => match &field.arrow_type {
             DataType::Struct(fields) => Ok(FieldLevels {
                 fields: fields.clone(),
@@ -147,6 +271,44 @@ pub fn parquet_to_arrow_field_levels(
     }
 }
 
+/// Returns the Arrow schema stored in `key_value_metadata` under
+/// [`ARROW_SCHEMA_META_KEY`](super::ARROW_SCHEMA_META_KEY), if any, as it was written
+pub(crate) fn embedded_arrow_schema(key_value_metadata: Option<&Vec<KeyValue>>) -> Option<Schema> {
+    parse_key_value_metadata(key_value_metadata)
+        .and_then(|mut metadata| metadata.remove(super::ARROW_SCHEMA_META_KEY))
+        .and_then(|value| get_arrow_schema_from_metadata(&value).ok())
+}
+
+/// Try to convert Arrow schema metadata into a schema to use as a hint when reading
+///
+/// Run-end encoded types are replaced by their values type, as they are only
+/// restored on request, see [`ArrowReaderOptions::with_run_end_encoded_output`]
+///
+/// [`ArrowReaderOptions::with_run_end_encoded_output`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_run_end_encoded_output
+fn get_arrow_schema_hint(encoded_meta: &str) -> Result<Schema> {
+    let schema = get_arrow_schema_from_metadata(encoded_meta)?;
+    let fields: Fields = schema.fields().iter().map(run_end_decoded_field).collect();
+    Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Returns `field` with any run-end encoded types replaced by their values type
+fn run_end_decoded_field(field: &FieldRef) -> FieldRef {
+    let data_type = match field.data_type() {
+        DataType::RunEndEncoded(_, values) => run_end_decoded_field(values).data_type().clone(),
+        DataType::List(f) => DataType::List(run_end_decoded_field(f)),
+        DataType::LargeList(f) => DataType::LargeList(run_end_decoded_field(f)),
+        DataType::FixedSizeList(f, size) => {
+            DataType::FixedSizeList(run_end_decoded_field(f), *size)
+        }
+        DataType::Struct(fields) => {
+            DataType::Struct(fields.iter().map(run_end_decoded_field).collect())
+        }
+        DataType::Map(f, sorted) => DataType::Map(run_end_decoded_field(f), *sorted),
+        _ => return Arc::clone(field),
+    };
+    Arc::new(field.as_ref().clone().with_data_type(data_type))
+}
+
 /// Try to convert Arrow schema metadata into a schema
 fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
     let decoded = BASE64_STANDARD.decode(encoded_meta);
@@ -288,6 +450,18 @@ pub struct ArrowSchemaConverter<'a> {
     ///
     /// See docs on [Self::with_coerce_types]`
     coerce_types: bool,
+    /// Should we write Arrow timestamps as INT96?
+    ///
+    /// See docs on [Self::with_int96_timestamps]`
+    int96_timestamps: bool,
+    /// Byte width to use for `Decimal256` stored as `FIXED_LEN_BYTE_ARRAY`
+    ///
+    /// See docs on [Self::with_decimal256_byte_width]`
+    decimal256_byte_width: Option<usize>,
+    /// Should all decimals be stored as `FIXED_LEN_BYTE_ARRAY`?
+    ///
+    /// See docs on [Self::with_fixed_len_byte_array_decimals]`
+    fixed_len_byte_array_decimals: bool,
 }
 
 impl Default for ArrowSchemaConverter<'_> {
@@ -302,6 +476,9 @@ impl<'a> ArrowSchemaConverter<'a> {
         Self {
             schema_root: "arrow_schema",
             coerce_types: false,
+            int96_timestamps: false,
+            decimal256_byte_width: None,
+            fixed_len_byte_array_decimals: false,
         }
     }
 
@@ -340,6 +517,46 @@ impl<'a> ArrowSchemaConverter<'a> {
         self
     }
 
+    /// Should Arrow timestamps be stored using the deprecated INT96 physical type (default `false`).
+    ///
+    /// INT96 timestamps store the Julian day and the nanoseconds within that day
+    /// of the UTC instant, and have no logical or converted type. They are only
+    /// needed for compatibility with legacy readers that do not understand the
+    /// `TIMESTAMP` logical type.
+    ///
+    /// The embedded Arrow schema still records the original unit and timezone,
+    /// which the reader uses to convert INT96 values back to the original type.
+    pub fn with_int96_timestamps(mut self, int96_timestamps: bool) -> Self {
+        self.int96_timestamps = int96_timestamps;
+        self
+    }
+
+    /// The byte width of the `FIXED_LEN_BYTE_ARRAY` used to store [`DataType::Decimal256`]
+    /// (default `None`).
+    ///
+    /// If `None`, the minimal width able to hold the declared precision is used, as
+    /// is always the case for [`DataType::Decimal128`]. Otherwise the given width is
+    /// used, which must be at least this minimal width and at most 32 bytes.
+    ///
+    /// This has no effect on decimals with a precision of 18 or less, which are
+    /// stored as `INT32` or `INT64`.
+    pub fn with_decimal256_byte_width(mut self, byte_width: Option<usize>) -> Self {
+        self.decimal256_byte_width = byte_width;
+        self
+    }
+
+    /// Should all decimals be stored as `FIXED_LEN_BYTE_ARRAY` (default `false`).
+    ///
+    /// By default, decimals with a precision of 18 or less are stored as `INT32` or
+    /// `INT64`, as recommended by the Parquet specification. Enabling this option
+    /// stores them as `FIXED_LEN_BYTE_ARRAY` of the minimal width for their precision
+    /// instead, for compatibility with legacy readers that do not support the
+    /// integer representations.
+    pub fn with_fixed_len_byte_array_decimals(mut self, value: bool) -> Self {
+        self.fixed_len_byte_array_decimals = value;
+        self
+    }
+
     /// Set the root schema element name (defaults to `"arrow_schema"`).
     pub fn schema_root(mut self, schema_root: &'a str) -> Self {
         self.schema_root = schema_root;
@@ -353,7 +570,7 @@ impl<'a> ArrowSchemaConverter<'a> {
         let fields = schema
             .fields()
             .iter()
-            .map(|field| arrow_to_parquet_type(field, self.coerce_types).map(Arc::new))
+            .map(|field| arrow_to_parquet_type(field, self).map(Arc::new))
             .collect::<Result<_>>()?;
         let group = Type::group_type_builder(self.schema_root)
             .with_fields(fields)
@@ -422,7 +639,7 @@ pub fn decimal_length_from_precision(precision: u8) -> usize {
 }
 
 /// Convert an arrow field to a parquet `Type`
-fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
+fn arrow_to_parquet_type(field: &Field, opts: &ArrowSchemaConverter) -> Result<Type> {
     const PARQUET_LIST_ELEMENT_NAME: &str = "element";
     const PARQUET_MAP_STRUCT_NAME: &str = "key_value";
     const PARQUET_KEY_FIELD_NAME: &str = "key";
@@ -516,6 +733,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_repetition(repetition)
             .with_id(id)
             .build(),
+        DataType::Timestamp(_, _) if opts.int96_timestamps => {
+            Type::primitive_type_builder(name, PhysicalType::INT96)
+                .with_repetition(repetition)
+                .with_id(id)
+                .build()
+        }
         DataType::Timestamp(TimeUnit::Second, _) => {
             // Cannot represent seconds in LogicalType
             Type::primitive_type_builder(name, PhysicalType::INT64)
@@ -545,7 +768,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_id(id)
             .build(),
         DataType::Date64 => {
-            if coerce_types {
+            if opts.coerce_types {
                 Type::primitive_type_builder(name, PhysicalType::INT32)
                     .with_logical_type(Some(LogicalType::Date))
                     .with_repetition(repetition)
@@ -624,15 +847,33 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         | DataType::Decimal256(precision, scale) => {
             // Decimal precision determines the Parquet physical type to use.
             // Following the: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#decimal
-            let (physical_type, length) = if *precision > 1 && *precision <= 9 {
+            let (physical_type, length) = if opts.fixed_len_byte_array_decimals && *precision <= 18
+            {
+                let length = decimal_length_from_precision(*precision);
+                (PhysicalType::FIXED_LEN_BYTE_ARRAY, length as i32)
+            } else if *precision > 1 && *precision <= 9 {
                 (PhysicalType::INT32, -1)
             } else if *precision <= 18 {
                 (PhysicalType::INT64, -1)
             } else {
-                (
-                    PhysicalType::FIXED_LEN_BYTE_ARRAY,
-                    decimal_length_from_precision(*precision) as i32,
-                )
+                let min_length = decimal_length_from_precision(*precision);
+                let length = match (field.data_type(), opts.decimal256_byte_width) {
+                    (DataType::Decimal256(_, _), Some(length)) => {
+                        if length < min_length || length > 32 {
+                            return Err(arrow_err!(
+                                "Invalid byte width {} for Decimal256 field '{}' with precision {}, \
+                                 expected a value between {} and 32",
+                                length,
+                                name,
+                                precision,
+                                min_length
+                            ));
+                        }
+                        length
+                    }
+                    _ => min_length,
+                };
+                (PhysicalType::FIXED_LEN_BYTE_ARRAY, length as i32)
             };
             Type::primitive_type_builder(name, physical_type)
                 .with_repetition(repetition)
@@ -659,12 +900,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_id(id)
             .build(),
         DataType::List(f) | DataType::FixedSizeList(f, _) | DataType::LargeList(f) => {
-            let field_ref = if coerce_types && f.name() != PARQUET_LIST_ELEMENT_NAME {
+            let field_ref = if opts.coerce_types && f.name() != PARQUET_LIST_ELEMENT_NAME {
                 // Ensure proper naming per the Parquet specification
                 let ff = f.as_ref().clone().with_name(PARQUET_LIST_ELEMENT_NAME);
-                Arc::new(arrow_to_parquet_type(&ff, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(&ff, opts)?)
             } else {
-                Arc::new(arrow_to_parquet_type(f, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(f, opts)?)
             };
 
             Type::group_type_builder(name)
@@ -689,7 +930,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             // recursively convert children to types/nodes
             let fields = fields
                 .iter()
-                .map(|f| arrow_to_parquet_type(f, coerce_types).map(Arc::new))
+                .map(|f| arrow_to_parquet_type(f, opts).map(Arc::new))
                 .collect::<Result<_>>()?;
             Type::group_type_builder(name)
                 .with_fields(fields)
@@ -701,7 +942,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         DataType::Map(field, _) => {
             if let DataType::Struct(struct_fields) = field.data_type() {
                 // If coercing then set inner struct name to "key_value"
-                let map_struct_name = if coerce_types {
+                let map_struct_name = if opts.coerce_types {
                     PARQUET_MAP_STRUCT_NAME
                 } else {
                     field.name()
@@ -709,11 +950,11 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
 
                 // If coercing then ensure struct fields are named "key" and "value"
                 let fix_map_field = |name: &str, fld: &Arc<Field>| -> Result<Arc<Type>> {
-                    if coerce_types && fld.name() != name {
+                    if opts.coerce_types && fld.name() != name {
                         let f = fld.as_ref().clone().with_name(name);
-                        Ok(Arc::new(arrow_to_parquet_type(&f, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(&f, opts)?))
                     } else {
-                        Ok(Arc::new(arrow_to_parquet_type(fld, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(fld, opts)?))
                     }
                 };
                 let key_field = fix_map_field(PARQUET_KEY_FIELD_NAME, &struct_fields[0])?;
@@ -740,11 +981,13 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         DataType::Dictionary(_, value) => {
             // Dictionary encoding not handled at the schema level
             let dict_field = field.clone().with_data_type(value.as_ref().clone());
-            arrow_to_parquet_type(&dict_field, coerce_types)
+            arrow_to_parquet_type(&dict_field, opts)
+        }
+        DataType::RunEndEncoded(_, value) => {
+            // Run-end encoding not handled at the schema level
+            let ree_field = field.clone().with_data_type(value.data_type().clone());
+            arrow_to_parquet_type(&ree_field, opts)
         }
-        DataType::RunEndEncoded(_, _) => Err(arrow_err!(
-            "Converting RunEndEncodedType to parquet not supported",
-        )),
     }
 }
 
@@ -759,11 +1002,14 @@ mod tests {
 
     use std::{collections::HashMap, sync::Arc};
 
-    use crate::arrow::PARQUET_FIELD_ID_META_KEY;
+    use crate::arrow::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY};
     use crate::file::metadata::KeyValue;
     use crate::file::reader::FileReader;
     use crate::{
-        arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
+        arrow::{
+            ArrowWriter,
+            arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder},
+        },
         schema::{parser::parse_message_type, types::SchemaDescriptor},
     };
     use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
@@ -2020,7 +2266,7 @@ mod tests {
                     "key_value",
                     Field::new("key", DataType::Utf8, false),
                     Field::new_list("value", Field::new("element", DataType::Utf8, true), true),
-                    false, // fails to roundtrip keys_sorted
+                    true,
                     true,
                 ),
                 Field::new_map(
@@ -2035,7 +2281,7 @@ mod tests {
                         true,
                     )
                     .with_metadata(meta(&[(PARQUET_FIELD_ID_META_KEY, "9")])),
-                    false, // fails to roundtrip keys_sorted
+                    true,
                     true,
                 )
                 .with_metadata(meta(&[(PARQUET_FIELD_ID_META_KEY, "7")])),
@@ -2049,7 +2295,7 @@ mod tests {
                             .with_metadata(meta(&[(PARQUET_FIELD_ID_META_KEY, "11")])),
                         true,
                     ),
-                    false, // fails to roundtrip keys_sorted
+                    false,
                     false,
                 ),
                 Field::new("c42", DataType::Decimal32(5, 2), false),
@@ -2200,11 +2446,163 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_arrow_schema_roundtrip_run_end_encoded() -> Result<()> {
+        let ree = |run_ends: DataType, values: DataType| {
+            DataType::RunEndEncoded(
+                Arc::new(Field::new("run_ends", run_ends, false)),
+                Arc::new(Field::new("values", values, true)),
+            )
+        };
+        let schema = Schema::new(vec![
+            Field::new("c1", ree(DataType::Int32, DataType::Utf8), true),
+            Field::new("c2", ree(DataType::Int16, DataType::Int64), false),
+            Field::new_list(
+                "c3",
+                Field::new("item", ree(DataType::Int64, DataType::Utf8), true),
+                true,
+            ),
+        ]);
+
+        // Run-end encoding is not represented in the parquet schema
+        let expected = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int64, false),
+            Field::new_list("c3", Field::new("item", DataType::Utf8, true), true),
+        ]);
+        let converter = ArrowSchemaConverter::new();
+        assert_eq!(converter.convert(&schema)?, converter.convert(&expected)?);
+
+        // write to an empty parquet file so that schema is serialized
+        let file = tempfile::tempfile().unwrap();
+        let writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), Arc::new(schema.clone()), None)?;
+        writer.close()?;
+
+        // The embedded arrow schema preserves the run-end encoded types
+        let arrow_reader =
+            ParquetRecordBatchReaderBuilder::try_new(file.try_clone().unwrap()).unwrap();
+        let metadata = arrow_reader.metadata().file_metadata().key_value_metadata();
+        let encoded = metadata
+            .unwrap()
+            .iter()
+            .find(|kv| kv.key == super::super::ARROW_SCHEMA_META_KEY)
+            .and_then(|kv| kv.value.as_deref())
+            .unwrap();
+        assert_eq!(get_arrow_schema_from_metadata(encoded)?, schema);
+
+        // But the data is read as the run values
+        assert_eq!(arrow_reader.schema().as_ref(), &expected);
+
+        // Unless the run-end encoding is requested
+        let options = ArrowReaderOptions::new().with_run_end_encoded_output(true);
+        let arrow_reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)?;
+        assert_eq!(arrow_reader.schema().as_ref(), &schema);
+        Ok(())
+    }
+
     #[test]
     fn test_get_arrow_schema_from_metadata() {
         assert!(get_arrow_schema_from_metadata("").is_err());
     }
 
+    #[test]
+    fn test_parquet_metadata_to_arrow_schema() -> Result<()> {
+        use crate::arrow::arrow_reader::ArrowReaderMetadata;
+        use crate::arrow::arrow_writer::ArrowWriterOptions;
+        use crate::file::metadata::ParquetMetaDataReader;
+        use bytes::Bytes;
+
+        let extension = HashMap::from([
+            ("ARROW:extension:name".to_string(), "my.ext".to_string()),
+            ("ARROW:extension:metadata".to_string(), "{}".to_string()),
+        ]);
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new(
+                    "ts",
+                    DataType::Timestamp(TimeUnit::Millisecond, Some("+08:00".into())),
+                    false,
+                ),
+                Field::new("ext", DataType::Utf8, true).with_metadata(extension),
+                Field::new_struct(
+                    "s",
+                    vec![
+                        Field::new("a", DataType::Int32, true),
+                        Field::new("d", DataType::Duration(TimeUnit::Second), true),
+                    ],
+                    true,
+                ),
+            ],
+            HashMap::from([("key".to_string(), "value".to_string())]),
+        );
+        let schema = Arc::new(schema);
+
+        // Returns the metadata of an empty parquet file with `schema`
+        let write = |options: ArrowWriterOptions| -> Result<ParquetMetaData> {
+            let mut buf = Vec::new();
+            let writer = ArrowWriter::try_new_with_options(&mut buf, schema.clone(), options)?;
+            writer.close()?;
+            ParquetMetaDataReader::new().parse_and_finish(&Bytes::from(buf))
+        };
+
+        let metadata = write(ArrowWriterOptions::new())?;
+        let read_schema = parquet_metadata_to_arrow_schema(&metadata)?.unwrap();
+        assert_eq!(read_schema.fields(), schema.fields());
+        assert_eq!(read_schema.metadata()["key"], "value");
+        let reader_schema =
+            ArrowReaderMetadata::try_new(Arc::new(metadata.clone()), Default::default())?
+                .schema()
+                .clone();
+        assert_eq!(read_schema, reader_schema);
+
+        let field = parquet_column_to_arrow_field(&metadata, 0)?;
+        assert_eq!(field.as_ref(), schema.field(0));
+        let field = parquet_column_to_arrow_field(&metadata, 1)?;
+        assert_eq!(field.as_ref(), schema.field(1));
+        let field = parquet_column_to_arrow_field(&metadata, 3)?;
+        let expected = Field::new_struct(
+            "s",
+            vec![Field::new("d", DataType::Duration(TimeUnit::Second), true)],
+            true,
+        );
+        assert_eq!(field.as_ref(), &expected);
+        let err = parquet_column_to_arrow_field(&metadata, 4).unwrap_err();
+        assert_eq!(err.to_string(), "Index 4 out of bound: 4");
+
+        // Without an embedded schema, only the parquet schema is used
+        let metadata = write(ArrowWriterOptions::new().with_skip_arrow_metadata(true))?;
+        assert!(parquet_metadata_to_arrow_schema(&metadata)?.is_none());
+        let field = parquet_column_to_arrow_field(&metadata, 0)?;
+        let expected = Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        );
+        assert_eq!(field.as_ref(), &expected);
+        let field = parquet_column_to_arrow_field(&metadata, 3)?;
+        let DataType::Struct(fields) = field.data_type() else {
+            panic!("expected struct, got {field}");
+        };
+        assert_eq!(fields[0].data_type(), &DataType::Int64);
+
+        // An invalid embedded schema is ignored
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(Some(vec![KeyValue::new(
+                ARROW_SCHEMA_META_KEY.to_string(),
+                "not an arrow schema".to_string(),
+            )]))
+            .build();
+        let options = ArrowWriterOptions::new()
+            .with_properties(props)
+            .with_skip_arrow_metadata(true);
+        let metadata = write(options)?;
+        assert!(parquet_metadata_to_arrow_schema(&metadata)?.is_none());
+        let field = parquet_column_to_arrow_field(&metadata, 0)?;
+        assert_eq!(field.as_ref(), &expected);
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "arrow_canonical_extension_types")]
     fn arrow_uuid_to_parquet_uuid() -> Result<()> {
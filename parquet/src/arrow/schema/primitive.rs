@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::arrow::arrow_reader::UnknownLogicalTypePolicy;
 use crate::basic::{ConvertedType, LogicalType, TimeUnit as ParquetTimeUnit, Type as PhysicalType};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::{BasicTypeInfo, Type};
@@ -24,11 +25,15 @@ use arrow_schema::{DECIMAL128_MAX_PRECISION, DataType, IntervalUnit, TimeUnit};
 /// provided by the arrow schema
 ///
 /// Note: the values embedded in the schema are advisory,
+///
+/// Columns annotated with a logical type not known to this crate are handled
+/// according to `unknown_logical_type_policy`
 pub fn convert_primitive(
     parquet_type: &Type,
     arrow_type_hint: Option<DataType>,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
 ) -> Result<DataType> {
-    let physical_type = from_parquet(parquet_type)?;
+    let physical_type = from_parquet(parquet_type, unknown_logical_type_policy)?;
     Ok(match arrow_type_hint {
         Some(hint) => apply_hint(physical_type, hint),
         None => physical_type,
@@ -102,12 +107,46 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
                 false => hinted,
             }
         }
+
+        // Potentially preserve run-end encoding
+        (_, DataType::RunEndEncoded(_, value)) => {
+            let hinted = apply_hint(parquet, value.data_type().clone());
+            match &hinted == value.data_type() {
+                true => hint,
+                false => hinted,
+            }
+        }
         _ => parquet,
     }
 }
 
-fn from_parquet(parquet_type: &Type) -> Result<DataType> {
+fn from_parquet(
+    parquet_type: &Type,
+    unknown_logical_type_policy: UnknownLogicalTypePolicy,
+) -> Result<DataType> {
     match parquet_type {
+        Type::PrimitiveType {
+            physical_type,
+            basic_info,
+            type_length,
+            ..
+        } if matches!(
+            basic_info.logical_type(),
+            Some(LogicalType::_Unknown { .. })
+        ) =>
+        {
+            match unknown_logical_type_policy {
+                UnknownLogicalTypePolicy::Error => Err(arrow_err!(
+                    "Unable to convert parquet {} column \"{}\" with unknown logical type {:?}",
+                    physical_type,
+                    basic_info.name(),
+                    basic_info.logical_type().unwrap()
+                )),
+                UnknownLogicalTypePolicy::PhysicalType => {
+                    Ok(from_physical_type(*physical_type, *type_length))
+                }
+            }
+        }
         Type::PrimitiveType {
             physical_type,
             basic_info,
@@ -131,6 +170,21 @@ fn from_parquet(parquet_type: &Type) -> Result<DataType> {
     }
 }
 
+/// Returns the [`DataType`] used to read `physical_type` in the absence of
+/// any logical or converted type annotation
+fn from_physical_type(physical_type: PhysicalType, type_length: i32) -> DataType {
+    match physical_type {
+        PhysicalType::BOOLEAN => DataType::Boolean,
+        PhysicalType::INT32 => DataType::Int32,
+        PhysicalType::INT64 => DataType::Int64,
+        PhysicalType::INT96 => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        PhysicalType::FLOAT => DataType::Float32,
+        PhysicalType::DOUBLE => DataType::Float64,
+        PhysicalType::BYTE_ARRAY => DataType::Binary,
+        PhysicalType::FIXED_LEN_BYTE_ARRAY => DataType::FixedSizeBinary(type_length),
+    }
+}
+
 fn decimal_type(scale: i32, precision: i32) -> Result<DataType> {
     if precision <= DECIMAL128_MAX_PRECISION as i32 {
         decimal_128_type(scale, precision)
@@ -147,6 +147,7 @@ impl Page {
 pub struct CompressedPage {
     compressed_page: Page,
     uncompressed_size: usize,
+    crc: Option<u32>,
 }
 
 impl CompressedPage {
@@ -156,9 +157,19 @@ impl CompressedPage {
         Self {
             compressed_page,
             uncompressed_size,
+            crc: None,
         }
     }
 
+    /// Computes the CRC32 checksum of the page buffer, to be written in the page header.
+    ///
+    /// This must be called before the page buffer is encrypted.
+    #[cfg(feature = "crc")]
+    pub(crate) fn with_checksum(mut self) -> Self {
+        self.crc = Some(crc32fast::hash(self.data()));
+        self
+    }
+
     /// Returns page type.
     pub fn page_type(&self) -> PageType {
         self.compressed_page.page_type()
@@ -221,8 +232,7 @@ impl CompressedPage {
             r#type: page_type,
             uncompressed_page_size: uncompressed_size as i32,
             compressed_page_size: compressed_size as i32,
-            // TODO: Add support for crc checksum
-            crc: None,
+            crc: self.crc.map(|crc| crc as i32),
             data_page_header: None,
             index_page_header: None,
             dictionary_page_header: None,
@@ -408,6 +418,15 @@ pub trait PageReader: Iterator<Item = Result<Page>> + Send {
     fn at_record_boundary(&mut self) -> Result<bool> {
         Ok(self.peek_next_page()?.is_none())
     }
+
+    /// Returns the index of the row group containing the column chunk being
+    /// read, if known
+    ///
+    /// This is used to report the location of errors decoding the pages, see
+    /// [`ParquetError::Decode`]
+    fn row_group_index(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// API for writing pages in a column chunk.
@@ -26,7 +26,7 @@ use crate::column::reader::decoder::{
     RepetitionLevelDecoder, RepetitionLevelDecoderImpl,
 };
 use crate::data_type::*;
-use crate::errors::{ParquetError, Result};
+use crate::errors::{DecodeError, ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::{ceil, num_required_bits, read_num_bytes};
 
@@ -134,6 +134,15 @@ pub struct GenericColumnReader<R, D, V> {
 
     /// The decoder for the values
     values_decoder: V,
+
+    /// The number of pages read or skipped from `page_reader`
+    pages_read: usize,
+
+    /// The index of the page being read or decoded, reported in errors
+    page_index: usize,
+
+    /// The encoding of the values of the page being decoded, reported in errors
+    encoding: Option<Encoding>,
 }
 
 impl<V> GenericColumnReader<RepetitionLevelDecoderImpl, DefinitionLevelDecoderImpl, V>
@@ -182,6 +191,9 @@ where
             num_decoded_values: 0,
             values_decoder,
             has_record_delimiter: false,
+            pages_read: 0,
+            page_index: 0,
+            encoding: None,
         }
     }
 
@@ -199,7 +211,20 @@ where
     ///
     /// `values` will be contiguously populated with the non-null values. Note that if the column
     /// is not required, this may be less than either `max_records` or the number of levels read
+    ///
+    /// Errors decoding the data are returned as [`ParquetError::Decode`]
     pub fn read_records(
+        &mut self,
+        max_records: usize,
+        def_levels: Option<&mut D::Buffer>,
+        rep_levels: Option<&mut R::Buffer>,
+        values: &mut V::Buffer,
+    ) -> Result<(usize, usize, usize)> {
+        self.read_records_inner(max_records, def_levels, rep_levels, values)
+            .map_err(|e| self.decode_err(e))
+    }
+
+    fn read_records_inner(
         &mut self,
         max_records: usize,
         mut def_levels: Option<&mut D::Buffer>,
@@ -284,10 +309,20 @@ where
     /// # Returns
     ///
     /// Returns the number of records skipped
+    ///
+    /// Errors decoding the data are returned as [`ParquetError::Decode`]
     pub fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.skip_records_inner(num_records)
+            .map_err(|e| self.decode_err(e))
+    }
+
+    fn skip_records_inner(&mut self, num_records: usize) -> Result<usize> {
         let mut remaining_records = num_records;
         while remaining_records != 0 {
             if self.num_buffered_values == self.num_decoded_values {
+                // Any error reading the header is reported against the next page
+                self.page_index = self.pages_read;
+                self.encoding = None;
                 let metadata = match self.page_reader.peek_next_page()? {
                     None => return Ok(num_records - remaining_records),
                     Some(metadata) => metadata,
@@ -311,6 +346,7 @@ where
                 if let Some(rows) = rows {
                     if rows <= remaining_records {
                         self.page_reader.skip_next_page()?;
+                        self.pages_read += 1;
                         remaining_records -= rows;
                         continue;
                     }
@@ -384,7 +420,7 @@ where
     /// Read the next page as a dictionary page. If the next page is not a dictionary page,
     /// this will return an error.
     fn read_dictionary_page(&mut self) -> Result<()> {
-        match self.page_reader.get_next_page()? {
+        match self.get_next_page()? {
             Some(Page::DictionaryPage {
                 buf,
                 num_values,
@@ -403,7 +439,7 @@ where
     /// Returns false if there's no page left.
     fn read_new_page(&mut self) -> Result<bool> {
         loop {
-            match self.page_reader.get_next_page()? {
+            match self.get_next_page()? {
                 // No more page to read
                 None => return Ok(false),
                 Some(current_page) => {
@@ -558,6 +594,31 @@ where
             Ok(true)
         }
     }
+
+    /// Reads the next page from `page_reader`, recording its location for errors
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        self.page_index = self.pages_read;
+        self.encoding = None;
+        let page = self.page_reader.get_next_page()?;
+        if let Some(page) = &page {
+            self.pages_read += 1;
+            self.encoding = Some(page.encoding());
+        }
+        Ok(page)
+    }
+
+    /// Adds the location of the page being decoded to `e`
+    fn decode_err(&self, e: ParquetError) -> ParquetError {
+        match e {
+            ParquetError::Decode(_) => e,
+            e => ParquetError::Decode(Box::new(
+                DecodeError::new(self.descr.path().clone(), e)
+                    .with_row_group(self.page_reader.row_group_index())
+                    .with_page(Some(self.page_index))
+                    .with_encoding(self.encoding),
+            )),
+        }
+    }
 }
 
 fn parse_v1_level(
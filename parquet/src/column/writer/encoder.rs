@@ -103,6 +103,13 @@ pub trait ColumnValueEncoder {
     /// Returns an estimate of the encoded size of dictionary page size in bytes, or `None` if no dictionary
     fn estimated_dict_page_size(&self) -> Option<usize>;
 
+    /// Returns true if the dictionary should be abandoned regardless of its size,
+    /// for example because a value too long to benefit from dictionary encoding
+    /// has been written
+    fn should_dict_fallback(&self) -> bool {
+        false
+    }
+
     /// Returns an estimate of the encoded data page size in bytes
     ///
     /// This should include:
@@ -198,7 +205,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         // Set either main encoder or fallback encoder.
         let encoder = get_encoder(
             props
-                .encoding(descr.path())
+                .value_encoding(descr.path(), dict_supported)
                 .unwrap_or_else(|| fallback_encoding(T::get_physical_type(), props)),
             descr,
         )?;
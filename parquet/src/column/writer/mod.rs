@@ -365,7 +365,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         props: WriterPropertiesPtr,
         page_writer: Box<dyn PageWriter + 'a>,
     ) -> Self {
-        let codec = props.compression(descr.path());
+        let codec = props.column_compression(&descr);
         let codec_options = CodecOptionsBuilder::default().build();
         let compressor = create_codec(codec, &codec_options).unwrap();
         let encoder = E::try_new(&descr, props.as_ref()).unwrap();
@@ -456,9 +456,13 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         // TODO: find out why we don't account for size of levels when we estimate page
         // size.
 
-        let num_levels = match def_levels {
-            Some(def_levels) => def_levels.len(),
-            None => values.len(),
+        // Without levels there is one level per written value, and `value_indices`
+        // may select a value more than once, such as for each row of a run of a
+        // non-nullable run-end encoded array
+        let num_levels = match (def_levels, value_indices) {
+            (Some(def_levels), _) => def_levels.len(),
+            (None, Some(indices)) => indices.len(),
+            (None, None) => values.len(),
         };
 
         if let Some(min) = min {
@@ -478,14 +482,35 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let mut values_offset = 0;
         let mut levels_offset = 0;
         let base_batch_size = self.props.write_batch_size();
+        let page_row_count_limit = self.props.data_page_row_count_limit();
         while levels_offset < num_levels {
             let mut end_offset = num_levels.min(levels_offset + base_batch_size);
 
-            // Split at record boundary
-            if let Some(r) = rep_levels {
-                while end_offset < r.len() && r[end_offset] != 0 {
-                    end_offset += 1;
+            // Limit the batch to the rows remaining in the current page, so that
+            // the page row count limit is never exceeded
+            let page_rows_remaining = page_row_count_limit
+                .saturating_sub(self.page_metrics.num_buffered_rows as usize)
+                .max(1);
+
+            match rep_levels {
+                Some(r) => {
+                    // Each row contains at least one level
+                    if page_rows_remaining < end_offset - levels_offset {
+                        let mut rows = 0;
+                        if let Some(idx) = r[levels_offset..end_offset].iter().position(|l| {
+                            rows += (*l == 0) as usize;
+                            rows > page_rows_remaining
+                        }) {
+                            end_offset = levels_offset + idx;
+                        }
+                    }
+
+                    // Split at record boundary
+                    while end_offset < r.len() && r[end_offset] != 0 {
+                        end_offset += 1;
+                    }
                 }
+                None => end_offset = end_offset.min(levels_offset + page_rows_remaining),
             }
 
             values_offset += self.write_mini_batch(
@@ -596,6 +621,12 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         &self.descr
     }
 
+    /// Returns the [`WriterProperties`] used by this writer
+    #[cfg(feature = "arrow")]
+    pub(crate) fn props(&self) -> &WriterProperties {
+        &self.props
+    }
+
     /// Finalizes writes and closes the column writer.
     /// Returns total bytes written, total rows written and column chunk metadata.
     pub fn close(mut self) -> Result<ColumnCloseResult> {
@@ -603,7 +634,14 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             self.add_data_page()?;
         }
         if self.encoder.has_dictionary() {
-            self.write_dictionary_page()?;
+            let num_entries = self.write_dictionary_page()?;
+            // Every value in the chunk is dictionary encoded, so the dictionary
+            // holds exactly the distinct values
+            if self.props.dictionary_distinct_count_enabled()
+                && self.column_metrics.column_distinct_count.is_none()
+            {
+                self.column_metrics.column_distinct_count = Some(num_entries as u64);
+            }
         }
         self.flush_data_pages()?;
         let metadata = self.build_column_metadata()?;
@@ -730,14 +768,16 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     /// Returns true if we need to fall back to non-dictionary encoding.
     ///
     /// We can only fall back if dictionary encoder is set and we have exceeded dictionary
-    /// size.
+    /// size, or the encoder has requested to fall back.
     #[inline]
     fn should_dict_fallback(&self) -> bool {
         match self.encoder.estimated_dict_page_size() {
             Some(size) => {
-                size >= self
-                    .props
-                    .column_dictionary_page_size_limit(self.descr.path())
+                self.encoder.should_dict_fallback()
+                    || size
+                        >= self
+                            .props
+                            .column_dictionary_page_size_limit(self.descr.path())
             }
             None => false,
         }
@@ -1254,6 +1294,12 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     /// Writes compressed data page into underlying sink and updates global metrics.
     #[inline]
     fn write_data_page(&mut self, page: CompressedPage) -> Result<()> {
+        #[cfg(feature = "crc")]
+        let page = match self.props.page_checksums_enabled() {
+            true => page.with_checksum(),
+            false => page,
+        };
+
         self.encodings.insert(page.encoding());
         match self.encoding_stats.last_mut() {
             Some(encoding_stats)
@@ -1283,10 +1329,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         Ok(())
     }
 
-    /// Writes dictionary page into underlying sink.
+    /// Writes dictionary page into underlying sink, returning the number of
+    /// dictionary entries.
     #[inline]
-    fn write_dictionary_page(&mut self) -> Result<()> {
-        let compressed_page = {
+    fn write_dictionary_page(&mut self) -> Result<usize> {
+        let (compressed_page, num_entries) = {
             let mut page = self
                 .encoder
                 .flush_dict_page()?
@@ -1306,7 +1353,16 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 encoding: self.props.dictionary_page_encoding(),
                 is_sorted: page.is_sorted,
             };
-            CompressedPage::new(dict_page, uncompressed_size)
+            (
+                CompressedPage::new(dict_page, uncompressed_size),
+                page.num_values,
+            )
+        };
+
+        #[cfg(feature = "crc")]
+        let compressed_page = match self.props.page_checksums_enabled() {
+            true => compressed_page.with_checksum(),
+            false => compressed_page,
         };
 
         self.encodings.insert(compressed_page.encoding());
@@ -1318,7 +1374,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let page_spec = self.page_writer.write_page(compressed_page)?;
         self.update_metrics_for_page(page_spec);
         // For the directory page, don't need to update column/offset index.
-        Ok(())
+        Ok(num_entries)
     }
 
     /// Updates column writer metrics with each page metadata.
@@ -1465,6 +1521,8 @@ fn compare_greater<T: ParquetValueType>(descr: &ColumnDescriptor, a: &T, b: &T)
 /// Returns encoding for a column when no other encoding is provided in writer properties.
 fn fallback_encoding(kind: Type, props: &WriterProperties) -> Encoding {
     match (kind, props.writer_version()) {
+        // See WriterPropertiesBuilder::set_auto_compression
+        (Type::INT32 | Type::INT64, _) if props.auto_compression() => Encoding::DELTA_BINARY_PACKED,
         (Type::BOOLEAN, WriterVersion::PARQUET_2_0) => Encoding::RLE,
         (Type::INT32, WriterVersion::PARQUET_2_0) => Encoding::DELTA_BINARY_PACKED,
         (Type::INT64, WriterVersion::PARQUET_2_0) => Encoding::DELTA_BINARY_PACKED,
@@ -2120,6 +2178,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_value_indices_without_levels() {
+        let page_writer = get_test_page_writer();
+        let props = Default::default();
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 0, 0, props);
+        let indices = [0, 0, 0, 1, 1];
+        let written = writer
+            .write_batch_internal(&[1, 2], Some(&indices), None, None, None, None, None)
+            .unwrap();
+        assert_eq!(written, 5);
+
+        let r = writer.close().unwrap();
+        assert_eq!(r.rows_written, 5);
+        assert_eq!(r.metadata.num_values(), 5);
+    }
+
     #[test]
     fn test_column_writer_check_byte_array_min_max() {
         let page_writer = get_test_page_writer();
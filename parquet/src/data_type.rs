@@ -118,6 +118,41 @@ impl Int96 {
             .wrapping_add(nanos)
     }
 
+    /// Creates an INT96 from a number of SECONDS since EPOCH
+    #[inline]
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self::from_epoch_units(seconds, SECONDS_IN_DAY, NANOSECONDS)
+    }
+
+    /// Creates an INT96 from a number of MILLISECONDS since EPOCH
+    #[inline]
+    pub fn from_millis(millis: i64) -> Self {
+        Self::from_epoch_units(millis, MILLISECONDS_IN_DAY, NANOSECONDS / MILLISECONDS)
+    }
+
+    /// Creates an INT96 from a number of MICROSECONDS since EPOCH
+    #[inline]
+    pub fn from_micros(micros: i64) -> Self {
+        Self::from_epoch_units(micros, MICROSECONDS_IN_DAY, NANOSECONDS / MICROSECONDS)
+    }
+
+    /// Creates an INT96 from a number of NANOSECONDS since EPOCH
+    #[inline]
+    pub fn from_nanos(nanos: i64) -> Self {
+        Self::from_epoch_units(nanos, NANOSECONDS_IN_DAY, 1)
+    }
+
+    /// Splits `value` units since EPOCH into a Julian day and the nanoseconds
+    /// within that day, rounding towards negative infinity for values before EPOCH
+    #[inline]
+    fn from_epoch_units(value: i64, units_in_day: i64, nanos_per_unit: i64) -> Self {
+        let day = value.div_euclid(units_in_day) + JULIAN_DAY_OF_EPOCH;
+        let nanos = value.rem_euclid(units_in_day) * nanos_per_unit;
+        let mut result = Self::new();
+        result.set_data(nanos as u32, (nanos >> 32) as u32, day as u32);
+        result
+    }
+
     #[inline]
     fn get_days(&self) -> i32 {
         self.data()[2] as i32
@@ -1370,6 +1405,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_from_epoch_units() {
+        // 1970-01-01T00:00:00 is the start of Julian day 2440588
+        assert_eq!(Int96::from_nanos(0).data(), &[0, 0, 2_440_588]);
+
+        // One millisecond before EPOCH is the last millisecond of the previous day
+        let i96 = Int96::from_millis(-1);
+        let nanos = NANOSECONDS_IN_DAY - 1_000_000;
+        assert_eq!(i96.data(), &[nanos as u32, (nanos >> 32) as u32, 2_440_587]);
+
+        for v in [-86_400_001, -1, 0, 1, 1_700_000_000_123] {
+            assert_eq!(Int96::from_seconds(v).to_seconds(), v);
+            assert_eq!(Int96::from_millis(v).to_millis(), v);
+            assert_eq!(Int96::from_micros(v).to_micros(), v);
+            assert_eq!(Int96::from_nanos(v).to_nanos(), v);
+        }
+    }
+
     #[test]
     fn test_byte_array_from() {
         assert_eq!(ByteArray::from(b"ABC".to_vec()).data(), b"ABC");
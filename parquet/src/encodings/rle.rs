@@ -404,6 +404,51 @@ impl RleDecoder {
         Ok(values_read)
     }
 
+    /// Decodes up to `max_values` values of bit width 1 directly into the packed
+    /// bits of `out`, returning the number of values read
+    ///
+    /// RLE runs are appended as a single fill, and bit-packed runs are copied
+    /// without being unpacked to individual values
+    #[cfg(feature = "arrow")]
+    #[inline(never)]
+    pub fn get_batch_bits(
+        &mut self,
+        out: &mut arrow_buffer::BooleanBufferBuilder,
+        max_values: usize,
+    ) -> Result<usize> {
+        assert_eq!(
+            self.bit_width, 1,
+            "get_batch_bits requires a bit width of 1"
+        );
+
+        let mut values_read = 0;
+        while values_read < max_values {
+            if self.rle_left > 0 {
+                let num_values = cmp::min(max_values - values_read, self.rle_left as usize);
+                let repeated_value = self.current_value.unwrap() != 0;
+                out.append_n(num_values, repeated_value);
+                self.rle_left -= num_values as u32;
+                values_read += num_values;
+            } else if self.bit_packed_left > 0 {
+                let num_values = cmp::min(max_values - values_read, self.bit_packed_left as usize);
+                let bit_reader = self.bit_reader.as_mut().expect("bit_reader should be set");
+
+                let num_values = bit_reader.get_bits(out, num_values);
+                if num_values == 0 {
+                    // Handle writers which truncate the final block
+                    self.bit_packed_left = 0;
+                    continue;
+                }
+                self.bit_packed_left -= num_values as u32;
+                values_read += num_values;
+            } else if !self.reload() {
+                break;
+            }
+        }
+
+        Ok(values_read)
+    }
+
     #[inline(never)]
     pub fn skip(&mut self, num_values: usize) -> Result<usize> {
         let mut values_skipped = 0;
@@ -624,6 +669,49 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_rle_decode_bool_bits() {
+        // Long RLE runs, including a run with a multi-byte header, split by
+        // bit-packed runs that are not byte aligned in the output
+        let mut expected = vec![true; 3];
+        expected.extend(std::iter::repeat_n(false, 20_000));
+        expected.extend((0..37).map(|i| i % 3 == 0));
+        expected.extend(std::iter::repeat_n(true, 1_000_001));
+        expected.extend((0..5).map(|i| i % 2 == 0));
+
+        let mut encoder = RleEncoder::new(1, 1024);
+        for v in &expected {
+            encoder.put(*v as u64);
+        }
+        let data: Bytes = encoder.consume().into();
+
+        let mut decoder = RleDecoder::new(1);
+        decoder.set_data(data.clone());
+        let mut builder = arrow_buffer::BooleanBufferBuilder::new(0);
+        let mut read = 0;
+        // The final bit-packed run is padded, so bound the reads by the number of values
+        for batch_size in [7, 10_000, 30_000, 1024].into_iter().cycle() {
+            let batch_size = batch_size.min(expected.len() - read);
+            if batch_size == 0 {
+                break;
+            }
+            let r = decoder.get_batch_bits(&mut builder, batch_size).unwrap();
+            assert_eq!(r, batch_size);
+            read += r;
+        }
+        let buffer = builder.finish();
+        assert!(buffer.iter().eq(expected.iter().copied()));
+
+        // Reading after skipping into the middle of a run
+        decoder.set_data(data);
+        assert_eq!(decoder.skip(20_010).unwrap(), 20_010);
+        let mut builder = arrow_buffer::BooleanBufferBuilder::new(0);
+        assert_eq!(decoder.get_batch_bits(&mut builder, 100).unwrap(), 100);
+        let buffer = builder.finish();
+        assert!(buffer.iter().eq(expected[20_010..20_110].iter().copied()));
+    }
+
     #[test]
     fn test_rle_skip_bool() {
         // RLE test data: 50 1s followed by 50 0s
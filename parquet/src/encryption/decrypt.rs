@@ -22,6 +22,7 @@ use crate::encryption::modules::{ModuleType, create_footer_aad, create_module_aa
 use crate::errors::{ParquetError, Result};
 use crate::file::column_crypto_metadata::ColumnCryptoMetaData;
 use crate::file::metadata::HeapSize;
+use crate::schema::types::ColumnPath;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Formatter;
@@ -352,6 +353,8 @@ pub struct FileDecryptionProperties {
     keys: DecryptionKeys,
     aad_prefix: Option<Vec<u8>>,
     footer_signature_verification: bool,
+    /// Skip decrypting columns for which no explicit key was provided
+    skip_columns_without_keys: bool,
 }
 
 impl HeapSize for FileDecryptionProperties {
@@ -417,6 +420,43 @@ impl FileDecryptionProperties {
         }
     }
 
+    /// Returns a copy of these properties with the provided column keys added,
+    /// where encrypted columns without a key are left undecrypted instead of
+    /// causing an error when the file metadata is read.
+    ///
+    /// See [`ArrowReaderOptions::with_column_keys`](crate::arrow::arrow_reader::ArrowReaderOptions::with_column_keys)
+    pub(crate) fn with_selective_column_keys(
+        &self,
+        column_keys: &HashMap<ColumnPath, Vec<u8>>,
+    ) -> Result<Self> {
+        let DecryptionKeys::Explicit(keys) = &self.keys else {
+            return Err(general_err!(
+                "Column decryption keys cannot be combined with a key retriever"
+            ));
+        };
+        let mut keys = keys.clone();
+        for (path, key) in column_keys {
+            keys.column_keys.insert(path.string(), key.clone());
+        }
+        Ok(Self {
+            keys: DecryptionKeys::Explicit(keys),
+            aad_prefix: self.aad_prefix.clone(),
+            footer_signature_verification: self.footer_signature_verification,
+            skip_columns_without_keys: true,
+        })
+    }
+
+    /// Returns true if the column should not be decrypted as it has no key,
+    /// see [`Self::with_selective_column_keys`]
+    pub(crate) fn is_column_skipped(&self, column_name: &str) -> bool {
+        match &self.keys {
+            DecryptionKeys::Explicit(keys) => {
+                self.skip_columns_without_keys && !keys.column_keys.contains_key(column_name)
+            }
+            DecryptionKeys::ViaRetriever(_) => false,
+        }
+    }
+
     /// Get the column names and associated decryption keys that have been configured.
     /// If a key retriever is used rather than explicit decryption keys, the result
     /// will be empty.
@@ -472,6 +512,7 @@ impl DecryptionPropertiesBuilder {
             keys,
             aad_prefix: self.aad_prefix,
             footer_signature_verification: self.footer_signature_verification,
+            skip_columns_without_keys: false,
         }))
     }
 
@@ -540,6 +581,7 @@ impl DecryptionPropertiesBuilderWithRetriever {
             keys,
             aad_prefix: self.aad_prefix,
             footer_signature_verification: self.footer_signature_verification,
+            skip_columns_without_keys: false,
         }))
     }
 
@@ -610,6 +652,11 @@ impl FileDecryptor {
         })
     }
 
+    /// Returns true if the column was not decrypted as no key was provided for it
+    pub(crate) fn is_column_skipped(&self, column_name: &str) -> bool {
+        self.decryption_properties.is_column_skipped(column_name)
+    }
+
     pub(crate) fn get_footer_decryptor(&self) -> Result<Arc<dyn BlockDecryptor>> {
         Ok(self.footer_decryptor.clone())
     }
@@ -19,12 +19,16 @@
 
 use core::num::TryFromIntError;
 use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::string::FromUtf8Error;
 use std::{cell, io, result, str};
 
 #[cfg(feature = "arrow")]
 use arrow_schema::ArrowError;
 
+use crate::basic::Encoding;
+use crate::schema::types::ColumnPath;
+
 /// Parquet error enumeration
 // Note: we don't implement PartialEq as the semantics for the
 // external variant are not well defined (#4469)
@@ -56,6 +60,10 @@ pub enum ParquetError {
     /// Returned when a function needs more data to complete properly.
     /// The `Range<u64>` indicates the range of bytes that are needed.
     NeedMoreDataRange(std::ops::Range<u64>),
+    /// Returned when the data of a column cannot be decoded, e.g. as it is
+    /// corrupt or uses an unsupported encoding. The [`DecodeError`] contains
+    /// the location of the data and the underlying error.
+    Decode(Box<DecodeError>),
 }
 
 impl std::fmt::Display for ParquetError {
@@ -76,6 +84,7 @@ impl std::fmt::Display for ParquetError {
             ParquetError::NeedMoreDataRange(range) => {
                 write!(fmt, "NeedMoreDataRange: {}..{}", range.start, range.end)
             }
+            ParquetError::Decode(e) => write!(fmt, "{e}"),
         }
     }
 }
@@ -84,11 +93,108 @@ impl Error for ParquetError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             ParquetError::External(e) => Some(e.as_ref()),
+            ParquetError::Decode(e) => e.source(),
             _ => None,
         }
     }
 }
 
+/// An error decoding the data of a column, see [`ParquetError::Decode`]
+#[derive(Debug)]
+pub struct DecodeError {
+    column: ColumnPath,
+    row_group: Option<usize>,
+    page: Option<usize>,
+    encoding: Option<Encoding>,
+    source: ParquetError,
+}
+
+impl DecodeError {
+    /// Create a new [`DecodeError`] for `column`, caused by `source`
+    pub(crate) fn new(column: ColumnPath, source: ParquetError) -> Self {
+        Self {
+            column,
+            row_group: None,
+            page: None,
+            encoding: None,
+            source,
+        }
+    }
+
+    /// Sets the index of the row group
+    pub(crate) fn with_row_group(self, row_group: Option<usize>) -> Self {
+        Self { row_group, ..self }
+    }
+
+    /// Sets the index of the page
+    pub(crate) fn with_page(self, page: Option<usize>) -> Self {
+        Self { page, ..self }
+    }
+
+    /// Sets the encoding of the values of the page
+    pub(crate) fn with_encoding(self, encoding: Option<Encoding>) -> Self {
+        Self { encoding, ..self }
+    }
+
+    /// Returns the path of the column that could not be decoded
+    pub fn column(&self) -> &ColumnPath {
+        &self.column
+    }
+
+    /// Returns the index of the row group within the file, if known
+    pub fn row_group(&self) -> Option<usize> {
+        self.row_group
+    }
+
+    /// Returns the index of the page within the column chunk, if known
+    ///
+    /// Pages are counted from the start of the column chunk, including any
+    /// dictionary page and any pages that were skipped.
+    pub fn page(&self) -> Option<usize> {
+        self.page
+    }
+
+    /// Returns the encoding of the values of the page, if known
+    ///
+    /// This is `None` if the page itself could not be read, e.g. as its
+    /// header or compressed data is corrupt
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.encoding
+    }
+
+    /// Returns the underlying error
+    pub fn inner(&self) -> &ParquetError {
+        &self.source
+    }
+
+    /// Returns the underlying error, discarding the location
+    pub fn into_inner(self) -> ParquetError {
+        self.source
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error decoding column {}", self.column)?;
+        if let Some(row_group) = self.row_group {
+            write!(f, " in row group {row_group}")?;
+        }
+        if let Some(page) = self.page {
+            write!(f, " at page {page}")?;
+        }
+        if let Some(encoding) = self.encoding {
+            write!(f, " with encoding {encoding}")?;
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
 impl From<TryFromIntError> for ParquetError {
     fn from(e: TryFromIntError) -> ParquetError {
         ParquetError::General(format!("Integer overflow: {e}"))
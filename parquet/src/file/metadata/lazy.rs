@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lazily decoded [`ColumnChunkMetaData`], see
+//! [`ParquetMetaDataReader::with_lazy_column_metadata`]
+//!
+//! [`ParquetMetaDataReader::with_lazy_column_metadata`]: crate::file::metadata::ParquetMetaDataReader::with_lazy_column_metadata
+
+use crate::errors::Result;
+use crate::file::metadata::thrift::decode_column_chunk;
+use crate::file::metadata::{ColumnChunkMetaData, HeapSize};
+use crate::schema::types::SchemaDescriptor;
+use bytes::Bytes;
+use std::cell::UnsafeCell;
+use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
+use std::sync::OnceLock;
+
+/// The thrift encoded metadata of the column chunks of a row group, which is
+/// decoded on first access and then cached
+///
+/// The decoded metadata is stored contiguously, so that once all columns have
+/// been decoded they can be returned as a slice without copying them.
+pub(crate) struct LazyColumnChunks {
+    /// The thrift encoded `ColumnChunk` of each column
+    encoded: Vec<Bytes>,
+    /// Set once the metadata of the corresponding column has been written to `decoded`
+    initialized: Box<[OnceLock<()>]>,
+    /// The decoded metadata of each column, which is only written by the thread
+    /// setting the corresponding `initialized`, and only read once it is set
+    decoded: Box<[UnsafeCell<MaybeUninit<ColumnChunkMetaData>>]>,
+}
+
+// SAFETY: the writes to `decoded` are synchronized by `initialized`, see above
+unsafe impl Sync for LazyColumnChunks {}
+
+impl LazyColumnChunks {
+    /// Create a new [`LazyColumnChunks`] from the thrift encoded `ColumnChunk`
+    /// of each column
+    pub(crate) fn new(encoded: Vec<Bytes>) -> Self {
+        let initialized = encoded.iter().map(|_| OnceLock::new()).collect();
+        let decoded = encoded
+            .iter()
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+        Self {
+            encoded,
+            initialized,
+            decoded,
+        }
+    }
+
+    /// Returns the number of columns
+    pub(crate) fn len(&self) -> usize {
+        self.encoded.len()
+    }
+
+    /// Returns the metadata of column `i`, decoding it if necessary
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds
+    pub(crate) fn column(
+        &self,
+        i: usize,
+        schema_descr: &SchemaDescriptor,
+    ) -> Result<&ColumnChunkMetaData> {
+        if self.initialized[i].get().is_none() {
+            let mut column = Some(decode_column_chunk(
+                &self.encoded[i],
+                &schema_descr.column(i),
+            )?);
+            self.initialized[i].get_or_init(|| {
+                let column = column.take().unwrap();
+                // SAFETY: only the thread initializing `initialized[i]` writes
+                // `decoded[i]`, which is not read before `initialized[i]` is set
+                unsafe { (*self.decoded[i].get()).write(column) };
+            });
+        }
+        // SAFETY: `initialized[i]` is set, so `decoded[i]` is initialized and
+        // will not be written again
+        Ok(unsafe { (*self.decoded[i].get()).assume_init_ref() })
+    }
+
+    /// Returns the metadata of all columns, decoding them if necessary
+    pub(crate) fn columns(
+        &self,
+        schema_descr: &SchemaDescriptor,
+    ) -> Result<&[ColumnChunkMetaData]> {
+        for i in 0..self.len() {
+            self.column(i, schema_descr)?;
+        }
+        // SAFETY: all columns are initialized and will not be written again, and
+        // `UnsafeCell<MaybeUninit<T>>` has the same layout as `T`
+        Ok(unsafe {
+            std::slice::from_raw_parts(
+                self.decoded.as_ptr().cast::<ColumnChunkMetaData>(),
+                self.decoded.len(),
+            )
+        })
+    }
+
+    /// Returns the decoded metadata of each column, if it has been decoded
+    fn decoded(&self) -> impl Iterator<Item = &ColumnChunkMetaData> {
+        self.initialized
+            .iter()
+            .zip(self.decoded.iter())
+            .filter(|(initialized, _)| initialized.get().is_some())
+            // SAFETY: `decoded` is initialized if `initialized` is set
+            .map(|(_, decoded)| unsafe { (*decoded.get()).assume_init_ref() })
+    }
+
+    /// Returns the number of columns that have been decoded
+    pub(crate) fn num_decoded(&self) -> usize {
+        self.decoded().count()
+    }
+}
+
+impl Drop for LazyColumnChunks {
+    fn drop(&mut self) {
+        for (initialized, decoded) in self.initialized.iter().zip(self.decoded.iter_mut()) {
+            if initialized.get().is_some() {
+                // SAFETY: `decoded` is initialized if `initialized` is set
+                unsafe { decoded.get_mut().assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl Debug for LazyColumnChunks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyColumnChunks")
+            .field("num_columns", &self.len())
+            .field("num_decoded", &self.num_decoded())
+            .finish()
+    }
+}
+
+impl HeapSize for LazyColumnChunks {
+    fn heap_size(&self) -> usize {
+        // the encoded bytes are slices of the footer, count them in full
+        let encoded = self.encoded.iter().map(|b| b.len()).sum::<usize>()
+            + self.encoded.capacity() * std::mem::size_of::<Bytes>();
+        let decoded = self.decoded().map(|c| c.heap_size()).sum::<usize>()
+            + self.initialized.len() * std::mem::size_of::<OnceLock<()>>()
+            + self.decoded.len() * std::mem::size_of::<ColumnChunkMetaData>();
+        encoded + decoded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::metadata::RowGroupMetaData;
+    use crate::schema::parser::parse_message_type;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_invalid_column_chunk() {
+        let schema = parse_message_type("message test { REQUIRED INT32 a; }").unwrap();
+        let schema_descr = SchemaDescriptor::new(Arc::new(schema));
+
+        let lazy = LazyColumnChunks::new(vec![Bytes::from_static(&[0xFF, 0xFF])]);
+        assert_eq!(lazy.len(), 1);
+        lazy.column(0, &schema_descr).unwrap_err();
+        lazy.columns(&schema_descr).unwrap_err();
+        assert_eq!(lazy.num_decoded(), 0);
+    }
+
+    #[test]
+    fn test_invalid_row_group_columns() {
+        let schema = parse_message_type("message test { REQUIRED INT32 a; }").unwrap();
+        let schema_descr = Arc::new(SchemaDescriptor::new(Arc::new(schema)));
+
+        let mut row_group = RowGroupMetaData::builder(schema_descr).build_unchecked();
+        let lazy = LazyColumnChunks::new(vec![Bytes::from_static(&[0xFF, 0xFF])]);
+        row_group.lazy_columns = Some(Arc::new(lazy));
+
+        row_group.try_column(0).unwrap_err();
+        row_group.try_columns().unwrap_err();
+        row_group.clone().try_columns_mut().unwrap_err();
+        row_group.clone().into_builder().build().unwrap_err();
+    }
+}
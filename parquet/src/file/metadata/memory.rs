@@ -18,7 +18,7 @@
 //! Memory calculations for [`ParquetMetadata::memory_size`]
 //!
 //! [`ParquetMetadata::memory_size`]: crate::file::metadata::ParquetMetaData::memory_size
-use crate::basic::{BoundaryOrder, ColumnOrder, Compression, Encoding, PageType};
+use crate::basic::{BoundaryOrder, ColumnOrder, Compression, Encoding, LogicalType, PageType};
 use crate::data_type::private::ParquetValueType;
 use crate::file::metadata::{
     ColumnChunkMetaData, FileMetaData, KeyValue, PageEncodingStats, RowGroupMetaData, SortingColumn,
@@ -150,9 +150,28 @@ impl HeapSize for KeyValue {
 
 impl HeapSize for RowGroupMetaData {
     fn heap_size(&self) -> usize {
-        // don't count schema_descr here because it is already
-        // counted in FileMetaData
-        self.columns.heap_size() + self.sorting_columns.heap_size()
+        // don't count schema_descr here because it is usually shared with
+        // FileMetaData, see ParquetMetaData::memory_size
+        //
+        // Column descriptors are usually shared with schema_descr, but count
+        // those that are not as they would otherwise be missed
+        let leaves = self.schema_descr.columns();
+        let unshared_column_descr_size = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(idx, column)| {
+                leaves
+                    .get(*idx)
+                    .is_none_or(|leaf| !Arc::ptr_eq(leaf, &column.column_descr))
+            })
+            .map(|(_, column)| column.column_descr.heap_size())
+            .sum::<usize>();
+
+        self.columns.heap_size()
+            + self.lazy_columns.heap_size()
+            + self.sorting_columns.heap_size()
+            + unshared_column_descr_size
     }
 }
 
@@ -327,6 +346,15 @@ impl HeapSize for PageLocation {
     }
 }
 
+impl HeapSize for LogicalType {
+    fn heap_size(&self) -> usize {
+        match self {
+            LogicalType::Geometry { crs } | LogicalType::Geography { crs, .. } => crs.heap_size(),
+            _ => 0, // no heap allocations
+        }
+    }
+}
+
 impl HeapSize for ColumnOrder {
     fn heap_size(&self) -> usize {
         0 // no heap allocations in ColumnOrder
@@ -87,10 +87,13 @@
 //!                         * Same name, different struct
 //! ```
 mod footer_tail;
+mod lazy;
 mod memory;
 mod parser;
 mod push_decoder;
 pub(crate) mod reader;
+mod serialized;
+mod summary;
 pub(crate) mod thrift;
 mod writer;
 
@@ -99,7 +102,12 @@ use crate::basic::{EncodingMask, PageType};
 use crate::encryption::decrypt::FileDecryptor;
 #[cfg(feature = "encryption")]
 use crate::file::column_crypto_metadata::ColumnCryptoMetaData;
+use crate::file::metadata::lazy::LazyColumnChunks;
 pub(crate) use crate::file::metadata::memory::HeapSize;
+#[cfg(feature = "arrow")]
+pub(crate) use crate::file::metadata::parser::{
+    parse_row_group_offset_index, row_group_offset_index_range,
+};
 #[cfg(feature = "encryption")]
 use crate::file::metadata::thrift::encryption::EncryptionAlgorithm;
 use crate::file::page_index::column_index::{ByteArrayColumnIndex, PrimitiveColumnIndex};
@@ -128,10 +136,12 @@ use crate::{
 
 pub use footer_tail::FooterTail;
 pub use push_decoder::ParquetMetaDataPushDecoder;
-pub use reader::{PageIndexPolicy, ParquetMetaDataReader};
+pub use reader::{DEFAULT_PREFETCH_HINT, PageIndexPolicy, ParquetMetaDataReader};
+use std::collections::HashSet;
 use std::io::Write;
 use std::ops::Range;
 use std::sync::Arc;
+pub use summary::{KeyValueMetadataMerge, ParquetSummaryMetaData, ParquetSummaryMetaDataWriter};
 pub use writer::ParquetMetaDataWriter;
 pub(crate) use writer::ThriftMetadataWriter;
 
@@ -256,6 +266,9 @@ impl ParquetMetaData {
     /// Returns `None` if the parquet file does not have a `ColumnIndex` or
     /// [ArrowReaderOptions::with_page_index] was set to false.
     ///
+    /// If only some columns were loaded, see [`ParquetMetaDataReader::with_page_index_columns`],
+    /// the column index of the other columns is [`ColumnIndexMetaData::NONE`].
+    ///
     /// [ArrowReaderOptions::with_page_index]: https://docs.rs/parquet/latest/parquet/arrow/arrow_reader/struct.ArrowReaderOptions.html#method.with_page_index
     pub fn column_index(&self) -> Option<&ParquetColumnIndex> {
         self.column_index.as_ref()
@@ -266,6 +279,9 @@ impl ParquetMetaData {
     /// Returns `None` if the parquet file does not have a `OffsetIndex` or
     /// [ArrowReaderOptions::with_page_index] was set to false.
     ///
+    /// If only some columns were loaded, see [`ParquetMetaDataReader::with_page_index_columns`],
+    /// the offset index of the other columns contains no page locations.
+    ///
     /// [ArrowReaderOptions::with_page_index]: https://docs.rs/parquet/latest/parquet/arrow/arrow_reader/struct.ArrowReaderOptions.html#method.with_page_index
     pub fn offset_index(&self) -> Option<&ParquetOffsetIndex> {
         self.offset_index.as_ref()
@@ -280,9 +296,14 @@ impl ParquetMetaData {
     /// 2. Includes heap memory for sub fields such as [`FileMetaData`] and
     ///    [`RowGroupMetaData`].
     ///
-    /// 3. Includes memory from shared pointers (e.g. [`SchemaDescPtr`]). This
-    ///    means `memory_size` will over estimate the memory size if such pointers
-    ///    are shared.
+    /// 3. Includes memory from shared pointers (e.g. [`SchemaDescPtr`]). Each
+    ///    [`SchemaDescriptor`] shared between the [`FileMetaData`] and the
+    ///    [`RowGroupMetaData`], and each [`ColumnDescriptor`] shared between a
+    ///    [`SchemaDescriptor`] and [`ColumnChunkMetaData`], is counted once.
+    ///    Pointers shared with structures outside this `ParquetMetaData`, for
+    ///    example another `ParquetMetaData` for a file with the same schema,
+    ///    are counted in full, so `memory_size` will over estimate the total
+    ///    memory size of such structures.
     ///
     /// 4. Does not include any allocator overheads
     pub fn memory_size(&self) -> usize {
@@ -291,14 +312,81 @@ impl ParquetMetaData {
         #[cfg(not(feature = "encryption"))]
         let encryption_size = 0usize;
 
+        // The schema of the file is counted by FileMetaData, count any other
+        // schema referenced by the row groups once
+        let mut schemas = HashSet::from([Arc::as_ptr(&self.file_metadata.schema_descr)]);
+        let row_group_schemas_size = self
+            .row_groups
+            .iter()
+            .filter(|rg| schemas.insert(Arc::as_ptr(&rg.schema_descr)))
+            .map(|rg| rg.schema_descr.heap_size())
+            .sum::<usize>();
+
         std::mem::size_of::<Self>()
             + self.file_metadata.heap_size()
             + self.row_groups.heap_size()
+            + row_group_schemas_size
             + self.column_index.heap_size()
             + self.offset_index.heap_size()
             + encryption_size
     }
 
+    /// Serializes this [`ParquetMetaData`] into a self-contained byte buffer
+    ///
+    /// This is intended for caching the parsed metadata of a file, for example
+    /// to avoid reading and decoding its footer again in a later query. The
+    /// returned bytes can be decoded with [`Self::try_from_bytes`], which
+    /// returns metadata equal to `self`, including the row groups, key-value
+    /// metadata, and column and offset indexes.
+    ///
+    /// Unlike [`ParquetMetaDataWriter`], the file offsets stored in the column
+    /// chunks, such as the location of bloom filters and page indexes, are
+    /// preserved, so the decoded metadata can be used to read the original file.
+    ///
+    /// The encoding is versioned and is not a valid parquet footer.
+    ///
+    /// Returns an error if the metadata belongs to an encrypted file.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::file::metadata::{PageIndexPolicy, ParquetMetaData};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buf = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(buf);
+    /// let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+    /// let metadata = ArrowReaderMetadata::load(&file, options.clone()).unwrap();
+    ///
+    /// // Store the metadata, e.g. in a cache
+    /// let cached = metadata.metadata().try_to_bytes().unwrap();
+    ///
+    /// // Later, read the file using the cached metadata
+    /// let metadata = Arc::new(ParquetMetaData::try_from_bytes(&cached).unwrap());
+    /// let metadata = ArrowReaderMetadata::try_new(metadata, options).unwrap();
+    /// let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(file, metadata)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn try_to_bytes(&self) -> Result<Vec<u8>> {
+        serialized::metadata_to_bytes(self)
+    }
+
+    /// Decodes [`ParquetMetaData`] previously serialized with [`Self::try_to_bytes`]
+    ///
+    /// Returns an error if `buf` is not valid serialized metadata, or was
+    /// written by an incompatible version of this crate.
+    pub fn try_from_bytes(buf: &[u8]) -> Result<Self> {
+        serialized::metadata_from_bytes(buf)
+    }
+
     /// Override the column index
     pub(crate) fn set_column_index(&mut self, index: Option<ParquetColumnIndex>) {
         self.column_index = index;
@@ -426,10 +514,175 @@ impl ParquetMetaDataBuilder {
     }
 
     /// Creates a new ParquetMetaData from the builder
+    ///
+    /// See [`Self::try_build`] to also validate the metadata
     pub fn build(self) -> ParquetMetaData {
         let Self(metadata) = self;
         metadata
     }
+
+    /// Creates a new ParquetMetaData from the builder, returning an error if
+    /// the metadata is not internally consistent
+    ///
+    /// This is useful when fabricating metadata, for example in tests, and checks that
+    ///
+    /// * every row group has the schema of the file and a column chunk per column
+    /// * the row counts are non-negative and sum to [`FileMetaData::num_rows`]
+    /// * the ordinals and file offsets of the row groups, where set, are
+    ///   strictly increasing
+    /// * the dictionary page of each column chunk precedes its first data page
+    /// * the column and offset indexes, if set, have an entry per column chunk
+    /// * the pages of each offset index are in order, starting at the first
+    ///   row of the row group and consistent with the column index
+    pub fn try_build(self) -> Result<ParquetMetaData> {
+        let Self(metadata) = self;
+        let file_metadata = metadata.file_metadata();
+        let schema_descr = file_metadata.schema_descr();
+
+        let mut num_rows = 0_i64;
+        let mut previous: Option<&RowGroupMetaData> = None;
+        for (idx, row_group) in metadata.row_groups().iter().enumerate() {
+            if row_group.schema_descr() != schema_descr {
+                return Err(general_err!(
+                    "Row group {} does not have the schema of the file",
+                    idx
+                ));
+            }
+            if row_group.num_columns() != schema_descr.num_columns() {
+                return Err(general_err!(
+                    "Row group {} has {} columns, expected {}",
+                    idx,
+                    row_group.num_columns(),
+                    schema_descr.num_columns()
+                ));
+            }
+            if row_group.num_rows() < 0 {
+                return Err(general_err!(
+                    "Row group {} has a negative number of rows {}",
+                    idx,
+                    row_group.num_rows()
+                ));
+            }
+            num_rows += row_group.num_rows();
+
+            if let Some(previous) = previous {
+                if let (Some(a), Some(b)) = (previous.ordinal(), row_group.ordinal()) {
+                    if a >= b {
+                        return Err(general_err!(
+                            "Row group {} has ordinal {} not greater than the preceding {}",
+                            idx,
+                            b,
+                            a
+                        ));
+                    }
+                }
+                if let (Some(a), Some(b)) = (previous.file_offset(), row_group.file_offset()) {
+                    if a >= b {
+                        return Err(general_err!(
+                            "Row group {} has file offset {} not greater than the preceding {}",
+                            idx,
+                            b,
+                            a
+                        ));
+                    }
+                }
+            }
+            previous = Some(row_group);
+
+            for (col_idx, column) in row_group.try_columns()?.iter().enumerate() {
+                let data_page_offset = column.data_page_offset();
+                if data_page_offset < 0
+                    || column
+                        .dictionary_page_offset()
+                        .is_some_and(|dictionary| dictionary < 0 || dictionary >= data_page_offset)
+                {
+                    return Err(general_err!(
+                        "Column {} of row group {} has invalid page offsets",
+                        col_idx,
+                        idx
+                    ));
+                }
+            }
+        }
+        if num_rows != file_metadata.num_rows() {
+            return Err(general_err!(
+                "Row groups contain {} rows, but the file metadata has {}",
+                num_rows,
+                file_metadata.num_rows()
+            ));
+        }
+
+        let check_shape = |name: &str, lens: Vec<usize>| {
+            if lens.len() != metadata.num_row_groups() {
+                return Err(general_err!(
+                    "The {} has {} row groups, expected {}",
+                    name,
+                    lens.len(),
+                    metadata.num_row_groups()
+                ));
+            }
+            match lens
+                .iter()
+                .position(|len| *len != schema_descr.num_columns())
+            {
+                Some(idx) => Err(general_err!(
+                    "The {} of row group {} has {} columns, expected {}",
+                    name,
+                    idx,
+                    lens[idx],
+                    schema_descr.num_columns()
+                )),
+                None => Ok(()),
+            }
+        };
+        if let Some(column_index) = metadata.column_index() {
+            check_shape("column index", column_index.iter().map(Vec::len).collect())?;
+        }
+        if let Some(offset_index) = metadata.offset_index() {
+            check_shape("offset index", offset_index.iter().map(Vec::len).collect())?;
+
+            for (idx, (row_group, columns)) in
+                metadata.row_groups().iter().zip(offset_index).enumerate()
+            {
+                for (col_idx, offset_index) in columns.iter().enumerate() {
+                    let pages = offset_index.page_locations();
+                    let ordered = pages.first().is_none_or(|page| page.first_row_index == 0)
+                        && pages.iter().all(|page| {
+                            page.first_row_index < row_group.num_rows()
+                                && page.offset >= 0
+                                && page.compressed_page_size >= 0
+                        })
+                        && pages.windows(2).all(|w| {
+                            w[0].first_row_index < w[1].first_row_index && w[0].offset < w[1].offset
+                        });
+                    if !ordered {
+                        return Err(general_err!(
+                            "The offset index of column {} of row group {} has invalid page locations",
+                            col_idx,
+                            idx
+                        ));
+                    }
+
+                    let column_index = metadata.column_index().map(|c| &c[idx][col_idx]);
+                    if let Some(column_index) =
+                        column_index.filter(|c| !matches!(c, ColumnIndexMetaData::NONE))
+                    {
+                        if column_index.num_pages() != pages.len() as u64 {
+                            return Err(general_err!(
+                                "The column index of column {} of row group {} has {} pages, but the offset index has {}",
+                                col_idx,
+                                idx,
+                                column_index.num_pages(),
+                                pages.len()
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(metadata)
+    }
 }
 
 impl From<ParquetMetaData> for ParquetMetaDataBuilder {
@@ -617,9 +870,15 @@ pub type RowGroupMetaDataPtr = Arc<RowGroupMetaData>;
 ///
 /// Includes [`ColumnChunkMetaData`] for each column in the row group, the number of rows
 /// the total byte size of the row group, and the [`SchemaDescriptor`] for the row group.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// If the metadata was read with [`ParquetMetaDataReader::with_lazy_column_metadata`],
+/// the [`ColumnChunkMetaData`] of each column is decoded on first access.
+#[derive(Debug, Clone)]
 pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
+    /// The column chunk metadata if it is decoded lazily, in which case
+    /// `columns` is empty
+    lazy_columns: Option<Arc<LazyColumnChunks>>,
     num_rows: i64,
     sorting_columns: Option<Vec<SortingColumn>>,
     total_byte_size: i64,
@@ -638,22 +897,98 @@ impl RowGroupMetaData {
 
     /// Number of columns in this row group.
     pub fn num_columns(&self) -> usize {
-        self.columns.len()
+        match &self.lazy_columns {
+            Some(lazy) => lazy.len(),
+            None => self.columns.len(),
+        }
     }
 
     /// Returns column chunk metadata for `i`th column.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds, or if the metadata is decoded lazily and is
+    /// invalid. See [`Self::try_column`] for a fallible version.
     pub fn column(&self, i: usize) -> &ColumnChunkMetaData {
-        &self.columns[i]
+        self.try_column(i).unwrap()
+    }
+
+    /// Returns column chunk metadata for `i`th column, returning an error if
+    /// it is decoded lazily and is invalid.
+    ///
+    /// See [`ParquetMetaDataReader::with_lazy_column_metadata`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds
+    pub fn try_column(&self, i: usize) -> Result<&ColumnChunkMetaData> {
+        match &self.lazy_columns {
+            Some(lazy) => lazy.column(i, &self.schema_descr),
+            None => Ok(&self.columns[i]),
+        }
     }
 
     /// Returns slice of column chunk metadata.
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    ///
+    /// # Panics
+    ///
+    /// If the metadata is decoded lazily and is invalid. See [`Self::try_columns`]
+    /// for a fallible version.
     pub fn columns(&self) -> &[ColumnChunkMetaData] {
-        &self.columns
+        self.try_columns().unwrap()
+    }
+
+    /// Returns slice of column chunk metadata, returning an error if it is
+    /// decoded lazily and is invalid.
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    pub fn try_columns(&self) -> Result<&[ColumnChunkMetaData]> {
+        match &self.lazy_columns {
+            Some(lazy) => lazy.columns(&self.schema_descr),
+            None => Ok(&self.columns),
+        }
     }
 
     /// Returns mutable slice of column chunk metadata.
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    ///
+    /// # Panics
+    ///
+    /// If the metadata is decoded lazily and is invalid. See [`Self::try_columns_mut`]
+    /// for a fallible version.
     pub fn columns_mut(&mut self) -> &mut [ColumnChunkMetaData] {
-        &mut self.columns
+        self.try_columns_mut().unwrap()
+    }
+
+    /// Returns mutable slice of column chunk metadata, returning an error if it
+    /// is decoded lazily and is invalid.
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    pub fn try_columns_mut(&mut self) -> Result<&mut [ColumnChunkMetaData]> {
+        Ok(self.decoded_columns_mut()?)
+    }
+
+    /// Decodes the metadata of all columns if it is decoded lazily, returning
+    /// the decoded metadata
+    pub(crate) fn decoded_columns_mut(&mut self) -> Result<&mut Vec<ColumnChunkMetaData>> {
+        if let Some(lazy) = &self.lazy_columns {
+            let columns = lazy.columns(&self.schema_descr)?.to_vec();
+            self.columns = columns;
+            self.lazy_columns = None;
+        }
+        Ok(&mut self.columns)
+    }
+
+    /// Returns the number of columns whose metadata has been decoded
+    #[cfg(test)]
+    pub(crate) fn num_decoded_columns(&self) -> usize {
+        match &self.lazy_columns {
+            Some(lazy) => lazy.num_decoded(),
+            None => self.columns.len(),
+        }
     }
 
     /// Number of rows in this row group.
@@ -672,8 +1007,14 @@ impl RowGroupMetaData {
     }
 
     /// Total size of all compressed column data in this row group.
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    ///
+    /// # Panics
+    ///
+    /// If the metadata is decoded lazily and is invalid.
     pub fn compressed_size(&self) -> i64 {
-        self.columns.iter().map(|c| c.total_compressed_size).sum()
+        self.columns().iter().map(|c| c.total_compressed_size).sum()
     }
 
     /// Returns reference to a schema descriptor.
@@ -702,11 +1043,31 @@ impl RowGroupMetaData {
     }
 
     /// Converts this [`RowGroupMetaData`] into a [`RowGroupMetaDataBuilder`]
-    pub fn into_builder(self) -> RowGroupMetaDataBuilder {
+    ///
+    /// If the metadata is decoded lazily, this decodes the metadata of all columns.
+    /// If it is invalid, the error is returned by [`RowGroupMetaDataBuilder::build`].
+    pub fn into_builder(mut self) -> RowGroupMetaDataBuilder {
+        // the lazy metadata is kept on error, to be decoded again by `build`
+        let _ = self.decoded_columns_mut();
         RowGroupMetaDataBuilder(self)
     }
 }
 
+impl PartialEq for RowGroupMetaData {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_rows == other.num_rows
+            && self.sorting_columns == other.sorting_columns
+            && self.total_byte_size == other.total_byte_size
+            && self.schema_descr == other.schema_descr
+            && self.file_offset == other.file_offset
+            && self.ordinal == other.ordinal
+            && match (self.try_columns(), other.try_columns()) {
+                (Ok(a), Ok(b)) => a == b,
+                _ => false,
+            }
+    }
+}
+
 /// Builder for row group metadata.
 pub struct RowGroupMetaDataBuilder(RowGroupMetaData);
 
@@ -715,6 +1076,7 @@ impl RowGroupMetaDataBuilder {
     fn new(schema_descr: SchemaDescPtr) -> Self {
         Self(RowGroupMetaData {
             columns: Vec::with_capacity(schema_descr.num_columns()),
+            lazy_columns: None,
             schema_descr,
             file_offset: None,
             num_rows: 0,
@@ -776,7 +1138,8 @@ impl RowGroupMetaDataBuilder {
     }
 
     /// Builds row group metadata.
-    pub fn build(self) -> Result<RowGroupMetaData> {
+    pub fn build(mut self) -> Result<RowGroupMetaData> {
+        self.0.decoded_columns_mut()?;
         if self.0.schema_descr.num_columns() != self.0.columns.len() {
             return Err(general_err!(
                 "Column length mismatch: {} != {}",
@@ -1048,12 +1411,39 @@ impl ColumnChunkMetaData {
         self.geo_statistics.as_deref()
     }
 
-    /// Returns the offset for the page encoding stats,
+    /// Returns the number of pages of each page type and encoding in this column chunk,
     /// or `None` if no page encoding stats are available.
     pub fn page_encoding_stats(&self) -> Option<&Vec<PageEncodingStats>> {
         self.encoding_stats.as_ref()
     }
 
+    /// Returns `true` if every data page of this column chunk is dictionary encoded,
+    /// i.e. the writer never fell back to a non-dictionary encoding.
+    ///
+    /// This is derived from [`Self::page_encoding_stats`], and returns `false` if
+    /// they are not available or the chunk contains no dictionary page.
+    pub fn dictionary_encoded_fully(&self) -> bool {
+        let Some(stats) = self.encoding_stats.as_ref() else {
+            return false;
+        };
+        let mut has_dictionary_page = false;
+        for s in stats {
+            match s.page_type {
+                PageType::DICTIONARY_PAGE => has_dictionary_page = true,
+                PageType::DATA_PAGE | PageType::DATA_PAGE_V2 => {
+                    if !matches!(
+                        s.encoding,
+                        Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+                    ) {
+                        return false;
+                    }
+                }
+                _ => {}
+            }
+        }
+        has_dictionary_page
+    }
+
     /// Returns the offset for the bloom filter.
     pub fn bloom_filter_offset(&self) -> Option<i64> {
         self.bloom_filter_offset
@@ -1607,7 +1997,8 @@ impl OffsetIndexBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::basic::{PageType, SortOrder};
+    use crate::basic::{LogicalType, PageType, SortOrder};
+    use crate::data_type::ByteArray;
     use crate::file::metadata::thrift::tests::{read_column_chunk, read_row_group};
 
     #[test]
@@ -1722,6 +2113,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_chunk_dictionary_encoded_fully() {
+        let column_descr = get_test_schema_descr().column(0);
+        let with_stats = |stats: Vec<(PageType, Encoding)>| {
+            let stats = stats
+                .into_iter()
+                .map(|(page_type, encoding)| PageEncodingStats {
+                    page_type,
+                    encoding,
+                    count: 1,
+                })
+                .collect();
+            ColumnChunkMetaData::builder(column_descr.clone())
+                .set_page_encoding_stats(stats)
+                .build()
+                .unwrap()
+        };
+
+        let no_stats = ColumnChunkMetaData::builder(column_descr.clone())
+            .build()
+            .unwrap();
+        assert!(!no_stats.dictionary_encoded_fully());
+
+        let full = with_stats(vec![
+            (PageType::DICTIONARY_PAGE, Encoding::PLAIN),
+            (PageType::DATA_PAGE, Encoding::RLE_DICTIONARY),
+        ]);
+        assert!(full.dictionary_encoded_fully());
+
+        let fallback = with_stats(vec![
+            (PageType::DICTIONARY_PAGE, Encoding::PLAIN),
+            (PageType::DATA_PAGE_V2, Encoding::RLE_DICTIONARY),
+            (PageType::DATA_PAGE_V2, Encoding::DELTA_BINARY_PACKED),
+        ]);
+        assert!(!fallback.dictionary_encoded_fully());
+
+        let plain = with_stats(vec![(PageType::DATA_PAGE, Encoding::PLAIN)]);
+        assert!(!plain.dictionary_encoded_fully());
+    }
+
     #[test]
     fn test_column_chunk_metadata_thrift_conversion() {
         let column_descr = get_test_schema_descr().column(0);
@@ -1880,9 +2311,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let base_expected_size = 2766;
+        let base_expected_size = 2774;
         #[cfg(feature = "encryption")]
-        let base_expected_size = 2934;
+        let base_expected_size = 2942;
 
         assert_eq!(parquet_meta.memory_size(), base_expected_size);
 
@@ -1911,15 +2342,119 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let bigger_expected_size = 3192;
+        let bigger_expected_size = 3200;
         #[cfg(feature = "encryption")]
-        let bigger_expected_size = 3360;
+        let bigger_expected_size = 3368;
 
         // more set fields means more memory usage
         assert!(bigger_expected_size > base_expected_size);
         assert_eq!(parquet_meta.memory_size(), bigger_expected_size);
     }
 
+    #[test]
+    fn test_memory_size_large_statistics() {
+        let schema = SchemaType::group_type_builder("schema")
+            .with_fields(vec![Arc::new(
+                SchemaType::primitive_type_builder("s", Type::BYTE_ARRAY)
+                    .build()
+                    .unwrap(),
+            )])
+            .build()
+            .unwrap();
+        let schema_descr = Arc::new(SchemaDescriptor::new(Arc::new(schema)));
+
+        // metadata with statistics and 4 pages of page index with values of `len` bytes
+        let make_metadata = |len: usize| {
+            let column = ColumnChunkMetaData::builder(schema_descr.column(0))
+                .set_statistics(Statistics::byte_array(
+                    Some(ByteArray::from(vec![b'a'; len])),
+                    Some(ByteArray::from(vec![b'z'; len])),
+                    None,
+                    None,
+                    false,
+                ))
+                .build()
+                .unwrap();
+            let row_group = RowGroupMetaData::builder(schema_descr.clone())
+                .set_num_rows(4)
+                .set_column_metadata(vec![column])
+                .build()
+                .unwrap();
+
+            let mut column_index = ColumnIndexBuilder::new(Type::BYTE_ARRAY);
+            let mut offset_index = OffsetIndexBuilder::new();
+            for page in 0..4 {
+                column_index.append(false, vec![b'a'; len], vec![b'z'; len], 0);
+                offset_index.append_row_count(1);
+                offset_index.append_offset_and_size(page * 10, 10);
+            }
+
+            let file_metadata = FileMetaData::new(1, 4, None, None, schema_descr.clone(), None);
+            ParquetMetaDataBuilder::new(file_metadata)
+                .set_row_groups(vec![row_group])
+                .set_column_index(Some(vec![vec![column_index.build().unwrap()]]))
+                .set_offset_index(Some(vec![vec![offset_index.build()]]))
+                .build()
+        };
+
+        let small = make_metadata(1).memory_size();
+        let large = make_metadata(100_000).memory_size();
+
+        // 2 statistics values and 8 page index values per column chunk
+        let expected_growth = 10 * (100_000 - 1);
+        let growth = large - small;
+        assert!(growth >= expected_growth, "{growth} < {expected_growth}");
+        assert!(
+            growth <= 2 * expected_growth,
+            "{growth} > 2 * {expected_growth}"
+        );
+    }
+
+    #[test]
+    fn test_memory_size_shared_schema() {
+        let crs = "x".repeat(10_000);
+        let schema = SchemaType::group_type_builder("schema")
+            .with_fields(vec![Arc::new(
+                SchemaType::primitive_type_builder("geo", Type::BYTE_ARRAY)
+                    .with_logical_type(Some(LogicalType::Geometry {
+                        crs: Some(crs.clone()),
+                    }))
+                    .build()
+                    .unwrap(),
+            )])
+            .build()
+            .unwrap();
+        let schema = Arc::new(schema);
+        let schema_descr = Arc::new(SchemaDescriptor::new(schema.clone()));
+        // the CRS string is counted as part of the schema
+        assert!(schema_descr.heap_size() >= crs.len());
+
+        let make_metadata = |row_group_schema_descr: SchemaDescPtr| {
+            let column = ColumnChunkMetaData::builder(row_group_schema_descr.column(0))
+                .build()
+                .unwrap();
+            let row_group = RowGroupMetaData::builder(row_group_schema_descr)
+                .set_num_rows(1)
+                .set_column_metadata(vec![column])
+                .build()
+                .unwrap();
+            let file_metadata = FileMetaData::new(1, 1, None, None, schema_descr.clone(), None);
+            ParquetMetaDataBuilder::new(file_metadata)
+                .set_row_groups(vec![row_group.clone(), row_group])
+                .build()
+        };
+
+        // the schema shared with the row groups is counted once
+        let shared = make_metadata(schema_descr.clone()).memory_size();
+        assert!(shared >= crs.len());
+        assert!(shared < 2 * crs.len());
+
+        // a distinct schema used by the row groups is counted once as well
+        let other_schema_descr = Arc::new(SchemaDescriptor::new(schema));
+        let unshared = make_metadata(other_schema_descr.clone()).memory_size();
+        assert_eq!(unshared, shared + other_schema_descr.heap_size());
+    }
+
     #[test]
     #[cfg(feature = "encryption")]
     fn test_memory_size_with_decryptor() {
@@ -1960,7 +2495,7 @@ mod tests {
             .set_row_groups(row_group_meta.clone())
             .build();
 
-        let base_expected_size = 2058;
+        let base_expected_size = 2066;
         assert_eq!(parquet_meta_data.memory_size(), base_expected_size);
 
         let footer_key = "0123456789012345".as_bytes();
@@ -1986,7 +2521,7 @@ mod tests {
             .set_file_decryptor(Some(decryptor))
             .build();
 
-        let expected_size_with_decryptor = 3072;
+        let expected_size_with_decryptor = 3080;
         assert!(expected_size_with_decryptor > base_expected_size);
 
         assert_eq!(
@@ -1995,6 +2530,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parquet_metadata_builder_try_build() {
+        let schema_descr = get_test_schema_descr();
+        let row_group = |num_rows: i64, offset: i64| {
+            let columns = schema_descr
+                .columns()
+                .iter()
+                .enumerate()
+                .map(|(idx, column)| {
+                    ColumnChunkMetaData::builder(column.clone())
+                        .set_data_page_offset(offset + 100 * idx as i64)
+                        .build()
+                        .unwrap()
+                })
+                .collect();
+            RowGroupMetaData::builder(schema_descr.clone())
+                .set_num_rows(num_rows)
+                .set_column_metadata(columns)
+                .set_file_offset(offset)
+                .build()
+                .unwrap()
+        };
+        let file_metadata =
+            |num_rows: i64| FileMetaData::new(2, num_rows, None, None, schema_descr.clone(), None);
+        let offset_index = |first_rows: &[i64]| {
+            let page_locations = first_rows
+                .iter()
+                .enumerate()
+                .map(|(idx, first_row)| PageLocation {
+                    offset: 4 + 10 * idx as i64,
+                    compressed_page_size: 10,
+                    first_row_index: *first_row,
+                })
+                .collect();
+            let index = OffsetIndexMetaData {
+                page_locations,
+                unencoded_byte_array_data_bytes: None,
+            };
+            vec![index.clone(), index]
+        };
+
+        let metadata = ParquetMetaDataBuilder::new(file_metadata(30))
+            .add_row_group(row_group(10, 4))
+            .add_row_group(row_group(20, 1000))
+            .set_offset_index(Some(vec![offset_index(&[0, 5]), offset_index(&[0])]))
+            .try_build()
+            .unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        let err = |builder: ParquetMetaDataBuilder| builder.try_build().unwrap_err().to_string();
+
+        let builder = ParquetMetaDataBuilder::new(file_metadata(31))
+            .add_row_group(row_group(10, 4))
+            .add_row_group(row_group(20, 1000));
+        assert_eq!(
+            err(builder),
+            "Parquet error: Row groups contain 30 rows, but the file metadata has 31"
+        );
+
+        let builder = ParquetMetaDataBuilder::new(file_metadata(30))
+            .add_row_group(row_group(10, 1000))
+            .add_row_group(row_group(20, 4));
+        assert_eq!(
+            err(builder),
+            "Parquet error: Row group 1 has file offset 4 not greater than the preceding 1000"
+        );
+
+        let builder = ParquetMetaDataBuilder::new(file_metadata(20))
+            .add_row_group(
+                row_group(10, 4)
+                    .into_builder()
+                    .set_ordinal(1)
+                    .build()
+                    .unwrap(),
+            )
+            .add_row_group(
+                row_group(10, 100)
+                    .into_builder()
+                    .set_ordinal(1)
+                    .build()
+                    .unwrap(),
+            );
+        assert_eq!(
+            err(builder),
+            "Parquet error: Row group 1 has ordinal 1 not greater than the preceding 1"
+        );
+
+        let mut invalid = row_group(10, 4).into_builder();
+        let mut columns = invalid.take_columns();
+        columns[1] = columns[1]
+            .clone()
+            .into_builder()
+            .set_dictionary_page_offset(Some(200))
+            .build()
+            .unwrap();
+        let builder = ParquetMetaDataBuilder::new(file_metadata(10))
+            .add_row_group(invalid.set_column_metadata(columns).build().unwrap());
+        assert_eq!(
+            err(builder),
+            "Parquet error: Column 1 of row group 0 has invalid page offsets"
+        );
+
+        let builder = ParquetMetaDataBuilder::new(file_metadata(10))
+            .add_row_group(row_group(10, 4))
+            .set_offset_index(Some(vec![offset_index(&[0]), offset_index(&[0])]));
+        assert_eq!(
+            err(builder),
+            "Parquet error: The offset index has 2 row groups, expected 1"
+        );
+
+        for first_rows in [&[1, 5][..], &[0, 5, 5], &[0, 10]] {
+            let builder = ParquetMetaDataBuilder::new(file_metadata(10))
+                .add_row_group(row_group(10, 4))
+                .set_offset_index(Some(vec![offset_index(first_rows)]));
+            assert_eq!(
+                err(builder),
+                "Parquet error: The offset index of column 0 of row group 0 has invalid page locations"
+            );
+        }
+
+        let mut column_index = ColumnIndexBuilder::new(Type::INT32);
+        column_index.append(
+            false,
+            1_i32.to_le_bytes().to_vec(),
+            2_i32.to_le_bytes().to_vec(),
+            0,
+        );
+        let column_index = column_index.build().unwrap();
+        let builder = ParquetMetaDataBuilder::new(file_metadata(10))
+            .add_row_group(row_group(10, 4))
+            .set_column_index(Some(vec![vec![column_index, ColumnIndexMetaData::NONE]]))
+            .set_offset_index(Some(vec![offset_index(&[0, 5])]));
+        assert_eq!(
+            err(builder),
+            "Parquet error: The column index of column 0 of row group 0 has 1 pages, but the offset index has 2"
+        );
+    }
+
     /// Returns sample schema descriptor so we can create column metadata.
     fn get_test_schema_descr() -> SchemaDescPtr {
         let schema = SchemaType::group_type_builder("schema")
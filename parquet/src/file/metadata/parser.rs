@@ -21,13 +21,20 @@
 //! into the corresponding Rust structures
 
 use crate::errors::ParquetError;
-use crate::file::metadata::thrift::parquet_metadata_from_bytes;
+use crate::file::metadata::thrift::{
+    parquet_metadata_from_bytes, parquet_metadata_from_bytes_lazy,
+};
 use crate::file::metadata::{ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData};
 
 use crate::file::page_index::column_index::ColumnIndexMetaData;
+#[cfg(feature = "arrow")]
+use crate::file::page_index::index_reader::acc_range;
 use crate::file::page_index::index_reader::{decode_column_index, decode_offset_index};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::reader::ChunkReader;
+use crate::util::push_buffers::PushBuffers;
 use bytes::Bytes;
+use std::ops::Range;
 
 /// Helper struct for metadata parsing
 ///
@@ -51,6 +58,8 @@ mod inner {
     pub(crate) struct MetadataParser {
         // the credentials and keys needed to decrypt metadata
         file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
+        // decode the column chunk metadata on first access
+        lazy_column_metadata: bool,
     }
 
     impl MetadataParser {
@@ -58,6 +67,13 @@ mod inner {
             MetadataParser::default()
         }
 
+        /// Decode the column chunk metadata on first access, unless the
+        /// metadata is encrypted
+        pub(crate) fn with_lazy_column_metadata(mut self, lazy_column_metadata: bool) -> Self {
+            self.lazy_column_metadata = lazy_column_metadata;
+            self
+        }
+
         pub(crate) fn with_file_decryption_properties(
             mut self,
             file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
@@ -68,7 +84,7 @@ mod inner {
 
         pub(crate) fn decode_metadata(
             &self,
-            buf: &[u8],
+            buf: &Bytes,
             encrypted_footer: bool,
         ) -> Result<ParquetMetaData> {
             if encrypted_footer || self.file_decryption_properties.is_some() {
@@ -77,6 +93,8 @@ mod inner {
                     encrypted_footer,
                     buf,
                 )
+            } else if self.lazy_column_metadata {
+                parquet_metadata_from_bytes_lazy(buf)
             } else {
                 decode_metadata(buf)
             }
@@ -148,22 +166,33 @@ mod inner {
     ///
     /// This has the same API as the encryption-enabled version
     #[derive(Debug, Default)]
-    pub(crate) struct MetadataParser;
+    pub(crate) struct MetadataParser {
+        // decode the column chunk metadata on first access
+        lazy_column_metadata: bool,
+    }
 
     impl MetadataParser {
         pub(crate) fn new() -> Self {
-            MetadataParser
+            MetadataParser::default()
+        }
+
+        /// Decode the column chunk metadata on first access
+        pub(crate) fn with_lazy_column_metadata(mut self, lazy_column_metadata: bool) -> Self {
+            self.lazy_column_metadata = lazy_column_metadata;
+            self
         }
 
         pub(crate) fn decode_metadata(
             &self,
-            buf: &[u8],
+            buf: &Bytes,
             encrypted_footer: bool,
         ) -> Result<ParquetMetaData> {
             if encrypted_footer {
                 Err(general_err!(
                     "Parquet file has an encrypted footer but the encryption feature is disabled"
                 ))
+            } else if self.lazy_column_metadata {
+                parquet_metadata_from_bytes_lazy(buf)
             } else {
                 decode_metadata(buf)
             }
@@ -202,19 +231,20 @@ pub(crate) fn decode_metadata(buf: &[u8]) -> crate::errors::Result<ParquetMetaDa
     parquet_metadata_from_bytes(buf)
 }
 
-/// Parses column index from the provided bytes and adds it to the metadata.
+/// Parses column index from the provided buffers and adds it to the metadata.
 ///
 /// Arguments
 /// * `metadata` - The ParquetMetaData to which the parsed column index will be added.
 /// * `column_index_policy` - The policy for handling column index parsing (e.g.,
 ///   Required, Optional, Skip).
-/// * `bytes` - The byte slice containing the column index data.
-/// * `start_offset` - The offset where `bytes` begin in the file.
+/// * `columns` - The leaf columns to parse the column index for, or `None` for all
+///   columns. The column index of other columns is set to [`ColumnIndexMetaData::NONE`].
+/// * `buffers` - The buffers containing the column index data.
 pub(crate) fn parse_column_index(
     metadata: &mut ParquetMetaData,
     column_index_policy: PageIndexPolicy,
-    bytes: &Bytes,
-    start_offset: u64,
+    columns: Option<&[bool]>,
+    buffers: &PushBuffers,
 ) -> crate::errors::Result<()> {
     if column_index_policy == PageIndexPolicy::Skip {
         return Ok(());
@@ -224,22 +254,19 @@ pub(crate) fn parse_column_index(
         .iter()
         .enumerate()
         .map(|(rg_idx, x)| {
-            x.columns()
-                .iter()
-                .enumerate()
-                .map(|(col_idx, c)| match c.column_index_range() {
-                    Some(r) => {
-                        let r_start = usize::try_from(r.start - start_offset)?;
-                        let r_end = usize::try_from(r.end - start_offset)?;
-                        inner::parse_single_column_index(
-                            &bytes[r_start..r_end],
-                            metadata,
-                            c,
-                            rg_idx,
-                            col_idx,
-                        )
+            (0..x.num_columns())
+                .map(|col_idx| {
+                    if columns.is_some_and(|columns| !columns[col_idx]) {
+                        return Ok(ColumnIndexMetaData::NONE);
+                    }
+                    let c = x.try_column(col_idx)?;
+                    match c.column_index_range() {
+                        Some(r) => {
+                            let bytes = get_range(buffers, r)?;
+                            inner::parse_single_column_index(&bytes, metadata, c, rg_idx, col_idx)
+                        }
+                        None => Ok(ColumnIndexMetaData::NONE),
                     }
-                    None => Ok(ColumnIndexMetaData::NONE),
                 })
                 .collect::<crate::errors::Result<Vec<_>>>()
         })
@@ -249,11 +276,17 @@ pub(crate) fn parse_column_index(
     Ok(())
 }
 
+/// Parses offset index from the provided buffers and adds it to the metadata.
+///
+/// The offset index of columns not included in `columns` is left empty, i.e. it
+/// contains no [`PageLocation`]s. See [`parse_column_index`] for the other arguments.
+///
+/// [`PageLocation`]: crate::file::page_index::offset_index::PageLocation
 pub(crate) fn parse_offset_index(
     metadata: &mut ParquetMetaData,
     offset_index_policy: PageIndexPolicy,
-    bytes: &Bytes,
-    start_offset: u64,
+    columns: Option<&[bool]>,
+    buffers: &PushBuffers,
 ) -> crate::errors::Result<()> {
     if offset_index_policy == PageIndexPolicy::Skip {
         return Ok(());
@@ -261,20 +294,20 @@ pub(crate) fn parse_offset_index(
     let row_groups = metadata.row_groups();
     let mut all_indexes = Vec::with_capacity(row_groups.len());
     for (rg_idx, x) in row_groups.iter().enumerate() {
-        let mut row_group_indexes = Vec::with_capacity(x.columns().len());
-        for (col_idx, c) in x.columns().iter().enumerate() {
+        let mut row_group_indexes = Vec::with_capacity(x.num_columns());
+        for col_idx in 0..x.num_columns() {
+            if columns.is_some_and(|columns| !columns[col_idx]) {
+                row_group_indexes.push(OffsetIndexMetaData {
+                    page_locations: vec![],
+                    unencoded_byte_array_data_bytes: None,
+                });
+                continue;
+            }
+            let c = x.try_column(col_idx)?;
             let result = match c.offset_index_range() {
-                Some(r) => {
-                    let r_start = usize::try_from(r.start - start_offset)?;
-                    let r_end = usize::try_from(r.end - start_offset)?;
-                    inner::parse_single_offset_index(
-                        &bytes[r_start..r_end],
-                        metadata,
-                        c,
-                        rg_idx,
-                        col_idx,
-                    )
-                }
+                Some(r) => get_range(buffers, r).and_then(|bytes| {
+                    inner::parse_single_offset_index(&bytes, metadata, c, rg_idx, col_idx)
+                }),
                 None => Err(general_err!("missing offset index")),
             };
 
@@ -297,3 +330,65 @@ pub(crate) fn parse_offset_index(
     metadata.set_offset_index(Some(all_indexes));
     Ok(())
 }
+
+/// Returns the bytes of `range` from `buffers`
+fn get_range(buffers: &PushBuffers, range: Range<u64>) -> crate::errors::Result<Bytes> {
+    buffers.get_bytes(range.start, usize::try_from(range.end - range.start)?)
+}
+
+/// Returns the byte range in the file spanning the offset indexes of the
+/// columns in row group `row_group_idx`, if any
+#[cfg(feature = "arrow")]
+pub(crate) fn row_group_offset_index_range(
+    metadata: &ParquetMetaData,
+    row_group_idx: usize,
+) -> crate::errors::Result<Option<Range<u64>>> {
+    let columns = metadata.row_group(row_group_idx).try_columns()?;
+    Ok(columns
+        .iter()
+        .fold(None, |range, c| acc_range(range, c.offset_index_range())))
+}
+
+/// Parses the offset indexes of the columns in row group `row_group_idx` from
+/// the provided bytes, which begin at `start_offset` in the file
+///
+/// Returns `None` if the offset index is missing or invalid and
+/// `offset_index_policy` is not [`PageIndexPolicy::Required`].
+#[cfg(feature = "arrow")]
+pub(crate) fn parse_row_group_offset_index(
+    metadata: &ParquetMetaData,
+    offset_index_policy: PageIndexPolicy,
+    row_group_idx: usize,
+    bytes: &Bytes,
+    start_offset: u64,
+) -> crate::errors::Result<Option<Vec<OffsetIndexMetaData>>> {
+    if offset_index_policy == PageIndexPolicy::Skip {
+        return Ok(None);
+    }
+    let result = metadata
+        .row_group(row_group_idx)
+        .try_columns()?
+        .iter()
+        .enumerate()
+        .map(|(col_idx, c)| match c.offset_index_range() {
+            Some(r) => {
+                let r_start = usize::try_from(r.start - start_offset)?;
+                let r_end = usize::try_from(r.end - start_offset)?;
+                inner::parse_single_offset_index(
+                    &bytes[r_start..r_end],
+                    metadata,
+                    c,
+                    row_group_idx,
+                    col_idx,
+                )
+            }
+            None => Err(general_err!("missing offset index")),
+        })
+        .collect::<crate::errors::Result<Vec<_>>>();
+
+    match result {
+        Ok(index) => Ok(Some(index)),
+        Err(e) if offset_index_policy == PageIndexPolicy::Required => Err(e),
+        Err(_) => Ok(None),
+    }
+}
@@ -225,6 +225,8 @@ pub struct ParquetMetaDataPushDecoder {
     column_index_policy: PageIndexPolicy,
     /// policy for loading OffsetIndex (part of the PageIndex)
     offset_index_policy: PageIndexPolicy,
+    /// leaf columns to load the PageIndex for, or `None` for all columns
+    page_index_columns: Option<Vec<usize>>,
     /// Underlying buffers
     buffers: crate::util::push_buffers::PushBuffers,
     /// Encryption API
@@ -249,6 +251,7 @@ impl ParquetMetaDataPushDecoder {
             state: DecodeState::ReadingFooter,
             column_index_policy: PageIndexPolicy::Optional,
             offset_index_policy: PageIndexPolicy::Optional,
+            page_index_columns: None,
             buffers: crate::util::push_buffers::PushBuffers::new(file_len),
             metadata_parser: MetadataParser::new(),
         })
@@ -299,6 +302,35 @@ impl ParquetMetaDataPushDecoder {
         self
     }
 
+    /// Only read the PageIndex for the leaf columns with the given indices
+    /// (defaults to all columns).
+    ///
+    /// Only the byte ranges of the ColumnIndex and OffsetIndex of these columns
+    /// are requested and decoded, with ranges close to each other requested
+    /// together. The ColumnIndex of other columns is
+    /// [`ColumnIndexMetaData::NONE`], and their OffsetIndex contains no page
+    /// locations.
+    ///
+    /// [`ColumnIndexMetaData::NONE`]: crate::file::page_index::column_index::ColumnIndexMetaData::NONE
+    pub fn with_page_index_columns(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.page_index_columns = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// Decode the [`ColumnChunkMetaData`] of each column on first access
+    /// (defaults to `false`).
+    ///
+    /// See [`ParquetMetaDataReader::with_lazy_column_metadata`] for more details.
+    ///
+    /// [`ColumnChunkMetaData`]: crate::file::metadata::ColumnChunkMetaData
+    /// [`ParquetMetaDataReader::with_lazy_column_metadata`]: crate::file::metadata::ParquetMetaDataReader::with_lazy_column_metadata
+    pub fn with_lazy_column_metadata(mut self, lazy_column_metadata: bool) -> Self {
+        self.metadata_parser = self
+            .metadata_parser
+            .with_lazy_column_metadata(lazy_column_metadata);
+        self
+    }
+
     #[cfg(feature = "encryption")]
     /// Provide decryption properties for decoding encrypted Parquet files
     pub(crate) fn with_file_decryption_properties(
@@ -397,27 +429,54 @@ impl ParquetMetaDataPushDecoder {
 
                 DecodeState::ReadingPageIndex(mut metadata) => {
                     // First determine if any page indexes are needed based on
-                    // the specified policies
-                    let range = range_for_page_index(
-                        &metadata,
-                        self.column_index_policy,
-                        self.offset_index_policy,
-                    );
+                    // the specified policies and columns
+                    let columns = match &self.page_index_columns {
+                        Some(columns) => Some(page_index_mask(&metadata, columns)?),
+                        None => None,
+                    };
+                    let ranges = match &columns {
+                        Some(columns) => ranges_for_page_index_columns(
+                            &metadata,
+                            self.column_index_policy,
+                            self.offset_index_policy,
+                            columns,
+                        ),
+                        None => range_for_page_index(
+                            &metadata,
+                            self.column_index_policy,
+                            self.offset_index_policy,
+                        )
+                        .into_iter()
+                        .collect(),
+                    };
 
-                    let Some(page_index_range) = range else {
+                    if ranges.is_empty() {
                         self.state = DecodeState::Finished;
                         return Ok(DecodeResult::Data(*metadata));
-                    };
+                    }
 
-                    if !self.buffers.has_range(&page_index_range) {
+                    let missing: Vec<_> = ranges
+                        .into_iter()
+                        .filter(|range| !self.buffers.has_range(range))
+                        .collect();
+                    if !missing.is_empty() {
                         self.state = DecodeState::ReadingPageIndex(metadata);
-                        return Ok(needs_range(page_index_range));
+                        return Ok(DecodeResult::NeedsData(missing));
                     }
 
-                    let buffer = self.get_bytes(&page_index_range)?;
-                    let offset = page_index_range.start;
-                    parse_column_index(&mut metadata, self.column_index_policy, &buffer, offset)?;
-                    parse_offset_index(&mut metadata, self.offset_index_policy, &buffer, offset)?;
+                    let columns = columns.as_deref();
+                    parse_column_index(
+                        &mut metadata,
+                        self.column_index_policy,
+                        columns,
+                        &self.buffers,
+                    )?;
+                    parse_offset_index(
+                        &mut metadata,
+                        self.offset_index_policy,
+                        columns,
+                        &self.buffers,
+                    )?;
                     self.state = DecodeState::Finished;
                     return Ok(DecodeResult::Data(*metadata));
                 }
@@ -475,6 +534,10 @@ pub fn range_for_page_index(
     column_index_policy: PageIndexPolicy,
     offset_index_policy: PageIndexPolicy,
 ) -> Option<Range<u64>> {
+    if column_index_policy == PageIndexPolicy::Skip && offset_index_policy == PageIndexPolicy::Skip
+    {
+        return None;
+    }
     let mut range = None;
     for c in metadata.row_groups().iter().flat_map(|r| r.columns()) {
         if column_index_policy != PageIndexPolicy::Skip {
@@ -487,12 +550,78 @@ pub fn range_for_page_index(
     range
 }
 
+/// The maximum gap between the page index ranges of different columns that are
+/// requested as a single range, see [`ranges_for_page_index_columns`]
+const PAGE_INDEX_COALESCE_GAP: u64 = 64 * 1024;
+
+/// Returns the byte ranges needed to read the offset/page indexes of the leaf
+/// columns included in `columns`, based on the specified policies
+///
+/// Ranges separated by at most [`PAGE_INDEX_COALESCE_GAP`] bytes are coalesced,
+/// returns an empty `Vec` if no page indexes are needed
+fn ranges_for_page_index_columns(
+    metadata: &ParquetMetaData,
+    column_index_policy: PageIndexPolicy,
+    offset_index_policy: PageIndexPolicy,
+    columns: &[bool],
+) -> Vec<Range<u64>> {
+    let mut ranges: Vec<Range<u64>> = metadata
+        .row_groups()
+        .iter()
+        .flat_map(|r| {
+            (0..r.num_columns())
+                .filter(|idx| columns[*idx])
+                .map(|idx| r.column(idx))
+        })
+        .flat_map(|c| {
+            let column_index = (column_index_policy != PageIndexPolicy::Skip)
+                .then(|| c.column_index_range())
+                .flatten();
+            let offset_index = (offset_index_policy != PageIndexPolicy::Skip)
+                .then(|| c.offset_index_range())
+                .flatten();
+            column_index.into_iter().chain(offset_index)
+        })
+        .collect();
+    ranges.sort_unstable_by_key(|r| r.start);
+
+    let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(PAGE_INDEX_COALESCE_GAP) => {
+                last.end = last.end.max(range.end)
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// Returns a mask of the leaf columns of `metadata` included in `columns`
+fn page_index_mask(metadata: &ParquetMetaData, columns: &[usize]) -> Result<Vec<bool>> {
+    let num_columns = metadata.file_metadata().schema_descr().num_columns();
+    let mut mask = vec![false; num_columns];
+    for &idx in columns {
+        if idx >= num_columns {
+            return Err(general_err!(
+                "Invalid page index column {}, file has {} leaf columns",
+                idx,
+                num_columns
+            ));
+        }
+        mask[idx] = true;
+    }
+    Ok(mask)
+}
+
 // These tests use the arrow writer to create a parquet file in memory
 // so they need the arrow feature and the test feature
 #[cfg(all(test, feature = "arrow"))]
 mod tests {
     use super::*;
     use crate::arrow::ArrowWriter;
+    use crate::file::metadata::ParquetMetaDataReader;
+    use crate::file::page_index::column_index::ColumnIndexMetaData;
     use crate::file::properties::WriterProperties;
     use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringViewArray};
     use bytes::Bytes;
@@ -624,6 +753,69 @@ mod tests {
         assert!(metadata.offset_index().is_none()); // or the offset index
     }
 
+    /// Decode the metadata incrementally, only reading the page indexes of column "b"
+    #[test]
+    fn test_metadata_decoder_page_index_columns() {
+        let file_len = TEST_FILE_DATA.len() as u64;
+        let mut metadata_decoder = ParquetMetaDataPushDecoder::try_new(file_len)
+            .unwrap()
+            .with_page_index_columns([1]);
+        let ranges = expect_needs_data(metadata_decoder.try_decode());
+        push_ranges_to_metadata_decoder(&mut metadata_decoder, ranges);
+        let ranges = expect_needs_data(metadata_decoder.try_decode());
+        push_ranges_to_metadata_decoder(&mut metadata_decoder, ranges);
+
+        // expect the column and offset index ranges of column "b" to be requested
+        // as a single range, as they are close to each other
+        let ranges = expect_needs_data(metadata_decoder.try_decode());
+        let full_metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&*TEST_FILE_DATA)
+            .unwrap();
+        let needed: Vec<_> = full_metadata
+            .row_groups()
+            .iter()
+            .flat_map(|rg| {
+                let c = rg.column(1);
+                [c.column_index_range(), c.offset_index_range()]
+            })
+            .map(Option::unwrap)
+            .collect();
+        let start = needed.iter().map(|r| r.start).min().unwrap();
+        let end = needed.iter().map(|r| r.end).max().unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0], start..end);
+        push_ranges_to_metadata_decoder(&mut metadata_decoder, ranges);
+
+        let metadata = expect_data(metadata_decoder.try_decode());
+        expect_finished(metadata_decoder.try_decode());
+
+        let column_index = metadata.column_index().unwrap();
+        let offset_index = metadata.offset_index().unwrap();
+        assert_eq!(column_index.len(), 2);
+        assert_eq!(offset_index.len(), 2);
+        for (column_index, offset_index) in column_index.iter().zip(offset_index) {
+            assert!(matches!(column_index[0], ColumnIndexMetaData::NONE));
+            assert!(matches!(column_index[1], ColumnIndexMetaData::INT64(_)));
+            assert!(matches!(column_index[2], ColumnIndexMetaData::NONE));
+            assert!(offset_index[0].page_locations().is_empty());
+            assert_eq!(offset_index[1].page_locations().len(), 2);
+            assert!(offset_index[2].page_locations().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_metadata_decoder_invalid_page_index_columns() {
+        let mut metadata_decoder = ParquetMetaDataPushDecoder::try_new(test_file_len())
+            .unwrap()
+            .with_page_index_columns([3]);
+        push_ranges_to_metadata_decoder(&mut metadata_decoder, vec![test_file_range()]);
+        let err = metadata_decoder.try_decode().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid page index column 3, file has 3 leaf columns"
+        );
+    }
+
     static TEST_BATCH: LazyLock<RecordBatch> = LazyLock::new(|| {
         // Input batch has 400 rows, with 3 columns: "a", "b", "c"
         // Note c is a different types (so the data page sizes will be different)
@@ -29,6 +29,11 @@ use crate::DecodeResult;
 #[cfg(all(feature = "async", feature = "arrow"))]
 use crate::arrow::async_reader::{MetadataFetch, MetadataSuffixFetch};
 
+/// A default for [`ParquetMetaDataReader::with_prefetch_hint`] of 512 KiB, which
+/// is large enough for the footer and page indexes of most files to be read in
+/// a single request
+pub const DEFAULT_PREFETCH_HINT: usize = 512 * 1024;
+
 /// Reads [`ParquetMetaData`] from a byte stream, with either synchronous or
 /// asynchronous I/O.
 ///
@@ -67,7 +72,9 @@ pub struct ParquetMetaDataReader {
     metadata: Option<ParquetMetaData>,
     column_index: PageIndexPolicy,
     offset_index: PageIndexPolicy,
+    page_index_columns: Option<Vec<usize>>,
     prefetch_hint: Option<usize>,
+    lazy_column_metadata: bool,
     // Size of the serialized thrift metadata plus the 8 byte footer. Only set if
     // `self.parse_metadata` is called.
     metadata_size: Option<usize>,
@@ -158,6 +165,35 @@ impl ParquetMetaDataReader {
         self
     }
 
+    /// Only read the page indexes for the leaf columns with the given indices
+    /// (defaults to all columns).
+    ///
+    /// Only the byte ranges of the column and offset indexes of these columns are
+    /// read and decoded, which can significantly reduce the IO and CPU needed for
+    /// files with many columns. The column index of other columns is
+    /// [`ColumnIndexMetaData::NONE`], and their offset index contains no page locations.
+    ///
+    /// The resulting [`ParquetMetaData`] can be used with the arrow reader, which reads
+    /// columns without page locations without using the offset index.
+    ///
+    /// ```no_run
+    /// # use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+    /// # fn open_parquet_file(path: &str) -> std::fs::File { unimplemented!(); }
+    /// let file = open_parquet_file("some_path.parquet");
+    /// // only read the page indexes of the first and third leaf columns
+    /// let metadata = ParquetMetaDataReader::new()
+    ///     .with_page_index_policy(PageIndexPolicy::Required)
+    ///     .with_page_index_columns([0, 2])
+    ///     .parse_and_finish(&file)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`ColumnIndexMetaData::NONE`]: crate::file::page_index::column_index::ColumnIndexMetaData::NONE
+    pub fn with_page_index_columns(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.page_index_columns = Some(columns.into_iter().collect());
+        self
+    }
+
     /// Provide a hint as to the number of bytes needed to fully parse the [`ParquetMetaData`].
     /// Only used for the asynchronous [`Self::try_load()`] method.
     ///
@@ -169,11 +205,55 @@ impl ParquetMetaDataReader {
     /// to fully decode the [`ParquetMetaData`], which can reduce the number of fetch requests and
     /// reduce latency. Setting `prefetch` too small will not trigger an error, but will result
     /// in extra fetches being performed.
+    ///
+    /// If the prefetched bytes contain the footer metadata and page indexes, as
+    /// is typical for small files with [`DEFAULT_PREFETCH_HINT`], they are
+    /// decoded with a single fetch. Page indexes can only be decoded from the
+    /// prefetched bytes of [`Self::try_load_via_suffix()`] if the entire file
+    /// was fetched, as the file size is otherwise unknown.
     pub fn with_prefetch_hint(mut self, prefetch: Option<usize>) -> Self {
         self.prefetch_hint = prefetch;
         self
     }
 
+    /// Decode the [`ColumnChunkMetaData`] of each column on first access
+    /// (defaults to `false`).
+    ///
+    /// Decoding the footer of a file with many columns is dominated by decoding
+    /// the metadata of its column chunks. When enabled, the encoded metadata
+    /// of each column chunk is kept, and only decoded when it is first accessed
+    /// with [`RowGroupMetaData::column`] or [`RowGroupMetaData::try_column`].
+    /// This makes reading a few columns of a wide file much cheaper.
+    ///
+    /// Note that [`RowGroupMetaData::columns`] decodes the metadata of all
+    /// columns, and that the encoded metadata is only checked to be well formed
+    /// thrift before it is decoded. [`RowGroupMetaData::column`] panics if the
+    /// metadata is invalid, see [`RowGroupMetaData::try_column`].
+    ///
+    /// The arrow reader only decodes the metadata of the columns it reads.
+    /// Encrypted metadata is always decoded eagerly.
+    ///
+    /// ```no_run
+    /// # use parquet::file::metadata::ParquetMetaDataReader;
+    /// # fn open_parquet_file(path: &str) -> std::fs::File { unimplemented!(); }
+    /// let file = open_parquet_file("some_path.parquet");
+    /// let metadata = ParquetMetaDataReader::new()
+    ///     .with_lazy_column_metadata(true)
+    ///     .parse_and_finish(&file)
+    ///     .unwrap();
+    /// // only decodes the metadata of the first column of the first row group
+    /// let column = metadata.row_group(0).try_column(0).unwrap();
+    /// ```
+    ///
+    /// [`ColumnChunkMetaData`]: crate::file::metadata::ColumnChunkMetaData
+    /// [`RowGroupMetaData::column`]: crate::file::metadata::RowGroupMetaData::column
+    /// [`RowGroupMetaData::try_column`]: crate::file::metadata::RowGroupMetaData::try_column
+    /// [`RowGroupMetaData::columns`]: crate::file::metadata::RowGroupMetaData::columns
+    pub fn with_lazy_column_metadata(mut self, lazy_column_metadata: bool) -> Self {
+        self.lazy_column_metadata = lazy_column_metadata;
+        self
+    }
+
     /// Provide the FileDecryptionProperties to use when decrypting the file.
     ///
     /// This is only necessary when the file is encrypted.
@@ -353,19 +433,19 @@ impl ParquetMetaDataReader {
             ));
         };
 
-        let push_decoder = ParquetMetaDataPushDecoder::try_new_with_metadata(file_size, metadata)?
-            .with_offset_index_policy(self.offset_index)
-            .with_column_index_policy(self.column_index);
-        let mut push_decoder = self.prepare_push_decoder(push_decoder);
+        let push_decoder = ParquetMetaDataPushDecoder::try_new_with_metadata(file_size, metadata)?;
+        let mut push_decoder = self.prepare_page_index_decoder(push_decoder);
 
         // Get bounds needed for page indexes (if any are present in the file).
-        let range = match needs_index_data(&mut push_decoder)? {
+        let ranges = match needs_index_data(&mut push_decoder)? {
             NeedsIndexData::No(metadata) => {
                 self.metadata = Some(metadata);
                 return Ok(());
             }
-            NeedsIndexData::Yes(range) => range,
+            NeedsIndexData::Yes(ranges) => ranges,
         };
+        // The span of all needed ranges
+        let range = ranges[0].start..ranges.iter().map(|r| r.end).max().unwrap_or_default();
 
         // Check to see if needed range is within `file_range`. Checking `range.end` seems
         // redundant, but it guards against `range_for_page_index()` returning garbage.
@@ -396,10 +476,11 @@ impl ParquetMetaDataReader {
         }
 
         // add the needed ranges to the decoder
-        let bytes_needed = usize::try_from(range.end - range.start)?;
-        let bytes = reader.get_bytes(range.start - file_range.start, bytes_needed)?;
-
-        push_decoder.push_range(range, bytes)?;
+        for range in ranges {
+            let bytes_needed = usize::try_from(range.end - range.start)?;
+            let bytes = reader.get_bytes(range.start - file_range.start, bytes_needed)?;
+            push_decoder.push_range(range, bytes)?;
+        }
         let metadata = parse_index_data(&mut push_decoder)?;
         self.metadata = Some(metadata);
 
@@ -499,35 +580,35 @@ impl ParquetMetaDataReader {
         // in this case we don't actually know what the file size is, so just use u64::MAX
         // this is ok since the offsets in the metadata are always valid
         let file_size = u64::MAX;
-        let push_decoder = ParquetMetaDataPushDecoder::try_new_with_metadata(file_size, metadata)?
-            .with_offset_index_policy(self.offset_index)
-            .with_column_index_policy(self.column_index);
-        let mut push_decoder = self.prepare_push_decoder(push_decoder);
+        let push_decoder = ParquetMetaDataPushDecoder::try_new_with_metadata(file_size, metadata)?;
+        let mut push_decoder = self.prepare_page_index_decoder(push_decoder);
 
         // Get bounds needed for page indexes (if any are present in the file).
-        let range = match needs_index_data(&mut push_decoder)? {
+        let ranges = match needs_index_data(&mut push_decoder)? {
             NeedsIndexData::No(metadata) => {
                 self.metadata = Some(metadata);
                 return Ok(());
             }
-            NeedsIndexData::Yes(range) => range,
+            NeedsIndexData::Yes(ranges) => ranges,
         };
 
-        let bytes = match &remainder {
-            Some((remainder_start, remainder)) if *remainder_start as u64 <= range.start => {
-                let remainder_start = *remainder_start as u64;
-                let offset = usize::try_from(range.start - remainder_start)?;
-                let end = usize::try_from(range.end - remainder_start)?;
-                assert!(end <= remainder.len());
-                remainder.slice(offset..end)
-            }
-            // Note: this will potentially fetch data already in remainder, this keeps things simple
-            _ => fetch.fetch(range.start..range.end).await?,
-        };
+        for range in ranges {
+            let bytes = match &remainder {
+                Some((remainder_start, remainder)) if *remainder_start as u64 <= range.start => {
+                    let remainder_start = *remainder_start as u64;
+                    let offset = usize::try_from(range.start - remainder_start)?;
+                    let end = usize::try_from(range.end - remainder_start)?;
+                    assert!(end <= remainder.len());
+                    remainder.slice(offset..end)
+                }
+                // Note: this will potentially fetch data already in remainder, this keeps things simple
+                _ => fetch.fetch(range.start..range.end).await?,
+            };
 
-        // Sanity check
-        assert_eq!(bytes.len() as u64, range.end - range.start);
-        push_decoder.push_range(range.clone(), bytes)?;
+            // Sanity check
+            assert_eq!(bytes.len() as u64, range.end - range.start);
+            push_decoder.push_range(range, bytes)?;
+        }
         let metadata = parse_index_data(&mut push_decoder)?;
         self.metadata = Some(metadata);
         Ok(())
@@ -689,9 +770,13 @@ impl ParquetMetaDataReader {
         } else {
             let metadata_start = suffix_len - metadata_offset;
             let slice = suffix.slice(metadata_start..suffix_len - FOOTER_SIZE);
+            // The remainder can only be located in the file if it starts at
+            // offset 0, i.e. the suffix is shorter than requested as it
+            // contains the entire file
+            let remainder = (suffix_len < prefetch).then(|| (0, suffix.slice(..metadata_start)));
             Ok((
                 self.decode_footer_metadata(slice, file_size, footer)?,
-                Some((0, suffix.slice(..metadata_start))),
+                remainder,
             ))
         }
     }
@@ -751,7 +836,8 @@ impl ParquetMetaDataReader {
         let push_decoder =
             ParquetMetaDataPushDecoder::try_new_with_footer_tail(file_size, footer_tail)?
                 // NOTE: DO NOT enable page indexes here, they are handled separately
-                .with_page_index_policy(PageIndexPolicy::Skip);
+                .with_page_index_policy(PageIndexPolicy::Skip)
+                .with_lazy_column_metadata(self.lazy_column_metadata);
 
         let mut push_decoder = self.prepare_push_decoder(push_decoder);
         push_decoder.push_range(range, buf)?;
@@ -767,6 +853,21 @@ impl ParquetMetaDataReader {
         }
     }
 
+    /// Configures a push decoder for reading the page indexes
+    fn prepare_page_index_decoder(
+        &self,
+        push_decoder: ParquetMetaDataPushDecoder,
+    ) -> ParquetMetaDataPushDecoder {
+        let push_decoder = push_decoder
+            .with_offset_index_policy(self.offset_index)
+            .with_column_index_policy(self.column_index);
+        let push_decoder = match &self.page_index_columns {
+            Some(columns) => push_decoder.with_page_index_columns(columns.iter().copied()),
+            None => push_decoder,
+        };
+        self.prepare_push_decoder(push_decoder)
+    }
+
     /// Prepares a push decoder and runs it to decode the metadata.
     #[cfg(feature = "encryption")]
     fn prepare_push_decoder(
@@ -805,20 +906,20 @@ impl ParquetMetaDataReader {
 enum NeedsIndexData {
     /// no additional data is needed (e.g. the indexes weren't requested)
     No(ParquetMetaData),
-    /// Additional data is needed, with the range that are required
-    Yes(Range<u64>),
+    /// Additional data is needed, with the ranges that are required, ordered by offset
+    Yes(Vec<Range<u64>>),
 }
 
-/// Determines a single combined range of bytes needed to read the page indexes,
-/// or returns the metadata if no additional data is needed (e.g. if no page indexes are requested)
+/// Determines the ranges of bytes needed to read the page indexes, or returns the
+/// metadata if no additional data is needed (e.g. if no page indexes are requested)
 fn needs_index_data(push_decoder: &mut ParquetMetaDataPushDecoder) -> Result<NeedsIndexData> {
     match push_decoder.try_decode()? {
-        DecodeResult::NeedsData(ranges) => {
-            let range = ranges
-                .into_iter()
-                .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
-                .ok_or_else(|| general_err!("Internal error: no ranges provided"))?;
-            Ok(NeedsIndexData::Yes(range))
+        DecodeResult::NeedsData(mut ranges) => {
+            if ranges.is_empty() {
+                return Err(general_err!("Internal error: no ranges provided"));
+            }
+            ranges.sort_unstable_by_key(|r| r.start);
+            Ok(NeedsIndexData::Yes(ranges))
         }
         DecodeResult::Data(metadata) => Ok(NeedsIndexData::No(metadata)),
         DecodeResult::Finished => Err(general_err!("Internal error: decoder was finished")),
@@ -986,6 +1087,64 @@ mod tests {
             "EOF: Parquet file too small. Size is 1728 but need 1729"
         );
     }
+
+    /// Returns a parquet file with 2 row groups of 3 INT32 columns
+    fn write_int32_file() -> Bytes {
+        use crate::data_type::Int32Type;
+        use crate::file::writer::SerializedFileWriter;
+        use crate::schema::parser::parse_message_type;
+        use std::sync::Arc;
+
+        let message_type = "
+        message test_schema {
+          REQUIRED INT32 a;
+          REQUIRED INT32 b;
+          REQUIRED INT32 c;
+        }
+        ";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut out = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut out, schema, Default::default()).unwrap();
+        for row_group in 0..2 {
+            let mut r = writer.next_row_group().unwrap();
+            while let Some(mut c) = r.next_column().unwrap() {
+                c.typed::<Int32Type>()
+                    .write_batch(&[row_group, 1, 2, 3], None, None)
+                    .unwrap();
+                c.close().unwrap();
+            }
+            r.close().unwrap();
+        }
+        writer.close().unwrap();
+        Bytes::from(out)
+    }
+
+    #[test]
+    fn test_lazy_column_metadata() {
+        let file = write_int32_file();
+        let eager = ParquetMetaDataReader::new()
+            .parse_and_finish(&file)
+            .unwrap();
+        let lazy = ParquetMetaDataReader::new()
+            .with_lazy_column_metadata(true)
+            .parse_and_finish(&file)
+            .unwrap();
+
+        let row_group = lazy.row_group(1);
+        assert_eq!(row_group.num_columns(), 3);
+        assert_eq!(row_group.num_decoded_columns(), 0);
+        assert_eq!(row_group.column(1), eager.row_group(1).column(1));
+        assert_eq!(row_group.num_decoded_columns(), 1);
+        assert_eq!(lazy.row_group(0).num_decoded_columns(), 0);
+
+        // decoding the remaining columns produces the same metadata
+        assert_eq!(lazy, eager);
+        assert_eq!(row_group.num_decoded_columns(), 3);
+
+        // the columns are decoded in place rather than copied
+        let column = row_group.column(1);
+        assert!(std::ptr::eq(column, &row_group.columns()[1]));
+    }
 }
 
 #[cfg(all(feature = "async", feature = "arrow", test))]
@@ -1338,7 +1497,7 @@ mod async_tests {
         fetch_count.store(0, Ordering::SeqCst);
         let f = MetadataFetchFn(&mut fetch);
         let metadata = ParquetMetaDataReader::new()
-            .with_page_indexes(true)
+            .with_page_index_policy(PageIndexPolicy::Required)
             .with_prefetch_hint(Some((len + 1000) as usize)) // prefetch entire file
             .load_and_finish(f, len)
             .await
@@ -1347,6 +1506,101 @@ mod async_tests {
         assert!(metadata.offset_index().is_some() && metadata.column_index().is_some());
     }
 
+    /// Returns a file of `num_rows` rows with a page index, and its metadata
+    fn write_page_index_file(num_rows: i32) -> (Bytes, ParquetMetaData) {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..num_rows)) as _,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(1000)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let data = Bytes::from(buf);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&data)
+            .unwrap();
+        assert!(metadata.offset_index().is_some() && metadata.column_index().is_some());
+        (data, metadata)
+    }
+
+    /// Loads the metadata of `data` with `prefetch_hint`, via ranges and via a
+    /// suffix, returning the number of (range, suffix) requests made for each
+    async fn load_with_prefetch_hint(
+        data: &Bytes,
+        expected: &ParquetMetaData,
+        prefetch_hint: usize,
+    ) -> (usize, (usize, usize)) {
+        let fetch_count = AtomicUsize::new(0);
+        let suffix_fetch_count = AtomicUsize::new(0);
+        let mut fetch = |range: Range<u64>| {
+            fetch_count.fetch_add(1, Ordering::SeqCst);
+            futures::future::ready(Ok(data.slice(range.start as usize..range.end as usize)))
+        };
+        let mut suffix_fetch = |suffix: usize| {
+            suffix_fetch_count.fetch_add(1, Ordering::SeqCst);
+            futures::future::ready(Ok(data.slice(data.len().saturating_sub(suffix)..)))
+        };
+
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .with_prefetch_hint(Some(prefetch_hint))
+            .load_and_finish(MetadataFetchFn(&mut fetch), data.len() as u64)
+            .await
+            .unwrap();
+        assert_eq!(&metadata, expected);
+        let range_fetches = fetch_count.swap(0, Ordering::SeqCst);
+
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .with_prefetch_hint(Some(prefetch_hint))
+            .load_via_suffix_and_finish(MetadataSuffixFetchFn(&mut fetch, &mut suffix_fetch))
+            .await
+            .unwrap();
+        assert_eq!(&metadata, expected);
+        let suffix_fetches = (
+            fetch_count.load(Ordering::SeqCst),
+            suffix_fetch_count.load(Ordering::SeqCst),
+        );
+        (range_fetches, suffix_fetches)
+    }
+
+    #[tokio::test]
+    async fn test_default_prefetch_hint() {
+        // The footer and page index of a small file are read in a single request
+        let (data, expected) = write_page_index_file(10_000);
+        assert!(data.len() < DEFAULT_PREFETCH_HINT);
+        let fetches = load_with_prefetch_hint(&data, &expected, DEFAULT_PREFETCH_HINT).await;
+        assert_eq!(fetches, (1, (0, 1)));
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_hint_partial_suffix() {
+        let (data, expected) = write_page_index_file(200_000);
+        let mut reader = ParquetMetaDataReader::new();
+        reader.try_parse(&data).unwrap();
+        let metadata_size = reader.metadata_size().unwrap();
+
+        // The prefetched bytes contain the page index, but not the entire file,
+        // so the page index can only be decoded from them if the file size is known
+        let fetches = load_with_prefetch_hint(&data, &expected, data.len() - 100).await;
+        assert_eq!(fetches, (1, (1, 1)));
+
+        // The prefetched bytes contain the footer but not the page index
+        let fetches = load_with_prefetch_hint(&data, &expected, metadata_size).await;
+        assert_eq!(fetches, (2, (1, 1)));
+
+        // The footer metadata is larger than the prefetched bytes
+        let fetches = load_with_prefetch_hint(&data, &expected, metadata_size - 1).await;
+        assert_eq!(fetches, (3, (1, 2)));
+    }
+
     fn write_parquet_file(offset_index_disabled: bool) -> Result<NamedTempFile> {
         let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
         let batch = RecordBatch::try_new(
@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Self-contained serialization of [`ParquetMetaData`], see
+//! [`ParquetMetaData::try_to_bytes`]
+//!
+//! The format is:
+//!
+//! 1. Magic bytes `PQMD` (4 bytes)
+//! 2. Format version (4 bytes, little endian)
+//! 3. Length prefixed [`FileMetaData`] (thrift encoded), including the row groups
+//! 4. Optional length prefixed [`ColumnIndex`] (thrift encoded) for each column chunk
+//! 5. Optional length prefixed [`OffsetIndex`] (thrift encoded) for each column chunk
+//!
+//! Lengths are 8 bytes, little endian. The page indexes are each preceded by
+//! a single byte, which is `1` if they are present and `0` otherwise. A
+//! [`ColumnIndexMetaData::NONE`] is written with a length of `0`.
+//!
+//! Unlike [`ParquetMetaDataWriter`], the offsets stored in the column chunks
+//! are written unchanged, so they still refer to the original file.
+//!
+//! [`FileMetaData`]: https://github.com/apache/parquet-format/tree/master?tab=readme-ov-file#metadata
+//! [`ColumnIndex`]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+//! [`OffsetIndex`]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+//! [`ParquetMetaDataWriter`]: crate::file::metadata::ParquetMetaDataWriter
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::parser::decode_metadata;
+use crate::file::metadata::thrift::FileMeta;
+use crate::file::metadata::{ParquetColumnIndex, ParquetMetaData, ParquetOffsetIndex};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::file::page_index::index_reader::{decode_column_index, decode_offset_index};
+use crate::parquet_thrift::{ThriftCompactOutputProtocol, WriteThrift};
+
+/// Magic bytes identifying serialized [`ParquetMetaData`]
+const MAGIC: &[u8; 4] = b"PQMD";
+
+/// The current version of the format
+const VERSION: u32 = 1;
+
+/// Serializes `metadata`, see [`ParquetMetaData::try_to_bytes`]
+pub(super) fn metadata_to_bytes(metadata: &ParquetMetaData) -> Result<Vec<u8>> {
+    #[cfg(feature = "encryption")]
+    if metadata.file_decryptor().is_some() {
+        return Err(general_err!(
+            "Serializing the metadata of an encrypted file is not supported"
+        ));
+    }
+
+    let mut buf = Vec::new();
+    buf.extend_from_slice(MAGIC);
+    buf.extend_from_slice(&VERSION.to_le_bytes());
+
+    let file_meta = FileMeta {
+        file_metadata: metadata.file_metadata(),
+        row_groups: &metadata.row_groups,
+    };
+    write_object(&mut buf, &file_meta)?;
+
+    match metadata.column_index() {
+        Some(column_index) => {
+            buf.push(1);
+            for index in column_index.iter().flatten() {
+                match index {
+                    ColumnIndexMetaData::NONE => buf.extend_from_slice(&0u64.to_le_bytes()),
+                    index => write_object(&mut buf, index)?,
+                }
+            }
+        }
+        None => buf.push(0),
+    }
+
+    match metadata.offset_index() {
+        Some(offset_index) => {
+            buf.push(1);
+            for index in offset_index.iter().flatten() {
+                write_object(&mut buf, index)?;
+            }
+        }
+        None => buf.push(0),
+    }
+
+    Ok(buf)
+}
+
+/// Deserializes [`ParquetMetaData`], see [`ParquetMetaData::try_from_bytes`]
+pub(super) fn metadata_from_bytes(buf: &[u8]) -> Result<ParquetMetaData> {
+    let mut decoder = Decoder { buf };
+
+    if decoder.take(MAGIC.len())? != MAGIC {
+        return Err(general_err!(
+            "Invalid serialized ParquetMetaData magic bytes"
+        ));
+    }
+    let version = u32::from_le_bytes(decoder.take(4)?.try_into().unwrap());
+    if version != VERSION {
+        return Err(general_err!(
+            "Unsupported serialized ParquetMetaData version {}, expected {}",
+            version,
+            VERSION
+        ));
+    }
+
+    let mut metadata = decode_metadata(decoder.take_prefixed()?)?;
+
+    if decoder.take_flag()? {
+        let column_index: ParquetColumnIndex = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| {
+                rg.try_columns()?
+                    .iter()
+                    .map(|c| match decoder.take_prefixed()? {
+                        [] => Ok(ColumnIndexMetaData::NONE),
+                        data => decode_column_index(data, c.column_type()),
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<_>>()?;
+        metadata.set_column_index(Some(column_index));
+    }
+
+    if decoder.take_flag()? {
+        let offset_index: ParquetOffsetIndex = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| {
+                (0..rg.num_columns())
+                    .map(|_| decode_offset_index(decoder.take_prefixed()?))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<_>>()?;
+        metadata.set_offset_index(Some(offset_index));
+    }
+
+    if !decoder.buf.is_empty() {
+        return Err(general_err!(
+            "Unexpected {} trailing bytes in serialized ParquetMetaData",
+            decoder.buf.len()
+        ));
+    }
+
+    Ok(metadata)
+}
+
+/// Writes `object` thrift encoded to `buf`, prefixed by its length
+fn write_object(buf: &mut Vec<u8>, object: &impl WriteThrift) -> Result<()> {
+    let start = buf.len();
+    buf.extend_from_slice(&0u64.to_le_bytes());
+    object.write_thrift(&mut ThriftCompactOutputProtocol::new(&mut *buf))?;
+    let len = (buf.len() - start - 8) as u64;
+    buf[start..start + 8].copy_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+/// Reads the sections of serialized [`ParquetMetaData`]
+struct Decoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.buf.len() < len {
+            return Err(eof_err!("Unexpected end of serialized ParquetMetaData"));
+        }
+        let (head, tail) = self.buf.split_at(len);
+        self.buf = tail;
+        Ok(head)
+    }
+
+    fn take_flag(&mut self) -> Result<bool> {
+        match self.take(1)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            [v] => Err(general_err!(
+                "Invalid page index flag {} in serialized ParquetMetaData",
+                v
+            )),
+            _ => unreachable!(),
+        }
+    }
+
+    fn take_prefixed(&mut self) -> Result<&'a [u8]> {
+        let len = u64::from_le_bytes(self.take(8)?.try_into().unwrap());
+        let len = usize::try_from(len)
+            .map_err(|_| general_err!("Invalid length {} in serialized ParquetMetaData", len))?;
+        self.take(len)
+    }
+}
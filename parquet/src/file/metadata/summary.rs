@@ -0,0 +1,554 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading and writing `_metadata` summary files, as produced by Spark and Dask
+
+use crate::errors::{ParquetError, Result};
+use crate::file::PARQUET_MAGIC;
+use crate::file::metadata::{
+    FileMetaData, KeyValue, ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData,
+    ThriftMetadataWriter,
+};
+use crate::file::reader::ChunkReader;
+use crate::file::writer::TrackedWrite;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+/// How [`ParquetSummaryMetaDataWriter`] combines the key-value metadata of
+/// the files appended to it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyValueMetadataMerge {
+    /// Return an error if the key-value metadata of the files differs (default)
+    #[default]
+    RequireIdentical,
+    /// Use the key-value metadata of the first file, ignoring that of the others
+    First,
+    /// Use the keys of all files. If a key is present in several files, the
+    /// value from the first file containing it is used
+    Union,
+}
+
+/// Writes a footer-only `_metadata` summary file containing the row groups of
+/// many parquet files
+///
+/// Systems such as Spark and Dask write a `_metadata` file alongside a
+/// directory of parquet files, so that a reader can plan a scan of the whole
+/// dataset by reading a single footer. The row groups of every file are stored
+/// in the summary footer, with [`ColumnChunkMetaData::file_path`] set to the
+/// path of the file containing the column chunk.
+///
+/// All files must have the same schema. Files without any row groups are
+/// validated but, as there is nowhere to record their path, do not appear in
+/// the summary file.
+///
+/// The page index is not copied into the summary file, however the
+/// page index locations of each column chunk are preserved, and so can be
+/// used to load the page index from the original file.
+///
+/// See [`ParquetSummaryMetaData`] to read the resulting file.
+///
+/// # Example
+/// ```no_run
+/// # use parquet::file::metadata::{ParquetMetaData, ParquetSummaryMetaDataWriter};
+/// # fn get_metadata(path: &str) -> ParquetMetaData { unimplemented!(); }
+/// let mut buffer = vec![];
+/// let mut writer = ParquetSummaryMetaDataWriter::new(&mut buffer);
+/// for path in ["part-0.parquet", "part-1.parquet"] {
+///     writer.append_file(path, &get_metadata(path)).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// ```
+///
+/// [`ColumnChunkMetaData::file_path`]: crate::file::metadata::ColumnChunkMetaData::file_path
+pub struct ParquetSummaryMetaDataWriter<W: Write> {
+    buf: TrackedWrite<W>,
+    key_value_merge: KeyValueMetadataMerge,
+    file_metadata: Option<FileMetaData>,
+    key_value_metadata: Option<Vec<KeyValue>>,
+    row_groups: Vec<RowGroupMetaData>,
+}
+
+impl<W: Write> ParquetSummaryMetaDataWriter<W> {
+    /// Create a new `ParquetSummaryMetaDataWriter` to write to `buf`
+    pub fn new(buf: W) -> Self {
+        Self {
+            buf: TrackedWrite::new(buf),
+            key_value_merge: KeyValueMetadataMerge::default(),
+            file_metadata: None,
+            key_value_metadata: None,
+            row_groups: vec![],
+        }
+    }
+
+    /// Set how the key-value metadata of the appended files is combined
+    ///
+    /// Defaults to [`KeyValueMetadataMerge::RequireIdentical`]
+    pub fn with_key_value_merge(mut self, key_value_merge: KeyValueMetadataMerge) -> Self {
+        self.key_value_merge = key_value_merge;
+        self
+    }
+
+    /// Append the row groups of the file at `path`, described by `metadata`
+    ///
+    /// `path` is stored in [`ColumnChunkMetaData::file_path`] of each column
+    /// chunk, replacing any existing value. It is conventionally relative to
+    /// the directory containing the summary file.
+    ///
+    /// Returns an error if the schema of `metadata` differs from that of the
+    /// files already appended, or if the key-value metadata cannot be merged.
+    ///
+    /// [`ColumnChunkMetaData::file_path`]: crate::file::metadata::ColumnChunkMetaData::file_path
+    pub fn append_file(
+        &mut self,
+        path: impl Into<String>,
+        metadata: &ParquetMetaData,
+    ) -> Result<()> {
+        let path = path.into();
+        let file_metadata = metadata.file_metadata();
+
+        #[cfg(feature = "encryption")]
+        if metadata.file_decryptor().is_some() {
+            return Err(nyi_err!(
+                "Writing summary metadata for encrypted file {} is not supported",
+                path
+            ));
+        }
+
+        match &self.file_metadata {
+            None => {
+                self.file_metadata = Some(file_metadata.clone());
+                self.key_value_metadata = file_metadata.key_value_metadata().cloned();
+            }
+            Some(first) => {
+                if first.schema_descr().root_schema() != file_metadata.schema_descr().root_schema()
+                {
+                    return Err(general_err!(
+                        "Schema of file {} differs from the schema of previously appended files",
+                        path
+                    ));
+                }
+                self.merge_key_value_metadata(&path, file_metadata.key_value_metadata())?;
+            }
+        }
+
+        for row_group in metadata.row_groups() {
+            let mut row_group = row_group.clone();
+            for column in row_group.try_columns_mut()? {
+                column.file_path = Some(path.clone());
+            }
+            self.row_groups.push(row_group);
+        }
+        Ok(())
+    }
+
+    fn merge_key_value_metadata(
+        &mut self,
+        path: &str,
+        other: Option<&Vec<KeyValue>>,
+    ) -> Result<()> {
+        match self.key_value_merge {
+            KeyValueMetadataMerge::RequireIdentical => {
+                if self.key_value_metadata.as_ref() != other {
+                    return Err(general_err!(
+                        "Key-value metadata of file {} differs from that of previously appended files",
+                        path
+                    ));
+                }
+            }
+            KeyValueMetadataMerge::First => {}
+            KeyValueMetadataMerge::Union => {
+                for kv in other.into_iter().flatten() {
+                    let existing = self.key_value_metadata.get_or_insert_with(Vec::new);
+                    if !existing.iter().any(|e| e.key == kv.key) {
+                        existing.push(kv.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the summary file to the buffer, returning the written metadata
+    ///
+    /// Returns an error if no files were appended
+    pub fn finish(mut self) -> Result<ParquetMetaData> {
+        let file_metadata = self
+            .file_metadata
+            .take()
+            .ok_or_else(|| general_err!("Cannot write summary metadata without any files"))?;
+
+        let schema_descr = file_metadata.schema_descr_ptr();
+        self.buf.write_all(&PARQUET_MAGIC)?;
+
+        let mut encoder = ThriftMetadataWriter::new(
+            &mut self.buf,
+            &schema_descr,
+            self.row_groups,
+            file_metadata.created_by().map(str::to_string),
+            file_metadata.version(),
+        );
+        if let Some(key_value_metadata) = self.key_value_metadata {
+            encoder = encoder.with_key_value_metadata(key_value_metadata);
+        }
+        let metadata = encoder.finish()?;
+        self.buf.flush()?;
+        Ok(metadata)
+    }
+}
+
+/// The contents of a `_metadata` summary file, split into a [`ParquetMetaData`]
+/// for each of the files it describes
+///
+/// The metadata of each file contains the row groups of that file, with
+/// [`ColumnChunkMetaData::file_path`] cleared, and so can be used to read the
+/// file without reading its footer. For example with
+/// [`ArrowReaderMetadata::try_new`] and [`ArrowReaderBuilder::new_with_metadata`].
+///
+/// See [`ParquetSummaryMetaDataWriter`] to write summary files.
+///
+/// # Example
+/// ```no_run
+/// # use std::fs::File;
+/// # use bytes::Bytes;
+/// # use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ParquetRecordBatchReaderBuilder};
+/// # use parquet::file::metadata::ParquetSummaryMetaData;
+/// let summary = ParquetSummaryMetaData::parse(&Bytes::from(std::fs::read("_metadata").unwrap())).unwrap();
+/// for (path, metadata) in summary.files() {
+///     let metadata = ArrowReaderMetadata::try_new(metadata.clone(), Default::default()).unwrap();
+///     let file = File::open(path).unwrap();
+///     let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(file, metadata)
+///         .build()
+///         .unwrap();
+/// }
+/// ```
+///
+/// [`ColumnChunkMetaData::file_path`]: crate::file::metadata::ColumnChunkMetaData::file_path
+/// [`ArrowReaderMetadata::try_new`]: https://docs.rs/parquet/latest/parquet/arrow/arrow_reader/struct.ArrowReaderMetadata.html#method.try_new
+/// [`ArrowReaderBuilder::new_with_metadata`]: https://docs.rs/parquet/latest/parquet/arrow/arrow_reader/struct.ArrowReaderBuilder.html#method.new_with_metadata
+#[derive(Debug, Clone)]
+pub struct ParquetSummaryMetaData {
+    metadata: Arc<ParquetMetaData>,
+    files: Vec<(String, Arc<ParquetMetaData>)>,
+}
+
+impl ParquetSummaryMetaData {
+    /// Parse a summary file from `reader`
+    pub fn parse<R: ChunkReader>(reader: &R) -> Result<Self> {
+        Self::try_new(ParquetMetaDataReader::new().parse_and_finish(reader)?)
+    }
+
+    /// Split the [`ParquetMetaData`] of a summary file by file path
+    ///
+    /// Returns an error if a row group does not have a file path, or if the
+    /// column chunks of a row group have different file paths
+    pub fn try_new(metadata: ParquetMetaData) -> Result<Self> {
+        let mut files: Vec<(String, Vec<RowGroupMetaData>)> = vec![];
+        let mut file_indices = HashMap::new();
+
+        for (idx, row_group) in metadata.row_groups().iter().enumerate() {
+            let mut row_group = row_group.clone();
+            let mut path = None;
+            for column in row_group.try_columns_mut()? {
+                let column_path = column.file_path.take().ok_or_else(|| {
+                    general_err!(
+                        "Column chunk in row group {} does not have a file path",
+                        idx
+                    )
+                })?;
+                match &path {
+                    None => path = Some(column_path),
+                    Some(path) if *path != column_path => {
+                        return Err(general_err!(
+                            "Column chunks in row group {} have different file paths: {} and {}",
+                            idx,
+                            path,
+                            column_path
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+            let path =
+                path.ok_or_else(|| general_err!("Row group {} does not have any columns", idx))?;
+
+            let file_idx = *file_indices.entry(path.clone()).or_insert_with(|| {
+                files.push((path, vec![]));
+                files.len() - 1
+            });
+            files[file_idx].1.push(row_group);
+        }
+
+        let file_metadata = metadata.file_metadata();
+        let files = files
+            .into_iter()
+            .map(|(path, row_groups)| {
+                let file_metadata = file_metadata_for(file_metadata, &row_groups);
+                (
+                    path,
+                    Arc::new(ParquetMetaData::new(file_metadata, row_groups)),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            metadata: Arc::new(metadata),
+            files,
+        })
+    }
+
+    /// Returns the [`ParquetMetaData`] of the summary file, containing the row
+    /// groups of all files
+    pub fn metadata(&self) -> &Arc<ParquetMetaData> {
+        &self.metadata
+    }
+
+    /// Returns the number of files described by the summary file
+    pub fn num_files(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns the path and [`ParquetMetaData`] of each file, in the order
+    /// they first appear in the summary file
+    pub fn files(&self) -> impl Iterator<Item = (&str, &Arc<ParquetMetaData>)> {
+        self.files.iter().map(|(path, m)| (path.as_str(), m))
+    }
+
+    /// Returns the [`ParquetMetaData`] of the file at `path`, if any
+    pub fn get(&self, path: &str) -> Option<&Arc<ParquetMetaData>> {
+        self.files.iter().find(|(p, _)| p == path).map(|(_, m)| m)
+    }
+}
+
+/// Returns the [`FileMetaData`] of a file containing `row_groups`, based on
+/// the [`FileMetaData`] of the summary file
+fn file_metadata_for(summary: &FileMetaData, row_groups: &[RowGroupMetaData]) -> FileMetaData {
+    FileMetaData::new(
+        summary.version(),
+        row_groups.iter().map(|rg| rg.num_rows()).sum(),
+        summary.created_by().map(str::to_string),
+        summary.key_value_metadata().cloned(),
+        summary.schema_descr_ptr(),
+        summary.column_orders().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_type::Int32Type;
+    use crate::file::properties::WriterProperties;
+    use crate::file::writer::SerializedFileWriter;
+    use crate::schema::parser::parse_message_type;
+    use bytes::Bytes;
+
+    /// Writes a file with `num_row_groups` row groups of a single INT32 column
+    fn write_file(message_type: &str, num_row_groups: i32, key_value: Option<&str>) -> Bytes {
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(
+                key_value.map(|v| vec![KeyValue::new("k".into(), v.to_string())]),
+            )
+            .build();
+        let mut out = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut out, schema, Arc::new(props)).unwrap();
+        for row_group in 0..num_row_groups {
+            let mut r = writer.next_row_group().unwrap();
+            while let Some(mut c) = r.next_column().unwrap() {
+                c.typed::<Int32Type>()
+                    .write_batch(&[row_group, 1, 2], None, None)
+                    .unwrap();
+                c.close().unwrap();
+            }
+            r.close().unwrap();
+        }
+        writer.close().unwrap();
+        Bytes::from(out)
+    }
+
+    const SCHEMA: &str = "message test_schema { REQUIRED INT32 a; }";
+
+    fn metadata(file: &Bytes) -> ParquetMetaData {
+        ParquetMetaDataReader::new().parse_and_finish(file).unwrap()
+    }
+
+    #[test]
+    fn test_summary_round_trip() {
+        let file_a = metadata(&write_file(SCHEMA, 2, Some("v")));
+        let file_b = metadata(&write_file(SCHEMA, 3, Some("v")));
+        let empty = metadata(&write_file(SCHEMA, 0, Some("v")));
+
+        let mut buf = vec![];
+        let mut writer = ParquetSummaryMetaDataWriter::new(&mut buf);
+        writer.append_file("a.parquet", &file_a).unwrap();
+        writer.append_file("empty.parquet", &empty).unwrap();
+        writer.append_file("b.parquet", &file_b).unwrap();
+        let written = writer.finish().unwrap();
+        assert_eq!(written.num_row_groups(), 5);
+        assert_eq!(written.file_metadata().num_rows(), 15);
+
+        let buf = Bytes::from(buf);
+        assert_eq!(&buf[..4], &PARQUET_MAGIC);
+        let summary = ParquetSummaryMetaData::parse(&buf).unwrap();
+        assert_eq!(summary.metadata().num_row_groups(), 5);
+        assert_eq!(
+            summary.metadata().row_group(2).column(0).file_path(),
+            Some("b.parquet")
+        );
+
+        let paths: Vec<_> = summary.files().map(|(path, _)| path).collect();
+        assert_eq!(paths, vec!["a.parquet", "b.parquet"]);
+        assert!(summary.get("empty.parquet").is_none());
+
+        for (path, expected) in [("a.parquet", &file_a), ("b.parquet", &file_b)] {
+            let actual = summary.get(path).unwrap();
+            assert_eq!(actual.row_groups(), expected.row_groups());
+            assert_eq!(
+                actual.file_metadata().num_rows(),
+                expected.file_metadata().num_rows()
+            );
+            assert_eq!(
+                actual.file_metadata().key_value_metadata(),
+                expected.file_metadata().key_value_metadata()
+            );
+        }
+    }
+
+    #[test]
+    fn test_summary_schema_mismatch() {
+        let file_a = metadata(&write_file(SCHEMA, 1, None));
+        let file_b = metadata(&write_file(
+            "message test_schema { REQUIRED INT32 b; }",
+            1,
+            None,
+        ));
+
+        let mut writer = ParquetSummaryMetaDataWriter::new(vec![]);
+        writer.append_file("a.parquet", &file_a).unwrap();
+        let err = writer.append_file("b.parquet", &file_b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Schema of file b.parquet differs from the schema of previously appended files"
+        );
+
+        let err = ParquetSummaryMetaDataWriter::new(vec![])
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot write summary metadata without any files"
+        );
+    }
+
+    #[test]
+    fn test_summary_key_value_merge() {
+        let file_a = metadata(&write_file(SCHEMA, 1, Some("a")));
+        let file_b = metadata(&write_file(SCHEMA, 1, Some("b")));
+        let file_none = metadata(&write_file(SCHEMA, 1, None));
+
+        let mut writer = ParquetSummaryMetaDataWriter::new(vec![]);
+        writer.append_file("a.parquet", &file_a).unwrap();
+        let err = writer.append_file("b.parquet", &file_b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Key-value metadata of file b.parquet differs from that of previously appended files"
+        );
+
+        let key_value = |merge, files: &[&ParquetMetaData]| {
+            let mut writer = ParquetSummaryMetaDataWriter::new(vec![]).with_key_value_merge(merge);
+            for (idx, file) in files.iter().enumerate() {
+                writer.append_file(format!("{idx}.parquet"), file).unwrap();
+            }
+            let metadata = writer.finish().unwrap();
+            metadata.file_metadata().key_value_metadata().map(|kv| {
+                kv.iter()
+                    .map(|kv| kv.value.clone().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let first = KeyValueMetadataMerge::First;
+        assert_eq!(
+            key_value(first, &[&file_a, &file_b]),
+            Some(vec!["a".to_string()])
+        );
+        assert_eq!(key_value(first, &[&file_none, &file_b]), None);
+
+        let union = KeyValueMetadataMerge::Union;
+        assert_eq!(
+            key_value(union, &[&file_a, &file_b]),
+            Some(vec!["a".to_string()])
+        );
+        assert_eq!(
+            key_value(union, &[&file_none, &file_b]),
+            Some(vec!["b".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_summary_missing_file_path() {
+        let file = metadata(&write_file(SCHEMA, 1, None));
+        let err = ParquetSummaryMetaData::try_new(file).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column chunk in row group 0 does not have a file path"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_summary_arrow_reader() {
+        use crate::arrow::arrow_reader::{ArrowReaderMetadata, ParquetRecordBatchReaderBuilder};
+        use arrow_array::cast::AsArray;
+        use arrow_array::types::Int32Type as ArrowInt32Type;
+
+        let files: HashMap<_, _> = [("a.parquet", 2), ("b.parquet", 1)]
+            .into_iter()
+            .map(|(path, num_row_groups)| (path, write_file(SCHEMA, num_row_groups, None)))
+            .collect();
+
+        let mut buf = vec![];
+        let mut writer = ParquetSummaryMetaDataWriter::new(&mut buf);
+        for path in ["a.parquet", "b.parquet"] {
+            writer.append_file(path, &metadata(&files[path])).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let summary = ParquetSummaryMetaData::parse(&Bytes::from(buf)).unwrap();
+        let mut values = vec![];
+        for (path, metadata) in summary.files() {
+            let metadata =
+                ArrowReaderMetadata::try_new(metadata.clone(), Default::default()).unwrap();
+            let reader =
+                ParquetRecordBatchReaderBuilder::new_with_metadata(files[path].clone(), metadata)
+                    .build()
+                    .unwrap();
+            for batch in reader {
+                let batch = batch.unwrap();
+                values.extend(
+                    batch
+                        .column(0)
+                        .as_primitive::<ArrowInt32Type>()
+                        .values()
+                        .iter()
+                        .copied(),
+                );
+            }
+        }
+        assert_eq!(values, vec![0, 1, 2, 1, 1, 2, 0, 1, 2]);
+    }
+}
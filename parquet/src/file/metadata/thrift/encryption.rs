@@ -144,6 +144,12 @@ fn row_group_from_encrypted_thrift(
                 }
                 Some(ColumnCryptoMetaData::ENCRYPTION_WITH_COLUMN_KEY(crypto_metadata)) => {
                     let column_name = crypto_metadata.path_in_schema.join(".");
+                    if decryptor.is_column_skipped(&column_name) {
+                        // No key was provided for this column, leave its metadata
+                        // encrypted. Attempting to read it will produce an error.
+                        columns.push(c);
+                        continue;
+                    }
                     decryptor.get_column_metadata_decryptor(
                         column_name.as_str(),
                         crypto_metadata.key_metadata.as_deref(),
@@ -191,6 +197,7 @@ fn row_group_from_encrypted_thrift(
 
     Ok(RowGroupMetaData {
         columns,
+        lazy_columns: None,
         num_rows,
         sorting_columns,
         total_byte_size,
@@ -23,6 +23,7 @@
 //! [Parquet metadata API]: crate::file::metadata
 //! [Parquet specification]: https://github.com/apache/parquet-format/tree/master
 
+use bytes::Bytes;
 use std::io::Write;
 use std::sync::Arc;
 
@@ -42,9 +43,9 @@ use crate::{
     errors::{ParquetError, Result},
     file::{
         metadata::{
-            ColumnChunkMetaData, ColumnChunkMetaDataBuilder, KeyValue, LevelHistogram,
-            PageEncodingStats, ParquetMetaData, RowGroupMetaData, RowGroupMetaDataBuilder,
-            SortingColumn,
+            ColumnChunkMetaData, ColumnChunkMetaDataBuilder, KeyValue, LazyColumnChunks,
+            LevelHistogram, PageEncodingStats, ParquetMetaData, RowGroupMetaData,
+            RowGroupMetaDataBuilder, SortingColumn,
         },
         statistics::ValueStatistics,
     },
@@ -206,8 +207,10 @@ fn convert_stats(
 
             // Generic null count.
             let null_count = Some(null_count as u64);
-            // Generic distinct count (count of distinct values occurring)
-            let distinct_count = stats.distinct_count.map(|value| value as u64);
+            // Generic distinct count (count of distinct values occurring), ignored if invalid
+            let distinct_count = stats
+                .distinct_count
+                .and_then(|value| u64::try_from(value).ok());
             // Whether or not statistics use deprecated min/max fields.
             let old_format = stats.min_value.is_none() && stats.max_value.is_none();
             // Generic min value as bytes.
@@ -582,9 +585,24 @@ fn read_column_chunk<'a>(
     Ok(col)
 }
 
+/// Decode the metadata of a column chunk from its thrift encoded `ColumnChunk`
+pub(crate) fn decode_column_chunk(
+    buf: &[u8],
+    column_descr: &Arc<ColumnDescriptor>,
+) -> Result<ColumnChunkMetaData> {
+    let mut prot = ThriftSliceInputProtocol::new(buf);
+    read_column_chunk(&mut prot, column_descr)
+}
+
+/// Read a `RowGroup`
+///
+/// If `lazy_buf` is provided, it must contain the bytes being read by `prot`,
+/// and the `ColumnChunk`s are not decoded but instead sliced from it, to be
+/// decoded on first access.
 fn read_row_group(
     prot: &mut ThriftSliceInputProtocol,
     schema_descr: &Arc<SchemaDescriptor>,
+    lazy_buf: Option<&Bytes>,
 ) -> Result<RowGroupMetaData> {
     // create default initialized RowGroupMetaData
     let mut row_group = RowGroupMetaDataBuilder::new(schema_descr.clone()).build_unchecked();
@@ -622,9 +640,23 @@ fn read_row_group(
                         list_ident.size
                     ));
                 }
-                for i in 0..list_ident.size as usize {
-                    let col = read_column_chunk(prot, &schema_descr.columns()[i])?;
-                    row_group.columns.push(col);
+                match lazy_buf {
+                    Some(buf) => {
+                        let mut encoded = Vec::with_capacity(list_ident.size as usize);
+                        for _ in 0..list_ident.size {
+                            let start = buf.len() - prot.as_slice().len();
+                            prot.skip(FieldType::Struct)?;
+                            let end = buf.len() - prot.as_slice().len();
+                            encoded.push(buf.slice(start..end));
+                        }
+                        row_group.lazy_columns = Some(Arc::new(LazyColumnChunks::new(encoded)));
+                    }
+                    None => {
+                        for i in 0..list_ident.size as usize {
+                            let col = read_column_chunk(prot, &schema_descr.columns()[i])?;
+                            row_group.columns.push(col);
+                        }
+                    }
                 }
                 mask |= RG_COLUMNS;
             }
@@ -672,6 +704,36 @@ fn read_row_group(
 /// Create [`ParquetMetaData`] from thrift input. Note that this only decodes the file metadata in
 /// the Parquet footer. Page indexes will need to be added later.
 pub(crate) fn parquet_metadata_from_bytes(buf: &[u8]) -> Result<ParquetMetaData> {
+    parquet_metadata_from_thrift(buf, None)
+}
+
+/// Create [`ParquetMetaData`] from thrift input, decoding the [`ColumnChunkMetaData`]
+/// of each column on first access. See [`parquet_metadata_from_bytes`].
+pub(crate) fn parquet_metadata_from_bytes_lazy(buf: &Bytes) -> Result<ParquetMetaData> {
+    parquet_metadata_from_thrift(buf, Some(buf))
+}
+
+/// Decode only the schema of the file metadata in the Parquet footer, skipping
+/// the fields before it. See [`parquet_metadata_from_bytes`].
+#[cfg(feature = "arrow")]
+pub(crate) fn parquet_schema_from_bytes(buf: &[u8]) -> Result<TypePtr> {
+    let mut prot = ThriftSliceInputProtocol::new(buf);
+    let mut last_field_id = 0i16;
+    loop {
+        let field_ident = prot.read_field_begin(last_field_id)?;
+        match field_ident.field_type {
+            FieldType::Stop => return Err(general_err!("Required field schema is missing")),
+            _ if field_ident.id == 2 => {
+                let val = read_thrift_vec::<SchemaElement, ThriftSliceInputProtocol>(&mut prot)?;
+                return parquet_schema_from_array(val);
+            }
+            field_type => prot.skip(field_type)?,
+        }
+        last_field_id = field_ident.id;
+    }
+}
+
+fn parquet_metadata_from_thrift(buf: &[u8], lazy_buf: Option<&Bytes>) -> Result<ParquetMetaData> {
     let mut prot = ThriftSliceInputProtocol::new(buf);
 
     // begin reading the file metadata
@@ -727,7 +789,7 @@ pub(crate) fn parquet_metadata_from_bytes(buf: &[u8]) -> Result<ParquetMetaData>
                 let list_ident = prot.read_list_begin()?;
                 let mut rg_vec = Vec::with_capacity(list_ident.size as usize);
                 for _ in 0..list_ident.size {
-                    rg_vec.push(read_row_group(&mut prot, schema_descr)?);
+                    rg_vec.push(read_row_group(&mut prot, schema_descr, lazy_buf)?);
                 }
                 row_groups = Some(rg_vec);
             }
@@ -1413,7 +1475,12 @@ impl WriteThrift for RowGroupMetaData {
 
     fn write_thrift<W: Write>(&self, writer: &mut ThriftCompactOutputProtocol<W>) -> Result<()> {
         // this will call ColumnChunkMetaData::write_thrift
-        self.columns.write_thrift_field(writer, 1, 0)?;
+        let columns = self.columns();
+        writer.write_field_begin(FieldType::List, 1, 0)?;
+        writer.write_list_begin(ColumnChunkMetaData::ELEMENT_TYPE, columns.len())?;
+        for column in columns {
+            column.write_thrift(writer)?;
+        }
         self.total_byte_size.write_thrift_field(writer, 2, 1)?;
         let mut last_field_id = self.num_rows.write_thrift_field(writer, 3, 2)?;
         if let Some(sorting_columns) = self.sorting_columns() {
@@ -1586,7 +1653,7 @@ pub(crate) mod tests {
         schema_descr: Arc<SchemaDescriptor>,
     ) -> Result<RowGroupMetaData> {
         let mut reader = ThriftSliceInputProtocol::new(buf);
-        crate::file::metadata::thrift::read_row_group(&mut reader, &schema_descr)
+        crate::file::metadata::thrift::read_row_group(&mut reader, &schema_descr, None)
     }
 
     pub(crate) fn read_column_chunk(
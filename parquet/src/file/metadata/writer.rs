@@ -78,7 +78,8 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
         // iter each column
         // write offset index to the file
         for (row_group_idx, row_group) in self.row_groups.iter_mut().enumerate() {
-            for (column_idx, column_metadata) in row_group.columns.iter_mut().enumerate() {
+            for (column_idx, column_metadata) in row_group.try_columns_mut()?.iter_mut().enumerate()
+            {
                 if let Some(offset_index) = &offset_indexes[row_group_idx][column_idx] {
                     let start_offset = self.buf.bytes_written();
                     self.object_writer.write_offset_index(
@@ -111,7 +112,8 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
         // iter each column
         // write column index to the file
         for (row_group_idx, row_group) in self.row_groups.iter_mut().enumerate() {
-            for (column_idx, column_metadata) in row_group.columns.iter_mut().enumerate() {
+            for (column_idx, column_metadata) in row_group.try_columns_mut()?.iter_mut().enumerate()
+            {
                 if let Some(column_index) = &column_indexes[row_group_idx][column_idx] {
                     let start_offset = self.buf.bytes_written();
                     self.object_writer.write_column_index(
@@ -732,14 +734,14 @@ impl MetadataObjectWriter {
             .into_iter()
             .enumerate()
             .map(|(rg_idx, mut rg)| {
-                let cols: Result<Vec<ColumnChunkMetaData>> = rg
-                    .columns
-                    .into_iter()
-                    .enumerate()
-                    .map(|(col_idx, c)| {
-                        Self::encrypt_column_chunk(c, file_encryptor, rg_idx, col_idx)
-                    })
-                    .collect();
+                let cols: Result<Vec<ColumnChunkMetaData>> =
+                    std::mem::take(rg.decoded_columns_mut()?)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(col_idx, c)| {
+                            Self::encrypt_column_chunk(c, file_encryptor, rg_idx, col_idx)
+                        })
+                        .collect();
                 rg.columns = cols?;
                 Ok(rg)
             })
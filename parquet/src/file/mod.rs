@@ -103,6 +103,7 @@ pub mod metadata;
 pub mod page_index;
 pub mod properties;
 pub mod reader;
+pub mod rewrite;
 pub mod serialized_reader;
 pub mod statistics;
 pub mod writer;
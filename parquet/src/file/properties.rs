@@ -16,12 +16,13 @@
 // under the License.
 
 //! Configuration via [`WriterProperties`] and [`ReaderProperties`]
-use crate::basic::{Compression, Encoding};
+use crate::basic::{Compression, Encoding, Type};
 use crate::compression::{CodecOptions, CodecOptionsBuilder};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptionProperties;
+use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{KeyValue, SortingColumn};
-use crate::schema::types::ColumnPath;
+use crate::schema::types::{ColumnDescriptor, ColumnPath, SchemaDescriptor};
 use std::str::FromStr;
 use std::{collections::HashMap, sync::Arc};
 
@@ -37,6 +38,8 @@ pub const DEFAULT_COMPRESSION: Compression = Compression::UNCOMPRESSED;
 pub const DEFAULT_DICTIONARY_ENABLED: bool = true;
 /// Default value for [`WriterProperties::dictionary_page_size_limit`]
 pub const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
+/// Default value for [`WriterProperties::dictionary_deduplicate_views`]
+pub const DEFAULT_DICTIONARY_DEDUPLICATE_VIEWS: bool = false;
 /// Default value for [`WriterProperties::data_page_row_count_limit`]
 pub const DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT: usize = 20_000;
 /// Default value for [`WriterProperties::statistics_enabled`]
@@ -61,6 +64,37 @@ pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
 pub const DEFAULT_OFFSET_INDEX_DISABLED: bool = false;
 /// Default values for [`WriterProperties::coerce_types`]
 pub const DEFAULT_COERCE_TYPES: bool = false;
+/// Default value for [`WriterProperties::int96_timestamps`]
+pub const DEFAULT_INT96_TIMESTAMPS: bool = false;
+/// Default value for [`WriterProperties::decimal256_byte_width`]
+pub const DEFAULT_DECIMAL256_BYTE_WIDTH: Option<usize> = None;
+/// Default value for [`WriterProperties::fixed_len_byte_array_decimals`]
+pub const DEFAULT_FIXED_LEN_BYTE_ARRAY_DECIMALS: bool = false;
+/// Default value for [`WriterProperties::truncate_interval_nanoseconds`]
+pub const DEFAULT_TRUNCATE_INTERVAL_NANOSECONDS: bool = false;
+/// Default value for [`WriterProperties::page_checksums_enabled`]
+pub const DEFAULT_PAGE_CHECKSUMS_ENABLED: bool = false;
+/// Default value for [`WriterProperties::dictionary_distinct_count_enabled`]
+pub const DEFAULT_DICTIONARY_DISTINCT_COUNT_ENABLED: bool = false;
+/// Default value for [`WriterProperties::auto_compression`]
+pub const DEFAULT_AUTO_COMPRESSION: bool = false;
+
+/// Returns the codec chosen for a column of `physical_type`, see
+/// [`WriterPropertiesBuilder::set_auto_compression`]
+fn auto_compression(physical_type: Type) -> Compression {
+    let snappy = match cfg!(feature = "snap") {
+        true => Compression::SNAPPY,
+        false => Compression::UNCOMPRESSED,
+    };
+    match physical_type {
+        Type::BYTE_ARRAY | Type::FIXED_LEN_BYTE_ARRAY => match cfg!(feature = "zstd") {
+            true => Compression::ZSTD(Default::default()),
+            false => snappy,
+        },
+        Type::INT32 | Type::INT64 | Type::INT96 | Type::FLOAT | Type::DOUBLE => snappy,
+        Type::BOOLEAN => Compression::UNCOMPRESSED,
+    }
+}
 
 /// Parquet writer version.
 ///
@@ -96,6 +130,28 @@ impl FromStr for WriterVersion {
     }
 }
 
+/// Presets of [`WriterProperties`] for files read by other Parquet implementations
+///
+/// See [`WriterPropertiesBuilder::set_compatibility`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WriterCompatibility {
+    /// Files readable by Apache Spark, matching its default output:
+    ///
+    /// * Data pages are written as [`WriterVersion::PARQUET_1_0`]
+    /// * Timestamps are written as INT96, see [`WriterPropertiesBuilder::set_int96_timestamps`]
+    /// * Types are coerced to native Parquet types and `List` and `Map`
+    ///   fields use the names prescribed by the Parquet specification, see
+    ///   [`WriterPropertiesBuilder::set_coerce_types`]
+    Spark,
+    /// Files readable by Apache Hive and Apache Impala, matching the output of
+    /// Spark with `spark.sql.parquet.writeLegacyFormat` enabled.
+    ///
+    /// This is the same as [`Self::Spark`], except that all decimals are written
+    /// as `FIXED_LEN_BYTE_ARRAY`, see [`WriterPropertiesBuilder::set_fixed_len_byte_array_decimals`]
+    Hive,
+}
+
 /// Where in the file [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter) should
 /// write Bloom filters
 ///
@@ -164,10 +220,18 @@ pub struct WriterProperties {
     pub(crate) key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    column_pattern_properties: Vec<(ColumnPattern, ColumnProperties)>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    int96_timestamps: bool,
+    decimal256_byte_width: Option<usize>,
+    fixed_len_byte_array_decimals: bool,
+    truncate_interval_nanoseconds: bool,
+    page_checksums_enabled: bool,
+    dictionary_distinct_count_enabled: bool,
+    auto_compression: bool,
     #[cfg(feature = "encryption")]
     pub(crate) file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
 }
@@ -220,16 +284,27 @@ impl WriterProperties {
 
     /// Returns dictionary page size limit for a specific column.
     pub fn column_dictionary_page_size_limit(&self, col: &ColumnPath) -> usize {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.dictionary_page_size_limit())
-            .or_else(|| self.default_column_properties.dictionary_page_size_limit())
+        self.column_property(col, |c| c.dictionary_page_size_limit())
             .unwrap_or(DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT)
     }
 
-    /// Returns the maximum page row count
+    /// Returns the maximum length in bytes of a value that may be dictionary
+    /// encoded for a column, or `None` if there is no limit.
     ///
-    /// Note: this is a best effort limit based on the write batch size
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_max_value_length`]
+    pub fn dictionary_max_value_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_property(col, |c| c.dictionary_max_value_length())
+    }
+
+    /// Returns `true` if views are deduplicated before dictionary encoding a column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_deduplicate_views`]
+    pub fn dictionary_deduplicate_views(&self, col: &ColumnPath) -> bool {
+        self.column_property(col, |c| c.dictionary_deduplicate_views())
+            .unwrap_or(DEFAULT_DICTIONARY_DEDUPLICATE_VIEWS)
+    }
+
+    /// Returns the maximum page row count
     ///
     /// For more details see [`WriterPropertiesBuilder::set_data_page_row_count_limit`]
     pub fn data_page_row_count_limit(&self) -> usize {
@@ -284,8 +359,13 @@ impl WriterProperties {
             self.default_column_properties.statistics_enabled() == Some(EnabledStatistics::Page);
         let column_page_stats_enabled = self
             .column_properties
-            .iter()
-            .any(|path_props| path_props.1.statistics_enabled() == Some(EnabledStatistics::Page));
+            .values()
+            .chain(
+                self.column_pattern_properties
+                    .iter()
+                    .map(|(_, props)| props),
+            )
+            .any(|props| props.statistics_enabled() == Some(EnabledStatistics::Page));
         if default_page_stats_enabled || column_page_stats_enabled {
             return false;
         }
@@ -334,6 +414,59 @@ impl WriterProperties {
         self.coerce_types
     }
 
+    /// Returns `true` if Arrow timestamps are written as INT96.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_int96_timestamps`]
+    pub fn int96_timestamps(&self) -> bool {
+        self.int96_timestamps
+    }
+
+    /// Returns the byte width used for Arrow `Decimal256` columns stored as
+    /// `FIXED_LEN_BYTE_ARRAY`, or `None` if the minimal width for the precision is used.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_decimal256_byte_width`]
+    pub fn decimal256_byte_width(&self) -> Option<usize> {
+        self.decimal256_byte_width
+    }
+
+    /// Returns `true` if all Arrow decimals are written as `FIXED_LEN_BYTE_ARRAY`.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_fixed_len_byte_array_decimals`]
+    pub fn fixed_len_byte_array_decimals(&self) -> bool {
+        self.fixed_len_byte_array_decimals
+    }
+
+    /// Returns `true` if Arrow `MonthDayNano` intervals with sub-millisecond
+    /// precision are truncated to milliseconds when written.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_truncate_interval_nanoseconds`]
+    pub fn truncate_interval_nanoseconds(&self) -> bool {
+        self.truncate_interval_nanoseconds
+    }
+
+    /// Returns `true` if a CRC32 checksum is written in the header of each page.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_page_checksums_enabled`]
+    pub fn page_checksums_enabled(&self) -> bool {
+        self.page_checksums_enabled
+    }
+
+    /// Returns `true` if the distinct count of fully dictionary encoded column
+    /// chunks is written to their statistics.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_distinct_count_enabled`]
+    pub fn dictionary_distinct_count_enabled(&self) -> bool {
+        self.dictionary_distinct_count_enabled
+    }
+
+    /// Returns `true` if a compression codec is chosen from the physical type of
+    /// columns without an explicitly configured codec.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_auto_compression`]
+    pub fn auto_compression(&self) -> bool {
+        self.auto_compression
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     ///
     /// This is not configurable.
@@ -361,31 +494,61 @@ impl WriterProperties {
     /// If encoding is not set, then column writer will choose the best encoding
     /// based on the column type.
     pub fn encoding(&self, col: &ColumnPath) -> Option<Encoding> {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.encoding())
-            .or_else(|| self.default_column_properties.encoding())
+        self.column_property(col, |c| c.encoding())
+    }
+
+    /// Returns the fallback encoding for a column, if set.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_fallback_encoding`]
+    pub fn fallback_encoding(&self, col: &ColumnPath) -> Option<Encoding> {
+        self.column_property(col, |c| c.fallback_encoding())
     }
 
-    /// Returns compression codec for a column.
+    /// Returns the encoding to use for data pages of a column that are not dictionary
+    /// encoded, if set, where `dictionary_enabled` is whether the column writer uses
+    /// dictionary encoding.
+    pub(crate) fn value_encoding(
+        &self,
+        col: &ColumnPath,
+        dictionary_enabled: bool,
+    ) -> Option<Encoding> {
+        self.fallback_encoding(col)
+            .filter(|_| dictionary_enabled)
+            .or_else(|| self.encoding(col))
+    }
+
+    /// Returns compression codec configured for a column.
+    ///
+    /// If [`Self::auto_compression`] is enabled and no codec was set for the
+    /// column, the codec used to write the column depends on its physical type,
+    /// which is not known from its path alone, and [`DEFAULT_COMPRESSION`] is
+    /// returned. Use [`Self::column_compression`] to get the codec actually
+    /// used to write a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_column_compression`]
     pub fn compression(&self, col: &ColumnPath) -> Compression {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.compression())
-            .or_else(|| self.default_column_properties.compression())
+        self.column_property(col, |c| c.compression())
             .unwrap_or(DEFAULT_COMPRESSION)
     }
 
+    /// Returns the compression codec used to write `column`, choosing one from its
+    /// physical type if [`Self::auto_compression`] is enabled and no codec was set
+    /// for the column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_auto_compression`]
+    pub fn column_compression(&self, column: &ColumnDescriptor) -> Compression {
+        match self.column_property(column.path(), |c| c.compression()) {
+            Some(codec) => codec,
+            None if self.auto_compression => auto_compression(column.physical_type()),
+            None => DEFAULT_COMPRESSION,
+        }
+    }
+
     /// Returns `true` if dictionary encoding is enabled for a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_dictionary_enabled`]
     pub fn dictionary_enabled(&self, col: &ColumnPath) -> bool {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.dictionary_enabled())
-            .or_else(|| self.default_column_properties.dictionary_enabled())
+        self.column_property(col, |c| c.dictionary_enabled())
             .unwrap_or(DEFAULT_DICTIONARY_ENABLED)
     }
 
@@ -393,10 +556,7 @@ impl WriterProperties {
     ///
     /// For more details see [`WriterPropertiesBuilder::set_statistics_enabled`]
     pub fn statistics_enabled(&self, col: &ColumnPath) -> EnabledStatistics {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.statistics_enabled())
-            .or_else(|| self.default_column_properties.statistics_enabled())
+        self.column_property(col, |c| c.statistics_enabled())
             .unwrap_or(DEFAULT_STATISTICS_ENABLED)
     }
 
@@ -406,13 +566,7 @@ impl WriterProperties {
     ///
     /// [`Statistics`]: crate::file::statistics::Statistics
     pub fn write_page_header_statistics(&self, col: &ColumnPath) -> bool {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.write_page_header_statistics())
-            .or_else(|| {
-                self.default_column_properties
-                    .write_page_header_statistics()
-            })
+        self.column_property(col, |c| c.write_page_header_statistics())
             .unwrap_or(DEFAULT_WRITE_PAGE_HEADER_STATISTICS)
     }
 
@@ -422,10 +576,7 @@ impl WriterProperties {
     ///
     /// For more details see [`WriterPropertiesBuilder::set_column_bloom_filter_enabled`]
     pub fn bloom_filter_properties(&self, col: &ColumnPath) -> Option<&BloomFilterProperties> {
-        self.column_properties
-            .get(col)
-            .and_then(|c| c.bloom_filter_properties())
-            .or_else(|| self.default_column_properties.bloom_filter_properties())
+        self.column_property(col, |c| c.bloom_filter_properties())
     }
 
     /// Return file encryption properties
@@ -435,6 +586,87 @@ impl WriterProperties {
     pub fn file_encryption_properties(&self) -> Option<&Arc<FileEncryptionProperties>> {
         self.file_encryption_properties.as_ref()
     }
+
+    /// Returns a property for a column, looking first at properties set for the
+    /// exact column path, then at matching column patterns, and finally at the
+    /// default column properties.
+    ///
+    /// When several patterns set the property, the most specific pattern wins,
+    /// see [`WriterPropertiesBuilder::set_column_pattern_compression`]
+    fn column_property<'a, T>(
+        &'a self,
+        col: &ColumnPath,
+        f: impl Fn(&'a ColumnProperties) -> Option<T>,
+    ) -> Option<T> {
+        if let Some(value) = self.column_properties.get(col).and_then(&f) {
+            return Some(value);
+        }
+
+        let mut best: Option<(usize, T)> = None;
+        for (pattern, props) in &self.column_pattern_properties {
+            // Patterns are stored in registration order, so `>=` ensures later
+            // patterns win ties
+            if best.as_ref().is_none_or(|(s, _)| pattern.specificity >= *s) && pattern.matches(col)
+            {
+                if let Some(value) = f(props) {
+                    best = Some((pattern.specificity, value));
+                }
+            }
+        }
+
+        match best {
+            Some((_, value)) => Some(value),
+            None => f(&self.default_column_properties),
+        }
+    }
+
+    /// Checks the [`Self::encoding`] and [`Self::fallback_encoding`] of each column in
+    /// `schema`, whether set for the column, a matching column pattern or by default,
+    /// against the physical type of the column.
+    ///
+    /// Returns an error if an encoding cannot be used for its column, rather than
+    /// failing once data is written.
+    pub(crate) fn validate_column_encodings(&self, schema: &SchemaDescriptor) -> Result<()> {
+        for column in schema.columns() {
+            let path = column.path();
+            for encoding in [self.encoding(path), self.fallback_encoding(path)]
+                .into_iter()
+                .flatten()
+            {
+                Self::validate_encoding(encoding, column)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_encoding(encoding: Encoding, column: &ColumnDescriptor) -> Result<()> {
+        let supported = match encoding {
+            Encoding::BYTE_STREAM_SPLIT => matches!(
+                column.physical_type(),
+                Type::FLOAT | Type::DOUBLE | Type::INT32 | Type::INT64 | Type::FIXED_LEN_BYTE_ARRAY
+            ),
+            Encoding::DELTA_BINARY_PACKED => {
+                matches!(column.physical_type(), Type::INT32 | Type::INT64)
+            }
+            Encoding::DELTA_LENGTH_BYTE_ARRAY | Encoding::DELTA_BYTE_ARRAY => {
+                matches!(
+                    column.physical_type(),
+                    Type::BYTE_ARRAY | Type::FIXED_LEN_BYTE_ARRAY
+                )
+            }
+            Encoding::RLE => column.physical_type() == Type::BOOLEAN,
+            _ => true,
+        };
+        if !supported {
+            return Err(general_err!(
+                "Encoding {} is not supported for column {} with physical type {}",
+                encoding,
+                column.path(),
+                column.physical_type()
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Builder for  [`WriterProperties`] Parquet writer configuration.
@@ -453,10 +685,18 @@ pub struct WriterPropertiesBuilder {
     key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    column_pattern_properties: Vec<(ColumnPattern, ColumnProperties)>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    int96_timestamps: bool,
+    decimal256_byte_width: Option<usize>,
+    fixed_len_byte_array_decimals: bool,
+    truncate_interval_nanoseconds: bool,
+    page_checksums_enabled: bool,
+    dictionary_distinct_count_enabled: bool,
+    auto_compression: bool,
     #[cfg(feature = "encryption")]
     file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
 }
@@ -476,10 +716,18 @@ impl Default for WriterPropertiesBuilder {
             key_value_metadata: None,
             default_column_properties: Default::default(),
             column_properties: HashMap::new(),
+            column_pattern_properties: Vec::new(),
             sorting_columns: None,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
+            int96_timestamps: DEFAULT_INT96_TIMESTAMPS,
+            decimal256_byte_width: DEFAULT_DECIMAL256_BYTE_WIDTH,
+            fixed_len_byte_array_decimals: DEFAULT_FIXED_LEN_BYTE_ARRAY_DECIMALS,
+            truncate_interval_nanoseconds: DEFAULT_TRUNCATE_INTERVAL_NANOSECONDS,
+            page_checksums_enabled: DEFAULT_PAGE_CHECKSUMS_ENABLED,
+            dictionary_distinct_count_enabled: DEFAULT_DICTIONARY_DISTINCT_COUNT_ENABLED,
+            auto_compression: DEFAULT_AUTO_COMPRESSION,
             #[cfg(feature = "encryption")]
             file_encryption_properties: None,
         }
@@ -501,10 +749,18 @@ impl WriterPropertiesBuilder {
             key_value_metadata: self.key_value_metadata,
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
+            column_pattern_properties: self.column_pattern_properties,
             sorting_columns: self.sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
+            int96_timestamps: self.int96_timestamps,
+            decimal256_byte_width: self.decimal256_byte_width,
+            fixed_len_byte_array_decimals: self.fixed_len_byte_array_decimals,
+            truncate_interval_nanoseconds: self.truncate_interval_nanoseconds,
+            page_checksums_enabled: self.page_checksums_enabled,
+            dictionary_distinct_count_enabled: self.dictionary_distinct_count_enabled,
+            auto_compression: self.auto_compression,
             #[cfg(feature = "encryption")]
             file_encryption_properties: self.file_encryption_properties,
         }
@@ -516,14 +772,49 @@ impl WriterPropertiesBuilder {
     /// Sets the `WriterVersion` written into the parquet metadata (defaults to [`PARQUET_1_0`]
     /// via [`DEFAULT_WRITER_VERSION`])
     ///
-    /// This value can determine what features some readers will support.
+    /// This value can determine what features some readers will support. It also
+    /// determines the format of the data pages written:
+    ///
+    /// * [`PARQUET_1_0`] writes `DATA_PAGE` headers, and uses `PLAIN` as the
+    ///   default encoding when dictionary encoding is disabled or falls back
+    /// * [`PARQUET_2_0`] writes `DATA_PAGE_V2` headers, and uses the `DELTA_*`
+    ///   encodings by default for integer and byte array columns
     ///
-    /// [`PARQUET_1_0`]: [WriterVersion::PARQUET_1_0]
+    /// Encodings set explicitly, such as with [`Self::set_encoding`], take precedence.
+    ///
+    /// [`PARQUET_1_0`]: WriterVersion::PARQUET_1_0
+    /// [`PARQUET_2_0`]: WriterVersion::PARQUET_2_0
     pub fn set_writer_version(mut self, value: WriterVersion) -> Self {
         self.writer_version = value;
         self
     }
 
+    /// Configures the writer to produce files readable by another Parquet implementation
+    ///
+    /// This sets the options described on [`WriterCompatibility`], which can be
+    /// overridden by calling the corresponding setters afterwards.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet::file::properties::{WriterCompatibility, WriterProperties, WriterVersion};
+    /// let props = WriterProperties::builder()
+    ///     .set_compatibility(WriterCompatibility::Hive)
+    ///     .build();
+    /// assert_eq!(props.writer_version(), WriterVersion::PARQUET_1_0);
+    /// assert!(props.int96_timestamps());
+    /// assert!(props.fixed_len_byte_array_decimals());
+    /// ```
+    pub fn set_compatibility(self, target: WriterCompatibility) -> Self {
+        let builder = self
+            .set_writer_version(WriterVersion::PARQUET_1_0)
+            .set_int96_timestamps(true)
+            .set_coerce_types(true);
+        match target {
+            WriterCompatibility::Spark => builder.set_fixed_len_byte_array_decimals(false),
+            WriterCompatibility::Hive => builder.set_fixed_len_byte_array_decimals(true),
+        }
+    }
+
     /// Sets best effort maximum size of a data page in bytes (defaults to `1024 * 1024`
     /// via [`DEFAULT_PAGE_SIZE`]).
     ///
@@ -539,16 +830,17 @@ impl WriterPropertiesBuilder {
         self
     }
 
-    /// Sets best effort maximum number of rows in a data page (defaults to `20_000`
+    /// Sets maximum number of rows in a data page (defaults to `20_000`
     /// via [`DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT`]).
     ///
-    /// The parquet writer will attempt to limit the number of rows in
-    /// each `DataPage` to this value. Reducing this value will result
-    /// in larger parquet files, but may improve the effectiveness of
-    /// page index based predicate pushdown during reading.
+    /// The parquet writer will limit the number of rows in each `DataPage`
+    /// to this value. Reducing this value will result in larger parquet
+    /// files, but may improve the effectiveness of page index based
+    /// predicate pushdown during reading.
     ///
-    /// Note: this is a best effort limit based on value of
-    /// [`set_write_batch_size`](Self::set_write_batch_size).
+    /// For repeated columns rows are counted as top-level records, rather
+    /// than leaf values, and a record is never split across pages. A single
+    /// record may therefore produce a page containing many values.
     pub fn set_data_page_row_count_limit(mut self, value: usize) -> Self {
         self.data_page_row_count_limit = value;
         self
@@ -664,6 +956,12 @@ impl WriterPropertiesBuilder {
     /// * If `Some`, must be greater than 0, otherwise will panic
     /// * If `None`, there's no effective limit.
     ///
+    /// Truncated values remain valid bounds: the minimum is truncated to a prefix,
+    /// and the maximum is truncated and then incremented, so that it still compares
+    /// greater than or equal to all values. For UTF-8 columns, truncation does not
+    /// split characters. A maximum that can not be incremented, such as a value of
+    /// only `0xFF` bytes, is written untruncated.
+    ///
     /// # See also
     /// Truncation of Page Index statistics is controlled separately via
     /// [`WriterPropertiesBuilder::set_column_index_truncate_length`]
@@ -705,6 +1003,133 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Should Arrow timestamps be written using the deprecated INT96 physical type
+    /// (defaults to `false` via [`DEFAULT_INT96_TIMESTAMPS`]).
+    ///
+    /// Some legacy readers, such as older versions of Hive and Impala, only
+    /// understand timestamps stored as INT96. Enabling this option stores all
+    /// timestamp columns as the Julian day and nanoseconds of the day of the
+    /// UTC instant, regardless of their unit or timezone.
+    ///
+    /// See [`ArrowSchemaConverter::with_int96_timestamps`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_int96_timestamps`]: crate::arrow::ArrowSchemaConverter::with_int96_timestamps
+    pub fn set_int96_timestamps(mut self, int96_timestamps: bool) -> Self {
+        self.int96_timestamps = int96_timestamps;
+        self
+    }
+
+    /// Sets the byte width of the `FIXED_LEN_BYTE_ARRAY` used to store Arrow
+    /// `Decimal256` columns (defaults to `None` via [`DEFAULT_DECIMAL256_BYTE_WIDTH`]).
+    ///
+    /// By default, the minimal number of bytes able to hold the declared precision
+    /// is used, e.g. 17 bytes for a precision of 39. Some readers expect a specific
+    /// width, such as the full 32 bytes of the Arrow representation, which can be
+    /// forced with this option.
+    ///
+    /// See [`ArrowSchemaConverter::with_decimal256_byte_width`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_decimal256_byte_width`]: crate::arrow::ArrowSchemaConverter::with_decimal256_byte_width
+    pub fn set_decimal256_byte_width(mut self, byte_width: Option<usize>) -> Self {
+        self.decimal256_byte_width = byte_width;
+        self
+    }
+
+    /// Should all Arrow decimals be written as `FIXED_LEN_BYTE_ARRAY` (defaults to
+    /// `false` via [`DEFAULT_FIXED_LEN_BYTE_ARRAY_DECIMALS`]).
+    ///
+    /// By default, decimals with a precision of 18 or less are written as `INT32`
+    /// or `INT64`. Some legacy readers, such as older versions of Hive and Impala,
+    /// only understand decimals stored as `FIXED_LEN_BYTE_ARRAY`.
+    ///
+    /// See [`ArrowSchemaConverter::with_fixed_len_byte_array_decimals`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_fixed_len_byte_array_decimals`]: crate::arrow::ArrowSchemaConverter::with_fixed_len_byte_array_decimals
+    pub fn set_fixed_len_byte_array_decimals(mut self, value: bool) -> Self {
+        self.fixed_len_byte_array_decimals = value;
+        self
+    }
+
+    /// Should Arrow `MonthDayNano` intervals with sub-millisecond precision be
+    /// truncated to milliseconds when written (defaults to `false` via
+    /// [`DEFAULT_TRUNCATE_INTERVAL_NANOSECONDS`]).
+    ///
+    /// The parquet `INTERVAL` type stores months, days and milliseconds, and so
+    /// `MonthDayNano` intervals are written with their nanoseconds converted to
+    /// milliseconds. By default, writing a value that is not a whole number of
+    /// milliseconds returns an error. Enabling this option instead truncates such
+    /// values towards zero, losing the sub-millisecond component.
+    ///
+    /// Values whose milliseconds do not fit in 32 bits always return an error.
+    pub fn set_truncate_interval_nanoseconds(mut self, truncate: bool) -> Self {
+        self.truncate_interval_nanoseconds = truncate;
+        self
+    }
+
+    /// Sets whether to write a CRC32 checksum in the header of each page
+    /// (defaults to `false` via [`DEFAULT_PAGE_CHECKSUMS_ENABLED`]).
+    ///
+    /// The checksum covers the page data as compressed, and applies to data
+    /// pages (v1 and v2) as well as dictionary pages. Readers can use it to
+    /// detect corruption, see [`ReaderPropertiesBuilder::set_verify_page_checksums`].
+    #[cfg(feature = "crc")]
+    pub fn set_page_checksums_enabled(mut self, enabled: bool) -> Self {
+        self.page_checksums_enabled = enabled;
+        self
+    }
+
+    /// Sets whether to write the distinct count of fully dictionary encoded
+    /// column chunks to their statistics (defaults to `false` via
+    /// [`DEFAULT_DICTIONARY_DISTINCT_COUNT_ENABLED`]).
+    ///
+    /// When a column chunk is written entirely with dictionary encoding, i.e.
+    /// the dictionary did not fall back to another encoding, the number of
+    /// dictionary entries is the number of distinct non-null values in the
+    /// chunk. Values are compared by their physical representation, so e.g.
+    /// `0.0` and `-0.0` are counted as distinct.
+    ///
+    /// Requires statistics to be enabled for the column, see
+    /// [`Self::set_statistics_enabled`]. A distinct count provided to
+    /// [`ColumnWriterImpl::write_batch_with_statistics`] takes precedence.
+    ///
+    /// [`ColumnWriterImpl::write_batch_with_statistics`]: crate::column::writer::ColumnWriterImpl::write_batch_with_statistics
+    pub fn set_dictionary_distinct_count_enabled(mut self, enabled: bool) -> Self {
+        self.dictionary_distinct_count_enabled = enabled;
+        self
+    }
+
+    /// Sets whether to choose a compression codec for each column from its
+    /// physical type (defaults to `false` via [`DEFAULT_AUTO_COMPRESSION`]).
+    ///
+    /// This only applies to columns without a codec set by
+    /// [`Self::set_compression`], [`Self::set_column_compression`] or
+    /// [`Self::set_column_pattern_compression`]. The choice is deterministic, and
+    /// depends only on the column's physical type and the codecs enabled at
+    /// compile time:
+    ///
+    /// * `BYTE_ARRAY` and `FIXED_LEN_BYTE_ARRAY` columns, such as strings,
+    ///   binary and decimals, use [`Compression::ZSTD`] with the default level.
+    ///   These typically compress well, and are dictionary encoded by default.
+    /// * `INT32`, `INT64`, `INT96`, `FLOAT` and `DOUBLE` columns use
+    ///   [`Compression::SNAPPY`], which is cheap to decode.
+    /// * `BOOLEAN` columns are bit-packed and left [`Compression::UNCOMPRESSED`].
+    ///
+    /// If the `zstd` feature is disabled [`Compression::SNAPPY`] is used instead,
+    /// and if the `snap` feature is disabled [`Compression::UNCOMPRESSED`] is used.
+    ///
+    /// Additionally, `INT32` and `INT64` columns without an encoding set, such as
+    /// by [`Self::set_encoding`] or [`Self::set_column_encoding`], use
+    /// [`Encoding::DELTA_BINARY_PACKED`] for data pages that are not dictionary
+    /// encoded, for both writer versions. This stores sorted and slowly changing
+    /// integers, such as identifiers and timestamps, compactly.
+    ///
+    /// Use [`WriterProperties::column_compression`] to get the codec chosen for a
+    /// column.
+    pub fn set_auto_compression(mut self, enabled: bool) -> Self {
+        self.auto_compression = enabled;
+        self
+    }
+
     /// Sets FileEncryptionProperties (defaults to `None`)
     #[cfg(feature = "encryption")]
     pub fn with_file_encryption_properties(
@@ -769,6 +1194,38 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the maximum length in bytes of a value that may be dictionary encoded
+    /// for all columns (defaults to no limit).
+    ///
+    /// Dictionary encoding long values, which are rarely repeated, only adds
+    /// overhead. If a value longer than this is written to a column chunk that is
+    /// being dictionary encoded, the column chunk immediately falls back to the
+    /// non-dictionary encoding, as if the dictionary page size limit had been
+    /// reached, see [`Self::set_dictionary_page_size_limit`].
+    ///
+    /// Note: this currently only applies to `BYTE_ARRAY` columns written from
+    /// arrow arrays, for example by [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter).
+    pub fn set_dictionary_max_value_length(mut self, value: usize) -> Self {
+        self.default_column_properties
+            .set_dictionary_max_value_length(value);
+        self
+    }
+
+    /// Sets whether to deduplicate the views of `Utf8View` and `BinaryView` arrays
+    /// before dictionary encoding them for all columns (defaults to `false` via
+    /// [`DEFAULT_DICTIONARY_DEDUPLICATE_VIEWS`]).
+    ///
+    /// Equal views always refer to equal values, so values whose views have
+    /// already been seen in an array are dictionary encoded without hashing the
+    /// value bytes again. This is cheap for arrays where many views refer to the
+    /// same data, such as those produced by the `take` or `filter` kernels, and
+    /// avoids hashing long values repeatedly.
+    pub fn set_dictionary_deduplicate_views(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_dictionary_deduplicate_views(value);
+        self
+    }
+
     /// Sets default [`EnabledStatistics`] level for all columns (defaults to [`Page`] via
     /// [`DEFAULT_STATISTICS_ENABLED`]).
     ///
@@ -865,6 +1322,17 @@ impl WriterPropertiesBuilder {
     /// global defaults or explicitly, this value is considered to be a fallback
     /// encoding for this column.
     ///
+    /// To always write data pages with this encoding, disable dictionary encoding for
+    /// the column with [`Self::set_column_dictionary_enabled`]. For example, strictly
+    /// increasing integer columns are typically much smaller with
+    /// [`Encoding::DELTA_BINARY_PACKED`] than with either dictionary or plain encoding.
+    ///
+    /// [`Encoding::BYTE_STREAM_SPLIT`] is supported for `FLOAT`, `DOUBLE`, `INT32`,
+    /// `INT64` and `FIXED_LEN_BYTE_ARRAY` columns, and
+    /// [`Encoding::DELTA_BINARY_PACKED`] for `INT32` and `INT64` columns. Creating a
+    /// file writer for a schema where this column has any other physical type returns
+    /// an error.
+    ///
     /// # Panics
     /// If user tries to set dictionary encoding here, regardless of dictionary
     /// encoding flag being set.
@@ -873,6 +1341,28 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the encoding for a specific column to use once its dictionary page size limit,
+    /// see [`Self::set_column_dictionary_page_size_limit`], has been reached.
+    ///
+    /// Unlike [`Self::set_column_encoding`], this only applies when dictionary encoding is
+    /// enabled for the column, and takes precedence over [`Self::set_column_encoding`] and
+    /// [`Self::set_encoding`] in that case. This allows, for example, a column of URLs to
+    /// fall back to [`Encoding::DELTA_BYTE_ARRAY`] whilst other columns use
+    /// [`Encoding::PLAIN`].
+    ///
+    /// The same physical type restrictions as for [`Self::set_column_encoding`] apply,
+    /// additionally [`Encoding::DELTA_LENGTH_BYTE_ARRAY`] and [`Encoding::DELTA_BYTE_ARRAY`]
+    /// are only supported for `BYTE_ARRAY` and `FIXED_LEN_BYTE_ARRAY` columns. Creating a
+    /// file writer for a schema where this column has any other physical type returns
+    /// an error.
+    ///
+    /// # Panics
+    /// If user tries to set dictionary encoding here.
+    pub fn set_column_fallback_encoding(mut self, col: ColumnPath, value: Encoding) -> Self {
+        self.get_mut_props(col).set_fallback_encoding(value);
+        self
+    }
+
     /// Sets compression codec for a specific column.
     ///
     /// Takes precedence over [`Self::set_compression`].
@@ -898,6 +1388,24 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the maximum length of a dictionary encoded value for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_dictionary_max_value_length`].
+    pub fn set_column_dictionary_max_value_length(mut self, col: ColumnPath, value: usize) -> Self {
+        self.get_mut_props(col)
+            .set_dictionary_max_value_length(value);
+        self
+    }
+
+    /// Sets whether to deduplicate views before dictionary encoding a specific column.
+    ///
+    /// Takes precedence over [`Self::set_dictionary_deduplicate_views`].
+    pub fn set_column_dictionary_deduplicate_views(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col)
+            .set_dictionary_deduplicate_views(value);
+        self
+    }
+
     /// Sets [`EnabledStatistics`] level for a specific column.
     ///
     /// Takes precedence over [`Self::set_statistics_enabled`].
@@ -944,6 +1452,128 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_bloom_filter_ndv(value);
         self
     }
+
+    // ----------------------------------------------------------------------
+    // Setters for columns matching a pattern
+
+    /// Helper method to get existing or new mutable reference of column pattern properties.
+    fn get_mut_pattern_props(&mut self, pattern: ColumnPattern) -> &mut ColumnProperties {
+        let idx = match self
+            .column_pattern_properties
+            .iter()
+            .position(|(p, _)| p.pattern == pattern.pattern)
+        {
+            Some(idx) => idx,
+            None => {
+                self.column_pattern_properties
+                    .push((pattern, Default::default()));
+                self.column_pattern_properties.len() - 1
+            }
+        };
+        &mut self.column_pattern_properties[idx].1
+    }
+
+    /// Sets encoding for all columns matching `pattern`.
+    ///
+    /// See [`Self::set_column_pattern_compression`] for the pattern syntax and
+    /// precedence, and [`Self::set_column_encoding`] for details on the encoding.
+    ///
+    /// # Panics
+    /// If user tries to set dictionary encoding here, regardless of dictionary
+    /// encoding flag being set.
+    pub fn set_column_pattern_encoding(mut self, pattern: ColumnPattern, value: Encoding) -> Self {
+        self.get_mut_pattern_props(pattern).set_encoding(value);
+        self
+    }
+
+    /// Sets compression codec for all columns matching `pattern`.
+    ///
+    /// See [`ColumnPattern`] for the pattern syntax.
+    ///
+    /// Properties set for an exact column, such as via [`Self::set_column_compression`],
+    /// take precedence over patterns, which in turn take precedence over the
+    /// defaults, such as [`Self::set_compression`]. If several patterns match a
+    /// column, the one with the most non-wildcard characters is used, with ties
+    /// resolved in favor of the pattern registered last.
+    ///
+    /// ```
+    /// # use parquet::basic::{Compression, ZstdLevel};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # use parquet::schema::types::ColumnPath;
+    /// let props = WriterProperties::builder()
+    ///     .set_column_pattern_compression("metrics.*.value".parse()?, Compression::ZSTD(ZstdLevel::default()))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     props.compression(&ColumnPath::from("metrics.cpu.value")),
+    ///     Compression::ZSTD(ZstdLevel::default())
+    /// );
+    /// assert_eq!(
+    ///     props.compression(&ColumnPath::from("metrics.cpu.name")),
+    ///     Compression::UNCOMPRESSED
+    /// );
+    /// # Ok::<(), parquet::errors::ParquetError>(())
+    /// ```
+    pub fn set_column_pattern_compression(
+        mut self,
+        pattern: ColumnPattern,
+        value: Compression,
+    ) -> Self {
+        self.get_mut_pattern_props(pattern).set_compression(value);
+        self
+    }
+
+    /// Sets flag to enable/disable dictionary encoding for all columns matching `pattern`.
+    ///
+    /// See [`Self::set_column_pattern_compression`] for the pattern syntax and precedence.
+    pub fn set_column_pattern_dictionary_enabled(
+        mut self,
+        pattern: ColumnPattern,
+        value: bool,
+    ) -> Self {
+        self.get_mut_pattern_props(pattern)
+            .set_dictionary_enabled(value);
+        self
+    }
+
+    /// Sets dictionary page size limit for all columns matching `pattern`.
+    ///
+    /// See [`Self::set_column_pattern_compression`] for the pattern syntax and precedence.
+    pub fn set_column_pattern_dictionary_page_size_limit(
+        mut self,
+        pattern: ColumnPattern,
+        value: usize,
+    ) -> Self {
+        self.get_mut_pattern_props(pattern)
+            .set_dictionary_page_size_limit(value);
+        self
+    }
+
+    /// Sets [`EnabledStatistics`] level for all columns matching `pattern`.
+    ///
+    /// See [`Self::set_column_pattern_compression`] for the pattern syntax and precedence.
+    pub fn set_column_pattern_statistics_enabled(
+        mut self,
+        pattern: ColumnPattern,
+        value: EnabledStatistics,
+    ) -> Self {
+        self.get_mut_pattern_props(pattern)
+            .set_statistics_enabled(value);
+        self
+    }
+
+    /// Sets whether a bloom filter should be written for all columns matching `pattern`.
+    ///
+    /// See [`Self::set_column_pattern_compression`] for the pattern syntax and precedence.
+    pub fn set_column_pattern_bloom_filter_enabled(
+        mut self,
+        pattern: ColumnPattern,
+        value: bool,
+    ) -> Self {
+        self.get_mut_pattern_props(pattern)
+            .set_bloom_filter_enabled(value);
+        self
+    }
 }
 
 impl From<WriterProperties> for WriterPropertiesBuilder {
@@ -960,10 +1590,18 @@ impl From<WriterProperties> for WriterPropertiesBuilder {
             key_value_metadata: props.key_value_metadata,
             default_column_properties: props.default_column_properties,
             column_properties: props.column_properties,
+            column_pattern_properties: props.column_pattern_properties,
             sorting_columns: props.sorting_columns,
             column_index_truncate_length: props.column_index_truncate_length,
             statistics_truncate_length: props.statistics_truncate_length,
             coerce_types: props.coerce_types,
+            int96_timestamps: props.int96_timestamps,
+            decimal256_byte_width: props.decimal256_byte_width,
+            fixed_len_byte_array_decimals: props.fixed_len_byte_array_decimals,
+            truncate_interval_nanoseconds: props.truncate_interval_nanoseconds,
+            page_checksums_enabled: props.page_checksums_enabled,
+            dictionary_distinct_count_enabled: props.dictionary_distinct_count_enabled,
+            auto_compression: props.auto_compression,
             #[cfg(feature = "encryption")]
             file_encryption_properties: props.file_encryption_properties,
         }
@@ -1057,6 +1695,119 @@ impl Default for BloomFilterProperties {
     }
 }
 
+/// A pattern matching [`ColumnPath`]s, used to set properties for several
+/// columns at once, see [`WriterPropertiesBuilder::set_column_pattern_compression`]
+///
+/// A pattern is a `.` separated column path where each component is matched
+/// against the corresponding component of the column path:
+///
+/// * `*` within a component matches any sequence of characters, for
+///   example `metric_*` matches `metric_a` and `metric_b`
+/// * `**` as a whole component matches any number of components, including none
+///
+/// For example, `metrics.*.value` matches `metrics.cpu.value` but not
+/// `metrics.cpu.total.value`, whereas `metrics.**.value` matches both. Note that
+/// nested types include their physical structure in the column path, for
+/// example the values of a list column `a` have a path such as `a.list.element`.
+///
+/// Patterns are validated when parsed:
+///
+/// ```
+/// # use parquet::file::properties::ColumnPattern;
+/// assert!("metrics.**.value".parse::<ColumnPattern>().is_ok());
+/// assert!("metrics..value".parse::<ColumnPattern>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnPattern {
+    /// The pattern as provided by the user
+    pattern: String,
+    components: Vec<PatternComponent>,
+    /// The number of non-wildcard characters, used to pick between overlapping patterns
+    specificity: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PatternComponent {
+    /// `**`, matches any number of path components
+    AnyComponents,
+    /// A path component, split on `*` wildcards
+    Component(Vec<String>),
+}
+
+impl FromStr for ColumnPattern {
+    type Err = ParquetError;
+
+    fn from_str(pattern: &str) -> Result<Self> {
+        let components = pattern
+            .split('.')
+            .map(|component| match component {
+                "" => Err(general_err!(
+                    "Invalid column pattern '{}': empty path component",
+                    pattern
+                )),
+                "**" => Ok(PatternComponent::AnyComponents),
+                c if c.contains("**") => Err(general_err!(
+                    "Invalid column pattern '{}': '**' must be a whole path component",
+                    pattern
+                )),
+                c => Ok(PatternComponent::Component(
+                    c.split('*').map(String::from).collect(),
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let specificity = pattern.chars().filter(|c| *c != '*').count();
+        Ok(Self {
+            pattern: pattern.to_string(),
+            components,
+            specificity,
+        })
+    }
+}
+
+impl ColumnPattern {
+    fn matches(&self, col: &ColumnPath) -> bool {
+        // Split on `.` so that paths such as `ColumnPath::from("a.b")` match
+        // in the same way as those with multiple parts
+        let parts: Vec<_> = col.parts().iter().flat_map(|p| p.split('.')).collect();
+        Self::matches_components(&self.components, &parts)
+    }
+
+    fn matches_components(components: &[PatternComponent], parts: &[&str]) -> bool {
+        match components.split_first() {
+            None => parts.is_empty(),
+            Some((PatternComponent::AnyComponents, rest)) => {
+                (0..=parts.len()).any(|skip| Self::matches_components(rest, &parts[skip..]))
+            }
+            Some((PatternComponent::Component(literals), rest)) => match parts.split_first() {
+                Some((part, remaining)) => {
+                    glob_match(literals, part) && Self::matches_components(rest, remaining)
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+/// Returns true if `s` matches the `*` separated `literals` of a glob
+fn glob_match(literals: &[String], s: &str) -> bool {
+    let (first, rest) = literals.split_first().expect("split yields at least one");
+    let Some(mut remaining) = s.strip_prefix(first.as_str()) else {
+        return false;
+    };
+    let Some((last, middle)) = rest.split_last() else {
+        // no wildcard
+        return remaining.is_empty();
+    };
+    for literal in middle {
+        match remaining.find(literal.as_str()) {
+            Some(idx) => remaining = &remaining[idx + literal.len()..],
+            None => return false,
+        }
+    }
+    remaining.ends_with(last.as_str())
+}
+
 /// Container for column properties that can be changed as part of writer.
 ///
 /// If a field is `None`, it means that no specific value has been set for this column,
@@ -1064,8 +1815,11 @@ impl Default for BloomFilterProperties {
 #[derive(Debug, Clone, Default, PartialEq)]
 struct ColumnProperties {
     encoding: Option<Encoding>,
+    fallback_encoding: Option<Encoding>,
     codec: Option<Compression>,
     dictionary_page_size_limit: Option<usize>,
+    dictionary_max_value_length: Option<usize>,
+    dictionary_deduplicate_views: Option<bool>,
     dictionary_enabled: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
     write_page_header_statistics: Option<bool>,
@@ -1090,6 +1844,16 @@ impl ColumnProperties {
         self.encoding = Some(value);
     }
 
+    /// Sets the encoding to use for this column once dictionary encoding falls back.
+    ///
+    /// Panics if user tries to set dictionary encoding here.
+    fn set_fallback_encoding(&mut self, value: Encoding) {
+        if value == Encoding::PLAIN_DICTIONARY || value == Encoding::RLE_DICTIONARY {
+            panic!("Dictionary encoding can not be used as fallback encoding");
+        }
+        self.fallback_encoding = Some(value);
+    }
+
     /// Sets compression codec for this column.
     fn set_compression(&mut self, value: Compression) {
         self.codec = Some(value);
@@ -1105,6 +1869,16 @@ impl ColumnProperties {
         self.dictionary_page_size_limit = Some(value);
     }
 
+    /// Sets the maximum length of a dictionary encoded value for this column.
+    fn set_dictionary_max_value_length(&mut self, value: usize) {
+        self.dictionary_max_value_length = Some(value);
+    }
+
+    /// Sets whether views are deduplicated before dictionary encoding this column.
+    fn set_dictionary_deduplicate_views(&mut self, value: bool) {
+        self.dictionary_deduplicate_views = Some(value);
+    }
+
     /// Sets the statistics level for this column.
     fn set_statistics_enabled(&mut self, enabled: EnabledStatistics) {
         self.statistics_enabled = Some(enabled);
@@ -1156,6 +1930,11 @@ impl ColumnProperties {
         self.encoding
     }
 
+    /// Returns optional fallback encoding for this column.
+    fn fallback_encoding(&self) -> Option<Encoding> {
+        self.fallback_encoding
+    }
+
     /// Returns optional compression codec for this column.
     fn compression(&self) -> Option<Compression> {
         self.codec
@@ -1173,6 +1952,17 @@ impl ColumnProperties {
         self.dictionary_page_size_limit
     }
 
+    /// Returns optional maximum length of a dictionary encoded value for this column.
+    fn dictionary_max_value_length(&self) -> Option<usize> {
+        self.dictionary_max_value_length
+    }
+
+    /// Returns `Some(true)` if views are deduplicated before dictionary encoding this
+    /// column. If result is `None`, then no setting has been provided.
+    fn dictionary_deduplicate_views(&self) -> Option<bool> {
+        self.dictionary_deduplicate_views
+    }
+
     /// Returns optional statistics level requested for this column. If result is `None`,
     /// then no setting has been provided.
     fn statistics_enabled(&self) -> Option<EnabledStatistics> {
@@ -1198,6 +1988,8 @@ pub type ReaderPropertiesPtr = Arc<ReaderProperties>;
 
 const DEFAULT_READ_BLOOM_FILTER: bool = false;
 const DEFAULT_READ_PAGE_STATS: bool = false;
+#[cfg(feature = "crc")]
+const DEFAULT_VERIFY_PAGE_CHECKSUMS: bool = true;
 
 /// Configuration settings for reading parquet files.
 ///
@@ -1221,6 +2013,8 @@ pub struct ReaderProperties {
     codec_options: CodecOptions,
     read_bloom_filter: bool,
     read_page_stats: bool,
+    #[cfg(feature = "crc")]
+    verify_page_checksums: bool,
 }
 
 impl ReaderProperties {
@@ -1243,6 +2037,12 @@ impl ReaderProperties {
     pub(crate) fn read_page_stats(&self) -> bool {
         self.read_page_stats
     }
+
+    /// Returns whether to verify the CRC32 checksums of pages
+    #[cfg(feature = "crc")]
+    pub(crate) fn verify_page_checksums(&self) -> bool {
+        self.verify_page_checksums
+    }
 }
 
 /// Builder for parquet file reader configuration. See example on
@@ -1251,6 +2051,8 @@ pub struct ReaderPropertiesBuilder {
     codec_options_builder: CodecOptionsBuilder,
     read_bloom_filter: Option<bool>,
     read_page_stats: Option<bool>,
+    #[cfg(feature = "crc")]
+    verify_page_checksums: Option<bool>,
 }
 
 /// Reader properties builder.
@@ -1261,6 +2063,8 @@ impl ReaderPropertiesBuilder {
             codec_options_builder: CodecOptionsBuilder::default(),
             read_bloom_filter: None,
             read_page_stats: None,
+            #[cfg(feature = "crc")]
+            verify_page_checksums: None,
         }
     }
 
@@ -1270,6 +2074,10 @@ impl ReaderPropertiesBuilder {
             codec_options: self.codec_options_builder.build(),
             read_bloom_filter: self.read_bloom_filter.unwrap_or(DEFAULT_READ_BLOOM_FILTER),
             read_page_stats: self.read_page_stats.unwrap_or(DEFAULT_READ_PAGE_STATS),
+            #[cfg(feature = "crc")]
+            verify_page_checksums: self
+                .verify_page_checksums
+                .unwrap_or(DEFAULT_VERIFY_PAGE_CHECKSUMS),
         }
     }
 
@@ -1312,11 +2120,25 @@ impl ReaderPropertiesBuilder {
         self.read_page_stats = Some(value);
         self
     }
+
+    /// Enable/disable verifying the CRC32 checksum of each page
+    ///
+    /// If set to `true`, then the reader will compute the checksum of every page
+    /// whose header contains one, and return an error identifying the column and
+    /// page if it does not match. Pages without a checksum are not verified.
+    ///
+    /// By default checksums are verified.
+    #[cfg(feature = "crc")]
+    pub fn set_verify_page_checksums(mut self, value: bool) -> Self {
+        self.verify_page_checksums = Some(value);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schema::parser::parse_message_type;
 
     #[test]
     fn test_writer_version() {
@@ -1356,6 +2178,30 @@ mod tests {
                 .bloom_filter_properties(&ColumnPath::from("col"))
                 .is_none()
         );
+        assert_eq!(
+            props.dictionary_distinct_count_enabled(),
+            DEFAULT_DICTIONARY_DISTINCT_COUNT_ENABLED
+        );
+        assert_eq!(props.auto_compression(), DEFAULT_AUTO_COMPRESSION);
+    }
+
+    #[test]
+    #[cfg(all(feature = "snap", feature = "zstd"))]
+    fn test_auto_compression() {
+        let zstd = Compression::ZSTD(Default::default());
+        let cases = [
+            (Type::BOOLEAN, Compression::UNCOMPRESSED),
+            (Type::INT32, Compression::SNAPPY),
+            (Type::INT64, Compression::SNAPPY),
+            (Type::INT96, Compression::SNAPPY),
+            (Type::FLOAT, Compression::SNAPPY),
+            (Type::DOUBLE, Compression::SNAPPY),
+            (Type::BYTE_ARRAY, zstd),
+            (Type::FIXED_LEN_BYTE_ARRAY, zstd),
+        ];
+        for (physical_type, expected) in cases {
+            assert_eq!(auto_compression(physical_type), expected);
+        }
     }
 
     #[test]
@@ -1554,6 +2400,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_column_fallback_encoding() {
+        let props = WriterProperties::builder()
+            .set_encoding(Encoding::DELTA_BINARY_PACKED)
+            .set_column_fallback_encoding(ColumnPath::from("col"), Encoding::PLAIN)
+            .build();
+
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        assert_eq!(props.fallback_encoding(&col), Some(Encoding::PLAIN));
+        assert_eq!(props.fallback_encoding(&other), None);
+        assert_eq!(props.value_encoding(&col, true), Some(Encoding::PLAIN));
+        assert_eq!(
+            props.value_encoding(&col, false),
+            Some(Encoding::DELTA_BINARY_PACKED)
+        );
+        assert_eq!(
+            props.value_encoding(&other, true),
+            Some(Encoding::DELTA_BINARY_PACKED)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dictionary encoding can not be used as fallback encoding")]
+    fn test_writer_properties_dictionary_fallback_encoding() {
+        WriterProperties::builder()
+            .set_column_fallback_encoding(ColumnPath::from("col"), Encoding::RLE_DICTIONARY);
+    }
+
     #[test]
     fn test_writer_properties_column_dictionary_page_size_limit() {
         let props = WriterProperties::builder()
@@ -1572,6 +2447,197 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_dictionary_value_options() {
+        let props = WriterProperties::builder()
+            .set_dictionary_max_value_length(64)
+            .set_column_dictionary_max_value_length(ColumnPath::from("col"), 16)
+            .set_column_dictionary_deduplicate_views(ColumnPath::from("col"), true)
+            .build();
+
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        assert_eq!(props.dictionary_max_value_length(&col), Some(16));
+        assert_eq!(props.dictionary_max_value_length(&other), Some(64));
+        assert!(props.dictionary_deduplicate_views(&col));
+        assert!(!props.dictionary_deduplicate_views(&other));
+
+        let props = WriterProperties::default();
+        assert_eq!(props.dictionary_max_value_length(&col), None);
+        assert_eq!(
+            props.dictionary_deduplicate_views(&col),
+            DEFAULT_DICTIONARY_DEDUPLICATE_VIEWS
+        );
+    }
+
+    #[test]
+    fn test_writer_properties_column_patterns() {
+        let zstd = Compression::ZSTD(Default::default());
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_column_pattern_compression("metrics.*.value".parse().unwrap(), zstd)
+            .set_column_pattern_compression("metrics.**".parse().unwrap(), Compression::LZ4_RAW)
+            .set_column_compression(
+                ColumnPath::from("metrics.cpu.value"),
+                Compression::GZIP(Default::default()),
+            )
+            .set_column_pattern_dictionary_enabled("metrics.**".parse().unwrap(), false)
+            .set_column_pattern_encoding(
+                "tags.list.element".parse().unwrap(),
+                Encoding::DELTA_BYTE_ARRAY,
+            )
+            .set_column_pattern_statistics_enabled(
+                "map_*.key_value.key".parse().unwrap(),
+                EnabledStatistics::Page,
+            )
+            .build();
+
+        let compression = |path: &str| props.compression(&ColumnPath::from(path));
+        let dictionary = |path: &str| props.dictionary_enabled(&ColumnPath::from(path));
+
+        // exact path > longest pattern > shorter pattern > default
+        assert_eq!(
+            compression("metrics.cpu.value"),
+            Compression::GZIP(Default::default())
+        );
+        assert_eq!(compression("metrics.mem.value"), zstd);
+        assert_eq!(compression("metrics.mem.total"), Compression::LZ4_RAW);
+        assert_eq!(compression("metrics.mem.total.value"), Compression::LZ4_RAW);
+        assert_eq!(compression("other"), Compression::SNAPPY);
+
+        // properties not set by the most specific pattern fall back to other patterns
+        assert!(!dictionary("metrics.cpu.value"));
+        assert!(!dictionary("metrics.mem.value"));
+        assert!(dictionary("other"));
+
+        // nested list and map path components
+        assert_eq!(
+            props.encoding(&ColumnPath::from(vec![
+                "tags".to_string(),
+                "list".to_string(),
+                "element".to_string()
+            ])),
+            Some(Encoding::DELTA_BYTE_ARRAY)
+        );
+        assert_eq!(props.encoding(&ColumnPath::from("tags")), None);
+        assert_eq!(
+            props.statistics_enabled(&ColumnPath::from("map_a.key_value.key")),
+            EnabledStatistics::Page
+        );
+        assert_eq!(
+            props.statistics_enabled(&ColumnPath::from("map_a.key_value.value")),
+            DEFAULT_STATISTICS_ENABLED
+        );
+        // page statistics set via a pattern require the offset index
+        assert!(!props.offset_index_disabled());
+
+        // pattern properties survive conversion back into a builder
+        let props = props.into_builder().build();
+        assert_eq!(
+            props.compression(&ColumnPath::from("metrics.mem.value")),
+            zstd
+        );
+    }
+
+    #[test]
+    fn test_writer_properties_overlapping_column_patterns() {
+        let zstd = Compression::ZSTD(Default::default());
+        let lz4 = Compression::LZ4_RAW;
+
+        // patterns with equal specificity resolve to the one registered last
+        for (first, second) in [(zstd, lz4), (lz4, zstd)] {
+            let props = WriterProperties::builder()
+                .set_column_pattern_compression("a.*.c".parse().unwrap(), first)
+                .set_column_pattern_compression("a.b.*".parse().unwrap(), second)
+                .build();
+            assert_eq!(props.compression(&ColumnPath::from("a.b.c")), second);
+            assert_eq!(props.compression(&ColumnPath::from("a.x.c")), first);
+        }
+
+        // setting a pattern again updates it, rather than registering it again
+        let props = WriterProperties::builder()
+            .set_column_pattern_compression("a.*.c".parse().unwrap(), zstd)
+            .set_column_pattern_compression("a.b.*".parse().unwrap(), lz4)
+            .set_column_pattern_compression("a.*.c".parse().unwrap(), Compression::SNAPPY)
+            .build();
+        assert_eq!(props.compression(&ColumnPath::from("a.b.c")), lz4);
+
+        // wildcards within a component
+        let props = WriterProperties::builder()
+            .set_column_pattern_compression("a.x*y*z".parse().unwrap(), zstd)
+            .build();
+        for (path, expected) in [
+            ("a.xyz", zstd),
+            ("a.x_y_z", zstd),
+            ("a.xzyz", zstd),
+            ("a.xy", DEFAULT_COMPRESSION),
+            ("a.xyzw", DEFAULT_COMPRESSION),
+            ("a.b.xyz", DEFAULT_COMPRESSION),
+        ] {
+            assert_eq!(
+                props.compression(&ColumnPath::from(path)),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_column_encodings() {
+        let schema = parse_message_type(
+            "message schema { REQUIRED INT64 id; REQUIRED BYTE_ARRAY url (UTF8); }",
+        )
+        .unwrap();
+        let schema = SchemaDescriptor::new(Arc::new(schema));
+        let validate = |builder: WriterPropertiesBuilder| {
+            builder
+                .build()
+                .validate_column_encodings(&schema)
+                .map_err(|e| e.to_string())
+        };
+        let err = "Parquet error: Encoding DELTA_BINARY_PACKED is not supported for column \"url\" with physical type BYTE_ARRAY";
+
+        let builder = WriterProperties::builder();
+        assert_eq!(
+            validate(builder.clone().set_encoding(Encoding::DELTA_BINARY_PACKED)),
+            Err(err.to_string())
+        );
+        assert_eq!(
+            validate(
+                builder.clone().set_column_pattern_encoding(
+                    "u*".parse().unwrap(),
+                    Encoding::DELTA_BINARY_PACKED
+                )
+            ),
+            Err(err.to_string())
+        );
+
+        // The encoding set for the column takes precedence over the default
+        let builder = builder
+            .set_encoding(Encoding::DELTA_BINARY_PACKED)
+            .set_column_encoding(ColumnPath::from("url"), Encoding::DELTA_BYTE_ARRAY);
+        assert_eq!(validate(builder.clone()), Ok(()));
+        assert_eq!(
+            validate(builder.set_column_pattern_encoding("i*".parse().unwrap(), Encoding::DELTA_BYTE_ARRAY)),
+            Err("Parquet error: Encoding DELTA_BYTE_ARRAY is not supported for column \"id\" with physical type INT64".to_string())
+        );
+    }
+
+    #[test]
+    fn test_writer_properties_invalid_column_pattern() {
+        for (pattern, expected) in [
+            ("a..b", "empty path component"),
+            ("", "empty path component"),
+            ("a.b**", "'**' must be a whole path component"),
+        ] {
+            let err = pattern.parse::<ColumnPattern>().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Parquet error: Invalid column pattern '{pattern}': {expected}")
+            );
+        }
+    }
+
     #[test]
     fn test_reader_properties_default_settings() {
         let props = ReaderProperties::builder().build();
@@ -0,0 +1,411 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Rewrite the footer of a parquet file without rewriting its data
+//!
+//! The footer of a parquet file is the last part of the file, so replacing it
+//! does not move any data pages, page indexes or bloom filters, and all the
+//! offsets stored in the row group metadata remain valid.
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{FooterTail, KeyValue};
+use crate::file::reader::ChunkReader;
+use crate::file::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet_thrift::{
+    FieldType, ThriftCompactInputProtocol, ThriftCompactOutputProtocol, ThriftSliceInputProtocol,
+    WriteThriftField, read_thrift_vec,
+};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+/// The field id of `key_value_metadata` in the thrift `FileMetaData` struct
+const KEY_VALUE_METADATA_FIELD_ID: i16 = 5;
+/// The field id of `encryption_algorithm` in the thrift `FileMetaData` struct
+const ENCRYPTION_ALGORITHM_FIELD_ID: i16 = 8;
+
+/// Copies the parquet file in `input` to `output`, replacing its key-value
+/// metadata with the result of calling `f` with the existing key-value metadata
+///
+/// All bytes preceding the footer, i.e. the data pages, page indexes and bloom
+/// filters, are copied unchanged, and only the `key_value_metadata` field of the
+/// footer is re-encoded. All other footer fields are copied as is, so the row
+/// group metadata remains valid for the copied data.
+///
+/// Returns the number of bytes written to `output`.
+///
+/// # Example
+/// ```no_run
+/// # use std::fs::File;
+/// # use parquet::file::rewrite::rewrite_metadata;
+/// let input = File::open("data.parquet").unwrap();
+/// let output = File::create("redacted.parquet").unwrap();
+/// // Remove the "password" entry from the key-value metadata
+/// rewrite_metadata(&input, output, |kv| {
+///     let kv: Vec<_> = kv?.into_iter().filter(|kv| kv.key != "password").collect();
+///     (!kv.is_empty()).then_some(kv)
+/// })
+/// .unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `input` is not a valid parquet file, or if its footer is
+/// encrypted or signed, as modifying it would require re-encrypting or re-signing it.
+///
+/// See [`rewrite_metadata_in_place`] to avoid copying the data of a [`File`]
+pub fn rewrite_metadata<R, W, F>(input: &R, mut output: W, f: F) -> Result<u64>
+where
+    R: ChunkReader,
+    W: Write,
+    F: FnMut(Option<Vec<KeyValue>>) -> Option<Vec<KeyValue>>,
+{
+    let (metadata_start, footer) = rewrite_footer(input, f)?;
+
+    let mut data = input.get_read(0)?.take(metadata_start);
+    let copied = std::io::copy(&mut data, &mut output)?;
+    if copied != metadata_start {
+        return Err(general_err!(
+            "Failed to copy file data, expected {metadata_start} bytes got {copied}"
+        ));
+    }
+    output.write_all(&footer)?;
+    output.flush()?;
+    Ok(metadata_start + footer.len() as u64)
+}
+
+/// Replaces the key-value metadata of the parquet file `file` with the result
+/// of calling `f` with the existing key-value metadata, without copying its data
+///
+/// `file` must be opened for both reading and writing. The new footer is written
+/// over the existing one, and the file is then truncated to its new length.
+///
+/// Returns the new length of the file.
+///
+/// Note that the file is modified in place: if writing the new footer fails,
+/// the file may be left without a valid footer. See [`rewrite_metadata`] for
+/// details on which metadata is preserved.
+pub fn rewrite_metadata_in_place<F>(file: &mut File, f: F) -> Result<u64>
+where
+    F: FnMut(Option<Vec<KeyValue>>) -> Option<Vec<KeyValue>>,
+{
+    let (metadata_start, footer) = rewrite_footer(&*file, f)?;
+
+    file.seek(SeekFrom::Start(metadata_start))?;
+    file.write_all(&footer)?;
+    let len = metadata_start + footer.len() as u64;
+    file.set_len(len)?;
+    file.flush()?;
+    Ok(len)
+}
+
+/// Reads the footer of `input` and returns the offset at which the thrift
+/// encoded metadata starts, along with the rewritten footer
+fn rewrite_footer<R, F>(input: &R, f: F) -> Result<(u64, Vec<u8>)>
+where
+    R: ChunkReader,
+    F: FnMut(Option<Vec<KeyValue>>) -> Option<Vec<KeyValue>>,
+{
+    let file_size = input.len();
+    if file_size < FOOTER_SIZE as u64 {
+        return Err(general_err!(
+            "Invalid Parquet file. Size is smaller than footer"
+        ));
+    }
+
+    let mut tail = [0; FOOTER_SIZE];
+    input
+        .get_read(file_size - FOOTER_SIZE as u64)?
+        .read_exact(&mut tail)?;
+    let tail = FooterTail::try_new(&tail)?;
+    if tail.is_encrypted_footer() {
+        return Err(nyi_err!(
+            "Rewriting the metadata of a file with an encrypted footer"
+        ));
+    }
+
+    let metadata_len = tail.metadata_length() as u64;
+    if metadata_len + FOOTER_SIZE as u64 > file_size {
+        return Err(general_err!(
+            "Invalid Parquet file. Reported metadata length of {metadata_len} + {FOOTER_SIZE} \
+             byte footer, but file is only {file_size} bytes"
+        ));
+    }
+    let metadata_start = file_size - FOOTER_SIZE as u64 - metadata_len;
+    let metadata = input.get_bytes(metadata_start, metadata_len as usize)?;
+
+    let mut footer = rewrite_file_metadata(&metadata, f)?;
+    let new_metadata_len = u32::try_from(footer.len())
+        .map_err(|_| general_err!("Rewritten metadata of {} bytes is too large", footer.len()))?;
+    footer.extend_from_slice(&new_metadata_len.to_le_bytes());
+    footer.extend_from_slice(&PARQUET_MAGIC);
+    Ok((metadata_start, footer))
+}
+
+/// Re-encodes the thrift `FileMetaData` in `metadata`, replacing its key-value metadata
+///
+/// The encoded values of all other fields are copied unchanged.
+fn rewrite_file_metadata<F>(metadata: &[u8], mut f: F) -> Result<Vec<u8>>
+where
+    F: FnMut(Option<Vec<KeyValue>>) -> Option<Vec<KeyValue>>,
+{
+    // The type, id and encoded value of each field, in order
+    let mut fields: Vec<(FieldType, i16, &[u8])> = Vec::new();
+    let mut key_value_metadata = None;
+
+    let mut prot = ThriftSliceInputProtocol::new(metadata);
+    let mut last_field_id = 0;
+    loop {
+        let field_ident = prot.read_field_begin(last_field_id)?;
+        if field_ident.field_type == FieldType::Stop {
+            break;
+        }
+        let value_start = prot.as_slice();
+        prot.skip(field_ident.field_type)?;
+        let value = &value_start[..value_start.len() - prot.as_slice().len()];
+        last_field_id = field_ident.id;
+
+        match field_ident.id {
+            KEY_VALUE_METADATA_FIELD_ID => {
+                let mut value_prot = ThriftSliceInputProtocol::new(value);
+                key_value_metadata = Some(read_thrift_vec::<KeyValue, _>(&mut value_prot)?);
+            }
+            ENCRYPTION_ALGORITHM_FIELD_ID => {
+                return Err(nyi_err!(
+                    "Rewriting the metadata of a file with a signed plaintext footer"
+                ));
+            }
+            id => fields.push((field_ident.field_type, id, value)),
+        }
+    }
+
+    let mut key_value_metadata = f(key_value_metadata);
+
+    let mut out = Vec::with_capacity(metadata.len());
+    let mut last_field_id = 0;
+    let mut write_key_value_metadata = |out: &mut Vec<u8>, last_field_id: &mut i16| {
+        if let Some(key_value_metadata) = key_value_metadata.take() {
+            let mut prot = ThriftCompactOutputProtocol::new(out);
+            *last_field_id = key_value_metadata.write_thrift_field(
+                &mut prot,
+                KEY_VALUE_METADATA_FIELD_ID,
+                *last_field_id,
+            )?;
+        }
+        Ok::<_, ParquetError>(())
+    };
+    for (field_type, id, value) in fields {
+        if id > KEY_VALUE_METADATA_FIELD_ID {
+            write_key_value_metadata(&mut out, &mut last_field_id)?;
+        }
+        ThriftCompactOutputProtocol::new(&mut out).write_field_begin(
+            field_type,
+            id,
+            last_field_id,
+        )?;
+        out.extend_from_slice(value);
+        last_field_id = id;
+    }
+    write_key_value_metadata(&mut out, &mut last_field_id)?;
+    ThriftCompactOutputProtocol::new(&mut out).write_struct_end()?;
+    Ok(out)
+}
+
+#[cfg(all(test, feature = "arrow"))]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+    use crate::file::properties::WriterProperties;
+    use crate::file::reader::FileReader;
+    use crate::file::serialized_reader::{ReadOptionsBuilder, SerializedFileReader};
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    fn key_value(key: &str, value: &str) -> KeyValue {
+        KeyValue::new(key.to_string(), value.to_string())
+    }
+
+    fn write_file() -> (Bytes, RecordBatch) {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..1000).map(|i| format!("v{}", i % 17)),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(300)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .set_bloom_filter_enabled(true)
+            .set_key_value_metadata(Some(vec![
+                key_value("owner", "me"),
+                key_value("password", "hunter2"),
+            ]))
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        (Bytes::from(buf), batch)
+    }
+
+    /// Returns the offset of the thrift encoded metadata of `data`
+    fn metadata_start(data: &Bytes) -> usize {
+        let tail: [u8; FOOTER_SIZE] = data[data.len() - FOOTER_SIZE..].try_into().unwrap();
+        data.len() - FOOTER_SIZE - FooterTail::try_new(&tail).unwrap().metadata_length()
+    }
+
+    /// Asserts that `rewritten` has the same data as `original`, with the given
+    /// key-value metadata
+    fn check_rewritten(original: &Bytes, rewritten: &Bytes, batch: &RecordBatch, kv: &[&str]) {
+        // The data pages, page indexes and bloom filters are unchanged
+        let start = metadata_start(original);
+        assert_eq!(metadata_start(rewritten), start);
+        assert_eq!(&rewritten[..start], &original[..start]);
+
+        let policy = PageIndexPolicy::Required;
+        let read_metadata = |data: &Bytes| {
+            ParquetMetaDataReader::new()
+                .with_page_index_policy(policy)
+                .parse_and_finish(data)
+                .unwrap()
+        };
+        let original_metadata = read_metadata(original);
+        let metadata = read_metadata(rewritten);
+        assert_eq!(metadata.row_groups(), original_metadata.row_groups());
+        assert_eq!(metadata.column_index(), original_metadata.column_index());
+        assert_eq!(metadata.offset_index(), original_metadata.offset_index());
+
+        let keys: Vec<_> = metadata
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+            .map(|kv| kv.key.as_str())
+            .collect();
+        assert_eq!(keys, kv);
+
+        let options = ArrowReaderOptions::new().with_page_index_policy(policy);
+        let read =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(rewritten.clone(), options)
+                .unwrap()
+                .with_batch_size(1000)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        assert_eq!(read, vec![batch.clone()]);
+
+        let options = ReadOptionsBuilder::new().with_reader_properties(
+            crate::file::properties::ReaderProperties::builder()
+                .set_read_bloom_filter(true)
+                .build(),
+        );
+        let reader =
+            SerializedFileReader::new_with_options(rewritten.clone(), options.build()).unwrap();
+        for i in 0..reader.num_row_groups() {
+            let row_group = reader.get_row_group(i).unwrap();
+            let bloom_filter = row_group.get_column_bloom_filter(1).unwrap();
+            assert!(bloom_filter.check(&"v3"));
+        }
+    }
+
+    #[test]
+    fn test_rewrite_metadata() {
+        let (original, batch) = write_file();
+
+        let mut seen = None;
+        let mut rewritten = Vec::new();
+        let len = rewrite_metadata(&original, &mut rewritten, |kv| {
+            seen = kv.clone();
+            Some(kv?.into_iter().filter(|kv| kv.key != "password").collect())
+        })
+        .unwrap();
+        let rewritten = Bytes::from(rewritten);
+        assert_eq!(len, rewritten.len() as u64);
+        assert!(rewritten.len() < original.len());
+
+        let seen: Vec<_> = seen.unwrap().into_iter().map(|kv| kv.key).collect();
+        assert_eq!(seen, vec!["owner", "password", "ARROW:schema"]);
+        check_rewritten(&original, &rewritten, &batch, &["owner", "ARROW:schema"]);
+
+        // Remove all key-value metadata
+        let mut removed = Vec::new();
+        rewrite_metadata(&rewritten, &mut removed, |_| None).unwrap();
+        let removed = Bytes::from(removed);
+        check_rewritten(&original, &removed, &batch, &[]);
+
+        // Add key-value metadata to a file without any
+        let mut added = Vec::new();
+        rewrite_metadata(&removed, &mut added, |kv| {
+            assert!(kv.is_none());
+            Some(vec![key_value("added", "value")])
+        })
+        .unwrap();
+        check_rewritten(&original, &Bytes::from(added), &batch, &["added"]);
+    }
+
+    #[test]
+    fn test_rewrite_metadata_in_place() {
+        let (original, batch) = write_file();
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&original).unwrap();
+
+        let len = rewrite_metadata_in_place(&mut file, |kv| {
+            let mut kv = kv.unwrap();
+            kv.retain(|kv| kv.key != "password");
+            kv.push(key_value("redacted", "true"));
+            Some(kv)
+        })
+        .unwrap();
+        assert_eq!(file.metadata().unwrap().len(), len);
+
+        let mut rewritten = Vec::new();
+        file.seek(SeekFrom::Start(0)).unwrap();
+        file.read_to_end(&mut rewritten).unwrap();
+        let expected = ["owner", "ARROW:schema", "redacted"];
+        check_rewritten(&original, &Bytes::from(rewritten), &batch, &expected);
+    }
+
+    #[test]
+    fn test_rewrite_metadata_invalid() {
+        let err = rewrite_metadata(&Bytes::from_static(b"PAR1"), Vec::new(), |kv| kv).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid Parquet file. Size is smaller than footer"
+        );
+
+        let (original, _) = write_file();
+        let mut data = original.to_vec();
+        let len = data.len();
+        data[len - 1] = b'E';
+        let err = rewrite_metadata(&Bytes::from(data), Vec::new(), |kv| kv).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Rewriting the metadata of a file with an encrypted footer"
+        );
+    }
+}
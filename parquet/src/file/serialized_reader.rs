@@ -38,6 +38,8 @@ use crate::parquet_thrift::ThriftSliceInputProtocol;
 use crate::parquet_thrift::{ReadThrift, ThriftReadInputProtocol};
 use crate::record::Row;
 use crate::record::reader::RowIter;
+#[cfg(feature = "crc")]
+use crate::schema::types::ColumnPath;
 use crate::schema::types::Type as SchemaType;
 use bytes::Bytes;
 use std::collections::VecDeque;
@@ -255,12 +257,14 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
         // Row groups should be processed sequentially.
         let props = Arc::clone(&self.props);
         let f = Arc::clone(&self.chunk_reader);
-        Ok(Box::new(SerializedRowGroupReader::new(
+        let mut row_group = SerializedRowGroupReader::new(
             f,
             row_group_metadata,
             self.metadata.offset_index().map(|x| x[i].as_slice()),
             props,
-        )?))
+        )?;
+        row_group.row_group_idx = Some(i);
+        Ok(Box::new(row_group))
     }
 
     fn get_row_iter(&self, projection: Option<SchemaType>) -> Result<RowIter<'_>> {
@@ -275,6 +279,8 @@ pub struct SerializedRowGroupReader<'a, R: ChunkReader> {
     offset_index: Option<&'a [OffsetIndexMetaData]>,
     props: ReaderPropertiesPtr,
     bloom_filters: Vec<Option<Sbbf>>,
+    /// The index of the row group within the file, if known
+    row_group_idx: Option<usize>,
 }
 
 impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
@@ -287,7 +293,7 @@ impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
     ) -> Result<Self> {
         let bloom_filters = if props.read_bloom_filter() {
             metadata
-                .columns()
+                .try_columns()?
                 .iter()
                 .map(|col| Sbbf::read_from_column_chunk(col, &*chunk_reader))
                 .collect::<Result<Vec<_>>>()?
@@ -300,6 +306,7 @@ impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
             offset_index,
             props,
             bloom_filters,
+            row_group_idx: None,
         })
     }
 }
@@ -320,13 +327,15 @@ impl<R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'_, R
         let page_locations = self.offset_index.map(|x| x[i].page_locations.clone());
 
         let props = Arc::clone(&self.props);
-        Ok(Box::new(SerializedPageReader::new_with_properties(
+        let mut page_reader = SerializedPageReader::new_with_properties(
             Arc::clone(&self.chunk_reader),
             col,
             usize::try_from(self.metadata.num_rows())?,
             page_locations,
             props,
-        )?))
+        )?;
+        page_reader.row_group_idx = self.row_group_idx;
+        Ok(Box::new(page_reader))
     }
 
     /// get bloom filter for the `i`th column
@@ -346,15 +355,6 @@ pub(crate) fn decode_page(
     physical_type: Type,
     decompressor: Option<&mut Box<dyn Codec>>,
 ) -> Result<Page> {
-    // Verify the 32-bit CRC checksum of the page
-    #[cfg(feature = "crc")]
-    if let Some(expected_crc) = page_header.crc {
-        let crc = crc32fast::hash(&buffer);
-        if crc != expected_crc as u32 {
-            return Err(general_err!("Page CRC checksum mismatch"));
-        }
-    }
-
     // When processing data page v2, depending on enabled compression for the
     // page, we should account for uncompressed data ('offset') of
     // repetition and definition levels.
@@ -502,10 +502,15 @@ enum SerializedPageReaderState {
     },
 }
 
-#[derive(Default)]
 struct SerializedPageReaderContext {
     /// Controls decoding of page-level statistics
     read_stats: bool,
+    /// Controls verification of page CRC32 checksums
+    #[cfg(feature = "crc")]
+    verify_checksums: bool,
+    /// The path of the column being read, used in error messages
+    #[cfg(feature = "crc")]
+    column_path: ColumnPath,
     /// Crypto context carrying objects required for decryption
     #[cfg(feature = "encryption")]
     crypto_context: Option<Arc<CryptoContext>>,
@@ -525,6 +530,9 @@ pub struct SerializedPageReader<R: ChunkReader> {
     state: SerializedPageReaderState,
 
     context: SerializedPageReaderContext,
+
+    /// The index of the row group of the column chunk, if known
+    row_group_idx: Option<usize>,
 }
 
 impl<R: ChunkReader> SerializedPageReader<R> {
@@ -545,6 +553,19 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         )
     }
 
+    /// Sets whether to verify page checksums, overriding the [`ReaderProperties`]
+    #[cfg(all(feature = "arrow", feature = "crc"))]
+    pub(crate) fn with_verify_checksums(mut self, verify: bool) -> Self {
+        self.context.verify_checksums = verify;
+        self
+    }
+
+    /// Stub No-op implementation when checksums are not supported.
+    #[cfg(all(feature = "arrow", not(feature = "crc")))]
+    pub(crate) fn with_verify_checksums(self, _verify: bool) -> Self {
+        self
+    }
+
     /// Stub No-op implementation when encryption is disabled.
     #[cfg(all(feature = "arrow", not(feature = "encryption")))]
     pub(crate) fn add_crypto_context(
@@ -589,7 +610,9 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         let decompressor = create_codec(meta.compression(), props.codec_options())?;
         let (start, len) = meta.byte_range();
 
-        let state = match page_locations {
+        // An empty offset index, e.g. for a column whose page index was not read,
+        // provides no page locations
+        let state = match page_locations.filter(|locations| !locations.is_empty()) {
             Some(locations) => {
                 // If the offset of the first page doesn't match the start of the column chunk
                 // then the preceding space must contain a dictionary page.
@@ -617,19 +640,33 @@ impl<R: ChunkReader> SerializedPageReader<R> {
                 require_dictionary: meta.dictionary_page_offset().is_some(),
             },
         };
-        let mut context = SerializedPageReaderContext::default();
-        if props.read_page_stats() {
-            context.read_stats = true;
-        }
+        let context = SerializedPageReaderContext {
+            read_stats: props.read_page_stats(),
+            #[cfg(feature = "crc")]
+            verify_checksums: props.verify_page_checksums(),
+            #[cfg(feature = "crc")]
+            column_path: meta.column_path().clone(),
+            #[cfg(feature = "encryption")]
+            crypto_context: None,
+        };
         Ok(Self {
             reader,
             decompressor,
             state,
             physical_type: meta.column_type(),
             context,
+            row_group_idx: None,
         })
     }
 
+    /// Sets the index of the row group of the column chunk, which is reported
+    /// in errors decoding its pages
+    #[cfg(feature = "arrow")]
+    pub(crate) fn with_row_group_index(mut self, row_group_idx: usize) -> Self {
+        self.row_group_idx = Some(row_group_idx);
+        self
+    }
+
     /// Similar to `peek_next_page`, but returns the offset of the next page instead of the page metadata.
     /// Unlike page metadata, an offset can uniquely identify a page.
     ///
@@ -832,6 +869,40 @@ impl SerializedPageReaderContext {
     }
 }
 
+impl SerializedPageReaderContext {
+    /// Verifies the CRC32 checksum of the page data in `buffer`, if the
+    /// checksum is present in `header` and verification is enabled
+    #[cfg(feature = "crc")]
+    fn verify_checksum(&self, header: &PageHeader, buffer: &[u8], page_index: usize) -> Result<()> {
+        let Some(expected) = header.crc.filter(|_| self.verify_checksums) else {
+            return Ok(());
+        };
+        let actual = crc32fast::hash(buffer);
+        if actual != expected as u32 {
+            let page = match header.r#type {
+                PageType::DICTIONARY_PAGE => "dictionary page".to_string(),
+                _ => format!("data page {page_index}"),
+            };
+            return Err(general_err!(
+                "Page CRC checksum mismatch for column {} in {page}: expected {:#010x}, got {actual:#010x}",
+                self.column_path,
+                expected as u32
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "crc"))]
+    fn verify_checksum(
+        &self,
+        _header: &PageHeader,
+        _buffer: &[u8],
+        _page_index: usize,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
 impl<R: ChunkReader> Iterator for SerializedPageReader<R> {
     type Item = Result<Page>;
 
@@ -918,6 +989,8 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     let buffer =
                         self.context
                             .decrypt_page_data(buffer, *page_index, *require_dictionary)?;
+                    self.context
+                        .verify_checksum(&header, &buffer, *page_index)?;
 
                     let page = decode_page(
                         header,
@@ -959,6 +1032,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                     let bytes =
                         self.context
                             .decrypt_page_data(bytes, *page_index, is_dictionary_page)?;
+                    self.context.verify_checksum(&header, &bytes, *page_index)?;
 
                     if !is_dictionary_page {
                         *page_index += 1;
@@ -1119,6 +1193,10 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
             SerializedPageReaderState::Pages { .. } => Ok(true),
         }
     }
+
+    fn row_group_index(&self) -> Option<usize> {
+        self.row_group_idx
+    }
 }
 
 #[cfg(test)]
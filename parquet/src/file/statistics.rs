@@ -138,8 +138,10 @@ pub(crate) fn from_thrift_page_stats(
 
             // Generic null count.
             let null_count = Some(null_count as u64);
-            // Generic distinct count (count of distinct values occurring)
-            let distinct_count = stats.distinct_count.map(|value| value as u64);
+            // Generic distinct count (count of distinct values occurring), ignored if invalid
+            let distinct_count = stats
+                .distinct_count
+                .and_then(|value| u64::try_from(value).ok());
             // Whether or not statistics use deprecated min/max fields.
             let old_format = stats.min_value.is_none() && stats.max_value.is_none();
             // Generic min value as bytes.
@@ -1046,6 +1048,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_decoding_distinct_invalid() {
+        let tstatistics = PageStatistics {
+            null_count: Some(0),
+            max: None,
+            min: None,
+            distinct_count: Some(-42),
+            max_value: None,
+            min_value: None,
+            is_max_value_exact: None,
+            is_min_value_exact: None,
+        };
+        let stats = from_thrift_page_stats(Type::BOOLEAN, Some(tstatistics))
+            .unwrap()
+            .unwrap();
+        assert_eq!(stats.distinct_count_opt(), None);
+    }
+
     /// Writes statistics to thrift and reads them back and ensures:
     /// - The statistics are the same
     /// - The statistics written to thrift are the same as the original statistics
@@ -68,6 +68,14 @@ impl<W: Write> TrackedWrite<W> {
         self.bytes_written
     }
 
+    /// Returns the number of bytes written to the underlying writer
+    ///
+    /// Unlike [`Self::bytes_written`] this excludes any bytes still held in
+    /// this instance's internal buffer
+    pub fn flushed_bytes(&self) -> usize {
+        self.bytes_written - self.inner.buffer().len()
+    }
+
     /// Returns a reference to the underlying writer.
     pub fn inner(&self) -> &W {
         self.inner.get_ref()
@@ -187,6 +195,7 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         let mut buf = TrackedWrite::new(buf);
 
         let schema_descriptor = SchemaDescriptor::new(schema.clone());
+        properties.validate_column_encodings(&schema_descriptor)?;
 
         #[cfg(feature = "encryption")]
         let file_encryptor = Self::get_file_encryptor(&properties, &schema_descriptor)?;
@@ -450,6 +459,13 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         self.buf.bytes_written()
     }
 
+    /// Returns the number of bytes written to the underlying writer
+    ///
+    /// See [`TrackedWrite::flushed_bytes`] for more information
+    pub fn flushed_bytes(&self) -> usize {
+        self.buf.flushed_bytes()
+    }
+
     /// Get the file encryptor used by this instance to encrypt data
     #[cfg(feature = "encryption")]
     pub(crate) fn file_encryptor(&self) -> Option<Arc<FileEncryptor>> {
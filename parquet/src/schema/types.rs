@@ -418,6 +418,9 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                         self.name
                     ));
                 }
+                // Logical types from newer versions of the format can not be validated,
+                // whether they can be read is left to the reader
+                (LogicalType::_Unknown { .. }, _) => {}
                 (a, b) => {
                     return Err(general_err!(
                         "Cannot annotate {:?} from {} for field '{}'",
@@ -676,7 +679,7 @@ pub struct BasicTypeInfo {
 impl HeapSize for BasicTypeInfo {
     fn heap_size(&self) -> usize {
         // no heap allocations in any other subfield
-        self.name.heap_size()
+        self.name.heap_size() + self.logical_type.heap_size()
     }
 }
 
@@ -617,6 +617,23 @@ impl BitReader {
         values_to_read
     }
 
+    /// Appends up to `num_values` single bit values to `out` without unpacking
+    /// them, returning the number of values read
+    #[cfg(feature = "arrow")]
+    pub(crate) fn get_bits(
+        &mut self,
+        out: &mut arrow_buffer::BooleanBufferBuilder,
+        num_values: usize,
+    ) -> usize {
+        let remaining_bits = (self.buffer.len() - self.byte_offset) * 8 - self.bit_offset;
+        let values_to_read = num_values.min(remaining_bits);
+
+        let start = self.byte_offset * 8 + self.bit_offset;
+        out.append_packed_range(start..start + values_to_read, &self.buffer);
+
+        self.skip(values_to_read, 1)
+    }
+
     /// Reads up to `num_bytes` to `buf` returning the number of bytes read
     pub(crate) fn get_aligned_bytes(&mut self, buf: &mut Vec<u8>, num_bytes: usize) -> usize {
         // Align to byte offset
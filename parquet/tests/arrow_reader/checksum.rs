@@ -18,13 +18,30 @@
 //! This file contains an end to end test for verifying checksums when reading parquet files.
 
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use arrow::util::test_util::parquet_test_data;
-use parquet::arrow::arrow_reader::ArrowReaderBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+};
+use parquet::basic::Encoding;
+use parquet::errors::Result;
+use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+use parquet::file::properties::{WriterProperties, WriterVersion};
 
 #[test]
 fn test_datapage_v1_corrupt_checksum() {
     let errors = read_file_batch_errors("datapage_v1-corrupt-checksum.parquet");
+    // strip the details of the mismatch (column, page and checksums)
+    let errors: Vec<_> = errors
+        .into_iter()
+        .map(|e| e.map_err(|e| e.split(" for column").next().unwrap().to_string()))
+        .collect();
     assert_eq!(errors, [
         Err("Parquet argument error: Parquet error: Page CRC checksum mismatch".to_string()),
         Ok(()),
@@ -73,3 +90,112 @@ fn read_file_batch_errors(name: &str) -> Vec<Result<(), String>> {
         })
         .collect()
 }
+
+/// Writes 1000 rows in pages of 100 rows, with page checksums enabled
+fn write_with_checksums(writer_version: WriterVersion, dictionary: bool) -> Bytes {
+    let a = Int32Array::from_iter_values((0..1000).map(|i| i % 10));
+    let b = StringArray::from_iter_values((0..1000).map(|i| format!("value_{}", i % 10)));
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(a) as ArrayRef),
+        ("b", Arc::new(b) as ArrayRef),
+    ])
+    .unwrap();
+
+    let mut props = WriterProperties::builder()
+        .set_page_checksums_enabled(true)
+        .set_writer_version(writer_version)
+        .set_dictionary_enabled(dictionary)
+        .set_data_page_row_count_limit(100)
+        .set_write_batch_size(100);
+    if !dictionary {
+        // so that every byte of the page affects the decoded values
+        props = props.set_encoding(Encoding::PLAIN);
+    }
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props.build())).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    Bytes::from(buf)
+}
+
+fn read_all(data: Bytes, verify: bool) -> Result<Vec<RecordBatch>> {
+    let options = ArrowReaderOptions::new().with_verify_page_checksums(verify);
+    ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)?
+        .build()?
+        .collect::<std::result::Result<_, _>>()
+        .map_err(Into::into)
+}
+
+/// Flips the last byte of the `page`th data page of column `a`
+fn corrupt_data_page(data: &Bytes, page: usize) -> Bytes {
+    let metadata = ParquetMetaDataReader::new()
+        .with_page_index_policy(PageIndexPolicy::Required)
+        .parse_and_finish(data)
+        .unwrap();
+    let location = &metadata.offset_index().unwrap()[0][0].page_locations()[page];
+    let end = (location.offset + location.compressed_page_size as i64) as usize;
+    flip_byte(data, end - 1)
+}
+
+/// Flips the last byte of the dictionary page of column `a`
+fn corrupt_dictionary_page(data: &Bytes) -> Bytes {
+    let metadata = ParquetMetaDataReader::new().parse_and_finish(data).unwrap();
+    let column = metadata.row_group(0).column(0);
+    assert!(column.dictionary_page_offset().is_some());
+    flip_byte(data, column.data_page_offset() as usize - 1)
+}
+
+fn flip_byte(data: &Bytes, offset: usize) -> Bytes {
+    let mut corrupted = data.to_vec();
+    corrupted[offset] ^= 0xFF;
+    corrupted.into()
+}
+
+#[test]
+fn test_write_checksums_roundtrip() {
+    for (writer_version, dictionary) in [
+        (WriterVersion::PARQUET_1_0, false),
+        (WriterVersion::PARQUET_2_0, false),
+        (WriterVersion::PARQUET_1_0, true),
+        (WriterVersion::PARQUET_2_0, true),
+    ] {
+        let data = write_with_checksums(writer_version, dictionary);
+        let batches = read_all(data, true).unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 1000);
+    }
+}
+
+#[test]
+fn test_detect_corrupt_data_page() {
+    for writer_version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+        let data = write_with_checksums(writer_version, false);
+        let corrupted = corrupt_data_page(&data, 2);
+
+        let err = read_all(corrupted.clone(), true).unwrap_err().to_string();
+        assert!(
+            err.contains("Page CRC checksum mismatch for column \"a\" in data page 2: expected"),
+            "{writer_version:?}: {err}"
+        );
+
+        // Without verification the corrupted value is silently returned
+        let batches = read_all(corrupted, false).unwrap();
+        let expected = read_all(data, true).unwrap();
+        assert_ne!(batches, expected);
+        let a = batches[0].column(0).as_primitive::<Int32Type>();
+        assert_ne!(a.value(299), 9);
+    }
+}
+
+#[test]
+fn test_detect_corrupt_dictionary_page() {
+    for writer_version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+        let data = write_with_checksums(writer_version, true);
+        let corrupted = corrupt_dictionary_page(&data);
+
+        let err = read_all(corrupted, true).unwrap_err().to_string();
+        assert!(
+            err.contains("Page CRC checksum mismatch for column \"a\" in dictionary page"),
+            "{writer_version:?}: {err}"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table driven row group pruning tests using synthetic [`ParquetMetaData`],
+//! built without writing or reading any files
+
+use std::sync::Arc;
+
+use arrow_array::Int32Array;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_schema::{DataType, Field, Schema};
+use bytes::Bytes;
+use parquet::arrow::ArrowSchemaConverter;
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ParquetRecordBatchReaderBuilder};
+use parquet::basic::Encoding;
+use parquet::file::metadata::{
+    ColumnChunkMetaData, FileMetaData, ParquetMetaData, ParquetMetaDataBuilder, RowGroupMetaData,
+};
+use parquet::file::statistics::Statistics;
+
+/// Returns metadata for a file with an `Int32` column `x` and a row group of
+/// `num_rows` rows for each `(num_rows, min, max)` in `row_groups`
+fn synthetic_metadata(row_groups: &[(i64, i32, i32)]) -> ParquetMetaData {
+    let schema = Schema::new(vec![Field::new("x", DataType::Int32, true)]);
+    let schema_descr = Arc::new(ArrowSchemaConverter::new().convert(&schema).unwrap());
+
+    let num_rows = row_groups.iter().map(|(num_rows, _, _)| num_rows).sum();
+    let file_metadata = FileMetaData::new(2, num_rows, None, None, schema_descr.clone(), None);
+
+    let mut offset = 4;
+    let mut builder = ParquetMetaDataBuilder::new(file_metadata);
+    for (ordinal, (num_rows, min, max)) in row_groups.iter().enumerate() {
+        let size = num_rows * 4;
+        let column = ColumnChunkMetaData::builder(schema_descr.column(0))
+            .set_encodings(vec![Encoding::PLAIN])
+            .set_num_values(*num_rows)
+            .set_total_compressed_size(size)
+            .set_total_uncompressed_size(size)
+            .set_data_page_offset(offset)
+            .set_statistics(Statistics::int32(
+                Some(*min),
+                Some(*max),
+                None,
+                Some(0),
+                false,
+            ))
+            .build()
+            .unwrap();
+        let row_group = RowGroupMetaData::builder(schema_descr.clone())
+            .set_num_rows(*num_rows)
+            .set_total_byte_size(size)
+            .set_file_offset(offset)
+            .set_ordinal(ordinal as i16)
+            .add_column_metadata(column)
+            .build()
+            .unwrap();
+        builder = builder.add_row_group(row_group);
+        offset += size;
+    }
+    builder.try_build().unwrap()
+}
+
+/// A predicate on `x` that may be true for some value in `[min, max]`
+#[derive(Debug, Clone, Copy)]
+enum Predicate {
+    Eq(i32),
+    Lt(i32),
+    Gt(i32),
+}
+
+impl Predicate {
+    fn may_match(&self, min: i32, max: i32) -> bool {
+        match *self {
+            Self::Eq(v) => min <= v && v <= max,
+            Self::Lt(v) => min < v,
+            Self::Gt(v) => max > v,
+        }
+    }
+}
+
+/// Returns the indexes of the row groups that may contain rows matching `predicate`
+fn prune(metadata: &ParquetMetaData, predicate: Predicate) -> Vec<usize> {
+    let arrow_metadata =
+        ArrowReaderMetadata::try_new(Arc::new(metadata.clone()), Default::default()).unwrap();
+    let converter = StatisticsConverter::try_new(
+        "x",
+        arrow_metadata.schema(),
+        arrow_metadata.parquet_schema(),
+    )
+    .unwrap();
+    let mins = converter.row_group_mins(metadata.row_groups()).unwrap();
+    let maxes = converter.row_group_maxes(metadata.row_groups()).unwrap();
+    let mins: &Int32Array = mins.as_primitive::<Int32Type>();
+    let maxes: &Int32Array = maxes.as_primitive::<Int32Type>();
+
+    (0..metadata.num_row_groups())
+        .filter(|idx| predicate.may_match(mins.value(*idx), maxes.value(*idx)))
+        .collect()
+}
+
+#[test]
+fn test_prune_synthetic_metadata() {
+    let metadata = synthetic_metadata(&[(100, 0, 99), (100, 100, 199), (50, 150, 300)]);
+
+    let cases = [
+        (Predicate::Eq(50), vec![0]),
+        (Predicate::Eq(175), vec![1, 2]),
+        (Predicate::Eq(500), vec![]),
+        (Predicate::Lt(100), vec![0]),
+        (Predicate::Lt(0), vec![]),
+        (Predicate::Gt(199), vec![2]),
+        (Predicate::Gt(-1), vec![0, 1, 2]),
+    ];
+    for (predicate, expected) in cases {
+        assert_eq!(prune(&metadata, predicate), expected, "{predicate:?}");
+    }
+}
+
+#[test]
+fn test_reader_with_synthetic_metadata() {
+    let metadata = synthetic_metadata(&[(100, 0, 99), (100, 100, 199)]);
+    let arrow_metadata =
+        ArrowReaderMetadata::try_new(Arc::new(metadata), Default::default()).unwrap();
+
+    // No data is read until a batch is requested, so an empty input suffices
+    let builder = ParquetRecordBatchReaderBuilder::new_with_metadata(Bytes::new(), arrow_metadata);
+    assert_eq!(builder.metadata().file_metadata().num_rows(), 200);
+    assert_eq!(builder.schema().field(0).data_type(), &DataType::Int32);
+
+    // All row groups are pruned
+    let mut reader = builder.with_row_groups(vec![]).build().unwrap();
+    assert!(reader.next().is_none());
+}
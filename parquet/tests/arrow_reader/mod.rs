@@ -43,6 +43,7 @@ mod bad_data;
 mod checksum;
 mod int96_stats_roundtrip;
 mod io;
+mod metadata_builder;
 #[cfg(feature = "async")]
 mod predicate_cache;
 mod statistics;
@@ -26,29 +26,35 @@ use super::make_test_file_rg;
 use super::{Scenario, struct_array};
 use arrow::compute::kernels::cast_utils::Parser;
 use arrow::datatypes::{
-    Date32Type, Date64Type, TimestampMicrosecondType, TimestampMillisecondType,
+    Date32Type, Date64Type, IntervalDayTime, TimestampMicrosecondType, TimestampMillisecondType,
     TimestampNanosecondType, TimestampSecondType, i256,
 };
+use arrow_array::cast::AsArray;
 use arrow_array::{
     Array, ArrayRef, BinaryArray, BinaryViewArray, BooleanArray, Date32Array, Date64Array,
     Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array, FixedSizeBinaryArray,
     Float16Array, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Int64Array,
-    LargeBinaryArray, LargeStringArray, RecordBatch, StringArray, StringViewArray,
-    Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
-    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
-    TimestampSecondArray, UInt8Array, UInt16Array, UInt32Array, UInt64Array, make_array,
-    new_null_array,
+    IntervalDayTimeArray, IntervalYearMonthArray, LargeBinaryArray, LargeStringArray, RecordBatch,
+    StringArray, StringViewArray, Time32MillisecondArray, Time32SecondArray,
+    Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
+    UInt16Array, UInt32Array, UInt64Array, make_array, new_null_array,
 };
 use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use bytes::Bytes;
 use half::f16;
-use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::statistics::PageStats;
 use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
 use parquet::arrow::arrow_reader::{
-    ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+    ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection,
 };
-use parquet::file::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+use parquet::arrow::{ArrowWriter, parquet_to_arrow_schema};
+use parquet::basic::Type as PhysicalType;
+use parquet::data_type::{ByteArray, FixedLenByteArray};
+use parquet::file::metadata::{ColumnChunkMetaData, ColumnIndexBuilder, RowGroupMetaData};
 use parquet::file::properties::{EnabledStatistics, WriterProperties};
 use parquet::file::statistics::{Statistics, ValueStatistics};
+use parquet::schema::parser::parse_message_type;
 use parquet::schema::types::{SchemaDescPtr, SchemaDescriptor};
 
 #[derive(Debug, Default, Clone)]
@@ -641,6 +647,233 @@ async fn test_data_page_stats_with_all_null_page() {
     }
 }
 
+#[test]
+fn test_page_statistics_iter() {
+    // Three row groups of uneven sizes, with pages of uneven sizes and an all null page
+    let values: Vec<Option<i64>> = (0..537)
+        .map(|i| (i % 5 != 0 && !(300..340).contains(&i)).then_some(i * 7 % 101))
+        .collect();
+    let schema = Arc::new(Schema::new(vec![Field::new("i64", DataType::Int64, true)]));
+    let props = WriterProperties::builder()
+        .set_data_page_row_count_limit(20)
+        .set_write_batch_size(13)
+        .build();
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+    for range in [0..100, 100..137, 137..537] {
+        let array = Arc::new(Int64Array::from(values[range].to_vec())) as ArrayRef;
+        let batch = RecordBatch::try_new(schema.clone(), vec![array]).unwrap();
+        writer.write(&batch).unwrap();
+        writer.flush().unwrap();
+    }
+    writer.close().unwrap();
+    let data = Bytes::from(buf);
+
+    let options = ArrowReaderOptions::new().with_page_index(true);
+    let reader = ArrowReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.num_row_groups(), 3);
+    let converter = StatisticsConverter::try_new("i64", &schema, reader.parquet_schema()).unwrap();
+
+    let pages: Vec<_> = converter.page_statistics_iter(metadata).unwrap().collect();
+    let num_pages: usize = metadata
+        .offset_index()
+        .unwrap()
+        .iter()
+        .map(|rg| rg[0].page_locations().len())
+        .sum();
+    assert_eq!(pages.len(), num_pages);
+    assert!(pages.iter().any(|page| page.is_null_page));
+
+    // The row ranges are contiguous and sum to the file row count
+    let mut next_row = 0;
+    for page in &pages {
+        assert_eq!(page.first_row_index, next_row);
+        assert!(page.row_count > 0);
+        next_row += page.row_count;
+    }
+    assert_eq!(next_row, metadata.file_metadata().num_rows() as u64);
+    assert_eq!(next_row, values.len() as u64);
+
+    // The statistics of each page match the values in its row range
+    for page in &pages {
+        let range = page.row_range();
+        let page_values =
+            Int64Array::from(values[range.start as usize..range.end as usize].to_vec());
+        let expected_min = Int64Array::from(vec![arrow::compute::min(&page_values)]);
+        let expected_max = Int64Array::from(vec![arrow::compute::max(&page_values)]);
+        assert_eq!(page.min.as_ref(), &expected_min as &dyn Array, "{page:?}");
+        assert_eq!(page.max.as_ref(), &expected_max as &dyn Array, "{page:?}");
+        assert_eq!(page.null_count, Some(page_values.null_count() as u64));
+        assert_eq!(
+            page.is_null_page,
+            page_values.null_count() == page_values.len()
+        );
+    }
+
+    // Without the page index, each row group is a single entry with unknown statistics
+    let reader = ArrowReaderBuilder::try_new(data).unwrap();
+    let pages: Vec<_> = converter
+        .page_statistics_iter(reader.metadata())
+        .unwrap()
+        .collect();
+    let row_ranges: Vec<_> = pages.iter().map(|page| page.row_range()).collect();
+    assert_eq!(row_ranges, vec![0..100, 100..137, 137..537]);
+    for page in &pages {
+        assert_eq!(page.page_index, None);
+        assert_eq!(page.min.null_count(), 1);
+        assert_eq!(page.max.null_count(), 1);
+        assert_eq!(page.null_count, None);
+    }
+}
+
+#[test]
+fn test_page_row_selection() {
+    // Two row groups of 500 rows, each with 10 pages of 50 sorted rows
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("i32", DataType::Int32, false),
+        Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        Field::new("utf8", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from_iter_values(0..1000)),
+            Arc::new(TimestampMicrosecondArray::from_iter_values(
+                (0..1000).map(|i| i * 100),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                (0..1000).map(|i| format!("{i:04}")),
+            )),
+        ],
+    )
+    .unwrap();
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(500)
+        .set_data_page_row_count_limit(50)
+        .set_write_batch_size(50)
+        .set_dictionary_enabled(false)
+        .build();
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    let data = Bytes::from(buf);
+
+    let options = ArrowReaderOptions::new().with_page_index(true);
+    let reader = ArrowReaderBuilder::try_new_with_options(data.clone(), options.clone()).unwrap();
+    let metadata = reader.metadata().clone();
+    let converter = |name| StatisticsConverter::try_new(name, &schema, reader.parquet_schema());
+
+    // Reads the rows of `row_groups` selected by `selection`
+    let read = |row_groups: &[usize], selection: RowSelection| {
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+                .unwrap()
+                .with_row_groups(row_groups.to_vec())
+                .with_row_selection(selection)
+                .build()
+                .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = arrow::compute::concat_batches(&schema, &batches).unwrap();
+        batch
+            .column(0)
+            .as_primitive::<arrow::datatypes::Int32Type>()
+            .values()
+            .to_vec()
+    };
+
+    // The per-page statistics are typed according to the arrow schema
+    let pages: Vec<_> = converter("i32")
+        .unwrap()
+        .page_statistics_iter(&metadata)
+        .unwrap()
+        .collect();
+    assert_eq!(pages.len(), 20);
+    assert_eq!(pages[3].row_range(), 150..200);
+    assert_eq!(
+        pages[3].min.as_ref(),
+        &Int32Array::from(vec![150]) as &dyn Array
+    );
+    assert_eq!(
+        pages[3].max.as_ref(),
+        &Int32Array::from(vec![199]) as &dyn Array
+    );
+    assert_eq!(pages[3].null_count, Some(0));
+
+    let pages: Vec<_> = converter("ts")
+        .unwrap()
+        .page_statistics_iter(&metadata)
+        .unwrap()
+        .collect();
+    let expected = TimestampMicrosecondArray::from(vec![64_900]);
+    assert_eq!(pages[12].max.as_ref(), &expected as &dyn Array);
+
+    let pages: Vec<_> = converter("utf8")
+        .unwrap()
+        .page_statistics_iter(&metadata)
+        .unwrap()
+        .collect();
+    let expected = StringArray::from(vec!["0950"]);
+    assert_eq!(pages[19].min.as_ref(), &expected as &dyn Array);
+
+    // ts > 50_000 skips the pages of the first row group
+    let selection = converter("ts")
+        .unwrap()
+        .page_row_selection(&metadata, &[0, 1], |page| {
+            let max = page.max.as_primitive::<TimestampMicrosecondType>();
+            max.is_null(0) || max.value(0) > 50_000
+        })
+        .unwrap();
+    assert_eq!(selection.skipped_row_count(), 500);
+    assert_eq!(read(&[0, 1], selection), (500..1000).collect::<Vec<_>>());
+
+    // 120 <= i32 < 180 only keeps the pages containing those rows
+    let predicate = |page: &PageStats| {
+        let min = page.min.as_primitive::<arrow::datatypes::Int32Type>();
+        let max = page.max.as_primitive::<arrow::datatypes::Int32Type>();
+        max.value(0) >= 120 && min.value(0) < 180
+    };
+    let i32_converter = converter("i32").unwrap();
+    let selection = i32_converter
+        .page_row_selection(&metadata, &[0, 1], predicate)
+        .unwrap();
+    assert_eq!(read(&[0, 1], selection), (100..200).collect::<Vec<_>>());
+
+    // The selection follows the order of the row groups
+    let selection = i32_converter
+        .page_row_selection(&metadata, &[1, 0], predicate)
+        .unwrap();
+    assert_eq!(selection.skipped_row_count(), 900);
+    assert_eq!(read(&[1, 0], selection), (100..200).collect::<Vec<_>>());
+
+    let selection = i32_converter
+        .page_row_selection(&metadata, &[1], predicate)
+        .unwrap();
+    assert!(!selection.selects_any());
+
+    // utf8 >= "0990" only keeps the last page
+    let selection = converter("utf8")
+        .unwrap()
+        .page_row_selection(&metadata, &[0, 1], |page| {
+            page.max.as_string::<i32>().value(0) >= "0990"
+        })
+        .unwrap();
+    assert_eq!(read(&[0, 1], selection), (950..1000).collect::<Vec<_>>());
+
+    let err = i32_converter
+        .page_row_selection(&metadata, &[2], predicate)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Arrow: Row group 2 out of bounds, file has 2 row groups"
+    );
+}
+
 /////////////// MORE GENERAL TESTS //////////////////////
 // . Many columns in a file
 // . Differnet data types
@@ -2079,6 +2312,275 @@ async fn test_decimal_256() {
     }
     .run();
 }
+
+#[test]
+fn test_decimal_256_high_precision() {
+    // Decimal256 with precision above 38 is stored as FIXED_LEN_BYTE_ARRAY
+    for (precision, scale) in [(39, 0), (50, 5), (76, 10)] {
+        let large = i256::from_i128(10).wrapping_pow(precision as u32 - 1);
+        let values = Decimal256Array::from(vec![
+            Some(-large),
+            Some(i256::from(1)),
+            Some(large),
+            None,
+            Some(-large - i256::from(1)),
+            Some(i256::from(-7)),
+        ])
+        .with_precision_and_scale(precision, scale)
+        .unwrap();
+        let batch =
+            RecordBatch::try_from_iter(vec![("decimal256_col", Arc::new(values) as ArrayRef)])
+                .unwrap();
+
+        // 3 rows per row group, each with a single data page
+        let reader = build_parquet_file(3, Some(EnabledStatistics::Page), None, vec![batch]);
+
+        Test {
+            reader: &reader,
+            expected_min: Arc::new(
+                Decimal256Array::from(vec![-large, -large - i256::from(1)])
+                    .with_precision_and_scale(precision, scale)
+                    .unwrap(),
+            ),
+            expected_max: Arc::new(
+                Decimal256Array::from(vec![large, i256::from(-7)])
+                    .with_precision_and_scale(precision, scale)
+                    .unwrap(),
+            ),
+            expected_null_counts: UInt64Array::from(vec![0, 1]),
+            expected_row_counts: Some(UInt64Array::from(vec![3, 3])),
+            expected_max_value_exact: BooleanArray::from(vec![true, true]),
+            expected_min_value_exact: BooleanArray::from(vec![true, true]),
+            column_name: "decimal256_col",
+            check: Check::Both,
+        }
+        .run();
+    }
+}
+
+#[test]
+fn test_decimal_256_truncated_statistics() {
+    let data_type = DataType::Decimal256(50, 5);
+    let values = Decimal256Array::from(vec![
+        i256::from(-123456789),
+        i256::from(987654321),
+        i256::from(-5),
+        i256::from(5),
+    ])
+    .with_data_type(data_type.clone());
+    let batch =
+        RecordBatch::try_from_iter(vec![("decimal256_col", Arc::new(values) as ArrayRef)]).unwrap();
+    let reader = build_parquet_file(2, Some(EnabledStatistics::Page), None, vec![batch]);
+    let metadata = reader.metadata();
+
+    // Truncate the min of the first row group, which must not be decoded as a
+    // (wrong) shorter value
+    let row_groups: Vec<_> = metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .map(|(idx, rg)| {
+            let column = rg.column(0).clone();
+            let stats = column.statistics().unwrap();
+            let mut min = stats.min_bytes_opt().unwrap().to_vec();
+            let max = stats.max_bytes_opt().unwrap().to_vec();
+            assert_eq!(min.len(), 21);
+            if idx == 0 {
+                min.truncate(8);
+            }
+            let stats = Statistics::fixed_len_byte_array(
+                Some(FixedLenByteArray::from(min)),
+                Some(FixedLenByteArray::from(max)),
+                None,
+                Some(0),
+                false,
+            );
+            let column = column.into_builder().set_statistics(stats).build().unwrap();
+            rg.clone()
+                .into_builder()
+                .set_column_metadata(vec![column])
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    let converter =
+        StatisticsConverter::try_new("decimal256_col", reader.schema(), reader.parquet_schema())
+            .unwrap();
+    let expected_min: ArrayRef = Arc::new(
+        Decimal256Array::from(vec![None, Some(i256::from(-5))]).with_data_type(data_type.clone()),
+    );
+    let expected_max: ArrayRef = Arc::new(
+        Decimal256Array::from(vec![i256::from(987654321), i256::from(5)])
+            .with_data_type(data_type.clone()),
+    );
+    assert_eq!(
+        &converter.row_group_mins(&row_groups).unwrap(),
+        &expected_min
+    );
+    assert_eq!(
+        &converter.row_group_maxes(&row_groups).unwrap(),
+        &expected_max
+    );
+
+    // Same for the page index
+    let column_index: Vec<Vec<_>> = row_groups
+        .iter()
+        .map(|rg| {
+            let stats = rg.column(0).statistics().unwrap();
+            let mut builder = ColumnIndexBuilder::new(PhysicalType::FIXED_LEN_BYTE_ARRAY);
+            builder.append(
+                false,
+                stats.min_bytes_opt().unwrap().to_vec(),
+                stats.max_bytes_opt().unwrap().to_vec(),
+                0,
+            );
+            vec![builder.build().unwrap()]
+        })
+        .collect();
+    let offset_index = metadata.offset_index().unwrap();
+    let mins = converter
+        .data_page_mins(&column_index, offset_index, [0, 1].iter())
+        .unwrap();
+    let maxes = converter
+        .data_page_maxes(&column_index, offset_index, [0, 1].iter())
+        .unwrap();
+    assert_eq!(&mins, &expected_min);
+    assert_eq!(&maxes, &expected_max);
+}
+
+#[test]
+fn test_interval() {
+    // Interval statistics are not supported, but must not result in an error
+    let year_month: ArrayRef = Arc::new(IntervalYearMonthArray::from(vec![1, -2, 3]));
+    let day_time: ArrayRef = Arc::new(IntervalDayTimeArray::from(vec![
+        IntervalDayTime::new(1, 2),
+        IntervalDayTime::new(-3, 4),
+        IntervalDayTime::new(5, -6),
+    ]));
+    let batch = RecordBatch::try_from_iter(vec![
+        ("year_month", year_month.clone()),
+        ("day_time", day_time.clone()),
+    ])
+    .unwrap();
+    let reader = build_parquet_file(3, Some(EnabledStatistics::Page), None, vec![batch]);
+
+    let metadata = reader.metadata();
+    let row_groups = metadata.row_groups();
+    let column_index = metadata.column_index().unwrap();
+    let offset_index = metadata.offset_index().unwrap();
+
+    for (column_name, array) in [("year_month", year_month), ("day_time", day_time)] {
+        let converter =
+            StatisticsConverter::try_new(column_name, reader.schema(), reader.parquet_schema())
+                .unwrap();
+        let expected = new_null_array(array.data_type(), 1);
+
+        assert_eq!(&converter.row_group_mins(row_groups).unwrap(), &expected);
+        assert_eq!(&converter.row_group_maxes(row_groups).unwrap(), &expected);
+
+        let mins = converter
+            .data_page_mins(column_index, offset_index, [0].iter())
+            .unwrap();
+        let maxes = converter
+            .data_page_maxes(column_index, offset_index, [0].iter())
+            .unwrap();
+        assert_eq!(&mins, &expected);
+        assert_eq!(&maxes, &expected);
+    }
+}
+
+#[test]
+fn test_distinct_counts() {
+    let int_col: ArrayRef = Arc::new(Int32Array::from(vec![
+        Some(1),
+        Some(2),
+        Some(1),
+        None,
+        Some(3),
+        Some(3),
+        Some(3),
+        Some(3),
+    ]));
+    let utf8_col: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("a"),
+        None,
+        None,
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        Some("d"),
+        Some("e"),
+    ]));
+    let plain_col: ArrayRef = Arc::new(Int64Array::from(vec![1, 1, 1, 1, 2, 2, 2, 2]));
+    let batch = RecordBatch::try_from_iter(vec![
+        ("int_col", int_col),
+        ("utf8_col", utf8_col),
+        ("plain_col", plain_col),
+    ])
+    .unwrap();
+
+    let write = |props: WriterProperties| {
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap()
+    };
+    let distinct_counts = |reader: &ParquetRecordBatchReaderBuilder<Bytes>, column_name| {
+        StatisticsConverter::try_new(column_name, reader.schema(), reader.parquet_schema())
+            .unwrap()
+            .row_group_distinct_counts(reader.metadata().row_groups())
+            .unwrap()
+    };
+
+    // 4 rows per row group, plain_col is not dictionary encoded
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(4)
+        .set_dictionary_distinct_count_enabled(true)
+        .set_column_dictionary_enabled("plain_col".into(), false)
+        .build();
+    let reader = write(props);
+    assert_eq!(
+        distinct_counts(&reader, "int_col"),
+        UInt64Array::from(vec![2, 1])
+    );
+    assert_eq!(
+        distinct_counts(&reader, "utf8_col"),
+        UInt64Array::from(vec![1, 4])
+    );
+    assert_eq!(
+        distinct_counts(&reader, "plain_col"),
+        UInt64Array::from(vec![None, None])
+    );
+
+    // Not written by default
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(4)
+        .build();
+    let reader = write(props);
+    for column_name in ["int_col", "utf8_col", "plain_col"] {
+        assert_eq!(
+            distinct_counts(&reader, column_name),
+            UInt64Array::from(vec![None, None])
+        );
+    }
+
+    // Not written if the dictionary falls back to another encoding
+    let props = WriterProperties::builder()
+        .set_dictionary_distinct_count_enabled(true)
+        .set_dictionary_page_size_limit(1)
+        .set_write_batch_size(1)
+        .build();
+    let reader = write(props);
+    for column_name in ["int_col", "utf8_col"] {
+        assert_eq!(
+            distinct_counts(&reader, column_name),
+            UInt64Array::from(vec![None])
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_dictionary() {
     let reader = TestReader {
@@ -2538,6 +3040,112 @@ fn missing_null_counts_as_zero() {
     );
 }
 
+#[test]
+fn ignore_corrupt_statistics() {
+    let message_type = "
+        message schema {
+            REQUIRED BYTE_ARRAY s (UTF8);
+            REQUIRED INT32 i;
+            REQUIRED INT32 u (UINT_32);
+        }
+    ";
+    let parquet_schema = Arc::new(SchemaDescriptor::new(Arc::new(
+        parse_message_type(message_type).unwrap(),
+    )));
+    let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+    // Statistics stored in the deprecated min and max fields, as old writers did
+    let byte_array_stats = |min: &str, max: &str, is_min_max_deprecated: bool| {
+        Statistics::ByteArray(ValueStatistics::new(
+            Some(ByteArray::from(min)),
+            Some(ByteArray::from(max)),
+            None,
+            Some(0),
+            is_min_max_deprecated,
+        ))
+    };
+    let int32_stats = |min: i32, max: i32| {
+        Statistics::Int32(ValueStatistics::new(
+            Some(min),
+            Some(max),
+            None,
+            Some(0),
+            true,
+        ))
+    };
+    let row_group = |s: Statistics| {
+        let columns = vec![
+            ColumnChunkMetaData::builder(parquet_schema.column(0))
+                .set_statistics(s)
+                .build()
+                .unwrap(),
+            ColumnChunkMetaData::builder(parquet_schema.column(1))
+                .set_statistics(int32_stats(-5, 5))
+                .build()
+                .unwrap(),
+            ColumnChunkMetaData::builder(parquet_schema.column(2))
+                .set_statistics(int32_stats(-5, 5))
+                .build()
+                .unwrap(),
+        ];
+        RowGroupMetaData::builder(parquet_schema.clone())
+            .set_column_metadata(columns)
+            .build()
+            .unwrap()
+    };
+    let row_groups = [
+        row_group(byte_array_stats("a", "\u{e9}", true)),
+        // min and max are equal, so are correct whatever the sort order
+        row_group(byte_array_stats("b", "b", true)),
+        // the min_value and max_value fields have a defined sort order
+        row_group(byte_array_stats("a", "\u{e9}", false)),
+    ];
+
+    let mins = |column: &str, created_by: Option<&str>| {
+        let converter = StatisticsConverter::try_new(column, &arrow_schema, &parquet_schema)
+            .unwrap()
+            .with_ignore_corrupt_statistics(created_by);
+        let mins = converter.row_group_mins(&row_groups).unwrap();
+        let maxes = converter.row_group_maxes(&row_groups).unwrap();
+        assert_eq!(mins.logical_nulls(), maxes.logical_nulls());
+        mins
+    };
+
+    let parquet_mr_1_7 =
+        Some("parquet-mr version 1.7.0 (build 1c44c64c2c7a41fe7ba8cfc7a6ce3a9ac2e7c0b0)");
+    let parquet_mr_1_8 =
+        Some("parquet-mr version 1.8.1 (build 4aba4dae7bb0d4edbcf7923ae1339f28fd3f7fcf)");
+    let parquet_mr_1_10 =
+        Some("parquet-mr version 1.10.0 (build 031a6654009e3b82020012a18434c582bd74c73a)");
+    let parquet_cpp_1_2 = Some("parquet-cpp version 1.2.0");
+    let all_strings: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+    let equal_strings: ArrayRef = Arc::new(StringArray::from(vec![None, Some("b"), Some("a")]));
+    let no_legacy_strings: ArrayRef = Arc::new(StringArray::from(vec![None, None, Some("a")]));
+
+    // statistics are returned as is by default
+    assert_eq!(&mins("s", None), &all_strings);
+
+    // strings were compared as signed bytes before parquet-mr 1.10.0
+    assert_eq!(&mins("s", parquet_mr_1_8), &equal_strings);
+    assert_eq!(&mins("s", parquet_cpp_1_2), &equal_strings);
+    assert_eq!(&mins("s", parquet_mr_1_10), &all_strings);
+    assert_eq!(&mins("s", Some("unknown writer")), &all_strings);
+
+    // binary statistics were incorrect before parquet-mr 1.8.0
+    assert_eq!(&mins("s", parquet_mr_1_7), &no_legacy_strings);
+
+    // signed columns are not affected
+    let signed: ArrayRef = Arc::new(Int32Array::from(vec![-5, -5, -5]));
+    assert_eq!(&mins("i", parquet_mr_1_7), &signed);
+
+    // unsigned integers were compared as signed values
+    let unsigned: ArrayRef = Arc::new(UInt32Array::from(vec![None, None, None]));
+    assert_eq!(&mins("u", parquet_mr_1_8), &unsigned);
+    assert_eq!(&mins("u", parquet_cpp_1_2), &unsigned);
+    let unsigned: ArrayRef = Arc::new(UInt32Array::from(vec![u32::MAX - 4; 3]));
+    assert_eq!(&mins("u", parquet_mr_1_10), &unsigned);
+}
+
 /// return an Arrow schema and corresponding Parquet SchemaDescriptor for
 /// a schema with a single boolean column "b"
 fn bool_arrow_and_parquet_schema() -> (SchemaRef, SchemaDescPtr) {
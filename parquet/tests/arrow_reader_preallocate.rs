@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for [`ArrowReaderOptions::with_preallocate_from_stats`], which use a
+//! tracking allocator to count the allocations made while decoding
+//!
+//! These are a separate test target, as the tracking allocator replaces the
+//! global allocator of the whole test binary
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_schema::DataType;
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+use parquet::file::properties::WriterProperties;
+
+const NUM_ROWS: usize = 8192;
+
+/// Writes a single row group with a plain encoded string column split across
+/// many small pages, so that the buffers grow repeatedly without preallocation
+fn write_file() -> (Bytes, StringArray) {
+    let values: StringArray = (0..NUM_ROWS)
+        .map(|i| Some(format!("value_{i:08}_{}", "x".repeat(i % 32))))
+        .collect();
+    let batch = RecordBatch::try_from_iter([("s", Arc::new(values.clone()) as ArrayRef)]).unwrap();
+
+    let props = WriterProperties::builder()
+        .set_dictionary_enabled(false)
+        .set_data_page_size_limit(1024)
+        .set_write_batch_size(64)
+        .build();
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    (Bytes::from(buf), values)
+}
+
+/// Reads `data` in a single batch, returning the number of allocations made
+fn read(data: &Bytes, options: ArrowReaderOptions, expected: &StringArray) -> usize {
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+    let string_view = builder.schema().field(0).data_type() == &DataType::Utf8View;
+    let mut reader = builder.with_batch_size(NUM_ROWS).build().unwrap();
+
+    let (batch, allocations) = count_allocations(|| reader.next().unwrap().unwrap());
+    assert_eq!(batch.num_rows(), NUM_ROWS);
+    match string_view {
+        true => assert!(batch.column(0).as_string_view().iter().eq(expected.iter())),
+        false => assert_eq!(batch.column(0).as_string::<i32>(), expected),
+    }
+    allocations
+}
+
+#[test]
+fn test_preallocate_utf8() {
+    let (data, expected) = write_file();
+    let default = read(&data, ArrowReaderOptions::new(), &expected);
+    let preallocated = read(
+        &data,
+        ArrowReaderOptions::new().with_preallocate_from_stats(true),
+        &expected,
+    );
+    assert!(
+        preallocated < default,
+        "expected fewer allocations with preallocation: {preallocated} >= {default}"
+    );
+}
+
+#[test]
+fn test_preallocate_utf8_view() {
+    let (data, expected) = write_file();
+    let options = ArrowReaderOptions::new().with_string_view(true);
+    let default = read(&data, options.clone(), &expected);
+    let preallocated = read(&data, options.with_preallocate_from_stats(true), &expected);
+    assert!(
+        preallocated < default,
+        "expected fewer allocations with preallocation: {preallocated} >= {default}"
+    );
+}
+
+// --- Allocation tracking ---
+
+thread_local! {
+    static ALLOCATIONS_IN_THREAD: Cell<usize> = const { Cell::new(0) };
+}
+
+pub struct TrackingAllocator {
+    allocator: std::alloc::System,
+}
+
+#[global_allocator]
+pub static GLOBAL_ALLOCATOR: TrackingAllocator = TrackingAllocator {
+    allocator: std::alloc::System,
+};
+
+#[allow(unsafe_code)]
+// SAFETY:
+// We just do book-keeping and then let another allocator do all the actual work.
+unsafe impl std::alloc::GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY:
+        // Just deferring
+        let ptr = unsafe { self.allocator.alloc(layout) };
+        if !ptr.is_null() {
+            let _ = ALLOCATIONS_IN_THREAD.try_with(|count| count.set(count.get() + 1));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY:
+        // Just deferring
+        unsafe { self.allocator.dealloc(ptr, layout) };
+    }
+
+    // No need to override `alloc_zeroed` or `realloc`, since they both by
+    // default defer to `alloc`, so each reallocation is counted
+}
+
+/// Returns the number of allocations made by this thread while running `run`
+fn count_allocations<R>(run: impl FnOnce() -> R) -> (R, usize) {
+    let start = ALLOCATIONS_IN_THREAD.with(Cell::get);
+    let ret = run();
+    (ret, ALLOCATIONS_IN_THREAD.with(Cell::get) - start)
+}
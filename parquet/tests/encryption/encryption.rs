@@ -25,11 +25,11 @@ use arrow::array::*;
 use arrow::error::Result as ArrowResult;
 use arrow_array::{Int32Array, RecordBatch};
 use arrow_schema::{DataType as ArrowDataType, DataType, Field, Schema};
-use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::{
-    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection,
-    RowSelector,
+    ArrowPredicateFn, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+    RowFilter, RowSelection, RowSelector,
 };
+use parquet::arrow::{ArrowWriter, ProjectionMask};
 use parquet::data_type::{ByteArray, ByteArrayType};
 use parquet::encryption::decrypt::FileDecryptionProperties;
 use parquet::encryption::encrypt::FileEncryptionProperties;
@@ -38,6 +38,8 @@ use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
 use parquet::file::writer::SerializedFileWriter;
 use parquet::schema::parser::parse_message_type;
+use parquet::schema::types::ColumnPath;
+use std::collections::HashMap;
 use std::fs::File;
 use std::sync::Arc;
 
@@ -874,6 +876,105 @@ fn test_write_encrypted_column() {
     assert_eq!(row_count, file_metadata.num_rows() as usize);
 }
 
+#[test]
+fn test_read_with_selective_column_keys() {
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+        (
+            "b",
+            Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    // Only column "a" is encrypted, column "b" is written in plaintext
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+        .with_column_key("a", column_key.clone())
+        .build()
+        .unwrap();
+    let props = WriterProperties::builder()
+        .with_file_encryption_properties(file_encryption_properties)
+        .build();
+
+    let mut file = tempfile::tempfile().unwrap();
+    let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let decryption_properties = FileDecryptionProperties::builder(footer_key)
+        .build()
+        .unwrap();
+
+    // Without a key for "a", the plaintext column can still be read
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(Arc::clone(&decryption_properties))
+        .with_column_keys(HashMap::new());
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(file.try_clone().unwrap(), options)
+            .unwrap();
+    let mask = ProjectionMask::columns(builder.parquet_schema(), ["b"]);
+    let batches = builder
+        .with_projection(mask)
+        .build()
+        .unwrap()
+        .collect::<ArrowResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0], batch.project(&[1]).unwrap());
+
+    // Projecting the encrypted column returns an error naming it
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(Arc::clone(&decryption_properties))
+        .with_column_keys(HashMap::new());
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(file.try_clone().unwrap(), options)
+            .unwrap();
+    let err = builder.build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: No decryption key was provided for encrypted column 'a'"
+    );
+
+    // As does using it in a row filter
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(Arc::clone(&decryption_properties))
+        .with_column_keys(HashMap::new());
+    let builder =
+        ParquetRecordBatchReaderBuilder::try_new_with_options(file.try_clone().unwrap(), options)
+            .unwrap();
+    let projection = ProjectionMask::columns(builder.parquet_schema(), ["a"]);
+    let mask = ProjectionMask::columns(builder.parquet_schema(), ["b"]);
+    let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+        projection,
+        |batch: RecordBatch| Ok(BooleanArray::from(vec![true; batch.num_rows()])),
+    ))]);
+    let err = builder
+        .with_projection(mask)
+        .with_row_filter(filter)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: No decryption key was provided for encrypted column 'a'"
+    );
+
+    // Providing the column key allows reading all columns
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(decryption_properties)
+        .with_column_keys(HashMap::from([(ColumnPath::from("a"), column_key)]));
+    let batches = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+        .unwrap()
+        .build()
+        .unwrap()
+        .collect::<ArrowResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0], batch);
+}
+
 #[test]
 fn test_write_encrypted_struct_field() {
     let int_32: Int32Array = [Some(1), Some(6)].iter().collect();